@@ -0,0 +1,87 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyBytes, PyDict};
+
+use _pydantic_core::{validate_core_schema, SchemaValidator, ValidationError};
+
+// a schema covering the validators which read JSON input directly
+const SCHEMA: &str = r"{
+    'type': 'union',
+    'choices': [
+        {
+            'type': 'typed-dict',
+            'total': False,
+            'fields': {
+                'int': {'type': 'typed-dict-field', 'schema': {'type': 'int', 'gt': 0}},
+                'float': {'type': 'typed-dict-field', 'schema': {'type': 'float', 'allow_inf_nan': False}},
+                'decimal': {'type': 'typed-dict-field', 'schema': {'type': 'decimal', 'max_digits': 10}},
+                'bool': {'type': 'typed-dict-field', 'schema': {'type': 'bool'}},
+                'str': {'type': 'typed-dict-field', 'schema': {'type': 'str', 'pattern': '^[a-z]+$'}},
+                'bytes': {'type': 'typed-dict-field', 'schema': {'type': 'bytes', 'max_length': 10}},
+                'date': {'type': 'typed-dict-field', 'schema': {'type': 'date'}},
+                'time': {'type': 'typed-dict-field', 'schema': {'type': 'time'}},
+                'datetime': {'type': 'typed-dict-field', 'schema': {'type': 'datetime', 'tz_constraint': 'aware'}},
+                'timedelta': {'type': 'typed-dict-field', 'schema': {'type': 'timedelta'}},
+                'uuid': {'type': 'typed-dict-field', 'schema': {'type': 'uuid'}},
+                'ulid': {'type': 'typed-dict-field', 'schema': {'type': 'ulid'}},
+                'url': {'type': 'typed-dict-field', 'schema': {'type': 'url'}},
+                'multi_host_url': {'type': 'typed-dict-field', 'schema': {'type': 'multi-host-url'}},
+                'literal': {'type': 'typed-dict-field', 'schema': {'type': 'literal', 'expected': [1, 'a', None]}},
+                'tuple': {
+                    'type': 'typed-dict-field',
+                    'schema': {
+                        'type': 'tuple',
+                        'items_schema': [{'type': 'int'}, {'type': 'str'}],
+                        'variadic_item_index': 1,
+                    },
+                },
+                'set': {'type': 'typed-dict-field', 'schema': {'type': 'set', 'items_schema': {'type': 'int'}}},
+                'dict': {
+                    'type': 'typed-dict-field',
+                    'schema': {'type': 'dict', 'keys_schema': {'type': 'int'}, 'values_schema': {'type': 'any'}},
+                },
+                'tagged': {
+                    'type': 'typed-dict-field',
+                    'schema': {
+                        'type': 'tagged-union',
+                        'discriminator': 'kind',
+                        'choices': {
+                            'a': {
+                                'type': 'typed-dict',
+                                'fields': {
+                                    'kind': {'type': 'typed-dict-field', 'schema': {'type': 'str'}},
+                                },
+                            },
+                        },
+                    },
+                },
+            },
+        },
+        {'type': 'list', 'items_schema': {'type': 'any'}},
+    ],
+}";
+
+static VALIDATOR: GILOnceCell<SchemaValidator> = GILOnceCell::new();
+
+fn build_validator(py: Python) -> SchemaValidator {
+    let schema = py.eval_bound(SCHEMA, None, None).unwrap();
+    let schema = validate_core_schema(&schema, None).unwrap();
+    let config = PyDict::new_bound(py);
+    config.set_item("catch_panics", true).unwrap();
+    SchemaValidator::py_new(py, &schema, Some(&config)).unwrap()
+}
+
+fuzz_target!(|data: &[u8]| {
+    Python::with_gil(|py| {
+        let validator = VALIDATOR.get_or_init(py, || build_validator(py));
+        let input = PyBytes::new_bound(py, data);
+        if let Err(err) = validator.validate_json(py, &input, None, None, None, None, None, None, None, false) {
+            // invalid input must only ever lead to a `ValidationError`, anything else, in particular
+            // a `PydanticInternalError` from a caught panic, is a bug
+            assert!(err.is_instance_of::<ValidationError>(py), "unexpected error: {err}");
+        }
+    });
+});
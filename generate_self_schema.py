@@ -69,6 +69,8 @@ def get_schema(obj: Any, definitions: dict[str, core_schema.CoreSchema]) -> core
         expected = all_literal_values(obj)
         assert expected, f'literal "expected" cannot be empty, obj={obj}'
         return {'type': 'literal', 'expected': expected}
+    elif origin is tuple:
+        return {'type': 'tuple', 'items_schema': [get_schema(arg, definitions) for arg in obj.__args__]}
     elif issubclass(origin, List):
         return {'type': 'list', 'items_schema': get_schema(obj.__args__[0], definitions)}
     elif issubclass(origin, Set):
@@ -1,30 +1,52 @@
 from __future__ import annotations
 
 import sys as _sys
+from collections.abc import Mapping as _Mapping
+from collections.abc import Sequence as _Sequence
+from contextlib import contextmanager as _contextmanager
 from typing import Any as _Any
+from typing import Iterator as _Iterator
+from typing import Literal as _Literal
 
 from ._pydantic_core import (
     ArgsKwargs,
     MultiHostUrl,
+    PhoneNumber,
     PydanticCustomError,
+    PydanticInternalError,
     PydanticKnownError,
+    PydanticMultipleErrors,
     PydanticOmit,
     PydanticSerializationError,
     PydanticSerializationUnexpectedValue,
     PydanticUndefined,
     PydanticUndefinedType,
     PydanticUseDefault,
+    PythonCallbackLimitExceeded,
+    ReadOnlyDict,
+    ReadOnlyList,
+    RuntimeSettings,
     SchemaError,
     SchemaSerializer,
     SchemaValidator,
     Some,
+    SubSerializer,
+    SubValidator,
     TzInfo,
     Url,
     ValidationError,
+    ValidationTimeout,
     __version__,
+    _coercion_audit_var,
+    _runtime_settings_var,
+    _union_debug_var,
     from_json,
+    register_format_backend,
+    register_string_format,
     to_json,
     to_jsonable_python,
+    unregister_format_backend,
+    unregister_string_format,
     validate_core_schema,
 )
 from .core_schema import CoreConfig, CoreSchema, CoreSchemaType, ErrorType
@@ -45,30 +67,53 @@ __all__ = [
     'CoreSchema',
     'CoreSchemaType',
     'SchemaValidator',
+    'SubValidator',
     'SchemaSerializer',
+    'SubSerializer',
     'Some',
     'Url',
     'MultiHostUrl',
+    'PhoneNumber',
     'ArgsKwargs',
+    'ReadOnlyDict',
+    'ReadOnlyList',
     'PydanticUndefined',
     'PydanticUndefinedType',
     'SchemaError',
     'ErrorDetails',
     'InitErrorDetails',
     'ValidationError',
+    'ValidationTimeout',
+    'PythonCallbackLimitExceeded',
+    'PydanticInternalError',
     'PydanticCustomError',
     'PydanticKnownError',
+    'PydanticMultipleErrors',
     'PydanticOmit',
     'PydanticUseDefault',
     'PydanticSerializationError',
     'PydanticSerializationUnexpectedValue',
     'TzInfo',
+    'RuntimeSettings',
+    'runtime_settings',
+    'coercion_audit',
+    'UnionCandidate',
+    'UnionDecision',
+    'SchemaHint',
+    'union_debug',
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'register_format_backend',
+    'unregister_format_backend',
+    'register_string_format',
+    'unregister_string_format',
     'validate_core_schema',
 ]
 
+_Mapping.register(ReadOnlyDict)
+_Sequence.register(ReadOnlyList)
+
 
 class ErrorDetails(_TypedDict):
     type: str
@@ -137,3 +182,127 @@ class MultiHostHost(_TypedDict):
     """The host part of this host, or `None`."""
     port: int | None
     """The port part of this host, or `None`."""
+
+
+class UnionCandidate(_TypedDict):
+    """
+    How a member of a smart mode union fared against the input.
+    """
+
+    choice: str
+    """The label of the member, or the name of its validator if it has no label."""
+    exactness: _Literal['exact', 'strict', 'lax'] | None
+    """How closely the input matched the member, `None` if validation against the member failed."""
+    fields_set_count: int | None
+    """The number of fields set when validating a model, typed dict or dataclass, otherwise `None`."""
+
+
+class UnionDecision(_TypedDict):
+    """
+    Which member of a smart mode union was chosen, and why.
+    """
+
+    union: str
+    """The name of the union validator, e.g. `union[int,str]`."""
+    loc: tuple[str, ...]
+    """Tuple of the model, typed dict and dataclass field names leading to the union."""
+    choice: str | None
+    """The label or validator name of the chosen member, `None` if no member was valid."""
+    reason: str
+    """
+    Why `choice` was chosen, one of `'exact type match'`, `'only valid choice'`, `'most fields set'`,
+    `'best exactness'`, `'leftmost of equally good choices'`, `'rightmost of equally good choices'`
+    or `'no valid choice'`.
+    """
+    candidates: list[UnionCandidate]
+    """The members tried, in order, members after an exact type match aren't tried."""
+
+
+class SchemaHint(_TypedDict):
+    """
+    An anti-pattern found in a schema by [`SchemaValidator.analyze`][pydantic_core.SchemaValidator.analyze].
+    """
+
+    kind: _Literal['large_union', 'backtracking_pattern', 'redundant_nullable']
+    """The kind of anti-pattern found."""
+    path: tuple[str | int, ...]
+    """The keys and indexes leading from the root of the schema to the schema the hint applies to."""
+    message: str
+    """A human readable description of the problem and how to fix it."""
+
+
+@_contextmanager
+def runtime_settings(
+    *,
+    ser_json_timedelta: _Literal['iso8601', 'float'] | None = None,
+    ser_json_inf_nan: _Literal['null', 'constants', 'strings'] | None = None,
+    warnings: bool | _Literal['none', 'warn', 'error'] | None = None,
+) -> _Iterator[RuntimeSettings]:
+    """
+    Temporarily override serialization behaviour for all `SchemaSerializer` calls in the current thread
+    or asyncio task, without rebuilding serializers.
+
+    Settings which are `None` are inherited from any enclosing `runtime_settings()` block.
+
+    Arguments:
+        ser_json_timedelta: Overrides the `ser_json_timedelta` config of every serializer.
+        ser_json_inf_nan: Overrides the `ser_json_inf_nan` config of every serializer.
+        warnings: The default for the `warnings` argument of `to_python` and `to_json`, an explicit
+            `warnings` argument still takes priority.
+
+    Yields:
+        The settings in effect within the block.
+    """
+    settings = RuntimeSettings(
+        ser_json_timedelta=ser_json_timedelta,
+        ser_json_inf_nan=ser_json_inf_nan,
+        warnings=warnings,
+        parent=_runtime_settings_var.get(),
+    )
+    token = _runtime_settings_var.set(settings)
+    try:
+        yield settings
+    finally:
+        _runtime_settings_var.reset(token)
+
+
+@_contextmanager
+def coercion_audit() -> _Iterator[list[tuple[tuple[str, ...], str, str]]]:
+    """
+    Record the lax conversions made by successful validation calls in the current thread or asyncio task,
+    e.g. `'1'` to `int`, for data quality tooling. Assignments and `revalidate_paths` calls are included.
+
+    Each conversion is recorded as a tuple of `(loc, from_type, to_type)`, where `loc` is a tuple of the model,
+    typed dict and dataclass field names leading to the value, `from_type` is the name of the input's type
+    (JSON values are named by the Python type they'd be parsed as) and `to_type` is the name of the type
+    the value was converted to.
+
+    Yields:
+        The list which conversions are appended to.
+    """
+    audit: list[tuple[tuple[str, ...], str, str]] = []
+    token = _coercion_audit_var.set(audit)
+    try:
+        yield audit
+    finally:
+        _coercion_audit_var.reset(token)
+
+
+@_contextmanager
+def union_debug() -> _Iterator[list[UnionDecision]]:
+    """
+    Record which member each smart mode union chooses during validation in the current thread
+    or asyncio task, and why, to help understand surprising choices.
+
+    Decisions are recorded as they're made, including those made within union members or validation calls
+    that later fail, and those made by assignments and `revalidate_paths` calls.
+
+    Yields:
+        The list which decisions are appended to.
+    """
+    decisions: list[UnionDecision] = []
+    token = _union_debug_var.set(decisions)
+    try:
+        yield decisions
+    finally:
+        _union_debug_var.reset(token)
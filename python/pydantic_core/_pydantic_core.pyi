@@ -1,10 +1,26 @@
 import datetime
-from typing import Any, Callable, Generic, Literal, TypeVar, final
+import random
+from contextvars import ContextVar
+from typing import (
+    Any,
+    Awaitable,
+    Callable,
+    ClassVar,
+    Generic,
+    Iterable,
+    Iterator,
+    Literal,
+    Mapping,
+    Sequence,
+    TypeVar,
+    final,
+    overload,
+)
 
 from _typeshed import SupportsAllComparisons
 from typing_extensions import LiteralString, Self, TypeAlias
 
-from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost
+from pydantic_core import ErrorDetails, ErrorTypeInfo, InitErrorDetails, MultiHostHost, SchemaHint, UnionDecision
 from pydantic_core.core_schema import CoreConfig, CoreSchema, ErrorType
 
 __all__ = [
@@ -13,14 +29,22 @@ __all__ = [
     'build_info',
     '_recursion_limit',
     'ArgsKwargs',
+    'ReadOnlyDict',
+    'ReadOnlyList',
     'SchemaValidator',
+    'SubValidator',
     'SchemaSerializer',
+    'SubSerializer',
     'Url',
     'MultiHostUrl',
     'SchemaError',
     'ValidationError',
+    'ValidationTimeout',
+    'PythonCallbackLimitExceeded',
+    'PydanticInternalError',
     'PydanticCustomError',
     'PydanticKnownError',
+    'PydanticMultipleErrors',
     'PydanticOmit',
     'PydanticUseDefault',
     'PydanticSerializationError',
@@ -31,14 +55,22 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'register_format_backend',
+    'unregister_format_backend',
+    'register_string_format',
+    'unregister_string_format',
     'list_all_errors',
     'TzInfo',
+    'RuntimeSettings',
     'validate_core_schema',
 ]
 __version__: str
 build_profile: str
 build_info: str
 _recursion_limit: int
+_runtime_settings_var: ContextVar[RuntimeSettings | None]
+_coercion_audit_var: ContextVar[list[tuple[tuple[str, ...], str, str]] | None]
+_union_debug_var: ContextVar[list[UnionDecision] | None]
 
 _T = TypeVar('_T', default=Any, covariant=True)
 
@@ -91,10 +123,20 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
+        deadline_ms: int | None = None,
+        tracer: Any | None = None,
+        report_lax: bool = False,
+        clock: datetime.datetime | Callable[[], datetime.datetime] | None = None,
+        rng: int | random.Random | None = None,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
 
+        Objects whose type defines a `__get_pydantic_core_input__()` method, e.g. ORM rows or protobuf messages,
+        are validated as the dict, list, tuple, set, `str`, `int`, `float` or `bool` it returns, before other
+        coercions and in strict mode too; a dict or tuple is also accepted as arguments for dataclasses and
+        functions.
+
         Arguments:
             input: The Python object to validate.
             strict: Whether to validate the object in strict mode.
@@ -105,13 +147,28 @@ class SchemaValidator:
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation, this is used when running
                 validation from the `__init__` method of a model.
+            deadline_ms: If set, validation is abandoned once it has taken longer than this many milliseconds.
+                The deadline is checked each time a recursive (`definition-ref`) schema is entered and before
+                each item of a list, tuple, set, frozenset or dict is validated.
+            tracer: An object with `on_enter(validator_name, loc)` and `on_exit(validator_name, loc)` methods,
+                called before and after validating the input and each field of models, typed dicts and
+                dataclasses, `loc` is a tuple of the field names leading to the field, e.g. for profiling.
+            report_lax: Whether to also return the locations where the input was only valid in lax mode,
+                e.g. to find inputs which would fail when migrating to strict mode. Locations are tuples of
+                the model, typed dict and dataclass field names leading to the value, `()` being the input itself.
+            clock: The current time for constraints relative to now, e.g. `now_op`, as a timezone aware datetime or
+                a function returning one, overriding [`CoreConfig.clock`][pydantic_core.core_schema.CoreConfig].
+            rng: The random number generator for default factories with `default_factory_takes_rng`, as an `int`
+                seed or a generator, overriding [`CoreConfig.rng`][pydantic_core.core_schema.CoreConfig].
 
         Raises:
             ValidationError: If validation fails.
+            ValidationTimeout: If `deadline_ms` is exceeded.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
-            The validated object.
+            The validated object, or if `report_lax` is set a tuple of the validated object and a list of
+                locations where lax validation was used.
         """
     def isinstance_python(
         self,
@@ -138,6 +195,11 @@ class SchemaValidator:
         strict: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
+        deadline_ms: int | None = None,
+        tracer: Any | None = None,
+        clock: datetime.datetime | Callable[[], datetime.datetime] | None = None,
+        rng: int | random.Random | None = None,
+        release_gil_during_parse: bool = False,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -155,9 +217,148 @@ class SchemaValidator:
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation.
+            deadline_ms: If set, validation is abandoned once it has taken longer than this many milliseconds.
+                The deadline is checked each time a recursive (`definition-ref`) schema is entered and before
+                each item of a list, tuple, set, frozenset or dict is validated.
+            tracer: An object with `on_enter(validator_name, loc)` and `on_exit(validator_name, loc)` methods,
+                called before and after validating the input and each field of models, typed dicts and
+                dataclasses, `loc` is a tuple of the field names leading to the field, e.g. for profiling.
+            clock: The current time for constraints relative to now, e.g. `now_op`, as a timezone aware datetime or
+                a function returning one, overriding [`CoreConfig.clock`][pydantic_core.core_schema.CoreConfig].
+            rng: The random number generator for default factories with `default_factory_takes_rng`, as an `int`
+                seed or a generator, overriding [`CoreConfig.rng`][pydantic_core.core_schema.CoreConfig].
+            release_gil_during_parse: Whether to release the GIL while the JSON data is parsed, so other threads can
+                run. Only parsing runs without the GIL, it's held while the parsed data is validated and the Python
+                objects are built. This only pays off for large inputs, for small inputs releasing the GIL costs
+                more than it saves.
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
+            ValidationTimeout: If `deadline_ms` is exceeded.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated Python object.
+        """
+    def validate_many(
+        self,
+        inputs: Iterable[Any],
+        *,
+        mode: Literal['python', 'json'] = 'python',
+        collect: Literal['errors', 'raise'] = 'errors',
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        tracer: Any | None = None,
+    ) -> tuple[list[Any], dict[int, ValidationError]]:
+        """
+        Validate each input of an iterable against the schema in one call.
+
+        This is much faster than calling `validate_python` or `validate_json` in a Python loop for many inputs,
+        since the validation state is set up once and reused, with
+        [`CoreConfig.intern_strings`][pydantic_core.core_schema.CoreConfig] keys are interned across all inputs.
+        Otherwise each input is validated as by `validate_python` or `validate_json`, including within
+        [`coercion_audit()`][pydantic_core.coercion_audit] and [`union_debug()`][pydantic_core.union_debug].
+
+        Arguments:
+            inputs: The inputs to validate, any iterable, it's consumed lazily so a generator can be used.
+            mode: Whether each input is validated as a Python object like `validate_python`, or as JSON data
+                like `validate_json`.
+            collect: With `'errors'` the error of each input which fails is collected, with `'raise'` the error
+                of the first input which fails is raised, located by the index of the input.
+            strict: Whether to validate the inputs in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            tracer: An object with `on_enter(validator_name, loc)` and `on_exit(validator_name, loc)` methods,
+                called around each input like [`validate_python`][pydantic_core.SchemaValidator.validate_python].
+
+        Raises:
+            ValidationError: With `collect='raise'`, if any input fails validation.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated Python objects in order, with `None` for each input which failed, and the
+                `ValidationError` of each input which failed keyed by its index.
+        """
+    def from_binary(self, data: bytes, *, strict: bool | None = None, context: Any | None = None) -> Any:
+        """
+        Validate data written by [`SchemaSerializer.to_binary`][pydantic_core.SchemaSerializer.to_binary]
+        and return the validated Python object.
+
+        The data is validated as if it were JSON, so anything that round-trips through `to_json` and
+        `validate_json` round-trips through `to_binary` and `from_binary`. Unlike `pickle`, loading the data
+        never executes code or constructs types not in the schema.
+
+        Arguments:
+            data: The binary data to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation fails.
+            ValueError: If the binary data is invalid.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated Python object.
+        """
+    def validate_bson(self, data: bytes, *, strict: bool | None = None, context: Any | None = None) -> Any:
+        """
+        Validate a [BSON](https://bsonspec.org/) document, e.g. written by
+        [`SchemaSerializer.to_bson`][pydantic_core.SchemaSerializer.to_bson], and return the validated Python object.
+
+        The document is read as a dict and validated like Python data. ObjectIds are read as their hex string,
+        datetimes as UTC datetimes, Decimal128s as `Decimal`s, binary with the UUID subtype as `UUID`s,
+        other binary as `bytes` and timestamps as ints.
+
+        Arguments:
+            data: The BSON document to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation fails.
+            ValueError: If the BSON data is invalid.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            The validated Python object.
+        """
+    def validate_redis_hash(
+        self,
+        mapping: Mapping[str, str] | Mapping[bytes, bytes],
+        *,
+        separator: str = '.',
+        strict: bool | None = None,
+        context: Any | None = None,
+    ) -> Any:
+        """
+        Validate the fields of a Redis hash, e.g. written by
+        [`SchemaSerializer.to_redis_hash`][pydantic_core.SchemaSerializer.to_redis_hash], and return the validated
+        Python object.
+
+        Fields are split on `separator` into nested dicts. Values which are JSON arrays, objects, strings, bools
+        or `null` are parsed, other values including numbers are validated as strings, so the hash is validated
+        like JSON in lax mode.
+
+        Arguments:
+            mapping: The hash's fields and values, as `str` or `bytes` as returned by Redis clients.
+            separator: The separator between the keys of nested fields.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation fails.
+            ValueError: If the hash is invalid, e.g. a field is both a value and has nested fields.
             Exception: Other error types maybe raised if internal errors occur.
 
         Returns:
@@ -215,7 +416,78 @@ class SchemaValidator:
         Returns:
             Either the model dict or a tuple of `(model_data, model_extra, fields_set)`
         """
-    def get_default_value(self, *, strict: bool | None = None, context: Any = None) -> Some | None:
+    def validate_assignment_many(
+        self,
+        obj: Any,
+        values: dict[str, Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+    ) -> dict[str, Any] | tuple[dict[str, Any], dict[str, Any] | None, set[str]]:
+        """
+        Validate assignments to several fields on a model at once, all or nothing.
+
+        Fields are validated in order, each with the other fields, including those assigned before it, as
+        [`info.data`][pydantic_core.core_schema.ValidationInfo.data]. The model is only updated if every assignment
+        is valid, the assigned fields are then added to `__pydantic_fields_set__` together, and model validators
+        are called once rather than once per field.
+
+        Arguments:
+            obj: The model instance being assigned to.
+            values: The values to assign keyed by field name.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If any assignment is invalid, with the errors of all of them.
+            TypeError: If the schema isn't for a model.
+
+        Returns:
+            Either the model or a tuple of `(model_data, model_extra, fields_set)`
+        """
+    def revalidate_paths(
+        self,
+        instance: Any,
+        paths: Sequence[str | Sequence[str | int]],
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+    ) -> Any:
+        """
+        Revalidate only the parts of a model instance covered by `paths`, e.g. after it was mutated without
+        validation, rather than the whole instance.
+
+        Each path is a field name, or a tuple or list of field names and indexes. The path is followed into nested
+        models, at the first field which isn't a model its value is validated in full, so `('items', 3, 'name')`
+        revalidates the `items` field unless it's a model. An empty path revalidates every field of the model.
+
+        The model's after validators are called again, as are the `requires` and `conflicts_with` checks involving
+        the revalidated fields, with the fields in `__pydantic_fields_set__` counting as set. Fields are validated
+        with the other fields as [`info.data`][pydantic_core.core_schema.ValidationInfo.data], like assignments.
+
+        Arguments:
+            instance: The model instance to revalidate.
+            paths: The paths to revalidate.
+            strict: Whether to validate in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+
+        Raises:
+            ValidationError: If validation fails, the instance and its nested models are then left unchanged.
+            TypeError: If `instance` isn't an instance of the model the schema is for.
+
+        Returns:
+            The instance, or what the model's after validators return, with the revalidated values set.
+        """
+    def get_default_value(
+        self, *, strict: bool | None = None, context: Any = None, rng: int | random.Random | None = None
+    ) -> Some | None:
         """
         Get the default value for the schema, including running default value validation.
 
@@ -224,31 +496,773 @@ class SchemaValidator:
                 If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            rng: The random number generator for default factories with `default_factory_takes_rng`, as an `int`
+                seed or a generator, overriding [`CoreConfig.rng`][pydantic_core.core_schema.CoreConfig].
+
+        Raises:
+            ValidationError: If validation fails.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
+        """
+    def get_effective_configs(self) -> dict[str, CoreConfig | None]:
+        """
+        Get the config each definition in the schema was built with, after applying
+        [`CoreConfig.config_merge`][pydantic_core.core_schema.CoreConfig] for models, dataclasses and typed dicts.
+
+        Returns:
+            A dict mapping each definition's `ref` to the config used to build it.
+        """
+    def stats(self, *, reset: bool = False) -> dict[str, Any]:
+        """
+        Get counters of how this validator has been used, counting is atomic so the validator can be shared
+        between threads.
+
+        The counters are:
+
+        * `calls` - the number of `validate_*` calls, including `validate_assignment`
+        * `failures` - the number of those calls which raised a `ValidationError`
+        * `lax_fallbacks` - the number of times a smart mode union was only matched by lax validation
+        * `union_retries` - the number of union members tried after the first member of a union
+        * `field_failures` - only with [`CoreConfig.field_failure_stats`][pydantic_core.core_schema.CoreConfig],
+          a dict of error locations to dicts of error types to the number of errors, e.g.
+          `{('user', 'email'): {'missing': 3}}`, integer location items like list indexes are counted together
+          as `'*'`
+
+        Arguments:
+            reset: Whether to reset the counters to zero after reading them.
+
+        Returns:
+            A dict of counter names to their values.
+        """
+    def analyze(self, *, max_union_choices: int = 10) -> list[SchemaHint]:
+        """
+        Find anti-patterns in the schema which can make validation slow, so schemas can be optimized before
+        they cause problems. The hints are:
+
+        * `large_union` - a union without a discriminator with more than `max_union_choices` choices, each choice
+          may be tried in turn, a [tagged union][pydantic_core.core_schema.tagged_union_schema] is usually faster
+        * `backtracking_pattern` - a string pattern validated with the `python-re` engine with a repeated group
+          containing a repetition, e.g. `(a+)+`, which can take exponential time for some inputs
+        * `redundant_nullable` - a nullable schema wrapping a nullable or none schema
+
+        Arguments:
+            max_union_choices: The number of choices above which unions are flagged.
+
+        Returns:
+            A list of hints, in the order the schemas appear in the schema.
+        """
+    def validator_for_loc(self, loc: str | tuple[str | int, ...] | list[str | int]) -> SubValidator:
+        """
+        A validator for just the part of the input at `loc`, e.g. to validate a single form field as it's edited,
+        without building a separate validator for each field.
+
+        `loc` is followed through the fields of models, dataclasses and typed dicts by name, the items of lists and
+        tuples by index and the values of dicts by any key, looking through function validators, defaults, nullable
+        schemas and definition references. Function validators wrapping a model or collection along the way aren't
+        called, but those of the part at `loc` are.
+
+        Arguments:
+            loc: The location of the part, as in [`ErrorDetails.loc`][pydantic_core.ErrorDetails], or a field name.
+
+        Raises:
+            ValueError: If there's no validator at `loc`.
+
+        Returns:
+            A [`SubValidator`][pydantic_core.SubValidator] sharing this validator's config, stats and freezing.
+        """
+    def fingerprint(self) -> str:
+        """
+        A hash of the schema and config the validator was built from, so caches can detect schema changes.
+
+        Validators which are equal have the same fingerprint, validators compare equal if their schemas and configs
+        are equal ignoring the order of keys in dicts, `metadata` and `serialization`, except for the order of
+        `fields` in typed dict and model fields schemas, which decides the order of the output.
+
+        The fingerprint is the same across processes for schemas made of builtin types, functions and classes,
+        which are identified by their qualified name, other objects are hashed with `hash()`.
+
+        Returns:
+            The hash as a 16 character hex string.
+        """
+    def alias_map(self, *, reverse: bool = False) -> dict[Any, Any]:
+        """
+        The paths each field is looked up by in the input, taken from the built validator, so aliases from
+        `alias_generator` and `populate_by_name` are included.
+
+        Only fields of the model, dataclass or typed dict at the root of the schema are included, looking through
+        function validators and definition references, nested models have their own validators.
+
+        Arguments:
+            reverse: Whether to map each path to the field name instead, paths of a single key are keyed by that
+                key and other paths by a tuple, a path shared by several fields maps to the first of them.
+
+        Returns:
+            A dict of field names to lists of paths, each a list of keys and indexes like a path in
+            `validation_alias`, in the order they're tried, e.g. `{'first_name': [['firstName'], ['first_name']]}`.
+        """
+    def freeze(self, *, audit: bool = False) -> Self:
+        """
+        Mark the validator as frozen, declaring the schema and config it was built from won't be mutated.
+
+        This only sets [`is_frozen`][pydantic_core.SchemaValidator.is_frozen] and, with `audit`, records a
+        fingerprint of the schema and config to check against, it doesn't make them immutable or add any
+        synchronization. Its caches and [`stats`][pydantic_core.SchemaValidator.stats] counters are synchronized
+        internally whether it's frozen or not.
+
+        Freezing again has no effect.
+
+        Arguments:
+            audit: Whether each `validate_*` call checks the schema and config haven't been mutated since, raising a
+                `RuntimeError` if they have. This walks the whole schema on each call, so it's meant for debugging.
+
+        Returns:
+            The same validator, so it can be frozen as it's built.
+        """
+    @property
+    def is_frozen(self) -> bool:
+        """
+        Whether [`freeze()`][pydantic_core.SchemaValidator.freeze] has been called.
+        """
+    def __eq__(self, other: object) -> bool: ...
+    def __hash__(self) -> int: ...
+
+_IncEx: TypeAlias = set[int] | set[str] | dict[int, _IncEx] | dict[str, _IncEx] | None
+_Selection: TypeAlias = dict[str, Literal[True] | _Selection | tuple[str, Literal[True] | _Selection]]
+_ByAlias: TypeAlias = bool | dict[str | tuple[str, ...], bool]
+
+@final
+class SchemaSerializer:
+    """
+    `SchemaSerializer` is the Python wrapper for `pydantic-core`'s Rust serialization logic, internally it owns one
+    `CombinedSerializer` which may in turn own more `CombinedSerializer`s which make up the full schema serializer.
+    """
+
+    def __new__(cls, schema: CoreSchema, config: CoreConfig | None = None) -> Self:
+        """
+        Create a new SchemaSerializer.
+
+        Arguments:
+            schema: The [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for serialization.
+            config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to to configure serialization.
+        """
+    def to_python(
+        self,
+        value: Any,
+        *,
+        mode: str | None = None,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: _ByAlias = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        selection: _Selection | None = None,
+        alias_profile: str | None = None,
+        cycles: Literal['error', 'omit', 'ref'] = 'error',
+    ) -> Any:
+        """
+        Serialize/marshal a Python object to a Python object including transforming and filtering data.
+
+        Arguments:
+            value: The Python object to serialize.
+            mode: The serialization mode to use, either `'python'` or `'json'`, defaults to `'python'`. In JSON mode,
+                all values are converted to JSON compatible types, e.g. `None`, `int`, `float`, `str`, `list`, `dict`.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields, or a dict of locations to whether fields at and below
+                them use alias names, where a location is a field name or a tuple of field names, `()` is the root
+                and other locations use alias names. Items of lists and dicts are in the location of their field.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            selection: A GraphQL style selection set, a dict of output keys to `True`, a nested selection for the
+                field's value, or a `(field name, selection)` tuple to alias a field. Only the selected fields are
+                included, in the order they're selected; nested selections apply to each item of lists.
+                `by_alias` is ignored since the selection decides the output keys.
+            alias_profile: Which of the `serialization_alias_profiles` of fields to use when serializing by alias,
+                fields without aliases for the profile use their `serialization_alias`.
+            cycles: How a value found inside itself, e.g. a model referencing itself, is serialized. `'error'` raises
+                an error, `'omit'` serializes it as `None`, `'ref'` serializes it as `{"$ref": "<loc>"}` where `<loc>`
+                is a JSON pointer to where the value was first serialized, e.g. `'#/children/0'`.
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+            The serialized Python object.
+        """
+    @overload
+    def to_json(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: _ByAlias = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        selection: _Selection | None = None,
+        field_sizes: Literal[False] = False,
+        alias_profile: str | None = None,
+        cycles: Literal['error', 'omit', 'ref'] = 'error',
+        dedupe_shared: bool = False,
+    ) -> bytes: ...
+    @overload
+    def to_json(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: _ByAlias = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+        selection: _Selection | None = None,
+        field_sizes: Literal[True],
+        alias_profile: str | None = None,
+        cycles: Literal['error', 'omit', 'ref'] = 'error',
+        dedupe_shared: bool = False,
+    ) -> tuple[bytes, dict[str, int]]:
+        """
+        Serialize a Python object to JSON including transforming and filtering data.
+
+        Arguments:
+            value: The Python object to serialize.
+            indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields, or a dict of locations to whether fields at and below
+                them use alias names, where a location is a field name or a tuple of field names, `()` is the root
+                and other locations use alias names. Items of lists and dicts are in the location of their field.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+            selection: A GraphQL style selection set, a dict of output keys to `True`, a nested selection for the
+                field's value, or a `(field name, selection)` tuple to alias a field. Only the selected fields are
+                included, in the order they're selected; nested selections apply to each item of lists.
+                `by_alias` is ignored since the selection decides the output keys.
+            field_sizes: Whether to also return the size in bytes of each member of the top level JSON object,
+                including its key, recorded while the JSON is written. Empty if the value isn't serialized to an object.
+            alias_profile: Which of the `serialization_alias_profiles` of fields to use when serializing by alias,
+                fields without aliases for the profile use their `serialization_alias`.
+            cycles: How a value found inside itself, e.g. a model referencing itself, is serialized. `'error'` raises
+                an error, `'omit'` serializes it as `None`, `'ref'` serializes it as `{"$ref": "<loc>"}` where `<loc>`
+                is a JSON pointer to where the value was first serialized, e.g. `'#/children/0'`.
+            dedupe_shared: Whether to write objects and arrays serialized from the same object more than once only
+                once, the output is `{"$defs": {...}, "value": ...}` where each shared value is in `$defs` and
+                replaced by `{"$ref": "#/$defs/<index>"}`. Values serialized with `include` or `exclude` aren't shared.
+                Can't be used with `cycles='ref'`.
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           JSON bytes, or a tuple of the JSON bytes and a dict of top level keys to sizes if `field_sizes` is set.
+        """
+    def to_binary(
+        self,
+        value: Any,
+        *,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to a compact binary format which can be loaded with
+        [`SchemaValidator.from_binary`][pydantic_core.SchemaValidator.from_binary], e.g. for caching.
+
+        The format is [MessagePack](https://msgpack.org/) of the data `to_json` would produce, so it's
+        self-describing and can be read by other MessagePack libraries.
+
+        Arguments:
+            value: The Python object to serialize.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           The serialized bytes.
+        """
+    def to_bson(
+        self,
+        value: Any,
+        *,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to a [BSON](https://bsonspec.org/) document, which can be loaded with
+        [`SchemaValidator.validate_bson`][pydantic_core.SchemaValidator.validate_bson], e.g. for MongoDB.
+
+        Datetimes are written as BSON datetimes, naive datetimes are assumed to be UTC and microseconds are
+        truncated to milliseconds. `Decimal`s are written as Decimal128s, `UUID`s as binary with the UUID subtype,
+        `bytes` as binary and PyMongo `ObjectId`s as ObjectIds. Other values are written as they would be in JSON.
+
+        Arguments:
+            value: The Python object to serialize, it must serialize to a dict.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails, e.g. the value doesn't serialize to a dict.
+
+        Returns:
+           The BSON document.
+        """
+    def to_metric_labels(
+        self,
+        value: Any,
+        *,
+        by_alias: bool = True,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        context: Any | None = None,
+    ) -> dict[str, str]:
+        """
+        Serialize the fields marked with `metric_label` to a dict of metric labels, e.g. for Prometheus or StatsD.
+
+        Fields are serialized as with `to_python(mode='json')`, `None` becomes an empty string and other values
+        which aren't strings are written as JSON. Label names have characters other than ASCII letters, digits
+        and `_` replaced with `_`, label values have whitespace, control characters and `,:|#="\\` replaced
+        with `_`.
+
+        Arguments:
+            value: The Python object to serialize, a model, dataclass or typed dict.
+            by_alias: Whether to use the alias names of fields as label names.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails.
+
+        Returns:
+           The label names and values.
+        """
+    def diff(
+        self,
+        old: Any,
+        new: Any,
+        *,
+        mode: str = 'json',
+        by_alias: bool = True,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        context: Any | None = None,
+    ) -> list[dict[str, Any]]:
+        """
+        Serialize `old` and `new` and return the changes between them, e.g. for audit logging.
+
+        Dicts are compared by key and lists and tuples by index, other values are changed unless they're equal
+        and of the same type. Each change is a dict with `op`, one of `'add'`, `'remove'` or `'replace'`, the
+        `path` to the value as a tuple of keys and indexes, and the serialized value `before` and `after` the
+        change, `before` is left out when the value is added and `after` when it's removed.
+
+        Arguments:
+            old: The Python object to serialize before the change.
+            new: The Python object to serialize after the change.
+            mode: The serialization mode to use, either `'python'` or `'json'`, defaults to `'json'`.
+            by_alias: Whether to use the alias names of fields.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails.
+
+        Returns:
+           The changes, in the order of the keys and items of `old` followed by those only in `new`.
+        """
+    def to_redis_hash(
+        self,
+        value: Any,
+        *,
+        separator: str = '.',
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> dict[str, str]:
+        """
+        Serialize a Python object to the fields of a Redis hash, which can be loaded with
+        [`SchemaValidator.validate_redis_hash`][pydantic_core.SchemaValidator.validate_redis_hash].
+
+        The object is serialized as with `to_python(mode='json')`, then nested dicts are flattened with their keys
+        joined by `separator`. Strings are written as they are, other values including lists and `None` are
+        written as JSON, strings which would be read back as something else, e.g. `'true'`, are written as JSON
+        strings.
+
+        Arguments:
+            value: The Python object to serialize, it must serialize to a dict.
+            separator: The separator between the keys of nested fields.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails, e.g. the value doesn't serialize to a dict.
+
+        Returns:
+           The hash's fields and values.
+        """
+    def to_sql_params(
+        self,
+        value: Any,
+        *,
+        many: bool = False,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> tuple[tuple[str, ...], tuple[Any, ...] | list[tuple[Any, ...]]]:
+        """
+        Serialize a Python object to column names and a tuple of parameters which can be bound in SQL, e.g.
+        for an `INSERT` statement, without calling `to_python` and adapting each value.
+
+        Columns are in the order `to_python` produces them, for models, dataclasses and typed dicts this is
+        the order of the fields in the schema. `None`, bools, ints, floats, strings, bytes and `Decimal`s are
+        passed through unchanged, other values like datetimes and `UUID`s are written as they would be in
+        JSON (e.g. ISO 8601 strings) and lists and dicts are written as JSON text.
+
+        Arguments:
+            value: The Python object to serialize, it must serialize to a dict.
+            many: Whether `value` is an iterable of objects, e.g. for `executemany()`, each object must have
+                the same columns.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields as column names.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails, e.g. the value doesn't serialize to a dict or
+                objects have different columns with `many=True`.
+
+        Returns:
+           The column names and the parameters, with `many=True` a list of parameter tuples.
+        """
+    def serialize(
+        self,
+        value: Any,
+        *,
+        media_type: str = 'application/json',
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to bytes in the format given by `media_type`, for content negotiation
+        in web frameworks.
+
+        `'application/json'` and types with a `+json` suffix are serialized like
+        [`to_json`][pydantic_core.SchemaSerializer.to_json], `'application/msgpack'`, `'application/x-msgpack'`,
+        `'application/vnd.msgpack'` and types with a `+msgpack` suffix like
+        [`to_binary`][pydantic_core.SchemaSerializer.to_binary], and `'application/bson'` and types with a `+bson`
+        suffix like [`to_bson`][pydantic_core.SchemaSerializer.to_bson].
+        Media type parameters, e.g. `charset`, are ignored. Other formats can be added with [`register_format_backend`][pydantic_core.register_format_backend].
+
+        Arguments:
+            value: The Python object to serialize.
+            media_type: The media type to serialize to.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+                If `None`, the `warnings` setting of [`runtime_settings`][pydantic_core.runtime_settings]
+                is used, falling back to True.
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            ValueError: If the media type isn't supported.
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+           The serialized bytes.
+        """
+    def to_python_async(
+        self,
+        value: Any,
+        *,
+        mode: str | None = None,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> Awaitable[Any]:
+        """
+        Like [`to_python`][pydantic_core.SchemaSerializer.to_python], but `function-plain` and `function-wrap`
+        serializers may return awaitables, e.g. coroutines.
+
+        Serialization happens immediately, awaitables returned by serializer functions are awaited in order
+        when the result is awaited, and their results are serialized with the function's `return_schema`.
+
+        Arguments are the same as for [`to_python`][pydantic_core.SchemaSerializer.to_python].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+            An awaitable which resolves to the serialized Python object.
+        """
+    def to_json_async(
+        self,
+        value: Any,
+        *,
+        indent: int | None = None,
+        include: _IncEx = None,
+        exclude: _IncEx = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> Awaitable[bytes]:
+        """
+        Like [`to_json`][pydantic_core.SchemaSerializer.to_json], but `function-plain` and `function-wrap`
+        serializers may return awaitables, e.g. coroutines.
+
+        Arguments are the same as for [`to_json`][pydantic_core.SchemaSerializer.to_json].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+        Returns:
+            An awaitable which resolves to JSON bytes.
+        """
+    def alias_map(self, *, alias_profile: str | None = None, reverse: bool = False) -> dict[str, Any]:
+        """
+        The keys each field is serialized under with `by_alias=True`, taken from the built serializer, fields
+        without an alias are serialized under their name and excluded fields are left out.
+
+        Only fields of the model, dataclass or typed dict at the root of the schema are included, looking through
+        wrap function serializers and definition references. Fields are sorted by name, then computed fields follow.
+
+        Arguments:
+            alias_profile: The alias profile to get the aliases of, as in `to_python`.
+            reverse: Whether to map each key to the field name instead.
+
+        Returns:
+            A dict of field names to lists of keys, e.g. `{'first_name': ['firstName']}`.
+        """
+    def freeze(self, *, audit: bool = False) -> Self:
+        """
+        Mark the serializer as frozen, like [`SchemaValidator.freeze()`][pydantic_core.SchemaValidator.freeze], this
+        is only an audit of the schema and config, not a guarantee they aren't mutated.
+
+        Freezing again has no effect.
+
+        Arguments:
+            audit: Whether each `to_*` call, `diff` and `serialize` check the schema and config haven't been mutated
+                since, raising a `RuntimeError` if they have.
+
+        Returns:
+            The same serializer, so it can be frozen as it's built.
+        """
+    @property
+    def is_frozen(self) -> bool:
+        """
+        Whether [`freeze()`][pydantic_core.SchemaSerializer.freeze] has been called.
+        """
+    def serializer_for_loc(self, loc: str | tuple[str | int, ...] | list[str | int]) -> SubSerializer:
+        """
+        A serializer for just the part of a value at `loc`, e.g. to dump the fields changed by a PATCH request,
+        mirroring [`SchemaValidator.validator_for_loc()`][pydantic_core.SchemaValidator.validator_for_loc].
+
+        Wrap function serializers of models and collections along the way aren't called, but those of the part at
+        `loc` are.
+
+        Arguments:
+            loc: The location of the part by field name and index, or a field name.
 
         Raises:
-            ValidationError: If validation fails.
-            Exception: Other error types maybe raised if internal errors occur.
+            ValueError: If there's no serializer at `loc`, including for excluded fields.
 
         Returns:
-            `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
+            A [`SubSerializer`][pydantic_core.SubSerializer] sharing this serializer's config and freezing.
         """
 
-_IncEx: TypeAlias = set[int] | set[str] | dict[int, _IncEx] | dict[str, _IncEx] | None
-
 @final
-class SchemaSerializer:
+class SubSerializer:
     """
-    `SchemaSerializer` is the Python wrapper for `pydantic-core`'s Rust serialization logic, internally it owns one
-    `CombinedSerializer` which may in turn own more `CombinedSerializer`s which make up the full schema serializer.
+    Serializes the part of a value at a location of a [`SchemaSerializer`][pydantic_core.SchemaSerializer], from
+    [`SchemaSerializer.serializer_for_loc()`][pydantic_core.SchemaSerializer.serializer_for_loc].
     """
 
-    def __new__(cls, schema: CoreSchema, config: CoreConfig | None = None) -> Self:
+    @property
+    def loc(self) -> tuple[str | int, ...]:
         """
-        Create a new SchemaSerializer.
-
-        Arguments:
-            schema: The [`CoreSchema`][pydantic_core.core_schema.CoreSchema] to use for serialization.
-            config: Optionally a [`CoreConfig`][pydantic_core.core_schema.CoreConfig] to to configure serialization.
+        The location of the part serialized.
         """
     def to_python(
         self,
@@ -262,33 +1276,13 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
-        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
     ) -> Any:
         """
-        Serialize/marshal a Python object to a Python object including transforming and filtering data.
-
-        Arguments:
-            value: The Python object to serialize.
-            mode: The serialization mode to use, either `'python'` or `'json'`, defaults to `'python'`. In JSON mode,
-                all values are converted to JSON compatible types, e.g. `None`, `int`, `float`, `str`, `list`, `dict`.
-            include: A set of fields to include, if `None` all fields are included.
-            exclude: A set of fields to exclude, if `None` no fields are excluded.
-            by_alias: Whether to use the alias names of fields.
-            exclude_unset: Whether to exclude fields that are not set,
-                e.g. are not included in `__pydantic_fields_set__`.
-            exclude_defaults: Whether to exclude fields that are equal to their default value.
-            exclude_none: Whether to exclude fields that have a value of `None`.
-            round_trip: Whether to enable serialization and validation round-trip support.
-            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
-                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
-            fallback: A function to call when an unknown value is encountered,
-                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
-            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
-            context: The context to use for serialization, this is passed to functional serializers as
-                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        Serialize the part to Python, like [`SchemaSerializer.to_python()`][pydantic_core.SchemaSerializer.to_python].
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -308,38 +1302,42 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
-        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
         context: Any | None = None,
     ) -> bytes:
         """
-        Serialize a Python object to JSON including transforming and filtering data.
-
-        Arguments:
-            value: The Python object to serialize.
-            indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
-            include: A set of fields to include, if `None` all fields are included.
-            exclude: A set of fields to exclude, if `None` no fields are excluded.
-            by_alias: Whether to use the alias names of fields.
-            exclude_unset: Whether to exclude fields that are not set,
-                e.g. are not included in `__pydantic_fields_set__`.
-            exclude_defaults: Whether to exclude fields that are equal to their default value.
-            exclude_none: Whether to exclude fields that have a value of `None`.
-            round_trip: Whether to enable serialization and validation round-trip support.
-            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
-                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
-            fallback: A function to call when an unknown value is encountered,
-                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
-            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
-            context: The context to use for serialization, this is passed to functional serializers as
-                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        Serialize the part to JSON, like [`SchemaSerializer.to_json()`][pydantic_core.SchemaSerializer.to_json].
 
         Raises:
             PydanticSerializationError: If serialization fails and no `fallback` function is provided.
 
         Returns:
-           JSON bytes.
+            JSON bytes.
+        """
+
+@final
+class RuntimeSettings:
+    """
+    Overrides of serialization behaviour, set for the current context by
+    [`runtime_settings`][pydantic_core.runtime_settings].
+    """
+
+    def __new__(
+        cls,
+        *,
+        ser_json_timedelta: Literal['iso8601', 'float'] | None = None,
+        ser_json_inf_nan: Literal['null', 'constants', 'strings'] | None = None,
+        warnings: bool | Literal['none', 'warn', 'error'] | None = None,
+        parent: RuntimeSettings | None = None,
+    ) -> Self:
+        """
+        Arguments:
+            ser_json_timedelta: Overrides the `ser_json_timedelta` config of every serializer.
+            ser_json_inf_nan: Overrides the `ser_json_inf_nan` config of every serializer.
+            warnings: The default for the `warnings` argument of `SchemaSerializer` methods.
+            parent: Settings to inherit values from where they're `None` here.
         """
 
 def to_json(
@@ -358,6 +1356,8 @@ def to_json(
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    cycles: Literal['error', 'omit', 'ref'] = 'error',
+    dedupe_shared: bool = False,
 ) -> bytes:
     """
     Serialize a Python object to JSON including transforming and filtering data.
@@ -382,6 +1382,10 @@ def to_json(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        cycles: How a value found inside itself is serialized, `'error'` raises an error, `'omit'` serializes it
+            as `None`, `'ref'` serializes it as `{"$ref": "<loc>"}` with a JSON pointer to where it was first seen.
+        dedupe_shared: Whether to write objects and arrays serialized from the same object more than once only once,
+            see [`SchemaSerializer.to_json`][pydantic_core.SchemaSerializer.to_json].
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -433,6 +1437,7 @@ def to_jsonable_python(
     fallback: Callable[[Any], Any] | None = None,
     serialize_as_any: bool = False,
     context: Any | None = None,
+    cycles: Literal['error', 'omit', 'ref'] = 'error',
 ) -> Any:
     """
     Serialize/marshal a Python object to a JSON-serializable Python object including transforming and filtering data.
@@ -457,6 +1462,8 @@ def to_jsonable_python(
         serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
         context: The context to use for serialization, this is passed to functional serializers as
             [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+        cycles: How a value found inside itself is serialized, `'error'` raises an error, `'omit'` serializes it
+            as `None`, `'ref'` serializes it as `{"$ref": "<loc>"}` with a JSON pointer to where it was first seen.
 
     Raises:
         PydanticSerializationError: If serialization fails and no `fallback` function is provided.
@@ -465,6 +1472,63 @@ def to_jsonable_python(
         The serialized Python object.
     """
 
+def register_format_backend(name: str, encoder: Callable[[Any], bytes], *, media_types: list[str]) -> None:
+    """
+    Register a serialization format for [`SchemaSerializer.serialize`][pydantic_core.SchemaSerializer.serialize],
+    so other formats (e.g. BSON) can be added by third-party packages.
+
+    `encoder` is called with the JSON compatible data
+    [`to_python(mode='json')`][pydantic_core.SchemaSerializer.to_python] would return, and must return bytes.
+    Registering a backend with the same `name` again replaces it.
+
+    Args:
+        name: The name of the backend, used in errors and to unregister it.
+        encoder: The function which encodes data in the format.
+        media_types: The media types serialized with `encoder`, parameters like `charset` are ignored.
+
+    Raises:
+        ValueError: If a media type is serialized by pydantic-core or another registered backend.
+    """
+
+def unregister_format_backend(name: str) -> bool:
+    """
+    Remove a format backend added with [`register_format_backend`][pydantic_core.register_format_backend].
+
+    Args:
+        name: The name the backend was registered with.
+
+    Returns:
+        Whether a backend with that name was registered.
+    """
+
+def register_string_format(name: str, check: Callable[[str], bool]) -> None:
+    """
+    Register a format for [`string_format_schema`][pydantic_core.core_schema.string_format_schema], so formats
+    pydantic-core doesn't check (e.g. `email`) can be used when importing JSON Schemas.
+
+    `check` is called with each string, it should return whether the string matches the format or raise a
+    `ValueError` whose message is included in the validation error. Schemas use the check registered when they
+    are built, registering a format with the same `name` again replaces it for schemas built later.
+
+    Args:
+        name: The name of the format.
+        check: The function which checks strings.
+
+    Raises:
+        ValueError: If the format is checked by pydantic-core.
+    """
+
+def unregister_string_format(name: str) -> bool:
+    """
+    Remove a format added with [`register_string_format`][pydantic_core.register_string_format].
+
+    Args:
+        name: The name the format was registered with.
+
+    Returns:
+        Whether a format with that name was registered.
+    """
+
 class Url(SupportsAllComparisons):
     """
     A URL type, internal logic uses the [url rust crate](https://docs.rs/url/latest/url/) originally developed
@@ -563,12 +1627,37 @@ class Url(SupportsAllComparisons):
         If the URL must be punycode encoded, this is the decoded string, e.g if the input URL is `https://£££.com`,
         `unicode_string()` will be `https://£££.com`
         """
+    def normalize(self) -> Self:
+        """
+        The URL in its canonical form, with the host lowercased and percent-encoding normalized: escapes of
+        unreserved characters (e.g. `%7E`) are decoded and all other escapes use uppercase hex digits.
+
+        Known default ports (e.g. `:443` for `https`) are already removed when the URL is parsed.
+
+        Equality and hashing of URLs use this form, so `http://example.com/%7Efoo` equals `http://example.com/~foo`.
+        """
+    def join(self, relative: str) -> Self:
+        """
+        Resolve `relative` against this URL, as a browser would resolve a link.
+
+        Args:
+            relative: The relative URL, e.g. `../other?q=1`, or a full URL.
+
+        Returns:
+            A new instance of this URL's type.
+
+        Raises:
+            ValueError: If `relative` can't be resolved.
+            ValidationError: If the resulting URL is invalid.
+        """
     def __repr__(self) -> str: ...
     def __str__(self) -> str:
         """
         The URL as a string, this will punycode encode the host if required.
         """
-    def __deepcopy__(self, memo: dict) -> str: ...
+    def __copy__(self) -> Self: ...
+    def __deepcopy__(self, memo: dict) -> Self: ...
+    def __reduce__(self) -> tuple[type[Self], tuple[str]]: ...
     @classmethod
     def build(
         cls,
@@ -677,12 +1766,37 @@ class MultiHostUrl(SupportsAllComparisons):
         """
         The URL as a unicode string, unlike `__str__()` this will not punycode encode the hosts.
         """
+    def normalize(self) -> Self:
+        """
+        The URL in its canonical form, with the host lowercased and percent-encoding normalized: escapes of
+        unreserved characters (e.g. `%7E`) are decoded and all other escapes use uppercase hex digits.
+
+        Known default ports (e.g. `:443` for `https`) are already removed when the URL is parsed.
+
+        Equality and hashing of URLs use this form.
+        """
+    def join(self, relative: str) -> Self:
+        """
+        Resolve `relative` against this URL, as a browser would resolve a link.
+
+        Args:
+            relative: The relative URL, e.g. `../other?q=1`, or a full URL.
+
+        Returns:
+            A new instance of this URL's type, the other hosts are kept unless `relative` sets the host.
+
+        Raises:
+            ValueError: If `relative` can't be resolved.
+            ValidationError: If the resulting URL is invalid.
+        """
     def __repr__(self) -> str: ...
     def __str__(self) -> str:
         """
         The URL as a string, this will punycode encode the hosts if required.
         """
+    def __copy__(self) -> Self: ...
     def __deepcopy__(self, memo: dict) -> Self: ...
+    def __reduce__(self) -> tuple[type[Self], tuple[str]]: ...
     @classmethod
     def build(
         cls,
@@ -718,6 +1832,58 @@ class MultiHostUrl(SupportsAllComparisons):
             An instance of `MultiHostUrl`
         """
 
+@final
+class PhoneNumber:
+    """
+    A phone number, as returned by [`phone_schema`][pydantic_core.core_schema.phone_schema] with
+    `as_phone_number=True`.
+
+    Parsing, validation and formatting use the
+    [phonenumber rust crate](https://docs.rs/phonenumber/latest/phonenumber/), a port of Google's libphonenumber.
+    """
+
+    def __new__(cls, number: str, default_region: str | None = None) -> Self:
+        """
+        Create a new `PhoneNumber` instance.
+
+        Args:
+            number: The phone number, in E.164 or international format, or national format with `default_region`.
+            default_region: The ISO 3166-1 alpha-2 code of the region national numbers belong to, e.g. `'GB'`.
+
+        Raises:
+            ValueError: If the number or region is invalid.
+        """
+    @property
+    def country_code(self) -> int:
+        """
+        The country calling code, e.g. `44` for `+44 20 7946 0018`.
+        """
+    @property
+    def national_number(self) -> str:
+        """
+        The national significant number, e.g. `'2079460018'` for `+44 20 7946 0018`.
+        """
+    @property
+    def extension(self) -> str | None:
+        """
+        The extension, or `None`.
+        """
+    @property
+    def region(self) -> str | None:
+        """
+        The ISO 3166-1 alpha-2 code of the region the number belongs to, or `None` for non-geographic numbers.
+        """
+    def format(self, format: Literal['e164', 'international', 'national', 'rfc3966'] = 'e164') -> str:
+        """
+        The number as a string in the given format, e.g. `'+44 20 7946 0018'` for `'international'`.
+        """
+    def __str__(self) -> str:
+        """
+        The number in E.164 format, e.g. `'+442079460018'`.
+        """
+    def __repr__(self) -> str: ...
+    def __deepcopy__(self, memo: dict) -> Self: ...
+
 @final
 class SchemaError(Exception):
     """
@@ -844,6 +2010,31 @@ class PydanticKnownError(ValueError):
     def message_template(self) -> str: ...
     def message(self) -> str: ...
 
+@final
+class PydanticMultipleErrors(ValueError):
+    """
+    Raised by function validators to report several errors at once, they're all added to the `ValidationError`.
+    """
+
+    def __new__(
+        cls,
+        errors: Sequence[
+            PydanticCustomError
+            | PydanticKnownError
+            | tuple[str | int | tuple[str | int, ...], PydanticCustomError | PydanticKnownError]
+        ],
+    ) -> Self:
+        """
+        Arguments:
+            errors: The errors, either alone or as `(loc, error)` where `loc` is the location of the error
+                relative to the value being validated.
+        """
+    @property
+    def errors(self) -> list[tuple[tuple[str | int, ...], PydanticCustomError | PydanticKnownError]]:
+        """
+        `(loc, error)` for each error.
+        """
+
 @final
 class PydanticOmit(Exception):
     def __new__(cls) -> Self: ...
@@ -852,6 +2043,61 @@ class PydanticOmit(Exception):
 class PydanticUseDefault(Exception):
     def __new__(cls) -> Self: ...
 
+@final
+class ValidationTimeout(Exception):
+    """
+    Raised when validation takes longer than the `deadline_ms` passed to
+    [`validate_python`][pydantic_core.SchemaValidator.validate_python] or
+    [`validate_json`][pydantic_core.SchemaValidator.validate_json].
+    """
+
+    def __new__(cls, deadline_ms: int) -> Self: ...
+    @property
+    def deadline_ms(self) -> int:
+        """
+        The deadline which was exceeded, in milliseconds.
+        """
+
+@final
+class PythonCallbackLimitExceeded(Exception):
+    """
+    Raised when a single validation or serialization call calls more Python functions than the
+    `max_python_callbacks` config allows.
+    """
+
+    def __new__(cls, max_python_callbacks: int) -> Self: ...
+    @property
+    def max_python_callbacks(self) -> int:
+        """
+        The limit which was exceeded.
+        """
+
+@final
+class PydanticInternalError(Exception):
+    """
+    Raised in place of an internal panic during validation or serialization when the `catch_panics` config is set,
+    this indicates a bug in pydantic-core and should be reported.
+    """
+
+    def __new__(cls, message: str, root_schema_node: str, location: str | None = None) -> Self: ...
+    @property
+    def message(self) -> str:
+        """
+        The panic message.
+        """
+    @property
+    def root_schema_node(self) -> str:
+        """
+        The name of the root validator or serializer of the call which panicked, e.g. `list[int]`,
+        the panic may have happened in any validator or serializer within it.
+        """
+    @property
+    def location(self) -> str | None:
+        """
+        The `file:line:column` in the Rust source where the panic was raised, `None` for panics raised
+        by Python code called during validation or serialization.
+        """
+
 @final
 class PydanticSerializationError(ValueError):
     def __new__(cls, message: str) -> Self: ...
@@ -868,6 +2114,87 @@ class ArgsKwargs:
     @property
     def kwargs(self) -> dict[str, Any] | None: ...
 
+@final
+class SubValidator:
+    """
+    Validates the part of the input at a location of a [`SchemaValidator`][pydantic_core.SchemaValidator], from
+    [`SchemaValidator.validator_for_loc()`][pydantic_core.SchemaValidator.validator_for_loc].
+
+    Errors are located from the root, as if the whole input had been validated, and `info.data` is `None` for
+    function validators since the other fields aren't validated.
+    """
+
+    @property
+    def loc(self) -> tuple[str | int, ...]:
+        """
+        The location of the part validated.
+        """
+    def validate_python(
+        self,
+        input: Any,
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+    ) -> Any:
+        """
+        Validate a Python object against the part's schema, like
+        [`SchemaValidator.validate_python()`][pydantic_core.SchemaValidator.validate_python].
+
+        Raises:
+            ValidationError: If validation fails.
+
+        Returns:
+            The validated Python object.
+        """
+    def validate_json(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+    ) -> Any:
+        """
+        Validate JSON data against the part's schema, like
+        [`SchemaValidator.validate_json()`][pydantic_core.SchemaValidator.validate_json].
+
+        Raises:
+            ValidationError: If validation fails or if the JSON data is invalid.
+
+        Returns:
+            The validated Python object.
+        """
+
+_KT = TypeVar('_KT')
+_VT_co = TypeVar('_VT_co', covariant=True)
+_ItemT_co = TypeVar('_ItemT_co', covariant=True)
+
+@final
+class ReadOnlyDict(Mapping[_KT, _VT_co]):
+    """
+    A read-only view of a validated dict, returned by dict schemas with `read_only=True`.
+    """
+
+    def __getitem__(self, key: _KT, /) -> _VT_co: ...
+    def __len__(self) -> int: ...
+    def __iter__(self) -> Iterator[_KT]: ...
+    def copy(self) -> dict[_KT, _VT_co]:
+        """Returns a mutable copy of the dict."""
+
+@final
+class ReadOnlyList(Sequence[_ItemT_co]):
+    """
+    A read-only view of a validated list, returned by list schemas with `read_only=True`.
+    """
+
+    @overload
+    def __getitem__(self, index: int, /) -> _ItemT_co: ...
+    @overload
+    def __getitem__(self, index: slice, /) -> list[_ItemT_co]: ...
+    def __len__(self) -> int: ...
+    def copy(self) -> list[_ItemT_co]:
+        """Returns a mutable copy of the list."""
+
 @final
 class PydanticUndefinedType:
     def __copy__(self) -> Self: ...
@@ -884,11 +2211,17 @@ def list_all_errors() -> list[ErrorTypeInfo]:
     """
 @final
 class TzInfo(datetime.tzinfo):
+    utc: ClassVar[TzInfo]
+    """
+    The UTC `TzInfo`, used for every datetime and time validated with a zero offset.
+    """
+
     def tzname(self, _dt: datetime.datetime | None) -> str | None: ...
     def utcoffset(self, _dt: datetime.datetime | None) -> datetime.timedelta: ...
     def dst(self, _dt: datetime.datetime | None) -> datetime.timedelta: ...
     def fromutc(self, dt: datetime.datetime) -> datetime.datetime: ...
-    def __deepcopy__(self, _memo: dict[Any, Any]) -> TzInfo: ...
+    def __copy__(self) -> Self: ...
+    def __deepcopy__(self, _memo: dict[Any, Any]) -> Self: ...
 
 def validate_core_schema(schema: CoreSchema, *, strict: bool | None = None) -> CoreSchema:
     """Validate a CoreSchema
@@ -10,6 +10,7 @@ import warnings
 from collections.abc import Mapping
 from datetime import date, datetime, time, timedelta
 from decimal import Decimal
+from random import Random
 from typing import TYPE_CHECKING, Any, Callable, Dict, Hashable, List, Pattern, Set, Tuple, Type, Union
 
 from typing_extensions import deprecated
@@ -62,7 +63,11 @@ class CoreConfig(TypedDict, total=False):
             as well as the alias. (Replaces 'allow_population_by_field_name' in Pydantic v1.) Default is `False`.
         str_max_length: The maximum length for string fields.
         str_min_length: The minimum length for string fields.
+        str_length_unit: How the length of string fields is measured, see `str_schema`. Default is 'chars'.
         str_strip_whitespace: Whether to strip whitespace from string fields.
+        str_strip_chars: Characters to strip from both ends of string fields.
+        str_unicode_normalization: The unicode normalization form to apply to string fields, 'NFC' or 'NFKC'.
+        str_casefold: Whether to casefold string fields, for caseless comparison.
         str_to_lower: Whether to convert string fields to lowercase.
         str_to_upper: Whether to convert string fields to uppercase.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
@@ -70,6 +75,20 @@ class CoreConfig(TypedDict, total=False):
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
             in float fields. Default is 'null'.
+        val_json_inf_nan: With 'strings', float fields validated from JSON accept the `"Infinity"`, `"-Infinity"` and
+            `"NaN"` strings written by `ser_json_inf_nan='strings'`, even in strict mode. Default is 'constants'.
+        ser_json_decimal: How `Decimal` values are serialized to JSON, 'str' writes a string, 'number' writes the
+            exact digits as a JSON number, 'float' converts to a float first. Default is 'str'.
+        ser_json_release_gil_every: If set, JSON serialization briefly releases the GIL when starting a list,
+            tuple, set or dict once this many bytes have been written since it was last released, so other threads
+            aren't starved while large documents are serialized. Default is `None`, the GIL is held throughout.
+        ser_json_tuple_keys: How tuple dict keys are serialized to JSON, 'join' joins the items' keys with
+            `ser_json_tuple_key_separator`, 'json' writes the items as a JSON array. Dict keys validated with a tuple
+            schema are parsed from the same format when validating JSON or strings. Default is 'join'.
+        ser_json_tuple_key_separator: The separator between items of tuple dict keys with
+            `ser_json_tuple_keys='join'`. Default is ','.
+        ser_json_namedtuple: Whether named tuples are serialized to JSON as an 'array' of their items or an
+            'object' keyed by field name. Default is 'array'.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
             Requires exceptiongroup backport pre Python 3.11.
@@ -77,6 +96,39 @@ class CoreConfig(TypedDict, total=False):
         regex_engine: The regex engine to use for regex pattern validation. Default is 'rust-regex'. See `StringSchema`.
         cache_strings: Whether to cache strings. Default is `True`, `True` or `'all'` is required to cache strings
             during general validation since validators don't know if they're in a key or a value.
+        val_json_cache_utf8: Whether to keep the UTF-8 encoding of strings validated from JSON on the `str` objects,
+            so that serializing them again (e.g. validate then dump) doesn't need to re-encode them. Default is `False`.
+        intern_strings: Whether equal strings output by `str` validators during one validation call should share a
+            single `str` object, reducing memory when validating large inputs with many repeated values (e.g. country
+            codes). Default is `False`.
+        catch_panics: Whether an internal panic during validation or serialization should be raised as a
+            `PydanticInternalError`, rather than aborting the call with a `PanicException`. Default is `False`.
+        numpy_support: Whether numpy scalars, e.g. `numpy.int64`, are serialized and validated in lax mode by `int`,
+            `float` and `bool` fields as the Python value they hold, and numpy arrays are serialized to JSON as lists.
+            numpy is only imported when this is set. Default is `False`.
+        config_merge: How the config of a model, dataclass or typed dict combines with the config of the schema
+            containing it. `'replace'` ignores the parent config, `'merge'` overrides the parent config with
+            the values set here. Default is `'replace'`.
+        clock: The current time for constraints relative to now, e.g. `now_op` of datetimes and dates, as a timezone
+            aware datetime or a function returning one, so validation is deterministic in tests and when replaying
+            events. Only used in the config of the top level schema. Default is the system clock.
+        rng: The random number generator passed to default factories with `default_factory_takes_rng`, either an
+            `int` seed for a new `random.Random` each validation, so results are reproducible, or a generator which
+            is used as is. Only used in the config of the top level schema. Default is a new unseeded `random.Random`.
+        field_failure_stats: Whether to count validation errors by location and error type, returned as
+            `field_failures` by [`SchemaValidator.stats()`][pydantic_core.SchemaValidator.stats]. Only used in the
+            config of the top level schema. Default is `False`.
+        max_python_callbacks: The most function validators, function serializers, call schemas and default factories
+            a single validation or serialization call may call, more raise `PythonCallbackLimitExceeded`, which
+            contains recursive schemas amplifying the cost of Python callbacks. Only used in the config of the top
+            level schema. Default is no limit.
+        alias_generator: Generates the validation and serialization alias of fields, and the alias of computed fields,
+            which don't have one when the schema is built, `'to_camel'` turns `user_id` into `userId`, `'to_pascal'`
+            into `UserId` and `'to_kebab'` into `user-id`. Default is no generated aliases.
+        extras_destination: Where models and dataclasses with `extra_fields_behavior='allow'` keep extras,
+            `'__pydantic_extra__'`, `'merge'` to add them to `__dict__` with the fields, or the name of another
+            attribute to store a dict of them in, serializers read extras from the same place. Default is
+            `'__pydantic_extra__'` for models and `'merge'` for dataclasses, whose extras then aren't serialized.
     """
 
     title: str
@@ -98,7 +150,11 @@ class CoreConfig(TypedDict, total=False):
     # fields related to string fields only
     str_max_length: int
     str_min_length: int
+    str_length_unit: Literal['chars', 'codepoints', 'bytes', 'graphemes', 'utf16', 'display-width']  # default: 'chars'
     str_strip_whitespace: bool
+    str_strip_chars: str
+    str_unicode_normalization: Literal['NFC', 'NFKC']
+    str_casefold: bool
     str_to_lower: bool
     str_to_upper: bool
     # fields related to float fields only
@@ -107,12 +163,30 @@ class CoreConfig(TypedDict, total=False):
     ser_json_timedelta: Literal['iso8601', 'float']  # default: 'iso8601'
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     ser_json_inf_nan: Literal['null', 'constants', 'strings']  # default: 'null'
+    val_json_inf_nan: Literal['constants', 'strings']  # default: 'constants'
+    ser_json_decimal: Literal['str', 'number', 'float']  # default: 'str'
+    ser_json_release_gil_every: int
+    ser_json_tuple_keys: Literal['join', 'json']  # default: 'join'
+    ser_json_tuple_key_separator: str  # default: ','
+    ser_json_namedtuple: Literal['array', 'object']  # default: 'array'
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
     validation_error_cause: bool  # default: False
     coerce_numbers_to_str: bool  # default: False
-    regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    regex_engine: Literal['rust-regex', 'python-re', 're2']  # default: 'rust-regex'
     cache_strings: Union[bool, Literal['all', 'keys', 'none']]  # default: 'True'
+    val_json_cache_utf8: bool  # default: False
+    intern_strings: bool  # default: False
+    catch_panics: bool  # default: False
+    numpy_support: bool  # default: False
+    # how the config of models, dataclasses and typed dicts combines with the parent config
+    config_merge: Literal['replace', 'merge']  # default: 'replace'
+    clock: Union[datetime, Callable[[], datetime]]
+    rng: Any  # an int seed or a generator, e.g. `random.Random`
+    field_failure_stats: bool  # default: False
+    max_python_callbacks: int
+    alias_generator: Literal['to_camel', 'to_pascal', 'to_kebab']
+    extras_destination: str  # '__pydantic_extra__', 'merge' or an attribute name
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -194,6 +268,26 @@ class ValidationInfo(Protocol):
         """
         ...
 
+    @property
+    def path(self) -> Tuple[Union[str, int], ...]:
+        """
+        The location of the value being validated, made up of the field names, item indexes and
+        dict keys leading to it, e.g. `('items', 2)` for the third item of the `items` field.
+        """
+        ...
+
+    def warn(self, message: str, category: type[Warning] | None = None) -> None:
+        """
+        Emit a warning once validation succeeds, warnings are discarded if validation fails.
+
+        Identical warnings emitted during the same validation are only emitted once.
+
+        Args:
+            message: The warning message.
+            category: The warning category, `UserWarning` by default.
+        """
+        ...
+
 
 ExpectedSerializationTypes = Literal[
     'none',
@@ -459,12 +553,19 @@ def computed_field(
 
 class AnySchema(TypedDict, total=False):
     type: Required[Literal['any']]
+    type_markers: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
-def any_schema(*, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None) -> AnySchema:
+def any_schema(
+    *,
+    type_markers: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> AnySchema:
     """
     Returns a schema that matches any value, e.g.:
 
@@ -476,12 +577,37 @@ def any_schema(*, ref: str | None = None, metadata: Any = None, serialization: S
     assert v.validate_python(1) == 1
     ```
 
+    With `type_markers`, values JSON can't represent are serialized to JSON as `{"__type__": ..., "value": ...}`
+    markers which validation restores, so heterogeneous values round-trip without loss:
+
+    ```py
+    from datetime import date
+
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.any_schema(type_markers=True)
+    value = {'when': date(2024, 1, 1), 'tags': {'a'}, 'point': (1, 2)}
+    json_data = SchemaSerializer(schema).to_json(value)
+    assert json_data == (
+        b'{"when":{"__type__":"date","value":"2024-01-01"},'
+        b'"tags":{"__type__":"set","value":["a"]},'
+        b'"point":{"__type__":"tuple","value":[1,2]}}'
+    )
+    assert SchemaValidator(schema).validate_json(json_data) == value
+    ```
+
+    Markers are used for `datetime`, `date`, `time`, `timedelta`, `UUID`, `Decimal`, `bytes`, `bytearray`,
+    `tuple`, `set`, `frozenset` and dicts with non-`str` keys, other types are serialized as usual.
+
     Args:
+        type_markers: Whether to use type markers when serializing to JSON and restore them when validating
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='any', ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='any', type_markers=type_markers, ref=ref, metadata=metadata, serialization=serialization
+    )
 
 
 class NoneSchema(TypedDict, total=False):
@@ -612,6 +738,8 @@ class FloatSchema(TypedDict, total=False):
     lt: float
     gt: float
     strict: bool
+    ser_json_inf_nan: Literal['null', 'constants', 'strings']
+    val_json_inf_nan: Literal['constants', 'strings']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -626,6 +754,8 @@ def float_schema(
     lt: float | None = None,
     gt: float | None = None,
     strict: bool | None = None,
+    ser_json_inf_nan: Literal['null', 'constants', 'strings'] | None = None,
+    val_json_inf_nan: Literal['constants', 'strings'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -649,6 +779,9 @@ def float_schema(
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a float or a value that can be converted to a float
+        ser_json_inf_nan: Overrides the `ser_json_inf_nan` config for this field
+        val_json_inf_nan: Overrides the `val_json_inf_nan` config for this field, with 'strings' the `"Infinity"`,
+            `"-Infinity"` and `"NaN"` strings are accepted from JSON even in strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -662,12 +795,26 @@ def float_schema(
         lt=lt,
         gt=gt,
         strict=strict,
+        ser_json_inf_nan=ser_json_inf_nan,
+        val_json_inf_nan=val_json_inf_nan,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
     )
 
 
+DecimalRounding = Literal[
+    'ROUND_UP',
+    'ROUND_DOWN',
+    'ROUND_CEILING',
+    'ROUND_FLOOR',
+    'ROUND_HALF_UP',
+    'ROUND_HALF_DOWN',
+    'ROUND_HALF_EVEN',
+    'ROUND_05UP',
+]
+
+
 class DecimalSchema(TypedDict, total=False):
     type: Required[Literal['decimal']]
     allow_inf_nan: bool  # whether 'NaN', '+inf', '-inf' should be forbidden. default: False
@@ -678,7 +825,11 @@ class DecimalSchema(TypedDict, total=False):
     gt: Decimal
     max_digits: int
     decimal_places: int
+    rounding: DecimalRounding
+    quantize_to: Decimal
     strict: bool
+    ser_json_decimal: Literal['str', 'number', 'float']
+    ser_json_inf_nan: Literal['null', 'constants', 'strings']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -694,7 +845,11 @@ def decimal_schema(
     gt: Decimal | None = None,
     max_digits: int | None = None,
     decimal_places: int | None = None,
+    rounding: DecimalRounding | None = None,
+    quantize_to: Decimal | None = None,
     strict: bool | None = None,
+    ser_json_decimal: Literal['str', 'number', 'float'] | None = None,
+    ser_json_inf_nan: Literal['null', 'constants', 'strings'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -720,7 +875,15 @@ def decimal_schema(
         gt: The value must be strictly greater than this number
         max_digits: The maximum number of decimal digits allowed
         decimal_places: The maximum number of decimal places allowed
+        rounding: If set, values with more than `decimal_places` decimal places are rounded with this rounding
+            mode from the `decimal` module instead of being rejected
+        quantize_to: Round values to the exponent of this decimal, e.g. `Decimal('0.01')` for cents,
+            using `rounding` which defaults to 'ROUND_HALF_EVEN'
         strict: Whether the value should be a float or a value that can be converted to a float
+        ser_json_decimal: Overrides the `ser_json_decimal` config for this field, with 'number' the digits are
+            written as they are by `str()`, so values like `Decimal('1E+3')` use an exponent
+        ser_json_inf_nan: Overrides the `ser_json_inf_nan` config for this field, the `"Infinity"`, `"-Infinity"` and
+            `"NaN"` strings and the JSON constants are both validated as decimals
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -733,9 +896,70 @@ def decimal_schema(
         le=le,
         max_digits=max_digits,
         decimal_places=decimal_places,
+        rounding=rounding,
+        quantize_to=quantize_to,
         multiple_of=multiple_of,
         allow_inf_nan=allow_inf_nan,
         strict=strict,
+        ser_json_decimal=ser_json_decimal,
+        ser_json_inf_nan=ser_json_inf_nan,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class MoneySchema(TypedDict, total=False):
+    type: Required[Literal['money']]
+    currencies: List[str]
+    rounding: DecimalRounding
+    money_serialization: Literal['object', 'str']  # default: 'object'
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def money_schema(
+    *,
+    currencies: list[str] | None = None,
+    rounding: DecimalRounding | None = None,
+    money_serialization: Literal['object', 'str'] | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> MoneySchema:
+    """
+    Returns a schema that matches an amount of money in an ISO 4217 currency, either a dict with `amount` and
+    `currency` keys or a string like `'12.34 USD'`, e.g.:
+
+    ```py
+    from decimal import Decimal
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.money_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('12.3 usd') == {'amount': Decimal('12.30'), 'currency': 'USD'}
+    assert v.validate_python({'amount': 500, 'currency': 'JPY'}) == {'amount': Decimal('500'), 'currency': 'JPY'}
+    ```
+
+    The amount is validated like a decimal, it may not have more decimal places than the currency's minor unit,
+    and is quantized to exactly that many places.
+
+    Args:
+        currencies: If set, only these currency codes are allowed
+        rounding: If set, amounts with more decimal places than the currency allows are rounded with this rounding
+            mode from the `decimal` module instead of being rejected
+        money_serialization: Whether to serialize to a dict with `amount` and `currency`, where the amount is a
+            string in JSON, or to a string like `'12.30 USD'`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='money',
+        currencies=currencies,
+        rounding=rounding,
+        money_serialization=money_serialization,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -747,10 +971,14 @@ class StringSchema(TypedDict, total=False):
     pattern: Union[str, Pattern[str]]
     max_length: int
     min_length: int
+    length_unit: Literal['chars', 'codepoints', 'bytes', 'graphemes', 'utf16', 'display-width']  # default: 'chars'
     strip_whitespace: bool
+    strip_chars: str
+    unicode_normalization: Literal['NFC', 'NFKC']
+    casefold: bool
     to_lower: bool
     to_upper: bool
-    regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    regex_engine: Literal['rust-regex', 'python-re', 're2']  # default: 'rust-regex'
     strict: bool
     coerce_numbers_to_str: bool
     ref: str
@@ -763,10 +991,14 @@ def str_schema(
     pattern: str | Pattern[str] | None = None,
     max_length: int | None = None,
     min_length: int | None = None,
+    length_unit: Literal['chars', 'codepoints', 'bytes', 'graphemes', 'utf16', 'display-width'] | None = None,
     strip_whitespace: bool | None = None,
+    strip_chars: str | None = None,
+    unicode_normalization: Literal['NFC', 'NFKC'] | None = None,
+    casefold: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
-    regex_engine: Literal['rust-regex', 'python-re'] | None = None,
+    regex_engine: Literal['rust-regex', 'python-re', 're2'] | None = None,
     strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
     ref: str | None = None,
@@ -784,11 +1016,35 @@ def str_schema(
     assert v.validate_python('hello') == 'hello'
     ```
 
+    Normalization, case folding and stripping are applied in that order, before the length and pattern
+    constraints are checked:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.str_schema(unicode_normalization='NFKC', casefold=True, strip_chars='_')
+    v = SchemaValidator(schema)
+    assert v.validate_python('__Ｓｍｉｔｈ__') == 'smith'
+    ```
+
     Args:
         pattern: A regex pattern that the value must match
         max_length: The value must be at most this length
         min_length: The value must be at least this length
+        length_unit: How the length is measured for `max_length` and `min_length`. Default is 'chars'.
+            - `chars`, or `codepoints`, counts unicode code points, like `len()`
+            - `bytes` counts UTF-8 bytes, e.g. for database columns with byte limits like MySQL `utf8mb4`
+            - `graphemes` counts extended grapheme clusters, i.e. characters as displayed
+            - `utf16` counts UTF-16 code units, as JavaScript's `String.length` does
+            - `display-width` counts the columns taken in a terminal, where wide East Asian characters and emoji
+                take two and combining characters none
+
+            Length errors in any unit but `chars` have the unit in their context, e.g.
+            `{'max_length': 4, 'unit': 'bytes'}`, and their message, e.g. "String should have at most 4 bytes".
         strip_whitespace: Whether to strip whitespace from the value
+        strip_chars: Characters to strip from both ends of the value, like `str.strip(chars)`
+        unicode_normalization: The unicode normalization form to apply to the value, 'NFC' or 'NFKC'
+        casefold: Whether to casefold the value like `str.casefold()`, for caseless comparison
         to_lower: Whether to convert the value to lowercase
         to_upper: Whether to convert the value to uppercase
         regex_engine: The regex engine to use for pattern validation. Default is 'rust-regex'.
@@ -797,6 +1053,9 @@ def str_schema(
               resistant, but does not support all regex features.
             - `python-re` use the [`re`](https://docs.python.org/3/library/re.html) module,
               which supports all regex features, but may be slower.
+            - `re2` uses the [`re2`](https://pypi.org/project/google-re2/) module, which is linear time
+              like `rust-regex` but requires the `google-re2` package.
+            Compiled patterns are cached and shared between validators, so repeated patterns are only compiled once.
         strict: Whether the value should be a string or a value that can be converted to a string
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         ref: optional unique identifier of the schema, used to reference the schema in other places
@@ -808,7 +1067,11 @@ def str_schema(
         pattern=pattern,
         max_length=max_length,
         min_length=min_length,
+        length_unit=length_unit,
         strip_whitespace=strip_whitespace,
+        strip_chars=strip_chars,
+        unicode_normalization=unicode_normalization,
+        casefold=casefold,
         to_lower=to_lower,
         to_upper=to_upper,
         regex_engine=regex_engine,
@@ -820,6 +1083,62 @@ def str_schema(
     )
 
 
+class StringFormatSchema(TypedDict, total=False):
+    type: Required[Literal['string-format']]
+    format: Required[str]
+    allow_unknown: bool  # default False
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def string_format_schema(
+    format: str,
+    *,
+    allow_unknown: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> StringFormatSchema:
+    """
+    Returns a schema that matches a string in a JSON Schema `format`, the validated value is the string unchanged, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.string_format_schema('ipv4')
+    v = SchemaValidator(schema)
+    print(v.validate_python('192.168.0.1'))
+    #> 192.168.0.1
+    ```
+
+    The formats `date-time`, `date`, `time`, `duration`, `uri`, `uri-reference`, `hostname`, `idn-hostname`, `ipv4`,
+    `ipv6`, `uuid` and `regex` are checked by pydantic-core, the errors of most are those of the equivalent schema,
+    e.g. `url_parsing` for `uri`. Other formats can be added with
+    [`register_string_format`][pydantic_core.register_string_format].
+
+    Args:
+        format: The name of the format
+        allow_unknown: Whether to accept any string for formats which aren't known, rather than raising a
+            `SchemaError`, as JSON Schema treats unknown formats as annotations
+        strict: Whether the input should be a string
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='string-format',
+        format=format,
+        allow_unknown=allow_unknown,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 class BytesSchema(TypedDict, total=False):
     type: Required[Literal['bytes']]
     max_length: int
@@ -1146,13 +1465,19 @@ def timedelta_schema(
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[List[Any]]
+    case_insensitive: bool  # default: False
     ref: str
     metadata: Any
     serialization: SerSchema
 
 
 def literal_schema(
-    expected: list[Any], *, ref: str | None = None, metadata: Any = None, serialization: SerSchema | None = None
+    expected: list[Any],
+    *,
+    case_insensitive: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
 ) -> LiteralSchema:
     """
     Returns a schema that matches a literal value, e.g.:
@@ -1167,11 +1492,20 @@ def literal_schema(
 
     Args:
         expected: The value must be one of these values
+        case_insensitive: Whether strings which only differ from an expected string by case are accepted,
+            the expected string is returned
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
     """
-    return _dict_not_none(type='literal', expected=expected, ref=ref, metadata=metadata, serialization=serialization)
+    return _dict_not_none(
+        type='literal',
+        expected=expected,
+        case_insensitive=case_insensitive,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
 
 
 class EnumSchema(TypedDict, total=False):
@@ -1179,6 +1513,9 @@ class EnumSchema(TypedDict, total=False):
     cls: Required[Any]
     members: Required[List[Any]]
     sub_type: Literal['str', 'int', 'float']
+    by: Literal['value', 'name', 'any']  # default: 'value'
+    case_insensitive_names: bool  # default: False
+    flag_serialization: Literal['int', 'list']  # default: 'int'
     missing: Callable[[Any], Any]
     strict: bool
     ref: str
@@ -1191,6 +1528,9 @@ def enum_schema(
     members: list[Any],
     *,
     sub_type: Literal['str', 'int', 'float'] | None = None,
+    by: Literal['value', 'name', 'any'] | None = None,
+    case_insensitive_names: bool | None = None,
+    flag_serialization: Literal['int', 'list'] | None = None,
     missing: Callable[[Any], Any] | None = None,
     strict: bool | None = None,
     ref: str | None = None,
@@ -1218,6 +1558,12 @@ def enum_schema(
         cls: The enum class
         members: The members of the enum, generally `list(MyEnum.__members__.values())`
         sub_type: The type of the enum, either 'str' or 'int' or None for plain enums
+        by: Whether members are looked up by `'value'`, by `'name'`, or by value then by name with `'any'`,
+            defaults to `'value'`. With `'name'`, members are also serialized to JSON by name
+        case_insensitive_names: Whether names which only differ from a member's name by case are accepted
+        flag_serialization: For `enum.Flag` subclasses, whether members are serialized to JSON as their int value
+            (the default) or as a list of the names of the flags set. Flags also accept ints which are
+            combinations of members, lists of member names and member names separated by `|`
         missing: A function to use when the value is not found in the enum, from `_missing_`
         strict: Whether to use strict mode, defaults to False
         ref: optional unique identifier of the schema, used to reference the schema in other places
@@ -1229,6 +1575,9 @@ def enum_schema(
         cls=cls,
         members=members,
         sub_type=sub_type,
+        by=by,
+        case_insensitive_names=case_insensitive_names,
+        flag_serialization=flag_serialization,
         missing=missing,
         strict=strict,
         ref=ref,
@@ -1363,6 +1712,7 @@ class UuidSchema(TypedDict, total=False):
     type: Required[Literal['uuid']]
     version: Literal[1, 3, 4, 5]
     strict: bool
+    output_format: Literal['canonical', 'hex', 'urn', 'braced', 'bytes']
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -1372,12 +1722,141 @@ def uuid_schema(
     *,
     version: Literal[1, 3, 4, 5] | None = None,
     strict: bool | None = None,
+    output_format: Literal['canonical', 'hex', 'urn', 'braced', 'bytes'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
 ) -> UuidSchema:
+    """
+    Returns a schema that matches a UUID value, e.g.:
+
+    ```py
+    from uuid import UUID
+    from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+    schema = core_schema.uuid_schema(version=4, output_format='hex')
+    v = SchemaValidator(schema)
+    value = v.validate_python('{12345678-1234-4678-9234-567812345678}')
+    assert value == UUID('12345678-1234-4678-9234-567812345678')
+    assert SchemaSerializer(schema).to_python(value) == '12345678123446789234567812345678'
+    ```
+
+    In lax mode UUIDs may be given as strings in the canonical, hex, URN or braced forms, or as 16 bytes.
+
+    Args:
+        version: The UUID version the value must have
+        strict: Whether the value should be a `uuid.UUID` instance in python
+        output_format: The format to serialize the UUID in, `'bytes'` gives the 16 raw bytes, which are encoded
+            according to `ser_json_bytes` in JSON; when set the format is used in python mode too,
+            by default UUIDs are left as they are in python mode and use the canonical form in JSON
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='uuid',
+        version=version,
+        strict=strict,
+        output_format=output_format,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class UlidSchema(TypedDict, total=False):
+    type: Required[Literal['ulid']]
+    as_uuid: bool  # default: False
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ulid_schema(
+    *,
+    as_uuid: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> UlidSchema:
+    """
+    Returns a schema that matches a [ULID](https://github.com/ulid/spec), e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ulid_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('01arz3ndektsv4rrffq69g5fav') == '01ARZ3NDEKTSV4RRFFQ69G5FAV'
+    ```
+
+    ULIDs are returned in their canonical uppercase form, in lax mode lowercase strings, 16 bytes
+    and `uuid.UUID` instances with the same 128 bits are also accepted.
+
+    Args:
+        as_uuid: Whether to return a `uuid.UUID` with the same 128 bits rather than the ULID string,
+            it's serialized as a ULID string in JSON
+        strict: Whether the value should be an uppercase ULID string
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ulid', as_uuid=as_uuid, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class NativeSchema(TypedDict, total=False):
+    type: Required[Literal['native']]
+    capsule: Required[Any]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def native_schema(
+    capsule: Any,
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> NativeSchema:
+    """
+    Returns a schema for a type implemented by an extension module, which validates and serializes values
+    without calling Python functions, extension modules usually provide a function returning this schema.
+
+    `capsule` is a `PyCapsule` named `pydantic_core.native_type` containing a pointer to this C struct,
+    which is exported from the `pydantic_core` Rust crate as `NativeTypeVTable`:
+
+    ```c
+    typedef PyObject *(*native_type_fn)(void *data, PyObject *value, int flag);
+
+    struct native_type_vtable {
+        uint32_t abi_version;  // must be 1
+        const char *name;  // used in validator names and errors, or NULL
+        void *data;  // passed to each function
+        native_type_fn validate;  // `flag` is 1 in strict mode
+        native_type_fn serialize;  // `flag` is 1 when serializing to JSON, or NULL to serialize by type inference
+    };
+    ```
+
+    Functions are called with the GIL held and return a new reference, or NULL with an exception set,
+    `ValueError`, `AssertionError` and `PydanticCustomError` raised by `validate` become validation errors.
+    Values returned by `serialize` are serialized by type inference. The struct must live as long as the capsule.
+
+    Args:
+        capsule: The capsule containing the vtable of the native type
+        strict: Whether `validate` is called in strict mode
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
     return _dict_not_none(
-        type='uuid', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+        type='native', capsule=capsule, strict=strict, ref=ref, metadata=metadata, serialization=serialization
     )
 
 
@@ -1400,7 +1879,11 @@ class ListSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    max_error_items: int
     strict: bool
+    read_only: bool  # default: False
+    return_input_if_valid: bool  # default: False
+    output: Literal['list', 'generator']  # default: 'list'
     ref: str
     metadata: Any
     serialization: IncExSeqOrElseSerSchema
@@ -1412,7 +1895,11 @@ def list_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    max_error_items: int | None = None,
     strict: bool | None = None,
+    read_only: bool | None = None,
+    return_input_if_valid: bool | None = None,
+    output: Literal['list', 'generator'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: IncExSeqOrElseSerSchema | None = None,
@@ -1433,7 +1920,14 @@ def list_schema(
         min_length: The value must be a list with at least this many items
         max_length: The value must be a list with at most this many items
         fail_fast: Stop validation on the first error
+        max_error_items: Stop validation after this many invalid items, rather than collecting an error for
+            every invalid item, `fail_fast` is the same as `max_error_items=1`
         strict: The value must be a list with exactly this many items
+        read_only: Whether to return a read-only `ReadOnlyList` view of the validated list
+        return_input_if_valid: In strict mode, whether to return the input list itself rather than a copy
+            if validation didn't change any items
+        output: With `'generator'`, return an iterator which validates each item as it's iterated rather than a
+            list, e.g. to validate very large JSON arrays lazily, the value is serialized like a generator
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1444,7 +1938,11 @@ def list_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        max_error_items=max_error_items,
         strict=strict,
+        read_only=read_only,
+        return_input_if_valid=return_input_if_valid,
+        output=output,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1552,6 +2050,7 @@ class TupleSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    max_error_items: int
     strict: bool
     ref: str
     metadata: Any
@@ -1565,6 +2064,7 @@ def tuple_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    max_error_items: int | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1590,6 +2090,8 @@ def tuple_schema(
         min_length: The value must be a tuple with at least this many items
         max_length: The value must be a tuple with at most this many items
         fail_fast: Stop validation on the first error
+        max_error_items: Stop validation after this many invalid items, rather than collecting an error for
+            every invalid item, `fail_fast` is the same as `max_error_items=1`
         strict: The value must be a tuple with exactly this many items
         ref: Optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1602,6 +2104,7 @@ def tuple_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        max_error_items=max_error_items,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1615,6 +2118,8 @@ class SetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    max_error_items: int
+    ordered: bool  # default: False
     strict: bool
     ref: str
     metadata: Any
@@ -1627,6 +2132,8 @@ def set_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    max_error_items: int | None = None,
+    ordered: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1650,6 +2157,10 @@ def set_schema(
         min_length: The value must be a set with at least this many items
         max_length: The value must be a set with at most this many items
         fail_fast: Stop validation on the first error
+        max_error_items: Stop validation after this many invalid items, rather than collecting an error for
+            every invalid item, `fail_fast` is the same as `max_error_items=1`
+        ordered: Whether to return a list of the unique items in the order they're first seen rather than a
+            set, e.g. for deterministic serialization
         strict: The value must be a set with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1661,6 +2172,8 @@ def set_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        max_error_items=max_error_items,
+        ordered=ordered,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1674,6 +2187,8 @@ class FrozenSetSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     fail_fast: bool
+    max_error_items: int
+    ordered: bool  # default: False
     strict: bool
     ref: str
     metadata: Any
@@ -1686,6 +2201,8 @@ def frozenset_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     fail_fast: bool | None = None,
+    max_error_items: int | None = None,
+    ordered: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -1709,6 +2226,10 @@ def frozenset_schema(
         min_length: The value must be a frozenset with at least this many items
         max_length: The value must be a frozenset with at most this many items
         fail_fast: Stop validation on the first error
+        max_error_items: Stop validation after this many invalid items, rather than collecting an error for
+            every invalid item, `fail_fast` is the same as `max_error_items=1`
+        ordered: Whether to return a tuple of the unique items in the order they're first seen rather than a
+            frozenset, e.g. for deterministic serialization
         strict: The value must be a frozenset with exactly this many items
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -1720,6 +2241,8 @@ def frozenset_schema(
         min_length=min_length,
         max_length=max_length,
         fail_fast=fail_fast,
+        max_error_items=max_error_items,
+        ordered=ordered,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -1807,6 +2330,10 @@ class DictSchema(TypedDict, total=False):
     min_length: int
     max_length: int
     strict: bool
+    exhaustive: bool  # default: False
+    read_only: bool  # default: False
+    return_input_if_valid: bool  # default: False
+    key_serialization: CoreSchema
     ref: str
     metadata: Any
     serialization: IncExDictOrElseSerSchema
@@ -1819,6 +2346,10 @@ def dict_schema(
     min_length: int | None = None,
     max_length: int | None = None,
     strict: bool | None = None,
+    exhaustive: bool | None = None,
+    read_only: bool | None = None,
+    return_input_if_valid: bool | None = None,
+    key_serialization: CoreSchema | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -1842,6 +2373,13 @@ def dict_schema(
         min_length: The value must be a dict with at least this many items
         max_length: The value must be a dict with at most this many items
         strict: Whether the keys and values should be validated with strict mode
+        exhaustive: Whether the dict must contain every expected value of `keys_schema`, which must be a literal
+            schema, a `missing` error is raised for each absent key
+        read_only: Whether to return a read-only `ReadOnlyDict` view of the validated dict
+        return_input_if_valid: In strict mode, whether to return the input dict itself rather than a copy
+            if validation didn't change any keys or values
+        key_serialization: The schema used to serialize keys instead of `keys_schema`, e.g. to serialize
+            enum, tuple or dataclass keys to strings with a function serializer
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1853,6 +2391,10 @@ def dict_schema(
         min_length=min_length,
         max_length=max_length,
         strict=strict,
+        exhaustive=exhaustive,
+        read_only=read_only,
+        return_input_if_valid=return_input_if_valid,
+        key_serialization=key_serialization,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1986,12 +2528,16 @@ def with_info_before_validator_function(
 
 class AfterValidatorFunctionSchema(_ValidatorFunctionSchema, total=False):
     type: Required[Literal['function-after']]
+    cache: Literal['by_value', 'by_hash']
+    cache_size: int  # default: 1024
 
 
 def no_info_after_validator_function(
     function: NoInfoValidatorFunction,
     schema: CoreSchema,
     *,
+    cache: Literal['by_value', 'by_hash'] | None = None,
+    cache_size: int | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2015,6 +2561,10 @@ def no_info_after_validator_function(
     Args:
         function: The validator function to call after the schema is validated
         schema: The schema to validate before the validator function
+        cache: Cache the function's output for each input, `'by_value'` reuses it for inputs of the same type
+            which are equal, `'by_hash'` for inputs with the same hash, unhashable inputs aren't cached; cached
+            outputs aren't copied, so the function should return immutable values
+        cache_size: The maximum number of outputs to cache, the least recently used is evicted, defaults to 1024
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2023,6 +2573,8 @@ def no_info_after_validator_function(
         type='function-after',
         function={'type': 'no-info', 'function': function},
         schema=schema,
+        cache=cache,
+        cache_size=cache_size,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2211,6 +2763,8 @@ def with_info_wrap_validator_function(
 class PlainValidatorFunctionSchema(TypedDict, total=False):
     type: Required[Literal['function-plain']]
     function: Required[ValidationFunction]
+    cache: Literal['by_value', 'by_hash']
+    cache_size: int  # default: 1024
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2219,6 +2773,8 @@ class PlainValidatorFunctionSchema(TypedDict, total=False):
 def no_info_plain_validator_function(
     function: NoInfoValidatorFunction,
     *,
+    cache: Literal['by_value', 'by_hash'] | None = None,
+    cache_size: int | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2240,6 +2796,10 @@ def no_info_plain_validator_function(
 
     Args:
         function: The validator function to call
+        cache: Cache the function's output for each input, `'by_value'` reuses it for inputs of the same type
+            which are equal, `'by_hash'` for inputs with the same hash, unhashable inputs aren't cached; cached
+            outputs aren't copied, so the function should return immutable values
+        cache_size: The maximum number of outputs to cache, the least recently used is evicted, defaults to 1024
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2247,6 +2807,8 @@ def no_info_plain_validator_function(
     return _dict_not_none(
         type='function-plain',
         function={'type': 'no-info', 'function': function},
+        cache=cache,
+        cache_size=cache_size,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2296,7 +2858,8 @@ class WithDefaultSchema(TypedDict, total=False):
     type: Required[Literal['default']]
     schema: Required[CoreSchema]
     default: Any
-    default_factory: Callable[[], Any]
+    default_factory: Union[Callable[[], Any], Callable[[Random], Any]]
+    default_factory_takes_rng: bool  # default: False
     on_error: Literal['raise', 'omit', 'default']  # default: 'raise'
     validate_default: bool  # default: False
     strict: bool
@@ -2309,7 +2872,8 @@ def with_default_schema(
     schema: CoreSchema,
     *,
     default: Any = PydanticUndefined,
-    default_factory: Callable[[], Any] | None = None,
+    default_factory: Callable[[], Any] | Callable[[Random], Any] | None = None,
+    default_factory_takes_rng: bool | None = None,
     on_error: Literal['raise', 'omit', 'default'] | None = None,
     validate_default: bool | None = None,
     strict: bool | None = None,
@@ -2335,6 +2899,9 @@ def with_default_schema(
         schema: The schema to add a default value to
         default: The default value to use
         default_factory: A function that returns the default value to use
+        default_factory_takes_rng: Whether `default_factory` is called with the random number generator for
+            validation, see [`CoreConfig.rng`][pydantic_core.core_schema.CoreConfig], e.g. to generate ids
+            which are reproducible in tests
         on_error: What to do if the schema validation fails. One of 'raise', 'omit', 'default'
         validate_default: Whether the default value should be validated
         strict: Whether the underlying schema should be validated with strict mode
@@ -2346,6 +2913,7 @@ def with_default_schema(
         type='default',
         schema=schema,
         default_factory=default_factory,
+        default_factory_takes_rng=default_factory_takes_rng,
         on_error=on_error,
         validate_default=validate_default,
         strict=strict,
@@ -2407,6 +2975,9 @@ class UnionSchema(TypedDict, total=False):
     custom_error_message: str
     custom_error_context: Dict[str, Union[str, int, float]]
     mode: Literal['smart', 'left_to_right']  # default: 'smart'
+    prefer_exact_type: bool  # default: True
+    prefer_leftmost_on_tie: bool  # default: True
+    adaptive_order: bool  # default: False
     strict: bool
     ref: str
     metadata: Any
@@ -2421,6 +2992,9 @@ def union_schema(
     custom_error_message: str | None = None,
     custom_error_context: dict[str, str | int] | None = None,
     mode: Literal['smart', 'left_to_right'] | None = None,
+    prefer_exact_type: bool | None = None,
+    prefer_leftmost_on_tie: bool | None = None,
+    adaptive_order: bool | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -2447,6 +3021,13 @@ def union_schema(
         mode: How to select which choice to return
             * `smart` (default) will try to return the choice which is the closest match to the input value
             * `left_to_right` will return the first choice in `choices` which succeeds validation
+        prefer_exact_type: In `smart` mode, whether a choice matching the input's exact type is preferred over
+            choices it's valid for in strict mode, default true. If false, exact and strict matches are equally good.
+        prefer_leftmost_on_tie: In `smart` mode, whether the leftmost of equally good choices is returned, default
+            true. If false, the rightmost is returned.
+        adaptive_order: In `smart` mode, whether choices are tried in order of how often they've been chosen,
+            recomputed periodically, default false. Which choice is returned doesn't change, in particular if more
+            than one choice matches the input's exact type the first of them in `choices` is returned.
         strict: Whether the underlying schemas should be validated with strict mode
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -2460,6 +3041,9 @@ def union_schema(
         custom_error_message=custom_error_message,
         custom_error_context=custom_error_context,
         mode=mode,
+        prefer_exact_type=prefer_exact_type,
+        prefer_leftmost_on_tie=prefer_leftmost_on_tie,
+        adaptive_order=adaptive_order,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -2467,6 +3051,88 @@ def union_schema(
     )
 
 
+class TaggedUnionRangeChoice(TypedDict, total=False):
+    schema: Required[CoreSchema]
+    ge: int
+    gt: int
+    le: int
+    lt: int
+
+
+def tagged_union_range_choice(
+    schema: CoreSchema,
+    *,
+    ge: int | None = None,
+    gt: int | None = None,
+    le: int | None = None,
+    lt: int | None = None,
+) -> TaggedUnionRangeChoice:
+    """
+    Returns a tagged union choice used for a range of int discriminator values, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    ok_schema = core_schema.typed_dict_schema({'status': core_schema.typed_dict_field(core_schema.int_schema())})
+    error_schema = core_schema.typed_dict_schema(
+        {
+            'status': core_schema.typed_dict_field(core_schema.int_schema()),
+            'error': core_schema.typed_dict_field(core_schema.str_schema()),
+        }
+    )
+    schema = core_schema.tagged_union_schema(
+        choices={},
+        discriminator='status',
+        range_choices=[
+            core_schema.tagged_union_range_choice(ok_schema, ge=200, lt=300),
+            core_schema.tagged_union_range_choice(error_schema, ge=400, lt=600),
+        ],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'status': 404, 'error': 'Not Found'}) == {'status': 404, 'error': 'Not Found'}
+    ```
+
+    Args:
+        schema: The schema to use for discriminator values in the range
+        ge: The discriminator value must be greater than or equal to this value
+        gt: The discriminator value must be greater than this value
+        le: The discriminator value must be less than or equal to this value
+        lt: The discriminator value must be less than this value
+    """
+    return _dict_not_none(schema=schema, ge=ge, gt=gt, le=le, lt=lt)
+
+
+class TaggedUnionPatternChoice(TypedDict, total=False):
+    pattern: Required[str]
+    schema: Required[CoreSchema]
+
+
+def tagged_union_pattern_choice(pattern: str, schema: CoreSchema) -> TaggedUnionPatternChoice:
+    """
+    Returns a tagged union choice used for str discriminator values matching a regex, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    v2_schema = core_schema.typed_dict_schema(
+        {'version': core_schema.typed_dict_field(core_schema.str_schema())}
+    )
+    schema = core_schema.tagged_union_schema(
+        choices={'legacy': core_schema.dict_schema()},
+        discriminator='version',
+        pattern_choices=[core_schema.tagged_union_pattern_choice(r'^v2\\.', v2_schema)],
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'version': 'v2.1'}) == {'version': 'v2.1'}
+    ```
+
+    Args:
+        pattern: A regex the discriminator value must match, anywhere in the value unless anchored
+        schema: The schema to use for matching discriminator values
+    """
+    return _dict_not_none(pattern=pattern, schema=schema)
+
+
 class TaggedUnionSchema(TypedDict, total=False):
     type: Required[Literal['tagged-union']]
     choices: Required[Dict[Hashable, CoreSchema]]
@@ -2476,6 +3142,9 @@ class TaggedUnionSchema(TypedDict, total=False):
     custom_error_context: Dict[str, Union[str, int, float]]
     strict: bool
     from_attributes: bool  # default: True
+    range_choices: List[TaggedUnionRangeChoice]
+    pattern_choices: List[TaggedUnionPatternChoice]
+    default_choice: Any
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2490,6 +3159,9 @@ def tagged_union_schema(
     custom_error_context: dict[str, int | str | float] | None = None,
     strict: bool | None = None,
     from_attributes: bool | None = None,
+    range_choices: list[TaggedUnionRangeChoice] | None = None,
+    pattern_choices: list[TaggedUnionPatternChoice] | None = None,
+    default_choice: Any = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2545,6 +3217,12 @@ def tagged_union_schema(
         custom_error_context: The custom error context to use if the validation fails
         strict: Whether the underlying schemas should be validated with strict mode
         from_attributes: Whether to use the attributes of the object to retrieve the discriminator value
+        range_choices: Schemas to use for int discriminator values which aren't keys in `choices`, each with bounds
+            (`ge`, `gt`, `le` and/or `lt`) on the values it's used for, e.g. HTTP status codes; ranges must not overlap
+        pattern_choices: Schemas to use for str discriminator values which aren't keys in `choices`, each with a
+            regex `pattern` the value must match, the first matching pattern is used
+        default_choice: The key in `choices` of the schema to use when the discriminator value is missing
+            or isn't one of the keys in `choices`, e.g. for API versions predating the discriminator
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -2558,6 +3236,9 @@ def tagged_union_schema(
         custom_error_context=custom_error_context,
         strict=strict,
         from_attributes=from_attributes,
+        range_choices=range_choices,
+        pattern_choices=pattern_choices,
+        default_choice=default_choice,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2723,13 +3404,20 @@ def json_or_python_schema(
     )
 
 
+# (field_name: str, data: dict[str, Any], context: Any, /) -> Any
+OnMissingFunction = Callable[[str, Dict[str, Any], Any], Any]
+
+
 class TypedDictField(TypedDict, total=False):
     type: Required[Literal['typed-dict-field']]
     schema: Required[CoreSchema]
     required: bool
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
-    serialization_alias: str
+    serialization_alias: Union[str, List[str]]
+    serialization_alias_profiles: Dict[str, Union[str, List[str]]]
     serialization_exclude: bool  # default: False
+    metric_label: bool  # default: False
+    on_missing: OnMissingFunction
     metadata: Any
 
 
@@ -2738,8 +3426,11 @@ def typed_dict_field(
     *,
     required: bool | None = None,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
-    serialization_alias: str | None = None,
+    serialization_alias: str | list[str] | None = None,
+    serialization_alias_profiles: dict[str, str | list[str]] | None = None,
     serialization_exclude: bool | None = None,
+    metric_label: bool | None = None,
+    on_missing: OnMissingFunction | None = None,
     metadata: Any = None,
 ) -> TypedDictField:
     """
@@ -2754,9 +3445,18 @@ def typed_dict_field(
     Args:
         schema: The schema to use for the field
         required: Whether the field is required
-        validation_alias: The alias(es) to use to find the field in the validation data
-        serialization_alias: The alias to use as a key when serializing
+        validation_alias: The alias(es) to use to find the field in the validation data, in alias paths ints
+            index lists from the end when negative and `'*'` gathers the rest of the path from each item into a list
+        serialization_alias: The alias to use as a key when serializing, if a list is provided the field is
+            serialized under each alias, and unless `validation_alias` is set, any of them is accepted when validating
+        serialization_alias_profiles: Aliases to use instead of `serialization_alias` when serializing with the
+            `alias_profile` they're keyed by, these aren't accepted when validating
         serialization_exclude: Whether to exclude the field when serializing
+        metric_label: Whether the field is included in
+            [`SchemaSerializer.to_metric_labels`][pydantic_core.SchemaSerializer.to_metric_labels]
+        on_missing: Called with the field name, a dict of the fields before it which have been validated and the
+            validation context when the field is missing from the input, the value it returns is validated like
+            input, it may raise validation errors, or `PydanticUseDefault` to use the field's default
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
@@ -2765,7 +3465,10 @@ def typed_dict_field(
         required=required,
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
+        serialization_alias_profiles=serialization_alias_profiles,
         serialization_exclude=serialization_exclude,
+        metric_label=metric_label,
+        on_missing=on_missing,
         metadata=metadata,
     )
 
@@ -2776,10 +3479,12 @@ class TypedDictSchema(TypedDict, total=False):
     computed_fields: List[ComputedField]
     strict: bool
     extras_schema: CoreSchema
+    extras_pattern_schemas: Dict[str, CoreSchema]
     # all these values can be set via config, equivalent fields have `typed_dict_` prefix
     extra_behavior: ExtraBehavior
     total: bool  # default: True
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
+    alias_priority: Literal['all', 'first_non_colliding']  # default: 'all'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2792,9 +3497,11 @@ def typed_dict_schema(
     computed_fields: list[ComputedField] | None = None,
     strict: bool | None = None,
     extras_schema: CoreSchema | None = None,
+    extras_pattern_schemas: dict[str, CoreSchema] | None = None,
     extra_behavior: ExtraBehavior | None = None,
     total: bool | None = None,
     populate_by_name: bool | None = None,
+    alias_priority: Literal['all', 'first_non_colliding'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2818,11 +3525,18 @@ def typed_dict_schema(
         computed_fields: Computed fields to use when serializing the model, only applies when directly inside a model
         strict: Whether the typed dict is strict
         extras_schema: The extra validator to use for the typed dict
+        extras_pattern_schemas: Schemas for extra keys matching regex patterns, like `patternProperties` in
+            JSON Schema, keys matching a pattern are validated by the schema of the first pattern they match whatever
+            the `extra_behavior`, other extra keys are handled by `extra_behavior` and `extras_schema`
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         extra_behavior: The extra behavior to use for the typed dict
         total: Whether the typed dict is total
         populate_by_name: Whether the typed dict should populate by name
+        alias_priority: How fields with a list of serialization aliases are serialized
+            * `all` (default) serializes the field under each alias
+            * `first_non_colliding` serializes the field under the first alias which isn't the name of another field
+              or already used by an earlier field, or by name if there's no such alias
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2831,9 +3545,11 @@ def typed_dict_schema(
         computed_fields=computed_fields,
         strict=strict,
         extras_schema=extras_schema,
+        extras_pattern_schemas=extras_pattern_schemas,
         extra_behavior=extra_behavior,
         total=total,
         populate_by_name=populate_by_name,
+        alias_priority=alias_priority,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -2845,9 +3561,15 @@ class ModelField(TypedDict, total=False):
     type: Required[Literal['model-field']]
     schema: Required[CoreSchema]
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
-    serialization_alias: str
+    attribute_path: Union[str, List[Union[str, int]]]
+    serialization_alias: Union[str, List[str]]
+    serialization_alias_profiles: Dict[str, Union[str, List[str]]]
     serialization_exclude: bool  # default: False
+    metric_label: bool  # default: False
     frozen: bool
+    requires: List[str]
+    conflicts_with: List[str]
+    on_missing: OnMissingFunction
     metadata: Any
 
 
@@ -2855,9 +3577,15 @@ def model_field(
     schema: CoreSchema,
     *,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
-    serialization_alias: str | None = None,
+    attribute_path: str | list[str | int] | None = None,
+    serialization_alias: str | list[str] | None = None,
+    serialization_alias_profiles: dict[str, str | list[str]] | None = None,
     serialization_exclude: bool | None = None,
+    metric_label: bool | None = None,
     frozen: bool | None = None,
+    requires: list[str] | None = None,
+    conflicts_with: list[str] | None = None,
+    on_missing: OnMissingFunction | None = None,
     metadata: Any = None,
 ) -> ModelField:
     """
@@ -2871,19 +3599,40 @@ def model_field(
 
     Args:
         schema: The schema to use for the field
-        validation_alias: The alias(es) to use to find the field in the validation data
-        serialization_alias: The alias to use as a key when serializing
+        validation_alias: The alias(es) to use to find the field in the validation data, in alias paths ints
+            index lists from the end when negative and `'*'` gathers the rest of the path from each item into a list
+        attribute_path: The path of attributes to get the field from in `from_attributes` mode instead of its
+            name or `validation_alias`, as a list like an alias path or a dotted string, e.g. `'profile.address.city'`
+        serialization_alias: The alias to use as a key when serializing, if a list is provided the field is
+            serialized under each alias, and unless `validation_alias` is set, any of them is accepted when validating
+        serialization_alias_profiles: Aliases to use instead of `serialization_alias` when serializing with the
+            `alias_profile` they're keyed by, these aren't accepted when validating
         serialization_exclude: Whether to exclude the field when serializing
-        frozen: Whether the field is frozen
+        metric_label: Whether the field is included in
+            [`SchemaSerializer.to_metric_labels`][pydantic_core.SchemaSerializer.to_metric_labels]
+        frozen: Whether the field is frozen, assigning to it with `validate_assignment` gets a `frozen_field` error
+        requires: Names of other fields which must be in the input if this field is, a missing field which has a
+            default gets a `dependent_field_missing` error
+        conflicts_with: Names of other fields which can't be in the input if this field is, this field gets a
+            `conflicting_field` error if any of them are
+        on_missing: Called with the field name, a dict of the fields before it which have been validated and the
+            validation context when the field is missing from the input, the value it returns is validated like
+            input, it may raise validation errors, or `PydanticUseDefault` to use the field's default
         metadata: Any other information you want to include with the schema, not used by pydantic-core
     """
     return _dict_not_none(
         type='model-field',
         schema=schema,
         validation_alias=validation_alias,
+        attribute_path=attribute_path,
         serialization_alias=serialization_alias,
+        serialization_alias_profiles=serialization_alias_profiles,
         serialization_exclude=serialization_exclude,
+        metric_label=metric_label,
         frozen=frozen,
+        requires=requires,
+        conflicts_with=conflicts_with,
+        on_missing=on_missing,
         metadata=metadata,
     )
 
@@ -2899,6 +3648,7 @@ class ModelFieldsSchema(TypedDict, total=False):
     extra_behavior: ExtraBehavior
     populate_by_name: bool  # replaces `allow_population_by_field_name` in pydantic v1
     from_attributes: bool
+    alias_priority: Literal['all', 'first_non_colliding']  # default: 'all'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -2914,6 +3664,7 @@ def model_fields_schema(
     extra_behavior: ExtraBehavior | None = None,
     populate_by_name: bool | None = None,
     from_attributes: bool | None = None,
+    alias_priority: Literal['all', 'first_non_colliding'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -2943,6 +3694,10 @@ def model_fields_schema(
         extra_behavior: The extra behavior to use for the typed dict
         populate_by_name: Whether the typed dict should populate by name
         from_attributes: Whether the typed dict should be populated from attributes
+        alias_priority: How fields with a list of serialization aliases are serialized
+            * `all` (default) serializes the field under each alias
+            * `first_non_colliding` serializes the field under the first alias which isn't the name of another field
+              or already used by an earlier field, or by name if there's no such alias
         serialization: Custom serialization schema
     """
     return _dict_not_none(
@@ -2955,6 +3710,7 @@ def model_fields_schema(
         extra_behavior=extra_behavior,
         populate_by_name=populate_by_name,
         from_attributes=from_attributes,
+        alias_priority=alias_priority,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3068,8 +3824,10 @@ class DataclassField(TypedDict, total=False):
     init_only: bool  # default: False
     frozen: bool  # default: False
     validation_alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
-    serialization_alias: str
+    serialization_alias: Union[str, List[str]]
+    serialization_alias_profiles: Dict[str, Union[str, List[str]]]
     serialization_exclude: bool  # default: False
+    metric_label: bool  # default: False
     metadata: Any
 
 
@@ -3081,8 +3839,10 @@ def dataclass_field(
     init: bool | None = None,
     init_only: bool | None = None,
     validation_alias: str | list[str | int] | list[list[str | int]] | None = None,
-    serialization_alias: str | None = None,
+    serialization_alias: str | list[str] | None = None,
+    serialization_alias_profiles: dict[str, str | list[str]] | None = None,
     serialization_exclude: bool | None = None,
+    metric_label: bool | None = None,
     metadata: Any = None,
     frozen: bool | None = None,
 ) -> DataclassField:
@@ -3106,11 +3866,17 @@ def dataclass_field(
         kw_only: Whether the field can be set with a positional argument as well as a keyword argument
         init: Whether the field should be validated during initialization
         init_only: Whether the field should be omitted  from `__dict__` and passed to `__post_init__`
-        validation_alias: The alias(es) to use to find the field in the validation data
-        serialization_alias: The alias to use as a key when serializing
+        validation_alias: The alias(es) to use to find the field in the validation data, in alias paths ints
+            index lists from the end when negative and `'*'` gathers the rest of the path from each item into a list
+        serialization_alias: The alias to use as a key when serializing, if a list is provided the field is
+            serialized under each alias, and unless `validation_alias` is set, any of them is accepted when validating
+        serialization_alias_profiles: Aliases to use instead of `serialization_alias` when serializing with the
+            `alias_profile` they're keyed by, these aren't accepted when validating
         serialization_exclude: Whether to exclude the field when serializing
+        metric_label: Whether the field is included in
+            [`SchemaSerializer.to_metric_labels`][pydantic_core.SchemaSerializer.to_metric_labels]
         metadata: Any other information you want to include with the schema, not used by pydantic-core
-        frozen: Whether the field is frozen
+        frozen: Whether the field is frozen, assigning to it with `validate_assignment` gets a `frozen_field` error
     """
     return _dict_not_none(
         type='dataclass-field',
@@ -3121,7 +3887,9 @@ def dataclass_field(
         init_only=init_only,
         validation_alias=validation_alias,
         serialization_alias=serialization_alias,
+        serialization_alias_profiles=serialization_alias_profiles,
         serialization_exclude=serialization_exclude,
+        metric_label=metric_label,
         metadata=metadata,
         frozen=frozen,
     )
@@ -3133,7 +3901,8 @@ class DataclassArgsSchema(TypedDict, total=False):
     fields: Required[List[DataclassField]]
     computed_fields: List[ComputedField]
     populate_by_name: bool  # default: False
-    collect_init_only: bool  # default: False
+    collect_init_only: bool  # default: True if any field is `init_only`
+    alias_priority: Literal['all', 'first_non_colliding']  # default: 'all'
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -3147,6 +3916,7 @@ def dataclass_args_schema(
     computed_fields: List[ComputedField] | None = None,
     populate_by_name: bool | None = None,
     collect_init_only: bool | None = None,
+    alias_priority: Literal['all', 'first_non_colliding'] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -3174,7 +3944,12 @@ def dataclass_args_schema(
         fields: The fields to use for the dataclass
         computed_fields: Computed fields to use when serializing the dataclass
         populate_by_name: Whether to populate by name
-        collect_init_only: Whether to collect init only fields into a dict to pass to `__post_init__`
+        collect_init_only: Whether to collect init only fields into a tuple to pass to `__post_init__`, by default
+            they're collected if there are any
+        alias_priority: How fields with a list of serialization aliases are serialized
+            * `all` (default) serializes the field under each alias
+            * `first_non_colliding` serializes the field under the first alias which isn't the name of another field
+              or already used by an earlier field, or by name if there's no such alias
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -3187,6 +3962,7 @@ def dataclass_args_schema(
         computed_fields=computed_fields,
         populate_by_name=populate_by_name,
         collect_init_only=collect_init_only,
+        alias_priority=alias_priority,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3266,27 +4042,341 @@ def dataclass_schema(
     )
 
 
-class ArgumentsParameter(TypedDict, total=False):
-    name: Required[str]
-    schema: Required[CoreSchema]
-    mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only']  # default positional_or_keyword
-    alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
+class NamedTupleSchema(TypedDict, total=False):
+    type: Required[Literal['namedtuple']]
+    cls: Required[Type[Any]]
+    fields: Required[Dict[str, CoreSchema]]
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
 
 
-def arguments_parameter(
-    name: str,
-    schema: CoreSchema,
+def namedtuple_schema(
+    cls: Type[Any],
+    fields: Dict[str, CoreSchema],
     *,
-    mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only'] | None = None,
-    alias: str | list[str | int] | list[list[str | int]] | None = None,
-) -> ArgumentsParameter:
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> NamedTupleSchema:
     """
-    Returns a schema that matches an argument parameter, e.g.:
+    Returns a schema that matches a `NamedTuple`, e.g.:
 
     ```py
+    from typing import NamedTuple
     from pydantic_core import SchemaValidator, core_schema
 
-    param = core_schema.arguments_parameter(
+    class Point(NamedTuple):
+        x: int
+        y: int = 0
+
+    schema = core_schema.namedtuple_schema(
+        Point,
+        {
+            'x': core_schema.int_schema(),
+            'y': core_schema.with_default_schema(core_schema.int_schema(), default=0),
+        },
+    )
+    v = SchemaValidator(schema)
+    assert v.validate_python({'x': '1'}) == Point(1, 0)
+    assert v.validate_python([1, 2]) == Point(1, 2)
+    assert v.validate_json('{"x": 1, "y": 2}') == Point(1, 2)
+    ```
+
+    Dicts and JSON objects are validated by field name, other sequences and JSON arrays by position, the values
+    are passed to `cls` like arguments so fields with a default schema can be left out. Named tuples are
+    serialized to JSON as arrays, or objects with
+    [`CoreConfig.ser_json_namedtuple`][pydantic_core.core_schema.CoreConfig] set to `'object'`.
+
+    Args:
+        cls: The `NamedTuple` class to construct
+        fields: The schema of each field, in order
+        strict: Whether to only accept instances of `cls` when validating Python objects
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='namedtuple',
+        cls=cls,
+        fields=fields,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class DequeSchema(TypedDict, total=False):
+    type: Required[Literal['deque']]
+    items_schema: CoreSchema
+    min_length: int
+    max_length: int
+    maxlen: int
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def deque_schema(
+    items_schema: CoreSchema | None = None,
+    *,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    maxlen: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> DequeSchema:
+    """
+    Returns a schema that matches a `collections.deque`, e.g.:
+
+    ```py
+    from collections import deque
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.deque_schema(core_schema.int_schema(), maxlen=2)
+    v = SchemaValidator(schema)
+    assert v.validate_python(['1', 2, 3]) == deque([2, 3], maxlen=2)
+    assert v.validate_json('[1, 2]') == deque([1, 2], maxlen=2)
+    ```
+
+    The items are validated like a list, deques are serialized to JSON as arrays.
+
+    Args:
+        items_schema: The value must be a deque with items that match this schema
+        min_length: The value must have at least this many items
+        max_length: The value must have at most this many items
+        maxlen: The `maxlen` of the deque constructed, extra items are dropped from the start
+        strict: Whether to only accept deques when validating Python objects
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='deque',
+        items_schema=items_schema,
+        min_length=min_length,
+        max_length=max_length,
+        maxlen=maxlen,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class CounterSchema(TypedDict, total=False):
+    type: Required[Literal['counter']]
+    keys_schema: CoreSchema  # default: AnySchema
+    values_schema: CoreSchema  # default: AnySchema
+    min_length: int
+    max_length: int
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def counter_schema(
+    keys_schema: CoreSchema | None = None,
+    values_schema: CoreSchema | None = None,
+    *,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> CounterSchema:
+    """
+    Returns a schema that matches a `collections.Counter`, e.g.:
+
+    ```py
+    from collections import Counter
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.counter_schema(core_schema.str_schema(), core_schema.int_schema())
+    v = SchemaValidator(schema)
+    assert v.validate_json('{"a": 1, "b": 2}') == Counter(a=1, b=2)
+    ```
+
+    The counts are validated like a dict, counters are serialized to JSON as objects.
+
+    Args:
+        keys_schema: The value must be a counter with keys that match this schema
+        values_schema: The value must be a counter with counts that match this schema
+        min_length: The value must have at least this many keys
+        max_length: The value must have at most this many keys
+        strict: Whether to only accept counters when validating Python objects
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='counter',
+        keys_schema=keys_schema,
+        values_schema=values_schema,
+        min_length=min_length,
+        max_length=max_length,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class OrderedDictSchema(TypedDict, total=False):
+    type: Required[Literal['ordered-dict']]
+    keys_schema: CoreSchema  # default: AnySchema
+    values_schema: CoreSchema  # default: AnySchema
+    min_length: int
+    max_length: int
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def ordered_dict_schema(
+    keys_schema: CoreSchema | None = None,
+    values_schema: CoreSchema | None = None,
+    *,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> OrderedDictSchema:
+    """
+    Returns a schema that matches a `collections.OrderedDict`, e.g.:
+
+    ```py
+    from collections import OrderedDict
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ordered_dict_schema(core_schema.str_schema(), core_schema.int_schema())
+    v = SchemaValidator(schema)
+    assert v.validate_python({'b': '2', 'a': 1}) == OrderedDict([('b', 2), ('a', 1)])
+    ```
+
+    The items are validated like a dict, ordered dicts are serialized to JSON as objects.
+
+    Args:
+        keys_schema: The value must be an ordered dict with keys that match this schema
+        values_schema: The value must be an ordered dict with values that match this schema
+        min_length: The value must have at least this many items
+        max_length: The value must have at most this many items
+        strict: Whether to only accept ordered dicts when validating Python objects
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ordered-dict',
+        keys_schema=keys_schema,
+        values_schema=values_schema,
+        min_length=min_length,
+        max_length=max_length,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class DefaultDictSchema(TypedDict, total=False):
+    type: Required[Literal['defaultdict']]
+    default_factory: Required[Callable[[], Any]]
+    keys_schema: CoreSchema  # default: AnySchema
+    values_schema: CoreSchema  # default: AnySchema
+    min_length: int
+    max_length: int
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def defaultdict_schema(
+    default_factory: Callable[[], Any],
+    keys_schema: CoreSchema | None = None,
+    values_schema: CoreSchema | None = None,
+    *,
+    min_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> DefaultDictSchema:
+    """
+    Returns a schema that matches a `collections.defaultdict`, e.g.:
+
+    ```py
+    from collections import defaultdict
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.defaultdict_schema(list, core_schema.str_schema(), core_schema.list_schema())
+    v = SchemaValidator(schema)
+    d = v.validate_json('{"a": [1]}')
+    assert d == defaultdict(list, a=[1])
+    assert d['b'] == []
+    ```
+
+    The items are validated like a dict, default dicts are serialized to JSON as objects.
+
+    Args:
+        default_factory: The `default_factory` of the defaultdict constructed
+        keys_schema: The value must be a defaultdict with keys that match this schema
+        values_schema: The value must be a defaultdict with values that match this schema
+        min_length: The value must have at least this many items
+        max_length: The value must have at most this many items
+        strict: Whether to only accept defaultdicts when validating Python objects
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='defaultdict',
+        default_factory=default_factory,
+        keys_schema=keys_schema,
+        values_schema=values_schema,
+        min_length=min_length,
+        max_length=max_length,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class ArgumentsParameter(TypedDict, total=False):
+    name: Required[str]
+    schema: Required[CoreSchema]
+    mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only']  # default positional_or_keyword
+    alias: Union[str, List[Union[str, int]], List[List[Union[str, int]]]]
+
+
+def arguments_parameter(
+    name: str,
+    schema: CoreSchema,
+    *,
+    mode: Literal['positional_only', 'positional_or_keyword', 'keyword_only'] | None = None,
+    alias: str | list[str | int] | list[list[str | int]] | None = None,
+) -> ArgumentsParameter:
+    """
+    Returns a schema that matches an argument parameter, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    param = core_schema.arguments_parameter(
         name='a', schema=core_schema.str_schema(), mode='positional_only'
     )
     schema = core_schema.arguments_schema([param])
@@ -3309,6 +4399,8 @@ class ArgumentsSchema(TypedDict, total=False):
     populate_by_name: bool
     var_args_schema: CoreSchema
     var_kwargs_schema: CoreSchema
+    var_kwargs_pattern_schemas: Dict[str, CoreSchema]
+    json_envelope: Union[bool, Tuple[str, str]]  # default False
     ref: str
     metadata: Any
     serialization: SerSchema
@@ -3320,6 +4412,8 @@ def arguments_schema(
     populate_by_name: bool | None = None,
     var_args_schema: CoreSchema | None = None,
     var_kwargs_schema: CoreSchema | None = None,
+    var_kwargs_pattern_schemas: dict[str, CoreSchema] | None = None,
+    json_envelope: bool | tuple[str, str] | None = None,
     ref: str | None = None,
     metadata: Any = None,
     serialization: SerSchema | None = None,
@@ -3346,6 +4440,12 @@ def arguments_schema(
         populate_by_name: Whether to populate by name
         var_args_schema: The variable args schema to use for the arguments schema
         var_kwargs_schema: The variable kwargs schema to use for the arguments schema
+        var_kwargs_pattern_schemas: Schemas for variable kwargs with names matching regex patterns, kwargs are
+            validated by the schema of the first pattern they match, other kwargs by `var_kwargs_schema`
+        json_envelope: Whether JSON objects with an `__args__` array or `__kwargs__` object hold the positional
+            and keyword arguments, so positional only parameters can be set from an object, e.g.
+            `{"__args__": [1], "__kwargs__": {"b": 2}}`, a tuple of two keys can be used in place of
+            `__args__` and `__kwargs__`, other keys aren't allowed alongside them
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -3356,6 +4456,8 @@ def arguments_schema(
         populate_by_name=populate_by_name,
         var_args_schema=var_args_schema,
         var_kwargs_schema=var_kwargs_schema,
+        var_kwargs_pattern_schemas=var_kwargs_pattern_schemas,
+        json_envelope=json_envelope,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -3538,6 +4640,15 @@ def json_schema(
     return _dict_not_none(type='json', schema=schema, ref=ref, metadata=metadata, serialization=serialization)
 
 
+class UrlComponentLengths(TypedDict, total=False):
+    username: int
+    password: int
+    host: int
+    path: int
+    query: int
+    fragment: int
+
+
 class UrlSchema(TypedDict, total=False):
     type: Required[Literal['url']]
     max_length: int
@@ -3546,6 +4657,10 @@ class UrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    allowed_hosts: List[str]
+    forbid_userinfo: bool  # default False
+    idna: Literal['allow', 'punycode', 'forbid']  # default 'allow'
+    max_component_lengths: UrlComponentLengths
     strict: bool
     ref: str
     metadata: Any
@@ -3560,6 +4675,10 @@ def url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    allowed_hosts: list[str] | None = None,
+    forbid_userinfo: bool | None = None,
+    idna: Literal['allow', 'punycode', 'forbid'] | None = None,
+    max_component_lengths: UrlComponentLengths | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -3584,6 +4703,13 @@ def url_schema(
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        allowed_hosts: The allowed hosts, a pattern starting with `*.` allows any subdomain of the rest of the
+            pattern, internationalized domain names should be written in punycode
+        forbid_userinfo: Whether to reject URLs with a username or password
+        idna: Whether internationalized domain names are allowed in the host, `'punycode'` only allows them
+            when written in punycode rather than unicode
+        max_component_lengths: The maximum lengths of the `username`, `password`, `host`, `path`, `query`
+            and `fragment` of the URL, as they're stored after parsing
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3597,6 +4723,10 @@ def url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        allowed_hosts=allowed_hosts,
+        forbid_userinfo=forbid_userinfo,
+        idna=idna,
+        max_component_lengths=max_component_lengths,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -3612,6 +4742,10 @@ class MultiHostUrlSchema(TypedDict, total=False):
     default_host: str
     default_port: int
     default_path: str
+    allowed_hosts: List[str]
+    forbid_userinfo: bool  # default False
+    idna: Literal['allow', 'punycode', 'forbid']  # default 'allow'
+    max_component_lengths: UrlComponentLengths
     strict: bool
     ref: str
     metadata: Any
@@ -3626,6 +4760,10 @@ def multi_host_url_schema(
     default_host: str | None = None,
     default_port: int | None = None,
     default_path: str | None = None,
+    allowed_hosts: list[str] | None = None,
+    forbid_userinfo: bool | None = None,
+    idna: Literal['allow', 'punycode', 'forbid'] | None = None,
+    max_component_lengths: UrlComponentLengths | None = None,
     strict: bool | None = None,
     ref: str | None = None,
     metadata: Any = None,
@@ -3650,6 +4788,13 @@ def multi_host_url_schema(
         default_host: The default host to use if the URL does not have a host
         default_port: The default port to use if the URL does not have a port
         default_path: The default path to use if the URL does not have a path
+        allowed_hosts: The allowed hosts, a pattern starting with `*.` allows any subdomain of the rest of the
+            pattern, internationalized domain names should be written in punycode
+        forbid_userinfo: Whether to reject URLs with a username or password
+        idna: Whether internationalized domain names are allowed in the host, `'punycode'` only allows them
+            when written in punycode rather than unicode
+        max_component_lengths: The maximum lengths of the `username`, `password`, `host`, `path`, `query`
+            and `fragment` of the URL, as they're stored after parsing
         strict: Whether to use strict URL parsing
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
@@ -3663,6 +4808,253 @@ def multi_host_url_schema(
         default_host=default_host,
         default_port=default_port,
         default_path=default_path,
+        allowed_hosts=allowed_hosts,
+        forbid_userinfo=forbid_userinfo,
+        idna=idna,
+        max_component_lengths=max_component_lengths,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class UriReferenceSchema(TypedDict, total=False):
+    type: Required[Literal['uri-reference']]
+    max_length: int
+    allowed_schemes: List[str]
+    normalize: bool  # default False
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def uri_reference_schema(
+    *,
+    max_length: int | None = None,
+    allowed_schemes: list[str] | None = None,
+    normalize: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> UriReferenceSchema:
+    """
+    Returns a schema that matches a URI reference as defined by RFC 3986, either an absolute URL or a reference
+    relative to some base URL, the validated value is a string, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.uri_reference_schema()
+    v = SchemaValidator(schema)
+    print(v.validate_python('../images/logo.png?size=2'))
+    #> ../images/logo.png?size=2
+    ```
+
+    Args:
+        max_length: The maximum length of the URI reference
+        allowed_schemes: The allowed schemes of absolute URLs, relative references are always allowed
+        normalize: Whether to return the normalized form of the URI reference, with the host lowercased and
+            percent-encoding normalized, rather than the input string
+        strict: Whether to use strict URL parsing
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='uri-reference',
+        max_length=max_length,
+        allowed_schemes=allowed_schemes,
+        normalize=normalize,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class HostnameSchema(TypedDict, total=False):
+    type: Required[Literal['hostname']]
+    idna: Literal['allow', 'punycode', 'forbid']  # default 'allow'
+    output: Literal['ascii', 'unicode']  # default 'ascii'
+    allow_wildcard: bool  # default False
+    max_label_length: int  # default 63
+    max_length: int  # default 253
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def hostname_schema(
+    *,
+    idna: Literal['allow', 'punycode', 'forbid'] | None = None,
+    output: Literal['ascii', 'unicode'] | None = None,
+    allow_wildcard: bool | None = None,
+    max_label_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> HostnameSchema:
+    """
+    Returns a schema that matches a hostname as defined by RFC 1123, the validated value is the lowercase hostname
+    as a string, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.hostname_schema()
+    v = SchemaValidator(schema)
+    print(v.validate_python('Bücher.example'))
+    #> xn--bcher-kva.example
+    ```
+
+    Args:
+        idna: Whether internationalized domain names are allowed, `'punycode'` only allows them when written
+            in punycode rather than unicode
+        output: Whether internationalized domain names are returned in punycode (`'ascii'`) or `'unicode'`
+        allow_wildcard: Whether to allow `*` as the first label, e.g. `*.example.com`
+        max_label_length: The maximum length of each label in its ASCII form, default 63
+        max_length: The maximum length of the hostname in its ASCII form, default 253
+        strict: Whether the input should be a string
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='hostname',
+        idna=idna,
+        output=output,
+        allow_wildcard=allow_wildcard,
+        max_label_length=max_label_length,
+        max_length=max_length,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+class DomainSchema(TypedDict, total=False):
+    type: Required[Literal['domain']]
+    idna: Literal['allow', 'punycode', 'forbid']  # default 'allow'
+    output: Literal['ascii', 'unicode']  # default 'ascii'
+    allow_wildcard: bool  # default False
+    max_label_length: int  # default 63
+    max_length: int  # default 253
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def domain_schema(
+    *,
+    idna: Literal['allow', 'punycode', 'forbid'] | None = None,
+    output: Literal['ascii', 'unicode'] | None = None,
+    allow_wildcard: bool | None = None,
+    max_label_length: int | None = None,
+    max_length: int | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> DomainSchema:
+    """
+    Returns a schema that matches a fully qualified domain name, a hostname with at least two labels
+    and a top-level domain which isn't numeric, the validated value is the lowercase domain as a string, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.domain_schema(output='unicode')
+    v = SchemaValidator(schema)
+    print(v.validate_python('XN--BCHER-KVA.Example'))
+    #> bücher.example
+    ```
+
+    Args:
+        idna: Whether internationalized domain names are allowed, `'punycode'` only allows them when written
+            in punycode rather than unicode
+        output: Whether internationalized domain names are returned in punycode (`'ascii'`) or `'unicode'`
+        allow_wildcard: Whether to allow `*` as the first label, e.g. `*.example.com`
+        max_label_length: The maximum length of each label in its ASCII form, default 63
+        max_length: The maximum length of the domain name in its ASCII form, default 253
+        strict: Whether the input should be a string
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='domain',
+        idna=idna,
+        output=output,
+        allow_wildcard=allow_wildcard,
+        max_label_length=max_label_length,
+        max_length=max_length,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
+PhoneNumberFormat = Literal['e164', 'international', 'national', 'rfc3966']
+
+
+class PhoneSchema(TypedDict, total=False):
+    type: Required[Literal['phone']]
+    default_region: str
+    as_phone_number: bool  # default False
+    output_format: PhoneNumberFormat
+    strict: bool
+    ref: str
+    metadata: Any
+    serialization: SerSchema
+
+
+def phone_schema(
+    *,
+    default_region: str | None = None,
+    as_phone_number: bool | None = None,
+    output_format: PhoneNumberFormat | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Any = None,
+    serialization: SerSchema | None = None,
+) -> PhoneSchema:
+    """
+    Returns a schema that matches a phone number, the validated value is the number in E.164 format, e.g.:
+
+    ```py
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.phone_schema(default_region='GB')
+    v = SchemaValidator(schema)
+    print(v.validate_python('020 7946 0018'))
+    #> +442079460018
+    ```
+
+    Args:
+        default_region: The ISO 3166-1 alpha-2 code of the region numbers without a leading `+` and country code
+            are read as national numbers of, without it such numbers are invalid
+        as_phone_number: Whether to return a `PhoneNumber` rather than the E.164 string, which keeps any extension
+        output_format: The format numbers are serialized in, by default strings are serialized as they are and
+            `PhoneNumber`s in E.164 format in JSON
+        strict: Whether the input should be a string or `PhoneNumber`
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='phone',
+        default_region=default_region,
+        as_phone_number=as_phone_number,
+        output_format=output_format,
         strict=strict,
         ref=ref,
         metadata=metadata,
@@ -3751,7 +5143,9 @@ if not MYPY:
         IntSchema,
         FloatSchema,
         DecimalSchema,
+        MoneySchema,
         StringSchema,
+        StringFormatSchema,
         BytesSchema,
         DateSchema,
         TimeSchema,
@@ -3784,15 +5178,26 @@ if not MYPY:
         ModelSchema,
         DataclassArgsSchema,
         DataclassSchema,
+        NamedTupleSchema,
+        DequeSchema,
+        CounterSchema,
+        OrderedDictSchema,
+        DefaultDictSchema,
         ArgumentsSchema,
         CallSchema,
         CustomErrorSchema,
         JsonSchema,
         UrlSchema,
         MultiHostUrlSchema,
+        UriReferenceSchema,
+        HostnameSchema,
+        DomainSchema,
+        PhoneSchema,
         DefinitionsSchema,
         DefinitionReferenceSchema,
         UuidSchema,
+        UlidSchema,
+        NativeSchema,
     ]
 elif False:
     CoreSchema: TypeAlias = Mapping[str, Any]
@@ -3806,7 +5211,9 @@ CoreSchemaType = Literal[
     'int',
     'float',
     'decimal',
+    'money',
     'str',
+    'string-format',
     'bytes',
     'date',
     'time',
@@ -3839,15 +5246,26 @@ CoreSchemaType = Literal[
     'model',
     'dataclass-args',
     'dataclass',
+    'namedtuple',
+    'deque',
+    'counter',
+    'ordered-dict',
+    'defaultdict',
     'arguments',
     'call',
     'custom-error',
     'json',
     'url',
     'multi-host-url',
+    'uri-reference',
+    'hostname',
+    'domain',
+    'phone',
     'definitions',
     'definition-ref',
     'uuid',
+    'ulid',
+    'native',
 ]
 
 CoreSchemaFieldType = Literal['model-field', 'dataclass-field', 'typed-dict-field', 'computed-field']
@@ -3868,6 +5286,8 @@ ErrorType = Literal[
     'get_attribute_error',
     'model_type',
     'model_attributes_type',
+    'dependent_field_missing',
+    'conflicting_field',
     'dataclass_type',
     'dataclass_exact_type',
     'none_required',
@@ -3887,6 +5307,7 @@ ErrorType = Literal[
     'string_too_short',
     'string_too_long',
     'string_pattern_mismatch',
+    'string_format_mismatch',
     'enum',
     'dict_type',
     'mapping_type',
@@ -3944,14 +5365,26 @@ ErrorType = Literal[
     'url_syntax_violation',
     'url_too_long',
     'url_scheme',
+    'url_host',
+    'url_userinfo',
+    'url_idna',
+    'url_component_too_long',
     'uuid_type',
     'uuid_parsing',
     'uuid_version',
+    'ulid_type',
+    'ulid_parsing',
+    'hostname_parsing',
+    'domain_parsing',
+    'phone_parsing',
     'decimal_type',
     'decimal_parsing',
     'decimal_max_digits',
     'decimal_max_places',
     'decimal_whole_digits',
+    'money_type',
+    'money_parsing',
+    'money_currency',
 ]
 
 
@@ -195,3 +195,160 @@ impl ExtraBehavior {
         Ok(res)
     }
 }
+
+/// Where models and dataclasses with `extra_behavior='allow'` store their extras, from the `extras_destination`
+/// config
+#[derive(Debug, Clone)]
+pub(crate) enum ExtrasDestination {
+    /// `__pydantic_extra__`, the default for models
+    ModelExtra,
+    /// the instance's `__dict__` along with the fields, the default for dataclasses
+    Merge,
+    /// a dict in the attribute with this name
+    Attribute(Py<PyString>),
+}
+
+impl ExtrasDestination {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let Some(config) = config else {
+            return Ok(None);
+        };
+        let Some(destination) = config.get_as::<Bound<'_, PyString>>(intern!(config.py(), "extras_destination"))?
+        else {
+            return Ok(None);
+        };
+        let destination = match destination.to_str()? {
+            "__pydantic_extra__" => Self::ModelExtra,
+            "merge" => Self::Merge,
+            // other attributes set by validation can't hold the extras
+            name if !is_identifier(name)
+                || matches!(name, "__dict__" | "__pydantic_fields_set__" | "__pydantic_private__") =>
+            {
+                return py_schema_err!("Invalid extras_destination: `{}`", name)
+            }
+            _ => Self::Attribute(destination.unbind()),
+        };
+        Ok(Some(destination))
+    }
+
+    /// The attribute the extras dict is stored in, `None` when they're merged into `__dict__`
+    pub fn attribute<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyString>> {
+        match self {
+            Self::ModelExtra => Some(intern!(py, "__pydantic_extra__").clone()),
+            Self::Merge => None,
+            Self::Attribute(name) => Some(name.bind(py).clone()),
+        }
+    }
+}
+
+fn is_identifier(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars.next().is_some_and(|c| c == '_' || c.is_alphabetic()) && chars.all(|c| c == '_' || c.is_alphanumeric())
+}
+
+/// The `alias_generator` config, which generates an alias for each field without `validation_alias` or
+/// `serialization_alias` when the schema is built, rather than calling a python function for each field
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub(crate) enum AliasGenerator {
+    Camel,
+    Pascal,
+    Kebab,
+}
+
+impl AliasGenerator {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Option<Self>> {
+        let Some(config) = config else {
+            return Ok(None);
+        };
+        let alias_generator: Option<Bound<'_, PyString>> = config.get_as(intern!(config.py(), "alias_generator"))?;
+        let res = match alias_generator.as_ref().map(|s| s.to_str()).transpose()? {
+            Some("to_camel") => Self::Camel,
+            Some("to_pascal") => Self::Pascal,
+            Some("to_kebab") => Self::Kebab,
+            Some(v) => {
+                return py_schema_err!(
+                    "Invalid alias_generator: `{}`, expected `to_camel`, `to_pascal` or `to_kebab`",
+                    v
+                )
+            }
+            None => return Ok(None),
+        };
+        Ok(Some(res))
+    }
+
+    /// The alias of `field_name`, which is split into words at underscores, hyphens and where a lowercase letter
+    /// or digit is followed by an uppercase letter, leading underscores are kept
+    pub fn apply(self, field_name: &str) -> String {
+        let name = field_name.trim_start_matches('_');
+        let mut alias = String::with_capacity(field_name.len());
+        alias.push_str(&field_name[..field_name.len() - name.len()]);
+        for (index, word) in split_words(name).into_iter().enumerate() {
+            match self {
+                Self::Camel if index == 0 => alias.push_str(&word.to_lowercase()),
+                Self::Camel | Self::Pascal => {
+                    let mut chars = word.chars();
+                    if let Some(first) = chars.next() {
+                        alias.extend(first.to_uppercase());
+                        alias.push_str(chars.as_str());
+                    }
+                }
+                Self::Kebab => {
+                    if index > 0 {
+                        alias.push('-');
+                    }
+                    alias.push_str(&word.to_lowercase());
+                }
+            }
+        }
+        alias
+    }
+}
+
+fn split_words(name: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut prev: Option<char> = None;
+    for (index, c) in name.char_indices() {
+        if c == '_' || c == '-' {
+            if start < index {
+                words.push(&name[start..index]);
+            }
+            start = index + c.len_utf8();
+        } else if c.is_uppercase() && prev.is_some_and(|p| p.is_lowercase() || p.is_ascii_digit()) {
+            words.push(&name[start..index]);
+            start = index;
+        }
+        prev = Some(c);
+    }
+    if start < name.len() {
+        words.push(&name[start..]);
+    }
+    words
+}
+
+/// Config used inside a schema which carries its own config, i.e. models, dataclasses and typed dicts.
+/// By default the parent config is ignored, with `config_merge='merge'` this schema's config is
+/// layered on top of the parent config.
+pub fn nested_config<'py>(
+    schema: &Bound<'py, PyDict>,
+    parent_config: Option<&Bound<'py, PyDict>>,
+) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let py = schema.py();
+    let config: Option<Bound<'py, PyDict>> = schema.get_as(intern!(py, "config"))?;
+    let config_merge: Option<Bound<'_, PyString>> = config.as_ref().get_as(intern!(py, "config_merge"))?;
+    let merge = match config_merge.as_ref().map(|s| s.to_str()).transpose()? {
+        Some("replace") | None => false,
+        Some("merge") => true,
+        Some(s) => return py_schema_err!("Invalid config_merge: {}, expected 'replace' or 'merge'", s),
+    };
+    match (parent_config, config) {
+        (Some(parent_config), config) if merge => {
+            let merged = parent_config.copy()?;
+            if let Some(config) = config {
+                merged.update(config.as_mapping())?;
+            }
+            Ok(Some(merged))
+        }
+        (_, config) => Ok(config),
+    }
+}
@@ -13,11 +13,12 @@ use std::{
     },
 };
 
-use pyo3::{prelude::*, PyTraverseError, PyVisit};
+use pyo3::types::PyDict;
+use pyo3::{intern, prelude::*, PyTraverseError, PyVisit};
 
 use ahash::AHashMap;
 
-use crate::{build_tools::py_schema_err, py_gc::PyGcTraverse};
+use crate::{build_tools::py_schema_err, py_gc::PyGcTraverse, tools::SchemaDict};
 
 /// Definitions are validators and serializers that are
 /// shared by reference.
@@ -132,15 +133,37 @@ impl<T: PyGcTraverse> PyGcTraverse for Definitions<T> {
 #[derive(Debug)]
 pub struct DefinitionsBuilder<T> {
     definitions: Definitions<T>,
+    // the config each definition was built with, for `SchemaValidator.get_effective_configs()`
+    configs: Vec<(String, Option<Py<PyDict>>)>,
 }
 
 impl<T: std::fmt::Debug> DefinitionsBuilder<T> {
     pub fn new() -> Self {
         Self {
             definitions: Definitions(AHashMap::new()),
+            configs: Vec::new(),
         }
     }
 
+    /// Record the config `schema` is built with if it's a definition, later records for the same
+    /// reference take precedence, so schemas with their own config can override the parent config.
+    pub fn record_config(&mut self, schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<()> {
+        if let Some(reference) = schema.get_as::<String>(intern!(schema.py(), "ref"))? {
+            let config = config.map(|c| c.copy().map(Bound::unbind)).transpose()?;
+            self.configs.push((reference, config));
+        }
+        Ok(())
+    }
+
+    /// Build a dict mapping each definition reference to the config it was built with
+    pub fn effective_configs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let configs = PyDict::new_bound(py);
+        for (reference, config) in &self.configs {
+            configs.set_item(reference, config)?;
+        }
+        Ok(configs)
+    }
+
     /// Get a ReferenceId for the given reference string.
     pub fn get_definition(&mut self, reference: &str) -> DefinitionRef<T> {
         // We either need a String copy or two hashmap lookups
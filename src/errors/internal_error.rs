@@ -0,0 +1,97 @@
+use std::any::Any;
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use pyo3::exceptions::PyException;
+use pyo3::prelude::*;
+
+/// Raised in place of a panic within validation or serialization when `catch_panics` is set, `root_schema_node` is
+/// the name of the validator or serializer the call started with, the panic may have been in any node below it
+#[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+pub struct PydanticInternalError {
+    #[pyo3(get)]
+    message: String,
+    #[pyo3(get)]
+    location: Option<String>,
+    #[pyo3(get)]
+    root_schema_node: String,
+}
+
+#[pymethods]
+impl PydanticInternalError {
+    #[new]
+    #[pyo3(signature = (message, root_schema_node, location=None))]
+    pub fn py_new(message: String, root_schema_node: String, location: Option<String>) -> Self {
+        Self {
+            message,
+            location,
+            root_schema_node,
+        }
+    }
+
+    fn __str__(&self) -> String {
+        match self.location {
+            Some(ref location) => format!(
+                "Internal error in `{}` at {location}: {}",
+                self.root_schema_node, self.message
+            ),
+            None => format!("Internal error in `{}`: {}", self.root_schema_node, self.message),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PydanticInternalError(message={:?}, root_schema_node={:?}, location={:?})",
+            self.message, self.root_schema_node, self.location
+        )
+    }
+}
+
+thread_local! {
+    // where the last panic on this thread happened, recorded by the hook set in `install_location_hook`
+    static PANIC_LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static LOCATION_HOOK: Once = Once::new();
+
+/// Wrap the panic hook to also record where in the Rust source panics happen, called when a validator or serializer
+/// is built with `catch_panics`, the previous hook is still called so panics are reported as before
+pub(crate) fn install_location_hook() {
+    LOCATION_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let location = info.location().map(ToString::to_string);
+            PANIC_LOCATION.with(|cell| *cell.borrow_mut() = location);
+            previous(info);
+        }));
+    });
+}
+
+/// Call `f`, converting a panic into a `PydanticInternalError` when `enabled`, `root_schema_node` is the name of the
+/// validator or serializer `f` runs
+pub(crate) fn catch_panics<R, E: From<PyErr>>(
+    enabled: bool,
+    root_schema_node: &str,
+    f: impl FnOnce() -> Result<R, E>,
+) -> Result<R, E> {
+    if !enabled {
+        return f();
+    }
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let location = PANIC_LOCATION.with(|cell| cell.borrow_mut().take());
+        let args = (panic_message(&*payload), root_schema_node.to_string(), location);
+        Err(PyErr::new::<PydanticInternalError, _>(args).into())
+    })
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
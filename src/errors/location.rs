@@ -81,6 +81,7 @@ impl From<PathItem> for LocItem {
                 let neg_value = -(val as i64);
                 neg_value.into()
             }
+            PathItem::Wildcard => "*".into(),
         }
     }
 }
@@ -180,6 +181,15 @@ impl Location {
             }
         };
     }
+
+    /// The location items from the outermost to the innermost, the order they're shown to the user
+    pub fn iter(&self) -> impl Iterator<Item = &LocItem> {
+        let items: &[LocItem] = match self {
+            Self::Empty => &[],
+            Self::List(loc) => loc,
+        };
+        items.iter().rev()
+    }
 }
 
 impl Serialize for Location {
@@ -1,16 +1,22 @@
 use pyo3::prelude::*;
 
+mod internal_error;
 mod line_error;
 mod location;
 mod types;
 mod validation_exception;
 mod value_exception;
 
+pub use self::internal_error::PydanticInternalError;
+pub(crate) use self::internal_error::{catch_panics, install_location_hook};
 pub use self::line_error::{InputValue, ToErrorValue, ValError, ValLineError, ValResult};
 pub use self::location::LocItem;
 pub use self::types::{list_all_errors, ErrorType, ErrorTypeDefaults, Number};
 pub use self::validation_exception::ValidationError;
-pub use self::value_exception::{PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault};
+pub use self::value_exception::{
+    PydanticCustomError, PydanticKnownError, PydanticMultipleErrors, PydanticOmit, PydanticUseDefault,
+    PythonCallbackLimitExceeded, ValidationTimeout,
+};
 
 pub fn py_err_string(py: Python, err: PyErr) -> String {
     let value = err.value_bound(py);
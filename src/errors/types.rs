@@ -186,6 +186,12 @@ error_types! {
         class_name: {ctx_type: String, ctx_fn: field_from_context},
     },
     ModelAttributesType {},
+    DependentFieldMissing {
+        field_name: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    ConflictingField {
+        field_name: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // dataclass errors (we don't talk about ArgsKwargs here for simplicity)
     DataclassType {
@@ -247,6 +253,10 @@ error_types! {
     StringPatternMismatch {
         pattern: {ctx_type: String, ctx_fn: field_from_context},
     },
+    StringFormatMismatch {
+        format: {ctx_type: String, ctx_fn: field_from_context},
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // ---------------------
     // enum errors
     Enum {
@@ -402,6 +412,17 @@ error_types! {
     UrlScheme {
         expected_schemes: {ctx_type: String, ctx_fn: field_from_context},
     },
+    UrlHost {
+        expected_hosts: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    UrlUserinfo {},
+    UrlIdna {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    UrlComponentTooLong {
+        component: {ctx_type: String, ctx_fn: field_from_context},
+        max_length: {ctx_type: usize, ctx_fn: field_from_context},
+    },
     // UUID errors,
     UuidType {},
     UuidParsing {
@@ -410,6 +431,22 @@ error_types! {
     UuidVersion {
         expected_version: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    // ULID errors
+    UlidType {},
+    UlidParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // hostname errors
+    HostnameParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    DomainParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // phone number errors
+    PhoneParsing {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
     // Decimal errors
     DecimalType {},
     DecimalParsing {},
@@ -422,6 +459,10 @@ error_types! {
     DecimalWholeDigits {
         whole_digits: {ctx_type: u64, ctx_fn: field_from_context},
     },
+    // Money errors
+    MoneyType {},
+    MoneyParsing {},
+    MoneyCurrency {},
 }
 
 macro_rules! render {
@@ -446,6 +487,24 @@ macro_rules! to_string_render {
     };
 }
 
+/// The template of `StringTooShort` and `StringTooLong` measuring the length in the `unit` from the context, i.e. the
+/// `length_unit` of the string schema, instead of characters
+fn string_length_template<'t>(py: Python, tmpl: &'t str, context: Option<&Py<PyDict>>) -> PyResult<Cow<'t, str>> {
+    let unit: Option<String> = match context {
+        Some(context) => context.bind(py).get_item("unit")?.and_then(|unit| unit.extract().ok()),
+        None => None,
+    };
+    let unit_name = match unit.as_deref() {
+        None | Some("chars" | "codepoints") => return Ok(Cow::Borrowed(tmpl)),
+        Some("bytes") => "byte",
+        Some("graphemes") => "grapheme",
+        Some("utf16") => "UTF-16 code unit",
+        Some("display-width") => "column",
+        Some(unit) => unit,
+    };
+    Ok(Cow::Owned(tmpl.replace("character", unit_name)))
+}
+
 fn plural_s<T: From<u8> + PartialEq>(value: T) -> &'static str {
     if value == 1.into() {
         ""
@@ -479,6 +538,8 @@ impl ErrorType {
             Self::GetAttributeError {..} => "Error extracting attribute: {error}",
             Self::ModelType {..} => "Input should be a valid dictionary or instance of {class_name}",
             Self::ModelAttributesType {..} => "Input should be a valid dictionary or object to extract fields from",
+            Self::DependentFieldMissing {..} => "Field required when '{field_name}' is set",
+            Self::ConflictingField {..} => "Field can't be set when '{field_name}' is set",
             Self::DataclassType {..} => "Input should be a dictionary or an instance of {class_name}",
             Self::DataclassExactType {..} => "Input should be an instance of {class_name}",
             Self::NoneRequired {..} => "Input should be None",
@@ -498,6 +559,7 @@ impl ErrorType {
             Self::StringTooShort {..} => "String should have at least {min_length} character{expected_plural}",
             Self::StringTooLong {..} => "String should have at most {max_length} character{expected_plural}",
             Self::StringPatternMismatch {..} => "String should match pattern '{pattern}'",
+            Self::StringFormatMismatch {..} => "String should match format '{format}', {error}",
             Self::Enum {..} => "Input should be {expected}",
             Self::DictType {..} => "Input should be a valid dictionary",
             Self::MappingType {..} => "Input should be a valid mapping, error: {error}",
@@ -556,14 +618,26 @@ impl ErrorType {
             Self::UrlSyntaxViolation {..} => "Input violated strict URL syntax rules, {error}",
             Self::UrlTooLong {..} => "URL should have at most {max_length} character{expected_plural}",
             Self::UrlScheme {..} => "URL scheme should be {expected_schemes}",
+            Self::UrlHost {..} => "URL host should be {expected_hosts}",
+            Self::UrlUserinfo {..} => "URL should not include a username or password",
+            Self::UrlIdna {..} => "URL host is not allowed, {error}",
+            Self::UrlComponentTooLong {..} => "URL {component} should have at most {max_length} character{expected_plural}",
             Self::UuidType {..} => "UUID input should be a string, bytes or UUID object",
             Self::UuidParsing {..} => "Input should be a valid UUID, {error}",
             Self::UuidVersion {..} => "UUID version {expected_version} expected",
+            Self::UlidType {..} => "ULID input should be a string, bytes or UUID object",
+            Self::UlidParsing {..} => "Input should be a valid ULID, {error}",
+            Self::HostnameParsing {..} => "Input should be a valid hostname, {error}",
+            Self::DomainParsing {..} => "Input should be a valid domain name, {error}",
+            Self::PhoneParsing {..} => "Input should be a valid phone number, {error}",
             Self::DecimalType {..} => "Decimal input should be an integer, float, string or Decimal object",
             Self::DecimalParsing {..} => "Input should be a valid decimal",
             Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
             Self::DecimalMaxPlaces {..} => "Decimal input should have no more than {decimal_places} decimal place{expected_plural}",
             Self::DecimalWholeDigits {..} => "Decimal input should have no more than {whole_digits} digit{expected_plural} before the decimal point",
+            Self::MoneyType {..} => "Money input should be a dictionary with an amount and currency or a string",
+            Self::MoneyParsing {..} => "Input should be an amount and a currency code, e.g. '12.34 USD'",
+            Self::MoneyCurrency {..} => "Input should be a valid ISO 4217 currency code",
         }
     }
 
@@ -582,6 +656,8 @@ impl ErrorType {
             Self::TimeDeltaType { .. } => "Input should be a valid duration",
             Self::TimeDeltaParsing { .. } => "Input should be a valid duration, {error}",
             Self::ArgumentsType { .. } => "Arguments must be an array or an object",
+            Self::MoneyType { .. } => "Money input should be an object with an amount and currency or a string",
+            Self::UlidType { .. } => "ULID input should be a string",
             _ => self.message_template_python(),
         }
     }
@@ -618,6 +694,8 @@ impl ErrorType {
             Self::JsonInvalid { error, .. } => render!(tmpl, error),
             Self::GetAttributeError { error, .. } => render!(tmpl, error),
             Self::ModelType { class_name, .. } => render!(tmpl, class_name),
+            Self::DependentFieldMissing { field_name, .. } => render!(tmpl, field_name),
+            Self::ConflictingField { field_name, .. } => render!(tmpl, field_name),
             Self::DataclassType { class_name, .. } => render!(tmpl, class_name),
             Self::DataclassExactType { class_name, .. } => render!(tmpl, class_name),
             Self::GreaterThan { gt, .. } => to_string_render!(tmpl, gt),
@@ -645,15 +723,18 @@ impl ErrorType {
                 to_string_render!(tmpl, field_type, max_length, actual_length, expected_plural,)
             }
             Self::IterationError { error, .. } => render!(tmpl, error),
-            Self::StringTooShort { min_length, .. } => {
+            Self::StringTooShort { min_length, context } => {
                 let expected_plural = plural_s(*min_length);
+                let tmpl = string_length_template(py, tmpl, context.as_ref())?;
                 to_string_render!(tmpl, min_length, expected_plural)
             }
-            Self::StringTooLong { max_length, .. } => {
+            Self::StringTooLong { max_length, context } => {
                 let expected_plural = plural_s(*max_length);
+                let tmpl = string_length_template(py, tmpl, context.as_ref())?;
                 to_string_render!(tmpl, max_length, expected_plural)
             }
             Self::StringPatternMismatch { pattern, .. } => render!(tmpl, pattern),
+            Self::StringFormatMismatch { format, error, .. } => render!(tmpl, format, error),
             Self::Enum { expected, .. } => to_string_render!(tmpl, expected),
             Self::MappingType { error, .. } => render!(tmpl, error),
             Self::BytesTooShort { min_length, .. } => {
@@ -708,7 +789,19 @@ impl ErrorType {
                 to_string_render!(tmpl, max_length, expected_plural)
             }
             Self::UrlScheme { expected_schemes, .. } => render!(tmpl, expected_schemes),
+            Self::UrlHost { expected_hosts, .. } => render!(tmpl, expected_hosts),
+            Self::UrlIdna { error, .. } => render!(tmpl, error),
+            Self::UrlComponentTooLong {
+                component, max_length, ..
+            } => {
+                let expected_plural = plural_s(*max_length);
+                to_string_render!(tmpl, component, max_length, expected_plural)
+            }
             Self::UuidParsing { error, .. } => render!(tmpl, error),
+            Self::UlidParsing { error, .. } => render!(tmpl, error),
+            Self::HostnameParsing { error, .. } => render!(tmpl, error),
+            Self::DomainParsing { error, .. } => render!(tmpl, error),
+            Self::PhoneParsing { error, .. } => render!(tmpl, error),
             Self::UuidVersion { expected_version, .. } => to_string_render!(tmpl, expected_version),
             Self::DecimalMaxDigits { max_digits, .. } => {
                 let expected_plural = plural_s(*max_digits);
@@ -1,12 +1,13 @@
-use pyo3::exceptions::{PyException, PyValueError};
+use pyo3::exceptions::{PyException, PyTypeError, PyValueError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
 
 use crate::input::InputType;
 use crate::tools::extract_i64;
 
 use super::line_error::ToErrorValue;
-use super::{ErrorType, ValError};
+use super::location::Location;
+use super::{ErrorType, ValError, ValLineError};
 
 #[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
@@ -54,6 +55,70 @@ impl PydanticUseDefault {
     }
 }
 
+#[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+pub struct ValidationTimeout {
+    #[pyo3(get)]
+    deadline_ms: u64,
+}
+
+impl ValidationTimeout {
+    pub(crate) fn new_err(deadline_ms: u64) -> PyErr {
+        PyErr::new::<Self, _>((deadline_ms,))
+    }
+}
+
+#[pymethods]
+impl ValidationTimeout {
+    #[new]
+    pub fn py_new(deadline_ms: u64) -> Self {
+        Self { deadline_ms }
+    }
+
+    fn __str__(&self) -> String {
+        format!("Validation did not complete within {}ms", self.deadline_ms)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ValidationTimeout(deadline_ms={})", self.deadline_ms)
+    }
+}
+
+#[pyclass(extends=PyException, module="pydantic_core._pydantic_core")]
+#[derive(Debug, Clone)]
+pub struct PythonCallbackLimitExceeded {
+    #[pyo3(get)]
+    pub(crate) max_python_callbacks: u64,
+}
+
+impl PythonCallbackLimitExceeded {
+    pub(crate) fn new_err(max_python_callbacks: u64) -> PyErr {
+        PyErr::new::<Self, _>((max_python_callbacks,))
+    }
+}
+
+#[pymethods]
+impl PythonCallbackLimitExceeded {
+    #[new]
+    pub fn py_new(max_python_callbacks: u64) -> Self {
+        Self { max_python_callbacks }
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "More than {} Python callbacks were called in a single call, see `max_python_callbacks`",
+            self.max_python_callbacks
+        )
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "PythonCallbackLimitExceeded(max_python_callbacks={})",
+            self.max_python_callbacks
+        )
+    }
+}
+
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone, Default)]
 pub struct PydanticCustomError {
@@ -187,3 +252,122 @@ impl PydanticKnownError {
         ValError::new(self.error_type, input)
     }
 }
+
+/// Raised by function validators to report several errors at once, each at a location relative to the value
+/// being validated
+#[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct PydanticMultipleErrors {
+    errors: Vec<(Location, ErrorType)>,
+}
+
+#[pymethods]
+impl PydanticMultipleErrors {
+    #[new]
+    pub fn py_new(errors: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let errors = errors
+            .iter()?
+            .map(|item| {
+                let item = item?;
+                match item.downcast::<PyTuple>() {
+                    Ok(tuple) if tuple.len() == 2 => {
+                        let loc = tuple.get_item(0)?;
+                        let location = if loc.is_instance_of::<PyTuple>() || loc.is_instance_of::<PyList>() {
+                            Location::try_from(Some(&loc))?
+                        } else {
+                            Location::new_some((&loc).into())
+                        };
+                        Ok((location, error_type(&tuple.get_item(1)?)?))
+                    }
+                    _ => Ok((Location::default(), error_type(&item)?)),
+                }
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if errors.is_empty() {
+            return Err(PyValueError::new_err("`errors` must not be empty"));
+        }
+        Ok(Self { errors })
+    }
+
+    /// `(loc, error)` for each error, `loc` being relative to the value being validated
+    #[getter]
+    pub fn errors<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        let errors = self
+            .errors
+            .iter()
+            .map(|(location, error_type)| {
+                let error = match error_type {
+                    ErrorType::CustomError {
+                        error_type,
+                        message_template,
+                        context,
+                    } => Py::new(
+                        py,
+                        PydanticCustomError {
+                            error_type: error_type.clone(),
+                            message_template: message_template.clone(),
+                            context: context.as_ref().map(|c| c.clone_ref(py)),
+                        },
+                    )?
+                    .into_any(),
+                    error_type => Py::new(
+                        py,
+                        PydanticKnownError {
+                            error_type: error_type.clone(),
+                        },
+                    )?
+                    .into_any(),
+                };
+                Ok((location.to_object(py), error))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, errors))
+    }
+
+    fn __str__(&self, py: Python) -> PyResult<String> {
+        let messages = self
+            .errors
+            .iter()
+            .map(|(_, error_type)| error_type.render_message(py, InputType::Python))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(messages.join("; "))
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("PydanticMultipleErrors({})", self.errors(py)?.repr()?))
+    }
+}
+
+impl PydanticMultipleErrors {
+    pub fn into_val_error(self, input: impl ToErrorValue) -> ValError {
+        let input_value = input.to_error_value();
+        let line_errors = self
+            .errors
+            .into_iter()
+            .map(|(location, error_type)| ValLineError {
+                error_type,
+                location,
+                input_value: input_value.clone(),
+            })
+            .collect();
+        ValError::LineErrors(line_errors)
+    }
+}
+
+/// The error type of a `PydanticCustomError` or `PydanticKnownError`
+fn error_type(error: &Bound<'_, PyAny>) -> PyResult<ErrorType> {
+    if let Ok(custom_error) = error.extract::<PydanticCustomError>() {
+        Ok(ErrorType::CustomError {
+            error_type: custom_error.error_type,
+            message_template: custom_error.message_template,
+            context: custom_error.context,
+        })
+    } else if let Ok(known_error) = error.extract::<PydanticKnownError>() {
+        Ok(known_error.error_type)
+    } else {
+        Err(PyTypeError::new_err(
+            "errors must be `PydanticCustomError` or `PydanticKnownError` instances or `(loc, error)` tuples",
+        ))
+    }
+}
@@ -0,0 +1,60 @@
+use std::sync::OnceLock;
+
+use pyo3::exceptions::PyRuntimeError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::validators::exact_schema_fingerprint;
+
+/// Whether a `SchemaValidator` or `SchemaSerializer` has been frozen with `freeze()`, and with `audit` the schema
+/// fingerprint it's audited against, set once so it's safe to read from any thread.
+///
+/// This is an audit of the schema and config, not a guarantee, nothing stops them being mutated after `freeze()`
+#[derive(Debug, Default)]
+pub(crate) struct Frozen(OnceLock<FrozenState>);
+
+#[derive(Debug)]
+struct FrozenState {
+    // the fingerprint of the schema and config when frozen, only with `audit`
+    audit_fingerprint: Option<u64>,
+}
+
+impl Frozen {
+    /// Freeze with the current schema and config, freezing again has no effect
+    pub fn freeze(&self, schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>, audit: bool) -> PyResult<()> {
+        if self.is_frozen() {
+            return Ok(());
+        }
+        let audit_fingerprint = match audit {
+            true => Some(exact_schema_fingerprint(schema, config)?),
+            false => None,
+        };
+        // if another thread froze first its state is kept
+        let _ = self.0.set(FrozenState { audit_fingerprint });
+        Ok(())
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.0.get().is_some()
+    }
+
+    /// With `audit`, check the schema and config haven't been mutated since `freeze()`, `type_name` is the name of
+    /// the frozen class for the error
+    pub fn audit(
+        &self,
+        schema: &Bound<'_, PyAny>,
+        config: Option<&Bound<'_, PyDict>>,
+        type_name: &str,
+    ) -> PyResult<()> {
+        let Some(expected) = self.0.get().and_then(|state| state.audit_fingerprint) else {
+            return Ok(());
+        };
+        if exact_schema_fingerprint(schema, config)? == expected {
+            Ok(())
+        } else {
+            Err(PyRuntimeError::new_err(format!(
+                "The schema or config of a frozen `{type_name}` was mutated after `freeze()`"
+            )))
+        }
+    }
+}
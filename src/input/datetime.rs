@@ -3,6 +3,7 @@ use pyo3::prelude::*;
 
 use pyo3::exceptions::PyValueError;
 use pyo3::pyclass::CompareOp;
+use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDate, PyDateTime, PyDelta, PyDeltaAccess, PyDict, PyTime, PyTzInfo};
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 use speedate::{Date, DateTime, Duration, ParseError, Time, TimeConfig};
@@ -226,6 +227,9 @@ impl<'a> EitherTime<'a> {
 
 fn time_as_tzinfo<'py>(py: Python<'py>, time: &Time) -> PyResult<Option<Bound<'py, PyTzInfo>>> {
     match time.tz_offset {
+        Some(0) => Ok(Some(
+            TzInfo::utc_singleton(py)?.bind(py).clone().into_any().downcast_into()?,
+        )),
         Some(offset) => {
             let tz_info: TzInfo = offset.try_into()?;
             Ok(Some(Bound::new(py, tz_info)?.into_any().downcast_into()?))
@@ -509,6 +513,15 @@ pub struct TzInfo {
     seconds: i32,
 }
 
+static UTC: GILOnceCell<Py<TzInfo>> = GILOnceCell::new();
+
+impl TzInfo {
+    /// The `TzInfo.utc` instance, shared by every datetime and time validated with a zero offset
+    fn utc_singleton(py: Python<'_>) -> PyResult<&'_ Py<Self>> {
+        UTC.get_or_try_init(py, || Py::new(py, Self { seconds: 0 }))
+    }
+}
+
 #[pymethods]
 impl TzInfo {
     #[new]
@@ -516,6 +529,11 @@ impl TzInfo {
         Self::try_from(seconds.trunc() as i32)
     }
 
+    #[classattr]
+    fn utc(py: Python) -> PyResult<Py<Self>> {
+        Self::utc_singleton(py).map(|utc| utc.clone_ref(py))
+    }
+
     fn utcoffset<'py>(&self, py: Python<'py>, _dt: &Bound<'_, PyAny>) -> PyResult<Bound<'py, PyDelta>> {
         PyDelta::new_bound(py, 0, self.seconds, 0, true)
     }
@@ -578,8 +596,13 @@ impl TzInfo {
         }
     }
 
-    fn __deepcopy__(&self, py: Python, _memo: &Bound<'_, PyDict>) -> PyResult<Py<Self>> {
-        Py::new(py, self.clone())
+    // tzinfos are immutable, so copies are the tzinfo itself
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: &Bound<'_, PyDict>) -> Bound<'py, Self> {
+        slf
     }
 
     pub fn __reduce__(&self, py: Python) -> PyResult<PyObject> {
@@ -63,9 +63,10 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    type Arguments<'a> = JsonArgs<'a, 'data>
+    type Arguments<'a>
+        = JsonArgs<'a, 'data>
     where
-        Self: 'a,;
+        Self: 'a;
 
     fn validate_args(&self) -> ValResult<JsonArgs<'_, 'data>> {
         match self {
@@ -75,6 +76,30 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
+    fn validate_args_envelope(&self, args_key: &str, kwargs_key: &str) -> ValResult<JsonArgs<'_, 'data>> {
+        match self {
+            JsonValue::Object(object) if object.get(args_key).is_some() || object.get(kwargs_key).is_some() => {
+                let mut args = JsonArgs::new(None, None);
+                for (key, value) in LazyIndexMap::iter(object) {
+                    let key = key.as_ref();
+                    match value {
+                        JsonValue::Array(array) if key == args_key => args.args = Some(array),
+                        JsonValue::Object(object) if key == kwargs_key => args.kwargs = Some(object),
+                        _ if key == args_key => {
+                            return Err(ValError::new_with_loc(ErrorTypeDefaults::ListType, value, args_key))
+                        }
+                        _ if key == kwargs_key => {
+                            return Err(ValError::new_with_loc(ErrorTypeDefaults::DictType, value, kwargs_key))
+                        }
+                        _ => return Err(ValError::new_with_loc(ErrorTypeDefaults::ExtraForbidden, value, key)),
+                    }
+                }
+                Ok(args)
+            }
+            _ => self.validate_args(),
+        }
+    }
+
     fn validate_dataclass_args<'a>(&'a self, class_name: &str) -> ValResult<JsonArgs<'a, 'data>> {
         match self {
             JsonValue::Object(object) => Ok(JsonArgs::new(None, Some(object))),
@@ -168,7 +193,10 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    type Dict<'a> = &'a JsonObject<'data> where Self: 'a;
+    type Dict<'a>
+        = &'a JsonObject<'data>
+    where
+        Self: 'a;
 
     fn validate_dict(&self, _strict: bool) -> ValResult<Self::Dict<'_>> {
         match self {
@@ -181,7 +209,10 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         self.validate_dict(false)
     }
 
-    type List<'a> = &'a JsonArray<'data> where Self: 'a;
+    type List<'a>
+        = &'a JsonArray<'data>
+    where
+        Self: 'a;
 
     fn validate_list(&self, _strict: bool) -> ValMatch<&JsonArray<'data>> {
         match self {
@@ -190,7 +221,10 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    type Tuple<'a> = &'a JsonArray<'data> where Self: 'a;
+    type Tuple<'a>
+        = &'a JsonArray<'data>
+    where
+        Self: 'a;
 
     fn validate_tuple(&self, _strict: bool) -> ValMatch<&JsonArray<'data>> {
         // just as in set's case, List has to be allowed
@@ -200,7 +234,10 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
         }
     }
 
-    type Set<'a> = &'a JsonArray<'data> where Self: 'a;
+    type Set<'a>
+        = &'a JsonArray<'data>
+    where
+        Self: 'a;
 
     fn validate_set(&self, _strict: bool) -> ValMatch<&JsonArray<'data>> {
         // we allow a list here since otherwise it would be impossible to create a set from JSON
@@ -448,14 +485,28 @@ impl<'data> BorrowInput<'_> for JsonValue<'data> {
     }
 }
 
+/// values found by a `LookupKey` are borrowed, unless they're gathered by a wildcard in an alias path
+impl<'data> BorrowInput<'_> for Cow<'_, JsonValue<'data>> {
+    type Input = JsonValue<'data>;
+    fn borrow_input(&self) -> &Self::Input {
+        self
+    }
+}
+
 fn string_to_vec(s: &str) -> JsonArray<'static> {
     JsonArray::new(s.chars().map(|c| JsonValue::Str(c.to_string().into())).collect())
 }
 
 impl<'py, 'data> ValidatedDict<'py> for &'_ JsonObject<'data> {
-    type Key<'a> = &'a str where Self: 'a;
+    type Key<'a>
+        = &'a str
+    where
+        Self: 'a;
 
-    type Item<'a> = &'a JsonValue<'data> where Self: 'a;
+    type Item<'a>
+        = Cow<'a, JsonValue<'data>>
+    where
+        Self: 'a;
 
     fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
         key.json_get(self)
@@ -469,7 +520,7 @@ impl<'py, 'data> ValidatedDict<'py> for &'_ JsonObject<'data> {
         &'a self,
         consumer: impl ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
     ) -> ValResult<R> {
-        Ok(consumer.consume_iterator(LazyIndexMap::iter(self).map(|(k, v)| Ok((k.as_ref(), v)))))
+        Ok(consumer.consume_iterator(LazyIndexMap::iter(self).map(|(k, v)| Ok((k.as_ref(), Cow::Borrowed(v))))))
     }
 }
 
@@ -532,7 +583,10 @@ impl<'a, 'data> Arguments<'_> for JsonArgs<'a, 'data> {
 }
 
 impl<'data> PositionalArgs<'_> for [JsonValue<'data>] {
-    type Item<'a> = &'a JsonValue<'data> where Self: 'a;
+    type Item<'a>
+        = &'a JsonValue<'data>
+    where
+        Self: 'a;
 
     fn len(&self) -> usize {
         <[JsonValue]>::len(self)
@@ -546,8 +600,14 @@ impl<'data> PositionalArgs<'_> for [JsonValue<'data>] {
 }
 
 impl<'data> KeywordArgs<'_> for JsonObject<'data> {
-    type Key<'a> = &'a str where Self: 'a;
-    type Item<'a> = &'a JsonValue<'data> where Self: 'a;
+    type Key<'a>
+        = &'a str
+    where
+        Self: 'a;
+    type Item<'a>
+        = Cow<'a, JsonValue<'data>>
+    where
+        Self: 'a;
 
     fn len(&self) -> usize {
         LazyIndexMap::len(self)
@@ -556,6 +616,6 @@ impl<'data> KeywordArgs<'_> for JsonObject<'data> {
         key.json_get(self)
     }
     fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>> {
-        LazyIndexMap::iter(self).map(|(k, v)| Ok((k.as_ref(), v)))
+        LazyIndexMap::iter(self).map(|(k, v)| Ok((k.as_ref(), Cow::Borrowed(v))))
     }
 }
@@ -10,7 +10,7 @@ use pyo3::types::{
     PyMapping, PySet, PyString, PyTime, PyTuple,
 };
 
-use pyo3::PyTypeCheck;
+use pyo3::{PyTypeCheck, PyTypeInfo};
 use speedate::MicrosecondsPrecisionOverflowBehavior;
 
 use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
@@ -90,11 +90,16 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
             .map(|dict| dict.to_owned().unbind().into_bound(py))
     }
 
-    type Arguments<'a> = PyArgs<'py> where Self: 'a;
+    type Arguments<'a>
+        = PyArgs<'py>
+    where
+        Self: 'a;
 
     fn validate_args(&self) -> ValResult<PyArgs<'py>> {
         if let Ok(dict) = self.downcast::<PyDict>() {
             Ok(PyArgs::new(None, Some(dict.clone())))
+        } else if let Some(args) = core_input_args(self)? {
+            Ok(args)
         } else if let Ok(args_kwargs) = self.extract::<ArgsKwargs>() {
             let args = args_kwargs.args.into_bound(self.py());
             let kwargs = args_kwargs.kwargs.map(|d| d.into_bound(self.py()));
@@ -111,6 +116,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     fn validate_dataclass_args<'a>(&'a self, class_name: &str) -> ValResult<PyArgs<'py>> {
         if let Ok(dict) = self.downcast::<PyDict>() {
             Ok(PyArgs::new(None, Some(dict.clone())))
+        } else if let Some(args) = core_input_args(self)? {
+            Ok(args)
         } else if let Ok(args_kwargs) = self.extract::<ArgsKwargs>() {
             let args = args_kwargs.args.into_bound(self.py());
             let kwargs = args_kwargs.kwargs.map(|d| d.into_bound(self.py()));
@@ -134,6 +141,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
             // force to a rust string to make sure behavior is consistent whether or not we go via a
             // rust string in StrConstrainedValidator - e.g. to_lower
             return Ok(ValidationMatch::strict(py_string_str(py_str)?.into()));
+        } else if let Some(py_str) = core_input_exact::<PyString>(self)? {
+            return Ok(ValidationMatch::lax(py_str.into()));
         }
 
         'lax: {
@@ -201,6 +210,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>> {
         if let Ok(bool) = self.downcast::<PyBool>() {
             return Ok(ValidationMatch::exact(bool.is_true()));
+        } else if let Some(bool) = core_input_exact::<PyBool>(self)? {
+            return Ok(ValidationMatch::lax(bool.is_true()));
         }
 
         if !strict {
@@ -237,6 +248,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
 
             // force to an int to upcast to a pure python int
             return EitherInt::upcast(self).map(|either_int| ValidationMatch::new(either_int, exactness));
+        } else if let Some(int) = core_input_exact::<PyInt>(self)? {
+            return Ok(ValidationMatch::lax(EitherInt::Py(int.into_any())));
         }
 
         'lax: {
@@ -280,6 +293,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     fn validate_float(&self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
         if let Ok(float) = self.downcast_exact::<PyFloat>() {
             return Ok(ValidationMatch::exact(EitherFloat::Py(float.clone())));
+        } else if let Some(float) = core_input_exact::<PyFloat>(self)? {
+            return Ok(ValidationMatch::lax(EitherFloat::Py(float)));
         }
 
         if !strict {
@@ -348,11 +363,16 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         }
     }
 
-    type Dict<'a> = GenericPyMapping<'a, 'py> where Self: 'a;
+    type Dict<'a>
+        = GenericPyMapping<'a, 'py>
+    where
+        Self: 'a;
 
     fn strict_dict<'a>(&'a self) -> ValResult<GenericPyMapping<'a, 'py>> {
         if let Ok(dict) = self.downcast::<PyDict>() {
             Ok(GenericPyMapping::Dict(dict))
+        } else if let Some(dict) = core_input_exact::<PyDict>(self)? {
+            Ok(GenericPyMapping::OwnedDict(dict))
         } else {
             Err(ValError::new(ErrorTypeDefaults::DictType, self))
         }
@@ -361,6 +381,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     fn lax_dict<'a>(&'a self) -> ValResult<GenericPyMapping<'a, 'py>> {
         if let Ok(dict) = self.downcast::<PyDict>() {
             Ok(GenericPyMapping::Dict(dict))
+        } else if let Some(dict) = core_input_exact::<PyDict>(self)? {
+            Ok(GenericPyMapping::OwnedDict(dict))
         } else if let Ok(mapping) = self.downcast::<PyMapping>() {
             Ok(GenericPyMapping::Mapping(mapping))
         } else {
@@ -377,6 +399,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
             // if from_attributes, first try a dict, then mapping then from_attributes
             if let Ok(dict) = self.downcast::<PyDict>() {
                 return Ok(GenericPyMapping::Dict(dict));
+            } else if let Some(dict) = core_input_exact::<PyDict>(self)? {
+                return Ok(GenericPyMapping::OwnedDict(dict));
             } else if !strict {
                 if let Ok(mapping) = self.downcast::<PyMapping>() {
                     return Ok(GenericPyMapping::Mapping(mapping));
@@ -402,11 +426,16 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         }
     }
 
-    type List<'a> = PySequenceIterable<'a, 'py> where Self: 'a;
+    type List<'a>
+        = PySequenceIterable<'a, 'py>
+    where
+        Self: 'a;
 
     fn validate_list<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(list) = self.downcast::<PyList>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::List(list)));
+        } else if let Some(iterable) = core_input_sequence(self)? {
+            return Ok(ValidationMatch::lax(iterable));
         } else if !strict {
             if let Ok(other) = extract_sequence_iterable(self) {
                 return Ok(ValidationMatch::lax(other));
@@ -416,11 +445,16 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         Err(ValError::new(ErrorTypeDefaults::ListType, self))
     }
 
-    type Tuple<'a> = PySequenceIterable<'a, 'py> where Self: 'a;
+    type Tuple<'a>
+        = PySequenceIterable<'a, 'py>
+    where
+        Self: 'a;
 
     fn validate_tuple<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(tup) = self.downcast::<PyTuple>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::Tuple(tup)));
+        } else if let Some(iterable) = core_input_sequence(self)? {
+            return Ok(ValidationMatch::lax(iterable));
         } else if !strict {
             if let Ok(other) = extract_sequence_iterable(self) {
                 return Ok(ValidationMatch::lax(other));
@@ -430,11 +464,16 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
         Err(ValError::new(ErrorTypeDefaults::TupleType, self))
     }
 
-    type Set<'a> = PySequenceIterable<'a, 'py> where Self: 'a;
+    type Set<'a>
+        = PySequenceIterable<'a, 'py>
+    where
+        Self: 'a;
 
     fn validate_set<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(set) = self.downcast::<PySet>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::Set(set)));
+        } else if let Some(iterable) = core_input_sequence(self)? {
+            return Ok(ValidationMatch::lax(iterable));
         } else if !strict {
             if let Ok(other) = extract_sequence_iterable(self) {
                 return Ok(ValidationMatch::lax(other));
@@ -447,6 +486,8 @@ impl<'py> Input<'py> for Bound<'py, PyAny> {
     fn validate_frozenset<'a>(&'a self, strict: bool) -> ValMatch<PySequenceIterable<'a, 'py>> {
         if let Ok(frozenset) = self.downcast::<PyFrozenSet>() {
             return Ok(ValidationMatch::exact(PySequenceIterable::FrozenSet(frozenset)));
+        } else if let Some(iterable) = core_input_sequence(self)? {
+            return Ok(ValidationMatch::lax(iterable));
         } else if !strict {
             if let Ok(other) = extract_sequence_iterable(self) {
                 return Ok(ValidationMatch::lax(other));
@@ -611,6 +652,60 @@ impl<'py> BorrowInput<'py> for Borrowed<'_, 'py, PyAny> {
     }
 }
 
+/// The input `obj` stands for, from its `__get_pydantic_core_input__` method, e.g. the fields of an ORM row or
+/// protobuf message as a dict, used before other coercions. The method is looked up on the type so objects with
+/// a catch-all `__getattr__` aren't mistaken for implementing it, and skipped for the builtin types which commonly
+/// reach the lax coercions so they don't pay for a failed lookup.
+fn core_input<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = obj.py();
+    if obj.is_instance_of::<PyString>()
+        || obj.is_instance_of::<PyBytes>()
+        || obj.is_instance_of::<PyInt>()
+        || obj.is_instance_of::<PyFloat>()
+        || PyAnyMethods::is_none(obj)
+    {
+        return Ok(None);
+    }
+    match obj.get_type().getattr(intern!(py, "__get_pydantic_core_input__")) {
+        Ok(method) if !obj.is_instance_of::<PyType>() => method.call1((obj,)).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// `core_input` if it's exactly a `T`
+fn core_input_exact<'py, T: PyTypeInfo>(obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, T>>> {
+    Ok(core_input(obj)?.and_then(|input| input.downcast_into_exact::<T>().ok()))
+}
+
+/// `core_input` if it's a list, tuple, set or frozenset
+fn core_input_sequence<'a, 'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<PySequenceIterable<'a, 'py>>> {
+    match core_input(obj)? {
+        Some(input)
+            if input.is_instance_of::<PyList>()
+                || input.is_instance_of::<PyTuple>()
+                || input.is_instance_of::<PySet>()
+                || input.is_instance_of::<PyFrozenSet>() =>
+        {
+            Ok(Some(PySequenceIterable::Iterator(input.iter()?)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// `core_input` as arguments if it's a dict of keyword arguments or a tuple of positional arguments
+fn core_input_args<'py>(obj: &Bound<'py, PyAny>) -> PyResult<Option<PyArgs<'py>>> {
+    let Some(input) = core_input(obj)? else {
+        return Ok(None);
+    };
+    if let Ok(dict) = input.downcast::<PyDict>() {
+        Ok(Some(PyArgs::new(None, Some(dict.clone()))))
+    } else if let Ok(tuple) = input.downcast::<PyTuple>() {
+        Ok(Some(PyArgs::new(Some(tuple.clone()), None)))
+    } else {
+        Ok(None)
+    }
+}
+
 /// Best effort check of whether it's likely to make sense to inspect obj for attributes and iterate over it
 /// with `obj.dir()`
 fn from_attributes_applicable(obj: &Bound<'_, PyAny>) -> bool {
@@ -690,7 +785,10 @@ impl<'py> Arguments<'py> for PyArgs<'py> {
 }
 
 impl<'py> PositionalArgs<'py> for PyPosArgs<'py> {
-    type Item<'a> = Borrowed<'a, 'py, PyAny> where Self: 'a;
+    type Item<'a>
+        = Borrowed<'a, 'py, PyAny>
+    where
+        Self: 'a;
 
     fn len(&self) -> usize {
         self.0.len()
@@ -706,11 +804,13 @@ impl<'py> PositionalArgs<'py> for PyPosArgs<'py> {
 }
 
 impl<'py> KeywordArgs<'py> for PyKwargs<'py> {
-    type Key<'a> = Bound<'py, PyAny>
+    type Key<'a>
+        = Bound<'py, PyAny>
     where
         Self: 'a;
 
-    type Item<'a> = Bound<'py, PyAny>
+    type Item<'a>
+        = Bound<'py, PyAny>
     where
         Self: 'a;
 
@@ -735,14 +835,18 @@ pub enum GenericPyMapping<'a, 'py> {
     Dict(&'a Bound<'py, PyDict>),
     Mapping(&'a Bound<'py, PyMapping>),
     GetAttr(Bound<'py, PyAny>, Option<Bound<'py, PyDict>>),
+    // a dict from `__get_pydantic_core_input__`
+    OwnedDict(Bound<'py, PyDict>),
 }
 
 impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
-    type Key<'a> = Bound<'py, PyAny>
+    type Key<'a>
+        = Bound<'py, PyAny>
     where
         Self: 'a;
 
-    type Item<'a> = Bound<'py, PyAny>
+    type Item<'a>
+        = Bound<'py, PyAny>
     where
         Self: 'a;
 
@@ -754,6 +858,7 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
             Self::Dict(dict) => key.py_get_dict_item(dict),
             Self::Mapping(mapping) => key.py_get_mapping_item(mapping),
             Self::GetAttr(obj, dict) => key.py_get_attr(obj, dict.as_ref()),
+            Self::OwnedDict(dict) => key.py_get_dict_item(dict),
         }
     }
 
@@ -764,6 +869,7 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
     fn as_py_dict(&self) -> Option<&Bound<'py, PyDict>> {
         match self {
             Self::Dict(dict) => Some(dict),
+            Self::OwnedDict(dict) => Some(dict),
             _ => None,
         }
     }
@@ -774,6 +880,7 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
     ) -> ValResult<R> {
         match self {
             Self::Dict(dict) => Ok(consumer.consume_iterator(dict.iter().map(Ok))),
+            Self::OwnedDict(dict) => Ok(consumer.consume_iterator(dict.iter().map(Ok))),
             Self::Mapping(mapping) => Ok(consumer.consume_iterator(iterate_mapping_items(mapping)?)),
             Self::GetAttr(obj, _) => Ok(consumer.consume_iterator(iterate_attributes(obj)?)),
         }
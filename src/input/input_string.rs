@@ -80,7 +80,10 @@ impl<'py> Input<'py> for StringMapping<'py> {
         None
     }
 
-    type Arguments<'a> = StringMappingDict<'py> where Self: 'a;
+    type Arguments<'a>
+        = StringMappingDict<'py>
+    where
+        Self: 'a;
 
     fn validate_args(&self) -> ValResult<StringMappingDict<'py>> {
         // do we want to support this?
@@ -140,7 +143,10 @@ impl<'py> Input<'py> for StringMapping<'py> {
         }
     }
 
-    type Dict<'a> = StringMappingDict<'py> where Self: 'a;
+    type Dict<'a>
+        = StringMappingDict<'py>
+    where
+        Self: 'a;
 
     fn strict_dict(&self) -> ValResult<StringMappingDict<'py>> {
         match self {
@@ -149,19 +155,28 @@ impl<'py> Input<'py> for StringMapping<'py> {
         }
     }
 
-    type List<'a> = Never where Self: 'a;
+    type List<'a>
+        = Never
+    where
+        Self: 'a;
 
     fn validate_list(&self, _strict: bool) -> ValMatch<Never> {
         Err(ValError::new(ErrorTypeDefaults::ListType, self))
     }
 
-    type Tuple<'a> = Never where Self: 'a;
+    type Tuple<'a>
+        = Never
+    where
+        Self: 'a;
 
     fn validate_tuple(&self, _strict: bool) -> ValMatch<Never> {
         Err(ValError::new(ErrorTypeDefaults::TupleType, self))
     }
 
-    type Set<'a> = Never where Self: 'a;
+    type Set<'a>
+        = Never
+    where
+        Self: 'a;
 
     fn validate_set(&self, _strict: bool) -> ValMatch<Never> {
         Err(ValError::new(ErrorTypeDefaults::SetType, self))
@@ -242,11 +257,13 @@ impl<'py> Arguments<'py> for StringMappingDict<'py> {
 }
 
 impl<'py> KeywordArgs<'py> for StringMappingDict<'py> {
-    type Key<'a> = StringMapping<'py>
+    type Key<'a>
+        = StringMapping<'py>
     where
         Self: 'a;
 
-    type Item<'a> = StringMapping<'py>
+    type Item<'a>
+        = StringMapping<'py>
     where
         Self: 'a;
 
@@ -266,11 +283,13 @@ impl<'py> KeywordArgs<'py> for StringMappingDict<'py> {
 }
 
 impl<'py> ValidatedDict<'py> for StringMappingDict<'py> {
-    type Key<'a> = StringMapping<'py>
+    type Key<'a>
+        = StringMapping<'py>
     where
         Self: 'a;
 
-    type Item<'a> = StringMapping<'py>
+    type Item<'a>
+        = StringMapping<'py>
     where
         Self: 'a;
     fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
@@ -12,7 +12,7 @@ use pyo3::intern;
 use pyo3::prelude::*;
 #[cfg(not(PyPy))]
 use pyo3::types::PyFunction;
-use pyo3::types::{PyBytes, PyFloat, PyFrozenSet, PyIterator, PyMapping, PySet, PyString};
+use pyo3::types::{PyBytes, PyDict, PyFloat, PyFrozenSet, PyIterator, PyList, PyMapping, PySet, PyString};
 
 use serde::{ser::Error, Serialize, Serializer};
 
@@ -20,8 +20,8 @@ use crate::errors::{
     py_err_string, ErrorType, ErrorTypeDefaults, InputValue, ToErrorValue, ValError, ValLineError, ValResult,
 };
 use crate::py_gc::PyGcTraverse;
-use crate::tools::{extract_i64, new_py_string, py_err};
-use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
+use crate::tools::{extract_i64, new_py_string, py_err, SchemaDict};
+use crate::validators::{CombinedValidator, Exactness, PathSegment, ValidationState, Validator};
 
 use super::{py_error_on_minusone, BorrowInput, Input};
 
@@ -48,8 +48,16 @@ impl<T> ValidationMatch<T> {
         (self.1 == Exactness::Exact).then_some(self.0)
     }
 
-    pub fn unpack(self, state: &mut ValidationState) -> T {
+    pub fn unpack<'py>(
+        self,
+        state: &mut ValidationState<'_, 'py>,
+        input: &(impl Input<'py> + ?Sized),
+        to_type: &'static str,
+    ) -> T {
         state.floor_exactness(self.1);
+        if self.1 == Exactness::Lax {
+            state.record_coercion(input, to_type);
+        }
         self.0
     }
 
@@ -58,6 +66,26 @@ impl<T> ValidationMatch<T> {
     }
 }
 
+/// The number of invalid items after which validating a sequence stops rather than collecting every error, set by
+/// `max_error_items`, `fail_fast` is the same as `max_error_items=1`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MaxErrorItems(Option<usize>);
+
+impl MaxErrorItems {
+    pub(crate) fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        if schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false) {
+            Ok(Self(Some(1)))
+        } else {
+            Ok(Self(schema.get_as(intern!(py, "max_error_items"))?))
+        }
+    }
+
+    pub(crate) fn reached(self, invalid_items: usize) -> bool {
+        invalid_items > 0 && self.0.is_some_and(|max_error_items| invalid_items >= max_error_items)
+    }
+}
+
 pub struct MaxLengthCheck<'a, INPUT: ?Sized> {
     current_length: usize,
     max_length: Option<usize>,
@@ -124,13 +152,16 @@ pub(crate) fn validate_iter_to_vec<'py>(
     mut max_length_check: MaxLengthCheck<'_, impl Input<'py> + ?Sized>,
     validator: &CombinedValidator,
     state: &mut ValidationState<'_, 'py>,
-    fail_fast: bool,
+    max_error_items: MaxErrorItems,
 ) -> ValResult<Vec<PyObject>> {
     let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
+    let mut invalid_items = 0;
     for (index, item_result) in iter.enumerate() {
+        state.check_deadline()?;
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        let segment = PathSegment::Loc(index.into());
+        match state.with_path(segment, |state| validator.validate(py, item.borrow_input(), state)) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
@@ -138,7 +169,8 @@ pub(crate) fn validate_iter_to_vec<'py>(
             Err(ValError::LineErrors(line_errors)) => {
                 max_length_check.incr()?;
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
-                if fail_fast {
+                invalid_items += 1;
+                if max_error_items.reached(invalid_items) {
                     break;
                 }
             }
@@ -184,6 +216,39 @@ impl BuildSet for Bound<'_, PyFrozenSet> {
     }
 }
 
+/// Collects the unique items in the order they're first seen, for `ordered` sets
+pub struct OrderedSetBuilder<'py> {
+    seen: Bound<'py, PySet>,
+    items: Bound<'py, PyList>,
+}
+
+impl<'py> OrderedSetBuilder<'py> {
+    pub fn new(py: Python<'py>) -> PyResult<Self> {
+        Ok(Self {
+            seen: PySet::empty_bound(py)?,
+            items: PyList::empty_bound(py),
+        })
+    }
+
+    pub fn into_list(self) -> Bound<'py, PyList> {
+        self.items
+    }
+}
+
+impl BuildSet for OrderedSetBuilder<'_> {
+    fn build_add(&self, item: PyObject) -> PyResult<()> {
+        if !self.seen.contains(&item)? {
+            self.seen.add(&item)?;
+            self.items.append(item)?;
+        }
+        Ok(())
+    }
+
+    fn build_len(&self) -> usize {
+        self.items.len()
+    }
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn validate_iter_to_set<'py>(
     py: Python<'py>,
@@ -194,12 +259,15 @@ pub(crate) fn validate_iter_to_set<'py>(
     max_length: Option<usize>,
     validator: &CombinedValidator,
     state: &mut ValidationState<'_, 'py>,
-    fail_fast: bool,
+    max_error_items: MaxErrorItems,
 ) -> ValResult<()> {
     let mut errors: Vec<ValLineError> = Vec::new();
+    let mut invalid_items = 0;
     for (index, item_result) in iter.enumerate() {
+        state.check_deadline()?;
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
-        match validator.validate(py, item.borrow_input(), state) {
+        let segment = PathSegment::Loc(index.into());
+        match state.with_path(segment, |state| validator.validate(py, item.borrow_input(), state)) {
             Ok(item) => {
                 set.build_add(item)?;
                 if let Some(max_length) = max_length {
@@ -221,11 +289,12 @@ pub(crate) fn validate_iter_to_set<'py>(
             }
             Err(ValError::LineErrors(line_errors)) => {
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                invalid_items += 1;
             }
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
-        if fail_fast && !errors.is_empty() {
+        if max_error_items.reached(invalid_items) {
             break;
         }
     }
@@ -16,8 +16,12 @@ mod argument_markers;
 mod build_tools;
 mod definitions;
 mod errors;
+mod frozen;
 mod input;
 mod lookup_key;
+mod native;
+mod phone;
+mod read_only;
 mod recursion_guard;
 mod serializers;
 mod tools;
@@ -26,17 +30,25 @@ mod validators;
 
 // required for benchmarks
 pub use self::input::TzInfo;
+pub use self::phone::PyPhoneNumber;
 pub use self::url::{PyMultiHostUrl, PyUrl};
 pub use argument_markers::{ArgsKwargs, PydanticUndefinedType};
 pub use build_tools::SchemaError;
 pub use errors::{
-    list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
+    list_all_errors, PydanticCustomError, PydanticInternalError, PydanticKnownError, PydanticMultipleErrors,
+    PydanticOmit, PydanticUseDefault, PythonCallbackLimitExceeded, ValidationError, ValidationTimeout,
 };
+pub use native::{NativeTypeFn, NativeTypeVTable, NATIVE_TYPE_ABI_VERSION, NATIVE_TYPE_CAPSULE_NAME};
+pub use read_only::{ReadOnlyDict, ReadOnlyList};
 pub use serializers::{
-    to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
-    WarningsArg,
+    get_runtime_settings_var, register_format_backend, to_json, to_jsonable_python, unregister_format_backend,
+    ByAliasArg, CyclesMode, PyRuntimeSettings, PydanticSerializationError, PydanticSerializationUnexpectedValue,
+    SchemaSerializer, SubSerializer, WarningsArg,
+};
+pub use validators::{
+    get_coercion_audit_var, get_union_debug_var, register_string_format, unregister_string_format,
+    validate_core_schema, PySome, SchemaValidator, SubValidator,
 };
-pub use validators::{validate_core_schema, PySome, SchemaValidator};
 
 use crate::input::Input;
 
@@ -125,22 +137,39 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PydanticUndefinedType>()?;
     m.add_class::<PySome>()?;
     m.add_class::<SchemaValidator>()?;
+    m.add_class::<SubValidator>()?;
     m.add_class::<ValidationError>()?;
     m.add_class::<SchemaError>()?;
     m.add_class::<PydanticCustomError>()?;
     m.add_class::<PydanticKnownError>()?;
+    m.add_class::<PydanticMultipleErrors>()?;
     m.add_class::<PydanticOmit>()?;
     m.add_class::<PydanticUseDefault>()?;
+    m.add_class::<ValidationTimeout>()?;
+    m.add_class::<PythonCallbackLimitExceeded>()?;
+    m.add_class::<PydanticInternalError>()?;
     m.add_class::<PydanticSerializationError>()?;
     m.add_class::<PydanticSerializationUnexpectedValue>()?;
     m.add_class::<PyUrl>()?;
     m.add_class::<PyMultiHostUrl>()?;
+    m.add_class::<PyPhoneNumber>()?;
     m.add_class::<ArgsKwargs>()?;
+    m.add_class::<ReadOnlyDict>()?;
+    m.add_class::<ReadOnlyList>()?;
+    m.add("_coercion_audit_var", get_coercion_audit_var(py)?)?;
+    m.add("_union_debug_var", get_union_debug_var(py)?)?;
     m.add_class::<SchemaSerializer>()?;
+    m.add_class::<SubSerializer>()?;
+    m.add_class::<PyRuntimeSettings>()?;
+    m.add("_runtime_settings_var", get_runtime_settings_var(py)?)?;
     m.add_class::<TzInfo>()?;
     m.add_function(wrap_pyfunction!(to_json, m)?)?;
     m.add_function(wrap_pyfunction!(from_json, m)?)?;
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
+    m.add_function(wrap_pyfunction!(register_format_backend, m)?)?;
+    m.add_function(wrap_pyfunction!(unregister_format_backend, m)?)?;
+    m.add_function(wrap_pyfunction!(register_string_format, m)?)?;
+    m.add_function(wrap_pyfunction!(unregister_string_format, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
     m.add_function(wrap_pyfunction!(validate_core_schema, m)?)?;
     Ok(())
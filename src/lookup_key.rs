@@ -1,9 +1,11 @@
 use core::slice::Iter;
+use std::borrow::Cow;
 use std::fmt;
+use std::sync::Arc;
 
 use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList, PyMapping, PyString};
+use pyo3::types::{PyDict, PyList, PyMapping, PyString, PyTuple};
 
 use jiter::{JsonObject, JsonValue};
 
@@ -32,7 +34,8 @@ pub(crate) enum LookupKey {
         path2: LookupPath,
     },
     /// look up keys by one or more "paths" a path might be `['foo', 'bar']` to get `d.?foo.?bar`
-    /// ints are also supported to index arrays/lists/tuples and dicts with int keys
+    /// ints are also supported to index arrays/lists/tuples and dicts with int keys, `'*'` gathers the rest of
+    /// the path from each item of an array/list/tuple into a list, e.g. `['items', '*', 'id']`
     /// we reuse Location as the enum is the same, and the meaning is the same
     PathChoices(Vec<LookupPath>),
 }
@@ -89,6 +92,35 @@ impl LookupKey {
         }
     }
 
+    /// Look up any one of several keys, used for fields with several serialization aliases
+    /// and no `validation_alias`
+    pub fn from_choices(py: Python, keys: &Bound<'_, PyList>, alt_alias: Option<&str>) -> PyResult<Self> {
+        let mut locs = keys
+            .iter()
+            .map(|key| {
+                let key_py = key.downcast_into::<PyString>()?;
+                Ok(LookupPath::from_str(py, key_py.to_str()?, Some(key_py.clone())))
+            })
+            .collect::<PyResult<Vec<_>>>()?;
+        if locs.is_empty() {
+            return py_schema_err!("Lookup keys should have at least one element");
+        }
+        if let Some(alt_alias) = alt_alias {
+            locs.push(LookupPath::from_str(py, alt_alias, None));
+        }
+        Ok(Self::PathChoices(locs))
+    }
+
+    /// Look up a path of attributes, given as a list like an alias path or a dotted string like
+    /// `'profile.address.city'`, used for fields' `attribute_path` in `from_attributes` mode
+    pub fn from_attribute_path(py: Python, value: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let path = match value.downcast::<PyString>() {
+            Ok(dotted) => LookupPath::from_dotted(py, dotted.to_str()?)?,
+            Err(_) => LookupPath::from_list(value)?,
+        };
+        Ok(Self::PathChoices(vec![path]))
+    }
+
     pub fn from_string(py: Python, key: &str) -> Self {
         Self::simple(py, key, None)
     }
@@ -129,9 +161,7 @@ impl LookupKey {
             },
             Self::PathChoices(path_choices) => {
                 for path in path_choices {
-                    // iterate over the path and plug each value into the py_any from the last step, starting with dict
-                    // this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path.iter().try_fold((**dict).clone(), |d, loc| loc.py_get_item(&d)) {
+                    if let Some(v) = path.py_get_item(dict) {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -178,9 +208,7 @@ impl LookupKey {
             },
             Self::PathChoices(path_choices) => {
                 for path in path_choices {
-                    // iterate over the path and plug each value into the py_any from the last step, starting with dict
-                    // this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path.iter().try_fold((**dict).clone(), |d, loc| loc.py_get_item(&d)) {
+                    if let Some(v) = path.py_get_item(dict) {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -238,21 +266,12 @@ impl LookupKey {
                 },
             },
             Self::PathChoices(path_choices) => {
-                'outer: for path in path_choices {
-                    // similar to above, but using `py_get_attrs`, we can't use try_fold because of the extra Err
-                    // so we have to loop manually
-                    let mut v = obj.clone();
-                    for loc in path.iter() {
-                        v = match loc.py_get_attrs(&v) {
-                            Ok(Some(v)) => v,
-                            Ok(None) => {
-                                continue 'outer;
-                            }
-                            Err(e) => return Err(e),
-                        }
+                for path in path_choices {
+                    // similar to above, but using `py_get_attrs`
+                    if let Some(v) = path.py_get_attrs(obj)? {
+                        // Successfully found an item, return it
+                        return Ok(Some((path, v)));
                     }
-                    // Successfully found an item, return it
-                    return Ok(Some((path, v)));
                 }
                 // got to the end of path_choices, without a match, return None
                 Ok(None)
@@ -260,13 +279,14 @@ impl LookupKey {
         }
     }
 
+    /// values gathered by a wildcard in the path are owned, others are borrowed from `dict`
     pub fn json_get<'a, 'data, 's>(
         &'s self,
         dict: &'a JsonObject<'data>,
-    ) -> ValResult<Option<(&'s LookupPath, &'a JsonValue<'data>)>> {
+    ) -> ValResult<Option<(&'s LookupPath, Cow<'a, JsonValue<'data>>)>> {
         match self {
             Self::Simple { key, path, .. } => match dict.get(key.as_str()) {
-                Some(value) => Ok(Some((path, value))),
+                Some(value) => Ok(Some((path, Cow::Borrowed(value)))),
                 None => Ok(None),
             },
             Self::Choice {
@@ -276,27 +296,22 @@ impl LookupKey {
                 path2,
                 ..
             } => match dict.get(key1.as_str()) {
-                Some(value) => Ok(Some((path1, value))),
+                Some(value) => Ok(Some((path1, Cow::Borrowed(value)))),
                 None => match dict.get(key2.as_str()) {
-                    Some(value) => Ok(Some((path2, value))),
+                    Some(value) => Ok(Some((path2, Cow::Borrowed(value)))),
                     None => Ok(None),
                 },
             },
             Self::PathChoices(path_choices) => {
                 for path in path_choices {
-                    let mut path_iter = path.iter();
-
                     // first step is different from the rest as we already know dict is JsonObject
                     // because of above checks, we know that path should have at least one element, hence unwrap
-                    let v: &JsonValue = match path_iter.next().unwrap().json_obj_get(dict) {
-                        Some(v) => v,
-                        None => continue,
+                    let (first, rest) = path.0.split_first().unwrap();
+                    let Some(v) = first.json_obj_get(dict) else {
+                        continue;
                     };
 
-                    // similar to above
-                    // iterate over the path and plug each value into the JsonValue from the last step, starting with v
-                    // from the first step, this could just be a loop but should be somewhat faster with a functional design
-                    if let Some(v) = path_iter.try_fold(v, |d, loc| loc.json_get(d)) {
+                    if let Some(v) = json_get_path(rest, v) {
                         // Successfully found an item, return it
                         return Ok(Some((path, v)));
                     }
@@ -307,6 +322,15 @@ impl LookupKey {
         }
     }
 
+    /// The paths looked up, in the order they're tried
+    pub fn paths(&self) -> Vec<&LookupPath> {
+        match self {
+            Self::Simple { path, .. } => vec![path],
+            Self::Choice { path1, path2, .. } => vec![path1, path2],
+            Self::PathChoices(paths) => paths.iter().collect(),
+        }
+    }
+
     pub fn error(
         &self,
         error_type: ErrorType,
@@ -366,6 +390,21 @@ impl LookupPath {
         }
     }
 
+    fn from_dotted(py: Python, dotted: &str) -> PyResult<LookupPath> {
+        if dotted.split('.').any(str::is_empty) {
+            return py_schema_err!(
+                "Attribute path '{}' should be attribute names separated by dots",
+                dotted
+            );
+        }
+        Ok(Self(
+            dotted
+                .split('.')
+                .map(|attr| PathItem::S(attr.to_string(), PyString::new_bound(py, attr).into()))
+                .collect(),
+        ))
+    }
+
     pub fn apply_error_loc(&self, mut line_error: ValLineError, loc_by_alias: bool, field_name: &str) -> ValLineError {
         if loc_by_alias {
             for path_item in self.iter().rev() {
@@ -381,6 +420,21 @@ impl LookupPath {
         self.0.iter()
     }
 
+    /// The path as a list of keys and indexes, like a path in `validation_alias`
+    pub fn to_py_list<'py>(&self, py: Python<'py>) -> Bound<'py, PyList> {
+        PyList::new_bound(py, self.iter().map(|item| item.to_object(py)))
+    }
+
+    /// iterate over the path and get each item from the value from the last step, starting with `dict`
+    fn py_get_item<'py>(&self, dict: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+        py_get_path(&self.0, dict.clone(), &mut |item, v| Ok(item.py_get_item(v))).unwrap_or(None)
+    }
+
+    /// like `py_get_item`, but getting attributes of objects other than dicts
+    fn py_get_attrs<'py>(&self, obj: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        py_get_path(&self.0, obj.clone(), &mut PathItem::py_get_attrs)
+    }
+
     /// get the `str` from the first item in the path, note paths always have length > 0, and the first item
     /// is always a string
     pub fn first_key(&self) -> &str {
@@ -396,6 +450,8 @@ pub(crate) enum PathItem {
     /// integer key, used to get items from a list, tuple OR a dict with int keys `Dict[int, ...]` (python only)
     Pos(usize),
     Neg(usize),
+    /// `'*'`, gathers the rest of the path from each item of a list, tuple or array into a list
+    Wildcard,
 }
 
 impl fmt::Display for PathItem {
@@ -404,6 +460,7 @@ impl fmt::Display for PathItem {
             Self::S(key, _) => write!(f, "'{key}'"),
             Self::Pos(key) => write!(f, "{key}"),
             Self::Neg(key) => write!(f, "-{key}"),
+            Self::Wildcard => write!(f, "'*'"),
         }
     }
 }
@@ -417,6 +474,7 @@ impl ToPyObject for PathItem {
                 let neg_value = -(*val as i64);
                 neg_value.to_object(py)
             }
+            Self::Wildcard => WILDCARD.to_object(py),
         }
     }
 }
@@ -425,7 +483,13 @@ impl PathItem {
     pub fn from_py(index: usize, obj: &Bound<'_, PyAny>) -> PyResult<Self> {
         if let Ok(py_str_key) = obj.downcast::<PyString>() {
             let str_key = py_str_key.to_str()?.to_string();
-            Ok(Self::S(str_key, py_str_key.clone().into()))
+            if str_key != WILDCARD {
+                Ok(Self::S(str_key, py_str_key.clone().into()))
+            } else if index == 0 {
+                py_err!(PyTypeError; "The first item in an alias path should not be a wildcard")
+            } else {
+                Ok(Self::Wildcard)
+            }
         } else if let Ok(usize_key) = obj.extract::<usize>() {
             if index == 0 {
                 py_err!(PyTypeError; "The first item in an alias path should be a string")
@@ -487,7 +551,7 @@ impl PathItem {
                         None
                     }
                 }
-                Self::S(..) => None,
+                Self::S(..) | Self::Wildcard => None,
             },
             _ => None,
         }
@@ -501,6 +565,59 @@ impl PathItem {
     }
 }
 
+const WILDCARD: &str = "*";
+
+/// get `path` from `value` with `get`, gathering the rest of the path from each item into a list at wildcards
+fn py_get_path<'py>(
+    path: &[PathItem],
+    value: Bound<'py, PyAny>,
+    get: &mut impl FnMut(&PathItem, &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>>,
+) -> PyResult<Option<Bound<'py, PyAny>>> {
+    match path.split_first() {
+        None => Ok(Some(value)),
+        Some((PathItem::Wildcard, rest)) => {
+            let items = if let Ok(list) = value.downcast::<PyList>() {
+                list.iter().collect::<Vec<_>>()
+            } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+                tuple.iter().collect()
+            } else {
+                return Ok(None);
+            };
+            let mut gathered = Vec::with_capacity(items.len());
+            for item in items {
+                match py_get_path(rest, item, get)? {
+                    Some(v) => gathered.push(v),
+                    // every item must have the rest of the path
+                    None => return Ok(None),
+                }
+            }
+            Ok(Some(PyList::new_bound(value.py(), gathered).into_any()))
+        }
+        Some((item, rest)) => match get(item, &value)? {
+            Some(v) => py_get_path(rest, v, get),
+            None => Ok(None),
+        },
+    }
+}
+
+/// like `py_get_path` for JSON, values gathered at wildcards are owned
+fn json_get_path<'a, 'data>(path: &[PathItem], value: &'a JsonValue<'data>) -> Option<Cow<'a, JsonValue<'data>>> {
+    match path.split_first() {
+        None => Some(Cow::Borrowed(value)),
+        Some((PathItem::Wildcard, rest)) => match value {
+            JsonValue::Array(array) => {
+                let gathered = array
+                    .iter()
+                    .map(|item| json_get_path(rest, item).map(Cow::into_owned))
+                    .collect::<Option<_>>()?;
+                Some(Cow::Owned(JsonValue::Array(Arc::new(gathered))))
+            }
+            _ => None,
+        },
+        Some((item, rest)) => json_get_path(rest, item.json_get(value)?),
+    }
+}
+
 /// wrapper around `getattr` that returns `Ok(None)` for attribute errors, but returns other errors
 /// We don't check `try_from_attributes` because that check was performed on the top level object before we got here
 fn py_get_attrs<'py>(obj: &Bound<'py, PyAny>, attr_name: &Py<PyString>) -> PyResult<Option<Bound<'py, PyAny>>> {
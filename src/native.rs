@@ -0,0 +1,103 @@
+use std::ffi::{c_char, c_int, c_void, CStr};
+
+use pyo3::ffi;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyCapsule, PyDict};
+
+use crate::build_tools::py_schema_err;
+use crate::tools::SchemaDict;
+
+/// The name the capsule passed to `native_schema` must have
+pub const NATIVE_TYPE_CAPSULE_NAME: &str = "pydantic_core.native_type";
+
+/// The version of `NativeTypeVTable`, incremented whenever its layout changes
+pub const NATIVE_TYPE_ABI_VERSION: u32 = 1;
+
+/// Returns a new reference to the result, or NULL with a python exception set, `data` is `NativeTypeVTable.data`
+pub type NativeTypeFn =
+    unsafe extern "C" fn(data: *mut c_void, value: *mut ffi::PyObject, flag: c_int) -> *mut ffi::PyObject;
+
+/// The functions of a native type, which lets extension modules add validators and serializers without calling
+/// python functions, a pointer to the vtable is passed to `core_schema.native_schema()` in a `PyCapsule` named
+/// `NATIVE_TYPE_CAPSULE_NAME`. The vtable and everything it points to must live as long as the capsule,
+/// functions are called with the GIL held, possibly from several threads.
+#[repr(C)]
+#[derive(Debug)]
+pub struct NativeTypeVTable {
+    /// must be `NATIVE_TYPE_ABI_VERSION`
+    pub abi_version: u32,
+    /// a NUL terminated name used in validator names and errors, or NULL
+    pub name: *const c_char,
+    /// passed to each function, e.g. the state of the type
+    pub data: *mut c_void,
+    /// validates the input, `flag` is 1 in strict mode, `ValueError`, `AssertionError` and `PydanticCustomError`
+    /// raised become validation errors like in function validators
+    pub validate: NativeTypeFn,
+    /// serializes a value, `flag` is 1 when serializing to JSON, the result is then serialized by type inference,
+    /// NULL to serialize values by type inference alone
+    pub serialize: Option<NativeTypeFn>,
+}
+
+/// A native type from the `capsule` of a `native` schema
+#[derive(Debug, Clone)]
+pub(crate) struct NativeType {
+    capsule: Py<PyCapsule>,
+    pub name: String,
+}
+
+impl_py_gc_traverse!(NativeType { capsule });
+
+impl NativeType {
+    pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        let capsule: Bound<'_, PyCapsule> = schema.get_as_req(intern!(py, "capsule"))?;
+        if !capsule
+            .name()?
+            .is_some_and(|name| name.to_bytes() == NATIVE_TYPE_CAPSULE_NAME.as_bytes())
+        {
+            return py_schema_err!("`capsule` should be named `{}`", NATIVE_TYPE_CAPSULE_NAME);
+        }
+        // SAFETY: the capsule's name promises it contains a `NativeTypeVTable`
+        let vtable = unsafe { &*capsule.pointer().cast::<NativeTypeVTable>() };
+        if vtable.abi_version != NATIVE_TYPE_ABI_VERSION {
+            return py_schema_err!(
+                "Unsupported native type ABI version {}, expected {}",
+                vtable.abi_version,
+                NATIVE_TYPE_ABI_VERSION
+            );
+        }
+        let name = match vtable.name.is_null() {
+            true => "native".to_string(),
+            // SAFETY: a non-NULL name is NUL terminated
+            false => format!("native[{}]", unsafe { CStr::from_ptr(vtable.name) }.to_string_lossy()),
+        };
+        Ok(Self {
+            capsule: capsule.unbind(),
+            name,
+        })
+    }
+
+    fn vtable<'a>(&'a self, py: Python<'a>) -> &'a NativeTypeVTable {
+        // SAFETY: the capsule was checked in `from_schema` and is kept alive by `self`
+        unsafe { &*self.capsule.bind(py).pointer().cast::<NativeTypeVTable>() }
+    }
+
+    pub fn validate<'py>(&self, input: &Bound<'py, PyAny>, strict: bool) -> PyResult<Bound<'py, PyAny>> {
+        let py = input.py();
+        let vtable = self.vtable(py);
+        // SAFETY: the GIL is held and the function returns a new reference or NULL with an exception set
+        unsafe { Bound::from_owned_ptr_or_err(py, (vtable.validate)(vtable.data, input.as_ptr(), c_int::from(strict))) }
+    }
+
+    /// The serialized value, `None` if the type has no `serialize` function
+    pub fn serialize<'py>(&self, value: &Bound<'py, PyAny>, json: bool) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let py = value.py();
+        let vtable = self.vtable(py);
+        let Some(serialize) = vtable.serialize else {
+            return Ok(None);
+        };
+        // SAFETY: as in `validate`
+        unsafe { Bound::from_owned_ptr_or_err(py, serialize(vtable.data, value.as_ptr(), c_int::from(json))) }.map(Some)
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use phonenumber::country::Id;
+use phonenumber::{Mode, PhoneNumber};
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
+
+#[pyclass(name = "PhoneNumber", module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct PyPhoneNumber {
+    number: PhoneNumber,
+}
+
+impl PyPhoneNumber {
+    pub fn new(number: PhoneNumber) -> Self {
+        Self { number }
+    }
+
+    pub fn number(&self) -> &PhoneNumber {
+        &self.number
+    }
+}
+
+#[pymethods]
+impl PyPhoneNumber {
+    #[new]
+    #[pyo3(signature = (number, default_region=None))]
+    pub fn py_new(number: &str, default_region: Option<&str>) -> PyResult<Self> {
+        let region = default_region
+            .map(|s| parse_region(s).ok_or_else(|| PyValueError::new_err(format!("Invalid region `{s}`"))))
+            .transpose()?;
+        parse_phone_number(number, region)
+            .map(Self::new)
+            .map_err(|error| PyValueError::new_err(format!("Invalid phone number `{number}`: {error}")))
+    }
+
+    #[getter]
+    pub fn country_code(&self) -> u16 {
+        self.number.code().value()
+    }
+
+    // the national significant number, as a string since it may have leading zeros
+    #[getter]
+    pub fn national_number(&self) -> String {
+        self.number.national().to_string()
+    }
+
+    #[getter]
+    pub fn extension(&self) -> Option<String> {
+        self.number.extension().map(ToString::to_string)
+    }
+
+    // the ISO 3166-1 alpha-2 code of the region the number belongs to, if it can be determined
+    #[getter]
+    pub fn region(&self) -> Option<String> {
+        self.number.country().id().map(|id| id.as_ref().to_string())
+    }
+
+    #[pyo3(signature = (format="e164"))]
+    pub fn format(&self, format: &str) -> PyResult<String> {
+        match parse_format(format) {
+            Some(mode) => Ok(format_phone_number(&self.number, mode)),
+            None => Err(PyValueError::new_err(format!(
+                "Invalid format `{format}`, expected 'e164', 'international', 'national' or 'rfc3966'"
+            ))),
+        }
+    }
+
+    pub fn __str__(&self) -> String {
+        format_phone_number(&self.number, Mode::E164)
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("PhoneNumber('{}')", self.__str__())
+    }
+
+    // the RFC 3966 form includes any extension, which the E.164 form drops
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python) -> PyObject {
+        let (self_key, other_key) = (self.key(), other.key());
+        match op {
+            CompareOp::Eq => (self_key == other_key).into_py(py),
+            CompareOp::Ne => (self_key != other_key).into_py(py),
+            _ => py.NotImplemented(),
+        }
+    }
+
+    fn __hash__(&self) -> u64 {
+        let mut s = DefaultHasher::new();
+        self.key().hash(&mut s);
+        s.finish()
+    }
+
+    #[pyo3(signature = (_memo, /))]
+    pub fn __deepcopy__(&self, py: Python, _memo: Bound<'_, PyAny>) -> Py<PyAny> {
+        self.clone().into_py(py)
+    }
+
+    fn __getnewargs__(&self) -> (String,) {
+        (self.key(),)
+    }
+}
+
+impl PyPhoneNumber {
+    fn key(&self) -> String {
+        format_phone_number(&self.number, Mode::Rfc3966)
+    }
+}
+
+/// Parse `s` as a phone number, numbers without a leading `+` and country code are read as national numbers
+/// of `region`
+pub(crate) fn parse_phone_number(s: &str, region: Option<Id>) -> Result<PhoneNumber, String> {
+    let number = phonenumber::parse(region, s).map_err(|e| e.to_string())?;
+    if number.is_valid() {
+        Ok(number)
+    } else {
+        Err("the number is not valid".to_string())
+    }
+}
+
+/// The region for an ISO 3166-1 alpha-2 code like "GB", case-insensitively
+pub(crate) fn parse_region(s: &str) -> Option<Id> {
+    s.to_ascii_uppercase().parse().ok()
+}
+
+pub(crate) fn parse_format(s: &str) -> Option<Mode> {
+    match s {
+        "e164" => Some(Mode::E164),
+        "international" => Some(Mode::International),
+        "national" => Some(Mode::National),
+        "rfc3966" => Some(Mode::Rfc3966),
+        _ => None,
+    }
+}
+
+pub(crate) fn format_phone_number(number: &PhoneNumber, mode: Mode) -> String {
+    number.format().mode(mode).to_string()
+}
@@ -0,0 +1,171 @@
+use pyo3::basic::CompareOp;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyIterator, PyList};
+
+use crate::tools::safe_repr;
+
+/// Read-only mapping over a validated dict, returned by dict validators with `read_only=True`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen, mapping)]
+#[derive(Debug)]
+pub struct ReadOnlyDict {
+    dict: Py<PyDict>,
+}
+
+impl ReadOnlyDict {
+    pub fn new(dict: Bound<'_, PyDict>) -> Self {
+        Self { dict: dict.unbind() }
+    }
+}
+
+#[pymethods]
+impl ReadOnlyDict {
+    fn __getitem__<'py>(&self, py: Python<'py>, key: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        self.dict.bind(py).as_any().get_item(key)
+    }
+
+    fn __len__(&self, py: Python) -> usize {
+        self.dict.bind(py).len()
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        self.dict.bind(py).as_any().iter()
+    }
+
+    fn __contains__(&self, py: Python, key: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.dict.bind(py).contains(key)
+    }
+
+    #[pyo3(signature = (key, default = None))]
+    fn get(&self, py: Python, key: &Bound<'_, PyAny>, default: Option<PyObject>) -> PyResult<PyObject> {
+        match self.dict.bind(py).get_item(key)? {
+            Some(value) => Ok(value.unbind()),
+            None => Ok(default.unwrap_or_else(|| py.None())),
+        }
+    }
+
+    fn keys<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.dict.bind(py).call_method0(pyo3::intern!(py, "keys"))
+    }
+
+    fn values<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.dict.bind(py).call_method0(pyo3::intern!(py, "values"))
+    }
+
+    fn items<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        self.dict.bind(py).call_method0(pyo3::intern!(py, "items"))
+    }
+
+    /// A mutable copy of the dict
+    fn copy<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.dict.bind(py).copy()
+    }
+
+    fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let other = match other.downcast::<Self>() {
+            Ok(other) => other.get().dict.bind(py).as_any().clone(),
+            Err(_) => other.clone(),
+        };
+        match op {
+            CompareOp::Eq | CompareOp::Ne => self.dict.bind(py).rich_compare(other, op).map(Bound::unbind),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        format!("ReadOnlyDict({})", safe_repr(self.dict.bind(py)))
+    }
+}
+
+/// Read-only sequence over a validated list, returned by list validators with `read_only=True`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen, sequence)]
+#[derive(Debug)]
+pub struct ReadOnlyList {
+    list: Py<PyList>,
+}
+
+impl ReadOnlyList {
+    pub fn new(list: Bound<'_, PyList>) -> Self {
+        Self { list: list.unbind() }
+    }
+}
+
+#[pymethods]
+impl ReadOnlyList {
+    /// Items by index, slices are returned as lists
+    fn __getitem__<'py>(&self, py: Python<'py>, index: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        self.list.bind(py).as_any().get_item(index)
+    }
+
+    fn __len__(&self, py: Python) -> usize {
+        self.list.bind(py).len()
+    }
+
+    fn __iter__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyIterator>> {
+        self.list.bind(py).as_any().iter()
+    }
+
+    fn __contains__(&self, py: Python, value: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.list.bind(py).contains(value)
+    }
+
+    fn index(&self, py: Python, value: &Bound<'_, PyAny>) -> PyResult<usize> {
+        self.list.bind(py).index(value)
+    }
+
+    fn count<'py>(&self, py: Python<'py>, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        self.list.bind(py).call_method1(pyo3::intern!(py, "count"), (value,))
+    }
+
+    /// A mutable copy of the list
+    fn copy<'py>(&self, py: Python<'py>) -> Bound<'py, PyList> {
+        self.list.bind(py).get_slice(0, usize::MAX)
+    }
+
+    fn __richcmp__(&self, py: Python, other: &Bound<'_, PyAny>, op: CompareOp) -> PyResult<PyObject> {
+        let other = match other.downcast::<Self>() {
+            Ok(other) => other.get().list.bind(py).as_any().clone(),
+            Err(_) => other.clone(),
+        };
+        match op {
+            CompareOp::Eq | CompareOp::Ne => self.list.bind(py).rich_compare(other, op).map(Bound::unbind),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        format!("ReadOnlyList({})", safe_repr(self.list.bind(py)))
+    }
+}
+
+/// `value` as a dict if it's a dict or a `ReadOnlyDict`
+pub fn as_py_dict<'py>(value: &Bound<'py, PyAny>) -> Option<Bound<'py, PyDict>> {
+    if let Ok(py_dict) = value.downcast::<PyDict>() {
+        Some(py_dict.clone())
+    } else if let Ok(read_only) = value.downcast::<ReadOnlyDict>() {
+        Some(read_only.get().dict.bind(value.py()).clone())
+    } else {
+        None
+    }
+}
+
+/// `value` as a list if it's a list or a `ReadOnlyList`
+pub fn as_py_list<'py>(value: &Bound<'py, PyAny>) -> Option<Bound<'py, PyList>> {
+    if let Ok(py_list) = value.downcast::<PyList>() {
+        Some(py_list.clone())
+    } else if let Ok(read_only) = value.downcast::<ReadOnlyList>() {
+        Some(read_only.get().list.bind(value.py()).clone())
+    } else {
+        None
+    }
+}
+
+/// The dict or list wrapped by a `ReadOnlyDict` or `ReadOnlyList`
+pub fn read_only_inner<'py>(value: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+    if let Ok(read_only) = value.downcast::<ReadOnlyDict>() {
+        Some(read_only.get().dict.bind(value.py()).clone().into_any())
+    } else if let Ok(read_only) = value.downcast::<ReadOnlyList>() {
+        Some(read_only.get().list.bind(value.py()).clone().into_any())
+    } else {
+        None
+    }
+}
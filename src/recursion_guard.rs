@@ -1,5 +1,11 @@
 use ahash::AHashSet;
+use pyo3::PyResult;
 use std::mem::MaybeUninit;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::errors::PythonCallbackLimitExceeded;
 
 type RecursionKey = (
     // Identifier for the input object, e.g. the id() of a Python dict
@@ -69,6 +75,34 @@ pub struct RecursionState {
     // depth could be a hashmap {validator_id => depth} but for simplicity and performance it's easier to just
     // use one number for all validators
     depth: u8,
+    // if set, validation is abandoned once the deadline has passed
+    deadline: Option<Deadline>,
+    // if set, validation or serialization is abandoned once too many Python callbacks have been called
+    callback_budget: Option<CallbackBudget>,
+}
+
+/// Counts the Python function validators, serializers and default factories called in a single call, up to
+/// `max_python_callbacks`, the count is shared with clones so calls made from the handlers of wrap functions are counted
+#[derive(Debug, Clone)]
+struct CallbackBudget {
+    max: u64,
+    count: Arc<AtomicU64>,
+}
+
+/// Point in time after which validation should be abandoned, checked wherever the recursion guard is used
+#[derive(Debug, Clone, Copy)]
+pub struct Deadline {
+    at: Instant,
+    ms: u64,
+}
+
+impl Deadline {
+    pub fn new(ms: u64) -> Self {
+        Self {
+            at: Instant::now() + Duration::from_millis(ms),
+            ms,
+        }
+    }
 }
 
 // with debug_assertions enabled, function stacks are a bit bigger so need to be a bit more restricted
@@ -86,6 +120,48 @@ pub const RECURSION_GUARD_LIMIT: u8 = (if cfg!(any(target_family = "wasm", all(w
 }) - GUARD_OFFSET;
 
 impl RecursionState {
+    pub fn with_deadline(deadline: Option<Deadline>) -> Self {
+        Self {
+            deadline,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_max_python_callbacks(self, max_python_callbacks: Option<u64>) -> Self {
+        Self {
+            callback_budget: max_python_callbacks.map(|max| CallbackBudget {
+                max,
+                count: Arc::new(AtomicU64::new(0)),
+            }),
+            ..self
+        }
+    }
+
+    /// Count a call of a Python callback, erroring if it's more than `max_python_callbacks` allows
+    pub fn count_python_callback(&self) -> PyResult<()> {
+        match self.callback_budget {
+            Some(ref budget) if budget.count.fetch_add(1, Ordering::Relaxed) >= budget.max => {
+                Err(PythonCallbackLimitExceeded::new_err(budget.max))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Start counting Python callbacks from zero again, when the state is reused for another input
+    pub fn reset_python_callbacks(&self) {
+        if let Some(ref budget) = self.callback_budget {
+            budget.count.store(0, Ordering::Relaxed);
+        }
+    }
+
+    /// If the deadline has passed, returns the time allowed in milliseconds
+    pub fn deadline_exceeded(&self) -> Option<u64> {
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline.at => Some(deadline.ms),
+            _ => None,
+        }
+    }
+
     // insert a new value
     // * return `false` if the stack already had it in it
     // * return `true` if the stack didn't have it in it and it was inserted
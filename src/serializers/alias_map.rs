@@ -0,0 +1,45 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use super::shared::CombinedSerializer;
+
+/// The keys each field of the model, dataclass or typed dict at the root of `serializer` is serialized under by
+/// alias with `alias_profile`, keyed by field name, or with `reverse` the field name keyed by each key
+pub(super) fn alias_map<'py>(
+    py: Python<'py>,
+    serializer: &CombinedSerializer,
+    alias_profile: Option<&str>,
+    reverse: bool,
+) -> PyResult<Bound<'py, PyDict>> {
+    let map = PyDict::new_bound(py);
+    visit_fields(serializer, alias_profile, &mut |field_name, aliases| {
+        if reverse {
+            aliases.iter().try_for_each(|alias| map.set_item(alias, field_name))
+        } else {
+            map.set_item(field_name, PyList::new_bound(py, aliases))
+        }
+    })?;
+    Ok(map)
+}
+
+/// Call `f` with the name and aliases of each field, looking through models, dataclasses, wrap function
+/// serializers and definition references to the fields serializer
+fn visit_fields(
+    serializer: &CombinedSerializer,
+    alias_profile: Option<&str>,
+    f: &mut impl FnMut(&str, &[&str]) -> PyResult<()>,
+) -> PyResult<()> {
+    match serializer {
+        CombinedSerializer::Fields(s) => s
+            .field_aliases(alias_profile)
+            .into_iter()
+            .try_for_each(|(name, aliases)| f(name, &aliases)),
+        CombinedSerializer::Model(s) => visit_fields(s.inner_serializer(), alias_profile, f),
+        CombinedSerializer::Dataclass(s) => visit_fields(s.inner_serializer(), alias_profile, f),
+        CombinedSerializer::FunctionWrap(s) => visit_fields(s.inner_serializer(), alias_profile, f),
+        CombinedSerializer::Recursive(s) => s
+            .definition()
+            .read(|definition| definition.map_or(Ok(()), |definition| visit_fields(definition, alias_profile, f))),
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyString, PyTuple};
+
+/// The `by_alias` argument of `SchemaSerializer.to_python` and `to_json`, either for the whole output or
+/// a dict of locations to whether fields at and below that location are serialized by alias
+#[derive(FromPyObject)]
+pub enum ByAliasArg<'py> {
+    Bool(bool),
+    Scopes(Bound<'py, PyDict>),
+}
+
+impl ByAliasArg<'_> {
+    /// `by_alias` for the root of the output, and the scopes below it if there are any
+    pub(crate) fn into_scope(self) -> PyResult<(bool, Option<Arc<AliasScope>>)> {
+        match self {
+            Self::Bool(by_alias) => Ok((by_alias, None)),
+            Self::Scopes(scopes) => {
+                let scope = AliasScope::new(&scopes)?;
+                // like the default of `by_alias`, locations outside every scope are serialized by alias
+                Ok((scope.by_alias.unwrap_or(true), Some(Arc::new(scope))))
+            }
+        }
+    }
+}
+
+/// Whether fields are serialized by alias at a location in the output and below it, unless a child scope says
+/// otherwise, children are keyed by field name, items of lists and dicts are in the scope of the field containing them
+#[derive(Debug, Default)]
+pub(crate) struct AliasScope {
+    by_alias: Option<bool>,
+    children: AHashMap<String, Arc<AliasScope>>,
+}
+
+impl AliasScope {
+    fn new(scopes: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let mut root = Self::default();
+        for (loc, by_alias) in scopes {
+            let Ok(by_alias) = by_alias.downcast::<PyBool>() else {
+                return Err(PyTypeError::new_err("`by_alias` values must be bools"));
+            };
+            let path: Vec<String> = if let Ok(field_name) = loc.downcast::<PyString>() {
+                vec![field_name.to_str()?.to_owned()]
+            } else if let Ok(tuple) = loc.downcast::<PyTuple>() {
+                tuple.extract().map_err(|_| invalid_loc())?
+            } else {
+                return Err(invalid_loc());
+            };
+            root.insert(&path, by_alias.is_true());
+        }
+        Ok(root)
+    }
+
+    fn insert(&mut self, path: &[String], by_alias: bool) {
+        match path.split_first() {
+            Some((field_name, rest)) => {
+                let child = self.children.entry(field_name.clone()).or_default();
+                // scopes are only built before serialization, so there are no other references yet
+                Arc::get_mut(child)
+                    .expect("alias scope is not shared while building")
+                    .insert(rest, by_alias);
+            }
+            None => self.by_alias = Some(by_alias),
+        }
+    }
+
+    /// `by_alias` for the field `field_name` within this scope, and the scope of the field if it has one
+    pub fn field<'a>(&'a self, field_name: &str, by_alias: bool) -> (bool, Option<&'a Arc<AliasScope>>) {
+        match self.children.get(field_name) {
+            Some(child) => (child.by_alias.unwrap_or(by_alias), Some(child)),
+            None => (by_alias, None),
+        }
+    }
+}
+
+fn invalid_loc() -> PyErr {
+    PyTypeError::new_err("`by_alias` keys must be field names or tuples of field names")
+}
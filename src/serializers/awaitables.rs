@@ -0,0 +1,199 @@
+use std::cell::RefCell;
+
+use pyo3::exceptions::PyKeyError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyBytes, PyDict, PyFrozenSet, PyList, PySet, PyTuple};
+
+use super::config::SerializationConfig;
+use super::extra::{CollectWarnings, DuckTypingSerMode, Extra, SerMode, SerRecursionState, WarningsMode};
+use super::runtime_settings::RuntimeSettings;
+use super::shared::{to_json_bytes, CombinedSerializer};
+use super::type_serializers::any::AnySerializer;
+use super::type_serializers::function::SerializationCallable;
+
+/// Python half of `to_python_async` and `to_json_async`: await each pending value in turn, serialize the result
+/// with the function's return serializer, then swap the results in for their placeholders.
+const RESOLVE_CODE: &str = r"
+async def resolve(value, pending, replace):
+    resolved = {}
+    try:
+        for placeholder, awaitable, serialize in pending:
+            resolved[placeholder] = serialize(await awaitable)
+    finally:
+        # close anything we didn't get to, to avoid 'coroutine was never awaited' warnings
+        for _, awaitable, _ in pending[len(resolved):]:
+            close = getattr(awaitable, 'close', None)
+            if close is not None:
+                close()
+    return replace(value, resolved)
+";
+
+static RESOLVE: GILOnceCell<PyObject> = GILOnceCell::new();
+
+fn get_resolve(py: Python<'_>) -> PyResult<&Bound<'_, PyAny>> {
+    RESOLVE
+        .get_or_try_init(py, || {
+            let locals = PyDict::new_bound(py);
+            py.run_bound(RESOLVE_CODE, None, Some(&locals))?;
+            locals
+                .get_item(intern!(py, "resolve"))?
+                .map(Bound::unbind)
+                .ok_or_else(|| PyKeyError::new_err("resolve"))
+        })
+        .map(|resolve| resolve.bind(py))
+}
+
+/// Stands in for the output of a function serializer until the awaitable it returned has been awaited
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+struct AwaitablePlaceholder;
+
+#[pymethods]
+impl AwaitablePlaceholder {
+    fn __repr__(&self) -> &'static str {
+        "AwaitablePlaceholder()"
+    }
+}
+
+/// Collects awaitables returned by `function-plain` and `function-wrap` serializers during
+/// `to_python_async` and `to_json_async`
+#[derive(Default)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) struct CollectAwaitables {
+    // (placeholder, awaitable, serialize)
+    pending: RefCell<Vec<(PyObject, PyObject, PyObject)>>,
+}
+
+impl CollectAwaitables {
+    /// If `value` is awaitable, record it and return a placeholder to use in the output instead,
+    /// once awaited the result is serialized with `serializer`.
+    pub fn defer(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: &CombinedSerializer,
+        extra: &Extra,
+    ) -> PyResult<Option<PyObject>> {
+        let py = value.py();
+        if !value.hasattr(intern!(py, "__await__"))? {
+            return Ok(None);
+        }
+        let placeholder: PyObject = Py::new(py, AwaitablePlaceholder)?.into_any();
+        let serialize = SerializationCallable::new(py, serializer, None, None, extra).into_py(py);
+        self.pending
+            .borrow_mut()
+            .push((placeholder.clone_ref(py), value.clone().unbind(), serialize));
+        Ok(Some(placeholder))
+    }
+
+    /// Build the awaitable returned to the caller, if `json` is set the resolved value is then dumped to JSON.
+    pub fn into_awaitable(self, py: Python, value: PyObject, json: Option<JsonOutput>) -> PyResult<PyObject> {
+        let pending = PyList::new_bound(
+            py,
+            self.pending
+                .into_inner()
+                .into_iter()
+                .map(|item| -> PyObject { item.into_py(py) }),
+        );
+        let replace = Py::new(py, ReplacePlaceholders { json })?;
+        get_resolve(py)?.call1((value, pending, replace)).map(Bound::unbind)
+    }
+}
+
+/// Settings used to convert the resolved JSON compatible value into JSON bytes in `to_json_async`
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) struct JsonOutput {
+    pub config: SerializationConfig,
+    pub indent: Option<usize>,
+    pub warnings_mode: WarningsMode,
+    pub runtime: RuntimeSettings,
+}
+
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+struct ReplacePlaceholders {
+    json: Option<JsonOutput>,
+}
+
+#[pymethods]
+impl ReplacePlaceholders {
+    fn __call__(&self, py: Python, value: &Bound<'_, PyAny>, resolved: &Bound<'_, PyDict>) -> PyResult<PyObject> {
+        let value = if resolved.is_empty() {
+            value.clone()
+        } else {
+            replace_placeholders(value, resolved)?
+        };
+        match self.json {
+            Some(ref json) => {
+                let warnings = CollectWarnings::new(json.warnings_mode);
+                let rec_guard = SerRecursionState::default();
+                let mut extra = Extra::new(
+                    py,
+                    &SerMode::Json,
+                    true,
+                    &warnings,
+                    false,
+                    false,
+                    false,
+                    false,
+                    &json.config,
+                    &rec_guard,
+                    false,
+                    None,
+                    DuckTypingSerMode::SchemaBased,
+                    None,
+                );
+                extra.runtime = json.runtime;
+                let serializer: CombinedSerializer = AnySerializer::INFER.into();
+                let bytes = to_json_bytes(&value, &serializer, None, None, &extra, json.indent, 1024, None)?;
+                warnings.final_check(py)?;
+                Ok(PyBytes::new_bound(py, &bytes).into())
+            }
+            None => Ok(value.unbind()),
+        }
+    }
+}
+
+/// Swap placeholders for their resolved values, the output of `to_python` only contains
+/// JSON-like containers so we only need to look inside dicts, lists, tuples and sets.
+fn replace_placeholders<'py>(value: &Bound<'py, PyAny>, resolved: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PyAny>> {
+    let py = value.py();
+    if value.is_instance_of::<AwaitablePlaceholder>() {
+        match resolved.get_item(value)? {
+            Some(v) => Ok(v),
+            None => Err(PyKeyError::new_err("awaitable was not resolved")),
+        }
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        let new_dict = PyDict::new_bound(py);
+        for (k, v) in dict {
+            new_dict.set_item(k, replace_placeholders(&v, resolved)?)?;
+        }
+        Ok(new_dict.into_any())
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        let items = list
+            .iter()
+            .map(|v| replace_placeholders(&v, resolved))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, items).into_any())
+    } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+        let items = tuple
+            .iter()
+            .map(|v| replace_placeholders(&v, resolved))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyTuple::new_bound(py, items).into_any())
+    } else if let Ok(set) = value.downcast::<PySet>() {
+        let items = set
+            .iter()
+            .map(|v| replace_placeholders(&v, resolved))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PySet::new_bound(py, &items)?.into_any())
+    } else if let Ok(frozenset) = value.downcast::<PyFrozenSet>() {
+        let items = frozenset
+            .iter()
+            .map(|v| replace_placeholders(&v, resolved))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyFrozenSet::new_bound(py, &items)?.into_any())
+    } else {
+        Ok(value.clone())
+    }
+}
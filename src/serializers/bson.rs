@@ -0,0 +1,139 @@
+use bson::spec::BinarySubtype;
+use bson::{Binary, Bson, Decimal128, Document};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{
+    timezone_utc_bound, PyByteArray, PyBytes, PyDateAccess, PyDateTime, PyDict, PyString, PyTimeAccess, PyTzInfoAccess,
+};
+
+use super::errors::PydanticSerializationError;
+use super::extra::{Extra, SerMode};
+use super::infer::{infer_json_key, infer_to_python};
+use super::ob_type::ObType;
+use super::shared::{CombinedSerializer, TypeSerializer};
+
+/// Serialize to a BSON document, read by `SchemaValidator.validate_bson`.
+///
+/// Values are serialized in python mode so datetimes, decimals, UUIDs and bytes can be written as the
+/// matching BSON types, anything BSON doesn't have a type for is written like JSON.
+pub(crate) fn to_bson_bytes(
+    value: &Bound<'_, PyAny>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+) -> PyResult<Vec<u8>> {
+    let py = value.py();
+    let python_value = serializer.to_python(value, include, exclude, extra)?;
+    let json_extra = Extra {
+        mode: &SerMode::Json,
+        ..extra.clone()
+    };
+    let Bson::Document(document) = to_bson(python_value.bind(py), &json_extra)? else {
+        return Err(PydanticSerializationError::new_err(format!(
+            "BSON documents must be dicts, got '{}'",
+            value.get_type().qualname()?
+        )));
+    };
+    let mut bytes = Vec::new();
+    document
+        .to_writer(&mut bytes)
+        .map_err(|e| PydanticSerializationError::new_err(format!("Error serializing to BSON: {e}")))?;
+    Ok(bytes)
+}
+
+fn to_bson(value: &Bound<'_, PyAny>, json_extra: &Extra) -> PyResult<Bson> {
+    let py = value.py();
+    let bson = match json_extra.ob_type_lookup.get_type(value) {
+        ObType::None => Bson::Null,
+        ObType::Bool => Bson::Boolean(value.extract()?),
+        ObType::Int | ObType::IntSubclass => {
+            let int: i64 = value.extract().map_err(|_| {
+                PydanticSerializationError::new_err(format!("BSON ints must fit in 64 bits, got {value}"))
+            })?;
+            match i32::try_from(int) {
+                Ok(int) => Bson::Int32(int),
+                Err(_) => Bson::Int64(int),
+            }
+        }
+        ObType::Float | ObType::FloatSubclass => Bson::Double(value.extract()?),
+        ObType::Str | ObType::StrSubclass => Bson::String(value.downcast::<PyString>()?.to_str()?.to_string()),
+        ObType::Bytes => binary(BinarySubtype::Generic, value.downcast::<PyBytes>()?.as_bytes()),
+        ObType::Bytearray => binary(BinarySubtype::Generic, &value.downcast::<PyByteArray>()?.to_vec()),
+        ObType::Uuid => {
+            let bytes = value.getattr(intern!(py, "bytes"))?;
+            binary(BinarySubtype::Uuid, bytes.downcast::<PyBytes>()?.as_bytes())
+        }
+        ObType::Decimal => {
+            let decimal: Decimal128 = value.str()?.to_str()?.parse().map_err(|e| {
+                PydanticSerializationError::new_err(format!(
+                    "Decimal {value} can't be written as a BSON Decimal128: {e}"
+                ))
+            })?;
+            Bson::Decimal128(decimal)
+        }
+        ObType::Datetime => Bson::DateTime(to_bson_datetime(value.downcast::<PyDateTime>()?)?),
+        ObType::Dict => {
+            let mut document = Document::new();
+            for (key, item) in value.downcast::<PyDict>()? {
+                let key = match key.downcast::<PyString>() {
+                    Ok(py_str) => py_str.to_str()?.to_string(),
+                    Err(_) => infer_json_key(&key, json_extra)?.into_owned(),
+                };
+                document.insert(key, to_bson(&item, json_extra)?);
+            }
+            Bson::Document(document)
+        }
+        ObType::List | ObType::Tuple | ObType::Set | ObType::Frozenset => Bson::Array(
+            value
+                .iter()?
+                .map(|item| to_bson(&item?, json_extra))
+                .collect::<PyResult<_>>()?,
+        ),
+        _ if is_object_id(value)? => {
+            let bytes: [u8; 12] = value.getattr(intern!(py, "binary"))?.extract()?;
+            Bson::ObjectId(bson::oid::ObjectId::from_bytes(bytes))
+        }
+        // other values are written as they would be in JSON
+        _ => {
+            let json_value = infer_to_python(value, None, None, json_extra)?;
+            to_bson(json_value.bind(py), json_extra)?
+        }
+    };
+    Ok(bson)
+}
+
+fn binary(subtype: BinarySubtype, bytes: &[u8]) -> Bson {
+    Bson::Binary(Binary {
+        subtype,
+        bytes: bytes.to_vec(),
+    })
+}
+
+/// Naive datetimes are assumed to be UTC, BSON datetimes only have millisecond precision
+fn to_bson_datetime(datetime: &Bound<'_, PyDateTime>) -> PyResult<bson::DateTime> {
+    let py = datetime.py();
+    let datetime = match datetime.get_tzinfo_bound() {
+        Some(_) => datetime
+            .call_method1(intern!(py, "astimezone"), (timezone_utc_bound(py),))?
+            .downcast_into::<PyDateTime>()?,
+        None => datetime.clone(),
+    };
+    bson::DateTime::builder()
+        .year(datetime.get_year())
+        .month(datetime.get_month())
+        .day(datetime.get_day())
+        .hour(datetime.get_hour())
+        .minute(datetime.get_minute())
+        .second(datetime.get_second())
+        .millisecond((datetime.get_microsecond() / 1000) as u16)
+        .build()
+        .map_err(|e| PydanticSerializationError::new_err(format!("Error serializing datetime to BSON: {e}")))
+}
+
+/// `bson.ObjectId` from PyMongo, written as a BSON ObjectId
+fn is_object_id(value: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = value.py();
+    let class = value.get_type();
+    Ok(class.qualname()? == "ObjectId" && class.getattr(intern!(py, "__module__"))?.to_string() == "bson.objectid")
+}
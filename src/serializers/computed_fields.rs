@@ -4,7 +4,7 @@ use pyo3::{intern, PyTraverseError, PyVisit};
 use serde::ser::SerializeMap;
 use serde::Serialize;
 
-use crate::build_tools::py_schema_error_type;
+use crate::build_tools::{py_schema_error_type, AliasGenerator};
 use crate::definitions::DefinitionsBuilder;
 use crate::py_gc::PyGcTraverse;
 use crate::serializers::filter::SchemaFilter;
@@ -39,6 +39,13 @@ impl ComputedFields {
         self.0.len()
     }
 
+    /// The property name and alias of each computed field
+    pub fn aliases(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|field| (field.property_name.as_str(), field.alias.as_str()))
+    }
+
     pub fn to_python(
         &self,
         model: &Bound<'_, PyAny>,
@@ -83,14 +90,20 @@ impl ComputedFields {
                 if extra.exclude_none && value.is_none() {
                     continue;
                 }
+                let (by_alias, alias_scope) = extra.field_alias_scope(&computed_field.property_name);
+                let cf_extra = Extra {
+                    by_alias,
+                    alias_scope,
+                    ..*extra
+                };
                 let cfs = ComputedFieldSerializer {
                     model,
                     computed_field,
                     include: next_include.as_ref(),
                     exclude: next_exclude.as_ref(),
-                    extra,
+                    extra: &cf_extra,
                 };
-                let key = match extra.by_alias {
+                let key = match cf_extra.by_alias {
                     true => computed_field.alias.as_str(),
                     false => computed_field.property_name.as_str(),
                 };
@@ -122,9 +135,14 @@ impl ComputedField {
         let return_schema = schema.get_as_req(intern!(py, "return_schema"))?;
         let serializer = CombinedSerializer::build(&return_schema, config, definitions)
             .map_err(|e| py_schema_error_type!("Computed field `{}`:\n  {}", property_name, e))?;
-        let alias_py = schema
-            .get_as(intern!(py, "alias"))?
-            .unwrap_or_else(|| property_name.clone());
+        let alias_py = match (
+            schema.get_as(intern!(py, "alias"))?,
+            AliasGenerator::from_config(config)?,
+        ) {
+            (Some(alias), _) => alias,
+            (None, Some(alias_generator)) => PyString::new_bound(py, &alias_generator.apply(property_name.to_str()?)),
+            (None, None) => property_name.clone(),
+        };
         Ok(Self {
             property_name: property_name.extract()?,
             property_name_py: property_name.into_py(py),
@@ -149,6 +167,12 @@ impl ComputedField {
         if let Some((next_include, next_exclude)) = filter.key_filter(property_name_py, include, exclude)? {
             let next_value = model.getattr(property_name_py)?;
 
+            let (by_alias, alias_scope) = extra.field_alias_scope(&self.property_name);
+            let extra = &Extra {
+                by_alias,
+                alias_scope,
+                ..*extra
+            };
             let value = self
                 .serializer
                 .to_python(&next_value, next_include.as_ref(), next_exclude.as_ref(), extra)?;
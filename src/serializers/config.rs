@@ -20,6 +20,12 @@ pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
     pub bytes_mode: BytesMode,
     pub inf_nan_mode: InfNanMode,
+    // number of bytes of JSON after which the GIL is briefly released at the next container
+    pub release_gil_every: Option<usize>,
+    pub tuple_key_mode: TupleKeyMode,
+    pub tuple_key_separator: String,
+    // whether numpy scalars and arrays are recognised, see `ObTypeLookup::cached_numpy`
+    pub numpy_support: bool,
 }
 
 impl SerializationConfig {
@@ -27,10 +33,24 @@ impl SerializationConfig {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
         let inf_nan_mode = InfNanMode::from_config(config)?;
+        let release_gil_every = match config {
+            Some(config) => config.get_as(intern!(config.py(), "ser_json_release_gil_every"))?,
+            None => None,
+        };
+        let tuple_key_mode = TupleKeyMode::from_config(config)?;
+        let tuple_key_separator = tuple_key_separator_from_config(config)?;
+        let numpy_support = match config {
+            Some(config) => config.get_as(intern!(config.py(), "numpy_support"))?.unwrap_or(false),
+            None => false,
+        };
         Ok(Self {
             timedelta_mode,
             bytes_mode,
             inf_nan_mode,
+            release_gil_every,
+            tuple_key_mode,
+            tuple_key_separator,
+            numpy_support,
         })
     }
 
@@ -39,6 +59,10 @@ impl SerializationConfig {
             timedelta_mode: TimedeltaMode::from_str(timedelta_mode)?,
             bytes_mode: BytesMode::from_str(bytes_mode)?,
             inf_nan_mode: InfNanMode::from_str(inf_nan_mode)?,
+            release_gil_every: None,
+            tuple_key_mode: TupleKeyMode::default(),
+            tuple_key_separator: DEFAULT_TUPLE_KEY_SEPARATOR.to_string(),
+            numpy_support: false,
         })
     }
 }
@@ -107,6 +131,43 @@ serialization_mode! {
     Strings => "strings",
 }
 
+serialization_mode! {
+    DecimalMode,
+    "ser_json_decimal",
+    Str => "str",
+    Number => "number",
+    Float => "float",
+}
+
+serialization_mode! {
+    TupleKeyMode,
+    "ser_json_tuple_keys",
+    Join => "join",
+    Json => "json",
+}
+
+serialization_mode! {
+    NamedTupleMode,
+    "ser_json_namedtuple",
+    Array => "array",
+    Object => "object",
+}
+
+const DEFAULT_TUPLE_KEY_SEPARATOR: &str = ",";
+
+/// The separator between items of tuple dict keys with `ser_json_tuple_keys='join'`
+pub(crate) fn tuple_key_separator_from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<String> {
+    let separator: Option<String> = match config {
+        Some(config) => config.get_as(intern!(config.py(), "ser_json_tuple_key_separator"))?,
+        None => None,
+    };
+    match separator {
+        Some(separator) if separator.is_empty() => py_schema_err!("`ser_json_tuple_key_separator` must not be empty"),
+        Some(separator) => Ok(separator),
+        None => Ok(DEFAULT_TUPLE_KEY_SEPARATOR.to_string()),
+    }
+}
+
 impl TimedeltaMode {
     fn total_seconds<'py>(py_timedelta: &Bound<'py, PyDelta>) -> PyResult<Bound<'py, PyAny>> {
         py_timedelta.call_method0(intern!(py_timedelta.py(), "total_seconds"))
@@ -202,3 +263,9 @@ impl FromPyObject<'_> for InfNanMode {
         Self::from_str(ob.downcast::<PyString>()?.to_str()?)
     }
 }
+
+impl FromPyObject<'_> for DecimalMode {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        Self::from_str(ob.downcast::<PyString>()?.to_str()?)
+    }
+}
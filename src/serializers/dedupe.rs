@@ -0,0 +1,109 @@
+use std::cell::RefCell;
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+/// The outputs of the items serialized with `to_json(..., dedupe_shared=True)`, so items which are the same object
+/// are serialized once and written once in the `$defs` of the output
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SharedValues {
+    // keyed by the id of the item and the serializer it was serialized with
+    outputs: RefCell<AHashMap<(usize, usize), SerializedItem>>,
+    // outputs which were used for more than one item, in the order they were first shared
+    shared: RefCell<Vec<PyObject>>,
+}
+
+#[derive(Debug, Clone)]
+struct SerializedItem {
+    // kept so its id isn't reused by another object
+    _item: PyObject,
+    output: PyObject,
+    shared: bool,
+}
+
+impl SharedValues {
+    /// The output of `item` if it was already serialized by the same serializer
+    pub fn get(&self, item: &Bound<'_, PyAny>, serializer_id: usize) -> Option<PyObject> {
+        let py = item.py();
+        let mut outputs = self.outputs.borrow_mut();
+        let serialized = outputs.get_mut(&(item.as_ptr() as usize, serializer_id))?;
+        if !serialized.shared {
+            serialized.shared = true;
+            self.shared.borrow_mut().push(serialized.output.clone_ref(py));
+        }
+        Some(serialized.output.clone_ref(py))
+    }
+
+    /// Record the output of `item`, only objects and arrays are shared since they're all that's worth deduplicating
+    pub fn insert(&self, item: &Bound<'_, PyAny>, serializer_id: usize, output: &Bound<'_, PyAny>) {
+        if output.is_exact_instance_of::<PyDict>() || output.is_exact_instance_of::<PyList>() {
+            let serialized = SerializedItem {
+                _item: item.clone().unbind(),
+                output: output.clone().unbind(),
+                shared: false,
+            };
+            self.outputs
+                .borrow_mut()
+                .insert((item.as_ptr() as usize, serializer_id), serialized);
+        }
+    }
+
+    /// `{"$defs": {...}, "value": ...}` where each output used more than once is in `$defs` and replaced
+    /// by `{"$ref": "#/$defs/<index>"}` wherever it's used
+    pub fn with_defs(&self, value: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        let py = value.py();
+        let shared = self.shared.borrow();
+        let refs = shared
+            .iter()
+            .enumerate()
+            .map(|(index, output)| (output.as_ptr() as usize, format!("#/$defs/{index}")))
+            .collect::<AHashMap<_, _>>();
+
+        let defs = PyDict::new_bound(py);
+        for (index, output) in shared.iter().enumerate() {
+            defs.set_item(index.to_string(), replace_shared(output.bind(py), &refs)?)?;
+        }
+        let result = PyDict::new_bound(py);
+        result.set_item(intern!(py, "$defs"), defs)?;
+        let value = match refs.get(&(value.as_ptr() as usize)) {
+            Some(loc) => ref_dict(py, loc)?,
+            None => replace_shared(value, &refs)?,
+        };
+        result.set_item(intern!(py, "value"), value)?;
+        Ok(result.into_py(py))
+    }
+}
+
+/// A copy of the JSON compatible `value` with the shared outputs inside it replaced by references,
+/// `value` is copied rather than modified since it might be returned by a function serializer
+fn replace_shared(value: &Bound<'_, PyAny>, refs: &AHashMap<usize, String>) -> PyResult<PyObject> {
+    let py = value.py();
+    let replace_item = |item: &Bound<'_, PyAny>| match refs.get(&(item.as_ptr() as usize)) {
+        Some(loc) => ref_dict(py, loc),
+        None => replace_shared(item, refs),
+    };
+    if let Ok(dict) = value.downcast_exact::<PyDict>() {
+        let new_dict = PyDict::new_bound(py);
+        for (key, item) in dict {
+            new_dict.set_item(key, replace_item(&item)?)?;
+        }
+        Ok(new_dict.into_py(py))
+    } else if let Ok(list) = value.downcast_exact::<PyList>() {
+        let items = list
+            .iter()
+            .map(|item| replace_item(&item))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, items).into_py(py))
+    } else {
+        Ok(value.clone().unbind())
+    }
+}
+
+/// `{"$ref": loc}`
+pub(crate) fn ref_dict(py: Python, loc: &str) -> PyResult<PyObject> {
+    let dict = PyDict::new_bound(py);
+    dict.set_item(intern!(py, "$ref"), loc)?;
+    Ok(dict.into_py(py))
+}
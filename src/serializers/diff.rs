@@ -0,0 +1,82 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+/// The differences between `old` and `new`, both serialized, as a list of dicts of the `op`, `'add'`, `'remove'` or
+/// `'replace'`, the `path` to the value as a tuple of keys and indexes, and its value `before` and `after` the
+/// change, which is left out when the value is added or removed
+pub(super) fn diff<'py>(old: &Bound<'py, PyAny>, new: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyList>> {
+    let changes = PyList::empty_bound(old.py());
+    diff_into(&changes, &mut Vec::new(), old, new)?;
+    Ok(changes)
+}
+
+/// Dicts are compared by key and lists and tuples by index, other values are changed unless they're equal and of
+/// the same type, so `1` replacing `True` is a change
+fn diff_into<'py>(
+    changes: &Bound<'py, PyList>,
+    path: &mut Vec<Bound<'py, PyAny>>,
+    old: &Bound<'py, PyAny>,
+    new: &Bound<'py, PyAny>,
+) -> PyResult<()> {
+    let py = old.py();
+    if let (Ok(old_dict), Ok(new_dict)) = (old.downcast::<PyDict>(), new.downcast::<PyDict>()) {
+        for (key, old_value) in old_dict {
+            path.push(key.clone());
+            match new_dict.get_item(&key)? {
+                Some(new_value) => diff_into(changes, path, &old_value, &new_value)?,
+                None => add_change(changes, path, Some(&old_value), None)?,
+            }
+            path.pop();
+        }
+        for (key, new_value) in new_dict {
+            if !old_dict.contains(&key)? {
+                path.push(key);
+                add_change(changes, path, None, Some(&new_value))?;
+                path.pop();
+            }
+        }
+        return Ok(());
+    }
+    if old.get_type().is(&new.get_type()) && (old.is_instance_of::<PyList>() || old.is_instance_of::<PyTuple>()) {
+        let (old_len, new_len) = (old.len()?, new.len()?);
+        for index in 0..old_len.max(new_len) {
+            path.push(index.into_py(py).into_bound(py));
+            match (index < old_len, index < new_len) {
+                (true, true) => diff_into(changes, path, &old.get_item(index)?, &new.get_item(index)?)?,
+                (true, false) => add_change(changes, path, Some(&old.get_item(index)?), None)?,
+                _ => add_change(changes, path, None, Some(&new.get_item(index)?))?,
+            }
+            path.pop();
+        }
+        return Ok(());
+    }
+    if !(old.get_type().is(&new.get_type()) && old.eq(new)?) {
+        add_change(changes, path, Some(old), Some(new))?;
+    }
+    Ok(())
+}
+
+fn add_change<'py>(
+    changes: &Bound<'py, PyList>,
+    path: &[Bound<'py, PyAny>],
+    before: Option<&Bound<'py, PyAny>>,
+    after: Option<&Bound<'py, PyAny>>,
+) -> PyResult<()> {
+    let py = changes.py();
+    let change = PyDict::new_bound(py);
+    let op = match (before, after) {
+        (Some(_), Some(_)) => "replace",
+        (Some(_), None) => "remove",
+        _ => "add",
+    };
+    change.set_item(intern!(py, "op"), op)?;
+    change.set_item(intern!(py, "path"), PyTuple::new_bound(py, path))?;
+    if let Some(before) = before {
+        change.set_item(intern!(py, "before"), before)?;
+    }
+    if let Some(after) = after {
+        change.set_item(intern!(py, "after"), after)?;
+    }
+    changes.append(change)
+}
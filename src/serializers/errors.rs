@@ -5,15 +5,28 @@ use pyo3::prelude::*;
 
 use serde::ser;
 
+use crate::errors::PythonCallbackLimitExceeded;
+
 /// `UNEXPECTED_TYPE_SER` is a special prefix to denote a `PydanticSerializationUnexpectedValue` error.
 pub(super) static UNEXPECTED_TYPE_SER_MARKER: &str = "__PydanticSerializationUnexpectedValue__";
 pub(super) static SERIALIZATION_ERR_MARKER: &str = "__PydanticSerializationError__";
+/// `CALLBACK_LIMIT_MARKER` is a special prefix to denote a `PythonCallbackLimitExceeded` error.
+static CALLBACK_LIMIT_MARKER: &str = "__PythonCallbackLimitExceeded__";
 
 // convert a `PyErr` or `PyDowncastError` into a serde serialization error
 pub(super) fn py_err_se_err<T: ser::Error, E: fmt::Display>(py_error: E) -> T {
     T::custom(py_error.to_string())
 }
 
+// convert an error from a function serializer into a serde serialization error, keeping the type of
+// `PythonCallbackLimitExceeded` so `to_json` raises it too
+pub(super) fn function_err_se_err<T: ser::Error>(py: Python, py_error: PyErr) -> T {
+    match py_error.value_bound(py).extract::<PythonCallbackLimitExceeded>() {
+        Ok(limit) => T::custom(format!("{CALLBACK_LIMIT_MARKER}{}", limit.max_python_callbacks)),
+        Err(_) => py_err_se_err(py_error),
+    }
+}
+
 #[pyclass(extends=PyValueError, module="pydantic_core._pydantic_core")]
 #[derive(Debug, Clone)]
 pub struct PythonSerializerError {
@@ -41,6 +54,15 @@ impl serde::ser::Error for PythonSerializerError {
 
 /// convert a serde serialization error into a `PyErr`
 pub(super) fn se_err_py_err(error: PythonSerializerError) -> PyErr {
+    serialization_err_py_err(error, "JSON")
+}
+
+/// convert an error from serializing to MessagePack in `to_binary` into a `PyErr`
+pub(super) fn binary_se_err_py_err(error: rmp_serde::encode::Error) -> PyErr {
+    serialization_err_py_err(error, "binary")
+}
+
+fn serialization_err_py_err(error: impl fmt::Display, format: &str) -> PyErr {
     let s = error.to_string();
     if let Some(msg) = s.strip_prefix(UNEXPECTED_TYPE_SER_MARKER) {
         if msg.is_empty() {
@@ -50,8 +72,10 @@ pub(super) fn se_err_py_err(error: PythonSerializerError) -> PyErr {
         }
     } else if let Some(msg) = s.strip_prefix(SERIALIZATION_ERR_MARKER) {
         PydanticSerializationError::new_err(msg.to_string())
+    } else if let Some(max) = s.strip_prefix(CALLBACK_LIMIT_MARKER).and_then(|max| max.parse().ok()) {
+        PythonCallbackLimitExceeded::new_err(max)
     } else {
-        let msg = format!("Error serializing to JSON: {s}");
+        let msg = format!("Error serializing to {format}: {s}");
         PydanticSerializationError::new_err(msg)
     }
 }
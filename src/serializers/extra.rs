@@ -1,6 +1,8 @@
 use std::cell::RefCell;
 use std::fmt;
+use std::sync::Arc;
 
+use ahash::AHashMap;
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
 use pyo3::prelude::*;
@@ -8,9 +10,14 @@ use pyo3::types::{PyBool, PyString};
 
 use serde::ser::Error;
 
-use super::config::SerializationConfig;
+use super::alias_scope::AliasScope;
+use super::awaitables::CollectAwaitables;
+use super::config::{InfNanMode, SerializationConfig, TimedeltaMode};
+use super::dedupe::{ref_dict, SharedValues};
 use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
 use super::ob_type::ObTypeLookup;
+use super::runtime_settings::RuntimeSettings;
+use super::shared::{CombinedSerializer, GilRelease};
 use crate::recursion_guard::ContainsRecursionState;
 use crate::recursion_guard::RecursionError;
 use crate::recursion_guard::RecursionGuard;
@@ -65,9 +72,9 @@ impl DuckTypingSerMode {
 }
 
 impl SerializationState {
-    pub fn new(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str) -> PyResult<Self> {
+    pub fn new(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str, cycles: CyclesMode) -> PyResult<Self> {
         let warnings = CollectWarnings::new(WarningsMode::None);
-        let rec_guard = SerRecursionState::default();
+        let rec_guard = SerRecursionState::default().with_cycles(cycles);
         let config = SerializationConfig::from_args(timedelta_mode, bytes_mode, inf_nan_mode)?;
         Ok(Self {
             warnings,
@@ -76,6 +83,19 @@ impl SerializationState {
         })
     }
 
+    pub fn with_dedupe_shared(mut self, dedupe_shared: bool) -> Self {
+        self.rec_guard = self.rec_guard.with_dedupe_shared(dedupe_shared);
+        self
+    }
+
+    /// Wrap `value` in `{"$defs": {...}, "value": ...}` if `dedupe_shared` is set
+    pub fn with_defs<'py>(&self, value: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        match self.rec_guard.shared_values {
+            Some(ref shared_values) => Ok(shared_values.with_defs(&value)?.into_bound(value.py())),
+            None => Ok(value),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn extra<'py>(
         &'py self,
@@ -120,6 +140,10 @@ pub(crate) struct Extra<'a> {
     pub ob_type_lookup: &'a ObTypeLookup,
     pub warnings: &'a CollectWarnings,
     pub by_alias: bool,
+    // set when `by_alias` is a dict of locations, the scope `by_alias` came from if it has child scopes
+    pub alias_scope: Option<&'a Arc<AliasScope>>,
+    // selects which of the `serialization_alias_profiles` of fields are used
+    pub alias_profile: Option<&'a str>,
     pub exclude_unset: bool,
     pub exclude_defaults: bool,
     pub exclude_none: bool,
@@ -137,6 +161,20 @@ pub(crate) struct Extra<'a> {
     pub fallback: Option<&'a Bound<'a, PyAny>>,
     pub duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<&'a Bound<'a, PyAny>>,
+    // set by `to_python_async` and `to_json_async` to allow function serializers to return awaitables
+    pub awaitables: Option<&'a CollectAwaitables>,
+    // overrides from `pydantic_core.runtime_settings()`, these take priority over the serializer's config
+    pub runtime: RuntimeSettings,
+    // set by `to_json` when `ser_json_release_gil_every` is configured
+    pub gil_release: Option<&'a GilRelease>,
+}
+
+fn ob_type_lookup<'py>(py: Python<'py>, config: &SerializationConfig) -> &'py ObTypeLookup {
+    if config.numpy_support {
+        ObTypeLookup::cached_numpy(py)
+    } else {
+        ObTypeLookup::cached(py)
+    }
 }
 
 impl<'a> Extra<'a> {
@@ -159,9 +197,11 @@ impl<'a> Extra<'a> {
     ) -> Self {
         Self {
             mode,
-            ob_type_lookup: ObTypeLookup::cached(py),
+            ob_type_lookup: ob_type_lookup(py, config),
             warnings,
             by_alias,
+            alias_scope: None,
+            alias_profile: None,
             exclude_unset,
             exclude_defaults,
             exclude_none,
@@ -175,6 +215,37 @@ impl<'a> Extra<'a> {
             fallback,
             duck_typing_ser_mode,
             context,
+            awaitables: None,
+            runtime: RuntimeSettings::default(),
+            gil_release: None,
+        }
+    }
+
+    /// `by_alias` for the field `field_name` of the current model, typed dict or dataclass, and its alias scope
+    pub fn field_alias_scope(&self, field_name: &str) -> (bool, Option<&'a Arc<AliasScope>>) {
+        match self.alias_scope {
+            Some(scope) => scope.field(field_name, self.by_alias),
+            None => (self.by_alias, None),
+        }
+    }
+
+    pub fn timedelta_mode(&self, mode: TimedeltaMode) -> TimedeltaMode {
+        self.runtime.timedelta_mode.unwrap_or(mode)
+    }
+
+    pub fn inf_nan_mode(&self, mode: InfNanMode) -> InfNanMode {
+        self.runtime.inf_nan_mode.unwrap_or(mode)
+    }
+
+    /// Count a call of a function serializer, see `max_python_callbacks`
+    pub fn count_python_callback(&self) -> PyResult<()> {
+        self.rec_guard.guard.borrow().count_python_callback()
+    }
+
+    /// Called by JSON serializers when starting a list, tuple, set or dict, see `ser_json_release_gil_every`
+    pub fn maybe_release_gil(&self, py: Python) {
+        if let Some(gil_release) = self.gil_release {
+            gil_release.maybe_release(py);
         }
     }
 
@@ -188,10 +259,69 @@ impl<'a> Extra<'a> {
         value: &Bound<'_, PyAny>,
         def_ref_id: usize,
     ) -> PyResult<RecursionGuard<'x, &'y Self>> {
-        RecursionGuard::new(self, value.as_ptr() as usize, def_ref_id).map_err(|e| match e {
-            RecursionError::Depth => PyValueError::new_err("Circular reference detected (depth exceeded)"),
-            RecursionError::Cyclic => PyValueError::new_err("Circular reference detected (id repeated)"),
-        })
+        self.cycle_guard(value, def_ref_id)?
+            .map_err(|_| PyValueError::new_err("Circular reference detected (id repeated)"))
+    }
+
+    /// Like `recursion_guard`, but when `value` is already being serialized and `cycles` isn't `'error'`,
+    /// returns what should be serialized in its place
+    pub fn cycle_guard<'x, 'y>(
+        self: &'x mut &'y Self,
+        value: &Bound<'_, PyAny>,
+        def_ref_id: usize,
+    ) -> PyResult<Result<RecursionGuard<'x, &'y Self>, SerCycle>> {
+        let rec_guard = self.rec_guard;
+        let key = (value.as_ptr() as usize, def_ref_id);
+        match RecursionGuard::new(self, key.0, key.1) {
+            Ok(guard) => {
+                rec_guard.see(key);
+                Ok(Ok(guard))
+            }
+            Err(RecursionError::Depth) => Err(PyValueError::new_err("Circular reference detected (depth exceeded)")),
+            Err(RecursionError::Cyclic) => match rec_guard.cycles {
+                CyclesMode::Error => Err(PyValueError::new_err("Circular reference detected (id repeated)")),
+                CyclesMode::Omit => Ok(Err(SerCycle::Omit)),
+                CyclesMode::Ref => Ok(Err(SerCycle::Ref(rec_guard.seen_loc(key)))),
+            },
+        }
+    }
+
+    /// Call `f` with `segment` appended to the location of the value being serialized,
+    /// locations are only tracked with `cycles='ref'`
+    pub fn with_loc<R>(&self, segment: &dyn fmt::Display, f: impl FnOnce() -> R) -> R {
+        if self.rec_guard.cycles != CyclesMode::Ref {
+            return f();
+        }
+        // escaped as a JSON pointer reference token
+        let segment = segment.to_string().replace('~', "~0").replace('/', "~1");
+        self.rec_guard.loc.borrow_mut().push(segment);
+        let result = f();
+        self.rec_guard.loc.borrow_mut().pop();
+        result
+    }
+
+    /// Serialize `item` at `segment` with `f` using `serializer`, or inference if it's `None`. With `dedupe_shared`
+    /// the output of an item already serialized by the same serializer, without include or exclude, is reused
+    pub fn item_to_python(
+        &self,
+        item: &Bound<'_, PyAny>,
+        serializer: Option<&CombinedSerializer>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        segment: &dyn fmt::Display,
+        f: impl FnOnce() -> PyResult<PyObject>,
+    ) -> PyResult<PyObject> {
+        let shared_values = match self.rec_guard.shared_values {
+            Some(ref shared_values) if include.is_none() && exclude.is_none() => shared_values,
+            _ => return self.with_loc(segment, f),
+        };
+        let serializer_id = serializer.map_or(0, |s| std::ptr::from_ref(s) as usize);
+        if let Some(output) = shared_values.get(item, serializer_id) {
+            return Ok(output);
+        }
+        let output = self.with_loc(segment, f)?;
+        shared_values.insert(item, serializer_id, output.bind(item.py()));
+        Ok(output)
     }
 
     pub fn serialize_infer<'py>(&'py self, value: &'py Bound<'py, PyAny>) -> super::infer::SerializeInfer<'py> {
@@ -222,6 +352,8 @@ pub(crate) struct ExtraOwned {
     mode: SerMode,
     warnings: CollectWarnings,
     by_alias: bool,
+    alias_scope: Option<Arc<AliasScope>>,
+    alias_profile: Option<String>,
     exclude_unset: bool,
     exclude_defaults: bool,
     exclude_none: bool,
@@ -235,6 +367,7 @@ pub(crate) struct ExtraOwned {
     pub fallback: Option<PyObject>,
     duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<PyObject>,
+    runtime: RuntimeSettings,
 }
 
 impl ExtraOwned {
@@ -243,6 +376,8 @@ impl ExtraOwned {
             mode: extra.mode.clone(),
             warnings: extra.warnings.clone(),
             by_alias: extra.by_alias,
+            alias_scope: extra.alias_scope.cloned(),
+            alias_profile: extra.alias_profile.map(ToString::to_string),
             exclude_unset: extra.exclude_unset,
             exclude_defaults: extra.exclude_defaults,
             exclude_none: extra.exclude_none,
@@ -256,15 +391,18 @@ impl ExtraOwned {
             fallback: extra.fallback.map(|model| model.clone().into()),
             duck_typing_ser_mode: extra.duck_typing_ser_mode,
             context: extra.context.map(|model| model.clone().into()),
+            runtime: extra.runtime,
         }
     }
 
     pub fn to_extra<'py>(&'py self, py: Python<'py>) -> Extra<'py> {
         Extra {
             mode: &self.mode,
-            ob_type_lookup: ObTypeLookup::cached(py),
+            ob_type_lookup: ob_type_lookup(py, &self.config),
             warnings: &self.warnings,
             by_alias: self.by_alias,
+            alias_scope: self.alias_scope.as_ref(),
+            alias_profile: self.alias_profile.as_deref(),
             exclude_unset: self.exclude_unset,
             exclude_defaults: self.exclude_defaults,
             exclude_none: self.exclude_none,
@@ -278,6 +416,9 @@ impl ExtraOwned {
             fallback: self.fallback.as_ref().map(|m| m.bind(py)),
             duck_typing_ser_mode: self.duck_typing_ser_mode,
             context: self.context.as_ref().map(|m| m.bind(py)),
+            awaitables: None,
+            runtime: self.runtime,
+            gil_release: None,
         }
     }
 }
@@ -458,10 +599,110 @@ impl CollectWarnings {
     }
 }
 
+/// How a value is serialized when it's found inside itself, from the `cycles` argument
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CyclesMode {
+    #[default]
+    Error,
+    Omit,
+    Ref,
+}
+
+impl<'py> FromPyObject<'py> for CyclesMode {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<CyclesMode> {
+        match ob.extract::<&str>() {
+            Ok("error") => Ok(Self::Error),
+            Ok("omit") => Ok(Self::Omit),
+            Ok("ref") => Ok(Self::Ref),
+            _ => Err(PyValueError::new_err(
+                "Invalid cycles parameter, should be `'error'`, `'omit'` or `'ref'`",
+            )),
+        }
+    }
+}
+
+/// What's serialized in place of a value found inside itself
+pub(crate) enum SerCycle {
+    /// `cycles='omit'`, the value is serialized as `None`
+    Omit,
+    /// `cycles='ref'`, the value is serialized as `{"$ref": <JSON pointer to where it was first serialized>}`
+    Ref(String),
+}
+
+impl SerCycle {
+    pub fn to_python(&self, py: Python) -> PyResult<PyObject> {
+        match self {
+            Self::Omit => Ok(py.None()),
+            Self::Ref(loc) => ref_dict(py, loc),
+        }
+    }
+
+    pub fn serde_serialize<S: serde::ser::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        match self {
+            Self::Omit => serializer.serialize_none(),
+            Self::Ref(loc) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("$ref", loc)?;
+                map.end()
+            }
+        }
+    }
+}
+
 #[derive(Default, Clone)]
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub struct SerRecursionState {
     guard: RefCell<RecursionState>,
+    cycles: CyclesMode,
+    // with `cycles='ref'`, the JSON pointer reference tokens of the value being serialized
+    loc: RefCell<Vec<String>>,
+    // with `cycles='ref'`, the location each guarded value was last entered at
+    seen: RefCell<AHashMap<(usize, usize), String>>,
+    // set with `dedupe_shared`
+    pub shared_values: Option<SharedValues>,
+}
+
+impl SerRecursionState {
+    pub fn new(max_python_callbacks: Option<u64>) -> Self {
+        Self {
+            guard: RefCell::new(RecursionState::default().with_max_python_callbacks(max_python_callbacks)),
+            ..Self::default()
+        }
+    }
+
+    pub fn with_cycles(mut self, cycles: CyclesMode) -> Self {
+        self.cycles = cycles;
+        self
+    }
+
+    pub fn with_dedupe_shared(mut self, dedupe_shared: bool) -> Self {
+        self.shared_values = dedupe_shared.then(SharedValues::default);
+        self
+    }
+
+    fn see(&self, key: (usize, usize)) {
+        if self.cycles == CyclesMode::Ref {
+            let loc = self.current_loc();
+            self.seen.borrow_mut().insert(key, loc);
+        }
+    }
+
+    fn seen_loc(&self, key: (usize, usize)) -> String {
+        self.seen
+            .borrow()
+            .get(&key)
+            .cloned()
+            .unwrap_or_else(|| self.current_loc())
+    }
+
+    fn current_loc(&self) -> String {
+        self.loc
+            .borrow()
+            .iter()
+            .fold("#".to_string(), |loc, segment| loc + "/" + segment)
+    }
 }
 
 impl ContainsRecursionState for &'_ Extra<'_> {
@@ -0,0 +1,131 @@
+use std::cell::{Cell, RefCell};
+use std::io;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde_json::ser::Formatter;
+
+/// The byte size of each member of the top level JSON object, recorded while it's written by wrapping the
+/// writer and formatter with `FieldSizesWriter` and `FieldSizesFormatter`
+#[derive(Debug, Default)]
+pub(crate) struct FieldSizes {
+    written: Cell<usize>,
+    // the start and end of the key, and the end of the value, of each member
+    members: RefCell<Vec<(usize, usize, usize)>>,
+}
+
+impl FieldSizes {
+    /// A dict of each key to the size of its `"key":value` member, `bytes` must be the JSON written
+    pub fn to_dict<'py>(&self, py: Python<'py>, bytes: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        for &(key_start, key_end, member_end) in self.members.borrow().iter() {
+            let key: String = serde_json::from_slice(&bytes[key_start..key_end]).expect("keys are JSON strings");
+            dict.set_item(key, member_end - key_start)?;
+        }
+        Ok(dict)
+    }
+}
+
+pub(crate) struct FieldSizesWriter<'a, W> {
+    pub inner: W,
+    pub sizes: &'a FieldSizes,
+}
+
+impl<W: io::Write> io::Write for FieldSizesWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.sizes.written.set(self.sizes.written.get() + written);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+pub(crate) struct FieldSizesFormatter<'a, F> {
+    inner: F,
+    sizes: &'a FieldSizes,
+    depth: usize,
+    root_object: bool,
+    key_start: usize,
+    key_end: usize,
+}
+
+impl<'a, F> FieldSizesFormatter<'a, F> {
+    pub fn new(inner: F, sizes: &'a FieldSizes) -> Self {
+        Self {
+            inner,
+            sizes,
+            depth: 0,
+            root_object: false,
+            key_start: 0,
+            key_end: 0,
+        }
+    }
+
+    fn in_root_object(&self) -> bool {
+        self.root_object && self.depth == 1
+    }
+}
+
+// only the methods `CompactFormatter` or `PrettyFormatter` override are forwarded, the defaults are used for the rest
+impl<F: Formatter> Formatter for FieldSizesFormatter<'_, F> {
+    fn begin_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.depth += 1;
+        self.inner.begin_array(writer)
+    }
+
+    fn end_array<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.depth -= 1;
+        self.inner.end_array(writer)
+    }
+
+    fn begin_array_value<W: ?Sized + io::Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        self.inner.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.depth == 0 {
+            self.root_object = true;
+        }
+        self.depth += 1;
+        self.inner.begin_object(writer)
+    }
+
+    fn end_object<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.depth -= 1;
+        self.inner.end_object(writer)
+    }
+
+    fn begin_object_key<W: ?Sized + io::Write>(&mut self, writer: &mut W, first: bool) -> io::Result<()> {
+        self.inner.begin_object_key(writer, first)?;
+        if self.in_root_object() {
+            self.key_start = self.sizes.written.get();
+        }
+        Ok(())
+    }
+
+    fn end_object_key<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.in_root_object() {
+            self.key_end = self.sizes.written.get();
+        }
+        self.inner.end_object_key(writer)
+    }
+
+    fn begin_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        self.inner.begin_object_value(writer)
+    }
+
+    fn end_object_value<W: ?Sized + io::Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if self.in_root_object() {
+            let member = (self.key_start, self.key_end, self.sizes.written.get());
+            self.sizes.members.borrow_mut().push(member);
+        }
+        self.inner.end_object_value(writer)
+    }
+}
@@ -1,14 +1,17 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use serde::ser::SerializeMap;
 use smallvec::SmallVec;
 
+use crate::build_tools::{py_schema_err, AliasGenerator};
 use crate::serializers::extra::SerCheck;
 use crate::serializers::DuckTypingSerMode;
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::computed_fields::ComputedFields;
@@ -19,12 +22,16 @@ use super::infer::{infer_json_key, infer_serialize, infer_to_python, SerializeIn
 use super::shared::PydanticSerializer;
 use super::shared::{CombinedSerializer, TypeSerializer};
 
+type InternedAliases = Vec<(String, Py<PyString>)>;
+
 /// representation of a field for serialization
 #[derive(Debug, Clone)]
 pub(super) struct SerField {
     pub key_py: Py<PyString>,
-    pub alias: Option<String>,
-    pub alias_py: Option<Py<PyString>>,
+    // usually zero or one, a field is serialized under each alias when there are several
+    pub aliases: InternedAliases,
+    // aliases used instead of `aliases` when the `alias_profile` they're keyed by is selected
+    pub profile_aliases: AHashMap<String, InternedAliases>,
     // None serializer means exclude
     pub serializer: Option<CombinedSerializer>,
     pub required: bool,
@@ -36,39 +43,170 @@ impl SerField {
     pub fn new(
         py: Python,
         key_py: Py<PyString>,
-        alias: Option<String>,
+        aliases: SerAliases,
         serializer: Option<CombinedSerializer>,
         required: bool,
     ) -> Self {
-        let alias_py = alias
-            .as_ref()
-            .map(|alias| PyString::new_bound(py, alias.as_str()).into());
+        // interning means output keys are shared with the keys created by validation,
+        // rather than allocating a new string for each serializer
+        let intern = |aliases: Vec<String>| -> InternedAliases {
+            aliases
+                .into_iter()
+                .map(|alias| {
+                    let alias_py = PyString::intern_bound(py, alias.as_str()).into();
+                    (alias, alias_py)
+                })
+                .collect()
+        };
         Self {
             key_py,
-            alias,
-            alias_py,
+            aliases: intern(aliases.default),
+            profile_aliases: aliases
+                .profiles
+                .into_iter()
+                .map(|(profile, aliases)| (profile, intern(aliases)))
+                .collect(),
             serializer,
             required,
         }
     }
 
-    pub fn get_key_py<'py>(&'py self, py: Python<'py>, extra: &Extra) -> &Bound<'py, PyAny> {
+    /// The aliases for `alias_profile`, or the default aliases if there's no profile or none for this field
+    fn profile_aliases(&self, alias_profile: Option<&str>) -> &[(String, Py<PyString>)] {
+        alias_profile
+            .and_then(|profile| self.profile_aliases.get(profile))
+            .unwrap_or(&self.aliases)
+    }
+
+    /// The aliases the field is serialized under, empty unless `by_alias` is set
+    fn active_aliases(&self, extra: &Extra) -> &[(String, Py<PyString>)] {
         if extra.by_alias {
-            if let Some(ref alias_py) = self.alias_py {
-                return alias_py.bind(py);
-            }
+            self.profile_aliases(extra.alias_profile)
+        } else {
+            &[]
+        }
+    }
+
+    pub fn get_key_py<'py>(&'py self, py: Python<'py>, extra: &Extra) -> &Bound<'py, PyAny> {
+        match self.active_aliases(extra).first() {
+            Some((_, alias_py)) => alias_py.bind(py),
+            None => self.key_py.bind(py),
         }
-        self.key_py.bind(py)
     }
 
     pub fn get_key_json<'a>(&'a self, key_str: &'a str, extra: &Extra) -> Cow<'a, str> {
-        if extra.by_alias {
-            if let Some(ref alias) = self.alias {
-                return Cow::Borrowed(alias.as_str());
+        match self.active_aliases(extra).first() {
+            Some((alias, _)) => Cow::Borrowed(alias.as_str()),
+            None => Cow::Borrowed(key_str),
+        }
+    }
+
+    /// Aliases after the first, which the field is also serialized under when `by_alias` is set
+    pub fn get_extra_aliases(&self, extra: &Extra) -> &[(String, Py<PyString>)] {
+        match self.active_aliases(extra).split_first() {
+            Some((_, rest)) => rest,
+            None => &[],
+        }
+    }
+}
+
+/// The aliases of a field from `serialization_alias` and `serialization_alias_profiles`
+#[derive(Debug, Default)]
+pub(super) struct SerAliases {
+    default: Vec<String>,
+    profiles: Vec<(String, Vec<String>)>,
+}
+
+/// `serialization_alias` is either a single alias or a list of aliases to serialize the field under,
+/// as are the values of `serialization_alias_profiles`, without `serialization_alias` the alias is generated
+/// by `alias_generator` if it's set
+pub(super) fn get_serialization_aliases(
+    field_info: &Bound<'_, PyDict>,
+    field_name: &str,
+    alias_generator: Option<AliasGenerator>,
+) -> PyResult<SerAliases> {
+    let py = field_info.py();
+    let default = match (
+        field_info.get_item(intern!(py, "serialization_alias"))?,
+        alias_generator,
+    ) {
+        (Some(alias), _) => extract_aliases(&alias)?,
+        (None, Some(alias_generator)) => vec![alias_generator.apply(field_name)],
+        (None, None) => Vec::new(),
+    };
+    let profiles = match field_info.get_as::<Bound<'_, PyDict>>(intern!(py, "serialization_alias_profiles"))? {
+        Some(profiles) => profiles
+            .iter()
+            .map(|(profile, aliases)| Ok((profile.extract()?, extract_aliases(&aliases)?)))
+            .collect::<PyResult<_>>()?,
+        None => Vec::new(),
+    };
+    Ok(SerAliases { default, profiles })
+}
+
+fn extract_aliases(aliases: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+    match aliases.downcast::<PyString>() {
+        Ok(alias) => Ok(vec![alias.extract()?]),
+        Err(_) => aliases.extract(),
+    }
+}
+
+/// With `alias_priority='first_non_colliding'`, keep only the first alias of each field, by default and for each
+/// alias profile, which isn't the name of another field or kept for an earlier field in `field_names`,
+/// fields without such an alias are serialized by name
+pub(super) fn apply_alias_priority(
+    schema: &Bound<'_, PyDict>,
+    fields: &mut AHashMap<String, SerField>,
+    field_names: &[String],
+) -> PyResult<()> {
+    let py = schema.py();
+    match schema.get_as::<Bound<'_, PyString>>(intern!(py, "alias_priority"))? {
+        None => return Ok(()),
+        Some(priority) => match priority.to_str()? {
+            "all" => return Ok(()),
+            "first_non_colliding" => (),
+            other => {
+                return py_schema_err!(
+                    "Invalid alias_priority: `{}`, expected `all` or `first_non_colliding`",
+                    other
+                )
             }
+        },
+    }
+
+    let collides = |alias: &str, field_name: &str| {
+        alias != field_name && fields.get(alias).is_some_and(|field| field.serializer.is_some())
+    };
+    let profiles: AHashSet<&String> = fields.values().flat_map(|field| field.profile_aliases.keys()).collect();
+    let mut kept: Vec<(&String, Option<&String>, InternedAliases)> = Vec::new();
+    for profile in std::iter::once(None).chain(profiles.into_iter().map(Some)) {
+        let mut taken: AHashSet<&str> = AHashSet::new();
+        for field_name in field_names {
+            let aliases = fields[field_name].profile_aliases(profile.map(String::as_str));
+            let first = aliases
+                .iter()
+                .find(|(alias, _)| !collides(alias, field_name) && !taken.contains(alias.as_str()));
+            if let Some((alias, _)) = first {
+                taken.insert(alias);
+            }
+            kept.push((field_name, profile, first.cloned().into_iter().collect()));
+        }
+    }
+
+    let kept: Vec<(String, Option<String>, InternedAliases)> = kept
+        .into_iter()
+        .map(|(field_name, profile, aliases)| (field_name.clone(), profile.cloned(), aliases))
+        .collect();
+    for (field_name, profile, aliases) in kept {
+        let field = fields.get_mut(&field_name).expect("field names are keys of fields");
+        match profile {
+            Some(profile) => {
+                field.profile_aliases.insert(profile, aliases);
+            }
+            None => field.aliases = aliases,
         }
-        Cow::Borrowed(key_str)
     }
+    Ok(())
 }
 
 fn exclude_default(value: &Bound<'_, PyAny>, extra: &Extra, serializer: &CombinedSerializer) -> PyResult<bool> {
@@ -131,6 +269,33 @@ impl GeneralFieldsSerializer {
         }
     }
 
+    /// The name of each field which isn't excluded with the keys it's serialized under by alias with
+    /// `alias_profile`, sorted by name since fields are serialized in the order of the value, then computed fields
+    pub(crate) fn field_aliases(&self, alias_profile: Option<&str>) -> Vec<(&str, Vec<&str>)> {
+        let mut field_aliases: Vec<(&str, Vec<&str>)> = self
+            .fields
+            .iter()
+            .filter(|(_, field)| field.serializer.is_some())
+            .map(|(name, field)| {
+                let aliases = match field.profile_aliases(alias_profile) {
+                    [] => vec![name.as_str()],
+                    aliases => aliases.iter().map(|(alias, _)| alias.as_str()).collect(),
+                };
+                (name.as_str(), aliases)
+            })
+            .collect();
+        field_aliases.sort_unstable_by_key(|(name, _)| *name);
+        if let Some(ref computed_fields) = self.computed_fields {
+            field_aliases.extend(computed_fields.aliases().map(|(name, alias)| (name, vec![alias])));
+        }
+        field_aliases
+    }
+
+    /// The serializer of the field called `name`, `None` if it's excluded
+    pub(crate) fn field_serializer(&self, name: &str) -> Option<&CombinedSerializer> {
+        self.fields.get(name).and_then(|field| field.serializer.as_ref())
+    }
+
     fn extract_dicts<'a>(&self, value: &Bound<'a, PyAny>) -> Option<(Bound<'a, PyDict>, Option<Bound<'a, PyDict>>)> {
         match self.mode {
             FieldsMode::ModelExtra => value.extract().ok(),
@@ -144,6 +309,22 @@ impl GeneralFieldsSerializer {
         }
     }
 
+    /// Serialize the value of a key which isn't a field with `extra_serializer`, or by inference if it's not set
+    fn extra_to_python(
+        &self,
+        key: &Bound<'_, PyAny>,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let serializer = self.extra_serializer.as_deref();
+        extra.item_to_python(value, serializer, include, exclude, key, || match serializer {
+            Some(serializer) => serializer.to_python(value, include, exclude, extra),
+            None => infer_to_python(value, include, exclude, extra),
+        })
+    }
+
     pub(crate) fn main_to_python<'py>(
         &self,
         py: Python<'py>,
@@ -168,21 +349,30 @@ impl GeneralFieldsSerializer {
                 }
                 continue;
             }
+            let (by_alias, alias_scope) = extra.field_alias_scope(&key_str);
             let field_extra = Extra {
                 field_name: Some(&key_str),
+                by_alias,
+                alias_scope,
                 ..extra
             };
             if let Some((next_include, next_exclude)) = self.filter.key_filter(&key, include, exclude)? {
                 if let Some(field) = op_field {
                     if let Some(ref serializer) = field.serializer {
                         if !exclude_default(&value, &field_extra, serializer)? {
-                            let value = serializer.to_python(
+                            let output_key = field.get_key_py(py, &field_extra);
+                            let (next_include, next_exclude) = (next_include.as_ref(), next_exclude.as_ref());
+                            let value = field_extra.item_to_python(
                                 &value,
-                                next_include.as_ref(),
-                                next_exclude.as_ref(),
-                                &field_extra,
+                                Some(serializer),
+                                next_include,
+                                next_exclude,
+                                output_key,
+                                || serializer.to_python(&value, next_include, next_exclude, &field_extra),
                             )?;
-                            let output_key = field.get_key_py(output_dict.py(), &field_extra);
+                            for (_, alias_py) in field.get_extra_aliases(&field_extra) {
+                                output_dict.set_item(alias_py.bind(py), &value)?;
+                            }
                             output_dict.set_item(output_key, value)?;
                         }
                     }
@@ -191,12 +381,8 @@ impl GeneralFieldsSerializer {
                         used_req_fields += 1;
                     }
                 } else if self.mode == FieldsMode::TypedDictAllow {
-                    let value = match &self.extra_serializer {
-                        Some(serializer) => {
-                            serializer.to_python(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra)?
-                        }
-                        None => infer_to_python(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra)?,
-                    };
+                    let value =
+                        self.extra_to_python(&key, &value, next_include.as_ref(), next_exclude.as_ref(), &field_extra)?;
                     output_dict.set_item(key, value)?;
                 } else if field_extra.check == SerCheck::Strict {
                     return Err(PydanticSerializationUnexpectedValue::new_err(None));
@@ -235,8 +421,11 @@ impl GeneralFieldsSerializer {
                 continue;
             }
             let key_str = key_str(&key).map_err(py_err_se_err)?;
+            let (by_alias, alias_scope) = extra.field_alias_scope(&key_str);
             let field_extra = Extra {
                 field_name: Some(&key_str),
+                by_alias,
+                alias_scope,
                 ..extra
             };
 
@@ -253,13 +442,16 @@ impl GeneralFieldsSerializer {
                                 &field_extra,
                             );
                             let output_key = field.get_key_json(&key_str, &field_extra);
-                            map.serialize_entry(&output_key, &s)?;
+                            field_extra.with_loc(&output_key, || map.serialize_entry(&output_key, &s))?;
+                            for (alias, _) in field.get_extra_aliases(&field_extra) {
+                                map.serialize_entry(alias, &s)?;
+                            }
                         }
                     }
                 } else if self.mode == FieldsMode::TypedDictAllow {
                     let output_key = infer_json_key(&key, &field_extra).map_err(py_err_se_err)?;
                     let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), &field_extra);
-                    map.serialize_entry(&output_key, &s)?;
+                    field_extra.with_loc(&output_key, || map.serialize_entry(&output_key, &s))?;
                 }
                 // no error case here since unions (which need the error case) use `to_python(..., mode='json')`
             }
@@ -355,12 +547,8 @@ impl TypeSerializer for GeneralFieldsSerializer {
                     continue;
                 }
                 if let Some((next_include, next_exclude)) = self.filter.key_filter(&key, include, exclude)? {
-                    let value = match &self.extra_serializer {
-                        Some(serializer) => {
-                            serializer.to_python(&value, next_include.as_ref(), next_exclude.as_ref(), extra)?
-                        }
-                        None => infer_to_python(&value, next_include.as_ref(), next_exclude.as_ref(), extra)?,
-                    };
+                    let value =
+                        self.extra_to_python(&key, &value, next_include.as_ref(), next_exclude.as_ref(), extra)?;
                     output_dict.set_item(key, value)?;
                 }
             }
@@ -411,6 +599,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
             FieldsMode::TypedDictAllow => main_dict.len() + self.computed_field_count(),
             _ => self.fields.len() + option_length!(extra_dict) + self.computed_field_count(),
         };
+        extra.maybe_release_gil(value.py());
         // NOTE! As above, we maintain the order of the input dict assuming that's right
         // we don't both with `used_fields` here because on unions, `to_python(..., mode='json')` is used
         let mut map = self.main_serde_serialize(
@@ -432,7 +621,7 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 if let Some((next_include, next_exclude)) = filter {
                     let output_key = infer_json_key(&key, extra).map_err(py_err_se_err)?;
                     let s = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), extra);
-                    map.serialize_entry(&output_key, &s)?;
+                    extra.with_loc(&output_key, || map.serialize_entry(&output_key, &s))?;
                 }
             }
         }
@@ -4,11 +4,15 @@ use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::pybacked::PyBackedStr;
-use pyo3::types::{PyByteArray, PyBytes, PyDict, PyFrozenSet, PyIterator, PyList, PySet, PyString, PyTuple};
+use pyo3::types::{
+    PyByteArray, PyBytes, PyDict, PyFrozenSet, PyIterator, PyList, PyMemoryView, PySet, PyString, PyTuple,
+};
 
 use serde::ser::{Error, Serialize, SerializeMap, SerializeSeq, Serializer};
 
 use crate::input::{EitherTimedelta, Int};
+use crate::phone::PyPhoneNumber;
+use crate::read_only::read_only_inner;
 use crate::serializers::type_serializers;
 use crate::tools::{extract_i64, py_err, safe_repr};
 use crate::url::{PyMultiHostUrl, PyUrl};
@@ -45,8 +49,9 @@ pub(crate) fn infer_to_python_known(
     let py = value.py();
 
     let mode = extra.mode;
-    let mut guard = match extra.recursion_guard(value, INFER_DEF_REF_ID) {
-        Ok(v) => v,
+    let mut guard = match extra.cycle_guard(value, INFER_DEF_REF_ID) {
+        Ok(Ok(v)) => v,
+        Ok(Err(cycle)) => return cycle.to_python(py),
         Err(e) => {
             return match mode {
                 SerMode::Json => Err(e),
@@ -77,12 +82,12 @@ pub(crate) fn infer_to_python_known(
             for (index, element) in py_seq.iter().enumerate() {
                 let op_next = filter.index_filter(index, include, exclude, len)?;
                 if let Some((next_include, next_exclude)) = op_next {
-                    items.push(infer_to_python(
-                        &element,
-                        next_include.as_ref(),
-                        next_exclude.as_ref(),
-                        extra,
-                    )?);
+                    let (next_include, next_exclude) = (next_include.as_ref(), next_exclude.as_ref());
+                    let item =
+                        extra.item_to_python(&element, None, next_include, next_exclude, &items.len(), || {
+                            infer_to_python(&element, next_include, next_exclude, extra)
+                        })?;
+                    items.push(item);
                 }
             }
             items
@@ -106,6 +111,7 @@ pub(crate) fn infer_to_python_known(
             extra.fallback,
             extra.duck_typing_ser_mode,
             extra.context,
+            extra.runtime,
         );
         serializer.serializer.to_python(value, include, exclude, &extra)
     };
@@ -121,7 +127,8 @@ pub(crate) fn infer_to_python_known(
             },
             ObType::Float | ObType::FloatSubclass => {
                 let v = value.extract::<f64>()?;
-                if (v.is_nan() || v.is_infinite()) && extra.config.inf_nan_mode == InfNanMode::Null {
+                if (v.is_nan() || v.is_infinite()) && extra.inf_nan_mode(extra.config.inf_nan_mode) == InfNanMode::Null
+                {
                     return Ok(py.None().into_py(py));
                 }
                 v.into_py(py)
@@ -181,8 +188,7 @@ pub(crate) fn infer_to_python_known(
             ObType::Timedelta => {
                 let either_delta = EitherTimedelta::try_from(value)?;
                 extra
-                    .config
-                    .timedelta_mode
+                    .timedelta_mode(extra.config.timedelta_mode)
                     .either_delta_to_json(value.py(), &either_delta)?
             }
             ObType::Url => {
@@ -193,6 +199,10 @@ pub(crate) fn infer_to_python_known(
                 let py_url: PyMultiHostUrl = value.extract()?;
                 py_url.__str__().into_py(py)
             }
+            ObType::PhoneNumber => {
+                let py_phone: PyPhoneNumber = value.extract()?;
+                py_phone.__str__().into_py(py)
+            }
             ObType::Uuid => {
                 let uuid = super::type_serializers::uuid::uuid_to_string(value)?;
                 uuid.into_py(py)
@@ -228,7 +238,15 @@ pub(crate) fn infer_to_python_known(
             }
             ObType::Path => value.str()?.into_py(py),
             ObType::Pattern => value.getattr(intern!(py, "pattern"))?.into_py(py),
+            ObType::NumpyScalar => {
+                let item = value.call_method0(intern!(py, "item"))?;
+                infer_to_python(&item, include, exclude, extra)?
+            }
+            ObType::NumpyArray => infer_to_python(&numpy_array_to_list(value)?, include, exclude, extra)?,
             ObType::Unknown => {
+                if let Some(inner) = read_only_inner(value) {
+                    return infer_to_python(&inner, include, exclude, extra);
+                }
                 if let Some(fallback) = extra.fallback {
                     let next_value = fallback.call1((value,))?;
                     let next_result = infer_to_python(&next_value, include, exclude, extra);
@@ -266,7 +284,7 @@ pub(crate) fn infer_to_python_known(
             ObType::Generator => {
                 let iter = super::type_serializers::generator::SerializationIterator::new(
                     value.downcast()?,
-                    super::type_serializers::any::AnySerializer.into(),
+                    super::type_serializers::any::AnySerializer::INFER.into(),
                     SchemaFilter::default(),
                     include,
                     exclude,
@@ -275,6 +293,9 @@ pub(crate) fn infer_to_python_known(
                 iter.into_py(py)
             }
             ObType::Unknown => {
+                if let Some(inner) = read_only_inner(value) {
+                    return infer_to_python(&inner, include, exclude, extra);
+                }
                 if let Some(fallback) = extra.fallback {
                     let next_value = fallback.call1((value,))?;
                     let next_result = infer_to_python(&next_value, include, exclude, extra);
@@ -344,8 +365,9 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
     mut extra: &Extra,
 ) -> Result<S::Ok, S::Error> {
     let extra_serialize_unknown = extra.serialize_unknown;
-    let mut guard = match extra.recursion_guard(value, INFER_DEF_REF_ID) {
-        Ok(v) => v,
+    let mut guard = match extra.cycle_guard(value, INFER_DEF_REF_ID) {
+        Ok(Ok(v)) => v,
+        Ok(Err(cycle)) => return cycle.serde_serialize(serializer),
         Err(e) => {
             return if extra_serialize_unknown {
                 serializer.serialize_str("...")
@@ -368,6 +390,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
     macro_rules! serialize_seq {
         ($t:ty) => {{
             let py_seq = value.downcast::<$t>().map_err(py_err_se_err)?;
+            extra.maybe_release_gil(value.py());
             let mut seq = serializer.serialize_seq(Some(py_seq.len()))?;
             for element in py_seq.iter() {
                 let item_serializer = SerializeInfer::new(&element, include, exclude, extra);
@@ -380,9 +403,11 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
     macro_rules! serialize_seq_filter {
         ($t:ty) => {{
             let py_seq = value.downcast::<$t>().map_err(py_err_se_err)?;
+            extra.maybe_release_gil(value.py());
             let mut seq = serializer.serialize_seq(Some(py_seq.len()))?;
             let filter = AnyFilter::new();
             let len = value.len().ok();
+            let mut position = 0;
 
             for (index, element) in py_seq.iter().enumerate() {
                 let op_next = filter
@@ -391,7 +416,8 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 if let Some((next_include, next_exclude)) = op_next {
                     let item_serializer =
                         SerializeInfer::new(&element, next_include.as_ref(), next_exclude.as_ref(), extra);
-                    seq.serialize_element(&item_serializer)?
+                    extra.with_loc(&position, || seq.serialize_element(&item_serializer))?;
+                    position += 1;
                 }
             }
             seq.end()
@@ -404,7 +430,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::Bool => serialize!(bool),
         ObType::Float | ObType::FloatSubclass => {
             let v = value.extract::<f64>().map_err(py_err_se_err)?;
-            type_serializers::float::serialize_f64(v, serializer, extra.config.inf_nan_mode)
+            type_serializers::float::serialize_f64(v, serializer, extra.inf_nan_mode(extra.config.inf_nan_mode))
         }
         ObType::Decimal => value.to_string().serialize(serializer),
         ObType::Str | ObType::StrSubclass => {
@@ -426,6 +452,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::Dict => {
             let dict = value.downcast::<PyDict>().map_err(py_err_se_err)?;
+            extra.maybe_release_gil(value.py());
             serialize_pairs_json(dict.iter().map(Ok), dict.len(), serializer, include, exclude, extra)
         }
         ObType::List => serialize_seq_filter!(PyList),
@@ -450,8 +477,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::Timedelta => {
             let either_delta = EitherTimedelta::try_from(value).map_err(py_err_se_err)?;
             extra
-                .config
-                .timedelta_mode
+                .timedelta_mode(extra.config.timedelta_mode)
                 .timedelta_serialize(value.py(), &either_delta, serializer)
         }
         ObType::Url => {
@@ -462,13 +488,18 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             let py_url: PyMultiHostUrl = value.extract().map_err(py_err_se_err)?;
             serializer.serialize_str(&py_url.__str__())
         }
+        ObType::PhoneNumber => {
+            let py_phone: PyPhoneNumber = value.extract().map_err(py_err_se_err)?;
+            serializer.serialize_str(&py_phone.__str__())
+        }
         ObType::PydanticSerializable => {
             let py = value.py();
             let py_serializer = value
                 .getattr(intern!(py, "__pydantic_serializer__"))
                 .map_err(py_err_se_err)?;
             let extracted_serializer: PyRef<SchemaSerializer> = py_serializer.extract().map_err(py_err_se_err)?;
-            let extra = extracted_serializer.build_extra(
+            let gil_release = extra.gil_release;
+            let mut extra = extracted_serializer.build_extra(
                 py,
                 extra.mode,
                 extra.by_alias,
@@ -482,13 +513,16 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 extra.fallback,
                 extra.duck_typing_ser_mode,
                 extra.context,
+                extra.runtime,
             );
+            extra.gil_release = gil_release;
             let pydantic_serializer =
                 PydanticSerializer::new(value, &extracted_serializer.serializer, include, exclude, &extra);
             pydantic_serializer.serialize(serializer)
         }
         ObType::Dataclass => {
             let (pairs_iter, fields_dict) = any_dataclass_iter(value).map_err(py_err_se_err)?;
+            extra.maybe_release_gil(value.py());
             serialize_pairs_json(pairs_iter, fields_dict.len(), serializer, include, exclude, extra)
         }
         ObType::Uuid => {
@@ -501,6 +535,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::Generator => {
             let py_seq = value.downcast::<PyIterator>().map_err(py_err_se_err)?;
+            extra.maybe_release_gil(value.py());
             let mut seq = serializer.serialize_seq(None)?;
             let filter = AnyFilter::new();
             for (index, r) in py_seq.iter().map_err(py_err_se_err)?.enumerate() {
@@ -530,7 +565,18 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 .map_err(py_err_se_err)?;
             serializer.serialize_str(&s)
         }
+        ObType::NumpyScalar => {
+            let item = value.call_method0(intern!(value.py(), "item")).map_err(py_err_se_err)?;
+            infer_serialize(&item, serializer, include, exclude, extra)
+        }
+        ObType::NumpyArray => {
+            let list = numpy_array_to_list(value).map_err(py_err_se_err)?;
+            infer_serialize(&list, serializer, include, exclude, extra)
+        }
         ObType::Unknown => {
+            if let Some(inner) = read_only_inner(value) {
+                return infer_serialize(&inner, serializer, include, exclude, extra);
+            }
             if let Some(fallback) = extra.fallback {
                 let next_value = fallback.call1((value,)).map_err(py_err_se_err)?;
                 let next_result = infer_serialize(&next_value, serializer, include, exclude, extra);
@@ -557,6 +603,16 @@ fn unknown_type_error(value: &Bound<'_, PyAny>) -> PyErr {
     ))
 }
 
+/// The items of a numpy array as lists, read through the buffer protocol with `memoryview`, or with `tolist()`
+/// for dtypes `memoryview` doesn't support, e.g. `datetime64` or complex numbers
+fn numpy_array_to_list<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    let tolist = intern!(value.py(), "tolist");
+    match PyMemoryView::from_bound(value).and_then(|view| view.call_method0(tolist)) {
+        Ok(list) => Ok(list),
+        Err(_) => value.call_method0(tolist),
+    }
+}
+
 fn serialize_unknown<'py>(value: &Bound<'py, PyAny>) -> Cow<'py, str> {
     if let Ok(s) = value.str() {
         s.to_string_lossy().into_owned().into()
@@ -579,10 +635,10 @@ pub(crate) fn infer_json_key_known<'a>(
 ) -> PyResult<Cow<'a, str>> {
     match ob_type {
         ObType::None => super::type_serializers::simple::none_json_key(),
-        ObType::Int | ObType::IntSubclass => super::type_serializers::simple::to_str_json_key(key),
+        ObType::Int | ObType::IntSubclass => super::type_serializers::simple::int_json_key(key),
         ObType::Float | ObType::FloatSubclass => {
             let v = key.extract::<f64>()?;
-            if (v.is_nan() || v.is_infinite()) && extra.config.inf_nan_mode == InfNanMode::Null {
+            if (v.is_nan() || v.is_infinite()) && extra.inf_nan_mode(extra.config.inf_nan_mode) == InfNanMode::Null {
                 super::type_serializers::simple::none_json_key()
             } else {
                 super::type_serializers::simple::to_str_json_key(key)
@@ -592,7 +648,7 @@ pub(crate) fn infer_json_key_known<'a>(
         ObType::Bool => super::type_serializers::simple::bool_json_key(key),
         ObType::Str | ObType::StrSubclass => {
             let py_str = key.downcast::<PyString>()?;
-            Ok(Cow::Owned(py_str.to_str()?.to_string()))
+            Ok(Cow::Borrowed(py_str.to_str()?))
         }
         ObType::Bytes => extra
             .config
@@ -630,7 +686,9 @@ pub(crate) fn infer_json_key_known<'a>(
         }
         ObType::Timedelta => {
             let either_delta = EitherTimedelta::try_from(key)?;
-            extra.config.timedelta_mode.json_key(key.py(), &either_delta)
+            extra
+                .timedelta_mode(extra.config.timedelta_mode)
+                .json_key(key.py(), &either_delta)
         }
         ObType::Url => {
             let py_url: PyUrl = key.extract()?;
@@ -640,10 +698,18 @@ pub(crate) fn infer_json_key_known<'a>(
             let py_url: PyMultiHostUrl = key.extract()?;
             Ok(Cow::Owned(py_url.__str__()))
         }
+        ObType::PhoneNumber => {
+            let py_phone: PyPhoneNumber = key.extract()?;
+            Ok(Cow::Owned(py_phone.__str__()))
+        }
         ObType::Tuple => {
-            let mut key_build = super::type_serializers::tuple::KeyBuilder::new();
+            let mut key_build = super::type_serializers::tuple::KeyBuilder::new(extra.config);
             for element in key.downcast::<PyTuple>()?.iter_borrowed() {
-                key_build.push(&infer_json_key(&element, extra)?);
+                if key_build.is_json() {
+                    key_build.push_json(&SerializeInfer::new(&element, None, None, extra))?;
+                } else {
+                    key_build.push(&infer_json_key(&element, extra)?);
+                }
             }
             Ok(Cow::Owned(key_build.finish()))
         }
@@ -670,7 +736,11 @@ pub(crate) fn infer_json_key_known<'a>(
                 .to_string_lossy()
                 .into_owned(),
         )),
-        ObType::Unknown => {
+        ObType::NumpyScalar => {
+            let item = key.call_method0(intern!(key.py(), "item"))?;
+            infer_json_key(&item, extra).map(|cow| Cow::Owned(cow.into_owned()))
+        }
+        ObType::NumpyArray | ObType::Unknown => {
             if let Some(fallback) = extra.fallback {
                 let next_key = fallback.call1((key,))?;
                 infer_json_key(&next_key, extra).map(|cow| Cow::Owned(cow.into_owned()))
@@ -699,7 +769,10 @@ fn serialize_pairs_python<'py>(
         let op_next = filter.key_filter(&k, include, exclude)?;
         if let Some((next_include, next_exclude)) = op_next {
             let k = key_transform(k)?;
-            let v = infer_to_python(&v, next_include.as_ref(), next_exclude.as_ref(), extra)?;
+            let (next_include, next_exclude) = (next_include.as_ref(), next_exclude.as_ref());
+            let v = extra.item_to_python(&v, None, next_include, next_exclude, &k, || {
+                infer_to_python(&v, next_include, next_exclude, extra)
+            })?;
             new_dict.set_item(k, v)?;
         }
     }
@@ -724,7 +797,7 @@ fn serialize_pairs_json<'py, S: Serializer>(
         if let Some((next_include, next_exclude)) = op_next {
             let key = infer_json_key(&key, extra).map_err(py_err_se_err)?;
             let value_serializer = SerializeInfer::new(&value, next_include.as_ref(), next_exclude.as_ref(), extra);
-            map.serialize_entry(&key, &value_serializer)?;
+            extra.with_loc(&key, || map.serialize_entry(&key, &value_serializer))?;
         }
     }
     map.end()
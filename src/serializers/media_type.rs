@@ -0,0 +1,153 @@
+use std::sync::{Mutex, PoisonError};
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+/// Formats `SchemaSerializer.serialize` can write, chosen by media type
+#[derive(Debug)]
+pub(crate) enum MediaType {
+    Json,
+    MessagePack,
+    Bson,
+    /// A backend added with `register_format_backend`
+    Custom {
+        name: String,
+        encoder: PyObject,
+    },
+}
+
+const JSON_TYPES: &[&str] = &["application/json"];
+const BSON_TYPES: &[&str] = &["application/bson"];
+const MESSAGE_PACK_TYPES: &[&str] = &[
+    "application/msgpack",
+    "application/x-msgpack",
+    "application/vnd.msgpack",
+];
+
+#[derive(Debug)]
+struct FormatBackend {
+    name: String,
+    media_types: Vec<String>,
+    encoder: PyObject,
+}
+
+static FORMAT_BACKENDS: Mutex<Vec<FormatBackend>> = Mutex::new(Vec::new());
+
+impl MediaType {
+    /// Parameters like `charset` are ignored, and structured syntax suffixes like `application/problem+json`
+    /// are matched by their suffix
+    pub fn parse(py: Python, media_type: &str) -> PyResult<Self> {
+        let essence = essence(media_type);
+        if let Some(builtin) = Self::parse_builtin(&essence) {
+            return Ok(builtin);
+        }
+        let backends = FORMAT_BACKENDS.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(backend) = backends.iter().find(|b| b.media_types.contains(&essence)) {
+            return Ok(Self::Custom {
+                name: backend.name.clone(),
+                encoder: backend.encoder.clone_ref(py),
+            });
+        }
+        let expected = JSON_TYPES
+            .iter()
+            .chain(MESSAGE_PACK_TYPES)
+            .chain(BSON_TYPES)
+            .copied()
+            .chain(backends.iter().flat_map(|b| b.media_types.iter().map(String::as_str)))
+            .map(|media_type| format!("'{media_type}'"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Err(PyValueError::new_err(format!(
+            "Unsupported media type '{media_type}', expected one of: {expected}"
+        )))
+    }
+
+    fn parse_builtin(essence: &str) -> Option<Self> {
+        if JSON_TYPES.contains(&essence) || essence.ends_with("+json") {
+            Some(Self::Json)
+        } else if MESSAGE_PACK_TYPES.contains(&essence) || essence.ends_with("+msgpack") {
+            Some(Self::MessagePack)
+        } else if BSON_TYPES.contains(&essence) || essence.ends_with("+bson") {
+            Some(Self::Bson)
+        } else {
+            None
+        }
+    }
+
+    /// Call a custom backend's encoder with the JSON compatible data
+    pub fn encode<'py>(
+        py: Python<'py>,
+        name: &str,
+        encoder: &PyObject,
+        data: PyObject,
+    ) -> PyResult<Bound<'py, PyBytes>> {
+        let encoded = encoder.call1(py, (data,))?.into_bound(py);
+        encoded.downcast_into::<PyBytes>().map_err(|e| {
+            let type_name = e.into_inner().get_type().qualname().map(|n| n.to_string());
+            PyTypeError::new_err(format!(
+                "Format backend '{name}' must return bytes, got '{}'",
+                type_name.as_deref().unwrap_or("<unknown>")
+            ))
+        })
+    }
+}
+
+fn essence(media_type: &str) -> String {
+    media_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase()
+}
+
+/// Register `encoder` to serialize the media types in `media_types`, replacing any backend with the same name.
+#[pyfunction(signature = (name, encoder, *, media_types))]
+pub fn register_format_backend(name: String, encoder: &Bound<'_, PyAny>, media_types: Vec<String>) -> PyResult<()> {
+    if !encoder.is_callable() {
+        return Err(PyTypeError::new_err("Format backend encoder must be callable"));
+    }
+    if media_types.is_empty() {
+        return Err(PyValueError::new_err(
+            "Format backends must have at least one media type",
+        ));
+    }
+    let media_types: Vec<String> = media_types.iter().map(|media_type| essence(media_type)).collect();
+    let mut backends = FORMAT_BACKENDS.lock().unwrap_or_else(PoisonError::into_inner);
+    for media_type in &media_types {
+        if MediaType::parse_builtin(media_type).is_some() {
+            return Err(PyValueError::new_err(format!(
+                "Media type '{media_type}' is serialized by pydantic-core and can't be registered"
+            )));
+        }
+        if let Some(other) = backends
+            .iter()
+            .find(|b| b.name != name && b.media_types.contains(media_type))
+        {
+            return Err(PyValueError::new_err(format!(
+                "Media type '{media_type}' is already registered by format backend '{}'",
+                other.name
+            )));
+        }
+    }
+    let backend = FormatBackend {
+        name,
+        media_types,
+        encoder: encoder.clone().unbind(),
+    };
+    match backends.iter_mut().find(|b| b.name == backend.name) {
+        Some(existing) => *existing = backend,
+        None => backends.push(backend),
+    }
+    Ok(())
+}
+
+/// Remove the format backend registered as `name`, returns whether there was one
+#[pyfunction]
+pub fn unregister_format_backend(name: &str) -> bool {
+    let mut backends = FORMAT_BACKENDS.lock().unwrap_or_else(PoisonError::into_inner);
+    let len = backends.len();
+    backends.retain(|b| b.name != name);
+    backends.len() != len
+}
@@ -0,0 +1,137 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PySet, PyString};
+use serde::Serialize;
+
+use crate::tools::SchemaDict;
+
+use super::errors::{se_err_py_err, PydanticSerializationError};
+use super::extra::Extra;
+use super::infer::SerializeInfer;
+use super::ser::PythonSerializer;
+use super::shared::{CombinedSerializer, TypeSerializer};
+
+/// Names of the fields marked with `metric_label` in a model, dataclass or typed dict schema
+pub(crate) fn metric_label_fields<'py>(schema: &Bound<'py, PyDict>) -> PyResult<Bound<'py, PySet>> {
+    let fields = PySet::empty_bound(schema.py())?;
+    add_metric_label_fields(&fields, schema, None)?;
+    Ok(fields)
+}
+
+fn add_metric_label_fields(
+    fields: &Bound<'_, PySet>,
+    schema: &Bound<'_, PyDict>,
+    definitions: Option<&Bound<'_, PyList>>,
+) -> PyResult<()> {
+    let py = schema.py();
+    let schema_type: Bound<'_, PyString> = schema.get_as_req(intern!(py, "type"))?;
+    match schema_type.to_str()? {
+        "definitions" => {
+            let definitions: Bound<'_, PyList> = schema.get_as_req(intern!(py, "definitions"))?;
+            let inner: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "schema"))?;
+            add_metric_label_fields(fields, &inner, Some(&definitions))?;
+        }
+        "definition-ref" => {
+            let schema_ref: String = schema.get_as_req(intern!(py, "schema_ref"))?;
+            for definition in definitions.into_iter().flatten() {
+                let definition = definition.downcast_into::<PyDict>()?;
+                if definition.get_as::<String>(intern!(py, "ref"))?.as_deref() == Some(schema_ref.as_str()) {
+                    return add_metric_label_fields(fields, &definition, definitions);
+                }
+            }
+        }
+        "model" | "dataclass" => {
+            let inner: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "schema"))?;
+            add_metric_label_fields(fields, &inner, definitions)?;
+        }
+        "model-fields" | "typed-dict" => {
+            let field_schemas: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
+            for (name, field) in field_schemas {
+                if is_metric_label(field.downcast()?)? {
+                    fields.add(name)?;
+                }
+            }
+        }
+        "dataclass-args" => {
+            let field_schemas: Bound<'_, PyList> = schema.get_as_req(intern!(py, "fields"))?;
+            for field in field_schemas {
+                let field = field.downcast_into::<PyDict>()?;
+                if is_metric_label(&field)? {
+                    fields.add(field.get_as_req::<Bound<'_, PyString>>(intern!(py, "name"))?)?;
+                }
+            }
+        }
+        _ => (),
+    }
+    Ok(())
+}
+
+fn is_metric_label(field: &Bound<'_, PyDict>) -> PyResult<bool> {
+    Ok(field.get_as(intern!(field.py(), "metric_label"))?.unwrap_or(false))
+}
+
+/// Serialize the fields marked with `metric_label` to a dict of label names to label values, both sanitized
+/// so they can be used with Prometheus and StatsD
+pub(crate) fn to_metric_labels<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    label_fields: &Bound<'py, PySet>,
+    extra: &Extra,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = value.py();
+    let labels = PyDict::new_bound(py);
+    if label_fields.is_empty() {
+        return Ok(labels);
+    }
+    let json_value = serializer
+        .to_python(value, Some(label_fields), None, extra)?
+        .into_bound(py);
+    let Ok(dict) = json_value.downcast::<PyDict>() else {
+        return Err(PydanticSerializationError::new_err(format!(
+            "Metric labels can only be built from values serialized to dicts, got '{}'",
+            json_value.get_type().qualname()?
+        )));
+    };
+    for (key, value) in dict {
+        let name = label_name(key.downcast::<PyString>()?.to_str()?);
+        labels.set_item(name, label_value(&value, extra)?)?;
+    }
+    Ok(labels)
+}
+
+/// Label names must match `[a-zA-Z_][a-zA-Z0-9_]*`
+fn label_name(key: &str) -> String {
+    let mut name: String = key
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        name.insert(0, '_');
+    }
+    name
+}
+
+/// `None` is an empty string, other values which aren't strings are written as JSON, then characters which
+/// separate tags or values in StatsD and InfluxDB line protocol, quotes, backslashes, whitespace and control
+/// characters are replaced with `_`
+fn label_value(value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<String> {
+    let raw = if let Ok(py_str) = value.downcast::<PyString>() {
+        py_str.to_str()?.to_string()
+    } else if value.is_none() {
+        String::new()
+    } else {
+        let mut ser = PythonSerializer::new(Vec::new());
+        SerializeInfer::new(value, None, None, extra)
+            .serialize(&mut ser)
+            .map_err(se_err_py_err)?;
+        String::from_utf8(ser.into_inner()).expect("JSON is valid UTF-8")
+    };
+    Ok(raw
+        .chars()
+        .map(|c| match c {
+            ',' | ':' | '|' | '#' | '=' | '"' | '\\' => '_',
+            c if c.is_whitespace() || c.is_control() => '_',
+            c => c,
+        })
+        .collect())
+}
@@ -1,30 +1,67 @@
 use std::fmt::Debug;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyBytes, PyDict};
+use pyo3::types::{PyBytes, PyDict, PyList, PySet, PyTuple};
 use pyo3::{PyTraverseError, PyVisit};
 
 use crate::definitions::{Definitions, DefinitionsBuilder};
+use crate::errors::{catch_panics, install_location_hook};
+use crate::frozen::Frozen;
 use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
 
+pub use alias_scope::ByAliasArg;
+use awaitables::{CollectAwaitables, JsonOutput};
+use binary::to_binary_bytes;
+use bson::to_bson_bytes;
 use config::SerializationConfig;
+pub(crate) use config::{tuple_key_separator_from_config, FromConfig, InfNanMode, TupleKeyMode};
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
+pub use extra::CyclesMode;
 use extra::{CollectWarnings, SerRecursionState, WarningsMode};
 pub(crate) use extra::{DuckTypingSerMode, Extra, SerMode, SerializationState};
+use field_sizes::FieldSizes;
+use media_type::MediaType;
+pub use media_type::{register_format_backend, unregister_format_backend};
+use metric_labels::{metric_label_fields, to_metric_labels};
+pub(crate) use ob_type::ObTypeLookup;
+use redis_hash::to_redis_hash;
+use runtime_settings::RuntimeSettings;
+pub use runtime_settings::{get_runtime_settings_var, PyRuntimeSettings};
+use selection::Selection;
 pub use shared::CombinedSerializer;
 use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use sql::to_sql_params;
+pub use sub_serializer::SubSerializer;
 
+mod alias_map;
+mod alias_scope;
+mod awaitables;
+mod binary;
+mod bson;
 mod computed_fields;
 mod config;
+mod dedupe;
+mod diff;
 mod errors;
 mod extra;
+mod field_sizes;
 mod fields;
 mod filter;
 mod infer;
+mod media_type;
+mod metric_labels;
 mod ob_type;
+mod redis_hash;
+mod runtime_settings;
+mod selection;
 pub mod ser;
 mod shared;
+mod sql;
+mod sub_serializer;
 mod type_serializers;
 
 #[derive(FromPyObject)]
@@ -40,10 +77,17 @@ pub struct SchemaSerializer {
     definitions: Definitions<CombinedSerializer>,
     expected_json_size: AtomicUsize,
     config: SerializationConfig,
+    // names of the fields marked with `metric_label`, used as `include` by `to_metric_labels`
+    metric_label_fields: Py<PySet>,
     // References to the Python schema and config objects are saved to enable
     // reconstructing the object for pickle support (see `__reduce__`).
     py_schema: Py<PyDict>,
     py_config: Option<Py<PyDict>>,
+    // convert panics in `to_python` and `to_json` into `PydanticInternalError`
+    catch_panics: bool,
+    // how many Python callbacks a single call may make, from the `max_python_callbacks` config
+    max_python_callbacks: Option<u64>,
+    frozen: Frozen,
 }
 
 impl SchemaSerializer {
@@ -63,8 +107,9 @@ impl SchemaSerializer {
         fallback: Option<&'a Bound<'a, PyAny>>,
         duck_typing_ser_mode: DuckTypingSerMode,
         context: Option<&'a Bound<'a, PyAny>>,
+        runtime: RuntimeSettings,
     ) -> Extra<'b> {
-        Extra::new(
+        let mut extra = Extra::new(
             py,
             mode,
             by_alias,
@@ -79,7 +124,15 @@ impl SchemaSerializer {
             fallback,
             duck_typing_ser_mode,
             context,
-        )
+        );
+        extra.runtime = runtime;
+        extra
+    }
+
+    /// Check a serializer frozen with `audit` hasn't had its schema or config mutated
+    fn audit_frozen(&self, py: Python) -> PyResult<()> {
+        let config = self.py_config.as_ref().map(|c| c.bind(py));
+        self.frozen.audit(self.py_schema.bind(py), config, "SchemaSerializer")
     }
 }
 
@@ -90,23 +143,54 @@ impl SchemaSerializer {
     pub fn py_new(schema: Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
         let mut definitions_builder = DefinitionsBuilder::new();
         let serializer = CombinedSerializer::build(schema.downcast()?, config, &mut definitions_builder)?;
+        let catch_panics: bool = config.get_as(intern!(schema.py(), "catch_panics"))?.unwrap_or(false);
+        let max_python_callbacks: Option<u64> = config.get_as(intern!(schema.py(), "max_python_callbacks"))?;
+        if catch_panics {
+            install_location_hook();
+        }
         Ok(Self {
             serializer,
             definitions: definitions_builder.finish()?,
             expected_json_size: AtomicUsize::new(1024),
             config: SerializationConfig::from_config(config)?,
+            metric_label_fields: metric_label_fields(&schema)?.unbind(),
             py_schema: schema.into(),
             py_config: match config {
                 Some(c) if !c.is_empty() => Some(c.clone().into()),
                 _ => None,
             },
+            catch_panics,
+            max_python_callbacks,
+            frozen: Frozen::default(),
         })
     }
 
+    /// Mark the serializer as frozen, with `audit` record the fingerprint of the schema and config so each call can
+    /// check they haven't been mutated since, nothing is made immutable
+    #[pyo3(signature = (*, audit=false))]
+    pub fn freeze<'py>(slf: &Bound<'py, Self>, audit: bool) -> PyResult<Bound<'py, Self>> {
+        let this = slf.get();
+        let py = slf.py();
+        let config = this.py_config.as_ref().map(|c| c.bind(py));
+        this.frozen.freeze(this.py_schema.bind(py), config, audit)?;
+        Ok(slf.clone())
+    }
+
+    #[getter]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.is_frozen()
+    }
+
+    /// A serializer for just the part of a value at `loc`, e.g. a single field
+    pub fn serializer_for_loc(slf: &Bound<'_, Self>, loc: &Bound<'_, PyAny>) -> PyResult<SubSerializer> {
+        SubSerializer::new(slf, loc)
+    }
+
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
-        fallback = None, serialize_as_any = false, context = None))]
+    #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = ByAliasArg::Bool(true),
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None,
+        fallback = None, serialize_as_any = false, context = None, selection = None, alias_profile = None,
+        cycles = CyclesMode::Error))]
     pub fn to_python(
         &self,
         py: Python,
@@ -114,25 +198,33 @@ impl SchemaSerializer {
         mode: Option<&str>,
         include: Option<&Bound<'_, PyAny>>,
         exclude: Option<&Bound<'_, PyAny>>,
-        by_alias: bool,
+        by_alias: ByAliasArg<'_>,
         exclude_unset: bool,
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
-        warnings: WarningsArg,
+        warnings: Option<WarningsArg>,
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        selection: Option<&Bound<'_, PyAny>>,
+        alias_profile: Option<&str>,
+        cycles: CyclesMode,
     ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
         let mode: SerMode = mode.into();
-        let warnings_mode = match warnings {
-            WarningsArg::Bool(b) => b.into(),
-            WarningsArg::Literal(mode) => mode,
+        let selection = selection.map(Selection::new).transpose()?;
+        // the selection decides the output keys, so fields are looked up by name
+        let (by_alias, alias_scope) = match selection {
+            Some(_) => (false, None),
+            None => by_alias.into_scope()?,
         };
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
         let warnings = CollectWarnings::new(warnings_mode);
-        let rec_guard = SerRecursionState::default();
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks).with_cycles(cycles);
         let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
-        let extra = self.build_extra(
+        let mut extra = self.build_extra(
             py,
             &mode,
             by_alias,
@@ -146,16 +238,27 @@ impl SchemaSerializer {
             fallback,
             duck_typing_ser_mode,
             context,
+            runtime,
         );
-        let v = self.serializer.to_python(value, include, exclude, &extra)?;
+        extra.alias_scope = alias_scope.as_ref();
+        extra.alias_profile = alias_profile;
+        let v = catch_panics(self.catch_panics, self.serializer.get_name(), || match selection {
+            Some(selection) => {
+                let include = selection.include(py, &self.serializer, include)?;
+                let v = self.serializer.to_python(value, include.as_ref(), exclude, &extra)?;
+                Ok(selection.apply(v.into_bound(py))?.unbind())
+            }
+            None => self.serializer.to_python(value, include, exclude, &extra),
+        })?;
         warnings.final_check(py)?;
         Ok(v)
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
-        fallback = None, serialize_as_any = false, context = None))]
+    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = ByAliasArg::Bool(true),
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None,
+        fallback = None, serialize_as_any = false, context = None, selection = None, field_sizes = false,
+        alias_profile = None, cycles = CyclesMode::Error, dedupe_shared = false))]
     pub fn to_json(
         &self,
         py: Python,
@@ -163,24 +266,40 @@ impl SchemaSerializer {
         indent: Option<usize>,
         include: Option<&Bound<'_, PyAny>>,
         exclude: Option<&Bound<'_, PyAny>>,
-        by_alias: bool,
+        by_alias: ByAliasArg<'_>,
         exclude_unset: bool,
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
-        warnings: WarningsArg,
+        warnings: Option<WarningsArg>,
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
         context: Option<&Bound<'_, PyAny>>,
+        selection: Option<&Bound<'_, PyAny>>,
+        field_sizes: bool,
+        alias_profile: Option<&str>,
+        cycles: CyclesMode,
+        dedupe_shared: bool,
     ) -> PyResult<PyObject> {
-        let warnings_mode = match warnings {
-            WarningsArg::Bool(b) => b.into(),
-            WarningsArg::Literal(mode) => mode,
+        self.audit_frozen(py)?;
+        if dedupe_shared && cycles == CyclesMode::Ref {
+            return Err(PyValueError::new_err(
+                "`dedupe_shared` can't be used with `cycles='ref'`",
+            ));
+        }
+        let selection = selection.map(Selection::new).transpose()?;
+        let (by_alias, alias_scope) = match selection {
+            Some(_) => (false, None),
+            None => by_alias.into_scope()?,
         };
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
         let warnings = CollectWarnings::new(warnings_mode);
-        let rec_guard = SerRecursionState::default();
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks)
+            .with_cycles(cycles)
+            .with_dedupe_shared(dedupe_shared);
         let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
-        let extra = self.build_extra(
+        let mut extra = self.build_extra(
             py,
             &SerMode::Json,
             by_alias,
@@ -194,24 +313,572 @@ impl SchemaSerializer {
             fallback,
             duck_typing_ser_mode,
             context,
+            runtime,
         );
-        let bytes = to_json_bytes(
-            value,
-            &self.serializer,
-            include,
-            exclude,
-            &extra,
-            indent,
-            self.expected_json_size.load(Ordering::Relaxed),
-        )?;
+        extra.alias_scope = alias_scope.as_ref();
+        extra.alias_profile = alias_profile;
+        let expected_json_size = self.expected_json_size.load(Ordering::Relaxed);
+        let sizes = field_sizes.then(FieldSizes::default);
+        let bytes = catch_panics(self.catch_panics, self.serializer.get_name(), || match selection {
+            Some(selection) => {
+                // the selection is applied to the JSON compatible python output, which is then written as JSON
+                let include = selection.include(py, &self.serializer, include)?;
+                let v = self.serializer.to_python(value, include.as_ref(), exclude, &extra)?;
+                let mut selected = selection.apply(v.into_bound(py))?;
+                if let Some(ref shared_values) = rec_guard.shared_values {
+                    selected = shared_values.with_defs(&selected)?.into_bound(py);
+                }
+                let serializer = type_serializers::any::AnySerializer::INFER.into();
+                let sizes = sizes.as_ref();
+                to_json_bytes(
+                    &selected,
+                    &serializer,
+                    None,
+                    None,
+                    &extra,
+                    indent,
+                    expected_json_size,
+                    sizes,
+                )
+            }
+            None => match rec_guard.shared_values {
+                // shared values are found while serializing to JSON compatible python, then written as JSON
+                Some(ref shared_values) => {
+                    let v = self.serializer.to_python(value, include, exclude, &extra)?;
+                    let deduped = shared_values.with_defs(v.bind(py))?;
+                    let serializer = type_serializers::any::AnySerializer::INFER.into();
+                    to_json_bytes(
+                        deduped.bind(py),
+                        &serializer,
+                        None,
+                        None,
+                        &extra,
+                        indent,
+                        expected_json_size,
+                        sizes.as_ref(),
+                    )
+                }
+                None => to_json_bytes(
+                    value,
+                    &self.serializer,
+                    include,
+                    exclude,
+                    &extra,
+                    indent,
+                    expected_json_size,
+                    sizes.as_ref(),
+                ),
+            },
+        })?;
 
         warnings.final_check(py)?;
 
         self.expected_json_size.store(bytes.len(), Ordering::Relaxed);
         let py_bytes = PyBytes::new_bound(py, &bytes);
+        match sizes {
+            Some(sizes) => Ok((py_bytes, sizes.to_dict(py, &bytes)?).into_py(py)),
+            None => Ok(py_bytes.into()),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None, fallback = None,
+        serialize_as_any = false, context = None))]
+    pub fn to_binary(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        let bytes = to_binary_bytes(value, &self.serializer, include, exclude, &extra)?;
+
+        warnings.final_check(py)?;
+
+        Ok(PyBytes::new_bound(py, &bytes).into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None, fallback = None,
+        serialize_as_any = false, context = None))]
+    pub fn to_bson(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = self.build_extra(
+            py,
+            &SerMode::Python,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        let bytes = to_bson_bytes(value, &self.serializer, include, exclude, &extra)?;
+
+        warnings.final_check(py)?;
+
+        Ok(PyBytes::new_bound(py, &bytes).into())
+    }
+
+    #[pyo3(signature = (value, *, by_alias = true, warnings = None, fallback = None, context = None))]
+    pub fn to_metric_labels(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        by_alias: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            false,
+            false,
+            false,
+            false,
+            &rec_guard,
+            false,
+            fallback,
+            DuckTypingSerMode::SchemaBased,
+            context,
+            runtime,
+        );
+        let labels = to_metric_labels(value, &self.serializer, self.metric_label_fields.bind(py), &extra)?;
+
+        warnings.final_check(py)?;
+
+        Ok(labels.into())
+    }
+
+    /// The changes between `old` and `new`, both serialized with `mode`, see `diff::diff`
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (old, new, *, mode = "json", by_alias = true, warnings = None, fallback = None,
+        context = None))]
+    pub fn diff(
+        &self,
+        py: Python,
+        old: &Bound<'_, PyAny>,
+        new: &Bound<'_, PyAny>,
+        mode: &str,
+        by_alias: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let mode: SerMode = Some(mode).into();
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let extra = self.build_extra(
+            py,
+            &mode,
+            by_alias,
+            &warnings,
+            false,
+            false,
+            false,
+            false,
+            &rec_guard,
+            false,
+            fallback,
+            DuckTypingSerMode::SchemaBased,
+            context,
+            runtime,
+        );
+        let changes = catch_panics(self.catch_panics, self.serializer.get_name(), || {
+            let old = self.serializer.to_python(old, None, None, &extra)?;
+            let new = self.serializer.to_python(new, None, None, &extra)?;
+            diff::diff(old.bind(py), new.bind(py))
+        })?;
+
+        warnings.final_check(py)?;
+
+        Ok(changes.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, separator = ".", include = None, exclude = None, by_alias = true,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None,
+        fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_redis_hash(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        separator: &str,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        if separator.is_empty() {
+            return Err(PyValueError::new_err("`separator` must not be empty"));
+        }
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        let hash = to_redis_hash(value, &self.serializer, include, exclude, &extra, separator)?;
+
+        warnings.final_check(py)?;
+
+        Ok(hash.into())
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, many = false, include = None, exclude = None, by_alias = true, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None, fallback = None,
+        serialize_as_any = false, context = None))]
+    pub fn to_sql_params(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        many: bool,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = self.build_extra(
+            py,
+            &SerMode::Python,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        let result = if many {
+            let values = value.iter()?.collect::<PyResult<Vec<_>>>()?;
+            match to_sql_params(values, &self.serializer, include, exclude, &extra)? {
+                Some(sql_params) => (sql_params.columns, PyList::new_bound(py, sql_params.params)).into_py(py),
+                None => (PyTuple::empty_bound(py), PyList::empty_bound(py)).into_py(py),
+            }
+        } else {
+            let sql_params = to_sql_params([value.clone()], &self.serializer, include, exclude, &extra)?
+                .expect("one row was serialized");
+            let params = sql_params.params.into_iter().next().expect("one row was serialized");
+            (sql_params.columns, params).into_py(py)
+        };
+
+        warnings.final_check(py)?;
+
+        Ok(result)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, media_type = "application/json", include = None, exclude = None, by_alias = true,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None,
+        fallback = None, serialize_as_any = false, context = None))]
+    pub fn serialize(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        media_type: &str,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let media_type = MediaType::parse(py, media_type)?;
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        let py_bytes = match media_type {
+            MediaType::Json => {
+                let bytes = to_json_bytes(
+                    value,
+                    &self.serializer,
+                    include,
+                    exclude,
+                    &extra,
+                    None,
+                    self.expected_json_size.load(Ordering::Relaxed),
+                    None,
+                )?;
+                self.expected_json_size.store(bytes.len(), Ordering::Relaxed);
+                PyBytes::new_bound(py, &bytes)
+            }
+            MediaType::MessagePack => {
+                PyBytes::new_bound(py, &to_binary_bytes(value, &self.serializer, include, exclude, &extra)?)
+            }
+            MediaType::Bson => {
+                let extra = Extra {
+                    mode: &SerMode::Python,
+                    ..extra
+                };
+                PyBytes::new_bound(py, &to_bson_bytes(value, &self.serializer, include, exclude, &extra)?)
+            }
+            MediaType::Custom { name, encoder } => {
+                let data = self.serializer.to_python(value, include, exclude, &extra)?;
+                MediaType::encode(py, &name, &encoder, data)?
+            }
+        };
+
+        warnings.final_check(py)?;
+
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None,
+        fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_python_async(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        mode: Option<&str>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let mode: SerMode = mode.into();
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let awaitables = CollectAwaitables::default();
+        let mut extra = self.build_extra(
+            py,
+            &mode,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        extra.awaitables = Some(&awaitables);
+        let v = self.serializer.to_python(value, include, exclude, &extra)?;
+        warnings.final_check(py)?;
+        awaitables.into_awaitable(py, v, None)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None,
+        fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_json_async(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        indent: Option<usize>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let awaitables = CollectAwaitables::default();
+        let mut extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        extra.awaitables = Some(&awaitables);
+        // serialize to JSON compatible python objects first, then dump to JSON once the awaitables are resolved
+        let v = self.serializer.to_python(value, include, exclude, &extra)?;
+        warnings.final_check(py)?;
+        let json = JsonOutput {
+            config: self.config.clone(),
+            indent,
+            warnings_mode,
+            runtime,
+        };
+        awaitables.into_awaitable(py, v, Some(json))
+    }
+
+    /// The keys each field of the model, dataclass or typed dict being serialized is serialized under by alias,
+    /// keyed by field name, or with `reverse` the field names keyed by alias
+    #[pyo3(signature = (*, alias_profile = None, reverse = false))]
+    pub fn alias_map<'py>(
+        &self,
+        py: Python<'py>,
+        alias_profile: Option<&str>,
+        reverse: bool,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        alias_map::alias_map(py, &self.serializer, alias_profile, reverse)
+    }
+
     pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
@@ -229,6 +896,7 @@ impl SchemaSerializer {
 
     fn __traverse__(&self, visit: PyVisit<'_>) -> Result<(), PyTraverseError> {
         visit.call(&self.py_schema)?;
+        visit.call(&self.metric_label_fields)?;
         if let Some(ref py_config) = self.py_config {
             visit.call(py_config)?;
         }
@@ -243,7 +911,7 @@ impl SchemaSerializer {
 #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
     exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
     inf_nan_mode = "constants", serialize_unknown = false, fallback = None, serialize_as_any = false,
-    context = None))]
+    context = None, cycles = CyclesMode::Error, dedupe_shared = false))]
 pub fn to_json(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -260,8 +928,16 @@ pub fn to_json(
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    cycles: CyclesMode,
+    dedupe_shared: bool,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
+    if dedupe_shared && cycles == CyclesMode::Ref {
+        return Err(PyValueError::new_err(
+            "`dedupe_shared` can't be used with `cycles='ref'`",
+        ));
+    }
+    let state =
+        SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode, cycles)?.with_dedupe_shared(dedupe_shared);
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
     let extra = state.extra(
         py,
@@ -274,8 +950,14 @@ pub fn to_json(
         duck_typing_ser_mode,
         context,
     );
-    let serializer = type_serializers::any::AnySerializer.into();
-    let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
+    let serializer = type_serializers::any::AnySerializer::INFER.into();
+    let bytes = if dedupe_shared {
+        let v = infer::infer_to_python(value, include, exclude, &extra)?;
+        let deduped = state.with_defs(v.into_bound(py))?;
+        to_json_bytes(&deduped, &serializer, None, None, &extra, indent, 1024, None)?
+    } else {
+        to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024, None)?
+    };
     state.final_check(py)?;
     let py_bytes = PyBytes::new_bound(py, &bytes);
     Ok(py_bytes.into())
@@ -285,7 +967,7 @@ pub fn to_json(
 #[pyfunction]
 #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
     timedelta_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
-    serialize_as_any = false, context = None))]
+    serialize_as_any = false, context = None, cycles = CyclesMode::Error))]
 pub fn to_jsonable_python(
     py: Python,
     value: &Bound<'_, PyAny>,
@@ -301,8 +983,9 @@ pub fn to_jsonable_python(
     fallback: Option<&Bound<'_, PyAny>>,
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
+    cycles: CyclesMode,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
+    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode, cycles)?;
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
     let extra = state.extra(
         py,
@@ -9,6 +9,7 @@ use pyo3::{intern, PyTypeInfo};
 use strum::Display;
 use strum_macros::EnumString;
 
+use crate::phone::PyPhoneNumber;
 use crate::url::{PyMultiHostUrl, PyUrl};
 
 #[derive(Debug, Clone)]
@@ -38,6 +39,7 @@ pub struct ObTypeLookup {
     // types from this package
     url: usize,
     multi_host_url: usize,
+    phone_number: usize,
     // enum type
     enum_object: PyObject,
     // generator
@@ -48,9 +50,51 @@ pub struct ObTypeLookup {
     pattern_object: PyObject,
     // uuid type
     uuid_object: PyObject,
+    // numpy types, only set by `cached_numpy` when numpy is installed
+    numpy: Option<NumpyTypes>,
 }
 
 static TYPE_LOOKUP: GILOnceCell<ObTypeLookup> = GILOnceCell::new();
+static NUMPY_TYPE_LOOKUP: GILOnceCell<ObTypeLookup> = GILOnceCell::new();
+
+#[derive(Debug, Clone)]
+struct NumpyTypes {
+    generic: PyObject,
+    ndarray: PyObject,
+    integer: PyObject,
+    floating: PyObject,
+    bool_: PyObject,
+}
+
+impl NumpyTypes {
+    fn import(py: Python) -> Option<Self> {
+        let numpy = py.import_bound("numpy").ok()?;
+        let get = |name: &str| numpy.getattr(name).ok().map(Bound::unbind);
+        Some(Self {
+            generic: get("generic")?,
+            ndarray: get("ndarray")?,
+            integer: get("integer")?,
+            floating: get("floating")?,
+            bool_: get("bool_")?,
+        })
+    }
+
+    fn get_type(&self, value: &Bound<'_, PyAny>) -> Option<ObType> {
+        let py = value.py();
+        let is_instance = |type_: &PyObject| value.is_instance(type_.bind(py)).unwrap_or(false);
+        if is_instance(&self.integer) {
+            Some(ObType::IntSubclass)
+        } else if is_instance(&self.floating) {
+            Some(ObType::FloatSubclass)
+        } else if is_instance(&self.generic) {
+            Some(ObType::NumpyScalar)
+        } else if is_instance(&self.ndarray) {
+            Some(ObType::NumpyArray)
+        } else {
+            None
+        }
+    }
+}
 
 #[derive(Debug)]
 pub enum IsType {
@@ -86,6 +130,7 @@ impl ObTypeLookup {
             timedelta: PyDelta::type_object_raw(py) as usize,
             url: PyUrl::type_object_raw(py) as usize,
             multi_host_url: PyMultiHostUrl::type_object_raw(py) as usize,
+            phone_number: PyPhoneNumber::type_object_raw(py) as usize,
             enum_object: py.import_bound("enum").unwrap().getattr("Enum").unwrap().to_object(py),
             generator_object: py
                 .import_bound("types")
@@ -101,6 +146,7 @@ impl ObTypeLookup {
                 .to_object(py),
             pattern_object: py.import_bound("re").unwrap().getattr("Pattern").unwrap().to_object(py),
             uuid_object: py.import_bound("uuid").unwrap().getattr("UUID").unwrap().to_object(py),
+            numpy: None,
         }
     }
 
@@ -108,19 +154,45 @@ impl ObTypeLookup {
         TYPE_LOOKUP.get_or_init(py, || Self::new(py))
     }
 
+    /// Like `cached` but also recognising numpy scalars and arrays, used with the `numpy_support` config so numpy
+    /// is only imported when it's set
+    pub fn cached_numpy(py: Python<'_>) -> &Self {
+        NUMPY_TYPE_LOOKUP.get_or_init(py, || Self {
+            numpy: NumpyTypes::import(py),
+            ..Self::new(py)
+        })
+    }
+
     pub fn is_type(&self, value: &Bound<'_, PyAny>, expected_ob_type: ObType) -> IsType {
         match self.ob_type_is_expected(Some(value), &value.get_type(), &expected_ob_type) {
-            IsType::False => {
-                if expected_ob_type == self.fallback_isinstance(value) {
+            IsType::False => match self.fallback_isinstance(value) {
+                ob_type if ob_type == expected_ob_type => IsType::Subclass,
+                // `numpy.bool_` isn't a subclass of `bool`, but converts to one
+                ObType::NumpyScalar if expected_ob_type == ObType::Bool && self.is_numpy_bool(value) => {
                     IsType::Subclass
-                } else {
-                    IsType::False
                 }
-            }
+                _ => IsType::False,
+            },
             is_type => is_type,
         }
     }
 
+    fn is_numpy_bool(&self, value: &Bound<'_, PyAny>) -> bool {
+        self.numpy
+            .as_ref()
+            .is_some_and(|numpy| value.is_instance(numpy.bool_.bind(value.py())).unwrap_or(false))
+    }
+
+    /// The Python value held by a numpy scalar, from `item()`, `None` if `value` isn't one or numpy isn't supported
+    pub fn numpy_item<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        match &self.numpy {
+            Some(numpy) if value.is_instance(numpy.generic.bind(value.py()))? => {
+                value.call_method0(intern!(value.py(), "item")).map(Some)
+            }
+            _ => Ok(None),
+        }
+    }
+
     fn ob_type_is_expected(
         &self,
         op_value: Option<&Bound<'_, PyAny>>,
@@ -163,6 +235,7 @@ impl ObTypeLookup {
             ObType::Bytearray => self.bytearray == ob_type,
             ObType::Url => self.url == ob_type,
             ObType::MultiHostUrl => self.multi_host_url == ob_type,
+            ObType::PhoneNumber => self.phone_number == ob_type,
             ObType::Dataclass => is_dataclass(op_value),
             ObType::PydanticSerializable => is_pydantic_serializable(op_value),
             ObType::Enum => self.enum_object.as_ptr() as usize == ob_type,
@@ -170,7 +243,7 @@ impl ObTypeLookup {
             ObType::Path => self.path_object.as_ptr() as usize == ob_type,
             ObType::Pattern => self.path_object.as_ptr() as usize == ob_type,
             ObType::Uuid => self.uuid_object.as_ptr() as usize == ob_type,
-            ObType::Unknown => false,
+            ObType::NumpyScalar | ObType::NumpyArray | ObType::Unknown => false,
         };
 
         if ans {
@@ -249,6 +322,8 @@ impl ObTypeLookup {
             ObType::Url
         } else if ob_type == self.multi_host_url {
             ObType::MultiHostUrl
+        } else if ob_type == self.phone_number {
+            ObType::PhoneNumber
         } else if ob_type == self.uuid_object.as_ptr() as usize {
             ObType::Uuid
         } else if is_pydantic_serializable(op_value) {
@@ -330,6 +405,8 @@ impl ObTypeLookup {
             ObType::Url
         } else if value.is_instance_of::<PyMultiHostUrl>() {
             ObType::MultiHostUrl
+        } else if value.is_instance_of::<PyPhoneNumber>() {
+            ObType::PhoneNumber
         } else if value.is_instance(self.decimal_object.bind(py)).unwrap_or(false) {
             ObType::Decimal
         } else if value.is_instance(self.uuid_object.bind(py)).unwrap_or(false) {
@@ -342,6 +419,8 @@ impl ObTypeLookup {
             ObType::Path
         } else if value.is_instance(self.pattern_object.bind(py)).unwrap_or(false) {
             ObType::Pattern
+        } else if let Some(ob_type) = self.numpy.as_ref().and_then(|numpy| numpy.get_type(value)) {
+            ob_type
         } else {
             ObType::Unknown
         }
@@ -409,6 +488,7 @@ pub enum ObType {
     // types from this package
     Url,
     MultiHostUrl,
+    PhoneNumber,
     // anything with __pydantic_serializer__, including BaseModel and pydantic dataclasses
     PydanticSerializable,
     // vanilla dataclasses
@@ -423,6 +503,9 @@ pub enum ObType {
     Pattern,
     // Uuid
     Uuid,
+    // numpy scalars other than integers and floats, and arrays, with the `numpy_support` config
+    NumpyScalar,
+    NumpyArray,
     // unknown type
     Unknown,
 }
@@ -0,0 +1,79 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use serde::Serialize;
+
+use super::errors::{se_err_py_err, PydanticSerializationError};
+use super::extra::Extra;
+use super::infer::SerializeInfer;
+use super::ser::PythonSerializer;
+use super::shared::{CombinedSerializer, TypeSerializer};
+
+/// Serialize to the flat string to string mapping read by `SchemaValidator.validate_redis_hash`.
+///
+/// The value is serialized like `to_python(mode='json')`, nested dicts are flattened with their keys joined
+/// by `separator`, strings are written as they are and other values as JSON. Strings which would be read
+/// back as JSON other than a number, e.g. `"true"` or `"[1]"`, are written as JSON strings.
+pub(crate) fn to_redis_hash<'py>(
+    value: &Bound<'py, PyAny>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+    separator: &str,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = value.py();
+    let json_value = serializer.to_python(value, include, exclude, extra)?.into_bound(py);
+    let Ok(dict) = json_value.downcast::<PyDict>() else {
+        return Err(PydanticSerializationError::new_err(format!(
+            "Redis hashes can only be built from values serialized to dicts, got '{}'",
+            json_value.get_type().qualname()?
+        )));
+    };
+    let hash = PyDict::new_bound(py);
+    flatten(&hash, dict, "", separator, extra)?;
+    Ok(hash)
+}
+
+fn flatten(
+    hash: &Bound<'_, PyDict>,
+    dict: &Bound<'_, PyDict>,
+    prefix: &str,
+    separator: &str,
+    extra: &Extra,
+) -> PyResult<()> {
+    for (key, value) in dict {
+        // keys are always strings in JSON mode
+        let key = key.downcast::<PyString>()?.to_str()?;
+        let field = format!("{prefix}{key}");
+        match value.downcast::<PyDict>() {
+            Ok(nested) if !nested.is_empty() => {
+                flatten(hash, nested, &format!("{field}{separator}"), separator, extra)?;
+            }
+            _ => hash.set_item(field, field_value(&value, extra)?)?,
+        }
+    }
+    Ok(())
+}
+
+fn field_value(value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<String> {
+    if let Ok(py_str) = value.downcast::<PyString>() {
+        let str = py_str.to_str()?;
+        if !is_non_number_json(str) {
+            return Ok(str.to_string());
+        }
+    }
+    let mut ser = PythonSerializer::new(Vec::new());
+    SerializeInfer::new(value, None, None, extra)
+        .serialize(&mut ser)
+        .map_err(se_err_py_err)?;
+    Ok(String::from_utf8(ser.into_inner()).expect("JSON is valid UTF-8"))
+}
+
+/// Whether `validate_redis_hash` would read `str` as JSON, numbers are read as strings so they're
+/// validated without losing precision
+fn is_non_number_json(str: &str) -> bool {
+    matches!(
+        serde_json::from_str::<serde_json::Value>(str),
+        Ok(value) if !value.is_number()
+    )
+}
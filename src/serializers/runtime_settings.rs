@@ -0,0 +1,97 @@
+use std::str::FromStr;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::IntoPyDict;
+
+use super::config::{InfNanMode, TimedeltaMode};
+use super::extra::WarningsMode;
+use super::WarningsArg;
+
+static RUNTIME_SETTINGS_VAR: GILOnceCell<PyObject> = GILOnceCell::new();
+
+/// The `ContextVar` holding the `RuntimeSettings` set by `pydantic_core.runtime_settings()`, being a context
+/// variable the overrides are local to the current thread or asyncio task.
+pub fn get_runtime_settings_var(py: Python<'_>) -> PyResult<&Bound<'_, PyAny>> {
+    RUNTIME_SETTINGS_VAR
+        .get_or_try_init(py, || {
+            let context_var = py.import_bound("contextvars")?.getattr("ContextVar")?;
+            let kwargs = [("default", py.None())].into_py_dict_bound(py);
+            context_var
+                .call(("pydantic_core_runtime_settings",), Some(&kwargs))
+                .map(Bound::unbind)
+        })
+        .map(|var| var.bind(py))
+}
+
+/// Overrides of serialization behaviour which apply to every `SchemaSerializer` call, regardless of
+/// the config the serializer was built with.
+#[derive(Debug, Clone, Copy, Default)]
+#[allow(clippy::struct_field_names)]
+pub(crate) struct RuntimeSettings {
+    pub timedelta_mode: Option<TimedeltaMode>,
+    pub inf_nan_mode: Option<InfNanMode>,
+    pub warnings_mode: Option<WarningsMode>,
+}
+
+impl RuntimeSettings {
+    /// The settings for the current context, this is a single `ContextVar.get()` call when no overrides are set.
+    pub fn current(py: Python) -> PyResult<Self> {
+        let settings = get_runtime_settings_var(py)?.call_method0(intern!(py, "get"))?;
+        if settings.is_none() {
+            Ok(Self::default())
+        } else {
+            Ok(settings.downcast::<PyRuntimeSettings>()?.get().settings)
+        }
+    }
+
+    pub fn warnings_mode(self, warnings: Option<WarningsArg>) -> WarningsMode {
+        match warnings {
+            Some(WarningsArg::Bool(b)) => b.into(),
+            Some(WarningsArg::Literal(mode)) => mode,
+            None => self.warnings_mode.unwrap_or(WarningsMode::Warn),
+        }
+    }
+}
+
+#[pyclass(name = "RuntimeSettings", module = "pydantic_core._pydantic_core", frozen)]
+#[derive(Debug)]
+pub struct PyRuntimeSettings {
+    settings: RuntimeSettings,
+}
+
+#[pymethods]
+impl PyRuntimeSettings {
+    #[new]
+    #[pyo3(signature = (*, ser_json_timedelta = None, ser_json_inf_nan = None, warnings = None, parent = None))]
+    fn py_new(
+        ser_json_timedelta: Option<&str>,
+        ser_json_inf_nan: Option<&str>,
+        warnings: Option<WarningsArg>,
+        parent: Option<&Bound<'_, Self>>,
+    ) -> PyResult<Self> {
+        // settings which aren't set here are inherited from the enclosing `runtime_settings()` block
+        let parent = parent.map(|p| p.get().settings).unwrap_or_default();
+        Ok(Self {
+            settings: RuntimeSettings {
+                timedelta_mode: ser_json_timedelta
+                    .map(TimedeltaMode::from_str)
+                    .transpose()?
+                    .or(parent.timedelta_mode),
+                inf_nan_mode: ser_json_inf_nan
+                    .map(InfNanMode::from_str)
+                    .transpose()?
+                    .or(parent.inf_nan_mode),
+                warnings_mode: match warnings {
+                    Some(warnings) => Some(RuntimeSettings::default().warnings_mode(Some(warnings))),
+                    None => parent.warnings_mode,
+                },
+            },
+        })
+    }
+
+    pub fn __repr__(&self) -> String {
+        format!("RuntimeSettings({:?})", self.settings)
+    }
+}
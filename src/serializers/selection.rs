@@ -0,0 +1,121 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyList, PySet, PyString, PyTuple};
+
+use super::shared::CombinedSerializer;
+
+/// A GraphQL style selection set, see the `selection` argument of `SchemaSerializer.to_python`
+#[derive(Debug)]
+pub(crate) struct Selection {
+    fields: Vec<SelectedField>,
+}
+
+#[derive(Debug)]
+struct SelectedField {
+    // the key in the output, the GraphQL alias if there is one
+    key: Py<PyString>,
+    field: Py<PyString>,
+    selection: Option<Selection>,
+}
+
+impl Selection {
+    /// `selection` is a dict of output keys to `True`, a nested selection, or a `(field name, selection)` tuple
+    pub fn new(selection: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let Ok(selection) = selection.downcast::<PyDict>() else {
+            return Err(PyTypeError::new_err(format!(
+                "`selection` must be a dict, got '{}'",
+                selection.get_type().qualname()?
+            )));
+        };
+        let mut fields = Vec::with_capacity(selection.len());
+        for (key, value) in selection {
+            let key = key
+                .downcast_into::<PyString>()
+                .map_err(|_| PyTypeError::new_err("`selection` keys must be strings"))?;
+            let (field, value) = match value.downcast::<PyTuple>() {
+                Ok(tuple) if tuple.len() == 2 => {
+                    let field = tuple
+                        .get_item(0)?
+                        .downcast_into::<PyString>()
+                        .map_err(|_| invalid_selection(&key))?;
+                    (field, tuple.get_item(1)?)
+                }
+                _ => (key.clone(), value),
+            };
+            let selection = if value.is_instance_of::<PyDict>() {
+                Some(Self::new(&value)?)
+            } else if value.downcast::<PyBool>().is_ok_and(PyBoolMethods::is_true) {
+                None
+            } else {
+                return Err(invalid_selection(&key));
+            };
+            fields.push(SelectedField {
+                key: key.unbind(),
+                field: field.unbind(),
+                selection,
+            });
+        }
+        Ok(Self { fields })
+    }
+
+    /// `include` if it's set, otherwise the selected field names when they filter the top level of
+    /// `serializer`'s output, so fields which aren't selected aren't serialized
+    pub fn include<'py>(
+        &self,
+        py: Python<'py>,
+        serializer: &CombinedSerializer,
+        include: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        if let Some(include) = include {
+            return Ok(Some(include.clone()));
+        }
+        let filters_fields = match serializer {
+            CombinedSerializer::Model(model) => !model.is_root_model(),
+            CombinedSerializer::Dataclass(_) | CombinedSerializer::Fields(_) => true,
+            _ => false,
+        };
+        if filters_fields {
+            let fields = PySet::new_bound(py, self.fields.iter().map(|f| f.field.bind(py)))?;
+            Ok(Some(fields.into_any()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Pick the selected fields from serialized output, renamed to their output keys, the selection is
+    /// applied to each item of lists and tuples
+    pub fn apply<'py>(&self, value: Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        let py = value.py();
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            let selected = PyDict::new_bound(py);
+            for field in &self.fields {
+                // fields which aren't in the output, e.g. because of `exclude_none`, are left out
+                if let Some(item) = dict.get_item(field.field.bind(py))? {
+                    let item = match &field.selection {
+                        Some(selection) => selection.apply(item)?,
+                        None => item,
+                    };
+                    selected.set_item(field.key.bind(py), item)?;
+                }
+            }
+            Ok(selected.into_any())
+        } else if let Ok(list) = value.downcast::<PyList>() {
+            let items = list.iter().map(|item| self.apply(item)).collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new_bound(py, items).into_any())
+        } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+            let items = tuple
+                .iter()
+                .map(|item| self.apply(item))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyTuple::new_bound(py, items).into_any())
+        } else {
+            Ok(value)
+        }
+    }
+}
+
+fn invalid_selection(key: &Bound<'_, PyString>) -> PyErr {
+    PyTypeError::new_err(format!(
+        "Invalid selection for '{key}', expected `True`, a dict or a `(field name, selection)` tuple"
+    ))
+}
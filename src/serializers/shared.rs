@@ -1,5 +1,7 @@
 use std::borrow::Cow;
+use std::cell::Cell;
 use std::fmt::Debug;
+use std::io;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::prelude::*;
@@ -9,7 +11,7 @@ use pyo3::{intern, PyTraverseError, PyVisit};
 
 use enum_dispatch::enum_dispatch;
 use serde::Serialize;
-use serde_json::ser::PrettyFormatter;
+use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter};
 
 use crate::build_tools::py_schema_err;
 use crate::build_tools::py_schema_error_type;
@@ -20,6 +22,7 @@ use crate::tools::{py_err, SchemaDict};
 
 use super::errors::se_err_py_err;
 use super::extra::Extra;
+use super::field_sizes::{FieldSizes, FieldSizesFormatter, FieldSizesWriter};
 use super::infer::infer_json_key;
 use super::ob_type::{IsType, ObType};
 
@@ -84,6 +87,7 @@ combined_serializer! {
         Function: super::type_serializers::function::FunctionPlainSerializer;
         FunctionWrap: super::type_serializers::function::FunctionWrapSerializer;
         Fields: super::fields::GeneralFieldsSerializer;
+        Collection: super::type_serializers::collection::CollectionSerializer;
     }
     // `find_only` is for type_serializers which are built directly via the `type` key and `find_serializer`
     // but aren't actually used for serialization, e.g. their `build` method must return another serializer
@@ -105,6 +109,14 @@ combined_serializer! {
         super::type_serializers::function::FunctionWrapSerializerBuilder;
         super::type_serializers::model::ModelFieldsBuilder;
         super::type_serializers::typed_dict::TypedDictBuilder;
+        super::type_serializers::url::UriReferenceBuilder;
+        super::type_serializers::url::HostnameBuilder;
+        super::type_serializers::url::DomainBuilder;
+        super::type_serializers::string::StringFormatBuilder;
+        super::type_serializers::collection::DequeBuilder;
+        super::type_serializers::collection::CounterBuilder;
+        super::type_serializers::collection::OrderedDictBuilder;
+        super::type_serializers::collection::DefaultDictBuilder;
     }
     // `both` means the struct is added to both the `CombinedSerializer` enum and the match statement in
     // `find_serializer` so they can be used via a `type` str.
@@ -115,6 +127,7 @@ combined_serializer! {
         Bool: super::type_serializers::simple::BoolSerializer;
         Float: super::type_serializers::float::FloatSerializer;
         Decimal: super::type_serializers::decimal::DecimalSerializer;
+        Money: super::type_serializers::money::MoneySerializer;
         Str: super::type_serializers::string::StrSerializer;
         Bytes: super::type_serializers::bytes::BytesSerializer;
         Datetime: super::type_serializers::datetime_etc::DatetimeSerializer;
@@ -128,9 +141,13 @@ combined_serializer! {
         Dict: super::type_serializers::dict::DictSerializer;
         Model: super::type_serializers::model::ModelSerializer;
         Dataclass: super::type_serializers::dataclass::DataclassSerializer;
+        NamedTuple: super::type_serializers::namedtuple::NamedTupleSerializer;
         Url: super::type_serializers::url::UrlSerializer;
         MultiHostUrl: super::type_serializers::url::MultiHostUrlSerializer;
         Uuid: super::type_serializers::uuid::UuidSerializer;
+        Ulid: super::type_serializers::ulid::UlidSerializer;
+        Native: super::type_serializers::native::NativeSerializer;
+        Phone: super::type_serializers::phone::PhoneSerializer;
         Any: super::type_serializers::any::AnySerializer;
         Format: super::type_serializers::format::FormatSerializer;
         ToString: super::type_serializers::format::ToStringSerializer;
@@ -218,12 +235,14 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Function(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::FunctionWrap(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Fields(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Collection(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::None(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Nullable(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Int(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Bool(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Float(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Decimal(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Money(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Str(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Bytes(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Datetime(inner) => inner.py_gc_traverse(visit),
@@ -237,6 +256,7 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Dict(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Model(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Dataclass(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::NamedTuple(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Url(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::MultiHostUrl(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Any(inner) => inner.py_gc_traverse(visit),
@@ -251,6 +271,9 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Recursive(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Tuple(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Uuid(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Ulid(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Native(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Phone(inner) => inner.py_gc_traverse(visit),
         }
     }
 }
@@ -345,25 +368,106 @@ pub(crate) fn to_json_bytes(
     extra: &Extra,
     indent: Option<usize>,
     expected_json_size: usize,
+    field_sizes: Option<&FieldSizes>,
 ) -> PyResult<Vec<u8>> {
-    let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
-
-    let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
-    let bytes = match indent {
-        Some(indent) => {
-            let indent = vec![b' '; indent];
-            let formatter = PrettyFormatter::with_indent(&indent);
-            let mut ser = PythonSerializer::with_formatter(writer, formatter);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
+    let bytes: Vec<u8> = Vec::with_capacity(expected_json_size);
+    match extra.config.release_gil_every {
+        Some(every) => {
+            let gil_release = GilRelease::new(every);
+            let extra = Extra {
+                gil_release: Some(&gil_release),
+                ..*extra
+            };
+            let serializer = PydanticSerializer::new(value, serializer, include, exclude, &extra);
+            let writer = CountingWriter {
+                bytes,
+                gil_release: &gil_release,
+            };
+            serialize_to_writer(&serializer, writer, indent, field_sizes).map(|writer| writer.bytes)
         }
         None => {
-            let mut ser = PythonSerializer::new(writer);
-            serializer.serialize(&mut ser).map_err(se_err_py_err)?;
-            ser.into_inner()
+            let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
+            serialize_to_writer(&serializer, bytes, indent, field_sizes)
         }
-    };
-    Ok(bytes)
+    }
+}
+
+fn serialize_to_writer<W: io::Write>(
+    serializer: &PydanticSerializer,
+    writer: W,
+    indent: Option<usize>,
+    field_sizes: Option<&FieldSizes>,
+) -> PyResult<W> {
+    let indent = indent.map(|indent| vec![b' '; indent]);
+    match (&indent, field_sizes) {
+        (Some(indent), None) => serialize_with_formatter(serializer, writer, PrettyFormatter::with_indent(indent)),
+        (None, None) => serialize_with_formatter(serializer, writer, CompactFormatter),
+        (Some(indent), Some(sizes)) => {
+            let writer = FieldSizesWriter { inner: writer, sizes };
+            let formatter = FieldSizesFormatter::new(PrettyFormatter::with_indent(indent), sizes);
+            serialize_with_formatter(serializer, writer, formatter).map(|writer| writer.inner)
+        }
+        (None, Some(sizes)) => {
+            let writer = FieldSizesWriter { inner: writer, sizes };
+            let formatter = FieldSizesFormatter::new(CompactFormatter, sizes);
+            serialize_with_formatter(serializer, writer, formatter).map(|writer| writer.inner)
+        }
+    }
+}
+
+fn serialize_with_formatter<W: io::Write, F: Formatter>(
+    serializer: &PydanticSerializer,
+    writer: W,
+    formatter: F,
+) -> PyResult<W> {
+    let mut ser = PythonSerializer::with_formatter(writer, formatter);
+    serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+    Ok(ser.into_inner())
+}
+
+/// Counts the bytes written by `to_json` so the GIL can be released periodically, see `ser_json_release_gil_every`
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub(crate) struct GilRelease {
+    every: usize,
+    written: Cell<usize>,
+    released_at: Cell<usize>,
+}
+
+impl GilRelease {
+    fn new(every: usize) -> Self {
+        Self {
+            every,
+            written: Cell::new(0),
+            released_at: Cell::new(0),
+        }
+    }
+
+    /// Called when starting a container, briefly releases the GIL if enough has been written since it was
+    /// last released to give other threads a chance to run.
+    pub fn maybe_release(&self, py: Python) {
+        let written = self.written.get();
+        if written - self.released_at.get() >= self.every {
+            py.allow_threads(|| ());
+            self.released_at.set(written);
+        }
+    }
+}
+
+struct CountingWriter<'a> {
+    bytes: Vec<u8>,
+    gil_release: &'a GilRelease,
+}
+
+impl io::Write for CountingWriter<'_> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.bytes.extend_from_slice(buf);
+        self.gil_release.written.set(self.gil_release.written.get() + buf.len());
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
 pub(super) fn any_dataclass_iter<'a, 'py>(
@@ -0,0 +1,102 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use serde::Serialize;
+
+use super::errors::{se_err_py_err, PydanticSerializationError};
+use super::extra::{Extra, SerMode};
+use super::infer::{infer_to_python_known, SerializeInfer};
+use super::ob_type::ObType;
+use super::ser::PythonSerializer;
+use super::shared::{CombinedSerializer, TypeSerializer};
+
+/// Column names and SQL parameters, as returned by `SchemaSerializer.to_sql_params`
+pub(crate) struct SqlParams<'py> {
+    pub columns: Bound<'py, PyTuple>,
+    pub params: Vec<Bound<'py, PyTuple>>,
+}
+
+/// Serialize each of `values` to a tuple of parameters which can be bound in SQL, in the order of the dict
+/// `to_python` produces, i.e. schema order for models, dataclasses and typed dicts.
+///
+/// `None`, bools, ints, floats, strings, bytes and decimals are passed to the database driver as they are,
+/// other values like datetimes and UUIDs are written as they would be in JSON, and values which would be
+/// JSON arrays or objects are written as JSON text.
+pub(crate) fn to_sql_params<'py>(
+    values: impl IntoIterator<Item = Bound<'py, PyAny>>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+) -> PyResult<Option<SqlParams<'py>>> {
+    let json_extra = Extra {
+        mode: &SerMode::Json,
+        ..extra.clone()
+    };
+    let mut columns: Option<Bound<'py, PyTuple>> = None;
+    let mut params = Vec::new();
+    for value in values {
+        let py = value.py();
+        let python_value = serializer.to_python(&value, include, exclude, extra)?.into_bound(py);
+        let Ok(dict) = python_value.downcast::<PyDict>() else {
+            return Err(PydanticSerializationError::new_err(format!(
+                "SQL parameters can only be built from values serialized to dicts, got '{}'",
+                python_value.get_type().qualname()?
+            )));
+        };
+        let row = match &columns {
+            // values are taken in the order of the first row's columns, models serialize fields in the order
+            // they're set on `__dict__` which can differ between instances
+            Some(columns) => {
+                let mut row = Vec::with_capacity(columns.len());
+                for column in columns {
+                    match dict.get_item(&column)? {
+                        Some(item) => row.push(sql_param(&item, &json_extra)?),
+                        None => return Err(columns_mismatch(columns, dict, params.len())),
+                    }
+                }
+                if dict.len() != columns.len() {
+                    return Err(columns_mismatch(columns, dict, params.len()));
+                }
+                row
+            }
+            None => {
+                columns = Some(PyTuple::new_bound(py, dict.keys()));
+                dict.values()
+                    .iter()
+                    .map(|item| sql_param(&item, &json_extra))
+                    .collect::<PyResult<Vec<_>>>()?
+            }
+        };
+        params.push(PyTuple::new_bound(py, row));
+    }
+    Ok(columns.map(|columns| SqlParams { columns, params }))
+}
+
+fn columns_mismatch(columns: &Bound<'_, PyTuple>, dict: &Bound<'_, PyDict>, index: usize) -> PyErr {
+    PydanticSerializationError::new_err(format!(
+        "All rows must have the same columns, expected {columns} but row {index} has {}",
+        PyTuple::new_bound(dict.py(), dict.keys())
+    ))
+}
+
+fn sql_param(value: &Bound<'_, PyAny>, json_extra: &Extra) -> PyResult<PyObject> {
+    let py = value.py();
+    let ob_type = json_extra.ob_type_lookup.get_type(value);
+    match ob_type {
+        ObType::None | ObType::Bool | ObType::Int | ObType::Float | ObType::Str | ObType::Bytes | ObType::Decimal => {
+            return Ok(value.clone().unbind())
+        }
+        _ => (),
+    }
+    let json_value = infer_to_python_known(ob_type, value, None, None, json_extra)?.into_bound(py);
+    if json_value.is_instance_of::<PyDict>() || json_value.is_instance_of::<PyList>() {
+        let mut ser = PythonSerializer::new(Vec::new());
+        SerializeInfer::new(&json_value, None, None, json_extra)
+            .serialize(&mut ser)
+            .map_err(se_err_py_err)?;
+        let json = String::from_utf8(ser.into_inner()).expect("JSON is valid UTF-8");
+        Ok(PyString::new_bound(py, &json).into_py(py))
+    } else {
+        Ok(json_value.unbind())
+    }
+}
@@ -0,0 +1,210 @@
+use std::sync::atomic::Ordering;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyTuple};
+
+use crate::errors::{catch_panics, LocItem};
+use crate::validators::extract_loc;
+
+use super::extra::{CollectWarnings, DuckTypingSerMode, SerMode, SerRecursionState};
+use super::runtime_settings::RuntimeSettings;
+use super::shared::{to_json_bytes, CombinedSerializer, TypeSerializer};
+use super::{SchemaSerializer, WarningsArg};
+
+/// Serializes just the part of a value at `loc` of a `SchemaSerializer`, from `SchemaSerializer.serializer_for_loc`,
+/// the part's serializer is looked up again for each call since it's owned by the `SchemaSerializer`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct SubSerializer {
+    schema_serializer: Py<SchemaSerializer>,
+    loc: Vec<LocItem>,
+}
+
+impl SubSerializer {
+    pub(super) fn new(schema_serializer: &Bound<'_, SchemaSerializer>, loc: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let py = schema_serializer.py();
+        let loc = extract_loc(loc)?;
+        if with_serializer_for_loc(&schema_serializer.get().serializer, &loc, &mut |_| ()).is_none() {
+            return Err(PyValueError::new_err(format!(
+                "No serializer found for location {}",
+                PyTuple::new_bound(py, &loc).repr()?
+            )));
+        }
+        Ok(Self {
+            schema_serializer: schema_serializer.clone().unbind(),
+            loc,
+        })
+    }
+
+    /// Call `f` with the serializer at `loc`
+    fn with_serializer<R>(&self, f: &mut dyn FnMut(&CombinedSerializer) -> PyResult<R>) -> PyResult<R> {
+        with_serializer_for_loc(&self.schema_serializer.get().serializer, &self.loc, f)
+            .unwrap_or_else(|| Err(PyValueError::new_err("The serializer at `loc` was not found")))
+    }
+}
+
+#[pymethods]
+impl SubSerializer {
+    #[getter]
+    fn loc<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::new_bound(py, &self.loc)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None, fallback = None,
+        serialize_as_any = false, context = None))]
+    fn to_python(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        mode: Option<&str>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let schema_serializer = self.schema_serializer.get();
+        schema_serializer.audit_frozen(py)?;
+        let mode: SerMode = mode.into();
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(schema_serializer.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = schema_serializer.build_extra(
+            py,
+            &mode,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        let v = self.with_serializer(&mut |serializer| {
+            catch_panics(schema_serializer.catch_panics, serializer.get_name(), || {
+                serializer.to_python(value, include, exclude, &extra)
+            })
+        })?;
+        warnings.final_check(py)?;
+        Ok(v)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = None,
+        fallback = None, serialize_as_any = false, context = None))]
+    fn to_json(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        indent: Option<usize>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        warnings: Option<WarningsArg>,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let schema_serializer = self.schema_serializer.get();
+        schema_serializer.audit_frozen(py)?;
+        let runtime = RuntimeSettings::current(py)?;
+        let warnings_mode = runtime.warnings_mode(warnings);
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(schema_serializer.max_python_callbacks);
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        let extra = schema_serializer.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+            runtime,
+        );
+        // the whole value's size is only a guess at the part's, so it isn't updated
+        let expected_json_size = schema_serializer.expected_json_size.load(Ordering::Relaxed);
+        let bytes = self.with_serializer(&mut |serializer| {
+            catch_panics(schema_serializer.catch_panics, serializer.get_name(), || {
+                to_json_bytes(
+                    value,
+                    serializer,
+                    include,
+                    exclude,
+                    &extra,
+                    indent,
+                    expected_json_size,
+                    None,
+                )
+            })
+        })?;
+        warnings.final_check(py)?;
+        Ok(PyBytes::new_bound(py, &bytes).into())
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("SubSerializer(loc={})", self.loc(py).repr()?))
+    }
+}
+
+/// Call `f` with the serializer of the part of the value at `loc`, going into the fields of models, dataclasses and
+/// typed dicts by name, the items of lists and tuples by index and the values of dicts by any key, looking through
+/// wrap function serializers, defaults, nullables and definition references on the way, `None` if there's no
+/// serializer at `loc`, e.g. for an excluded field
+fn with_serializer_for_loc<R>(
+    serializer: &CombinedSerializer,
+    loc: &[LocItem],
+    f: &mut dyn FnMut(&CombinedSerializer) -> R,
+) -> Option<R> {
+    let Some((first, rest)) = loc.split_first() else {
+        return Some(f(serializer));
+    };
+    let next = match (serializer, first) {
+        (CombinedSerializer::Fields(s), LocItem::S(name)) => s.field_serializer(name),
+        (CombinedSerializer::List(s), LocItem::I(_)) => Some(s.item_serializer()),
+        (CombinedSerializer::Tuple(s), LocItem::I(index)) => {
+            usize::try_from(*index).ok().and_then(|index| s.item_serializer(index))
+        }
+        (CombinedSerializer::Dict(s), _) => Some(s.value_serializer()),
+        // serializers wrapping the part of the value at `loc`
+        (CombinedSerializer::Model(s), _) => return with_serializer_for_loc(s.inner_serializer(), loc, f),
+        (CombinedSerializer::Dataclass(s), _) => return with_serializer_for_loc(s.inner_serializer(), loc, f),
+        (CombinedSerializer::FunctionWrap(s), _) => return with_serializer_for_loc(s.inner_serializer(), loc, f),
+        (CombinedSerializer::WithDefault(s), _) => return with_serializer_for_loc(s.inner_serializer(), loc, f),
+        (CombinedSerializer::Nullable(s), _) => return with_serializer_for_loc(s.inner_serializer(), loc, f),
+        (CombinedSerializer::Recursive(s), _) => {
+            return s
+                .definition()
+                .read(|definition| definition.and_then(|definition| with_serializer_for_loc(definition, loc, f)))
+        }
+        _ => None,
+    };
+    next.and_then(|next| with_serializer_for_loc(next, rest, f))
+}
@@ -1,28 +1,41 @@
 use std::borrow::Cow;
 
+use base64::Engine;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList, PyString};
 
 use serde::ser::Serializer;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::tools::{SchemaDict, TYPE_MARKER_KEY, TYPE_MARKER_VALUE_KEY};
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    ObType, TypeSerializer,
 };
 
 #[derive(Debug, Clone, Default)]
-pub struct AnySerializer;
+pub struct AnySerializer {
+    // whether to wrap values JSON can't represent in `{"__type__": ..., "value": ...}` markers
+    type_markers: bool,
+}
+
+impl AnySerializer {
+    /// Serializes by type inference, without type markers
+    pub const INFER: Self = Self { type_markers: false };
+}
 
 impl BuildSerializer for AnySerializer {
     const EXPECTED_TYPE: &'static str = "any";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let type_markers = schema.get_as(intern!(schema.py(), "type_markers"))?.unwrap_or(false);
+        Ok(Self { type_markers }.into())
     }
 }
 
@@ -36,7 +49,11 @@ impl TypeSerializer for AnySerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        infer_to_python(value, include, exclude, extra)
+        if self.type_markers && extra.mode.is_json() {
+            to_type_markers(value, extra)
+        } else {
+            infer_to_python(value, include, exclude, extra)
+        }
     }
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
@@ -51,10 +68,93 @@ impl TypeSerializer for AnySerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        infer_serialize(value, serializer, include, exclude, extra)
+        if self.type_markers {
+            let marked = to_type_markers(value, extra).map_err(py_err_se_err)?;
+            infer_serialize(marked.bind(value.py()), serializer, None, None, extra)
+        } else {
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+// arbitrary id to identify recursion through `to_type_markers`, distinct from definition ref slot ids
+const TYPE_MARKERS_DEF_REF_ID: usize = usize::MAX - 1;
+
+/// Convert `value` to JSON compatible python objects, wrapping values which JSON can't represent loss-lessly
+/// in `{"__type__": ..., "value": ...}` markers so `any` validators with `type_markers` can restore them.
+/// Types without a marker, e.g. models or enums, are serialized by inference as usual.
+fn to_type_markers(value: &Bound<'_, PyAny>, mut extra: &Extra) -> PyResult<PyObject> {
+    let py = value.py();
+    let mut guard = extra.recursion_guard(value, TYPE_MARKERS_DEF_REF_ID)?;
+    let extra = guard.state();
+
+    let marked_items = |items: Bound<'_, PyAny>| -> PyResult<PyObject> {
+        let items = items
+            .iter()?
+            .map(|item| to_type_markers(&item?, extra))
+            .collect::<PyResult<Vec<_>>>()?;
+        Ok(PyList::new_bound(py, items).into_py(py))
+    };
+
+    let (type_name, marked_value) = match extra.ob_type_lookup.get_type(value) {
+        ObType::List => return marked_items(value.clone()),
+        ObType::Dict => {
+            let dict = value.downcast::<PyDict>()?;
+            if dict_needs_marker(dict) {
+                let pairs = dict
+                    .iter()
+                    .map(|(k, v)| {
+                        Ok(PyList::new_bound(
+                            py,
+                            [to_type_markers(&k, extra)?, to_type_markers(&v, extra)?],
+                        ))
+                    })
+                    .collect::<PyResult<Vec<_>>>()?;
+                ("dict", PyList::new_bound(py, pairs).into_py(py))
+            } else {
+                let marked = PyDict::new_bound(py);
+                for (k, v) in dict {
+                    marked.set_item(infer_json_key(&k, extra)?, to_type_markers(&v, extra)?)?;
+                }
+                return Ok(marked.into_py(py));
+            }
+        }
+        ObType::Tuple => ("tuple", marked_items(value.clone())?),
+        ObType::Set => ("set", marked_items(value.clone())?),
+        ObType::Frozenset => ("frozenset", marked_items(value.clone())?),
+        ObType::Bytes => ("bytes", encode_bytes(py, value.downcast::<PyBytes>()?.as_bytes())),
+        ObType::Bytearray => {
+            // Safety: the GIL is held and encoding doesn't run python code, so the bytearray can't be mutated
+            let bytes = unsafe { value.downcast::<PyByteArray>()?.as_bytes() };
+            ("bytearray", encode_bytes(py, bytes))
+        }
+        ObType::Datetime => ("datetime", infer_to_python(value, None, None, extra)?),
+        ObType::Date => ("date", infer_to_python(value, None, None, extra)?),
+        ObType::Time => ("time", infer_to_python(value, None, None, extra)?),
+        ObType::Timedelta => ("timedelta", infer_to_python(value, None, None, extra)?),
+        ObType::Uuid => ("uuid", infer_to_python(value, None, None, extra)?),
+        ObType::Decimal => ("decimal", infer_to_python(value, None, None, extra)?),
+        _ => return infer_to_python(value, None, None, extra),
+    };
+    let marker = PyDict::new_bound(py);
+    marker.set_item(intern!(py, TYPE_MARKER_KEY), type_name)?;
+    marker.set_item(intern!(py, TYPE_MARKER_VALUE_KEY), marked_value)?;
+    Ok(marker.into_py(py))
+}
+
+/// Dicts with non-str keys, or which look like a type marker, are serialized as a marked list of pairs
+fn dict_needs_marker(dict: &Bound<'_, PyDict>) -> bool {
+    let looks_like_marker = dict.len() == 2
+        && matches!(dict.contains(TYPE_MARKER_KEY), Ok(true))
+        && matches!(dict.contains(TYPE_MARKER_VALUE_KEY), Ok(true));
+    looks_like_marker || dict.keys().iter().any(|key| !key.is_exact_instance_of::<PyString>())
+}
+
+/// Bytes are always base64 encoded in markers, regardless of `ser_json_bytes`, so arbitrary bytes round-trip
+fn encode_bytes(py: Python, bytes: &[u8]) -> PyObject {
+    base64::engine::general_purpose::URL_SAFE.encode(bytes).into_py(py)
+}
@@ -0,0 +1,139 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyType};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+
+use super::{py_err_se_err, BuildSerializer, CombinedSerializer, Extra, SerMode, TypeSerializer};
+
+/// Serializes a `collections` type with the `list` or `dict` serializer for its items, in python mode instances
+/// of the collection are rebuilt so the type is preserved
+#[derive(Debug, Clone)]
+pub struct CollectionSerializer {
+    inner: Box<CombinedSerializer>,
+    class: Py<PyType>,
+    is_deque: bool,
+    maxlen: Option<usize>,
+    default_factory: Option<PyObject>,
+    name: &'static str,
+}
+
+macro_rules! collection_builder {
+    ($builder:ident, $expected_type:literal, $class_name:literal) => {
+        pub struct $builder;
+
+        impl BuildSerializer for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
+                definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+            ) -> PyResult<CombinedSerializer> {
+                CollectionSerializer::build(schema, config, definitions, $class_name)
+            }
+        }
+    };
+}
+
+collection_builder!(DequeBuilder, "deque", "deque");
+collection_builder!(CounterBuilder, "counter", "Counter");
+collection_builder!(OrderedDictBuilder, "ordered-dict", "OrderedDict");
+collection_builder!(DefaultDictBuilder, "defaultdict", "defaultdict");
+
+impl CollectionSerializer {
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+        class_name: &'static str,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let class = py.import_bound(intern!(py, "collections"))?.getattr(class_name)?;
+        let is_deque = class_name == "deque";
+        let inner_schema = PyDict::new_bound(py);
+        inner_schema.set_item(intern!(py, "type"), if is_deque { "list" } else { "dict" })?;
+        for key in ["items_schema", "keys_schema", "values_schema"] {
+            if let Some(value) = schema.get_item(key)? {
+                inner_schema.set_item(key, value)?;
+            }
+        }
+        Ok(Self {
+            inner: Box::new(CombinedSerializer::build(&inner_schema, config, definitions)?),
+            class: class.downcast_into::<PyType>()?.unbind(),
+            is_deque,
+            maxlen: schema.get_as(intern!(py, "maxlen"))?,
+            default_factory: schema.get_as(intern!(py, "default_factory"))?,
+            name: class_name,
+        }
+        .into())
+    }
+
+    /// The items of a deque as a list, since the `list` serializer doesn't accept deques
+    fn as_inner_value<'py>(&self, value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        if self.is_deque && value.is_instance(self.class.bind(value.py()))? {
+            let items = value.iter()?.collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new_bound(value.py(), items).into_any())
+        } else {
+            Ok(value.clone())
+        }
+    }
+}
+
+impl_py_gc_traverse!(CollectionSerializer {
+    inner,
+    class,
+    default_factory
+});
+
+impl TypeSerializer for CollectionSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        let items = self
+            .inner
+            .to_python(&self.as_inner_value(value)?, include, exclude, extra)?;
+        let class = self.class.bind(py);
+        match extra.mode {
+            SerMode::Json => Ok(items),
+            _ if !value.is_instance(class)? => Ok(items),
+            _ if self.is_deque => Ok(class.call1((items, self.maxlen))?.unbind()),
+            _ => match self.default_factory {
+                Some(ref default_factory) => Ok(class.call1((default_factory, items))?.unbind()),
+                None => Ok(class.call1((items,))?.unbind()),
+            },
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        self.inner.json_key(key, extra)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        let items = self.as_inner_value(value).map_err(py_err_se_err)?;
+        self.inner.serde_serialize(&items, serializer, include, exclude, extra)
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+
+    fn retry_with_lax_check(&self) -> bool {
+        self.inner.retry_with_lax_check()
+    }
+}
@@ -6,15 +6,15 @@ use std::borrow::Cow;
 use ahash::AHashMap;
 use serde::ser::SerializeMap;
 
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{nested_config, py_schema_error_type, AliasGenerator, ExtraBehavior, ExtrasDestination};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::DuckTypingSerMode;
 use crate::tools::SchemaDict;
 
 use super::{
-    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    apply_alias_priority, get_serialization_aliases, infer_json_key, infer_json_key_known, infer_serialize,
+    infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, ComputedFields, Extra, FieldsMode,
+    GeneralFieldsSerializer, ObType, SerAliases, SerCheck, SerField, TypeSerializer,
 };
 
 pub struct DataclassArgsBuilder;
@@ -29,8 +29,11 @@ impl BuildSerializer for DataclassArgsBuilder {
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
 
+        let alias_generator = AliasGenerator::from_config(config)?;
+
         let fields_list: Bound<'_, PyList> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, SerField> = AHashMap::with_capacity(fields_list.len());
+        let mut field_names: Vec<String> = Vec::with_capacity(fields.capacity());
 
         let fields_mode = match ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)? {
             ExtraBehavior::Allow => FieldsMode::TypedDictAllow,
@@ -40,21 +43,24 @@ impl BuildSerializer for DataclassArgsBuilder {
         for (index, item) in fields_list.iter().enumerate() {
             let field_info = item.downcast::<PyDict>()?;
             let name: String = field_info.get_as_req(intern!(py, "name"))?;
+            field_names.push(name.clone());
 
-            let key_py: Py<PyString> = PyString::new_bound(py, &name).into();
+            let key_py: Py<PyString> = PyString::intern_bound(py, &name).into();
 
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                fields.insert(name, SerField::new(py, key_py, None, None, true));
+                fields.insert(name, SerField::new(py, key_py, SerAliases::default(), None, true));
             } else {
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", index, e))?;
 
-                let alias = field_info.get_as(intern!(py, "serialization_alias"))?;
-                fields.insert(name, SerField::new(py, key_py, alias, Some(serializer), true));
+                let aliases = get_serialization_aliases(field_info, &name, alias_generator)?;
+                fields.insert(name, SerField::new(py, key_py, aliases, Some(serializer), true));
             }
         }
 
+        apply_alias_priority(schema, &mut fields, &field_names)?;
+
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
         Ok(GeneralFieldsSerializer::new(fields, fields_mode, None, computed_fields).into())
@@ -66,6 +72,8 @@ pub struct DataclassSerializer {
     class: Py<PyType>,
     serializer: Box<CombinedSerializer>,
     fields: Vec<Py<PyString>>,
+    // only set with `extra_behavior='allow'`, otherwise extras aren't serialized
+    extras_destination: Option<ExtrasDestination>,
     name: String,
 }
 
@@ -74,17 +82,22 @@ impl BuildSerializer for DataclassSerializer {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
 
-        // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        // models use the config from this model, unless it's set to merge with the parent config
+        let config = nested_config(schema, config)?;
 
         let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
         let serializer = Box::new(CombinedSerializer::build(&sub_schema, config.as_ref(), definitions)?);
+        let extras_destination =
+            match ExtraBehavior::from_schema_or_config(py, &sub_schema, config.as_ref(), ExtraBehavior::Ignore)? {
+                ExtraBehavior::Allow => ExtrasDestination::from_config(config.as_ref())?,
+                _ => None,
+            };
 
         let fields = schema
             .get_as_req::<Bound<'_, PyList>>(intern!(py, "fields"))?
@@ -96,6 +109,7 @@ impl BuildSerializer for DataclassSerializer {
             class: class.clone().unbind(),
             serializer,
             fields,
+            extras_destination,
             name: class.getattr(intern!(py, "__name__"))?.extract()?,
         }
         .into())
@@ -103,6 +117,11 @@ impl BuildSerializer for DataclassSerializer {
 }
 
 impl DataclassSerializer {
+    /// The serializer this one wraps
+    pub(crate) fn inner_serializer(&self) -> &CombinedSerializer {
+        &self.serializer
+    }
+
     fn allow_value(&self, value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<bool> {
         match extra.check {
             SerCheck::Strict => Ok(value.get_type().is(self.class.bind(value.py()))),
@@ -119,6 +138,20 @@ impl DataclassSerializer {
             let field_name = field_name.bind(py);
             dict.set_item(field_name, value.getattr(field_name)?)?;
         }
+        if let Some(ref extras_destination) = self.extras_destination {
+            let extras = match extras_destination.attribute(py) {
+                Some(attribute) => value.getattr(attribute).ok(),
+                None => value.getattr(intern!(py, "__dict__")).ok(),
+            };
+            if let Some(Ok(extras)) = extras.as_ref().map(|extras| extras.downcast::<PyDict>()) {
+                for (key, extra_value) in extras {
+                    // merged extras are the keys of `__dict__` which aren't fields
+                    if !dict.contains(&key)? {
+                        dict.set_item(key, extra_value)?;
+                    }
+                }
+            }
+        }
         Ok(dict)
     }
 }
@@ -145,7 +178,8 @@ impl TypeSerializer for DataclassSerializer {
         }
         if self.allow_value(value, &dc_extra)? {
             let py = value.py();
-            if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
+            if let (CombinedSerializer::Fields(fields_serializer), None) = (&*self.serializer, &self.extras_destination)
+            {
                 let output_dict = fields_serializer.main_to_python(
                     py,
                     known_dataclass_iter(&self.fields, value),
@@ -194,8 +228,10 @@ impl TypeSerializer for DataclassSerializer {
             return infer_serialize(value, serializer, include, exclude, &dc_extra);
         }
         if self.allow_value(value, &dc_extra).map_err(py_err_se_err)? {
-            if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
+            if let (CombinedSerializer::Fields(fields_serializer), None) = (&*self.serializer, &self.extras_destination)
+            {
                 let expected_len = self.fields.len() + fields_serializer.computed_field_count();
+                extra.maybe_release_gil(value.py());
                 let mut map = fields_serializer.main_serde_serialize(
                     known_dataclass_iter(&self.fields, value),
                     expected_len,
@@ -1,33 +1,78 @@
 use std::borrow::Cow;
+use std::str::FromStr;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use serde::ser::Error;
+use serde::Serialize;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::{DecimalMode, FromConfig, InfNanMode};
+use crate::serializers::errors::py_err_se_err;
 use crate::serializers::infer::{infer_json_key_known, infer_serialize_known, infer_to_python_known};
 use crate::serializers::ob_type::{IsType, ObType};
+use crate::tools::SchemaDict;
 
+use super::float::serialize_f64;
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
 };
 
 #[derive(Debug, Clone)]
-pub struct DecimalSerializer {}
+pub struct DecimalSerializer {
+    decimal_mode: DecimalMode,
+    inf_nan_mode: InfNanMode,
+}
 
 impl BuildSerializer for DecimalSerializer {
     const EXPECTED_TYPE: &'static str = "decimal";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let py = schema.py();
+        let decimal_mode = match schema.get_as(intern!(py, "ser_json_decimal"))? {
+            Some(decimal_mode) => decimal_mode,
+            None => DecimalMode::from_config(config)?,
+        };
+        let inf_nan_mode = match schema.get_as(intern!(py, "ser_json_inf_nan"))? {
+            Some(inf_nan_mode) => inf_nan_mode,
+            None => InfNanMode::from_config(config)?,
+        };
+        Ok(Self {
+            decimal_mode,
+            inf_nan_mode,
+        }
+        .into())
     }
 }
 
 impl_py_gc_traverse!(DecimalSerializer {});
 
+impl DecimalSerializer {
+    fn serialize_decimal<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        let is_finite = || -> PyResult<bool> { value.call_method0(intern!(value.py(), "is_finite"))?.extract() };
+        if self.decimal_mode == DecimalMode::Number && is_finite().map_err(py_err_se_err)? {
+            // with `arbitrary_precision` the digits are written to JSON as they are, without going via a float
+            let digits = value.str().map_err(py_err_se_err)?;
+            serde_json::Number::from_str(digits.to_str().map_err(py_err_se_err)?)
+                .map_err(S::Error::custom)?
+                .serialize(serializer)
+        } else {
+            let float: f64 = value.extract().map_err(py_err_se_err)?;
+            serialize_f64(float, serializer, extra.inf_nan_mode(self.inf_nan_mode))
+        }
+    }
+}
+
 impl TypeSerializer for DecimalSerializer {
     fn to_python(
         &self,
@@ -36,8 +81,13 @@ impl TypeSerializer for DecimalSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let _py = value.py();
+        let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
+            // python values can't hold the exact digits as a number, so both modes give a float
+            IsType::Exact | IsType::Subclass if extra.mode.is_json() && self.decimal_mode != DecimalMode::Str => {
+                let float: f64 = value.extract()?;
+                Ok(float.into_py(py))
+            }
             IsType::Exact | IsType::Subclass => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
@@ -65,9 +115,10 @@ impl TypeSerializer for DecimalSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => {
-                infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra)
-            }
+            IsType::Exact | IsType::Subclass => match self.decimal_mode {
+                DecimalMode::Str => infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra),
+                DecimalMode::Number | DecimalMode::Float => self.serialize_decimal(value, serializer, extra),
+            },
             IsType::False => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
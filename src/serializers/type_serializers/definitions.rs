@@ -54,6 +54,12 @@ impl Clone for DefinitionRefSerializer {
     }
 }
 
+impl DefinitionRefSerializer {
+    pub(crate) fn definition(&self) -> &DefinitionRef<CombinedSerializer> {
+        &self.definition
+    }
+}
+
 impl std::fmt::Debug for DefinitionRefSerializer {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("DefinitionRefSerializer")
@@ -93,7 +99,10 @@ impl TypeSerializer for DefinitionRefSerializer {
     ) -> PyResult<PyObject> {
         self.definition.read(|comb_serializer| {
             let comb_serializer = comb_serializer.unwrap();
-            let mut guard = extra.recursion_guard(value, self.definition.id())?;
+            let mut guard = match extra.cycle_guard(value, self.definition.id())? {
+                Ok(guard) => guard,
+                Err(cycle) => return cycle.to_python(value.py()),
+            };
             comb_serializer.to_python(value, include, exclude, guard.state())
         })
     }
@@ -112,9 +121,10 @@ impl TypeSerializer for DefinitionRefSerializer {
     ) -> Result<S::Ok, S::Error> {
         self.definition.read(|comb_serializer| {
             let comb_serializer = comb_serializer.unwrap();
-            let mut guard = extra
-                .recursion_guard(value, self.definition.id())
-                .map_err(py_err_se_err)?;
+            let mut guard = match extra.cycle_guard(value, self.definition.id()).map_err(py_err_se_err)? {
+                Ok(guard) => guard,
+                Err(cycle) => return cycle.serde_serialize(serializer),
+            };
             comb_serializer.serde_serialize(value, serializer, include, exclude, guard.state())
         })
     }
@@ -7,6 +7,7 @@ use pyo3::types::PyDict;
 use serde::ser::SerializeMap;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::read_only::as_py_dict;
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
@@ -33,7 +34,12 @@ impl BuildSerializer for DictSerializer {
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
-        let key_serializer = match schema.get_as(intern!(py, "keys_schema"))? {
+        // `key_serialization` takes priority so keys can be serialized differently to how they're validated
+        let keys_schema = match schema.get_as(intern!(py, "key_serialization"))? {
+            Some(key_serialization) => Some(key_serialization),
+            None => schema.get_as(intern!(py, "keys_schema"))?,
+        };
+        let key_serializer = match keys_schema {
             Some(items_schema) => CombinedSerializer::build(&items_schema, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
         };
@@ -65,6 +71,13 @@ impl BuildSerializer for DictSerializer {
     }
 }
 
+impl DictSerializer {
+    /// The serializer of each value
+    pub(crate) fn value_serializer(&self) -> &CombinedSerializer {
+        &self.value_serializer
+    }
+}
+
 impl_py_gc_traverse!(DictSerializer {
     key_serializer,
     value_serializer
@@ -79,8 +92,8 @@ impl TypeSerializer for DictSerializer {
         extra: &Extra,
     ) -> PyResult<PyObject> {
         let py = value.py();
-        match value.downcast::<PyDict>() {
-            Ok(py_dict) => {
+        match as_py_dict(value) {
+            Some(py_dict) => {
                 let value_serializer = self.value_serializer.as_ref();
 
                 let new_dict = PyDict::new_bound(py);
@@ -91,14 +104,21 @@ impl TypeSerializer for DictSerializer {
                             SerMode::Json => self.key_serializer.json_key(&key, extra)?.into_py(py),
                             _ => self.key_serializer.to_python(&key, None, None, extra)?,
                         };
-                        let value =
-                            value_serializer.to_python(&value, next_include.as_ref(), next_exclude.as_ref(), extra)?;
+                        let (next_include, next_exclude) = (next_include.as_ref(), next_exclude.as_ref());
+                        let value = extra.item_to_python(
+                            &value,
+                            Some(value_serializer),
+                            next_include,
+                            next_exclude,
+                            key.bind(py),
+                            || value_serializer.to_python(&value, next_include, next_exclude, extra),
+                        )?;
                         new_dict.set_item(key, value)?;
                     }
                 }
                 Ok(new_dict.into_py(py))
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
             }
@@ -117,8 +137,9 @@ impl TypeSerializer for DictSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyDict>() {
-            Ok(py_dict) => {
+        match as_py_dict(value) {
+            Some(py_dict) => {
+                extra.maybe_release_gil(value.py());
                 let mut map = serializer.serialize_map(Some(py_dict.len()))?;
                 let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
@@ -134,12 +155,12 @@ impl TypeSerializer for DictSerializer {
                             next_exclude.as_ref(),
                             extra,
                         );
-                        map.serialize_entry(&key, &value_serialize)?;
+                        extra.with_loc(&key, || map.serialize_entry(&key, &value_serialize))?;
                     }
                 }
                 map.end()
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
             }
@@ -3,12 +3,13 @@ use std::borrow::Cow;
 use crate::build_tools::py_schema_err;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyList, PyString, PyType};
 
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::errors::py_err_se_err;
 use crate::serializers::infer::{infer_json_key, infer_serialize, infer_to_python};
 use crate::tools::SchemaDict;
+use crate::validators::FlagMembers;
 
 use super::float::FloatSerializer;
 use super::simple::IntSerializer;
@@ -19,6 +20,10 @@ use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 pub struct EnumSerializer {
     class: Py<PyType>,
     serializer: Option<Box<CombinedSerializer>>,
+    // members are serialized by name in JSON mode, set when the schema validates by name
+    use_enum_names: bool,
+    // set for flags with `flag_serialization='list'`, members are serialized as a list of names in JSON mode
+    flag_members: Option<FlagMembers>,
 }
 
 impl BuildSerializer for EnumSerializer {
@@ -29,7 +34,22 @@ impl BuildSerializer for EnumSerializer {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        let sub_type: Option<String> = schema.get_as(intern!(schema.py(), "sub_type"))?;
+        let py = schema.py();
+        let sub_type: Option<String> = schema.get_as(intern!(py, "sub_type"))?;
+        let by: Option<String> = schema.get_as(intern!(py, "by"))?;
+        let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
+        let flag_serialization: Option<Bound<'_, PyString>> = schema.get_as(intern!(py, "flag_serialization"))?;
+        let flag_members = match flag_serialization.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("int") => None,
+            Some("list") => {
+                let members = schema.get_as_req(intern!(py, "members"))?;
+                match FlagMembers::build(&class, &members, false)? {
+                    Some(flag_members) => Some(flag_members),
+                    None => return py_schema_err!("`flag_serialization` is only supported for `enum.Flag` subclasses"),
+                }
+            }
+            Some(s) => return py_schema_err!("Invalid `flag_serialization` value: {}, expected 'int' or 'list'", s),
+        };
 
         let serializer = match sub_type.as_deref() {
             Some("int") => Some(Box::new(IntSerializer::new().into())),
@@ -39,8 +59,10 @@ impl BuildSerializer for EnumSerializer {
             None => None,
         };
         Ok(Self {
-            class: schema.get_as_req(intern!(schema.py(), "cls"))?,
+            class: class.unbind(),
             serializer,
+            use_enum_names: by.as_deref() == Some("name"),
+            flag_members,
         }
         .into())
     }
@@ -60,6 +82,14 @@ impl TypeSerializer for EnumSerializer {
         if value.is_exact_instance(self.class.bind(py)) {
             // if we're in JSON mode, we need to get the value attribute and serialize that
             if extra.mode.is_json() {
+                if self.use_enum_names {
+                    return Ok(value.getattr(intern!(py, "name"))?.into_py(py));
+                }
+                if let Some(ref flag_members) = self.flag_members {
+                    let flag_value: i64 = value.getattr(intern!(py, "value"))?.extract()?;
+                    let names: Vec<&str> = flag_members.names(flag_value).collect();
+                    return Ok(PyList::new_bound(py, names).into_py(py));
+                }
                 let dot_value = value.getattr(intern!(py, "value"))?;
                 match self.serializer {
                     Some(ref s) => s.to_python(&dot_value, include, exclude, extra),
@@ -78,6 +108,10 @@ impl TypeSerializer for EnumSerializer {
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         let py = key.py();
         if key.is_exact_instance(self.class.bind(py)) {
+            if self.use_enum_names {
+                let name = key.getattr(intern!(py, "name"))?;
+                return Ok(Cow::Owned(name.downcast::<PyString>()?.to_str()?.to_string()));
+            }
             let dot_value = key.getattr(intern!(py, "value"))?;
             let k = match self.serializer {
                 Some(ref s) => s.json_key(&dot_value, extra),
@@ -101,6 +135,18 @@ impl TypeSerializer for EnumSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         if value.is_exact_instance(self.class.bind(value.py())) {
+            if self.use_enum_names {
+                let name = value.getattr(intern!(value.py(), "name")).map_err(py_err_se_err)?;
+                let name = name.downcast::<PyString>().map_err(py_err_se_err)?;
+                return serializer.serialize_str(name.to_str().map_err(py_err_se_err)?);
+            }
+            if let Some(ref flag_members) = self.flag_members {
+                let flag_value: i64 = value
+                    .getattr(intern!(value.py(), "value"))
+                    .and_then(|v| v.extract())
+                    .map_err(py_err_se_err)?;
+                return serializer.collect_seq(flag_members.names(flag_value));
+            }
             let dot_value = value.getattr(intern!(value.py(), "value")).map_err(py_err_se_err)?;
             match self.serializer {
                 Some(ref s) => s.serde_serialize(&dot_value, serializer, include, exclude, extra),
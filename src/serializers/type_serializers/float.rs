@@ -6,7 +6,7 @@ use std::borrow::Cow;
 use serde::Serializer;
 
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::config::InfNanMode;
+use crate::serializers::config::{FromConfig, InfNanMode};
 use crate::tools::SchemaDict;
 
 use super::simple::to_str_json_key;
@@ -56,7 +56,11 @@ impl BuildSerializer for FloatSerializer {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Self::new(schema.py(), config).map(Into::into)
+        let inf_nan_mode = match schema.get_as(intern!(schema.py(), "ser_json_inf_nan"))? {
+            Some(inf_nan_mode) => inf_nan_mode,
+            None => InfNanMode::from_config(config)?,
+        };
+        Ok(Self { inf_nan_mode }.into())
     }
 }
 
@@ -107,7 +111,7 @@ impl TypeSerializer for FloatSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match value.extract::<f64>() {
-            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode),
+            Ok(v) => serialize_f64(v, serializer, extra.inf_nan_mode(self.inf_nan_mode)),
             Err(_) => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
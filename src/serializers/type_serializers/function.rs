@@ -12,14 +12,14 @@ use pyo3::types::PyString;
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 use crate::tools::{function_name, py_err, py_error_type};
-use crate::{PydanticOmit, PydanticSerializationUnexpectedValue};
+use crate::{PydanticOmit, PydanticSerializationUnexpectedValue, PythonCallbackLimitExceeded};
 
 use super::format::WhenUsed;
 
 use super::any::AnySerializer;
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, AnyFilter, BuildSerializer, CombinedSerializer,
-    Extra, ExtraOwned, PydanticSerializationError, SerMode, TypeSerializer,
+    function_err_se_err, infer_json_key, infer_serialize, infer_to_python, AnyFilter, BuildSerializer,
+    CombinedSerializer, Extra, ExtraOwned, PydanticSerializationError, SerMode, TypeSerializer,
 };
 
 pub struct FunctionBeforeSerializerBuilder;
@@ -149,6 +149,7 @@ impl FunctionPlainSerializer {
     ) -> PyResult<(bool, PyObject)> {
         let py = value.py();
         if self.when_used.should_use(value, extra) {
+            extra.count_python_callback()?;
             let v = if self.is_field_serializer {
                 if let Some(model) = extra.model {
                     if self.info_arg {
@@ -191,6 +192,8 @@ fn on_error(py: Python, err: PyErr, function_name: &str, extra: &Extra) -> PyRes
         }
     } else if let Ok(err) = exception.extract::<PydanticSerializationError>() {
         py_err!(PydanticSerializationError; "{}", err)
+    } else if exception.is_instance_of::<PythonCallbackLimitExceeded>() {
+        Err(err)
     } else if exception.is_instance_of::<PyRecursionError>() {
         py_err!(PydanticSerializationError; "Error calling function `{}`: RecursionError", function_name)
     } else {
@@ -213,7 +216,14 @@ macro_rules! function_type_serializer {
                 let py = value.py();
                 match self.call(value, include, exclude, extra) {
                     // None for include/exclude here, as filtering should be done
-                    Ok((true, v)) => self.return_serializer.to_python(v.bind(py), None, None, extra),
+                    Ok((true, v)) => {
+                        if let Some(awaitables) = extra.awaitables {
+                            if let Some(placeholder) = awaitables.defer(v.bind(py), &self.return_serializer, extra)? {
+                                return Ok(placeholder);
+                            }
+                        }
+                        self.return_serializer.to_python(v.bind(py), None, None, extra)
+                    }
                     Ok((false, v)) => self
                         .get_fallback_serializer()
                         .to_python(v.bind(py), None, None, extra),
@@ -261,7 +271,7 @@ macro_rules! function_type_serializer {
                             .serde_serialize(v.bind(py), serializer, None, None, extra)
                     }
                     Err(err) => {
-                        on_error(py, err, &self.function_name, extra).map_err(py_err_se_err)?;
+                        on_error(py, err, &self.function_name, extra).map_err(|err| function_err_se_err(py, err))?;
                         infer_serialize(value, serializer, include, exclude, extra)
                     }
                 }
@@ -372,6 +382,11 @@ impl BuildSerializer for FunctionWrapSerializer {
 }
 
 impl FunctionWrapSerializer {
+    /// The serializer this one wraps
+    pub(crate) fn inner_serializer(&self) -> &CombinedSerializer {
+        &self.serializer
+    }
+
     fn call(
         &self,
         value: &Bound<'_, PyAny>,
@@ -381,6 +396,7 @@ impl FunctionWrapSerializer {
     ) -> PyResult<(bool, PyObject)> {
         let py = value.py();
         if self.when_used.should_use(value, extra) {
+            extra.count_python_callback()?;
             let serialize = SerializationCallable::new(py, &self.serializer, include, exclude, extra);
             let v = if self.is_field_serializer {
                 if let Some(model) = extra.model {
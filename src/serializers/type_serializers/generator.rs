@@ -117,6 +117,7 @@ impl TypeSerializer for GeneratorSerializer {
                     Ok(len) => Some(len),
                     Err(_) => None,
                 };
+                extra.maybe_release_gil(value.py());
                 let mut seq = serializer.serialize_seq(len)?;
                 let item_serializer = self.item_serializer.as_ref();
 
@@ -2,14 +2,16 @@ use std::borrow::Cow;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyString};
 
 use serde::ser::SerializeSeq;
 
 use crate::definitions::DefinitionsBuilder;
+use crate::read_only::as_py_list;
 use crate::tools::SchemaDict;
 
 use super::any::AnySerializer;
+use super::generator::GeneratorSerializer;
 use super::{
     infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra, PydanticSerializer,
     SchemaFilter, TypeSerializer,
@@ -31,6 +33,13 @@ impl BuildSerializer for ListSerializer {
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
+        // lists validated to a generator are serialized like generators
+        if schema
+            .get_as::<Bound<'_, PyString>>(intern!(py, "output"))?
+            .is_some_and(|output| output == "generator")
+        {
+            return GeneratorSerializer::build(schema, config, definitions);
+        }
         let item_serializer = match schema.get_as(intern!(py, "items_schema"))? {
             Some(items_schema) => CombinedSerializer::build(&items_schema, config, definitions)?,
             None => AnySerializer::build(schema, config, definitions)?,
@@ -45,6 +54,13 @@ impl BuildSerializer for ListSerializer {
     }
 }
 
+impl ListSerializer {
+    /// The serializer of each item
+    pub(crate) fn item_serializer(&self) -> &CombinedSerializer {
+        &self.item_serializer
+    }
+}
+
 impl_py_gc_traverse!(ListSerializer { item_serializer });
 
 impl TypeSerializer for ListSerializer {
@@ -55,8 +71,8 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
+        match as_py_list(value) {
+            Some(py_list) => {
                 let py = value.py();
                 let item_serializer = self.item_serializer.as_ref();
 
@@ -64,17 +80,21 @@ impl TypeSerializer for ListSerializer {
                 for (index, element) in py_list.iter().enumerate() {
                     let op_next = self.filter.index_filter(index, include, exclude, value.len().ok())?;
                     if let Some((next_include, next_exclude)) = op_next {
-                        items.push(item_serializer.to_python(
+                        let (next_include, next_exclude) = (next_include.as_ref(), next_exclude.as_ref());
+                        let item = extra.item_to_python(
                             &element,
-                            next_include.as_ref(),
-                            next_exclude.as_ref(),
-                            extra,
-                        )?);
+                            Some(item_serializer),
+                            next_include,
+                            next_exclude,
+                            &items.len(),
+                            || item_serializer.to_python(&element, next_include, next_exclude, extra),
+                        )?;
+                        items.push(item);
                     }
                 }
                 Ok(items.into_py(py))
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
             }
@@ -93,10 +113,12 @@ impl TypeSerializer for ListSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
-        match value.downcast::<PyList>() {
-            Ok(py_list) => {
+        match as_py_list(value) {
+            Some(py_list) => {
+                extra.maybe_release_gil(value.py());
                 let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
                 let item_serializer = self.item_serializer.as_ref();
+                let mut position = 0;
 
                 for (index, element) in py_list.iter().enumerate() {
                     let op_next = self
@@ -111,12 +133,13 @@ impl TypeSerializer for ListSerializer {
                             next_exclude.as_ref(),
                             extra,
                         );
-                        seq.serialize_element(&item_serialize)?;
+                        extra.with_loc(&position, || seq.serialize_element(&item_serialize))?;
+                        position += 1;
                     }
                 }
                 seq.end()
             }
-            Err(_) => {
+            None => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
             }
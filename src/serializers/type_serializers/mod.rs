@@ -1,5 +1,6 @@
 pub mod any;
 pub mod bytes;
+pub mod collection;
 pub mod dataclass;
 pub mod datetime_etc;
 pub mod decimal;
@@ -15,14 +16,19 @@ pub mod json_or_python;
 pub mod list;
 pub mod literal;
 pub mod model;
+pub mod money;
+pub mod namedtuple;
+pub mod native;
 pub mod nullable;
 pub mod other;
+pub mod phone;
 pub mod set_frozenset;
 pub mod simple;
 pub mod string;
 pub mod timedelta;
 pub mod tuple;
 pub mod typed_dict;
+pub mod ulid;
 pub mod union;
 pub mod url;
 pub mod uuid;
@@ -30,10 +36,12 @@ pub mod with_default;
 
 use super::computed_fields::ComputedFields;
 use super::config::utf8_py_error;
-use super::errors::{py_err_se_err, PydanticSerializationError};
+use super::errors::{function_err_se_err, py_err_se_err, PydanticSerializationError};
 use super::extra::{Extra, ExtraOwned, SerCheck, SerMode};
-use super::fields::{FieldsMode, GeneralFieldsSerializer, SerField};
+use super::fields::{
+    apply_alias_priority, get_serialization_aliases, FieldsMode, GeneralFieldsSerializer, SerAliases, SerField,
+};
 use super::filter::{AnyFilter, SchemaFilter};
 use super::infer::{infer_json_key, infer_json_key_known, infer_serialize, infer_to_python};
-use super::ob_type::{IsType, ObType};
+use super::ob_type::{IsType, ObType, ObTypeLookup};
 use super::shared::{to_json_bytes, BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
@@ -1,5 +1,6 @@
 use std::borrow::Cow;
 
+use pyo3::exceptions::PyAttributeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet, PyString, PyType};
@@ -7,12 +8,12 @@ use pyo3::types::{PyDict, PySet, PyString, PyType};
 use ahash::AHashMap;
 
 use super::{
-    infer_json_key, infer_json_key_known, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer,
-    CombinedSerializer, ComputedFields, Extra, FieldsMode, GeneralFieldsSerializer, ObType, SerCheck, SerField,
-    TypeSerializer,
+    apply_alias_priority, get_serialization_aliases, infer_json_key, infer_json_key_known, infer_serialize,
+    infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, ComputedFields, Extra, FieldsMode,
+    GeneralFieldsSerializer, ObType, SerAliases, SerCheck, SerField, TypeSerializer,
 };
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, ExtraBehavior};
+use crate::build_tools::{nested_config, py_schema_error_type, AliasGenerator, ExtraBehavior, ExtrasDestination};
 use crate::definitions::DefinitionsBuilder;
 use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 use crate::serializers::extra::DuckTypingSerMode;
@@ -33,15 +34,24 @@ impl BuildSerializer for ModelFieldsBuilder {
         let py = schema.py();
 
         let fields_mode = match has_extra(schema, config)? {
-            true => FieldsMode::ModelExtra,
+            // extras merged into `__dict__` are its keys which aren't fields, like extras of typed dicts
+            true => match ExtrasDestination::from_config(config)? {
+                Some(ExtrasDestination::Merge) => FieldsMode::TypedDictAllow,
+                _ => FieldsMode::ModelExtra,
+            },
             false => FieldsMode::SimpleDict,
         };
 
+        let alias_generator = AliasGenerator::from_config(config)?;
+
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, SerField> = AHashMap::with_capacity(fields_dict.len());
+        let mut field_names: Vec<String> = Vec::with_capacity(fields.capacity());
 
         let extra_serializer = match (schema.get_item(intern!(py, "extras_schema"))?, &fields_mode) {
-            (Some(v), FieldsMode::ModelExtra) => Some(CombinedSerializer::build(&v.extract()?, config, definitions)?),
+            (Some(v), FieldsMode::ModelExtra | FieldsMode::TypedDictAllow) => {
+                Some(CombinedSerializer::build(&v.extract()?, config, definitions)?)
+            }
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
@@ -49,23 +59,26 @@ impl BuildSerializer for ModelFieldsBuilder {
         for (key, value) in fields_dict {
             let key_py = key.downcast_into::<PyString>()?;
             let key: String = key_py.extract()?;
+            field_names.push(key.clone());
             let field_info = value.downcast()?;
 
             let key_py: Py<PyString> = key_py.into();
 
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                fields.insert(key, SerField::new(py, key_py, None, None, true));
+                fields.insert(key, SerField::new(py, key_py, SerAliases::default(), None, true));
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let aliases = get_serialization_aliases(field_info, &key, alias_generator)?;
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
 
-                fields.insert(key, SerField::new(py, key_py, alias, Some(serializer), true));
+                fields.insert(key, SerField::new(py, key_py, aliases, Some(serializer), true));
             }
         }
 
+        apply_alias_priority(schema, &mut fields, &field_names)?;
+
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
         Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields).into())
@@ -79,6 +92,7 @@ pub struct ModelSerializer {
     has_extra: bool,
     root_model: bool,
     name: String,
+    extras_destination: ExtrasDestination,
 }
 
 impl BuildSerializer for ModelSerializer {
@@ -86,13 +100,13 @@ impl BuildSerializer for ModelSerializer {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
 
-        // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        // models use the config from this model, unless it's set to merge with the parent config
+        let config = nested_config(schema, config)?;
 
         let class: Py<PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
@@ -106,6 +120,8 @@ impl BuildSerializer for ModelSerializer {
             has_extra: has_extra(schema, config.as_ref())?,
             root_model,
             name,
+            extras_destination: ExtrasDestination::from_config(config.as_ref())?
+                .unwrap_or(ExtrasDestination::ModelExtra),
         }
         .into())
     }
@@ -118,6 +134,15 @@ fn has_extra(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) ->
 }
 
 impl ModelSerializer {
+    /// The serializer this one wraps
+    pub(crate) fn inner_serializer(&self) -> &CombinedSerializer {
+        &self.serializer
+    }
+
+    pub(crate) fn is_root_model(&self) -> bool {
+        self.root_model
+    }
+
     fn allow_value(&self, value: &Bound<'_, PyAny>, extra: &Extra) -> PyResult<bool> {
         let class = self.class.bind(value.py());
         match extra.check {
@@ -145,13 +170,21 @@ impl ModelSerializer {
             attrs = new_attrs;
         }
 
-        if self.has_extra {
-            let model_extra = model.getattr(intern!(py, "__pydantic_extra__"))?;
-            let py_tuple = (attrs, model_extra).to_object(py).into_bound(py);
-            Ok(py_tuple)
-        } else {
-            Ok(attrs.into_any())
+        // extras merged into `__dict__` are serialized by the fields serializer as keys which aren't fields
+        let Some(attribute) = self.extras_destination.attribute(py).filter(|_| self.has_extra) else {
+            return Ok(attrs.into_any());
+        };
+        let model_extra = match model.getattr(&attribute) {
+            Ok(model_extra) => model_extra,
+            Err(err) if err.is_instance_of::<PyAttributeError>(py) => py.None().into_bound(py),
+            Err(err) => return Err(err),
+        };
+        // as in validation, attributes without a slot or descriptor are stored in `__dict__`
+        if attrs.contains(&attribute)? {
+            attrs = attrs.copy()?;
+            attrs.del_item(&attribute)?;
         }
+        Ok((attrs, model_extra).to_object(py).into_bound(py))
     }
 }
 
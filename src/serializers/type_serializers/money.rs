@@ -0,0 +1,126 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use serde::ser::SerializeMap;
+
+use crate::build_tools::py_schema_err;
+use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    TypeSerializer,
+};
+
+#[derive(Debug, Clone)]
+pub struct MoneySerializer {
+    // serialize to a string like `'12.30 USD'` rather than a dict with `amount` and `currency`
+    as_str: bool,
+}
+
+impl BuildSerializer for MoneySerializer {
+    const EXPECTED_TYPE: &'static str = "money";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let money_serialization: Option<Bound<'_, PyString>> = schema.get_as(intern!(py, "money_serialization"))?;
+        let as_str = match money_serialization.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("object") => false,
+            Some("str") => true,
+            Some(s) => return py_schema_err!("Invalid `money_serialization` value: {}, expected 'object' or 'str'", s),
+        };
+        Ok(Self { as_str }.into())
+    }
+}
+
+impl_py_gc_traverse!(MoneySerializer {});
+
+/// The amount and currency of a dict like those returned by money validation
+fn money_parts<'py>(value: &Bound<'py, PyAny>) -> PyResult<Option<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> {
+    let py = value.py();
+    let Ok(dict) = value.downcast::<PyDict>() else {
+        return Ok(None);
+    };
+    match (
+        dict.get_item(intern!(py, "amount"))?,
+        dict.get_item(intern!(py, "currency"))?,
+    ) {
+        (Some(amount), Some(currency)) => Ok(Some((amount, currency))),
+        _ => Ok(None),
+    }
+}
+
+fn money_str(amount: &Bound<'_, PyAny>, currency: &Bound<'_, PyAny>) -> PyResult<String> {
+    Ok(format!("{} {}", amount.str()?, currency.str()?))
+}
+
+impl TypeSerializer for MoneySerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match money_parts(value)? {
+            Some((amount, currency)) if self.as_str => Ok(money_str(&amount, &currency)?.into_py(py)),
+            Some((amount, currency)) => {
+                let money = PyDict::new_bound(py);
+                // amounts are strings in JSON so no precision is lost
+                match extra.mode.is_json() {
+                    true => money.set_item(intern!(py, "amount"), amount.str()?)?,
+                    false => money.set_item(intern!(py, "amount"), amount)?,
+                }
+                money.set_item(intern!(py, "currency"), currency)?;
+                Ok(money.into_py(py))
+            }
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+        infer_json_key(key, extra)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match money_parts(value).map_err(py_err_se_err)? {
+            Some((amount, currency)) if self.as_str => {
+                serializer.serialize_str(&money_str(&amount, &currency).map_err(py_err_se_err)?)
+            }
+            Some((amount, currency)) => {
+                let amount = amount.str().map_err(py_err_se_err)?;
+                let currency = currency.str().map_err(py_err_se_err)?;
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("amount", amount.to_str().map_err(py_err_se_err)?)?;
+                map.serialize_entry("currency", currency.to_str().map_err(py_err_se_err)?)?;
+                map.end()
+            }
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
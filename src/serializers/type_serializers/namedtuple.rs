@@ -0,0 +1,205 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+
+use serde::ser::{SerializeMap, SerializeSeq};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::{FromConfig, NamedTupleMode};
+use crate::tools::SchemaDict;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    PydanticSerializer, SchemaFilter, SerMode, TypeSerializer,
+};
+
+#[derive(Debug, Clone)]
+pub struct NamedTupleSerializer {
+    names: Vec<Py<PyString>>,
+    serializers: Vec<CombinedSerializer>,
+    // include and exclude select items by index, like tuples
+    filter: SchemaFilter<usize>,
+    mode: NamedTupleMode,
+    name: String,
+}
+
+impl BuildSerializer for NamedTupleSerializer {
+    const EXPECTED_TYPE: &'static str = "namedtuple";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let py = schema.py();
+        let fields: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
+        let mut names = Vec::with_capacity(fields.len());
+        let mut serializers = Vec::with_capacity(fields.len());
+        for (name, field_schema) in fields {
+            names.push(name.downcast_into::<PyString>()?.unbind());
+            serializers.push(CombinedSerializer::build(
+                field_schema.downcast()?,
+                config,
+                definitions,
+            )?);
+        }
+        let class = schema.get_as_req::<Bound<'_, PyAny>>(intern!(py, "cls"))?;
+
+        Ok(Self {
+            names,
+            serializers,
+            filter: SchemaFilter::from_schema(schema)?,
+            mode: NamedTupleMode::from_config(config)?,
+            name: class.getattr(intern!(py, "__qualname__"))?.extract()?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(NamedTupleSerializer { serializers });
+
+impl NamedTupleSerializer {
+    /// The tuple if it has an item for each field
+    fn as_tuple<'a, 'py>(&self, value: &'a Bound<'py, PyAny>) -> Option<&'a Bound<'py, PyTuple>> {
+        value
+            .downcast::<PyTuple>()
+            .ok()
+            .filter(|py_tuple| py_tuple.len() == self.serializers.len())
+    }
+
+    /// Call `f` with each item which isn't excluded, its field name and serializer, and the include and exclude
+    /// to serialize it with
+    fn for_each_item<'py, E>(
+        &self,
+        py_tuple: &Bound<'py, PyTuple>,
+        include: Option<&Bound<'py, PyAny>>,
+        exclude: Option<&Bound<'py, PyAny>>,
+        mut f: impl FnMut(
+            Bound<'py, PyAny>,
+            &Bound<'py, PyString>,
+            &CombinedSerializer,
+            Option<Bound<'py, PyAny>>,
+            Option<Bound<'py, PyAny>>,
+        ) -> Result<(), E>,
+    ) -> PyResult<Result<(), E>> {
+        let py = py_tuple.py();
+        let fields = self.names.iter().zip(&self.serializers);
+        for (index, (item, (name, serializer))) in py_tuple.iter().zip(fields).enumerate() {
+            if let Some((next_include, next_exclude)) =
+                self.filter
+                    .index_filter(index, include, exclude, Some(self.serializers.len()))?
+            {
+                if let Err(err) = f(item, name.bind(py), serializer, next_include, next_exclude) {
+                    return Ok(Err(err));
+                }
+            }
+        }
+        Ok(Ok(()))
+    }
+}
+
+impl TypeSerializer for NamedTupleSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        let Some(py_tuple) = self.as_tuple(value) else {
+            extra.warnings.on_fallback_py(&self.name, value, extra)?;
+            return infer_to_python(value, include, exclude, extra);
+        };
+        match (extra.mode, self.mode) {
+            (SerMode::Json, NamedTupleMode::Object) => {
+                let dict = PyDict::new_bound(py);
+                self.for_each_item(
+                    py_tuple,
+                    include,
+                    exclude,
+                    |item, name, serializer, include, exclude| {
+                        dict.set_item(
+                            name,
+                            serializer.to_python(&item, include.as_ref(), exclude.as_ref(), extra)?,
+                        )
+                    },
+                )??;
+                Ok(dict.into_py(py))
+            }
+            (mode, _) => {
+                let mut items = Vec::with_capacity(self.serializers.len());
+                self.for_each_item(py_tuple, include, exclude, |item, _, serializer, include, exclude| {
+                    serializer
+                        .to_python(&item, include.as_ref(), exclude.as_ref(), extra)
+                        .map(|item| items.push(item))
+                })??;
+                match mode {
+                    SerMode::Json => Ok(PyList::new_bound(py, items).into_py(py)),
+                    _ => Ok(PyTuple::new_bound(py, items).into_py(py)),
+                }
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        infer_json_key(key, extra)
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        let Some(py_tuple) = self.as_tuple(value) else {
+            extra.warnings.on_fallback_ser::<S>(&self.name, value, extra)?;
+            return infer_serialize(value, serializer, include, exclude, extra);
+        };
+        match self.mode {
+            NamedTupleMode::Object => {
+                let mut map = serializer.serialize_map(Some(self.serializers.len()))?;
+                self.for_each_item(
+                    py_tuple,
+                    include,
+                    exclude,
+                    |item, name, serializer, include, exclude| {
+                        let key = name.to_str().map_err(py_err_se_err)?;
+                        map.serialize_entry(
+                            key,
+                            &PydanticSerializer::new(&item, serializer, include.as_ref(), exclude.as_ref(), extra),
+                        )
+                    },
+                )
+                .map_err(py_err_se_err)??;
+                map.end()
+            }
+            NamedTupleMode::Array => {
+                let mut seq = serializer.serialize_seq(Some(self.serializers.len()))?;
+                self.for_each_item(py_tuple, include, exclude, |item, _, serializer, include, exclude| {
+                    seq.serialize_element(&PydanticSerializer::new(
+                        &item,
+                        serializer,
+                        include.as_ref(),
+                        exclude.as_ref(),
+                        extra,
+                    ))
+                })
+                .map_err(py_err_se_err)??;
+                seq.end()
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn retry_with_lax_check(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,76 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::definitions::DefinitionsBuilder;
+use crate::native::NativeType;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    TypeSerializer,
+};
+
+/// Serializes with the `serialize` function of a native type from an extension module, if it has one,
+/// then by type inference
+#[derive(Debug, Clone)]
+pub struct NativeSerializer {
+    native: NativeType,
+}
+
+impl BuildSerializer for NativeSerializer {
+    const EXPECTED_TYPE: &'static str = "native";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {
+            native: NativeType::from_schema(schema)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(NativeSerializer { native });
+
+impl TypeSerializer for NativeSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        match self.native.serialize(value, extra.mode.is_json())? {
+            Some(serialized) => infer_to_python(&serialized, include, exclude, extra),
+            None => infer_to_python(value, include, exclude, extra),
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        match self.native.serialize(key, true)? {
+            Some(serialized) => Ok(Cow::Owned(infer_json_key(&serialized, extra)?.into_owned())),
+            None => infer_json_key(key, extra),
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match self.native.serialize(value, true).map_err(py_err_se_err)? {
+            Some(serialized) => infer_serialize(&serialized, serializer, include, exclude, extra),
+            None => infer_serialize(value, serializer, include, exclude, extra),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.native.name
+    }
+}
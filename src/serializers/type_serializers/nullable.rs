@@ -30,6 +30,13 @@ impl BuildSerializer for NullableSerializer {
     }
 }
 
+impl NullableSerializer {
+    /// The serializer this one wraps
+    pub(crate) fn inner_serializer(&self) -> &CombinedSerializer {
+        &self.serializer
+    }
+}
+
 impl_py_gc_traverse!(NullableSerializer { serializer });
 
 impl TypeSerializer for NullableSerializer {
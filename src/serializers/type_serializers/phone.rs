@@ -0,0 +1,122 @@
+use std::borrow::Cow;
+
+use phonenumber::Mode;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::definitions::DefinitionsBuilder;
+use crate::phone::{format_phone_number, parse_format, parse_phone_number, PyPhoneNumber};
+use crate::tools::SchemaDict;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    TypeSerializer,
+};
+
+/// Phone numbers are validated to an E.164 string or a `PhoneNumber`, with `output_format` both are written in
+/// that format, otherwise `PhoneNumber`s are written as E.164 strings in JSON
+#[derive(Debug, Clone)]
+pub struct PhoneSerializer {
+    output_format: Option<Mode>,
+}
+
+impl_py_gc_traverse!(PhoneSerializer {});
+
+impl BuildSerializer for PhoneSerializer {
+    const EXPECTED_TYPE: &'static str = "phone";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        let output_format: Option<Bound<'_, PyString>> = schema.get_as(intern!(schema.py(), "output_format"))?;
+        let output_format = match output_format {
+            Some(s) => match parse_format(s.to_str()?) {
+                Some(mode) => Some(mode),
+                None => {
+                    return py_schema_err!(
+                        "Invalid `output_format` value: {}, expected 'e164', 'international', 'national' or 'rfc3966'",
+                        s
+                    )
+                }
+            },
+            None => None,
+        };
+        Ok(Self { output_format }.into())
+    }
+}
+
+impl PhoneSerializer {
+    /// The string to write for `value`, or `None` if it isn't a phone number
+    fn format_value(&self, value: &Bound<'_, PyAny>) -> PyResult<Option<String>> {
+        if let Ok(py_phone) = value.downcast::<PyPhoneNumber>() {
+            let mode = self.output_format.unwrap_or(Mode::E164);
+            Ok(Some(format_phone_number(py_phone.get().number(), mode)))
+        } else if let Ok(py_str) = value.downcast::<PyString>() {
+            let s = py_str.to_str()?;
+            // strings from validation are E.164 numbers, so can be reparsed without a region
+            match (self.output_format, parse_phone_number(s, None)) {
+                (Some(mode), Ok(number)) => Ok(Some(format_phone_number(&number, mode))),
+                _ => Ok(Some(s.to_owned())),
+            }
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+impl TypeSerializer for PhoneSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        if self.output_format.is_none() && !extra.mode.is_json() && value.is_instance_of::<PyPhoneNumber>() {
+            return Ok(value.into_py(py));
+        }
+        match self.format_value(value)? {
+            Some(s) => Ok(s.into_py(py)),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        match self.format_value(key)? {
+            Some(s) => Ok(Cow::Owned(s)),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match self.format_value(value).map_err(py_err_se_err)? {
+            Some(s) => serializer.serialize_str(&s),
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
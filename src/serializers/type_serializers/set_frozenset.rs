@@ -2,7 +2,7 @@ use std::borrow::Cow;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet};
+use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet, PyTuple};
 
 use serde::ser::SerializeSeq;
 
@@ -16,11 +16,13 @@ use super::{
 };
 
 macro_rules! build_serializer {
-    ($struct_name:ident, $expected_type:literal, $py_type:ty) => {
+    ($struct_name:ident, $expected_type:literal, $py_type:ty, $ordered_type:ty) => {
         #[derive(Debug, Clone)]
         pub struct $struct_name {
             item_serializer: Box<CombinedSerializer>,
             name: String,
+            // `ordered` sets are validated to a list or tuple of the unique items, which keep their order
+            ordered: bool,
         }
 
         impl BuildSerializer for $struct_name {
@@ -40,6 +42,7 @@ macro_rules! build_serializer {
                 Ok(Self {
                     item_serializer: Box::new(item_serializer),
                     name,
+                    ordered: schema.get_as(intern!(py, "ordered"))?.unwrap_or(false),
                 }
                 .into())
             }
@@ -56,6 +59,22 @@ macro_rules! build_serializer {
                 extra: &Extra,
             ) -> PyResult<PyObject> {
                 let py = value.py();
+                if let Some(ordered) = self
+                    .ordered
+                    .then(|| value.downcast::<$ordered_type>().ok())
+                    .flatten()
+                {
+                    let item_serializer = self.item_serializer.as_ref();
+
+                    let mut items = Vec::with_capacity(ordered.len());
+                    for element in ordered.iter() {
+                        items.push(item_serializer.to_python(&element, include, exclude, extra)?);
+                    }
+                    return match extra.mode {
+                        SerMode::Json => Ok(PyList::new_bound(py, items).into_py(py)),
+                        _ => Ok(<$ordered_type>::new_bound(py, items).into_py(py)),
+                    };
+                }
                 match value.downcast::<$py_type>() {
                     Ok(py_set) => {
                         let item_serializer = self.item_serializer.as_ref();
@@ -88,8 +107,23 @@ macro_rules! build_serializer {
                 exclude: Option<&Bound<'_, PyAny>>,
                 extra: &Extra,
             ) -> Result<S::Ok, S::Error> {
+                if let Some(ordered) = self
+                    .ordered
+                    .then(|| value.downcast::<$ordered_type>().ok())
+                    .flatten()
+                {
+                    let mut seq = serializer.serialize_seq(Some(ordered.len()))?;
+                    let item_serializer = self.item_serializer.as_ref();
+
+                    for value in ordered.iter() {
+                        let item_serialize = PydanticSerializer::new(&value, item_serializer, include, exclude, extra);
+                        seq.serialize_element(&item_serialize)?;
+                    }
+                    return seq.end();
+                }
                 match value.downcast::<$py_type>() {
                     Ok(py_set) => {
+                        extra.maybe_release_gil(value.py());
                         let mut seq = serializer.serialize_seq(Some(py_set.len()))?;
                         let item_serializer = self.item_serializer.as_ref();
 
@@ -116,5 +150,5 @@ macro_rules! build_serializer {
     };
 }
 
-build_serializer!(SetSerializer, "set", PySet);
-build_serializer!(FrozenSetSerializer, "frozenset", PyFrozenSet);
+build_serializer!(SetSerializer, "set", PySet, PyList);
+build_serializer!(FrozenSetSerializer, "frozenset", PyFrozenSet, PyTuple);
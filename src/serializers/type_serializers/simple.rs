@@ -1,5 +1,5 @@
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyInt};
 
 use std::borrow::Cow;
 
@@ -182,7 +182,18 @@ pub(crate) fn to_str_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a,
     Ok(Cow::Owned(key.str()?.to_string_lossy().into_owned()))
 }
 
-build_simple_serializer!(IntSerializer, "int", Int, ObType::Int, to_str_json_key, true);
+pub(crate) fn int_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, str>> {
+    // format exact ints in rust rather than calling `str()` which builds an intermediate python string,
+    // subclasses may override `__str__` so they still go via python
+    if key.is_exact_instance_of::<PyInt>() {
+        if let Ok(int) = key.extract::<i64>() {
+            return Ok(Cow::Owned(int.to_string()));
+        }
+    }
+    to_str_json_key(key)
+}
+
+build_simple_serializer!(IntSerializer, "int", Int, ObType::Int, int_json_key, true);
 
 pub(crate) fn bool_json_key<'a>(key: &'a Bound<'_, PyAny>) -> PyResult<Cow<'a, str>> {
     let v = if key.is_truthy().unwrap_or(false) {
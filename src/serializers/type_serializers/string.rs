@@ -57,8 +57,7 @@ impl TypeSerializer for StrSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         if let Ok(py_str) = key.downcast::<PyString>() {
-            // FIXME py cow to avoid the copy
-            Ok(Cow::Owned(py_str.to_string_lossy().into_owned()))
+            Ok(py_str.to_string_lossy())
         } else {
             extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
             infer_json_key(key, extra)
@@ -94,3 +93,18 @@ pub fn serialize_py_str<S: serde::ser::Serializer>(
     let s = py_str.to_str().map_err(py_err_se_err)?;
     serializer.serialize_str(s)
 }
+
+/// Strings validated against a `format` are returned unchanged, so are serialized as strings
+pub struct StringFormatBuilder;
+
+impl BuildSerializer for StringFormatBuilder {
+    const EXPECTED_TYPE: &'static str = "string-format";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        StrSerializer::build(schema, config, definitions)
+    }
+}
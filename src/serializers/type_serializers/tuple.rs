@@ -5,12 +5,15 @@ use std::borrow::Cow;
 use std::iter;
 
 use serde::ser::SerializeSeq;
+use serde::Serialize;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
+use crate::serializers::config::{SerializationConfig, TupleKeyMode};
 use crate::serializers::extra::SerCheck;
 use crate::serializers::type_serializers::any::AnySerializer;
 use crate::tools::SchemaDict;
-use crate::PydanticSerializationUnexpectedValue;
+use crate::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
@@ -43,6 +46,13 @@ impl BuildSerializer for TupleSerializer {
         let mut serializer_names = serializers.iter().map(TypeSerializer::get_name).collect::<Vec<_>>();
         let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
         if let Some(variadic_item_index) = variadic_item_index {
+            if variadic_item_index >= serializers.len() {
+                return py_schema_err!(
+                    "`variadic_item_index` {} is out of bounds for {} items",
+                    variadic_item_index,
+                    serializers.len()
+                );
+            }
             serializer_names.insert(variadic_item_index + 1, "...");
         }
         let name = format!("tuple[{}]", serializer_names.join(", "));
@@ -75,9 +85,16 @@ impl TypeSerializer for TupleSerializer {
                 let mut items = Vec::with_capacity(n_items);
 
                 self.for_each_tuple_item_and_serializer(py_tuple, include, exclude, extra, |entry| {
-                    entry
-                        .serializer
-                        .to_python(&entry.item, entry.include.as_ref(), entry.exclude.as_ref(), extra)
+                    let (include, exclude) = (entry.include.as_ref(), entry.exclude.as_ref());
+                    extra
+                        .item_to_python(
+                            &entry.item,
+                            Some(entry.serializer),
+                            include,
+                            exclude,
+                            &items.len(),
+                            || entry.serializer.to_python(&entry.item, include, exclude, extra),
+                        )
                         .map(|item| items.push(item))
                 })??;
 
@@ -96,13 +113,23 @@ impl TypeSerializer for TupleSerializer {
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match key.downcast::<PyTuple>() {
             Ok(py_tuple) => {
-                let mut key_builder = KeyBuilder::new();
+                let mut key_builder = KeyBuilder::new(extra.config);
 
                 self.for_each_tuple_item_and_serializer(py_tuple, None, None, extra, |entry| {
-                    entry
-                        .serializer
-                        .json_key(&entry.item, extra)
-                        .map(|key| key_builder.push(&key))
+                    if key_builder.is_json() {
+                        key_builder.push_json(&PydanticSerializer::new(
+                            &entry.item,
+                            entry.serializer,
+                            None,
+                            None,
+                            extra,
+                        ))
+                    } else {
+                        entry
+                            .serializer
+                            .json_key(&entry.item, extra)
+                            .map(|key| key_builder.push(&key))
+                    }
                 })??;
 
                 Ok(Cow::Owned(key_builder.finish()))
@@ -127,16 +154,21 @@ impl TypeSerializer for TupleSerializer {
                 let py_tuple = py_tuple.downcast::<PyTuple>().map_err(py_err_se_err)?;
 
                 let n_items = py_tuple.len();
+                extra.maybe_release_gil(value.py());
                 let mut seq = serializer.serialize_seq(Some(n_items))?;
+                let mut position = 0;
 
                 self.for_each_tuple_item_and_serializer(py_tuple, include, exclude, extra, |entry| {
-                    seq.serialize_element(&PydanticSerializer::new(
+                    let item_serialize = PydanticSerializer::new(
                         &entry.item,
                         entry.serializer,
                         entry.include.as_ref(),
                         entry.exclude.as_ref(),
                         extra,
-                    ))
+                    );
+                    let result = extra.with_loc(&position, || seq.serialize_element(&item_serialize));
+                    position += 1;
+                    result
                 })
                 .map_err(py_err_se_err)??;
 
@@ -166,6 +198,15 @@ struct TupleSerializerEntry<'a, 'py> {
 }
 
 impl TupleSerializer {
+    /// The serializer of the item at `index`, items from the variadic item on are serialized by the variadic item's
+    /// serializer since their position relative to any items after it depends on the length of the value
+    pub(crate) fn item_serializer(&self, index: usize) -> Option<&CombinedSerializer> {
+        match self.variadic_item_index {
+            Some(variadic_item_index) if index >= variadic_item_index => self.serializers.get(variadic_item_index),
+            _ => self.serializers.get(index),
+        }
+    }
+
     /// Try to serialize each item in the tuple with the corresponding serializer.
     ///
     /// If the tuple doesn't match the length of the serializer, in strict mode, an error is returned.
@@ -206,6 +247,13 @@ impl TupleSerializer {
         }
 
         if let Some(variadic_item_index) = self.variadic_item_index {
+            // every item other than the variadic one is required
+            let min_items = self.serializers.len() - 1;
+            if extra.check == SerCheck::Strict && n_items < min_items {
+                return Err(PydanticSerializationUnexpectedValue::new_err(Some(format!(
+                    "Expected at least {min_items} items, but got {n_items}"
+                ))));
+            }
             // Need `saturating_sub` to handle items with too few elements without panicking
             let n_variadic_items = (n_items + 1).saturating_sub(self.serializers.len());
             let serializers_iter = self.serializers[..variadic_item_index]
@@ -237,7 +285,7 @@ impl TupleSerializer {
                         item: element,
                         include: next_include,
                         exclude: next_exclude,
-                        serializer: &CombinedSerializer::Any(AnySerializer),
+                        serializer: &CombinedSerializer::Any(AnySerializer::INFER),
                     }) {
                         return Ok(Err(e));
                     };
@@ -248,29 +296,50 @@ impl TupleSerializer {
     }
 }
 
-pub(crate) struct KeyBuilder {
+/// Builds the JSON key for a tuple, either the keys of the items joined by `ser_json_tuple_key_separator`
+/// or with `ser_json_tuple_keys='json'` the items as a JSON array.
+pub(crate) struct KeyBuilder<'a> {
     key: String,
     first: bool,
+    separator: &'a str,
+    json: bool,
 }
 
-impl KeyBuilder {
-    pub fn new() -> Self {
+impl<'a> KeyBuilder<'a> {
+    pub fn new(config: &'a SerializationConfig) -> Self {
+        let json = config.tuple_key_mode == TupleKeyMode::Json;
         Self {
             key: String::with_capacity(31),
             first: true,
+            separator: if json { "," } else { &config.tuple_key_separator },
+            json,
         }
     }
 
+    pub fn is_json(&self) -> bool {
+        self.json
+    }
+
     pub fn push(&mut self, key: &str) {
         if self.first {
             self.first = false;
         } else {
-            self.key.push(',');
+            self.key.push_str(self.separator);
         }
         self.key.push_str(key);
     }
 
+    pub fn push_json(&mut self, item: &impl Serialize) -> PyResult<()> {
+        let item = serde_json::to_string(item).map_err(|e| PydanticSerializationError::new_err(e.to_string()))?;
+        self.push(&item);
+        Ok(())
+    }
+
     pub fn finish(self) -> String {
-        self.key
+        if self.json {
+            format!("[{}]", self.key)
+        } else {
+            self.key
+        }
     }
 }
@@ -5,11 +5,14 @@ use pyo3::types::{PyDict, PyString};
 use ahash::AHashMap;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{py_schema_error_type, schema_or_config, ExtraBehavior};
+use crate::build_tools::{nested_config, py_schema_error_type, schema_or_config, AliasGenerator, ExtraBehavior};
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
 
-use super::{BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode, GeneralFieldsSerializer, SerField};
+use super::{
+    apply_alias_priority, get_serialization_aliases, BuildSerializer, CombinedSerializer, ComputedFields, FieldsMode,
+    GeneralFieldsSerializer, SerAliases, SerField,
+};
 
 #[derive(Debug, Clone)]
 pub struct TypedDictBuilder;
@@ -24,21 +27,28 @@ impl BuildSerializer for TypedDictBuilder {
     ) -> PyResult<CombinedSerializer> {
         let py = schema.py();
 
+        // like validation, typed dicts use the config from this TypedDict, unless it's set to merge with the parent
+        let config = nested_config(schema, config)?;
+        let config = config.as_ref();
+        let alias_generator = AliasGenerator::from_config(config)?;
+
         let total =
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
 
-        let fields_mode = match ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)? {
+        let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
+        let fields_mode = match extra_behavior {
             ExtraBehavior::Allow => FieldsMode::TypedDictAllow,
+            // keys matching `extras_pattern_schemas` are kept by validation whatever the extra behavior
+            _ if schema.contains(intern!(py, "extras_pattern_schemas"))? => FieldsMode::TypedDictAllow,
             _ => FieldsMode::SimpleDict,
         };
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: AHashMap<String, SerField> = AHashMap::with_capacity(fields_dict.len());
+        let mut field_names: Vec<String> = Vec::with_capacity(fields.capacity());
 
-        let extra_serializer = match (schema.get_item(intern!(py, "extras_schema"))?, &fields_mode) {
-            (Some(v), FieldsMode::TypedDictAllow) => {
-                Some(CombinedSerializer::build(&v.extract()?, config, definitions)?)
-            }
+        let extra_serializer = match (schema.get_item(intern!(py, "extras_schema"))?, &extra_behavior) {
+            (Some(v), ExtraBehavior::Allow) => Some(CombinedSerializer::build(&v.extract()?, config, definitions)?),
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
@@ -46,23 +56,26 @@ impl BuildSerializer for TypedDictBuilder {
         for (key, value) in fields_dict {
             let key_py = key.downcast_into::<PyString>()?;
             let key: String = key_py.extract()?;
+            field_names.push(key.clone());
             let field_info = value.downcast()?;
 
             let key_py: Py<PyString> = key_py.into();
             let required = field_info.get_as(intern!(py, "required"))?.unwrap_or(total);
 
             if field_info.get_as(intern!(py, "serialization_exclude"))? == Some(true) {
-                fields.insert(key, SerField::new(py, key_py, None, None, required));
+                fields.insert(key, SerField::new(py, key_py, SerAliases::default(), None, required));
             } else {
-                let alias: Option<String> = field_info.get_as(intern!(py, "serialization_alias"))?;
+                let aliases = get_serialization_aliases(field_info, &key, alias_generator)?;
 
                 let schema = field_info.get_as_req(intern!(py, "schema"))?;
                 let serializer = CombinedSerializer::build(&schema, config, definitions)
                     .map_err(|e| py_schema_error_type!("Field `{}`:\n  {}", key, e))?;
-                fields.insert(key, SerField::new(py, key_py, alias, Some(serializer), required));
+                fields.insert(key, SerField::new(py, key_py, aliases, Some(serializer), required));
             }
         }
 
+        apply_alias_priority(schema, &mut fields, &field_names)?;
+
         let computed_fields = ComputedFields::new(schema, config, definitions)?;
 
         Ok(GeneralFieldsSerializer::new(fields, fields_mode, extra_serializer, computed_fields).into())
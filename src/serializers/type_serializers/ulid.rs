@@ -0,0 +1,100 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::validators::ulid_to_string;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    IsType, ObType, TypeSerializer,
+};
+
+/// ULIDs are validated to a string, or to a `uuid.UUID` with `as_uuid`, which is written as a ULID string in JSON
+#[derive(Debug, Clone)]
+pub struct UlidSerializer;
+
+impl_py_gc_traverse!(UlidSerializer {});
+
+impl BuildSerializer for UlidSerializer {
+    const EXPECTED_TYPE: &'static str = "ulid";
+
+    fn build(
+        _schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+enum UlidValue<'a, 'py> {
+    Str(&'a Bound<'py, PyString>),
+    Uuid(u128),
+}
+
+fn ulid_value<'a, 'py>(value: &'a Bound<'py, PyAny>, extra: &Extra) -> PyResult<Option<UlidValue<'a, 'py>>> {
+    if let Ok(py_str) = value.downcast::<PyString>() {
+        Ok(Some(UlidValue::Str(py_str)))
+    } else if let IsType::Exact | IsType::Subclass = extra.ob_type_lookup.is_type(value, ObType::Uuid) {
+        let int: u128 = value.getattr(intern!(value.py(), "int"))?.extract()?;
+        Ok(Some(UlidValue::Uuid(int)))
+    } else {
+        Ok(None)
+    }
+}
+
+impl TypeSerializer for UlidSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        match ulid_value(value, extra)? {
+            Some(UlidValue::Uuid(int)) if extra.mode.is_json() => Ok(ulid_to_string(int).into_py(py)),
+            Some(_) => Ok(value.into_py(py)),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                infer_to_python(value, include, exclude, extra)
+            }
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        match ulid_value(key, extra)? {
+            Some(UlidValue::Str(py_str)) => Ok(Cow::Owned(py_str.to_str()?.to_owned())),
+            Some(UlidValue::Uuid(int)) => Ok(Cow::Owned(ulid_to_string(int))),
+            None => {
+                extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                infer_json_key(key, extra)
+            }
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        match ulid_value(value, extra).map_err(py_err_se_err)? {
+            Some(UlidValue::Str(py_str)) => serializer.serialize_str(py_str.to_str().map_err(py_err_se_err)?),
+            Some(UlidValue::Uuid(int)) => serializer.serialize_str(&ulid_to_string(int)),
+            None => {
+                extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                infer_serialize(value, serializer, include, exclude, extra)
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -7,6 +7,7 @@ use crate::definitions::DefinitionsBuilder;
 
 use crate::url::{PyMultiHostUrl, PyUrl};
 
+use super::string::StrSerializer;
 use super::{
     infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
     TypeSerializer,
@@ -89,3 +90,47 @@ macro_rules! build_serializer {
 }
 build_serializer!(UrlSerializer, "url", PyUrl);
 build_serializer!(MultiHostUrlSerializer, "multi-host-url", PyMultiHostUrl);
+
+/// URI references are validated to strings, so are serialized as strings
+pub struct UriReferenceBuilder;
+
+impl BuildSerializer for UriReferenceBuilder {
+    const EXPECTED_TYPE: &'static str = "uri-reference";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        StrSerializer::build(schema, config, definitions)
+    }
+}
+
+/// Hostnames and domain names are validated to strings, so are serialized as strings
+pub struct HostnameBuilder;
+
+impl BuildSerializer for HostnameBuilder {
+    const EXPECTED_TYPE: &'static str = "hostname";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        StrSerializer::build(schema, config, definitions)
+    }
+}
+
+pub struct DomainBuilder;
+
+impl BuildSerializer for DomainBuilder {
+    const EXPECTED_TYPE: &'static str = "domain";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        StrSerializer::build(schema, config, definitions)
+    }
+}
@@ -1,21 +1,66 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyBytes, PyDict, PyString};
+use uuid::Uuid;
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
+use crate::tools::SchemaDict;
 
 use super::{
     infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
-    IsType, ObType, SerMode, TypeSerializer,
+    IsType, ObType, ObTypeLookup, SerMode, TypeSerializer,
 };
 
 pub(crate) fn uuid_to_string(py_uuid: &Bound<'_, PyAny>) -> PyResult<String> {
-    Ok(py_uuid.str()?.to_string())
+    let py = py_uuid.py();
+    // `UUID.__str__` formats `UUID.int` in python, doing that here is much faster,
+    // subclasses may override `__str__` so they still go via python
+    if let IsType::Exact = ObTypeLookup::cached(py).is_type(py_uuid, ObType::Uuid) {
+        let int: u128 = py_uuid.getattr(intern!(py, "int"))?.extract()?;
+        Ok(Uuid::from_u128(int).hyphenated().to_string())
+    } else {
+        Ok(py_uuid.str()?.to_string())
+    }
+}
+
+/// How UUIDs are written when `output_format` is set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UuidFormat {
+    Canonical,
+    Hex,
+    Urn,
+    Braced,
+    Bytes,
+}
+
+impl UuidFormat {
+    fn format(self, py_uuid: &Bound<'_, PyAny>) -> PyResult<String> {
+        if self == Self::Canonical {
+            return uuid_to_string(py_uuid);
+        }
+        let uuid = uuid_from_int(py_uuid)?;
+        Ok(match self {
+            Self::Hex => uuid.simple().to_string(),
+            Self::Urn => uuid.urn().to_string(),
+            Self::Braced => uuid.braced().to_string(),
+            Self::Canonical | Self::Bytes => uuid.hyphenated().to_string(),
+        })
+    }
+}
+
+fn uuid_from_int(py_uuid: &Bound<'_, PyAny>) -> PyResult<Uuid> {
+    let int: u128 = py_uuid.getattr(intern!(py_uuid.py(), "int"))?.extract()?;
+    Ok(Uuid::from_u128(int))
 }
 
 #[derive(Debug, Clone)]
-pub struct UuidSerializer;
+pub struct UuidSerializer {
+    // when set, UUIDs are serialized in this format in python mode too, rather than left as they are
+    output_format: Option<UuidFormat>,
+}
 
 impl_py_gc_traverse!(UuidSerializer {});
 
@@ -23,11 +68,33 @@ impl BuildSerializer for UuidSerializer {
     const EXPECTED_TYPE: &'static str = "uuid";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        let py = schema.py();
+        let output_format: Option<Bound<'_, PyString>> = schema.get_as(intern!(py, "output_format"))?;
+        let output_format = match output_format.as_ref().map(|s| s.to_str()).transpose()? {
+            None => None,
+            Some("canonical") => Some(UuidFormat::Canonical),
+            Some("hex") => Some(UuidFormat::Hex),
+            Some("urn") => Some(UuidFormat::Urn),
+            Some("braced") => Some(UuidFormat::Braced),
+            Some("bytes") => Some(UuidFormat::Bytes),
+            Some(s) => {
+                return py_schema_err!(
+                    "Invalid `output_format` value: {}, expected 'canonical', 'hex', 'urn', 'braced' or 'bytes'",
+                    s
+                )
+            }
+        };
+        Ok(Self { output_format }.into())
+    }
+}
+
+impl UuidSerializer {
+    fn format(&self, py_uuid: &Bound<'_, PyAny>) -> PyResult<String> {
+        self.output_format.unwrap_or(UuidFormat::Canonical).format(py_uuid)
     }
 }
 
@@ -41,9 +108,20 @@ impl TypeSerializer for UuidSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Uuid) {
-            IsType::Exact | IsType::Subclass => match extra.mode {
-                SerMode::Json => Ok(uuid_to_string(value)?.into_py(py)),
-                _ => Ok(value.into_py(py)),
+            IsType::Exact | IsType::Subclass => match (self.output_format, extra.mode) {
+                (Some(UuidFormat::Bytes), SerMode::Json) => {
+                    let uuid = uuid_from_int(value)?;
+                    Ok(extra
+                        .config
+                        .bytes_mode
+                        .bytes_to_string(py, uuid.as_bytes())?
+                        .into_py(py))
+                }
+                (Some(UuidFormat::Bytes), _) => {
+                    Ok(PyBytes::new_bound(py, uuid_from_int(value)?.as_bytes()).into_py(py))
+                }
+                (Some(_), _) | (None, SerMode::Json) => Ok(self.format(value)?.into_py(py)),
+                (None, _) => Ok(value.into_py(py)),
             },
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
@@ -54,8 +132,13 @@ impl TypeSerializer for UuidSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::Uuid) {
+            IsType::Exact | IsType::Subclass if self.output_format == Some(UuidFormat::Bytes) => {
+                let uuid = uuid_from_int(key)?;
+                let str = extra.config.bytes_mode.bytes_to_string(key.py(), uuid.as_bytes())?;
+                Ok(Cow::Owned(str.into_owned()))
+            }
             IsType::Exact | IsType::Subclass => {
-                let str = uuid_to_string(key)?;
+                let str = self.format(key)?;
                 Ok(Cow::Owned(str))
             }
             IsType::False => {
@@ -74,8 +157,12 @@ impl TypeSerializer for UuidSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::Uuid) {
+            IsType::Exact | IsType::Subclass if self.output_format == Some(UuidFormat::Bytes) => {
+                let uuid = uuid_from_int(value).map_err(py_err_se_err)?;
+                extra.config.bytes_mode.serialize_bytes(uuid.as_bytes(), serializer)
+            }
             IsType::Exact | IsType::Subclass => {
-                let s = uuid_to_string(value).map_err(py_err_se_err)?;
+                let s = self.format(value).map_err(py_err_se_err)?;
                 serializer.serialize_str(&s)
             }
             IsType::False => {
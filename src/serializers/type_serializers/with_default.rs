@@ -6,7 +6,7 @@ use pyo3::types::PyDict;
 
 use crate::definitions::DefinitionsBuilder;
 use crate::tools::SchemaDict;
-use crate::validators::DefaultType;
+use crate::validators::{DefaultType, Rng};
 
 use super::{BuildSerializer, CombinedSerializer, Extra, TypeSerializer};
 
@@ -34,6 +34,13 @@ impl BuildSerializer for WithDefaultSerializer {
     }
 }
 
+impl WithDefaultSerializer {
+    /// The serializer this one wraps
+    pub(crate) fn inner_serializer(&self) -> &CombinedSerializer {
+        &self.serializer
+    }
+}
+
 impl_py_gc_traverse!(WithDefaultSerializer { default, serializer });
 
 impl TypeSerializer for WithDefaultSerializer {
@@ -72,6 +79,7 @@ impl TypeSerializer for WithDefaultSerializer {
     }
 
     fn get_default(&self, py: Python) -> PyResult<Option<PyObject>> {
-        self.default.default_value(py)
+        // there's no validation to get a generator from for `exclude_defaults`, so it's unseeded
+        self.default.default_value(py, &mut Rng::default())
     }
 }
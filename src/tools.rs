@@ -146,3 +146,9 @@ pub(crate) fn new_py_string<'py>(py: Python<'py>, s: &str, cache_str: StringCach
         pystring_fast_new(py, s, ascii_only)
     }
 }
+
+/// Key holding the type name in the `{"__type__": ..., "value": ...}` markers used by `any` schemas with
+/// `type_markers` enabled
+pub const TYPE_MARKER_KEY: &str = "__type__";
+/// Key holding the JSON representation of the value in type markers
+pub const TYPE_MARKER_VALUE_KEY: &str = "value";
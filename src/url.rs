@@ -1,13 +1,16 @@
+use std::borrow::Cow;
 use std::collections::hash_map::DefaultHasher;
 use std::fmt;
 use std::fmt::Formatter;
 use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
 
+use ahash::AHashMap;
 use idna::punycode::decode_to_string;
 use pyo3::exceptions::PyValueError;
 use pyo3::pyclass::CompareOp;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyType};
+use pyo3::types::{PyDict, PyString, PyTuple, PyType};
 use pyo3::{intern, prelude::*};
 use url::Url;
 
@@ -16,6 +19,80 @@ use crate::SchemaValidator;
 
 static SCHEMA_DEFINITION_URL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
+const URL_CACHE_SIZE: usize = 1024;
+
+// URLs parsed by `Url(...)` and `MultiHostUrl(...)`, so URLs which are constructed repeatedly from the same string,
+// e.g. when unpickled in each worker process, are only validated once
+static URL_CACHE: GILOnceCell<Mutex<UrlCache<PyUrl>>> = GILOnceCell::new();
+static MULTI_HOST_URL_CACHE: GILOnceCell<Mutex<UrlCache<PyMultiHostUrl>>> = GILOnceCell::new();
+
+/// Parsed URLs keyed by the string they were parsed from, the least recently used URL is evicted when full
+struct UrlCache<T> {
+    entries: AHashMap<String, (T, u64)>,
+    uses: u64,
+}
+
+impl<T> Default for UrlCache<T> {
+    fn default() -> Self {
+        Self {
+            entries: AHashMap::new(),
+            uses: 0,
+        }
+    }
+}
+
+impl<T: Clone> UrlCache<T> {
+    /// Get `url` parsed from the cache, calling `parse` and caching the result if it's missing, only `str` URLs
+    /// are cached
+    fn get_or_parse(
+        cache: &'static GILOnceCell<Mutex<Self>>,
+        url: &Bound<'_, PyAny>,
+        parse: impl FnOnce() -> PyResult<T>,
+    ) -> PyResult<T> {
+        let Ok(url) = url.downcast_exact::<PyString>() else {
+            return parse();
+        };
+        let cache = cache.get_or_init(url.py(), Mutex::default);
+        let key = url.to_str()?;
+        {
+            let mut cache = cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            cache.uses += 1;
+            let uses = cache.uses;
+            if let Some((parsed, last_used)) = cache.entries.get_mut(key) {
+                *last_used = uses;
+                return Ok(parsed.clone());
+            }
+        }
+        // the lock isn't held while parsing since validation may call python code
+        let parsed = parse()?;
+        let mut cache = cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if cache.entries.len() >= URL_CACHE_SIZE {
+            let least_recent = cache
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recent) = least_recent {
+                cache.entries.remove(&least_recent);
+            }
+        }
+        let uses = cache.uses;
+        cache.entries.insert(key.to_string(), (parsed.clone(), uses));
+        Ok(parsed)
+    }
+}
+
+/// `(type(slf), (str(slf),))`, so URLs, including subclasses, are pickled as the string they're constructed from
+fn reduce_url<'py>(slf: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyTuple>> {
+    Ok(PyTuple::new_bound(
+        slf.py(),
+        [
+            slf.get_type().into_any(),
+            PyTuple::new_bound(slf.py(), [slf.str()?]).into_any(),
+        ],
+    ))
+}
+
 #[pyclass(name = "Url", module = "pydantic_core._pydantic_core", subclass, frozen)]
 #[derive(Clone, Hash)]
 #[cfg_attr(debug_assertions, derive(Debug))]
@@ -31,6 +108,10 @@ impl PyUrl {
     pub fn url(&self) -> &Url {
         &self.lib_url
     }
+
+    pub(crate) fn normalized(&self) -> Self {
+        Self::new(normalize_url(&self.lib_url))
+    }
 }
 
 fn build_schema_validator(py: Python, schema_type: &str) -> SchemaValidator {
@@ -43,10 +124,12 @@ fn build_schema_validator(py: Python, schema_type: &str) -> SchemaValidator {
 impl PyUrl {
     #[new]
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
-        let schema_obj = SCHEMA_DEFINITION_URL
-            .get_or_init(py, || build_schema_validator(py, "url"))
-            .validate_python(py, url, None, None, None, None)?;
-        schema_obj.extract(py)
+        UrlCache::get_or_parse(&URL_CACHE, url, || {
+            let schema_obj = SCHEMA_DEFINITION_URL
+                .get_or_init(py, || build_schema_validator(py, "url"))
+                .validate_python(py, url, None, None, None, None, None, None, false, None, None)?;
+            schema_obj.extract(py)
+        })
     }
 
     #[getter]
@@ -125,20 +208,31 @@ impl PyUrl {
         format!("Url('{}')", self.lib_url)
     }
 
+    pub fn normalize<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        slf.get_type().call1((slf.get().normalized().__str__(),))
+    }
+
+    pub fn join<'py>(slf: &Bound<'py, Self>, relative: &str) -> PyResult<Bound<'py, PyAny>> {
+        let joined = join_url(&slf.get().lib_url, relative)?;
+        slf.get_type().call1((joined.as_str(),))
+    }
+
+    // comparisons and hashing use the normalized URL, so equivalent URLs are equal
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        let (self_url, other_url) = (normalize_url(&self.lib_url), normalize_url(&other.lib_url));
         match op {
-            CompareOp::Lt => Ok(self.lib_url < other.lib_url),
-            CompareOp::Le => Ok(self.lib_url <= other.lib_url),
-            CompareOp::Eq => Ok(self.lib_url == other.lib_url),
-            CompareOp::Ne => Ok(self.lib_url != other.lib_url),
-            CompareOp::Gt => Ok(self.lib_url > other.lib_url),
-            CompareOp::Ge => Ok(self.lib_url >= other.lib_url),
+            CompareOp::Lt => Ok(self_url < other_url),
+            CompareOp::Le => Ok(self_url <= other_url),
+            CompareOp::Eq => Ok(self_url == other_url),
+            CompareOp::Ne => Ok(self_url != other_url),
+            CompareOp::Gt => Ok(self_url > other_url),
+            CompareOp::Ge => Ok(self_url >= other_url),
         }
     }
 
     fn __hash__(&self) -> u64 {
         let mut s = DefaultHasher::new();
-        self.hash(&mut s);
+        self.normalized().hash(&mut s);
         s.finish()
     }
 
@@ -146,13 +240,18 @@ impl PyUrl {
         true // an empty string is not a valid URL
     }
 
+    // URLs are immutable, so copies are the URL itself
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
     #[pyo3(signature = (_memo, /))]
-    pub fn __deepcopy__(&self, py: Python, _memo: Bound<'_, PyAny>) -> Py<PyAny> {
-        self.clone().into_py(py)
+    pub fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: &Bound<'_, PyAny>) -> Bound<'py, Self> {
+        slf
     }
 
-    fn __getnewargs__(&self) -> (&str,) {
-        (self.__str__(),)
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyTuple>> {
+        reduce_url(slf.as_any())
     }
 
     #[classmethod]
@@ -215,6 +314,21 @@ impl PyMultiHostUrl {
     pub fn mut_lib_url(&mut self) -> &mut Url {
         &mut self.ref_url.lib_url
     }
+
+    /// The URL of each host, the first is the URL returned by `lib_url`
+    pub fn lib_urls(&self) -> impl Iterator<Item = &Url> {
+        std::iter::once(&self.ref_url.lib_url).chain(self.extra_urls.iter().flatten())
+    }
+
+    pub(crate) fn normalized(&self) -> Self {
+        Self {
+            ref_url: self.ref_url.normalized(),
+            extra_urls: self
+                .extra_urls
+                .as_ref()
+                .map(|extra_urls| extra_urls.iter().map(normalize_url).collect()),
+        }
+    }
 }
 
 static SCHEMA_DEFINITION_MULTI_HOST_URL: GILOnceCell<SchemaValidator> = GILOnceCell::new();
@@ -223,10 +337,12 @@ static SCHEMA_DEFINITION_MULTI_HOST_URL: GILOnceCell<SchemaValidator> = GILOnceC
 impl PyMultiHostUrl {
     #[new]
     pub fn py_new(py: Python, url: &Bound<'_, PyAny>) -> PyResult<Self> {
-        let schema_obj = SCHEMA_DEFINITION_MULTI_HOST_URL
-            .get_or_init(py, || build_schema_validator(py, "multi-host-url"))
-            .validate_python(py, url, None, None, None, None)?;
-        schema_obj.extract(py)
+        UrlCache::get_or_parse(&MULTI_HOST_URL_CACHE, url, || {
+            let schema_obj = SCHEMA_DEFINITION_MULTI_HOST_URL
+                .get_or_init(py, || build_schema_validator(py, "multi-host-url"))
+                .validate_python(py, url, None, None, None, None, None, None, false, None, None)?;
+            schema_obj.extract(py)
+        })
     }
 
     #[getter]
@@ -329,20 +445,38 @@ impl PyMultiHostUrl {
         format!("MultiHostUrl('{}')", self.__str__())
     }
 
+    pub fn normalize<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyAny>> {
+        slf.get_type().call1((slf.get().normalized().__str__(),))
+    }
+
+    pub fn join<'py>(slf: &Bound<'py, Self>, relative: &str) -> PyResult<Bound<'py, PyAny>> {
+        let multi_host_url = slf.get();
+        let joined = join_url(multi_host_url.lib_url(), relative)?;
+        // the other hosts are only kept if `relative` doesn't replace the authority
+        let extra_urls = if same_authority(&joined, multi_host_url.lib_url()) {
+            multi_host_url.extra_urls.clone()
+        } else {
+            None
+        };
+        slf.get_type().call1((Self::new(joined, extra_urls).__str__(),))
+    }
+
+    // comparisons and hashing use the normalized URL, so equivalent URLs are equal
     fn __richcmp__(&self, other: &Self, op: CompareOp) -> PyResult<bool> {
+        let (self_str, other_str) = (self.normalized().unicode_string(), other.normalized().unicode_string());
         match op {
-            CompareOp::Lt => Ok(self.unicode_string() < other.unicode_string()),
-            CompareOp::Le => Ok(self.unicode_string() <= other.unicode_string()),
-            CompareOp::Eq => Ok(self.unicode_string() == other.unicode_string()),
-            CompareOp::Ne => Ok(self.unicode_string() != other.unicode_string()),
-            CompareOp::Gt => Ok(self.unicode_string() > other.unicode_string()),
-            CompareOp::Ge => Ok(self.unicode_string() >= other.unicode_string()),
+            CompareOp::Lt => Ok(self_str < other_str),
+            CompareOp::Le => Ok(self_str <= other_str),
+            CompareOp::Eq => Ok(self_str == other_str),
+            CompareOp::Ne => Ok(self_str != other_str),
+            CompareOp::Gt => Ok(self_str > other_str),
+            CompareOp::Ge => Ok(self_str >= other_str),
         }
     }
 
     fn __hash__(&self) -> u64 {
         let mut s = DefaultHasher::new();
-        self.hash(&mut s);
+        self.normalized().hash(&mut s);
         s.finish()
     }
 
@@ -350,12 +484,18 @@ impl PyMultiHostUrl {
         true // an empty string is not a valid URL
     }
 
-    pub fn __deepcopy__(&self, py: Python, _memo: &Bound<'_, PyDict>) -> Py<PyAny> {
-        self.clone().into_py(py)
+    // URLs are immutable, so copies are the URL itself
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
     }
 
-    fn __getnewargs__(&self) -> (String,) {
-        (self.__str__(),)
+    #[pyo3(signature = (_memo, /))]
+    pub fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: &Bound<'_, PyAny>) -> Bound<'py, Self> {
+        slf
+    }
+
+    fn __reduce__<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyTuple>> {
+        reduce_url(slf.as_any())
     }
 
     #[classmethod]
@@ -499,6 +639,81 @@ fn unicode_url(lib_url: &Url) -> String {
     }
 }
 
+/// The canonical form of a URL: the host lowercased, and percent-encoding normalized as per RFC 3986 section 6.2.2.2,
+/// default ports of special schemes are already removed when parsing so need no handling here
+pub(crate) fn normalize_url(lib_url: &Url) -> Url {
+    let mut url = lib_url.clone();
+    // hosts of special schemes are lowercased when parsed, but opaque hosts are kept as written
+    if let Some(url::Host::Domain(domain)) = lib_url.host() {
+        if domain.bytes().any(|b| b.is_ascii_uppercase()) {
+            url.set_host(Some(&domain.to_ascii_lowercase())).ok();
+        }
+    }
+    if let Cow::Owned(username) = normalize_percent_encoding(lib_url.username()) {
+        url.set_username(&username).ok();
+    }
+    if let Some(Cow::Owned(password)) = lib_url.password().map(normalize_percent_encoding) {
+        url.set_password(Some(&password)).ok();
+    }
+    if let Cow::Owned(path) = normalize_percent_encoding(lib_url.path()) {
+        url.set_path(&path);
+    }
+    if let Some(Cow::Owned(query)) = lib_url.query().map(normalize_percent_encoding) {
+        url.set_query(Some(&query));
+    }
+    if let Some(Cow::Owned(fragment)) = lib_url.fragment().map(normalize_percent_encoding) {
+        url.set_fragment(Some(&fragment));
+    }
+    url
+}
+
+/// Uppercase the hex digits of percent-encoded bytes, and decode those which are unreserved characters
+pub(crate) fn normalize_percent_encoding(s: &str) -> Cow<'_, str> {
+    if !s.contains('%') {
+        return Cow::Borrowed(s);
+    }
+    let hex_value = |digit: u8| char::from(digit).to_digit(16).unwrap_or_default() as u8;
+    let bytes = s.as_bytes();
+    let mut normalized = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        match bytes.get(index..index + 3) {
+            Some(&[b'%', high, low]) if high.is_ascii_hexdigit() && low.is_ascii_hexdigit() => {
+                let value = (hex_value(high) << 4) | hex_value(low);
+                if value.is_ascii_alphanumeric() || matches!(value, b'-' | b'.' | b'_' | b'~') {
+                    normalized.push(value);
+                } else {
+                    normalized.extend_from_slice(&[b'%', high.to_ascii_uppercase(), low.to_ascii_uppercase()]);
+                }
+                index += 3;
+            }
+            _ => {
+                normalized.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    // only ASCII escapes have been replaced, so the string is still valid UTF-8
+    match String::from_utf8(normalized) {
+        Ok(normalized) => Cow::Owned(normalized),
+        Err(_) => Cow::Borrowed(s),
+    }
+}
+
+fn join_url(lib_url: &Url, relative: &str) -> PyResult<Url> {
+    lib_url
+        .join(relative)
+        .map_err(|err| PyValueError::new_err(format!("Invalid relative URL `{relative}`: {err}")))
+}
+
+fn same_authority(a: &Url, b: &Url) -> bool {
+    a.scheme() == b.scheme()
+        && a.username() == b.username()
+        && a.password() == b.password()
+        && a.host() == b.host()
+        && a.port() == b.port()
+}
+
 fn decode_punycode(domain: &str) -> Option<String> {
     let mut result = String::with_capacity(domain.len());
     for chunk in domain.split('.') {
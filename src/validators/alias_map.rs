@@ -0,0 +1,54 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::lookup_key::LookupKey;
+
+use super::CombinedValidator;
+
+/// The paths each field of the model, dataclass or typed dict at the root of `validator` is looked up by, keyed
+/// by field name, or with `reverse` the field name keyed by each path, as a key when the path is a single key
+/// and as a tuple otherwise, a path shared by several fields is kept for the first
+pub(super) fn alias_map<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+    reverse: bool,
+) -> PyResult<Bound<'py, PyDict>> {
+    let map = PyDict::new_bound(py);
+    visit_fields(validator, &mut |field_name, lookup_key| {
+        let paths = lookup_key.paths().into_iter().map(|path| path.to_py_list(py));
+        if reverse {
+            for path in paths {
+                let key = match path.len() {
+                    1 => path.get_item(0)?,
+                    _ => path.to_tuple().into_any(),
+                };
+                if !map.contains(&key)? {
+                    map.set_item(key, field_name)?;
+                }
+            }
+            Ok(())
+        } else {
+            map.set_item(field_name, PyList::new_bound(py, paths))
+        }
+    })?;
+    Ok(map)
+}
+
+/// Call `f` with the name and lookup key of each field, looking through models, dataclasses, function validators
+/// and definition references to the fields validator
+fn visit_fields(validator: &CombinedValidator, f: &mut impl FnMut(&str, &LookupKey) -> PyResult<()>) -> PyResult<()> {
+    match validator {
+        CombinedValidator::ModelFields(v) => v.lookup_keys().try_for_each(|(name, key)| f(name, key)),
+        CombinedValidator::TypedDict(v) => v.lookup_keys().try_for_each(|(name, key)| f(name, key)),
+        CombinedValidator::DataclassArgs(v) => v.lookup_keys().try_for_each(|(name, key)| f(name, key)),
+        CombinedValidator::Model(v) => visit_fields(v.inner_validator(), f),
+        CombinedValidator::Dataclass(v) => visit_fields(v.inner_validator(), f),
+        CombinedValidator::FunctionBefore(v) => visit_fields(v.inner_validator(), f),
+        CombinedValidator::FunctionAfter(v) => visit_fields(v.inner_validator(), f),
+        CombinedValidator::FunctionWrap(v) => visit_fields(v.inner_validator(), f),
+        CombinedValidator::DefinitionRef(v) => v
+            .definition()
+            .read(|definition| definition.map_or(Ok(()), |definition| visit_fields(definition, f))),
+        _ => Ok(()),
+    }
+}
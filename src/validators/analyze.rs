@@ -0,0 +1,169 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple};
+use regex_syntax::ast::{parse::Parser, Ast, RepetitionKind, RepetitionRange};
+
+use crate::tools::SchemaDict;
+
+/// Find patterns in `schema` which are slow to build or validate, each hint is a dict of its `kind`,
+/// the `path` of keys and indexes to the schema it applies to and a `message`
+pub(super) fn analyze_schema<'py>(
+    schema: &Bound<'py, PyAny>,
+    config: Option<&Bound<'py, PyDict>>,
+    max_union_choices: usize,
+) -> PyResult<Bound<'py, PyList>> {
+    let py = schema.py();
+    let mut analyzer = Analyzer {
+        max_union_choices,
+        path: Vec::new(),
+        hints: PyList::empty_bound(py),
+    };
+    let python_re = match config {
+        Some(config) => is_python_re(config)?.unwrap_or(false),
+        None => false,
+    };
+    analyzer.visit(schema, python_re)?;
+    Ok(analyzer.hints)
+}
+
+struct Analyzer<'py> {
+    max_union_choices: usize,
+    path: Vec<PyObject>,
+    hints: Bound<'py, PyList>,
+}
+
+impl<'py> Analyzer<'py> {
+    /// Check `value` if it's a schema and visit its children, `python_re` is whether the config in scope
+    /// uses the `python-re` regex engine
+    fn visit(&mut self, value: &Bound<'py, PyAny>, mut python_re: bool) -> PyResult<()> {
+        let py = value.py();
+        if let Ok(dict) = value.downcast::<PyDict>() {
+            // models, dataclasses and typed dicts can set a config for their fields
+            if let Some(config) = dict.get_as::<Bound<'_, PyDict>>(intern!(py, "config"))? {
+                python_re = is_python_re(&config)?.unwrap_or(python_re);
+            }
+            if let Some(schema_type) = dict.get_as::<Bound<'_, PyString>>(intern!(py, "type"))? {
+                self.check_schema(dict, schema_type.to_str()?, python_re)?;
+            }
+            for (key, item) in dict.iter() {
+                // metadata is arbitrary, so could contain things which look like schemas
+                if key.eq(intern!(py, "metadata"))? {
+                    continue;
+                }
+                self.path.push(key.unbind());
+                self.visit(&item, python_re)?;
+                self.path.pop();
+            }
+        } else if let Ok(list) = value.downcast::<PyList>() {
+            for (index, item) in list.iter().enumerate() {
+                self.path.push(index.into_py(py));
+                self.visit(&item, python_re)?;
+                self.path.pop();
+            }
+        } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+            // union choices can be `(schema, label)` tuples
+            for (index, item) in tuple.iter().enumerate() {
+                self.path.push(index.into_py(py));
+                self.visit(&item, python_re)?;
+                self.path.pop();
+            }
+        }
+        Ok(())
+    }
+
+    fn check_schema(&self, schema: &Bound<'py, PyDict>, schema_type: &str, python_re: bool) -> PyResult<()> {
+        let py = schema.py();
+        match schema_type {
+            "union" => {
+                let choices: Bound<'_, PyList> = schema.get_as_req(intern!(py, "choices"))?;
+                if choices.len() > self.max_union_choices {
+                    self.add_hint(
+                        "large_union",
+                        format!(
+                            "Union with {} choices and no discriminator, each choice may be tried in turn, \
+                             consider a tagged union",
+                            choices.len()
+                        ),
+                    )?;
+                }
+            }
+            "nullable" => {
+                let inner: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "schema"))?;
+                let inner_type: Option<String> = inner.get_as(intern!(py, "type"))?;
+                if let Some(inner_type @ ("nullable" | "none")) = inner_type.as_deref() {
+                    self.add_hint(
+                        "redundant_nullable",
+                        format!("Nullable schema wraps a `{inner_type}` schema, which already allows `None`"),
+                    )?;
+                }
+            }
+            "str" => {
+                let Some(pattern) = schema.get_item(intern!(py, "pattern"))? else {
+                    return Ok(());
+                };
+                let python_re = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "regex_engine"))? {
+                    Some(engine) => engine.to_str()? == "python-re",
+                    None => python_re,
+                };
+                // compiled patterns always use `re`, otherwise the rust regex engine runs in linear time
+                let pattern: String = match pattern.downcast::<PyString>() {
+                    Ok(s) if python_re => s.to_str()?.to_owned(),
+                    Ok(_) => return Ok(()),
+                    Err(_) => pattern.getattr(intern!(py, "pattern"))?.extract()?,
+                };
+                if has_nested_quantifier(&pattern) {
+                    self.add_hint(
+                        "backtracking_pattern",
+                        format!(
+                            "Pattern `{pattern}` has a repeated group containing a repetition, which can take \
+                             exponential time with the `python-re` engine, consider the `rust-regex` engine"
+                        ),
+                    )?;
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    fn add_hint(&self, kind: &str, message: String) -> PyResult<()> {
+        let py = self.hints.py();
+        let hint = PyDict::new_bound(py);
+        hint.set_item(intern!(py, "kind"), kind)?;
+        hint.set_item(intern!(py, "path"), PyTuple::new_bound(py, &self.path))?;
+        hint.set_item(intern!(py, "message"), message)?;
+        self.hints.append(hint)
+    }
+}
+
+fn is_python_re(config: &Bound<'_, PyDict>) -> PyResult<Option<bool>> {
+    let engine: Option<Bound<'_, PyString>> = config.get_as(intern!(config.py(), "regex_engine"))?;
+    engine.map(|e| Ok(e.to_str()? == "python-re")).transpose()
+}
+
+/// Whether `pattern` has an unbounded repetition of an expression which itself contains an unbounded repetition,
+/// e.g. `(a+)+`, the usual cause of catastrophic backtracking, patterns `regex_syntax` can't parse are ignored
+fn has_nested_quantifier(pattern: &str) -> bool {
+    match Parser::new().parse(pattern) {
+        Ok(ast) => find_nested_quantifier(&ast, false),
+        Err(_) => false,
+    }
+}
+
+fn find_nested_quantifier(ast: &Ast, in_repetition: bool) -> bool {
+    match ast {
+        Ast::Repetition(rep) => {
+            let unbounded = matches!(
+                rep.op.kind,
+                RepetitionKind::ZeroOrMore
+                    | RepetitionKind::OneOrMore
+                    | RepetitionKind::Range(RepetitionRange::AtLeast(_))
+            );
+            (unbounded && in_repetition) || find_nested_quantifier(&rep.ast, in_repetition || unbounded)
+        }
+        Ast::Group(group) => find_nested_quantifier(&group.ast, in_repetition),
+        Ast::Concat(concat) => concat.asts.iter().any(|a| find_nested_quantifier(a, in_repetition)),
+        Ast::Alternation(alt) => alt.asts.iter().any(|a| find_nested_quantifier(a, in_repetition)),
+        _ => false,
+    }
+}
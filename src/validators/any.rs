@@ -1,30 +1,67 @@
+use base64::Engine;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyFrozenSet, PyList, PySet, PyString, PyTuple};
+use pyo3::{PyTraverseError, PyVisit};
 
-use crate::errors::ValResult;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
+use crate::py_gc::PyGcTraverse;
+use crate::recursion_guard::RecursionGuard;
+use crate::tools::{SchemaDict, TYPE_MARKER_KEY, TYPE_MARKER_VALUE_KEY};
 
 use super::{
-    validation_state::Exactness, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator,
+    build_validator, validation_state::Exactness, BuildValidator, CombinedValidator, DefinitionsBuilder,
+    ValidationState, Validator,
 };
 
 /// This might seem useless, but it's useful in DictValidator to avoid Option<Validator> a lot
-#[derive(Debug, Clone)]
-pub struct AnyValidator;
+#[derive(Debug)]
+pub struct AnyValidator {
+    // validators for the values of `{"__type__": ..., "value": ...}` markers, set if `type_markers` is enabled
+    marker_validators: Option<Vec<(&'static str, CombinedValidator)>>,
+}
+
+// types whose markers are decoded by the validator for the type, see `AnySerializer`
+const MARKER_TYPES: [&str; 6] = ["datetime", "date", "time", "timedelta", "uuid", "decimal"];
+
+// arbitrary id to identify recursion through marker decoding, distinct from definition ids
+const TYPE_MARKERS_NODE_ID: usize = usize::MAX;
 
 impl BuildValidator for AnyValidator {
     const EXPECTED_TYPE: &'static str = "any";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
+        schema: &Bound<'_, PyDict>,
         _config: Option<&Bound<'_, PyDict>>,
-        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
-        Ok(Self.into())
+        let py = schema.py();
+        let marker_validators = match schema.get_as(intern!(py, "type_markers"))?.unwrap_or(false) {
+            true => Some(
+                MARKER_TYPES
+                    .into_iter()
+                    .map(|type_name| {
+                        let type_schema = PyDict::new_bound(py);
+                        type_schema.set_item(intern!(py, "type"), type_name)?;
+                        Ok((type_name, build_validator(&type_schema, None, definitions)?))
+                    })
+                    .collect::<PyResult<_>>()?,
+            ),
+            false => None,
+        };
+        Ok(Self { marker_validators }.into())
     }
 }
 
-impl_py_gc_traverse!(AnyValidator {});
+impl PyGcTraverse for AnyValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for (_, validator) in self.marker_validators.iter().flatten() {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+}
 
 impl Validator for AnyValidator {
     fn validate<'py>(
@@ -35,10 +72,149 @@ impl Validator for AnyValidator {
     ) -> ValResult<PyObject> {
         // in a union, Any should be preferred to doing lax coercions
         state.floor_exactness(Exactness::Strict);
-        Ok(input.to_object(py))
+        let value = input.to_object(py);
+        match self.marker_validators {
+            Some(ref marker_validators) => decode_markers(value.bind(py), marker_validators, state),
+            None => Ok(value),
+        }
     }
 
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
 }
+
+/// Restore values serialized in `{"__type__": ..., "value": ...}` markers, in lists and dicts recursively.
+/// Markers with an unknown type are left as dicts.
+fn decode_markers<'py>(
+    value: &Bound<'py, PyAny>,
+    marker_validators: &[(&'static str, CombinedValidator)],
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<PyObject> {
+    let py = value.py();
+    let is_list = value.is_exact_instance_of::<PyList>();
+    if !is_list && !value.is_exact_instance_of::<PyDict>() {
+        return Ok(value.clone().unbind());
+    }
+    let Ok(mut guard) = RecursionGuard::new(state, value.as_ptr() as usize, TYPE_MARKERS_NODE_ID) else {
+        return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, value));
+    };
+    let state = guard.state();
+
+    if is_list {
+        return Ok(PyList::new_bound(py, decode_items(value, marker_validators, state)?).into_py(py));
+    }
+    let dict = value.downcast::<PyDict>()?;
+    if let Some(decoded) = decode_marker(dict, marker_validators, state)? {
+        return Ok(decoded);
+    }
+    let decoded = PyDict::new_bound(py);
+    for (key, value) in dict {
+        let value = decode_markers(&value, marker_validators, state).map_err(|e| e.with_outer_location(&key))?;
+        decoded.set_item(key, value)?;
+    }
+    Ok(decoded.into_py(py))
+}
+
+/// Decode the value of `dict` if it's a marker for a known type
+fn decode_marker<'py>(
+    dict: &Bound<'py, PyDict>,
+    marker_validators: &[(&'static str, CombinedValidator)],
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<Option<PyObject>> {
+    let py = dict.py();
+    if dict.len() != 2 {
+        return Ok(None);
+    }
+    let (Some(type_name), Some(value)) = (
+        dict.get_item(intern!(py, TYPE_MARKER_KEY))?,
+        dict.get_item(intern!(py, TYPE_MARKER_VALUE_KEY))?,
+    ) else {
+        return Ok(None);
+    };
+    let Ok(type_name) = type_name.downcast::<PyString>() else {
+        return Ok(None);
+    };
+
+    let decoded = match type_name.to_str()? {
+        "tuple" => PyTuple::new_bound(py, decode_marker_items(&value, marker_validators, state)?).into_py(py),
+        "set" => PySet::new_bound(py, &decode_marker_items(&value, marker_validators, state)?)?.into_py(py),
+        "frozenset" => PyFrozenSet::new_bound(py, &decode_marker_items(&value, marker_validators, state)?)?.into_py(py),
+        "dict" => {
+            let decoded = PyDict::new_bound(py);
+            for (index, pair) in decode_marker_items(&value, marker_validators, state)?
+                .into_iter()
+                .enumerate()
+            {
+                let pair = pair.bind(py);
+                match pair.downcast::<PyList>() {
+                    Ok(pair) if pair.len() == 2 => decoded.set_item(pair.get_item(0)?, pair.get_item(1)?)?,
+                    _ => {
+                        return Err(ValError::new(ErrorTypeDefaults::ListType, pair)
+                            .with_outer_location(index)
+                            .with_outer_location(TYPE_MARKER_VALUE_KEY))
+                    }
+                }
+            }
+            decoded.into_py(py)
+        }
+        "bytes" => PyBytes::new_bound(py, &decode_base64(&value)?).into_py(py),
+        "bytearray" => PyByteArray::new_bound(py, &decode_base64(&value)?).into_py(py),
+        type_name => {
+            let Some((_, validator)) = marker_validators.iter().find(|(name, _)| *name == type_name) else {
+                return Ok(None);
+            };
+            // marker values are always strings or numbers, so need lax validation even in strict mode
+            let state = &mut state.rebind_extra(|extra| extra.strict = Some(false));
+            validator
+                .validate(py, &value, state)
+                .map_err(|e| e.with_outer_location(TYPE_MARKER_VALUE_KEY))?
+        }
+    };
+    Ok(Some(decoded))
+}
+
+/// Decode the items of the list in a `tuple`, `set`, `frozenset` or `dict` marker
+fn decode_marker_items<'py>(
+    value: &Bound<'py, PyAny>,
+    marker_validators: &[(&'static str, CombinedValidator)],
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<Vec<PyObject>> {
+    if !value.is_instance_of::<PyList>() {
+        return Err(ValError::new(ErrorTypeDefaults::ListType, value).with_outer_location(TYPE_MARKER_VALUE_KEY));
+    }
+    decode_items(value, marker_validators, state).map_err(|e| e.with_outer_location(TYPE_MARKER_VALUE_KEY))
+}
+
+fn decode_items<'py>(
+    list: &Bound<'py, PyAny>,
+    marker_validators: &[(&'static str, CombinedValidator)],
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<Vec<PyObject>> {
+    list.downcast::<PyList>()?
+        .iter()
+        .enumerate()
+        .map(|(index, item)| decode_markers(&item, marker_validators, state).map_err(|e| e.with_outer_location(index)))
+        .collect()
+}
+
+/// Bytes in markers are always base64 encoded, see `AnySerializer`
+fn decode_base64(value: &Bound<'_, PyAny>) -> ValResult<Vec<u8>> {
+    let invalid = || {
+        ValError::new(
+            ErrorType::ValueError {
+                error: Some(
+                    pyo3::exceptions::PyValueError::new_err("bytes must be base64 encoded in type markers")
+                        .into_py(value.py()),
+                ),
+                context: None,
+            },
+            value,
+        )
+        .with_outer_location(TYPE_MARKER_VALUE_KEY)
+    };
+    let encoded = value.downcast::<PyString>().map_err(|_| invalid())?;
+    base64::engine::general_purpose::URL_SAFE
+        .decode(encoded.to_str()?)
+        .map_err(|_| invalid())
+}
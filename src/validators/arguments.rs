@@ -12,6 +12,7 @@ use crate::lookup_key::LookupKey;
 
 use crate::tools::SchemaDict;
 
+use super::pattern_extras::PatternExtras;
 use super::validation_state::ValidationState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 
@@ -30,8 +31,12 @@ pub struct ArgumentsValidator {
     positional_params_count: usize,
     var_args_validator: Option<Box<CombinedValidator>>,
     var_kwargs_validator: Option<Box<CombinedValidator>>,
+    // validators for extra keyword arguments with names matching patterns, used in place of `var_kwargs_validator`
+    pattern_kwargs: Option<PatternExtras>,
     loc_by_alias: bool,
     extra: ExtraBehavior,
+    // keys of JSON objects holding positional and keyword arguments, so positional only parameters can be set
+    json_envelope: Option<(String, String)>,
 }
 
 impl BuildValidator for ArgumentsValidator {
@@ -128,19 +133,44 @@ impl BuildValidator for ArgumentsValidator {
                 Some(v) => Some(Box::new(build_validator(&v, config, definitions)?)),
                 None => None,
             },
+            pattern_kwargs: PatternExtras::build(
+                schema,
+                intern!(py, "var_kwargs_pattern_schemas"),
+                config,
+                definitions,
+            )?,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             extra: ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Forbid)?,
+            json_envelope: json_envelope_keys(schema)?,
         }
         .into())
     }
 }
 
+/// `json_envelope` is either `True` to use the default keys or a tuple of the args and kwargs keys
+fn json_envelope_keys(schema: &Bound<'_, PyDict>) -> PyResult<Option<(String, String)>> {
+    match schema.get_item(intern!(schema.py(), "json_envelope"))? {
+        None => Ok(None),
+        Some(value) => match value.extract::<bool>() {
+            Ok(true) => Ok(Some(("__args__".to_string(), "__kwargs__".to_string()))),
+            Ok(false) => Ok(None),
+            Err(_) => match value.extract::<Vec<String>>().as_deref() {
+                Ok([args_key, kwargs_key]) if args_key != kwargs_key => {
+                    Ok(Some((args_key.clone(), kwargs_key.clone())))
+                }
+                _ => py_schema_err!("`json_envelope` should be a bool or a tuple of two different keys"),
+            },
+        },
+    }
+}
+
 impl_py_gc_traverse!(Parameter { validator });
 
 impl_py_gc_traverse!(ArgumentsValidator {
     parameters,
     var_args_validator,
-    var_kwargs_validator
+    var_kwargs_validator,
+    pattern_kwargs
 });
 
 impl Validator for ArgumentsValidator {
@@ -150,7 +180,10 @@ impl Validator for ArgumentsValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let args = input.validate_args()?;
+        let args = match self.json_envelope {
+            Some((ref args_key, ref kwargs_key)) => input.validate_args_envelope(args_key, kwargs_key)?,
+            None => input.validate_args()?,
+        };
 
         let mut output_args: Vec<PyObject> = Vec::with_capacity(self.positional_params_count);
         let output_kwargs = PyDict::new_bound(py);
@@ -278,8 +311,12 @@ impl Validator for ArgumentsValidator {
                         Err(err) => return Err(err),
                     };
                     if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
-                        match self.var_kwargs_validator {
-                            Some(ref validator) => match validator.validate(py, value.borrow_input(), state) {
+                        let pattern_validator = match self.pattern_kwargs {
+                            Some(ref pattern_kwargs) => pattern_kwargs.find(py, &either_str.as_cow()?)?,
+                            None => None,
+                        };
+                        match pattern_validator.or(self.var_kwargs_validator.as_deref()) {
+                            Some(validator) => match validator.validate(py, value.borrow_input(), state) {
                                 Ok(value) => {
                                     output_kwargs.set_item(either_str.as_py_string(py, state.cache_str()), value)?;
                                 }
@@ -294,7 +331,7 @@ impl Validator for ArgumentsValidator {
                                 if let ExtraBehavior::Forbid = self.extra {
                                     errors.push(ValLineError::new_with_loc(
                                         ErrorTypeDefaults::UnexpectedKeywordArgument,
-                                        value,
+                                        value.borrow_input(),
                                         raw_key.clone(),
                                     ));
                                 }
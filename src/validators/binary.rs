@@ -0,0 +1,111 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::io::Cursor;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use jiter::{JsonValue, LazyIndexMap};
+use num_bigint::BigInt;
+use serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use smallvec::SmallVec;
+
+// how `serde_json::Number` with `arbitrary_precision` serializes ints too large for 64 bits,
+// see `serialize_bigint_as_number`
+const NUMBER_TOKEN: &str = "$serde_json::private::Number";
+
+/// Parse data written by `SchemaSerializer.to_binary` - MessagePack of the data `to_json` would produce -
+/// so it can be validated like JSON
+pub fn parse_binary(data: &[u8]) -> Result<JsonValue<'_>, String> {
+    let mut deserializer = rmp_serde::Deserializer::new(Cursor::new(data));
+    let BinaryValue(value) = BinaryValue::deserialize(&mut deserializer).map_err(|e| e.to_string())?;
+    match data.len() as u64 - deserializer.position() {
+        0 => Ok(value),
+        _ => Err("unexpected data after the end of the value".to_string()),
+    }
+}
+
+struct BinaryValue<'de>(JsonValue<'de>);
+
+impl<'de> Deserialize<'de> for BinaryValue<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(BinaryValueVisitor).map(BinaryValue)
+    }
+}
+
+struct BinaryValueVisitor;
+
+impl<'de> Visitor<'de> for BinaryValueVisitor {
+    type Value = JsonValue<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("null, a bool, number or string, an array or a map with string keys")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(JsonValue::Null)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(JsonValue::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(JsonValue::Int(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(match i64::try_from(v) {
+            Ok(v) => JsonValue::Int(v),
+            Err(_) => JsonValue::BigInt(v.into()),
+        })
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(JsonValue::Float(v))
+    }
+
+    fn visit_borrowed_str<E: de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+        Ok(JsonValue::Str(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(JsonValue::Str(Cow::Owned(v.to_string())))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut array = SmallVec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(BinaryValue(item)) = seq.next_element()? {
+            array.push(item);
+        }
+        Ok(JsonValue::Array(Arc::new(array)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut object = LazyIndexMap::new();
+        while let Some(BinaryValue(key)) = map.next_key()? {
+            let JsonValue::Str(key) = key else {
+                return Err(de::Error::custom("map keys must be strings"));
+            };
+            let BinaryValue(value) = map.next_value()?;
+            if key == NUMBER_TOKEN && object.is_empty() {
+                return number_value(value);
+            }
+            object.insert(key, value);
+        }
+        Ok(JsonValue::Object(Arc::new(object)))
+    }
+}
+
+fn number_value<E: de::Error>(value: JsonValue<'_>) -> Result<JsonValue<'_>, E> {
+    let JsonValue::Str(number) = value else {
+        return Err(E::custom("invalid number"));
+    };
+    match BigInt::from_str(&number) {
+        Ok(big_int) => Ok(JsonValue::BigInt(big_int)),
+        Err(_) => number.parse().map(JsonValue::Float).map_err(E::custom),
+    }
+}
@@ -5,11 +5,13 @@ use crate::build_tools::is_strict;
 use crate::errors::ValResult;
 use crate::input::Input;
 
+use super::numpy::{numpy_item, numpy_support};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct BoolValidator {
     strict: bool,
+    numpy_support: bool,
 }
 
 impl BuildValidator for BoolValidator {
@@ -22,6 +24,7 @@ impl BuildValidator for BoolValidator {
     ) -> PyResult<CombinedValidator> {
         Ok(Self {
             strict: is_strict(schema, config)?,
+            numpy_support: numpy_support(config)?,
         }
         .into())
     }
@@ -36,11 +39,17 @@ impl Validator for BoolValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
+        if self.numpy_support {
+            if let Some(item) = numpy_item(input, strict, state)? {
+                return self.validate(py, &item, state);
+            }
+        }
         // TODO in theory this could be quicker if we used PyBool rather than going to a bool
         // and back again, might be worth profiling?
         input
-            .validate_bool(state.strict_or(self.strict))
-            .map(|val_match| val_match.unpack(state).into_py(py))
+            .validate_bool(strict)
+            .map(|val_match| val_match.unpack(state, input, "bool").into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -0,0 +1,88 @@
+use std::io::Cursor;
+
+use bson::spec::BinarySubtype;
+use bson::{Bson, Document};
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{timezone_utc_bound, PyBytes, PyDateTime, PyDelta, PyDict, PyList};
+
+use super::decimal::get_decimal_type;
+use super::uuid::get_uuid_type;
+
+/// Parse a BSON document, e.g. written by `SchemaSerializer.to_bson`, to a dict so it can be validated.
+///
+/// ObjectIds are read as their hex string, datetimes as UTC datetimes, Decimal128s as `Decimal`s, binary
+/// with the UUID subtype as `UUID`s and other binary as bytes.
+pub fn parse_bson<'py>(py: Python<'py>, data: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+    let mut reader = Cursor::new(data);
+    let document = Document::from_reader(&mut reader).map_err(|e| invalid_bson(&e))?;
+    if reader.position() != data.len() as u64 {
+        return Err(invalid_bson(&"unexpected data after the end of the document"));
+    }
+    document_to_python(py, &document)
+}
+
+fn invalid_bson(error: &impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(format!("Invalid BSON data: {error}"))
+}
+
+fn document_to_python<'py>(py: Python<'py>, document: &Document) -> PyResult<Bound<'py, PyDict>> {
+    let dict = PyDict::new_bound(py);
+    for (key, value) in document {
+        dict.set_item(key, to_python(py, value)?)?;
+    }
+    Ok(dict)
+}
+
+fn to_python(py: Python, value: &Bson) -> PyResult<PyObject> {
+    let value = match value {
+        Bson::Null => py.None(),
+        Bson::Boolean(bool) => bool.into_py(py),
+        Bson::Int32(int) => int.into_py(py),
+        Bson::Int64(int) => int.into_py(py),
+        Bson::Double(float) => float.into_py(py),
+        Bson::String(str) | Bson::Symbol(str) | Bson::JavaScriptCode(str) => str.into_py(py),
+        Bson::Array(array) => PyList::new_bound(
+            py,
+            array
+                .iter()
+                .map(|item| to_python(py, item))
+                .collect::<PyResult<Vec<_>>>()?,
+        )
+        .into_py(py),
+        Bson::Document(document) => document_to_python(py, document)?.into_py(py),
+        Bson::ObjectId(object_id) => object_id.to_hex().into_py(py),
+        Bson::DateTime(datetime) => to_py_datetime(py, datetime.timestamp_millis())?,
+        Bson::Decimal128(decimal) => get_decimal_type(py).call1((decimal.to_string(),))?.into_py(py),
+        Bson::Binary(binary) => match binary.subtype {
+            BinarySubtype::Uuid => {
+                let kwargs = PyDict::new_bound(py);
+                kwargs.set_item(intern!(py, "bytes"), PyBytes::new_bound(py, &binary.bytes))?;
+                get_uuid_type(py)?
+                    .call((), Some(&kwargs))
+                    .map_err(|e| invalid_bson(&e))?
+                    .into_py(py)
+            }
+            _ => PyBytes::new_bound(py, &binary.bytes).into_py(py),
+        },
+        Bson::Timestamp(timestamp) => (u64::from(timestamp.time) << 32 | u64::from(timestamp.increment)).into_py(py),
+        other => {
+            return Err(invalid_bson(&format_args!(
+                "unsupported element type {:?}",
+                other.element_type()
+            )))
+        }
+    };
+    Ok(value)
+}
+
+fn to_py_datetime(py: Python, timestamp_millis: i64) -> PyResult<PyObject> {
+    let out_of_range = |_| invalid_bson(&"datetime out of range");
+    let epoch = PyDateTime::new_bound(py, 1970, 1, 1, 0, 0, 0, 0, Some(&timezone_utc_bound(py)))?;
+    let days =
+        i32::try_from(timestamp_millis.div_euclid(86_400_000)).map_err(|_| invalid_bson(&"datetime out of range"))?;
+    let millis = timestamp_millis.rem_euclid(86_400_000) as i32;
+    let delta = PyDelta::new_bound(py, days, millis / 1000, (millis % 1000) * 1000, false).map_err(out_of_range)?;
+    epoch.add(delta).map(Bound::unbind).map_err(out_of_range)
+}
@@ -48,7 +48,7 @@ impl Validator for BytesValidator {
     ) -> ValResult<PyObject> {
         input
             .validate_bytes(state.strict_or(self.strict))
-            .map(|m| m.unpack(state).into_py(py))
+            .map(|m| m.unpack(state, input, "bytes").into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -72,7 +72,9 @@ impl Validator for BytesConstrainedValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_bytes = input.validate_bytes(state.strict_or(self.strict))?.unpack(state);
+        let either_bytes = input
+            .validate_bytes(state.strict_or(self.strict))?
+            .unpack(state, input, "bytes");
         let len = either_bytes.len()?;
 
         if let Some(min_length) = self.min_length {
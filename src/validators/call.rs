@@ -82,6 +82,7 @@ impl Validator for CallValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let args = self.arguments_validator.validate(py, input, state)?.into_bound(py);
+        state.count_python_callback()?;
 
         let return_value = if let Ok((args, kwargs)) = args.extract::<(Bound<PyTuple>, Bound<PyDict>)>() {
             self.function.call_bound(py, args, Some(&kwargs))?
@@ -0,0 +1,162 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyType};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{input_as_python_instance, Input, InputType};
+use crate::tools::SchemaDict;
+
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// The type from the `collections` module a `CollectionValidator` constructs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CollectionKind {
+    Deque,
+    Counter,
+    OrderedDict,
+    DefaultDict,
+}
+
+impl CollectionKind {
+    fn class_name(self) -> &'static str {
+        match self {
+            Self::Deque => "deque",
+            Self::Counter => "Counter",
+            Self::OrderedDict => "OrderedDict",
+            Self::DefaultDict => "defaultdict",
+        }
+    }
+}
+
+/// Validates the items like a list, or a dict for the mapping types, then constructs the collection from them
+#[derive(Debug)]
+pub struct CollectionValidator {
+    strict: bool,
+    kind: CollectionKind,
+    class: Py<PyType>,
+    inner: Box<CombinedValidator>,
+    maxlen: Option<usize>,
+    default_factory: Option<PyObject>,
+}
+
+macro_rules! collection_builder {
+    ($builder:ident, $expected_type:literal, $kind:expr) => {
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
+                definitions: &mut DefinitionsBuilder<CombinedValidator>,
+            ) -> PyResult<CombinedValidator> {
+                CollectionValidator::build(schema, config, definitions, $kind)
+            }
+        }
+    };
+}
+
+collection_builder!(DequeBuilder, "deque", CollectionKind::Deque);
+collection_builder!(CounterBuilder, "counter", CollectionKind::Counter);
+collection_builder!(OrderedDictBuilder, "ordered-dict", CollectionKind::OrderedDict);
+collection_builder!(DefaultDictBuilder, "defaultdict", CollectionKind::DefaultDict);
+
+impl CollectionValidator {
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+        kind: CollectionKind,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class = py
+            .import_bound(intern!(py, "collections"))?
+            .getattr(kind.class_name())?;
+        let inner_type = if kind == CollectionKind::Deque { "list" } else { "dict" };
+        let default_factory = match kind {
+            CollectionKind::DefaultDict => Some(schema.get_as_req(intern!(py, "default_factory"))?),
+            _ => None,
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            kind,
+            class: class.downcast_into::<PyType>()?.unbind(),
+            inner: Box::new(build_validator(
+                inner_schema(schema, inner_type)?.as_any(),
+                config,
+                definitions,
+            )?),
+            maxlen: schema.get_as(intern!(py, "maxlen"))?,
+            default_factory,
+        }
+        .into())
+    }
+}
+
+/// The `list` or `dict` schema the items are validated with, instances of the collection are validated when
+/// it's strict, so the inner schema isn't
+fn inner_schema<'py>(schema: &Bound<'py, PyDict>, inner_type: &str) -> PyResult<Bound<'py, PyDict>> {
+    let py = schema.py();
+    let inner = PyDict::new_bound(py);
+    inner.set_item(intern!(py, "type"), inner_type)?;
+    inner.set_item(intern!(py, "strict"), false)?;
+    for key in [
+        "items_schema",
+        "keys_schema",
+        "values_schema",
+        "min_length",
+        "max_length",
+    ] {
+        if let Some(value) = schema.get_item(key)? {
+            inner.set_item(key, value)?;
+        }
+    }
+    Ok(inner)
+}
+
+impl_py_gc_traverse!(CollectionValidator {
+    class,
+    inner,
+    default_factory
+});
+
+impl Validator for CollectionValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let class = self.class.bind(py);
+        let items = match input_as_python_instance(input, class) {
+            // deques aren't lists, so in strict mode they're validated as a list of their items
+            Some(py_input) if self.kind == CollectionKind::Deque => {
+                let list = PyList::new_bound(py, py_input.iter()?.collect::<PyResult<Vec<_>>>()?);
+                self.inner.validate(py, list.as_any(), state)?
+            }
+            Some(_) => self.inner.validate(py, input, state)?,
+            None if state.strict_or(self.strict) && state.extra().input_type == InputType::Python => {
+                return Err(ValError::new(
+                    ErrorType::IsInstanceOf {
+                        class: self.get_name().to_string(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+            None => self.inner.validate(py, input, state)?,
+        };
+        let collection = match (self.kind, &self.default_factory) {
+            (CollectionKind::Deque, _) => class.call1((items, self.maxlen))?,
+            (CollectionKind::DefaultDict, Some(default_factory)) => class.call1((default_factory, items))?,
+            _ => class.call1((items,))?,
+        };
+        Ok(collection.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        self.kind.class_name()
+    }
+}
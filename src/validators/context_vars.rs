@@ -0,0 +1,52 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{IntoPyDict, PyList};
+
+static COERCION_AUDIT_VAR: GILOnceCell<PyObject> = GILOnceCell::new();
+static UNION_DEBUG_VAR: GILOnceCell<PyObject> = GILOnceCell::new();
+
+fn get_context_var<'py>(
+    py: Python<'py>,
+    cell: &'py GILOnceCell<PyObject>,
+    name: &str,
+) -> PyResult<&'py Bound<'py, PyAny>> {
+    cell.get_or_try_init(py, || {
+        let context_var = py.import_bound("contextvars")?.getattr("ContextVar")?;
+        let kwargs = [("default", py.None())].into_py_dict_bound(py);
+        context_var.call((name,), Some(&kwargs)).map(Bound::unbind)
+    })
+    .map(|var| var.bind(py))
+}
+
+/// The list held by a context var, if set
+fn current_list<'py>(var: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyList>>> {
+    let list = var.call_method0(intern!(var.py(), "get"))?;
+    if list.is_none() {
+        Ok(None)
+    } else {
+        Ok(Some(list.downcast_into::<PyList>()?))
+    }
+}
+
+/// The `ContextVar` holding the list yielded by `pydantic_core.coercion_audit()`, lax conversions made
+/// by successful validation calls within the block are appended to the list.
+pub fn get_coercion_audit_var(py: Python<'_>) -> PyResult<&Bound<'_, PyAny>> {
+    get_context_var(py, &COERCION_AUDIT_VAR, "pydantic_core_coercion_audit")
+}
+
+/// The list to record coercions in if validating within `coercion_audit()`
+pub fn current_coercion_audit(py: Python<'_>) -> PyResult<Option<Bound<'_, PyList>>> {
+    current_list(get_coercion_audit_var(py)?)
+}
+
+/// The `ContextVar` holding the list yielded by `pydantic_core.union_debug()`, a description of each
+/// smart mode union decision made within the block is appended to the list.
+pub fn get_union_debug_var(py: Python<'_>) -> PyResult<&Bound<'_, PyAny>> {
+    get_context_var(py, &UNION_DEBUG_VAR, "pydantic_core_union_debug")
+}
+
+/// The list to record union decisions in if validating within `union_debug()`
+pub fn current_union_debug(py: Python<'_>) -> PyResult<Option<Bound<'_, PyList>>> {
+    current_list(get_union_debug_var(py)?)
+}
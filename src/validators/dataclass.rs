@@ -6,7 +6,9 @@ use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{
+    is_strict, nested_config, schema_or_config_same, AliasGenerator, ExtraBehavior, ExtrasDestination,
+};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::{
     input_as_python_instance, Arguments, BorrowInput, Input, InputType, KeywordArgs, PositionalArgs, ValidationMatch,
@@ -17,7 +19,9 @@ use crate::validators::function::convert_err;
 
 use super::model::{create_class, force_setattr, Revalidate};
 use super::validation_state::Exactness;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, PathSegment, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 struct Field {
@@ -54,6 +58,7 @@ impl BuildValidator for DataclassArgsValidator {
         let py = schema.py();
 
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+        let alias_generator = AliasGenerator::from_config(config)?;
 
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
@@ -79,7 +84,21 @@ impl BuildValidator for DataclassArgsValidator {
                     let alt_alias = if populate_by_name { Some(name.as_str()) } else { None };
                     LookupKey::from_py(py, &alias, alt_alias)?
                 }
-                None => LookupKey::from_string(py, &name),
+                None => match field.get_item(intern!(py, "serialization_alias"))? {
+                    Some(aliases) if aliases.is_instance_of::<PyList>() => {
+                        let alt_alias = if populate_by_name { Some(name.as_str()) } else { None };
+                        LookupKey::from_choices(py, aliases.downcast()?, alt_alias)?
+                    }
+                    Some(_) => LookupKey::from_string(py, &name),
+                    None => match alias_generator {
+                        Some(alias_generator) => {
+                            let alias = PyString::new_bound(py, &alias_generator.apply(&name));
+                            let alt_alias = if populate_by_name { Some(name.as_str()) } else { None };
+                            LookupKey::from_py(py, &alias, alt_alias)?
+                        }
+                        None => LookupKey::from_string(py, &name),
+                    },
+                },
             };
 
             let schema = field.get_as_req(intern!(py, "schema"))?;
@@ -112,7 +131,11 @@ impl BuildValidator for DataclassArgsValidator {
             });
         }
 
-        let init_only_count = if schema.get_as(intern!(py, "collect_init_only"))?.unwrap_or(false) {
+        // by default init only fields (`InitVar`s) are collected if there are any, so they reach `__post_init__`
+        let collect_init_only = schema
+            .get_as(intern!(py, "collect_init_only"))?
+            .unwrap_or_else(|| fields.iter().any(|f| f.init_only));
+        let init_only_count = if collect_init_only {
             Some(fields.iter().filter(|f| f.init_only).count())
         } else {
             None
@@ -138,6 +161,29 @@ impl_py_gc_traverse!(Field { validator });
 
 impl_py_gc_traverse!(DataclassArgsValidator { fields });
 
+impl DataclassArgsValidator {
+    /// Whether extras are kept on the dataclass rather than ignored or forbidden
+    pub(super) fn allows_extra(&self) -> bool {
+        self.extra_behavior == ExtraBehavior::Allow
+    }
+
+    /// The name and lookup key of each field set from the input, in order
+    pub(super) fn lookup_keys(&self) -> impl Iterator<Item = (&str, &LookupKey)> {
+        self.fields
+            .iter()
+            .filter(|field| field.init)
+            .map(|field| (field.name.as_str(), &field.lookup_key))
+    }
+
+    /// The validator of the field called `name` set from the input
+    pub(super) fn field_validator(&self, name: &str) -> Option<&CombinedValidator> {
+        self.fields
+            .iter()
+            .find(|field| field.init && field.name == name)
+            .map(|field| &field.validator)
+    }
+}
+
 impl Validator for DataclassArgsValidator {
     fn validate<'py>(
         &self,
@@ -215,31 +261,45 @@ impl Validator for DataclassArgsValidator {
                     ));
                 }
                 // found a positional argument, validate it
-                (Some(pos_value), None) => match field.validator.validate(py, pos_value.borrow_input(), state) {
-                    Ok(value) => {
-                        set_item!(field, value);
-                        fields_set_count += 1;
-                    }
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                (Some(pos_value), None) => {
+                    let segment = PathSegment::Field(field.py_name.clone_ref(py));
+                    match state.with_path(segment, |state| {
+                        state.traced(py, field.validator.get_name(), Some(&field.name), |state| {
+                            field.validator.validate(py, pos_value.borrow_input(), state)
+                        })
+                    }) {
+                        Ok(value) => {
+                            set_item!(field, value);
+                            fields_set_count += 1;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 // found a keyword argument, validate it
-                (None, Some((lookup_path, kw_value))) => match field.validator.validate(py, kw_value, state) {
-                    Ok(value) => {
-                        set_item!(field, value);
-                        fields_set_count += 1;
-                    }
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(
-                            line_errors
-                                .into_iter()
-                                .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
-                        );
+                (None, Some((lookup_path, kw_value))) => {
+                    let segment = PathSegment::Field(field.py_name.clone_ref(py));
+                    match state.with_path(segment, |state| {
+                        state.traced(py, field.validator.get_name(), Some(&field.name), |state| {
+                            field.validator.validate(py, kw_value, state)
+                        })
+                    }) {
+                        Ok(value) => {
+                            set_item!(field, value);
+                            fields_set_count += 1;
+                        }
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(
+                                line_errors
+                                    .into_iter()
+                                    .map(|err| lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name)),
+                            );
+                        }
+                        Err(err) => return Err(err),
                     }
-                    Err(err) => return Err(err),
-                },
+                }
                 // found neither, check if there is a default value, otherwise error
                 (None, None) => {
                     match field.validator.default_value(py, Some(field.name.as_str()), state) {
@@ -323,8 +383,10 @@ impl Validator for DataclassArgsValidator {
                                                 Err(err) => return Err(err),
                                             }
                                         } else {
-                                            output_dict
-                                                .set_item(either_str.as_py_string(py, state.cache_str()), value)?;
+                                            output_dict.set_item(
+                                                either_str.as_py_string(py, state.cache_str()),
+                                                value.borrow_input().to_object(py),
+                                            )?;
                                         }
                                     }
                                 }
@@ -393,11 +455,10 @@ impl Validator for DataclassArgsValidator {
                 }
             }
 
-            match field.validator.validate(
-                py,
-                field_value,
-                &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone())),
-            ) {
+            let state = &mut state.rebind_extra(|extra| extra.data = Some(data_dict.clone()));
+            match state.traced(py, field.validator.get_name(), Some(&field.name), |state| {
+                field.validator.validate(py, field_value, state)
+            }) {
                 Ok(output) => ok(output),
                 Err(ValError::LineErrors(line_errors)) => {
                     let errors = line_errors
@@ -444,6 +505,7 @@ pub struct DataclassValidator {
     name: String,
     frozen: bool,
     slots: bool,
+    extras_destination: Option<ExtrasDestination>,
 }
 
 impl BuildValidator for DataclassValidator {
@@ -451,14 +513,15 @@ impl BuildValidator for DataclassValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
 
-        // dataclasses ignore the parent config and always use the config from this dataclasses
-        let config = schema.get_as(intern!(py, "config"))?;
+        // dataclasses use the config from this dataclass, unless it's set to merge with the parent config
+        let config = nested_config(schema, config)?;
         let config = config.as_ref();
+        definitions.record_config(schema, config)?;
 
         let class = schema.get_as_req::<Bound<'_, PyType>>(intern!(py, "cls"))?;
         let name = match schema.get_as_req::<String>(intern!(py, "cls_name")) {
@@ -476,6 +539,21 @@ impl BuildValidator for DataclassValidator {
 
         let fields = schema.get_as_req(intern!(py, "fields"))?;
 
+        let slots = schema.get_as(intern!(py, "slots"))?.unwrap_or(false);
+        let extras_destination = ExtrasDestination::from_config(config)?;
+        // with slots, fields are set as attributes, extras merged into `__dict__` need the class to have one
+        if slots && matches!(extras_destination, None | Some(ExtrasDestination::Merge)) {
+            if let CombinedValidator::DataclassArgs(ref args) = validator {
+                if args.allows_extra() && class.getattr(intern!(py, "__dictoffset__"))?.extract::<isize>()? == 0 {
+                    return py_schema_err!(
+                        "Dataclass `{}` has `__slots__` and no `__dict__` to store extras in, \
+                        use an `extras_destination` slot or don't allow extras",
+                        name
+                    );
+                }
+            }
+        }
+
         Ok(Self {
             strict: is_strict(schema, config)?,
             validator: Box::new(validator),
@@ -490,7 +568,8 @@ impl BuildValidator for DataclassValidator {
             )?,
             name,
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
-            slots: schema.get_as(intern!(py, "slots"))?.unwrap_or(false),
+            slots,
+            extras_destination,
         }
         .into())
     }
@@ -560,6 +639,7 @@ impl Validator for DataclassValidator {
                 .validate_assignment(py, new_dict.as_any(), field_name, field_value, state)?;
 
         let (dc_dict, _): (Bound<'_, PyDict>, Bound<'_, PyAny>) = val_assignment_result.extract(py)?;
+        let dc_extra = self.take_extra(&dc_dict, Some(obj))?;
 
         if self.slots {
             let value = dc_dict
@@ -569,6 +649,9 @@ impl Validator for DataclassValidator {
         } else {
             force_setattr(py, obj, intern!(py, "__dict__"), dc_dict)?;
         }
+        if let Some((attribute, dc_extra)) = dc_extra {
+            force_setattr(py, obj, attribute, dc_extra)?;
+        }
 
         Ok(obj.to_object(py))
     }
@@ -579,6 +662,11 @@ impl Validator for DataclassValidator {
 }
 
 impl DataclassValidator {
+    /// The validator this one wraps
+    pub(super) fn inner_validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
     /// here we just call the inner validator, then set attributes on `self_instance`
     fn validate_init<'py>(
         &self,
@@ -607,6 +695,35 @@ impl DataclassValidator {
         Ok(dict)
     }
 
+    /// When extras are stored in an attribute, move the items of `dc_dict` which aren't fields into a new dict,
+    /// or a copy of the extras of `existing`, returned with the attribute to set it on after `__dict__`, which may
+    /// contain the attribute
+    fn take_extra<'py>(
+        &self,
+        dc_dict: &Bound<'py, PyDict>,
+        existing: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<Option<(Bound<'py, PyString>, Bound<'py, PyDict>)>> {
+        let py = dc_dict.py();
+        let Some(attribute) = self.extras_destination.as_ref().and_then(|d| d.attribute(py)) else {
+            return Ok(None);
+        };
+        let dc_extra = match existing.and_then(|dc| dc.getattr(&attribute).ok()) {
+            Some(existing) if existing.is_instance_of::<PyDict>() => existing.downcast_into::<PyDict>()?.copy()?,
+            _ => PyDict::new_bound(py),
+        };
+        for (key, value) in dc_dict.copy()? {
+            if !self
+                .fields
+                .iter()
+                .any(|field| field.bind(py).as_any().eq(&key).unwrap_or(false))
+            {
+                dc_dict.del_item(&key)?;
+                dc_extra.set_item(key, value)?;
+            }
+        }
+        Ok(Some((attribute, dc_extra)))
+    }
+
     fn set_dict_call<'py>(
         &self,
         py: Python<'py>,
@@ -614,15 +731,18 @@ impl DataclassValidator {
         val_output: PyObject,
         input: &(impl Input<'py> + ?Sized),
     ) -> ValResult<()> {
-        let (dc_dict, post_init_kwargs): (Bound<'_, PyAny>, Bound<'_, PyAny>) = val_output.extract(py)?;
+        let (dc_dict, post_init_kwargs): (Bound<'_, PyDict>, Bound<'_, PyAny>) = val_output.extract(py)?;
+        let dc_extra = self.take_extra(&dc_dict, None)?;
         if self.slots {
-            let dc_dict = dc_dict.downcast::<PyDict>()?;
             for (key, value) in dc_dict.iter() {
                 force_setattr(py, dc, key, value)?;
             }
         } else {
             force_setattr(py, dc, intern!(py, "__dict__"), dc_dict)?;
         }
+        if let Some((attribute, dc_extra)) = dc_extra {
+            force_setattr(py, dc, attribute, dc_extra)?;
+        }
 
         if let Some(ref post_init) = self.post_init {
             let post_init = post_init.bind(py);
@@ -2,9 +2,8 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDate, PyDict, PyString};
 use speedate::{Date, Time};
-use strum::EnumMessage;
 
-use crate::build_tools::{is_strict, py_schema_error_type};
+use crate::build_tools::is_strict;
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{EitherDate, Input};
 
@@ -47,7 +46,7 @@ impl Validator for DateValidator {
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let date = match input.validate_date(strict) {
-            Ok(val_match) => val_match.unpack(state),
+            Ok(val_match) => val_match.unpack(state, input, "date"),
             // if the error was a parsing error, in lax mode we allow datetimes at midnight
             Err(line_errors @ ValError::LineErrors(..)) if !strict => {
                 state.floor_exactness(Exactness::Lax);
@@ -81,9 +80,7 @@ impl Validator for DateValidator {
 
             if let Some(ref today_constraint) = constraints.today {
                 let offset = today_constraint.utc_offset(py)?;
-                let today = Date::today(offset).map_err(|e| {
-                    py_schema_error_type!("Date::today() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let today = state.now(py, offset)?.date;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = raw_date.partial_cmp(&today) {
                     let date_compliant = today_constraint.op.compare(c);
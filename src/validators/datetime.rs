@@ -4,7 +4,6 @@ use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyString};
 use speedate::{DateTime, Time};
 use std::cmp::Ordering;
-use strum::EnumMessage;
 
 use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
@@ -66,7 +65,7 @@ impl Validator for DateTimeValidator {
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let datetime = match input.validate_datetime(strict, self.microseconds_precision) {
-            Ok(val_match) => val_match.unpack(state),
+            Ok(val_match) => val_match.unpack(state, input, "datetime"),
             // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
             Err(line_errors @ ValError::LineErrors(..)) if !strict => {
                 state.floor_exactness(Exactness::Lax);
@@ -110,9 +109,7 @@ impl Validator for DateTimeValidator {
 
             if let Some(ref now_constraint) = constraints.now {
                 let offset = now_constraint.utc_offset(py)?;
-                let now = DateTime::now(offset).map_err(|e| {
-                    py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
-                })?;
+                let now = state.now(py, offset)?;
                 // `if let Some(c)` to match behaviour of gt/lt/le/ge
                 if let Some(c) = speedate_dt.partial_cmp(&now) {
                     let dt_compliant = now_constraint.op.compare(c);
@@ -4,7 +4,7 @@ use pyo3::sync::GILOnceCell;
 use pyo3::types::{IntoPyDict, PyDict, PyTuple, PyType};
 use pyo3::{prelude::*, PyTypeInfo};
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config_same};
 use crate::errors::ErrorType;
 use crate::errors::ValResult;
 use crate::errors::{ErrorTypeDefaults, Number};
@@ -40,6 +40,84 @@ pub struct DecimalValidator {
     gt: Option<Py<PyAny>>,
     max_digits: Option<u64>,
     decimal_places: Option<u64>,
+    quantize: Option<Quantize>,
+}
+
+/// Round values with `Decimal.quantize` instead of rejecting them for having too many decimal places
+#[derive(Debug, Clone)]
+struct Quantize {
+    exp: Py<PyAny>,
+    // built once, with the rounding mode and enough precision that quantizing never fails
+    context: Py<PyAny>,
+}
+
+impl_py_gc_traverse!(Quantize { exp, context });
+
+impl Quantize {
+    fn build(
+        py: Python,
+        quantize_to: Option<Bound<'_, PyAny>>,
+        rounding: Option<Bound<'_, PyAny>>,
+        decimal_places: Option<u64>,
+    ) -> PyResult<Option<Self>> {
+        let exp = match (quantize_to, decimal_places) {
+            (Some(quantize_to), _) => {
+                let quantize_to = get_decimal_type(py).call1((quantize_to,))?;
+                if !quantize_to.call_method0(intern!(py, "is_finite"))?.extract::<bool>()? {
+                    return py_schema_err!("`quantize_to` must be a finite decimal, got {}", quantize_to);
+                }
+                quantize_to
+            }
+            (None, Some(decimal_places)) if rounding.is_some() => {
+                get_decimal_type(py).call1((format!("1e-{decimal_places}"),))?
+            }
+            (None, _) if rounding.is_some() => {
+                return py_schema_err!("`rounding` can only be used with `decimal_places` or `quantize_to`");
+            }
+            (None, _) => return Ok(None),
+        };
+        Ok(Some(Self {
+            exp: exp.unbind(),
+            context: quantize_context(py, rounding)?.unbind(),
+        }))
+    }
+
+    fn quantize<'py>(&self, decimal: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+        quantize(decimal, self.exp.bind(decimal.py()), &self.context)
+    }
+}
+
+/// A `decimal.Context` rounding with `rounding`, `ROUND_HALF_EVEN` by default, with enough precision that
+/// quantizing never fails
+pub(crate) fn quantize_context<'py>(
+    py: Python<'py>,
+    rounding: Option<Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let decimal_module = py.import_bound(intern!(py, "decimal"))?;
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item(intern!(py, "prec"), decimal_module.getattr(intern!(py, "MAX_PREC"))?)?;
+    kwargs.set_item(
+        intern!(py, "rounding"),
+        match rounding {
+            Some(rounding) => rounding,
+            None => decimal_module.getattr(intern!(py, "ROUND_HALF_EVEN"))?,
+        },
+    )?;
+    decimal_module
+        .getattr(intern!(py, "Context"))?
+        .call((), Some(&kwargs))
+        .map_err(|e| py_schema_error_type!("Invalid decimal `rounding`: {}", e.value_bound(py)))
+}
+
+pub(crate) fn quantize<'py>(
+    decimal: &Bound<'py, PyAny>,
+    exp: &Bound<'py, PyAny>,
+    context: &Py<PyAny>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let py = decimal.py();
+    let kwargs = PyDict::new_bound(py);
+    kwargs.set_item(intern!(py, "context"), context)?;
+    decimal.call_method(intern!(py, "quantize"), (exp,), Some(&kwargs))
 }
 
 impl BuildValidator for DecimalValidator {
@@ -58,6 +136,15 @@ impl BuildValidator for DecimalValidator {
                 "allow_inf_nan=True cannot be used with max_digits or decimal_places",
             ));
         }
+        let quantize = Quantize::build(
+            py,
+            schema.get_as(intern!(py, "quantize_to"))?,
+            schema.get_as(intern!(py, "rounding"))?,
+            decimal_places,
+        )?;
+        if allow_inf_nan && quantize.is_some() {
+            return py_schema_err!("allow_inf_nan=True cannot be used with quantize_to");
+        }
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan,
@@ -69,6 +156,7 @@ impl BuildValidator for DecimalValidator {
             ge: schema.get_as(intern!(py, "ge"))?,
             gt: schema.get_as(intern!(py, "gt"))?,
             max_digits,
+            quantize,
         }
         .into())
     }
@@ -79,10 +167,11 @@ impl_py_gc_traverse!(DecimalValidator {
     le,
     lt,
     ge,
-    gt
+    gt,
+    quantize
 });
 
-fn extract_decimal_digits_info(decimal: &Bound<'_, PyAny>, normalized: bool) -> ValResult<(u64, u64)> {
+pub(crate) fn extract_decimal_digits_info(decimal: &Bound<'_, PyAny>, normalized: bool) -> ValResult<(u64, u64)> {
     let py = decimal.py();
     let mut normalized_decimal: Option<Bound<'_, PyAny>> = None;
     if normalized {
@@ -122,13 +211,17 @@ impl Validator for DecimalValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let decimal = input.validate_decimal(state.strict_or(self.strict), py)?;
+        let mut decimal = input.validate_decimal(state.strict_or(self.strict), py)?;
 
         if !self.allow_inf_nan || self.check_digits {
             if !decimal.call_method0(intern!(py, "is_finite"))?.extract()? {
                 return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
             }
 
+            if let Some(quantize) = &self.quantize {
+                decimal = quantize.quantize(&decimal)?;
+            }
+
             if self.check_digits {
                 if let Ok((normalized_decimals, normalized_digits)) = extract_decimal_digits_info(&decimal, true) {
                     if let Ok((decimals, digits)) = extract_decimal_digits_info(&decimal, false) {
@@ -49,6 +49,10 @@ impl DefinitionRefValidator {
     pub fn new(definition: DefinitionRef<CombinedValidator>) -> Self {
         Self { definition }
     }
+
+    pub(super) fn definition(&self) -> &DefinitionRef<CombinedValidator> {
+        &self.definition
+    }
 }
 
 impl BuildValidator for DefinitionRefValidator {
@@ -75,6 +79,7 @@ impl Validator for DefinitionRefValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        state.check_deadline()?;
         self.definition.read(|validator| {
             let validator = validator.unwrap();
             if let Some(id) = input.as_python().map(py_identity) {
@@ -97,6 +102,7 @@ impl Validator for DefinitionRefValidator {
         field_value: &Bound<'py, PyAny>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        state.check_deadline()?;
         self.definition.read(|validator| {
             let validator = validator.unwrap();
             let Ok(mut guard) = RecursionGuard::new(state, py_identity(obj), self.definition.id()) else {
@@ -106,6 +112,23 @@ impl Validator for DefinitionRefValidator {
         })
     }
 
+    fn validate_assignment_many<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        values: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        state.check_deadline()?;
+        self.definition.read(|validator| {
+            let validator = validator.unwrap();
+            let Ok(mut guard) = RecursionGuard::new(state, py_identity(obj), self.definition.id()) else {
+                return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, obj));
+            };
+            validator.validate_assignment_many(py, obj, values, guard.state())
+        })
+    }
+
     fn get_name(&self) -> &str {
         self.definition.get_or_init_name(|v| v.get_name().into())
     }
@@ -1,18 +1,26 @@
-use pyo3::intern;
+use std::sync::Arc;
+
+use jiter::JsonValue;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyInt, PyList, PySet, PyString};
+use pyo3::{intern, PyTraverseError, PyVisit};
 
-use crate::build_tools::is_strict;
-use crate::errors::{LocItem, ValError, ValLineError, ValResult};
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorTypeDefaults, LocItem, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
 use crate::input::ConsumeIterator;
-use crate::input::{Input, ValidatedDict};
+use crate::input::{EitherString, Input, InputType, ValidatedDict, ValidationMatch};
+use crate::serializers::{tuple_key_separator_from_config, FromConfig, TupleKeyMode};
 
+use crate::py_gc::PyGcTraverse;
+use crate::read_only::ReadOnlyDict;
 use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
 use super::list::length_check;
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, PathSegment, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct DictValidator {
@@ -21,9 +29,38 @@ pub struct DictValidator {
     value_validator: Box<CombinedValidator>,
     min_length: Option<usize>,
     max_length: Option<usize>,
+    tuple_keys: Option<TupleKeys>,
+    // with `exhaustive`, the keys of the literal `keys_schema` which must all be present
+    required_keys: Option<Vec<Py<PyAny>>>,
+    read_only: bool,
+    return_input_if_valid: bool,
     name: String,
 }
 
+/// How tuple keys were serialized to JSON, so they can be parsed back to a tuple
+#[derive(Debug)]
+struct TupleKeys {
+    mode: TupleKeyMode,
+    separator: String,
+}
+
+impl TupleKeys {
+    fn parse<'k>(&self, key: &'k str) -> Option<JsonValue<'k>> {
+        match self.mode {
+            TupleKeyMode::Join => {
+                let items = key
+                    .split(self.separator.as_str())
+                    .map(|item| JsonValue::Str(item.into()));
+                Some(JsonValue::Array(Arc::new(items.collect())))
+            }
+            TupleKeyMode::Json => match JsonValue::parse(key.as_bytes(), true) {
+                Ok(array @ JsonValue::Array(_)) => Some(array),
+                _ => None,
+            },
+        }
+    }
+}
+
 impl BuildValidator for DictValidator {
     const EXPECTED_TYPE: &'static str = "dict";
 
@@ -33,7 +70,21 @@ impl BuildValidator for DictValidator {
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        let key_validator = match schema.get_item(intern!(py, "keys_schema"))? {
+        let keys_schema = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "keys_schema"))?;
+        let tuple_keys = match keys_schema {
+            Some(ref keys_schema) if keys_schema.get_as::<String>(intern!(py, "type"))?.as_deref() == Some("tuple") => {
+                Some(TupleKeys {
+                    mode: TupleKeyMode::from_config(config)?,
+                    separator: tuple_key_separator_from_config(config)?,
+                })
+            }
+            _ => None,
+        };
+        let required_keys = match schema.get_as(intern!(py, "exhaustive"))? {
+            Some(true) => Some(required_keys(keys_schema.as_ref())?),
+            _ => None,
+        };
+        let key_validator = match keys_schema {
             Some(schema) => Box::new(build_validator(&schema, config, definitions)?),
             None => Box::new(AnyValidator::build(schema, config, definitions)?),
         };
@@ -53,16 +104,57 @@ impl BuildValidator for DictValidator {
             value_validator,
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
+            tuple_keys,
+            required_keys,
+            read_only: schema.get_as(intern!(py, "read_only"))?.unwrap_or(false),
+            return_input_if_valid: schema.get_as(intern!(py, "return_input_if_valid"))?.unwrap_or(false),
             name,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(DictValidator {
-    key_validator,
-    value_validator
-});
+/// The expected values of a literal `keys_schema`
+fn required_keys(keys_schema: Option<&Bound<'_, PyDict>>) -> PyResult<Vec<Py<PyAny>>> {
+    let Some(keys_schema) = keys_schema else {
+        return py_schema_err!("`exhaustive` requires a literal `keys_schema`");
+    };
+    let py = keys_schema.py();
+    if keys_schema.get_as::<String>(intern!(py, "type"))?.as_deref() != Some("literal") {
+        return py_schema_err!("`exhaustive` requires a literal `keys_schema`");
+    }
+    let expected: Bound<'_, PyList> = keys_schema.get_as_req(intern!(py, "expected"))?;
+    Ok(expected.iter().map(Bound::unbind).collect())
+}
+
+/// The location of a required key which is missing
+fn required_key_loc(key: &Bound<'_, PyAny>) -> PyResult<LocItem> {
+    Ok(if let Ok(py_str) = key.downcast::<PyString>() {
+        py_str.to_str()?.into()
+    } else if let Ok(int) = key.extract::<i64>() {
+        int.into()
+    } else {
+        key.repr()?.to_string().into()
+    })
+}
+
+impl DictValidator {
+    /// The validator of each value
+    pub(super) fn value_validator(&self) -> &CombinedValidator {
+        &self.value_validator
+    }
+}
+
+impl PyGcTraverse for DictValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.key_validator.py_gc_traverse(visit)?;
+        self.value_validator.py_gc_traverse(visit)?;
+        for key in self.required_keys.iter().flatten() {
+            key.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+}
 
 impl Validator for DictValidator {
     fn validate<'py>(
@@ -73,15 +165,31 @@ impl Validator for DictValidator {
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let dict = input.validate_dict(strict)?;
-        dict.iterate(ValidateToDict {
+        let output = dict.iterate(ValidateToDict {
             py,
             input,
             min_length: self.min_length,
             max_length: self.max_length,
             key_validator: &self.key_validator,
             value_validator: &self.value_validator,
+            tuple_keys: match state.extra().input_type {
+                InputType::Python => None,
+                _ => self.tuple_keys.as_ref(),
+            },
+            required_keys: self.required_keys.as_deref(),
             state,
-        })?
+        })??;
+        // with `return_input_if_valid`, the input is returned if validating didn't change any keys or values
+        let output = match dict.as_py_dict() {
+            Some(input_dict) if self.return_input_if_valid && strict && unchanged(input_dict, &output) => {
+                input_dict.clone()
+            }
+            _ => output,
+        };
+        match self.read_only {
+            true => Ok(ReadOnlyDict::new(output).into_py(py)),
+            false => Ok(output.into_py(py)),
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -89,6 +197,16 @@ impl Validator for DictValidator {
     }
 }
 
+/// Whether `input` is a dict (not a subclass) and every key and value of `output` is the same object as in `input`
+fn unchanged(input: &Bound<'_, PyDict>, output: &Bound<'_, PyDict>) -> bool {
+    input.is_exact_instance_of::<PyDict>()
+        && input.len() == output.len()
+        && input
+            .iter()
+            .zip(output.iter())
+            .all(|((key, value), (out_key, out_value))| key.is(&out_key) && value.is(&out_value))
+}
+
 struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     py: Python<'py>,
     input: &'a I,
@@ -96,6 +214,9 @@ struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     max_length: Option<usize>,
     key_validator: &'a CombinedValidator,
     value_validator: &'a CombinedValidator,
+    // set when validating JSON or strings with tuple keys
+    tuple_keys: Option<&'a TupleKeys>,
+    required_keys: Option<&'a [Py<PyAny>]>,
     state: &'a mut ValidationState<'s, 'py>,
 }
 
@@ -105,15 +226,43 @@ where
     Key: BorrowInput<'py> + Clone + Into<LocItem>,
     Value: BorrowInput<'py>,
 {
-    type Output = ValResult<PyObject>;
-    fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<PyObject> {
+    type Output = ValResult<Bound<'py, PyDict>>;
+    fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> Self::Output {
         let output = PyDict::new_bound(self.py);
+        // valid keys, to find which required keys are missing
+        let seen_keys = match self.required_keys {
+            Some(_) => Some(PySet::empty_bound(self.py)?),
+            None => None,
+        };
         let mut errors: Vec<ValLineError> = Vec::new();
 
         for item_result in iterator {
+            self.state.check_deadline()?;
             let (key, value) = item_result?;
-            let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
-                Ok(value) => Some(value),
+            let key_str = match self.tuple_keys {
+                Some(_) => key
+                    .borrow_input()
+                    .validate_str(true, false)
+                    .ok()
+                    .map(ValidationMatch::into_inner),
+                None => None,
+            };
+            let key_str = key_str.as_ref().map(EitherString::as_cow).transpose()?;
+            let tuple_key = match (self.tuple_keys, &key_str) {
+                (Some(tuple_keys), Some(key_str)) => tuple_keys.parse(key_str),
+                _ => None,
+            };
+            let key_result = match tuple_key {
+                Some(ref tuple_key) => self.key_validator.validate(self.py, tuple_key, self.state),
+                None => self.key_validator.validate(self.py, key.borrow_input(), self.state),
+            };
+            let output_key = match key_result {
+                Ok(value) => {
+                    if let Some(ref seen_keys) = seen_keys {
+                        seen_keys.add(&value)?;
+                    }
+                    Some(value)
+                }
                 Err(ValError::LineErrors(line_errors)) => {
                     for err in line_errors {
                         // these are added in reverse order so [key] is shunted along by the second call
@@ -124,11 +273,15 @@ where
                 Err(ValError::Omit) => continue,
                 Err(err) => return Err(err),
             };
-            let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
+            let segment = PathSegment::Loc(key.clone().into());
+            let output_value = match self.state.with_path(segment, |state| {
+                self.value_validator.validate(self.py, value.borrow_input(), state)
+            }) {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
+                    let loc = typed_key_loc(self.py, output_key.as_ref()).unwrap_or_else(|| key.clone().into());
                     for err in line_errors {
-                        errors.push(err.with_outer_location(key.clone()));
+                        errors.push(err.with_outer_location(loc.clone()));
                     }
                     None
                 }
@@ -140,12 +293,36 @@ where
             }
         }
 
+        for key in self.required_keys.unwrap_or_default() {
+            if !seen_keys
+                .as_ref()
+                .map_or(Ok(true), |seen_keys| seen_keys.contains(key))?
+            {
+                errors.push(ValLineError::new_with_loc(
+                    ErrorTypeDefaults::Missing,
+                    self.input,
+                    required_key_loc(key.bind(self.py))?,
+                ));
+            }
+        }
+
         if errors.is_empty() {
             let input = self.input;
             length_check!(input, "Dictionary", self.min_length, self.max_length, output);
-            Ok(output.into())
+            Ok(output)
         } else {
             Err(ValError::LineErrors(errors))
         }
     }
 }
+
+/// Values are located by their validated key when it's a plain `str` or `int`, so e.g. the JSON key `"1"` of a
+/// `dict[int, X]` is located as `1`, like it is when validating python
+fn typed_key_loc(py: Python<'_>, output_key: Option<&PyObject>) -> Option<LocItem> {
+    let output_key = output_key?.bind(py);
+    if output_key.is_exact_instance_of::<PyString>() || output_key.is_exact_instance_of::<PyInt>() {
+        Some(output_key.into())
+    } else {
+        None
+    }
+}
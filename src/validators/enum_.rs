@@ -1,11 +1,14 @@
 // Validator for Enums, so named because "enum" is a reserved keyword in Rust.
 use std::marker::PhantomData;
+use std::str::FromStr;
 
 use pyo3::exceptions::PyTypeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFloat, PyInt, PyList, PyString, PyType};
 
+use ahash::AHashMap;
+
 use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
@@ -32,21 +35,52 @@ impl BuildValidator for BuildEnumValidator {
         }
 
         let py = schema.py();
+        let by = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "by"))? {
+            Some(by) => EnumBy::from_str(by.to_str()?)?,
+            None => EnumBy::Value,
+        };
+        let case_insensitive_names: bool = schema.get_as(intern!(py, "case_insensitive_names"))?.unwrap_or(false);
+
         let value_str = intern!(py, "value");
         let expected: Vec<(Bound<'_, PyAny>, PyObject)> = members
             .iter()
             .map(|v| Ok((v.getattr(value_str)?, v.into())))
             .collect::<PyResult<_>>()?;
-
-        let repr_args: Vec<String> = expected
+        let names: Vec<(Bound<'_, PyAny>, PyObject)> = members
             .iter()
-            .map(|(k, _)| k.repr()?.extract())
+            .map(|v| Ok((v.getattr(intern!(py, "name"))?, v.into())))
             .collect::<PyResult<_>>()?;
 
+        let mut repr_args: Vec<String> = Vec::new();
+        if by != EnumBy::Name {
+            for (k, _) in &expected {
+                repr_args.push(k.repr()?.extract()?);
+            }
+        }
+        if by != EnumBy::Value {
+            for (k, _) in &names {
+                repr_args.push(k.repr()?.extract()?);
+            }
+        }
+
         let class: Bound<PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let class_repr = class_repr(schema, &class)?;
+        let flag_members = FlagMembers::build(&class, &members, case_insensitive_names)?;
 
-        let lookup = LiteralLookup::new(py, expected.into_iter())?;
+        let lookup = match by {
+            EnumBy::Name => None,
+            _ => Some(LiteralLookup::new(py, expected.into_iter())?),
+        };
+        let name_lookup = match by {
+            EnumBy::Value => None,
+            _ => {
+                let name_lookup = LiteralLookup::new(py, names.into_iter())?;
+                Some(match case_insensitive_names {
+                    true => name_lookup.case_insensitive()?,
+                    false => name_lookup,
+                })
+            }
+        };
 
         macro_rules! build {
             ($vv:ty, $name_prefix:literal) => {
@@ -54,6 +88,8 @@ impl BuildValidator for BuildEnumValidator {
                     phantom: PhantomData::<$vv>,
                     class: class.clone().into(),
                     lookup,
+                    name_lookup,
+                    flag_members,
                     missing: schema.get_as(intern!(py, "missing"))?,
                     expected_repr: expected_repr_name(repr_args, "").0,
                     strict: is_strict(schema, config)?,
@@ -74,6 +110,27 @@ impl BuildValidator for BuildEnumValidator {
     }
 }
 
+/// Whether enum members are looked up by value, by name, or by value then by name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EnumBy {
+    Value,
+    Name,
+    Any,
+}
+
+impl FromStr for EnumBy {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "value" => Ok(Self::Value),
+            "name" => Ok(Self::Name),
+            "any" => Ok(Self::Any),
+            s => py_schema_err!("Invalid enum `by` value: {}, expected 'value', 'name' or 'any'", s),
+        }
+    }
+}
+
 pub trait EnumValidateValue: std::fmt::Debug + Clone + Send + Sync {
     fn validate_value<'py, I: Input<'py> + ?Sized>(
         py: Python<'py>,
@@ -87,7 +144,12 @@ pub trait EnumValidateValue: std::fmt::Debug + Clone + Send + Sync {
 pub struct EnumValidator<T: EnumValidateValue> {
     phantom: PhantomData<T>,
     class: Py<PyType>,
-    lookup: LiteralLookup<PyObject>,
+    // `None` when only looking up by name
+    lookup: Option<LiteralLookup<PyObject>>,
+    // looks up members by name, `None` when only looking up by value
+    name_lookup: Option<LiteralLookup<PyObject>>,
+    // set for `enum.Flag` subclasses, to validate combinations of members
+    flag_members: Option<FlagMembers>,
     missing: Option<PyObject>,
     expected_repr: String,
     strict: bool,
@@ -116,7 +178,13 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
                 },
                 input,
             ));
-        } else if let Some(v) = T::validate_value(py, input, &self.lookup, strict)? {
+        } else if let Some(v) = self.validate_value_or_name(py, input, strict)? {
+            state.floor_exactness(Exactness::Lax);
+            return Ok(v);
+        } else if let Some(v) = match self.flag_members {
+            Some(ref flag_members) => flag_members.validate(class, input)?,
+            None => None,
+        } {
             state.floor_exactness(Exactness::Lax);
             return Ok(v);
         } else if let Some(ref missing) = self.missing {
@@ -160,6 +228,132 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
     }
 }
 
+impl<T: EnumValidateValue> EnumValidator<T> {
+    fn validate_value_or_name<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        strict: bool,
+    ) -> ValResult<Option<PyObject>> {
+        if let Some(ref lookup) = self.lookup {
+            if let Some(v) = T::validate_value(py, input, lookup, strict)? {
+                return Ok(Some(v));
+            }
+        }
+        if let Some(ref name_lookup) = self.name_lookup {
+            return Ok(name_lookup.validate_str(input, strict)?.map(|v| v.clone_ref(py)));
+        }
+        Ok(None)
+    }
+}
+
+/// Members of an `enum.Flag` subclass, so combinations of them can be validated and serialized
+#[derive(Debug, Clone)]
+pub(crate) struct FlagMembers {
+    // all members by name, lowercased if `case_insensitive_names` is set
+    by_name: AHashMap<String, i64>,
+    case_insensitive_names: bool,
+    // members with a single bit set in definition order, other values are combinations of these
+    bits: Vec<(String, i64)>,
+    all_bits: i64,
+}
+
+impl FlagMembers {
+    /// `None` if `class` isn't a subclass of `enum.Flag`
+    pub fn build(
+        class: &Bound<'_, PyType>,
+        members: &Bound<'_, PyList>,
+        case_insensitive_names: bool,
+    ) -> PyResult<Option<Self>> {
+        let py = class.py();
+        let flag_type = py.import_bound(intern!(py, "enum"))?.getattr(intern!(py, "Flag"))?;
+        if !class.is_subclass(&flag_type)? {
+            return Ok(None);
+        }
+        let mut by_name = AHashMap::new();
+        let mut bits: Vec<(String, i64)> = Vec::new();
+        let mut all_bits = 0;
+        for member in members {
+            let name: String = member.getattr(intern!(py, "name"))?.extract()?;
+            let value = member.getattr(intern!(py, "value"))?;
+            let Some(int) = value.extract::<i64>().ok().filter(|int| *int >= 0) else {
+                return py_schema_err!(
+                    "Flag enum values must be non-negative ints that fit in 64 bits, got {} for {}",
+                    safe_repr(&value),
+                    name
+                );
+            };
+            if int.count_ones() == 1 && !bits.iter().any(|(_, bit)| *bit == int) {
+                bits.push((name.clone(), int));
+            }
+            all_bits |= int;
+            match case_insensitive_names {
+                true => by_name.insert(name.to_lowercase(), int),
+                false => by_name.insert(name, int),
+            };
+        }
+        Ok(Some(Self {
+            by_name,
+            case_insensitive_names,
+            bits,
+            all_bits,
+        }))
+    }
+
+    /// Validate an int which is a combination of members, a list of member names, or member names
+    /// separated by `|`
+    fn validate<'py>(
+        &self,
+        class: &Bound<'py, PyType>,
+        input: &(impl Input<'py> + ?Sized),
+    ) -> ValResult<Option<PyObject>> {
+        let py = class.py();
+        let value = if let Ok(either_int) = input.exact_int() {
+            match either_int.into_i64(py) {
+                Ok(int) if int >= 0 && int & !self.all_bits == 0 => int,
+                _ => return Ok(None),
+            }
+        } else {
+            let object = input.to_object(py).into_bound(py);
+            let names: Vec<String> = if let Ok(py_str) = object.downcast::<PyString>() {
+                py_str
+                    .to_str()?
+                    .split('|')
+                    .map(|name| name.trim().to_string())
+                    .collect()
+            } else if let Ok(list) = object.downcast::<PyList>() {
+                match list.iter().map(|name| name.extract()).collect::<PyResult<_>>() {
+                    Ok(names) => names,
+                    Err(_) => return Ok(None),
+                }
+            } else {
+                return Ok(None);
+            };
+            let mut value = 0;
+            for name in names {
+                let bits = match self.case_insensitive_names {
+                    true => self.by_name.get(&name.to_lowercase()),
+                    false => self.by_name.get(&name),
+                };
+                match bits {
+                    Some(bits) => value |= bits,
+                    None => return Ok(None),
+                }
+            }
+            value
+        };
+        Ok(Some(class.call1((value,))?.unbind()))
+    }
+
+    /// Names of the single bit members in `value`
+    pub fn names(&self, value: i64) -> impl Iterator<Item = &str> {
+        self.bits
+            .iter()
+            .filter(move |(_, bit)| value & bit == *bit)
+            .map(|(name, _)| name.as_str())
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct PlainEnumValidator;
 
@@ -0,0 +1,211 @@
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyBytes, PyDict, PyFloat, PyInt, PyList, PyString, PyTuple};
+
+/// A hash of `schema` and `config` which is the same for equal schemas, see `schemas_equal`, and is stable across
+/// processes for schemas of builtin data, functions and classes, other objects are hashed with python's `hash()`
+pub(super) fn schema_fingerprint(schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>) -> PyResult<u64> {
+    fingerprint(schema, config, false)
+}
+
+/// Like `schema_fingerprint` but including `metadata` and `serialization`, so any change to the schema's data
+/// changes it, used to detect schemas mutated after `freeze()`
+pub(crate) fn exact_schema_fingerprint(schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>) -> PyResult<u64> {
+    fingerprint(schema, config, true)
+}
+
+fn fingerprint(schema: &Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>, all_keys: bool) -> PyResult<u64> {
+    let mut hasher = DefaultHasher::new();
+    hash_value(schema, false, all_keys, &mut hasher)?;
+    match config {
+        Some(config) => hash_value(config.as_any(), false, all_keys, &mut hasher)?,
+        None => NONE.hash(&mut hasher),
+    }
+    Ok(hasher.finish())
+}
+
+/// Whether two schemas build the same validator, ignoring `metadata`, `serialization` and the order of keys in dicts,
+/// except the `fields` of typed dicts and models where the order decides the order of the output
+pub(super) fn schemas_equal(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<bool> {
+    values_equal(a, b, false)
+}
+
+// tags so values of different types with the same content, e.g. `[]` and `()`, hash differently
+const NONE: u8 = 0;
+const BOOL: u8 = 1;
+const INT: u8 = 2;
+const FLOAT: u8 = 3;
+const STR: u8 = 4;
+const BYTES: u8 = 5;
+const LIST: u8 = 6;
+const TUPLE: u8 = 7;
+const DICT: u8 = 8;
+const NAMED: u8 = 9;
+const OTHER: u8 = 10;
+
+fn hash_value(value: &Bound<'_, PyAny>, ordered: bool, all_keys: bool, hasher: &mut DefaultHasher) -> PyResult<()> {
+    let py = value.py();
+    if value.is_none() {
+        NONE.hash(hasher);
+    } else if let Ok(b) = value.downcast_exact::<PyBool>() {
+        BOOL.hash(hasher);
+        b.is_true().hash(hasher);
+    } else if let Ok(i) = value.downcast_exact::<PyInt>() {
+        INT.hash(hasher);
+        match i.extract::<i64>() {
+            Ok(i) => i.hash(hasher),
+            Err(_) => i.str()?.to_str()?.hash(hasher),
+        }
+    } else if let Ok(f) = value.downcast_exact::<PyFloat>() {
+        FLOAT.hash(hasher);
+        // `-0.0 == 0.0`, so they must hash the same
+        let f = if f.value() == 0.0 { 0.0 } else { f.value() };
+        f.to_bits().hash(hasher);
+    } else if let Ok(s) = value.downcast_exact::<PyString>() {
+        STR.hash(hasher);
+        s.to_str()?.hash(hasher);
+    } else if let Ok(b) = value.downcast_exact::<PyBytes>() {
+        BYTES.hash(hasher);
+        b.as_bytes().hash(hasher);
+    } else if let Ok(list) = value.downcast_exact::<PyList>() {
+        LIST.hash(hasher);
+        list.len().hash(hasher);
+        for item in list {
+            hash_value(&item, false, all_keys, hasher)?;
+        }
+    } else if let Ok(tuple) = value.downcast_exact::<PyTuple>() {
+        TUPLE.hash(hasher);
+        tuple.len().hash(hasher);
+        for item in tuple {
+            hash_value(&item, false, all_keys, hasher)?;
+        }
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        DICT.hash(hasher);
+        let is_schema = is_schema(dict)?;
+        let mut entries = Vec::with_capacity(dict.len());
+        for (key, item) in dict {
+            if is_schema && !all_keys && is_ignored_key(&key)? {
+                continue;
+            }
+            let mut entry_hasher = DefaultHasher::new();
+            hash_value(&key, false, all_keys, &mut entry_hasher)?;
+            let ordered = is_schema && key.eq(intern!(py, "fields"))?;
+            hash_value(&item, ordered, all_keys, &mut entry_hasher)?;
+            entries.push(entry_hasher.finish());
+        }
+        if !ordered {
+            entries.sort_unstable();
+        }
+        entries.hash(hasher);
+    } else if let Some((module, qualname)) = qualified_name(value) {
+        // functions and classes are hashed by name rather than identity, so the hash is the same in each process
+        NAMED.hash(hasher);
+        module.hash(hasher);
+        qualname.hash(hasher);
+    } else {
+        OTHER.hash(hasher);
+        qualified_name(value.get_type().as_any()).hash(hasher);
+        match value.hash() {
+            Ok(h) => h.hash(hasher),
+            Err(_) => value.as_ptr().hash(hasher),
+        }
+    }
+    Ok(())
+}
+
+fn values_equal(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>, ordered: bool) -> PyResult<bool> {
+    let py = a.py();
+    if a.is(b) {
+        return Ok(true);
+    }
+    // `1`, `1.0` and `True` are equal in python but may build different validators
+    if !a.get_type().is(&b.get_type()) {
+        return Ok(false);
+    }
+    if let (Ok(a), Ok(b)) = (a.downcast::<PyList>(), b.downcast::<PyList>()) {
+        sequences_equal(a.iter(), b.iter(), a.len() == b.len())
+    } else if let (Ok(a), Ok(b)) = (a.downcast::<PyTuple>(), b.downcast::<PyTuple>()) {
+        sequences_equal(a.iter(), b.iter(), a.len() == b.len())
+    } else if let (Ok(a), Ok(b)) = (a.downcast::<PyDict>(), b.downcast::<PyDict>()) {
+        let is_schema = is_schema(a)?;
+        let a_items = relevant_items(a, is_schema)?;
+        let b_items = relevant_items(b, is_schema)?;
+        if a_items.len() != b_items.len() {
+            return Ok(false);
+        }
+        for (index, (key, a_item)) in a_items.iter().enumerate() {
+            let b_item = if ordered {
+                match &b_items[index] {
+                    (b_key, b_item) if b_key.eq(key)? => Some(b_item),
+                    _ => None,
+                }
+            } else {
+                b_items
+                    .iter()
+                    .find(|(b_key, _)| b_key.eq(key).unwrap_or(false))
+                    .map(|(_, v)| v)
+            };
+            let fields = is_schema && key.eq(intern!(py, "fields"))?;
+            match b_item {
+                Some(b_item) if values_equal(a_item, b_item, fields)? => (),
+                _ => return Ok(false),
+            }
+        }
+        Ok(true)
+    } else {
+        a.eq(b)
+    }
+}
+
+fn sequences_equal<'py>(
+    a: impl Iterator<Item = Bound<'py, PyAny>>,
+    mut b: impl Iterator<Item = Bound<'py, PyAny>>,
+    same_len: bool,
+) -> PyResult<bool> {
+    if !same_len {
+        return Ok(false);
+    }
+    for a_item in a {
+        match b.next() {
+            Some(b_item) if values_equal(&a_item, &b_item, false)? => (),
+            _ => return Ok(false),
+        }
+    }
+    Ok(true)
+}
+
+fn relevant_items<'py>(
+    dict: &Bound<'py, PyDict>,
+    is_schema: bool,
+) -> PyResult<Vec<(Bound<'py, PyAny>, Bound<'py, PyAny>)>> {
+    let mut items = Vec::with_capacity(dict.len());
+    for (key, item) in dict {
+        if !(is_schema && is_ignored_key(&key)?) {
+            items.push((key, item));
+        }
+    }
+    Ok(items)
+}
+
+/// Whether `dict` is a schema, rather than e.g. the `fields` of a typed dict, which could have a field named `type`
+fn is_schema(dict: &Bound<'_, PyDict>) -> PyResult<bool> {
+    Ok(dict
+        .get_item(intern!(dict.py(), "type"))?
+        .is_some_and(|t| t.is_instance_of::<PyString>()))
+}
+
+// neither changes how input is validated
+fn is_ignored_key(key: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let py = key.py();
+    Ok(key.eq(intern!(py, "metadata"))? || key.eq(intern!(py, "serialization"))?)
+}
+
+fn qualified_name(value: &Bound<'_, PyAny>) -> Option<(String, String)> {
+    let py = value.py();
+    let module = value.getattr(intern!(py, "__module__")).ok()?.extract().ok()?;
+    let qualname = value.getattr(intern!(py, "__qualname__")).ok()?.extract().ok()?;
+    Some((module, qualname))
+}
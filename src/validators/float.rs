@@ -4,11 +4,13 @@ use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::build_tools::{is_strict, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, schema_or_config_same};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::Input;
+use crate::input::{EitherFloat, Input, ValidationMatch};
+use crate::serializers::InfNanMode;
 use crate::tools::SchemaDict;
 
+use super::numpy::{numpy_item, numpy_support};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 pub struct FloatBuilder;
@@ -32,16 +34,51 @@ impl BuildValidator for FloatBuilder {
             Ok(FloatValidator {
                 strict: is_strict(schema, config)?,
                 allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+                inf_nan_strings: inf_nan_strings(schema, config)?,
+                numpy_support: numpy_support(config)?,
             }
             .into())
         }
     }
 }
 
+/// Whether `val_json_inf_nan` is `'strings'`, so the strings written by `ser_json_inf_nan='strings'` round-trip
+fn inf_nan_strings(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+    match schema_or_config_same(schema, config, intern!(schema.py(), "val_json_inf_nan"))? {
+        Some(InfNanMode::Null) => py_schema_err!("`val_json_inf_nan` should be 'constants' or 'strings'"),
+        mode => Ok(mode == Some(InfNanMode::Strings)),
+    }
+}
+
+/// Validate a float, with `inf_nan_strings` taking the `"Infinity"`, `"-Infinity"` and `"NaN"` strings as floats
+/// when validating JSON or strings, even in strict mode
+fn validate_float<'a, 'py>(
+    input: &'a (impl Input<'py> + ?Sized),
+    strict: bool,
+    inf_nan_strings: bool,
+) -> ValResult<ValidationMatch<EitherFloat<'a>>> {
+    if inf_nan_strings && input.as_python().is_none() {
+        if let Ok(either_str) = input.exact_str() {
+            let float = match either_str.as_cow()?.as_ref() {
+                "Infinity" => Some(f64::INFINITY),
+                "-Infinity" => Some(f64::NEG_INFINITY),
+                "NaN" => Some(f64::NAN),
+                _ => None,
+            };
+            if let Some(float) = float {
+                return Ok(ValidationMatch::lax(EitherFloat::F64(float)));
+            }
+        }
+    }
+    input.validate_float(strict)
+}
+
 #[derive(Debug, Clone)]
 pub struct FloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    inf_nan_strings: bool,
+    numpy_support: bool,
 }
 
 impl BuildValidator for FloatValidator {
@@ -56,6 +93,8 @@ impl BuildValidator for FloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            inf_nan_strings: inf_nan_strings(schema, config)?,
+            numpy_support: numpy_support(config)?,
         }
         .into())
     }
@@ -70,7 +109,13 @@ impl Validator for FloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        if self.numpy_support {
+            if let Some(item) = numpy_item(input, strict, state)? {
+                return self.validate(py, &item, state);
+            }
+        }
+        let either_float = validate_float(input, strict, self.inf_nan_strings)?.unpack(state, input, "float");
         if !self.allow_inf_nan && !either_float.as_f64().is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
         }
@@ -86,6 +131,8 @@ impl Validator for FloatValidator {
 pub struct ConstrainedFloatValidator {
     strict: bool,
     allow_inf_nan: bool,
+    inf_nan_strings: bool,
+    numpy_support: bool,
     multiple_of: Option<f64>,
     le: Option<f64>,
     lt: Option<f64>,
@@ -102,7 +149,13 @@ impl Validator for ConstrainedFloatValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_float = input.validate_float(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        if self.numpy_support {
+            if let Some(item) = numpy_item(input, strict, state)? {
+                return self.validate(py, &item, state);
+            }
+        }
+        let either_float = validate_float(input, strict, self.inf_nan_strings)?.unpack(state, input, "float");
         let float: f64 = either_float.as_f64();
         if !self.allow_inf_nan && !float.is_finite() {
             return Err(ValError::new(ErrorTypeDefaults::FiniteNumber, input));
@@ -183,6 +236,8 @@ impl BuildValidator for ConstrainedFloatValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             allow_inf_nan: schema_or_config_same(schema, config, intern!(py, "allow_inf_nan"))?.unwrap_or(true),
+            inf_nan_strings: inf_nan_strings(schema, config)?,
+            numpy_support: numpy_support(config)?,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
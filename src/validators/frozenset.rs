@@ -1,12 +1,12 @@
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyFrozenSet};
+use pyo3::types::{PyDict, PyFrozenSet, PyTuple};
 
 use crate::errors::ValResult;
-use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
+use crate::input::{Input, MaxErrorItems, OrderedSetBuilder, ValidatedSet};
 use crate::tools::SchemaDict;
 
 use super::list::min_length_check;
-use super::set::set_build;
+use super::set::{set_build, ValidateToSet};
 use super::validation_state::ValidationState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 
@@ -17,7 +17,9 @@ pub struct FrozenSetValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: String,
-    fail_fast: bool,
+    max_error_items: MaxErrorItems,
+    // the unique items are returned in the order they're first seen, as a tuple
+    ordered: bool,
 }
 
 impl BuildValidator for FrozenSetValidator {
@@ -34,16 +36,35 @@ impl Validator for FrozenSetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
+        let collection = input
+            .validate_frozenset(state.strict_or(self.strict))?
+            .unpack(state, input, "frozenset");
+        if self.ordered {
+            let ordered = OrderedSetBuilder::new(py)?;
+            collection.iterate(ValidateToSet {
+                py,
+                input,
+                set: &ordered,
+                field_type: "Frozenset",
+                max_length: self.max_length,
+                item_validator: &self.item_validator,
+                state,
+                max_error_items: self.max_error_items,
+            })??;
+            let items = ordered.into_list();
+            min_length_check!(input, "Frozenset", self.min_length, items);
+            return Ok(PyTuple::new_bound(py, items).into_py(py));
+        }
         let f_set = PyFrozenSet::empty_bound(py)?;
-        collection.iterate(ValidateToFrozenSet {
+        collection.iterate(ValidateToSet {
             py,
             input,
-            f_set: &f_set,
+            set: &f_set,
+            field_type: "Frozenset",
             max_length: self.max_length,
             item_validator: &self.item_validator,
             state,
-            fail_fast: self.fail_fast,
+            max_error_items: self.max_error_items,
         })??;
         min_length_check!(input, "Frozenset", self.min_length, f_set);
         Ok(f_set.into_py(py))
@@ -53,34 +74,3 @@ impl Validator for FrozenSetValidator {
         &self.name
     }
 }
-
-struct ValidateToFrozenSet<'a, 's, 'py, I: Input<'py> + ?Sized> {
-    py: Python<'py>,
-    input: &'a I,
-    f_set: &'a Bound<'py, PyFrozenSet>,
-    max_length: Option<usize>,
-    item_validator: &'a CombinedValidator,
-    state: &'a mut ValidationState<'s, 'py>,
-    fail_fast: bool,
-}
-
-impl<'py, T, I> ConsumeIterator<PyResult<T>> for ValidateToFrozenSet<'_, '_, 'py, I>
-where
-    T: BorrowInput<'py>,
-    I: Input<'py> + ?Sized,
-{
-    type Output = ValResult<()>;
-    fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<()> {
-        validate_iter_to_set(
-            self.py,
-            self.f_set,
-            iterator,
-            self.input,
-            "Frozenset",
-            self.max_length,
-            self.item_validator,
-            self.state,
-            self.fail_fast,
-        )
-    }
-}
@@ -1,23 +1,23 @@
 use std::sync::Arc;
 
-use pyo3::exceptions::{PyAssertionError, PyValueError};
+use pyo3::exceptions::{PyAssertionError, PyTypeError, PyUserWarning, PyValueError, PyWarning};
 use pyo3::prelude::*;
-use pyo3::types::{PyAny, PyDict, PyString};
+use pyo3::types::{PyAny, PyDict, PyList, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::{
-    ErrorType, PydanticCustomError, PydanticKnownError, PydanticOmit, ToErrorValue, ValError, ValResult,
-    ValidationError,
+    ErrorType, PydanticCustomError, PydanticKnownError, PydanticMultipleErrors, PydanticOmit, ToErrorValue, ValError,
+    ValResult, ValidationError,
 };
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
 use crate::tools::{function_name, safe_repr, SchemaDict};
 use crate::PydanticUseDefault;
 
+use super::function_cache::FunctionCache;
 use super::generator::InternalValidator;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, InputType, ValidationState,
-    Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, InputType, ValidationState, Validator,
 };
 
 struct FunctionInfo {
@@ -45,7 +45,7 @@ fn destructure_function_schema(schema: &Bound<'_, PyDict>) -> PyResult<FunctionI
 }
 
 macro_rules! impl_build {
-    ($impl_name:ident, $name:literal) => {
+    ($impl_name:ident, $name:literal $(, $cache:ident)?) => {
         impl BuildValidator for $impl_name {
             const EXPECTED_TYPE: &'static str = $name;
             fn build(
@@ -72,6 +72,7 @@ macro_rules! impl_build {
                     name,
                     field_name: func_info.field_name,
                     info_arg: func_info.info_arg,
+                    $($cache: FunctionCache::from_schema(schema, func_info.info_arg)?,)?
                 }
                 .into())
             }
@@ -92,6 +93,11 @@ pub struct FunctionBeforeValidator {
 impl_build!(FunctionBeforeValidator, "function-before");
 
 impl FunctionBeforeValidator {
+    /// The validator this one wraps
+    pub(super) fn inner_validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
     fn _validate<'s, 'py>(
         &'s self,
         call: impl FnOnce(Bound<'py, PyAny>, &mut ValidationState<'_, 'py>) -> ValResult<PyObject>,
@@ -99,8 +105,9 @@ impl FunctionBeforeValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &'s mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        state.count_python_callback()?;
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
             self.func.call1(py, (input.to_object(py), info))
         } else {
             self.func.call1(py, (input.to_object(py),))
@@ -140,6 +147,18 @@ impl Validator for FunctionBeforeValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn validate_assignment_many<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        values: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let validate =
+            move |v, s: &mut ValidationState<'_, 'py>| self.validator.validate_assignment_many(py, &v, values, s);
+        self._validate(validate, py, obj, state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -153,11 +172,17 @@ pub struct FunctionAfterValidator {
     name: String,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
+    cache: Option<Arc<FunctionCache>>,
 }
 
-impl_build!(FunctionAfterValidator, "function-after");
+impl_build!(FunctionAfterValidator, "function-after", cache);
 
 impl FunctionAfterValidator {
+    /// The validator this one wraps
+    pub(super) fn inner_validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
     fn _validate<'py, I: Input<'py> + ?Sized>(
         &self,
         call: impl FnOnce(&I, &mut ValidationState<'_, 'py>) -> ValResult<PyObject>,
@@ -166,11 +191,36 @@ impl FunctionAfterValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let v = call(input, state)?;
+        self.call_func(py, v, input, state)
+    }
+
+    /// Call the function with `v`, the output of the inner validator for `input`
+    pub(super) fn call_func<'py>(
+        &self,
+        py: Python<'py>,
+        v: PyObject,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match self.cache {
+            Some(ref cache) => cache.get_or_call(v.bind(py), || self.call_func_uncached(py, &v, input, state)),
+            None => self.call_func_uncached(py, &v, input, state),
+        }
+    }
+
+    fn call_func_uncached<'py>(
+        &self,
+        py: Python<'py>,
+        v: &PyObject,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        state.count_python_callback()?;
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
-            self.func.call1(py, (v.to_object(py), info))
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
+            self.func.call1(py, (v, info))
         } else {
-            self.func.call1(py, (v.to_object(py),))
+            self.func.call1(py, (v,))
         };
         r.map_err(|e| convert_err(py, e, input))
     }
@@ -179,7 +229,8 @@ impl FunctionAfterValidator {
 impl_py_gc_traverse!(FunctionAfterValidator {
     validator,
     func,
-    config
+    config,
+    cache
 });
 
 impl Validator for FunctionAfterValidator {
@@ -206,6 +257,19 @@ impl Validator for FunctionAfterValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn validate_assignment_many<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        values: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let validate = move |v: &Bound<'py, PyAny>, s: &mut ValidationState<'_, 'py>| {
+            self.validator.validate_assignment_many(py, v, values, s)
+        };
+        self._validate(validate, py, obj, state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -218,6 +282,7 @@ pub struct FunctionPlainValidator {
     name: String,
     field_name: Option<Py<PyString>>,
     info_arg: bool,
+    cache: Option<Arc<FunctionCache>>,
 }
 
 impl BuildValidator for FunctionPlainValidator {
@@ -239,28 +304,48 @@ impl BuildValidator for FunctionPlainValidator {
             name: format!("function-plain[{}()]", function_name(function_info.function.bind(py))?),
             field_name: function_info.field_name.clone(),
             info_arg: function_info.info_arg,
+            cache: FunctionCache::from_schema(schema, function_info.info_arg)?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(FunctionPlainValidator { func, config });
+impl_py_gc_traverse!(FunctionPlainValidator { func, config, cache });
 
-impl Validator for FunctionPlainValidator {
-    fn validate<'py>(
+impl FunctionPlainValidator {
+    fn call_func<'py>(
         &self,
         py: Python<'py>,
+        input_value: &PyObject,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        state.count_python_callback()?;
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
-            self.func.call1(py, (input.to_object(py), info))
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
+            self.func.call1(py, (input_value, info))
         } else {
-            self.func.call1(py, (input.to_object(py),))
+            self.func.call1(py, (input_value,))
         };
         r.map_err(|e| convert_err(py, e, input))
     }
+}
+
+impl Validator for FunctionPlainValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let input_value = input.to_object(py);
+        match self.cache {
+            Some(ref cache) => {
+                cache.get_or_call(input_value.bind(py), || self.call_func(py, &input_value, input, state))
+            }
+            None => self.call_func(py, &input_value, input, state),
+        }
+    }
 
     fn get_name(&self) -> &str {
         &self.name
@@ -310,6 +395,11 @@ impl BuildValidator for FunctionWrapValidator {
 }
 
 impl FunctionWrapValidator {
+    /// The validator this one wraps
+    pub(super) fn inner_validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
     fn _validate<'py>(
         &self,
         handler: &Bound<'_, PyAny>,
@@ -317,14 +407,34 @@ impl FunctionWrapValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        state.count_python_callback()?;
         let r = if self.info_arg {
-            let info = ValidationInfo::new(py, state.extra(), &self.config, self.field_name.clone());
+            let info = ValidationInfo::new(py, state, &self.config, self.field_name.clone());
             self.func.call1(py, (input.to_object(py), handler, info))
         } else {
             self.func.call1(py, (input.to_object(py), handler))
         };
         r.map_err(|e| convert_err(py, e, input))
     }
+
+    fn assignment_handler(
+        &self,
+        py: Python,
+        assignment: Assignment,
+        state: &mut ValidationState<'_, '_>,
+    ) -> AssignmentValidatorCallable {
+        AssignmentValidatorCallable {
+            validator: InternalValidator::new(
+                py,
+                "AssignmentValidatorCallable",
+                self.validator.clone(),
+                state,
+                self.hide_input_in_errors,
+                self.validation_error_cause,
+            ),
+            assignment,
+        }
+    }
 }
 
 impl_py_gc_traverse!(FunctionWrapValidator {
@@ -352,7 +462,9 @@ impl Validator for FunctionWrapValidator {
         };
         let handler = Bound::new(py, handler)?;
         let result = self._validate(handler.as_any(), py, input, state);
-        state.exactness = handler.borrow_mut().validator.exactness;
+        let mut handler = handler.borrow_mut();
+        state.exactness = handler.validator.exactness;
+        state.lax_report = handler.validator.lax_report.take();
         result
     }
 
@@ -364,18 +476,19 @@ impl Validator for FunctionWrapValidator {
         field_value: &Bound<'py, PyAny>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let handler = AssignmentValidatorCallable {
-            validator: InternalValidator::new(
-                py,
-                "AssignmentValidatorCallable",
-                self.validator.clone(),
-                state,
-                self.hide_input_in_errors,
-                self.validation_error_cause,
-            ),
-            updated_field_name: field_name.to_string(),
-            updated_field_value: field_value.to_object(py),
-        };
+        let assignment = Assignment::Field(field_name.to_string(), field_value.to_object(py));
+        let handler = self.assignment_handler(py, assignment, state);
+        self._validate(Bound::new(py, handler)?.as_any(), py, obj, state)
+    }
+
+    fn validate_assignment_many<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        values: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let handler = self.assignment_handler(py, Assignment::Fields(values.clone().unbind()), state);
         self._validate(Bound::new(py, handler)?.as_any(), py, obj, state)
     }
 
@@ -416,11 +529,17 @@ impl ValidatorCallable {
     }
 }
 
+/// The assignment the handler of a wrap validator validates
+#[derive(Debug)]
+enum Assignment {
+    Field(String, Py<PyAny>),
+    Fields(Py<PyDict>),
+}
+
 #[pyclass(module = "pydantic_core._pydantic_core")]
 #[derive(Debug)]
 struct AssignmentValidatorCallable {
-    updated_field_name: String,
-    updated_field_value: Py<PyAny>,
+    assignment: Assignment,
     validator: InternalValidator,
 }
 
@@ -434,13 +553,16 @@ impl AssignmentValidatorCallable {
         outer_location: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
         let outer_location = outer_location.map(Into::into);
-        self.validator.validate_assignment(
-            py,
-            input_value,
-            self.updated_field_name.as_str(),
-            self.updated_field_value.bind(py),
-            outer_location,
-        )
+        match self.assignment {
+            Assignment::Field(ref field_name, ref field_value) => {
+                self.validator
+                    .validate_assignment(py, input_value, field_name, field_value.bind(py), outer_location)
+            }
+            Assignment::Fields(ref values) => {
+                self.validator
+                    .validate_assignment_many(py, input_value, values.bind(py), outer_location)
+            }
+        }
     }
 
     fn __repr__(&self) -> String {
@@ -479,6 +601,8 @@ pub fn convert_err(py: Python<'_>, err: PyErr, input: impl ToErrorValue) -> ValE
             pydantic_value_error.into_val_error(input)
         } else if let Ok(pydantic_error_type) = error_value.extract::<PydanticKnownError>() {
             pydantic_error_type.into_val_error(input)
+        } else if let Ok(multiple_errors) = error_value.extract::<PydanticMultipleErrors>() {
+            multiple_errors.into_val_error(input)
         } else if let Ok(validation_error) = err.value_bound(py).extract::<ValidationError>() {
             validation_error.into_val_error()
         } else {
@@ -495,23 +619,42 @@ pub fn convert_err(py: Python<'_>, err: PyErr, input: impl ToErrorValue) -> ValE
     }
 }
 
-#[pyclass(module = "pydantic_core._pydantic_core", get_all)]
+#[pyclass(module = "pydantic_core._pydantic_core")]
 pub struct ValidationInfo {
+    #[pyo3(get)]
     config: PyObject,
+    #[pyo3(get)]
     context: Option<PyObject>,
+    #[pyo3(get)]
     data: Option<Py<PyDict>>,
+    #[pyo3(get)]
     field_name: Option<Py<PyString>>,
+    #[pyo3(get)]
     mode: InputType,
+    // where the value being validated is, e.g. `('items', 2)` for the third item of the `items` field
+    #[pyo3(get)]
+    path: Py<PyTuple>,
+    // shared with the validation state, see `ValidationState.warnings`
+    warnings: Py<PyList>,
 }
 
 impl ValidationInfo {
-    fn new(py: Python, extra: &Extra, config: &PyObject, field_name: Option<Py<PyString>>) -> Self {
+    fn new<'py>(
+        py: Python<'py>,
+        state: &mut ValidationState<'_, 'py>,
+        config: &PyObject,
+        field_name: Option<Py<PyString>>,
+    ) -> Self {
+        let warnings = state.warnings(py).clone_ref(py);
+        let extra = state.extra();
         Self {
             config: config.clone_ref(py),
             context: extra.context.map(|ctx| ctx.clone().into()),
             field_name,
             data: extra.data.as_ref().map(|data| data.clone().into()),
             mode: extra.input_type,
+            path: state.path_to_py(py).unbind(),
+            warnings,
         }
     }
 
@@ -530,6 +673,17 @@ impl ValidationInfo {
 
 #[pymethods]
 impl ValidationInfo {
+    /// Emit a warning once validation succeeds, identical warnings from the same validation are emitted once
+    #[pyo3(signature = (message, category = None))]
+    fn warn(&self, py: Python, message: String, category: Option<Bound<'_, PyType>>) -> PyResult<()> {
+        let category = match category {
+            Some(category) if category.is_subclass_of::<PyWarning>()? => category,
+            Some(_) => return Err(PyTypeError::new_err("`category` must be a subclass of `Warning`")),
+            None => py.get_type_bound::<PyUserWarning>(),
+        };
+        self.warnings.bind(py).append((category, message))
+    }
+
     fn __repr__(&self, py: Python) -> PyResult<String> {
         let context = match self.context {
             Some(ref context) => safe_repr(context.bind(py)).to_string(),
@@ -0,0 +1,205 @@
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use ahash::AHashMap;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::ValResult;
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+const DEFAULT_CACHE_SIZE: usize = 1024;
+
+/// How `FunctionCache` decides whether an input has been seen before
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CacheMode {
+    // inputs of the same type which are equal
+    ByValue,
+    // inputs with the same hash, they aren't compared
+    ByHash,
+}
+
+/// The outputs of a function validator for the inputs it was most recently called with, from the `cache` schema
+/// option, the least recently used output is evicted when full.
+///
+/// Cached outputs are returned as they are, not copied, so a mutable output is shared by every input it's returned for.
+#[derive(Debug)]
+pub struct FunctionCache {
+    mode: CacheMode,
+    size: usize,
+    entries: Mutex<CacheEntries>,
+}
+
+/// The cached entries, in a list ordered from most to least recently used linked through indexes into `entries`
+#[derive(Debug, Default)]
+struct CacheEntries {
+    entries: Vec<CacheEntry>,
+    // index in `entries` by the hash of the input, inputs with the same hash replace each other
+    by_hash: AHashMap<isize, usize>,
+    most_recent: Option<usize>,
+    least_recent: Option<usize>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    hash: isize,
+    input: PyObject,
+    output: PyObject,
+    more_recent: Option<usize>,
+    less_recent: Option<usize>,
+}
+
+impl CacheEntries {
+    fn unlink(&mut self, index: usize) {
+        let CacheEntry {
+            more_recent,
+            less_recent,
+            ..
+        } = self.entries[index];
+        match more_recent {
+            Some(more_recent) => self.entries[more_recent].less_recent = less_recent,
+            None => self.most_recent = less_recent,
+        }
+        match less_recent {
+            Some(less_recent) => self.entries[less_recent].more_recent = more_recent,
+            None => self.least_recent = more_recent,
+        }
+    }
+
+    fn push_most_recent(&mut self, index: usize) {
+        let entry = &mut self.entries[index];
+        entry.more_recent = None;
+        entry.less_recent = self.most_recent;
+        match self.most_recent {
+            Some(most_recent) => self.entries[most_recent].more_recent = Some(index),
+            None => self.least_recent = Some(index),
+        }
+        self.most_recent = Some(index);
+    }
+
+    fn mark_used(&mut self, index: usize) {
+        if self.most_recent != Some(index) {
+            self.unlink(index);
+            self.push_most_recent(index);
+        }
+    }
+
+    /// Cache `output` for `input`, replacing an input with the same hash, otherwise the least recently used input if
+    /// there are already `size` inputs
+    fn insert(&mut self, size: usize, hash: isize, input: PyObject, output: PyObject) {
+        let index = match self.by_hash.get(&hash) {
+            Some(&index) => index,
+            None if self.entries.len() < size => {
+                self.entries.push(CacheEntry {
+                    hash,
+                    input,
+                    output,
+                    more_recent: None,
+                    less_recent: None,
+                });
+                let index = self.entries.len() - 1;
+                self.by_hash.insert(hash, index);
+                self.push_most_recent(index);
+                return;
+            }
+            None => {
+                let index = self
+                    .least_recent
+                    .expect("`size` is at least one, so a full cache isn't empty");
+                self.by_hash.remove(&self.entries[index].hash);
+                self.by_hash.insert(hash, index);
+                index
+            }
+        };
+        let entry = &mut self.entries[index];
+        entry.hash = hash;
+        entry.input = input;
+        entry.output = output;
+        self.mark_used(index);
+    }
+}
+
+impl FunctionCache {
+    /// The cache from the `cache` and `cache_size` schema options, functions taking `info` can't be cached since their
+    /// output may depend on it
+    pub fn from_schema(schema: &Bound<'_, PyDict>, info_arg: bool) -> PyResult<Option<Arc<Self>>> {
+        let py = schema.py();
+        let mode = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "cache"))? {
+            Some(mode) => match mode.to_str()? {
+                "by_value" => CacheMode::ByValue,
+                "by_hash" => CacheMode::ByHash,
+                mode => return py_schema_err!("Invalid cache mode: {}", mode),
+            },
+            None => return Ok(None),
+        };
+        if info_arg {
+            return py_schema_err!(
+                "`cache` can't be used with a `with-info` function, its output may depend on `info`"
+            );
+        }
+        let size = schema.get_as(intern!(py, "cache_size"))?.unwrap_or(DEFAULT_CACHE_SIZE);
+        if size == 0 {
+            return py_schema_err!("`cache_size` must be greater than {}", 0);
+        }
+        Ok(Some(Arc::new(Self {
+            mode,
+            size,
+            entries: Mutex::default(),
+        })))
+    }
+
+    /// The output cached for `input`, otherwise call `call` and cache its output if it succeeds, unhashable inputs
+    /// aren't cached
+    pub fn get_or_call(
+        &self,
+        input: &Bound<'_, PyAny>,
+        call: impl FnOnce() -> ValResult<PyObject>,
+    ) -> ValResult<PyObject> {
+        let py = input.py();
+        let Ok(hash) = input.hash() else {
+            return call();
+        };
+        let cached = {
+            let mut entries = self.lock();
+            entries.by_hash.get(&hash).copied().map(|index| {
+                entries.mark_used(index);
+                let entry = &entries.entries[index];
+                (entry.input.clone_ref(py), entry.output.clone_ref(py))
+            })
+        };
+        if let Some((cached_input, output)) = cached {
+            // compared without the lock held since `__eq__` may call python code
+            let cached_input = cached_input.bind(py);
+            if self.mode == CacheMode::ByHash
+                || (cached_input.get_type().is(&input.get_type()) && cached_input.eq(input).unwrap_or(false))
+            {
+                return Ok(output);
+            }
+        }
+
+        let output = call()?;
+        self.lock()
+            .insert(self.size, hash, input.clone().unbind(), output.clone_ref(py));
+        Ok(output)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, CacheEntries> {
+        self.entries.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl PyGcTraverse for FunctionCache {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        // entries are only locked with the GIL held, so the lock is only busy on free-threaded builds
+        if let Ok(entries) = self.entries.try_lock() {
+            for entry in &entries.entries {
+                visit.call(&entry.input)?;
+                visit.call(&entry.output)?;
+            }
+        }
+        Ok(())
+    }
+}
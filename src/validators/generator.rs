@@ -1,7 +1,7 @@
 use std::fmt;
 use std::sync::Arc;
 
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList};
 use pyo3::{prelude::*, PyTraverseError, PyVisit};
 
 use crate::errors::{ErrorType, LocItem, ValError, ValResult};
@@ -12,8 +12,10 @@ use crate::tools::SchemaDict;
 use crate::ValidationError;
 
 use super::list::get_items_schema;
+use super::stats::ValidatorStats;
 use super::{
-    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, ValidationState, Validator,
+    emit_warnings, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, LaxReport,
+    PathSegment, Tracer, ValidationState, Validator,
 };
 
 #[derive(Debug, Clone)]
@@ -146,9 +148,10 @@ impl ValidatorIterator {
                                     ));
                                 }
                             }
-                            validator
-                                .validate(py, next.borrow_input(), Some(index.into()))
-                                .map(Some)
+                            let output = validator.validate(py, next.borrow_input(), Some(index.into()))?;
+                            // items are validated after the validation the generator is from has finished
+                            validator.emit_warnings(py)?;
+                            Ok(Some(output))
                         }
                         None => Ok(Some(next.to_object(py))),
                     },
@@ -227,6 +230,14 @@ pub struct InternalValidator {
     hide_input_in_errors: bool,
     validation_error_cause: bool,
     cache_str: jiter::StringCacheMode,
+    cache_utf8: bool,
+    tracer: Option<Tracer>,
+    pub(crate) lax_report: Option<LaxReport>,
+    union_debug: Option<Py<PyList>>,
+    field_path: Vec<String>,
+    path: Vec<PathSegment>,
+    warnings: Py<PyList>,
+    stats: Option<Arc<ValidatorStats>>,
 }
 
 impl fmt::Debug for InternalValidator {
@@ -240,10 +251,12 @@ impl InternalValidator {
         py: Python,
         name: &str,
         validator: Arc<CombinedValidator>,
-        state: &ValidationState,
+        state: &mut ValidationState,
         hide_input_in_errors: bool,
         validation_error_cause: bool,
     ) -> Self {
+        // shared so warnings are emitted with those from the rest of validation
+        let warnings = state.warnings(py).clone_ref(py);
         let extra = state.extra();
         Self {
             name: name.to_string(),
@@ -259,9 +272,22 @@ impl InternalValidator {
             hide_input_in_errors,
             validation_error_cause,
             cache_str: extra.cache_str,
+            cache_utf8: extra.cache_utf8,
+            tracer: state.tracer.as_ref().map(|tracer| tracer.clone_ref(py)),
+            lax_report: state.lax_report.clone(),
+            union_debug: state.union_debug.clone(),
+            field_path: state.field_path.clone(),
+            path: state.path.clone(),
+            warnings,
+            stats: state.stats.clone(),
         }
     }
 
+    /// Emit the warnings from `ValidationInfo.warn()` so far
+    pub fn emit_warnings(&self, py: Python) -> PyResult<()> {
+        emit_warnings(self.warnings.bind(py))
+    }
+
     pub fn validate_assignment<'py>(
         &mut self,
         py: Python<'py>,
@@ -269,6 +295,29 @@ impl InternalValidator {
         field_name: &str,
         field_value: &Bound<'py, PyAny>,
         outer_location: Option<LocItem>,
+    ) -> PyResult<PyObject> {
+        self.assign(py, outer_location, |validator, state| {
+            validator.validate_assignment(py, model, field_name, field_value, state)
+        })
+    }
+
+    pub fn validate_assignment_many<'py>(
+        &mut self,
+        py: Python<'py>,
+        model: &Bound<'py, PyAny>,
+        values: &Bound<'py, PyDict>,
+        outer_location: Option<LocItem>,
+    ) -> PyResult<PyObject> {
+        self.assign(py, outer_location, |validator, state| {
+            validator.validate_assignment_many(py, model, values, state)
+        })
+    }
+
+    fn assign<'py>(
+        &mut self,
+        py: Python<'py>,
+        outer_location: Option<LocItem>,
+        validate: impl FnOnce(&CombinedValidator, &mut ValidationState<'_, 'py>) -> ValResult<PyObject>,
     ) -> PyResult<PyObject> {
         let extra = Extra {
             input_type: self.validation_mode,
@@ -278,24 +327,31 @@ impl InternalValidator {
             context: self.context.as_ref().map(|data| data.bind(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
+            cache_utf8: self.cache_utf8,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard);
         state.exactness = self.exactness;
-        let result = self
-            .validator
-            .validate_assignment(py, model, field_name, field_value, &mut state)
-            .map_err(|e| {
-                ValidationError::from_val_error(
-                    py,
-                    self.name.to_object(py),
-                    InputType::Python,
-                    e,
-                    outer_location,
-                    self.hide_input_in_errors,
-                    self.validation_error_cause,
-                )
-            });
+        state.tracer = self.tracer.as_ref().map(|tracer| tracer.clone_ref(py));
+        state.lax_report = self.lax_report.take();
+        state.union_debug.clone_from(&self.union_debug);
+        state.field_path.clone_from(&self.field_path);
+        state.path.clone_from(&self.path);
+        state.path.extend(outer_location.clone().map(PathSegment::Loc));
+        state.warnings = Some(self.warnings.clone_ref(py));
+        state.stats.clone_from(&self.stats);
+        let result = validate(&self.validator, &mut state).map_err(|e| {
+            ValidationError::from_val_error(
+                py,
+                self.name.to_object(py),
+                InputType::Python,
+                e,
+                outer_location,
+                self.hide_input_in_errors,
+                self.validation_error_cause,
+            )
+        });
         self.exactness = state.exactness;
+        self.lax_report = state.lax_report.take();
         result
     }
 
@@ -313,9 +369,18 @@ impl InternalValidator {
             context: self.context.as_ref().map(|data| data.bind(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
+            cache_utf8: self.cache_utf8,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard);
         state.exactness = self.exactness;
+        state.tracer = self.tracer.as_ref().map(|tracer| tracer.clone_ref(py));
+        state.lax_report = self.lax_report.take();
+        state.union_debug.clone_from(&self.union_debug);
+        state.field_path.clone_from(&self.field_path);
+        state.path.clone_from(&self.path);
+        state.path.extend(outer_location.clone().map(PathSegment::Loc));
+        state.warnings = Some(self.warnings.clone_ref(py));
+        state.stats.clone_from(&self.stats);
         let result = self.validator.validate(py, input, &mut state).map_err(|e| {
             ValidationError::from_val_error(
                 py,
@@ -328,6 +393,7 @@ impl InternalValidator {
             )
         });
         self.exactness = state.exactness;
+        self.lax_report = state.lax_report.take();
         result
     }
 }
@@ -336,5 +402,7 @@ impl_py_gc_traverse!(InternalValidator {
     validator,
     data,
     context,
-    self_instance
+    self_instance,
+    tracer,
+    union_debug
 });
@@ -0,0 +1,181 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::url::IdnaPolicy;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Validates hostnames as per RFC 1123, or with `domain` fully qualified domain names, returning the normalized
+/// (lowercase) hostname as a string
+#[derive(Debug, Clone)]
+pub struct HostnameValidator {
+    strict: bool,
+    // require at least two labels and a non-numeric top-level domain
+    domain: bool,
+    idna: IdnaPolicy,
+    // return internationalized domain names in unicode rather than punycode
+    unicode: bool,
+    // allow `*` as the first label
+    allow_wildcard: bool,
+    max_label_length: usize,
+    max_length: usize,
+    name: &'static str,
+}
+
+impl BuildValidator for HostnameValidator {
+    const EXPECTED_TYPE: &'static str = "hostname";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Self::from_schema(schema, config, false, Self::EXPECTED_TYPE)
+    }
+}
+
+pub struct DomainValidatorBuilder;
+
+impl BuildValidator for DomainValidatorBuilder {
+    const EXPECTED_TYPE: &'static str = "domain";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        HostnameValidator::from_schema(schema, config, true, Self::EXPECTED_TYPE)
+    }
+}
+
+impl HostnameValidator {
+    fn from_schema(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        domain: bool,
+        name: &'static str,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let output: Option<Bound<'_, PyString>> = schema.get_as(intern!(py, "output"))?;
+        let unicode = match output.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("ascii") => false,
+            Some("unicode") => true,
+            Some(s) => return py_schema_err!("Invalid `output` value: {}, expected 'ascii' or 'unicode'", s),
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            domain,
+            idna: IdnaPolicy::from_schema(schema)?,
+            unicode,
+            allow_wildcard: schema.get_as(intern!(py, "allow_wildcard"))?.unwrap_or(false),
+            max_label_length: schema.get_as(intern!(py, "max_label_length"))?.unwrap_or(63),
+            max_length: schema.get_as(intern!(py, "max_length"))?.unwrap_or(253),
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(HostnameValidator {});
+
+impl Validator for HostnameValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let either_str = input
+            .validate_str(state.strict_or(self.strict), false)?
+            .unpack(state, input, "str");
+        let cow = either_str.as_cow()?;
+        match self.normalize(&cow) {
+            Ok(hostname) => Ok(hostname.into_py(py)),
+            Err(error) => {
+                let error_type = if self.domain {
+                    ErrorType::DomainParsing { error, context: None }
+                } else {
+                    ErrorType::HostnameParsing { error, context: None }
+                };
+                Err(ValError::new(error_type, input))
+            }
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
+
+impl HostnameValidator {
+    /// Check `s` and convert it to its lowercase, ASCII or unicode, form
+    fn normalize(&self, s: &str) -> Result<String, String> {
+        if s.is_empty() {
+            return Err("input is empty".to_string());
+        }
+        let (wildcard, host) = match s.strip_prefix("*.") {
+            Some(host) if self.allow_wildcard => (true, host),
+            _ => (false, s),
+        };
+        let host = host.to_ascii_lowercase();
+        let is_idn = !host.is_ascii() || host.split('.').any(|label| label.starts_with("xn--"));
+        let ascii = match self.idna {
+            _ if !is_idn => host,
+            IdnaPolicy::Forbid => return Err("internationalized domain names are not allowed".to_string()),
+            IdnaPolicy::Punycode if !host.is_ascii() => {
+                return Err("internationalized domain names should be written in punycode".to_string())
+            }
+            _ => idna::Config::default()
+                .use_std3_ascii_rules(true)
+                .to_ascii(&host)
+                .map_err(|_| "invalid internationalized domain name".to_string())?,
+        };
+
+        for label in ascii.split('.') {
+            if label.is_empty() {
+                return Err("empty label".to_string());
+            }
+            if let Some(c) = label.chars().find(|c| !c.is_ascii_alphanumeric() && *c != '-') {
+                return Err(format!("invalid character `{c}`"));
+            }
+            if label.starts_with('-') || label.ends_with('-') {
+                return Err(format!("label `{label}` starts or ends with a hyphen"));
+            }
+            if label.len() > self.max_label_length {
+                return Err(format!(
+                    "label `{label}` is longer than {} characters",
+                    self.max_label_length
+                ));
+            }
+        }
+        let length = if wildcard { ascii.len() + 2 } else { ascii.len() };
+        if length > self.max_length {
+            return Err(format!("longer than {} characters", self.max_length));
+        }
+        if self.domain {
+            match ascii.rsplit_once('.') {
+                None => return Err("expected at least two labels".to_string()),
+                Some((_, tld)) if tld.bytes().all(|b| b.is_ascii_digit()) => {
+                    return Err(format!("top-level domain `{tld}` is numeric"));
+                }
+                Some(_) => (),
+            }
+        }
+
+        let hostname = if self.unicode {
+            idna::domain_to_unicode(&ascii).0
+        } else {
+            ascii
+        };
+        if wildcard {
+            Ok(format!("*.{hostname}"))
+        } else {
+            Ok(hostname)
+        }
+    }
+}
@@ -8,11 +8,13 @@ use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{Input, Int};
 use crate::tools::SchemaDict;
 
+use super::numpy::{numpy_item, numpy_support};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
 pub struct IntValidator {
     strict: bool,
+    numpy_support: bool,
 }
 
 impl BuildValidator for IntValidator {
@@ -34,6 +36,7 @@ impl BuildValidator for IntValidator {
         } else {
             Ok(Self {
                 strict: is_strict(schema, config)?,
+                numpy_support: numpy_support(config)?,
             }
             .into())
         }
@@ -49,9 +52,15 @@ impl Validator for IntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
+        if self.numpy_support {
+            if let Some(item) = numpy_item(input, strict, state)? {
+                return self.validate(py, &item, state);
+            }
+        }
         input
-            .validate_int(state.strict_or(self.strict))
-            .map(|val_match| val_match.unpack(state).into_py(py))
+            .validate_int(strict)
+            .map(|val_match| val_match.unpack(state, input, "int").into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -62,6 +71,7 @@ impl Validator for IntValidator {
 #[derive(Debug, Clone)]
 pub struct ConstrainedIntValidator {
     strict: bool,
+    numpy_support: bool,
     multiple_of: Option<Int>,
     le: Option<Int>,
     lt: Option<Int>,
@@ -78,7 +88,13 @@ impl Validator for ConstrainedIntValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let either_int = input.validate_int(state.strict_or(self.strict))?.unpack(state);
+        let strict = state.strict_or(self.strict);
+        if self.numpy_support {
+            if let Some(item) = numpy_item(input, strict, state)? {
+                return self.validate(py, &item, state);
+            }
+        }
+        let either_int = input.validate_int(strict)?.unpack(state, input, "int");
         let int_value = either_int.as_int()?;
 
         if let Some(ref multiple_of) = self.multiple_of {
@@ -149,6 +165,7 @@ impl ConstrainedIntValidator {
         let py = schema.py();
         Ok(Self {
             strict: is_strict(schema, config)?,
+            numpy_support: numpy_support(config)?,
             multiple_of: schema.get_as(intern!(py, "multiple_of"))?,
             le: schema.get_as(intern!(py, "le"))?,
             lt: schema.get_as(intern!(py, "lt"))?,
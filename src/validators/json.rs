@@ -53,7 +53,7 @@ impl Validator for JsonValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let v_match = validate_json_bytes(input)?;
-        let json_either_bytes = v_match.unpack(state);
+        let json_either_bytes = v_match.unpack(state, input, "json");
         let json_bytes = json_either_bytes.as_slice();
         match self.validator {
             Some(ref validator) => {
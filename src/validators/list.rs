@@ -1,14 +1,18 @@
 use std::sync::OnceLock;
 
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyDict, PyList, PyString};
 
+use crate::build_tools::py_schema_err;
 use crate::errors::ValResult;
 use crate::input::{
-    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
+    no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxErrorItems, MaxLengthCheck,
+    ValidatedList,
 };
+use crate::read_only::ReadOnlyList;
 use crate::tools::SchemaDict;
 
+use super::generator::GeneratorValidator;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -18,7 +22,11 @@ pub struct ListValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: OnceLock<String>,
-    fail_fast: bool,
+    max_error_items: MaxErrorItems,
+    read_only: bool,
+    return_input_if_valid: bool,
+    // set with `output='generator'`, validates the items lazily as they're iterated
+    generator: Option<Box<CombinedValidator>>,
 }
 
 pub fn get_items_schema(
@@ -104,28 +112,56 @@ impl BuildValidator for ListValidator {
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
         let item_validator = get_items_schema(schema, config, definitions)?.map(Box::new);
+        let output = schema.get_as::<Bound<'_, PyString>>(pyo3::intern!(py, "output"))?;
+        let generator = match output.as_ref().map(|output| output.to_str()).transpose()? {
+            None | Some("list") => None,
+            Some("generator") => Some(Box::new(GeneratorValidator::build(schema, config, definitions)?)),
+            Some(output) => return py_schema_err!("Invalid list output: `{}`", output),
+        };
         Ok(Self {
             strict: crate::build_tools::is_strict(schema, config)?,
             item_validator,
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
             name: OnceLock::new(),
-            fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            max_error_items: MaxErrorItems::from_schema(schema)?,
+            read_only: schema.get_as(pyo3::intern!(py, "read_only"))?.unwrap_or(false),
+            return_input_if_valid: schema
+                .get_as(pyo3::intern!(py, "return_input_if_valid"))?
+                .unwrap_or(false),
+            generator,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(ListValidator { item_validator });
+impl_py_gc_traverse!(ListValidator {
+    item_validator,
+    generator
+});
 
-impl Validator for ListValidator {
-    fn validate<'py>(
+impl ListValidator {
+    /// The validator of each item, `None` when items aren't validated
+    pub(super) fn item_validator(&self) -> Option<&CombinedValidator> {
+        self.item_validator.as_deref()
+    }
+
+    fn validate_to_list<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
-    ) -> ValResult<PyObject> {
-        let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
+    ) -> ValResult<Bound<'py, PyList>> {
+        let strict = state.strict_or(self.strict);
+        let seq = input.validate_list(strict)?.unpack(state, input, "list");
+        // with `return_input_if_valid`, the input is returned if validating didn't change any items
+        let input_list = match self.return_input_if_valid && strict {
+            true => seq
+                .as_py_list()
+                .filter(|list| list.is_exact_instance_of::<PyList>())
+                .cloned(),
+            false => None,
+        };
 
         let actual_length = seq.len();
         let output = match self.item_validator {
@@ -137,13 +173,15 @@ impl Validator for ListValidator {
                 field_type: "List",
                 item_validator: v,
                 state,
-                fail_fast: self.fail_fast,
+                max_error_items: self.max_error_items,
             })??,
             None => {
                 if let Some(py_list) = seq.as_py_list() {
                     length_check!(input, "List", self.min_length, self.max_length, py_list);
-                    let list_copy = py_list.get_slice(0, usize::MAX);
-                    return Ok(list_copy.into_py(py));
+                    return match input_list {
+                        Some(input_list) => Ok(input_list),
+                        None => Ok(py_list.get_slice(0, usize::MAX)),
+                    };
                 }
 
                 seq.iterate(ToVec {
@@ -156,7 +194,35 @@ impl Validator for ListValidator {
             }
         };
         min_length_check!(input, "List", self.min_length, output);
-        Ok(output.into_py(py))
+        if let Some(input_list) = input_list {
+            if input_list.len() == output.len() && input_list.iter().zip(&output).all(|(item, out)| item.is(out)) {
+                return Ok(input_list);
+            }
+        }
+        Ok(PyList::new_bound(py, output))
+    }
+}
+
+impl Validator for ListValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if let Some(ref generator) = self.generator {
+            // the input must still be a list, or something accepted as one, but the items aren't validated until
+            // they're iterated
+            input
+                .validate_list(state.strict_or(self.strict))?
+                .unpack(state, input, "list");
+            return generator.validate(py, input, state);
+        }
+        let list = self.validate_to_list(py, input, state)?;
+        match self.read_only {
+            true => Ok(ReadOnlyList::new(list).into_py(py)),
+            false => Ok(list.into_py(py)),
+        }
     }
 
     fn get_name(&self) -> &str {
@@ -187,7 +253,7 @@ struct ValidateToVec<'a, 's, 'py, I: Input<'py> + ?Sized> {
     field_type: &'static str,
     item_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
-    fail_fast: bool,
+    max_error_items: MaxErrorItems,
 }
 
 // pretty arbitrary default capacity when creating vecs from iteration
@@ -208,7 +274,7 @@ where
             max_length_check,
             self.item_validator,
             self.state,
-            self.fail_fast,
+            self.max_error_items,
         )
     }
 }
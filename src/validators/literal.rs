@@ -1,6 +1,7 @@
 // Validator for things inside of a typing.Literal[]
 // which can be an int, a string, bytes or an Enum value (including `class Foo(str, Enum)` type enums)
 use core::fmt::Debug;
+use std::borrow::Cow;
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyInt, PyList};
@@ -31,6 +32,8 @@ pub struct LiteralLookup<T: Debug> {
     expected_bool: Option<BoolLiteral>,
     expected_int: Option<AHashMap<i64, usize>>,
     expected_str: Option<AHashMap<String, usize>>,
+    // lowercased strings, set by `case_insensitive`
+    expected_str_lower: Option<AHashMap<String, usize>>,
     // Catch all for hashable types like Enum and bytes (the latter only because it is seldom used)
     expected_py_dict: Option<Py<PyDict>>,
     // Catch all for unhashable types like list
@@ -87,6 +90,7 @@ impl<T: Debug> LiteralLookup<T> {
                 true => None,
                 false => Some(expected_str),
             },
+            expected_str_lower: None,
             expected_py_dict: match expected_py_dict.is_empty() {
                 true => None,
                 false => Some(expected_py_dict.into()),
@@ -99,6 +103,27 @@ impl<T: Debug> LiteralLookup<T> {
         })
     }
 
+    /// Also match strings which only differ from an expected string by case, the expected value is returned
+    pub fn case_insensitive(mut self) -> PyResult<Self> {
+        // check in the order the choices were given so the error is deterministic
+        let mut expected_str: Vec<(&String, usize)> =
+            self.expected_str.iter().flatten().map(|(str, id)| (str, *id)).collect();
+        expected_str.sort_unstable_by_key(|(_, id)| *id);
+        let mut expected_str_lower: AHashMap<String, usize> = AHashMap::new();
+        for (str, id) in &expected_str {
+            if let Some(other_id) = expected_str_lower.insert(lowercase(str).into_owned(), *id) {
+                let other = expected_str.iter().find(|(_, id)| *id == other_id);
+                return py_schema_err!(
+                    "Literal choices '{}' and '{}' can't be told apart when `case_insensitive` is set",
+                    other.map_or("", |(str, _)| str.as_str()),
+                    str
+                );
+            }
+        }
+        self.expected_str_lower = Some(expected_str_lower);
+        Ok(self)
+    }
+
     pub fn validate<'a, 'py, I: Input<'py> + ?Sized>(
         &self,
         py: Python<'py>,
@@ -140,6 +165,11 @@ impl<T: Debug> LiteralLookup<T> {
                 if let Some(id) = expected_strings.get(cow.as_ref()) {
                     return Ok(Some((input, &self.values[*id])));
                 }
+                if let Some(expected_str_lower) = &self.expected_str_lower {
+                    if let Some(id) = expected_str_lower.get(lowercase(&cow).as_ref()) {
+                        return Ok(Some((input, &self.values[*id])));
+                    }
+                }
             }
         }
         // cache py_input if needed, since we might need it for multiple lookups
@@ -188,9 +218,15 @@ impl<T: Debug> LiteralLookup<T> {
         if let Some(expected_strings) = &self.expected_str {
             if let Ok(either_str) = input.validate_str(strict, false) {
                 let s = either_str.into_inner();
-                if let Some(id) = expected_strings.get(s.as_cow()?.as_ref()) {
+                let cow = s.as_cow()?;
+                if let Some(id) = expected_strings.get(cow.as_ref()) {
                     return Ok(Some(&self.values[*id]));
                 }
+                if let Some(expected_str_lower) = &self.expected_str_lower {
+                    if let Some(id) = expected_str_lower.get(lowercase(&cow).as_ref()) {
+                        return Ok(Some(&self.values[*id]));
+                    }
+                }
             }
         }
         Ok(None)
@@ -217,6 +253,18 @@ impl<T: Debug> LiteralLookup<T> {
     }
 }
 
+/// Lowercase `str`, without copying in the common case of ASCII strings that are already lowercase
+fn lowercase(str: &str) -> Cow<'_, str> {
+    if str.is_ascii() {
+        match str.bytes().any(|b| b.is_ascii_uppercase()) {
+            true => Cow::Owned(str.to_ascii_lowercase()),
+            false => Cow::Borrowed(str),
+        }
+    } else {
+        Cow::Owned(str.to_lowercase())
+    }
+}
+
 impl<T: PyGcTraverse + Debug> PyGcTraverse for LiteralLookup<T> {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
         self.expected_py_dict.py_gc_traverse(visit)?;
@@ -250,7 +298,10 @@ impl BuildValidator for LiteralValidator {
             repr_args.push(item.repr()?.extract()?);
         }
         let (expected_repr, name) = expected_repr_name(repr_args, "literal");
-        let lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?;
+        let mut lookup = LiteralLookup::new(py, expected.into_iter().map(|v| (v.clone(), v.into())))?;
+        if schema.get_as(intern!(py, "case_insensitive"))?.unwrap_or(false) {
+            lookup = lookup.case_insensitive()?;
+        }
         Ok(CombinedValidator::Literal(Self {
             lookup,
             expected_repr,
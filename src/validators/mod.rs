@@ -1,29 +1,38 @@
 use std::fmt::Debug;
+use std::sync::Arc;
 
 use enum_dispatch::enum_dispatch;
 use jiter::StringCacheMode;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::pyclass::CompareOp;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyList, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
+use crate::errors::{catch_panics, install_location_hook, LocItem, ValError, ValResult, ValidationError};
+use crate::frozen::Frozen;
 use crate::input::{Input, InputType, StringMapping};
 use crate::py_gc::PyGcTraverse;
-use crate::recursion_guard::RecursionState;
+use crate::recursion_guard::{Deadline, RecursionState};
 use crate::tools::SchemaDict;
 
+mod alias_map;
+mod analyze;
 mod any;
 mod arguments;
+mod binary;
 mod bool;
+mod bson;
 mod bytes;
 mod call;
 mod callable;
 mod chain;
+mod collection;
+mod context_vars;
 mod custom_error;
 mod dataclass;
 mod date;
@@ -32,10 +41,13 @@ pub(crate) mod decimal;
 mod definitions;
 mod dict;
 mod enum_;
+mod fingerprint;
 mod float;
 mod frozenset;
 mod function;
+mod function_cache;
 mod generator;
+mod hostname;
 mod int;
 mod is_instance;
 mod is_subclass;
@@ -46,21 +58,47 @@ mod list;
 mod literal;
 mod model;
 mod model_fields;
+mod money;
+mod namedtuple;
+mod native;
 mod none;
 mod nullable;
+mod numpy;
+mod on_missing;
+mod pattern_extras;
+mod phone;
+mod redis_hash;
+mod revalidate;
 mod set;
+mod stats;
 mod string;
+mod string_format;
+mod sub_validator;
+mod tag_matcher;
 mod time;
 mod timedelta;
 mod tuple;
 mod typed_dict;
+mod ulid;
 mod union;
 mod url;
 mod uuid;
+mod validate_many;
 mod validation_state;
 mod with_default;
 
-pub use self::validation_state::{Exactness, ValidationState};
+use self::context_vars::{current_coercion_audit, current_union_debug};
+pub use self::context_vars::{get_coercion_audit_var, get_union_debug_var};
+use self::stats::ValidatorStats;
+pub use self::validation_state::{
+    emit_warnings, Clock, Exactness, LaxReport, PathSegment, Rng, Tracer, ValidationState,
+};
+pub(crate) use enum_::FlagMembers;
+pub(crate) use fingerprint::exact_schema_fingerprint;
+pub use string_format::{register_string_format, unregister_string_format};
+pub(crate) use sub_validator::extract_loc;
+pub use sub_validator::SubValidator;
+pub(crate) use ulid::ulid_to_string;
 pub use with_default::DefaultType;
 
 #[pyclass(module = "pydantic_core._pydantic_core", name = "Some")]
@@ -112,6 +150,18 @@ pub struct SchemaValidator {
     hide_input_in_errors: bool,
     validation_error_cause: bool,
     cache_str: StringCacheMode,
+    cache_utf8: bool,
+    intern_strings: bool,
+    catch_panics: bool,
+    effective_configs: Py<PyDict>,
+    stats: Arc<ValidatorStats>,
+    // the source of the current time from the `clock` config, the system clock if unset
+    clock: Option<PyObject>,
+    // the seed or generator for default factories which take one from the `rng` config
+    rng: Option<PyObject>,
+    // how many Python callbacks a single call may make, from the `max_python_callbacks` config
+    max_python_callbacks: Option<u64>,
+    frozen: Frozen,
 }
 
 #[pymethods]
@@ -122,6 +172,7 @@ impl SchemaValidator {
         let mut definitions_builder = DefinitionsBuilder::new();
 
         let validator = build_validator(schema, config, &mut definitions_builder)?;
+        let effective_configs = definitions_builder.effective_configs(py)?.unbind();
         let definitions = definitions_builder.finish()?;
         let py_schema = schema.into_py(py);
         let py_config = match config {
@@ -141,6 +192,16 @@ impl SchemaValidator {
         let cache_str: StringCacheMode = config
             .get_as(intern!(py, "cache_strings"))?
             .unwrap_or(StringCacheMode::All);
+        let cache_utf8: bool = config.get_as(intern!(py, "val_json_cache_utf8"))?.unwrap_or(false);
+        let intern_strings: bool = config.get_as(intern!(py, "intern_strings"))?.unwrap_or(false);
+        let catch_panics: bool = config.get_as(intern!(py, "catch_panics"))?.unwrap_or(false);
+        let clock: Option<PyObject> = config.get_as(intern!(py, "clock"))?;
+        let rng: Option<PyObject> = config.get_as(intern!(py, "rng"))?;
+        let field_failure_stats: bool = config.get_as(intern!(py, "field_failure_stats"))?.unwrap_or(false);
+        let max_python_callbacks: Option<u64> = config.get_as(intern!(py, "max_python_callbacks"))?;
+        if catch_panics {
+            install_location_hook();
+        }
         Ok(Self {
             validator,
             definitions,
@@ -150,9 +211,92 @@ impl SchemaValidator {
             hide_input_in_errors,
             validation_error_cause,
             cache_str,
+            cache_utf8,
+            intern_strings,
+            catch_panics,
+            effective_configs,
+            stats: Arc::new(ValidatorStats::new(field_failure_stats)),
+            clock,
+            rng,
+            max_python_callbacks,
+            frozen: Frozen::default(),
         })
     }
 
+    /// Mark the validator as frozen, with `audit` record the fingerprint of the schema and config so each call can
+    /// check they haven't been mutated since, nothing is made immutable
+    #[pyo3(signature = (*, audit=false))]
+    pub fn freeze<'py>(slf: &Bound<'py, Self>, audit: bool) -> PyResult<Bound<'py, Self>> {
+        let this = slf.get();
+        let py = slf.py();
+        let config = this.py_config.as_ref().map(|c| c.bind(py));
+        this.frozen.freeze(this.py_schema.bind(py), config, audit)?;
+        Ok(slf.clone())
+    }
+
+    #[getter]
+    pub fn is_frozen(&self) -> bool {
+        self.frozen.is_frozen()
+    }
+
+    /// Get the config each definition was built with, keyed by `ref`
+    pub fn get_effective_configs<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.effective_configs.bind(py).copy()
+    }
+
+    /// Get counts of validation calls, failed calls, unions matched by lax validation and union members retried,
+    /// and of errors by field and error type with the `field_failure_stats` config
+    #[pyo3(signature = (*, reset=false))]
+    pub fn stats<'py>(&self, py: Python<'py>, reset: bool) -> PyResult<Bound<'py, PyDict>> {
+        self.stats.to_dict(py, reset)
+    }
+
+    /// Find anti-patterns in the schema which make validation slow, e.g. large unions without a discriminator
+    #[pyo3(signature = (*, max_union_choices=10))]
+    pub fn analyze<'py>(&self, py: Python<'py>, max_union_choices: usize) -> PyResult<Bound<'py, PyList>> {
+        let config = self.py_config.as_ref().map(|c| c.bind(py));
+        analyze::analyze_schema(self.py_schema.bind(py), config, max_union_choices)
+    }
+
+    /// The paths each field of the model, dataclass or typed dict being validated is looked up by, keyed by field
+    /// name, or with `reverse` the field names keyed by path
+    #[pyo3(signature = (*, reverse=false))]
+    pub fn alias_map<'py>(&self, py: Python<'py>, reverse: bool) -> PyResult<Bound<'py, PyDict>> {
+        alias_map::alias_map(py, &self.validator, reverse)
+    }
+
+    /// A validator for just the part of the input at `loc`, e.g. a single field
+    pub fn validator_for_loc(slf: &Bound<'_, Self>, loc: &Bound<'_, PyAny>) -> PyResult<SubValidator> {
+        SubValidator::new(slf, loc)
+    }
+
+    /// A hash of the schema and config, the same for validators which are equal, to detect schema changes
+    pub fn fingerprint(&self, py: Python) -> PyResult<String> {
+        let config = self.py_config.as_ref().map(|c| c.bind(py));
+        let fingerprint = fingerprint::schema_fingerprint(self.py_schema.bind(py), config)?;
+        Ok(format!("{fingerprint:016x}"))
+    }
+
+    fn __richcmp__(&self, other: &Self, op: CompareOp, py: Python) -> PyResult<PyObject> {
+        let eq = || -> PyResult<bool> {
+            let configs_equal = match (&self.py_config, &other.py_config) {
+                (Some(a), Some(b)) => fingerprint::schemas_equal(a.bind(py), b.bind(py))?,
+                (a, b) => a.is_none() && b.is_none(),
+            };
+            Ok(configs_equal && fingerprint::schemas_equal(self.py_schema.bind(py), other.py_schema.bind(py))?)
+        };
+        match op {
+            CompareOp::Eq => Ok(eq()?.into_py(py)),
+            CompareOp::Ne => Ok((!eq()?).into_py(py)),
+            _ => Ok(py.NotImplemented()),
+        }
+    }
+
+    fn __hash__(&self, py: Python) -> PyResult<u64> {
+        let config = self.py_config.as_ref().map(|c| c.bind(py));
+        fingerprint::schema_fingerprint(self.py_schema.bind(py), config)
+    }
+
     pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
@@ -161,7 +305,8 @@ impl SchemaValidator {
         Ok((cls, init_args))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, deadline_ms=None, tracer=None, report_lax=false, clock=None, rng=None))]
     pub fn validate_python(
         &self,
         py: Python,
@@ -170,17 +315,32 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        deadline_ms: Option<u64>,
+        tracer: Option<&Bound<'_, PyAny>>,
+        report_lax: bool,
+        clock: Option<&Bound<'_, PyAny>>,
+        rng: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
-        self._validate(
-            py,
-            input,
-            InputType::Python,
+        let mut lax_report = LaxReport::default();
+        let options = ValidateOptions {
             strict,
             from_attributes,
             context,
             self_instance,
-        )
-        .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+            deadline_ms,
+            tracer,
+            clock,
+            rng,
+            lax_report: report_lax.then_some(&mut lax_report),
+        };
+        let value = self
+            ._validate(py, input, InputType::Python, options)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))?;
+        if report_lax {
+            Ok((value, lax_report.locations_to_py(py)).into_py(py))
+        } else {
+            Ok(value)
+        }
     }
 
     #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
@@ -193,15 +353,14 @@ impl SchemaValidator {
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<bool> {
-        match self._validate(
-            py,
-            input,
-            InputType::Python,
+        let options = ValidateOptions {
             strict,
             from_attributes,
             context,
             self_instance,
-        ) {
+            ..ValidateOptions::default()
+        };
+        match self._validate(py, input, InputType::Python, options) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
             Err(ValError::Omit) => Err(ValidationError::omit_error()),
@@ -210,7 +369,8 @@ impl SchemaValidator {
         }
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None, deadline_ms=None, tracer=None, clock=None, rng=None, release_gil_during_parse=false))]
     pub fn validate_json(
         &self,
         py: Python,
@@ -218,21 +378,133 @@ impl SchemaValidator {
         strict: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        deadline_ms: Option<u64>,
+        tracer: Option<&Bound<'_, PyAny>>,
+        clock: Option<&Bound<'_, PyAny>>,
+        rng: Option<&Bound<'_, PyAny>>,
+        release_gil_during_parse: bool,
     ) -> PyResult<PyObject> {
         let r = match json::validate_json_bytes(input) {
-            Ok(v_match) => self._validate_json(
-                py,
-                input,
-                v_match.into_inner().as_slice(),
-                strict,
-                context,
-                self_instance,
-            ),
+            Ok(v_match) => {
+                let options = ValidateOptions {
+                    strict,
+                    context,
+                    self_instance,
+                    deadline_ms,
+                    tracer,
+                    clock,
+                    rng,
+                    ..ValidateOptions::default()
+                };
+                self._validate_json(
+                    py,
+                    input,
+                    v_match.into_inner().as_slice(),
+                    options,
+                    release_gil_during_parse,
+                )
+            }
             Err(err) => Err(err),
         };
         r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
     }
 
+    /// Validate each input of an iterable, returning the outputs and the errors of the inputs which failed by index
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (inputs, *, mode=None, collect=None, strict=None, from_attributes=None, context=None, tracer=None))]
+    pub fn validate_many<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: &Bound<'py, PyAny>,
+        mode: Option<validate_many::ManyMode>,
+        collect: Option<validate_many::CollectMode>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        tracer: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyDict>)> {
+        let (mode, collect) = (mode.unwrap_or_default(), collect.unwrap_or_default());
+        let options = ValidateOptions {
+            strict,
+            from_attributes,
+            context,
+            tracer,
+            ..ValidateOptions::default()
+        };
+        validate_many::validate_many(self, py, inputs, mode, collect, options)
+    }
+
+    /// Validate data written by `SchemaSerializer.to_binary`, it's validated as if it were JSON
+    #[pyo3(signature = (data, *, strict=None, context=None))]
+    pub fn from_binary(
+        &self,
+        py: Python,
+        data: &[u8],
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let value =
+            binary::parse_binary(data).map_err(|e| PyValueError::new_err(format!("Invalid binary data: {e}")))?;
+        let t = InputType::Json;
+        let options = ValidateOptions {
+            strict,
+            context,
+            ..ValidateOptions::default()
+        };
+        match self._validate(py, &value, t, options) {
+            Ok(r) => Ok(r),
+            Err(e) => Err(self.prepare_validation_err(py, e, t)),
+        }
+    }
+
+    /// Validate a BSON document, e.g. written by `SchemaSerializer.to_bson`, it's validated as if it were python
+    #[pyo3(signature = (data, *, strict=None, context=None))]
+    pub fn validate_bson(
+        &self,
+        py: Python,
+        data: &[u8],
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let value = bson::parse_bson(py, data)?;
+        let t = InputType::Python;
+        let options = ValidateOptions {
+            strict,
+            context,
+            ..ValidateOptions::default()
+        };
+        match self._validate(py, value.as_any(), t, options) {
+            Ok(r) => Ok(r),
+            Err(e) => Err(self.prepare_validation_err(py, e, t)),
+        }
+    }
+
+    /// Validate a Redis hash, e.g. written by `SchemaSerializer.to_redis_hash`, it's validated as if it were JSON
+    #[pyo3(signature = (mapping, *, separator=".", strict=None, context=None))]
+    pub fn validate_redis_hash(
+        &self,
+        py: Python,
+        mapping: &Bound<'_, PyAny>,
+        separator: &str,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        if separator.is_empty() {
+            return Err(PyValueError::new_err("`separator` must not be empty"));
+        }
+        let value = redis_hash::parse_redis_hash(mapping, separator)?;
+        let t = InputType::Json;
+        let options = ValidateOptions {
+            strict,
+            context,
+            ..ValidateOptions::default()
+        };
+        match self._validate(py, &value, t, options) {
+            Ok(r) => Ok(r),
+            Err(e) => Err(self.prepare_validation_err(py, e, t)),
+        }
+    }
+
     #[pyo3(signature = (input, *, strict=None, context=None))]
     pub fn validate_strings(
         &self,
@@ -244,7 +516,12 @@ impl SchemaValidator {
         let t = InputType::String;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
-        match self._validate(py, &string_mapping, t, strict, None, context, None) {
+        let options = ValidateOptions {
+            strict,
+            context,
+            ..ValidateOptions::default()
+        };
+        match self._validate(py, &string_mapping, t, options) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
         }
@@ -262,29 +539,70 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
-        let extra = Extra {
-            input_type: InputType::Python,
-            data: None,
+        self.validate_with(
+            py,
+            InputType::Python,
             strict,
             from_attributes,
             context,
-            self_instance: None,
-            cache_str: self.cache_str,
-        };
+            |validator, state| validator.validate_assignment(py, &obj, field_name, &field_value, state),
+        )
+    }
+
+    /// Validate assignments to several fields at once, the object is only updated if they're all valid and model
+    /// validators are called once
+    #[pyo3(signature = (obj, values, *, strict=None, from_attributes=None, context=None))]
+    pub fn validate_assignment_many(
+        &self,
+        py: Python,
+        obj: Bound<'_, PyAny>,
+        values: &Bound<'_, PyDict>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        if let Some(field_name) = values.keys().iter().find(|key| !key.is_instance_of::<PyString>()) {
+            return Err(PyTypeError::new_err(format!(
+                "Field names should be str, not {}",
+                field_name.get_type().qualname()?
+            )));
+        }
+        self.validate_with(
+            py,
+            InputType::Python,
+            strict,
+            from_attributes,
+            context,
+            |validator, state| validator.validate_assignment_many(py, &obj, values, state),
+        )
+    }
 
-        let guard = &mut RecursionState::default();
-        let mut state = ValidationState::new(extra, guard);
-        self.validator
-            .validate_assignment(py, &obj, field_name, &field_value, &mut state)
-            .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
+    /// Revalidate the fields of a model instance named by `paths`, each a field name or a tuple or list of field
+    /// names and indexes, going into nested models, along with the model's after validators and the `requires` and
+    /// `conflicts_with` involving those fields, the instance is left unchanged if validation fails
+    #[pyo3(signature = (instance, paths, *, strict=None, context=None))]
+    pub fn revalidate_paths(
+        &self,
+        py: Python,
+        instance: Bound<'_, PyAny>,
+        paths: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let paths = revalidate::extract_paths(paths)?;
+        let paths: Vec<&[Bound<'_, PyAny>]> = paths.iter().map(Vec::as_slice).collect();
+        self.validate_with(py, InputType::Python, strict, None, context, |validator, state| {
+            revalidate::revalidate_instance(py, validator, &instance, &paths, state)
+        })
     }
 
-    #[pyo3(signature = (*, strict=None, context=None))]
+    #[pyo3(signature = (*, strict=None, context=None, rng=None))]
     pub fn get_default_value(
         &self,
         py: Python,
         strict: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
+        rng: Option<&Bound<'_, PyAny>>,
     ) -> PyResult<PyObject> {
         let extra = Extra {
             input_type: InputType::Python,
@@ -294,9 +612,12 @@ impl SchemaValidator {
             context,
             self_instance: None,
             cache_str: self.cache_str,
+            cache_utf8: self.cache_utf8,
         };
-        let recursion_guard = &mut RecursionState::default();
+        let recursion_guard = &mut RecursionState::default().with_max_python_callbacks(self.max_python_callbacks);
         let mut state = ValidationState::new(extra, recursion_guard);
+        state.clock = self.clock.as_ref().map(|clock| Clock::new(clock.clone_ref(py)));
+        state.rng = self.rng_for_call(py, rng);
         let r = self.validator.default_value(py, None::<i64>, &mut state);
         match r {
             Ok(maybe_default) => match maybe_default {
@@ -327,49 +648,168 @@ impl SchemaValidator {
         if let Some(ref py_config) = self.py_config {
             visit.call(py_config)?;
         }
+        visit.call(&self.effective_configs)?;
+        if let Some(ref clock) = self.clock {
+            visit.call(clock)?;
+        }
+        if let Some(ref rng) = self.rng {
+            visit.call(rng)?;
+        }
         Ok(())
     }
 }
 
 impl SchemaValidator {
-    #[allow(clippy::too_many_arguments)]
+    /// Check a validator frozen with `audit` hasn't had its schema or config mutated
+    fn audit_frozen(&self, py: Python) -> PyResult<()> {
+        let config = self.py_config.as_ref().map(|c| c.bind(py));
+        self.frozen.audit(self.py_schema.bind(py), config, "SchemaValidator")
+    }
+
     fn _validate<'py>(
         &self,
         py: Python<'py>,
         input: &(impl Input<'py> + ?Sized),
         input_type: InputType,
-        strict: Option<bool>,
-        from_attributes: Option<bool>,
-        context: Option<&Bound<'py, PyAny>>,
-        self_instance: Option<&Bound<'py, PyAny>>,
+        options: ValidateOptions<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let mut recursion_guard = RecursionState::default();
-        let mut state = ValidationState::new(
-            Extra::new(
-                strict,
-                from_attributes,
-                context,
-                self_instance,
-                input_type,
-                self.cache_str,
-            ),
-            &mut recursion_guard,
+        self.audit_frozen(py)?;
+        let mut recursion_guard = self.recursion_guard(options.deadline_ms);
+        let (mut state, coercion_audit) = self.new_state(py, &mut recursion_guard, input_type, &options)?;
+        let validator = &self.validator;
+        let result = catch_panics(self.catch_panics, validator.get_name(), || {
+            state.traced(py, validator.get_name(), None, |state| {
+                validator.validate(py, input, state)
+            })
+        });
+        self.record_stats(&result);
+        if let (Ok(_), Some(state_report)) = (&result, state.lax_report.take()) {
+            if let Some(coercion_audit) = coercion_audit {
+                state_report.extend_audit(&coercion_audit)?;
+            }
+            if let Some(lax_report) = options.lax_report {
+                *lax_report = state_report;
+            }
+        }
+        if let (Ok(_), Some(warnings)) = (&result, state.warnings.take()) {
+            emit_warnings(warnings.bind(py))?;
+        }
+        result
+    }
+
+    /// The recursion guard of a call validating with the root validator, with the call's deadline
+    fn recursion_guard(&self, deadline_ms: Option<u64>) -> RecursionState {
+        RecursionState::with_deadline(deadline_ms.map(Deadline::new))
+            .with_max_python_callbacks(self.max_python_callbacks)
+    }
+
+    /// The state of a call validating with the root validator, shared by `_validate` and `validate_many` so inputs
+    /// are validated the same way by both, and the list to append coercions to within `coercion_audit()`
+    fn new_state<'a, 'py>(
+        &self,
+        py: Python<'py>,
+        recursion_guard: &'a mut RecursionState,
+        input_type: InputType,
+        options: &ValidateOptions<'a, 'py>,
+    ) -> PyResult<(ValidationState<'a, 'py>, Option<Bound<'py, PyList>>)> {
+        let extra = Extra::new(
+            options.strict,
+            options.from_attributes,
+            options.context,
+            options.self_instance,
+            input_type,
+            self.cache_str,
+            self.cache_utf8,
         );
-        self.validator.validate(py, input, &mut state)
+        let mut state = ValidationState::new(extra, recursion_guard);
+        state.tracer = options.tracer.map(|tracer| Tracer::new(tracer.clone().unbind()));
+        // a clock passed to validation overrides the `clock` config
+        state.clock = match options.clock {
+            Some(clock) => Some(Clock::new(clock.clone().unbind())),
+            None => self.clock.as_ref().map(|clock| Clock::new(clock.clone_ref(py))),
+        };
+        state.rng = self.rng_for_call(py, options.rng);
+        state.stats = Some(self.stats.clone());
+        let report_lax = options.lax_report.is_some();
+        let coercion_audit = current_coercion_audit(py)?;
+        if report_lax || coercion_audit.is_some() {
+            state.lax_report = Some(LaxReport::new(report_lax, coercion_audit.is_some()));
+        }
+        if report_lax {
+            state.exactness = Some(Exactness::Exact);
+        }
+        state.union_debug = current_union_debug(py)?.map(Bound::unbind);
+        if self.intern_strings {
+            state.interned_strs = Some(PyDict::new_bound(py).unbind());
+        }
+        Ok((state, coercion_audit))
     }
 
-    fn _validate_json(
+    fn _validate_json<'py>(
         &self,
-        py: Python,
-        input: &Bound<'_, PyAny>,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
         json_data: &[u8],
-        strict: Option<bool>,
-        context: Option<&Bound<'_, PyAny>>,
-        self_instance: Option<&Bound<'_, PyAny>>,
+        options: ValidateOptions<'_, 'py>,
+        release_gil_during_parse: bool,
     ) -> ValResult<PyObject> {
-        let json_value =
-            jiter::JsonValue::parse(json_data, true).map_err(|e| json::map_json_err(input, e, json_data))?;
-        self._validate(py, &json_value, InputType::Json, strict, None, context, self_instance)
+        // `json_data` is never a `bytearray` which could be mutated while the GIL is released, they're copied
+        let parse = || jiter::JsonValue::parse(json_data, true);
+        let json_value = if release_gil_during_parse {
+            py.allow_threads(parse)
+        } else {
+            parse()
+        }
+        .map_err(|e| json::map_json_err(input, e, json_data))?;
+        self._validate(py, &json_value, InputType::Json, options)
+    }
+
+    /// Validate with `validate` and a new state, for calls which don't validate an input with the root validator,
+    /// e.g. assignments
+    fn validate_with<'py>(
+        &self,
+        py: Python<'py>,
+        input_type: InputType,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        validate: impl FnOnce(&CombinedValidator, &mut ValidationState<'_, 'py>) -> ValResult<PyObject>,
+    ) -> PyResult<PyObject> {
+        self.audit_frozen(py)?;
+        let options = ValidateOptions {
+            strict,
+            from_attributes,
+            context,
+            ..ValidateOptions::default()
+        };
+        let mut recursion_guard = self.recursion_guard(None);
+        let (mut state, coercion_audit) = self.new_state(py, &mut recursion_guard, input_type, &options)?;
+        let result = catch_panics(self.catch_panics, self.validator.get_name(), || {
+            validate(&self.validator, &mut state)
+        });
+        self.record_stats(&result);
+        if let (Ok(_), Some(coercion_audit), Some(lax_report)) = (&result, coercion_audit, state.lax_report.take()) {
+            lax_report.extend_audit(&coercion_audit)?;
+        }
+        if let (Ok(_), Some(warnings)) = (&result, state.warnings.take()) {
+            emit_warnings(warnings.bind(py))?;
+        }
+        result.map_err(|e| self.prepare_validation_err(py, e, input_type))
+    }
+
+    fn record_stats(&self, result: &ValResult<PyObject>) {
+        self.stats.record_call(result.is_ok());
+        if let Err(ValError::LineErrors(errors)) = result {
+            self.stats.record_field_failures(errors);
+        }
+    }
+
+    /// An `rng` passed to validation overrides the `rng` config
+    fn rng_for_call(&self, py: Python, rng: Option<&Bound<'_, PyAny>>) -> Rng {
+        match rng {
+            Some(rng) => Rng::new(Some(rng.clone().unbind())),
+            None => Rng::new(self.rng.as_ref().map(|rng| rng.clone_ref(py))),
+        }
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
@@ -385,6 +825,23 @@ impl SchemaValidator {
     }
 }
 
+/// The settings of a single call of `SchemaValidator._validate`, from the arguments of e.g. `validate_python`
+#[derive(Default)]
+struct ValidateOptions<'a, 'py> {
+    strict: Option<bool>,
+    from_attributes: Option<bool>,
+    context: Option<&'a Bound<'py, PyAny>>,
+    self_instance: Option<&'a Bound<'py, PyAny>>,
+    deadline_ms: Option<u64>,
+    tracer: Option<&'a Bound<'py, PyAny>>,
+    // overrides the `clock` config
+    clock: Option<&'a Bound<'py, PyAny>>,
+    // overrides the `rng` config
+    rng: Option<&'a Bound<'py, PyAny>>,
+    // filled in with the locations which used lax validation if set
+    lax_report: Option<&'a mut LaxReport>,
+}
+
 static SCHEMA_DEFINITION: GILOnceCell<SchemaValidator> = GILOnceCell::new();
 
 #[derive(Debug, Clone)]
@@ -405,7 +862,7 @@ impl<'py> SelfValidator<'py> {
         let py = schema.py();
         let mut recursion_guard = RecursionState::default();
         let mut state = ValidationState::new(
-            Extra::new(strict, None, None, None, InputType::Python, true.into()),
+            Extra::new(strict, None, None, None, InputType::Python, true.into(), false),
             &mut recursion_guard,
         );
         match self.validator.validator.validate(py, schema, &mut state) {
@@ -436,6 +893,15 @@ impl<'py> SelfValidator<'py> {
             hide_input_in_errors: false,
             validation_error_cause: false,
             cache_str: true.into(),
+            cache_utf8: false,
+            intern_strings: false,
+            catch_panics: false,
+            effective_configs: PyDict::new_bound(py).unbind(),
+            stats: Arc::default(),
+            clock: None,
+            rng: None,
+            max_python_callbacks: None,
+            frozen: Frozen::default(),
         })
     }
 }
@@ -489,6 +955,7 @@ pub fn build_validator(
     let dict = schema.downcast::<PyDict>()?;
     let type_: Bound<'_, PyString> = dict.get_as_req(intern!(schema.py(), "type"))?;
     let type_ = type_.to_str()?;
+    definitions.record_config(dict, config)?;
     validator_match!(
         type_,
         dict,
@@ -507,8 +974,16 @@ pub fn build_validator(
         // dataclasses
         dataclass::DataclassArgsValidator,
         dataclass::DataclassValidator,
+        // named tuples
+        namedtuple::NamedTupleValidator,
+        // `collections` types
+        collection::DequeBuilder,
+        collection::CounterBuilder,
+        collection::OrderedDictBuilder,
+        collection::DefaultDictBuilder,
         // strings
         string::StrValidator,
+        string_format::StringFormatValidator,
         // integers
         int::IntValidator,
         // boolean
@@ -517,6 +992,8 @@ pub fn build_validator(
         float::FloatBuilder,
         // decimals
         decimal::DecimalValidator,
+        // money
+        money::MoneyValidator,
         // tuples
         tuple::TupleValidator,
         // list/arrays
@@ -575,8 +1052,17 @@ pub fn build_validator(
         // url types
         url::UrlValidator,
         url::MultiHostUrlValidator,
+        url::UriReferenceValidator,
+        // hostnames
+        hostname::HostnameValidator,
+        hostname::DomainValidatorBuilder,
+        // phone numbers
+        phone::PhoneValidator,
         // uuid types
         uuid::UuidValidator,
+        ulid::UlidValidator,
+        // types implemented by extension modules
+        native::NativeValidator,
         // recursive (self-referencing) models
         definitions::DefinitionRefValidator,
         definitions::DefinitionsValidatorBuilder,
@@ -601,6 +1087,8 @@ pub struct Extra<'a, 'py> {
     self_instance: Option<&'a Bound<'py, PyAny>>,
     /// Whether to use a cache of short strings to accelerate python string construction
     cache_str: StringCacheMode,
+    /// Whether to populate the UTF-8 buffer of strings created from JSON, so serialization can reuse it
+    cache_utf8: bool,
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
@@ -611,6 +1099,7 @@ impl<'a, 'py> Extra<'a, 'py> {
         self_instance: Option<&'a Bound<'py, PyAny>>,
         input_type: InputType,
         cache_str: StringCacheMode,
+        cache_utf8: bool,
     ) -> Self {
         Extra {
             input_type,
@@ -620,6 +1109,7 @@ impl<'a, 'py> Extra<'a, 'py> {
             context,
             self_instance,
             cache_str,
+            cache_utf8,
         }
     }
 }
@@ -634,6 +1124,7 @@ impl Extra<'_, '_> {
             context: self.context,
             self_instance: self.self_instance,
             cache_str: self.cache_str,
+            cache_utf8: self.cache_utf8,
         }
     }
 }
@@ -654,9 +1145,14 @@ pub enum CombinedValidator {
     // dataclasses
     DataclassArgs(dataclass::DataclassArgsValidator),
     Dataclass(dataclass::DataclassValidator),
+    // named tuples
+    NamedTuple(namedtuple::NamedTupleValidator),
+    // `collections` types
+    Collection(collection::CollectionValidator),
     // strings
     Str(string::StrValidator),
     StrConstrained(string::StrConstrainedValidator),
+    StringFormat(string_format::StringFormatValidator),
     // integers
     Int(int::IntValidator),
     ConstrainedInt(int::ConstrainedIntValidator),
@@ -667,6 +1163,8 @@ pub enum CombinedValidator {
     ConstrainedFloat(float::ConstrainedFloatValidator),
     // decimals
     Decimal(decimal::DecimalValidator),
+    // money
+    Money(money::MoneyValidator),
     // lists
     List(list::ListValidator),
     // sets - unique lists
@@ -727,8 +1225,14 @@ pub enum CombinedValidator {
     // url types
     Url(url::UrlValidator),
     MultiHostUrl(url::MultiHostUrlValidator),
+    UriReference(url::UriReferenceValidator),
+    Hostname(hostname::HostnameValidator),
+    Phone(phone::PhoneValidator),
     // uuid types
     Uuid(uuid::UuidValidator),
+    Ulid(ulid::UlidValidator),
+    // types implemented by extension modules
+    Native(native::NativeValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
     // input dependent
@@ -771,6 +1275,21 @@ pub trait Validator: Send + Sync + Debug {
         Err(py_err.into())
     }
 
+    /// Validate assignments to several fields of a model, `values` maps field names to values
+    fn validate_assignment_many<'py>(
+        &self,
+        _py: Python<'py>,
+        _obj: &Bound<'py, PyAny>,
+        _values: &Bound<'py, PyDict>,
+        _state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let py_err = PyTypeError::new_err(format!(
+            "validate_assignment_many is not supported for {}",
+            self.get_name()
+        ));
+        Err(py_err.into())
+    }
+
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
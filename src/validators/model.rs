@@ -1,17 +1,18 @@
 use std::ptr::null_mut;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::ffi;
 use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
 use pyo3::{intern, prelude::*};
 
 use super::function::convert_err;
+use super::revalidate::RevalidateFn;
 use super::validation_state::Exactness;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, ValidationState, Validator,
 };
 use crate::build_tools::py_schema_err;
-use crate::build_tools::schema_or_config_same;
+use crate::build_tools::{nested_config, schema_or_config_same, ExtrasDestination};
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{input_as_python_instance, py_error_on_minusone, Input};
 use crate::tools::{py_err, SchemaDict};
@@ -59,6 +60,7 @@ pub struct ModelValidator {
     frozen: bool,
     custom_init: bool,
     root_model: bool,
+    extras_destination: ExtrasDestination,
     undefined: PyObject,
     name: String,
 }
@@ -68,12 +70,13 @@ impl BuildValidator for ModelValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
-        // models ignore the parent config and always use the config from this model
-        let config = schema.get_as(intern!(py, "config"))?;
+        // models use the config from this model, unless it's set to merge with the parent config
+        let config = nested_config(schema, config)?;
+        definitions.record_config(schema, config.as_ref())?;
 
         let class: Bound<'_, PyType> = schema.get_as_req(intern!(py, "cls"))?;
         let sub_schema = schema.get_as_req(intern!(py, "schema"))?;
@@ -97,6 +100,8 @@ impl BuildValidator for ModelValidator {
             frozen: schema.get_as(intern!(py, "frozen"))?.unwrap_or(false),
             custom_init: schema.get_as(intern!(py, "custom_init"))?.unwrap_or(false),
             root_model: schema.get_as(intern!(py, "root_model"))?.unwrap_or(false),
+            extras_destination: ExtrasDestination::from_config(config.as_ref())?
+                .unwrap_or(ExtrasDestination::ModelExtra),
             undefined: PydanticUndefinedType::new(py).to_object(py),
             // Get the class's `__name__`, not using `class.qualname()`
             name,
@@ -132,15 +137,15 @@ impl Validator for ModelValidator {
                     self.validate_construct(py, &inner_input, Some(&fields_set), state)
                 } else {
                     // get dict here so from_attributes logic doesn't apply
-                    let dict = py_input.getattr(intern!(py, DUNDER_DICT))?;
-                    let model_extra = py_input.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?;
-
-                    let inner_input = if PyAnyMethods::is_none(&model_extra) {
-                        dict
-                    } else {
-                        let full_model_dict = dict.downcast::<PyDict>()?.copy()?;
-                        full_model_dict.update(model_extra.downcast()?)?;
-                        full_model_dict.into_any()
+                    let (dict, model_extra) = self.get_dict_and_extra(py_input)?;
+
+                    let inner_input = match model_extra {
+                        Some(model_extra) if !PyAnyMethods::is_none(&model_extra) => {
+                            let full_model_dict = dict.copy()?;
+                            full_model_dict.update(model_extra.downcast()?)?;
+                            full_model_dict.into_any()
+                        }
+                        _ => dict.into_any(),
                     };
                     self.validate_construct(py, &inner_input, Some(&fields_set), state)
                 }
@@ -181,17 +186,66 @@ impl Validator for ModelValidator {
                 Ok(model.into_py(py))
             };
         }
-        let old_dict = model.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+        self.assign(model, |input_dict| {
+            input_dict.set_item(field_name, field_value)?;
+            self.validator
+                .validate_assignment(py, input_dict.as_any(), field_name, field_value, state)
+        })
+    }
+
+    fn validate_assignment_many<'py>(
+        &self,
+        py: Python<'py>,
+        model: &Bound<'py, PyAny>,
+        values: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if self.frozen {
+            return Err(ValError::new(ErrorTypeDefaults::FrozenInstance, values.as_any()));
+        } else if self.root_model {
+            // `root` is the only field, so there's at most one assignment to validate
+            return match values
+                .iter()
+                .find(|(field_name, _)| !field_name.eq(ROOT_FIELD).unwrap_or(false))
+            {
+                Some((field_name, field_value)) => {
+                    let field_name = field_name.str()?.to_string();
+                    self.validate_assignment(py, model, &field_name, &field_value, state)
+                }
+                None => match values.get_item(ROOT_FIELD)? {
+                    Some(field_value) => self.validate_assignment(py, model, ROOT_FIELD, &field_value, state),
+                    None => Ok(model.into_py(py)),
+                },
+            };
+        }
+        self.assign(model, |input_dict| {
+            self.validator
+                .validate_assignment_many(py, input_dict.as_any(), values, state)
+        })
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl ModelValidator {
+    /// Validate assignments to `model` with `validate`, called with a dict of its fields and extras, then set the
+    /// validated fields and extras and add the fields assigned to its fields set
+    fn assign<'py>(
+        &self,
+        model: &Bound<'py, PyAny>,
+        validate: impl FnOnce(&Bound<'py, PyDict>) -> ValResult<PyObject>,
+    ) -> ValResult<PyObject> {
+        let py = model.py();
+        let (old_dict, old_extra) = self.get_dict_and_extra(model)?;
 
         let input_dict = old_dict.copy()?;
-        if let Ok(old_extra) = model.getattr(intern!(py, DUNDER_MODEL_EXTRA_KEY))?.downcast::<PyDict>() {
+        if let Some(Ok(old_extra)) = old_extra.as_ref().map(|extra| extra.downcast::<PyDict>()) {
             input_dict.update(old_extra.as_mapping())?;
         }
-        input_dict.set_item(field_name, field_value)?;
 
-        let output = self
-            .validator
-            .validate_assignment(py, input_dict.as_any(), field_name, field_value, state)?;
+        let output = validate(&input_dict)?;
 
         let (validated_dict, validated_extra, validated_fields_set): (
             Bound<'_, PyDict>,
@@ -206,22 +260,40 @@ impl Validator for ModelValidator {
             }
         }
 
-        force_setattr(py, model, intern!(py, DUNDER_DICT), validated_dict.to_object(py))?;
-        force_setattr(
-            py,
-            model,
-            intern!(py, DUNDER_MODEL_EXTRA_KEY),
-            validated_extra.to_object(py),
-        )?;
+        self.set_dict_and_extra(model, validated_dict.as_any(), &validated_extra)?;
         Ok(model.into_py(py))
     }
 
-    fn get_name(&self) -> &str {
-        &self.name
+    /// The validator this one wraps
+    pub(super) fn inner_validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+
+    /// A copy of the `__dict__` of `instance` with the fields named by `paths` revalidated, `None` if `instance`
+    /// isn't an instance of the class or the model is a root model
+    pub(super) fn revalidate_dict<'py>(
+        &self,
+        py: Python<'py>,
+        instance: &Bound<'py, PyAny>,
+        paths: &[&[Bound<'py, PyAny>]],
+        state: &mut ValidationState<'_, 'py>,
+        revalidate: RevalidateFn<'_, 'py>,
+    ) -> ValResult<Option<Bound<'py, PyDict>>> {
+        let CombinedValidator::ModelFields(ref fields_validator) = *self.validator else {
+            return Ok(None);
+        };
+        if !instance.is_instance(self.class.bind(py))? {
+            return Ok(None);
+        }
+        let dict = instance
+            .getattr(intern!(py, DUNDER_DICT))?
+            .downcast_into::<PyDict>()?
+            .copy()?;
+        let fields_set = instance.getattr(intern!(py, DUNDER_FIELDS_SET_KEY)).ok();
+        fields_validator.revalidate_fields(py, &dict, fields_set.as_ref(), paths, state, revalidate)?;
+        Ok(Some(dict))
     }
-}
 
-impl ModelValidator {
     /// here we just call the inner validator, then set attributes on `self_instance`
     fn validate_init<'py>(
         &self,
@@ -246,7 +318,7 @@ impl ModelValidator {
         } else {
             let (model_dict, model_extra, fields_set): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) =
                 output.extract(py)?;
-            set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
+            self.set_model_attrs(self_instance, &model_dict, &model_extra, &fields_set)?;
         }
         self.call_post_init(py, self_instance.clone(), input, state.extra())
     }
@@ -288,11 +360,84 @@ impl ModelValidator {
             let (model_dict, model_extra, val_fields_set): (Bound<PyAny>, Bound<PyAny>, Bound<PyAny>) =
                 output.extract(py)?;
             let fields_set = existing_fields_set.unwrap_or(&val_fields_set);
-            set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
+            self.set_model_attrs(&instance, &model_dict, &model_extra, fields_set)?;
         }
         self.call_post_init(py, instance, input, state.extra())
     }
 
+    fn set_model_attrs(
+        &self,
+        instance: &Bound<'_, PyAny>,
+        model_dict: &Bound<'_, PyAny>,
+        model_extra: &Bound<'_, PyAny>,
+        fields_set: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let py = instance.py();
+        self.set_dict_and_extra(instance, model_dict, model_extra)?;
+        force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), py.None())?;
+        force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
+        Ok(())
+    }
+
+    /// Set `__dict__` and the extras of `model` as `extras_destination` says, `__pydantic_extra__` is `None` unless
+    /// the extras are stored there
+    fn set_dict_and_extra(
+        &self,
+        model: &Bound<'_, PyAny>,
+        model_dict: &Bound<'_, PyAny>,
+        model_extra: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let py = model.py();
+        match self.extras_destination {
+            ExtrasDestination::ModelExtra => {
+                force_setattr(py, model, intern!(py, DUNDER_DICT), model_dict)?;
+                force_setattr(py, model, intern!(py, DUNDER_MODEL_EXTRA_KEY), model_extra)
+            }
+            ExtrasDestination::Merge => {
+                if let Ok(model_extra) = model_extra.downcast::<PyDict>() {
+                    model_dict.downcast::<PyDict>()?.update(model_extra.as_mapping())?;
+                }
+                force_setattr(py, model, intern!(py, DUNDER_DICT), model_dict)?;
+                force_setattr(py, model, intern!(py, DUNDER_MODEL_EXTRA_KEY), py.None())
+            }
+            ExtrasDestination::Attribute(ref attribute) => {
+                force_setattr(py, model, intern!(py, DUNDER_DICT), model_dict)?;
+                force_setattr(py, model, intern!(py, DUNDER_MODEL_EXTRA_KEY), py.None())?;
+                // without `extra_behavior='allow'` there are no extras to store
+                if !PyAnyMethods::is_none(model_extra) {
+                    force_setattr(py, model, attribute, model_extra)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// The fields in `__dict__` of `model` and its extras, which are `None` when they're merged into `__dict__`
+    fn get_dict_and_extra<'py>(
+        &self,
+        model: &Bound<'py, PyAny>,
+    ) -> PyResult<(Bound<'py, PyDict>, Option<Bound<'py, PyAny>>)> {
+        let py = model.py();
+        let dict = model.getattr(intern!(py, DUNDER_DICT))?.downcast_into::<PyDict>()?;
+        let Some(attribute) = self.extras_destination.attribute(py) else {
+            return Ok((dict, None));
+        };
+        let model_extra = match model.getattr(&attribute) {
+            Ok(model_extra) => model_extra,
+            Err(err) if err.is_instance_of::<PyAttributeError>(py) => return Ok((dict, None)),
+            Err(err) => return Err(err),
+        };
+        // attributes without a slot or descriptor are stored in `__dict__`, where they aren't fields
+        let dict = if dict.contains(&attribute)? {
+            let fields_dict = dict.copy()?;
+            fields_dict.del_item(&attribute)?;
+            fields_dict
+        } else {
+            dict
+        };
+        Ok((dict, Some(model_extra)))
+    }
+
     fn call_post_init<'py>(
         &self,
         py: Python<'py>,
@@ -330,20 +475,6 @@ pub(super) fn create_class<'py>(class: &Bound<'py, PyType>) -> PyResult<Bound<'p
     }
 }
 
-fn set_model_attrs(
-    instance: &Bound<'_, PyAny>,
-    model_dict: &Bound<'_, PyAny>,
-    model_extra: &Bound<'_, PyAny>,
-    fields_set: &Bound<'_, PyAny>,
-) -> PyResult<()> {
-    let py = instance.py();
-    force_setattr(py, instance, intern!(py, DUNDER_DICT), model_dict)?;
-    force_setattr(py, instance, intern!(py, DUNDER_MODEL_EXTRA_KEY), model_extra)?;
-    force_setattr(py, instance, intern!(py, DUNDER_MODEL_PRIVATE_KEY), py.None())?;
-    force_setattr(py, instance, intern!(py, DUNDER_FIELDS_SET_KEY), fields_set)?;
-    Ok(())
-}
-
 pub(super) fn force_setattr<N, V>(py: Python<'_>, obj: &Bound<'_, PyAny>, attr_name: N, value: V) -> PyResult<()>
 where
     N: ToPyObject,
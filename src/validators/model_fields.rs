@@ -1,12 +1,12 @@
 use pyo3::exceptions::PyKeyError;
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PySet, PyString, PyType};
+use pyo3::types::{PyDict, PyList, PySet, PyString, PyType};
 
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{is_strict, schema_or_config_same, AliasGenerator, ExtraBehavior};
 use crate::errors::LocItem;
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
@@ -14,18 +14,37 @@ use crate::input::{BorrowInput, Input, ValidatedDict, ValidationMatch};
 use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::on_missing::OnMissing;
+use super::revalidate::RevalidateFn;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, PathSegment, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 struct Field {
     name: String,
     lookup_key: LookupKey,
+    // used instead of `lookup_key` when getting the field from attributes
+    attribute_key: Option<LookupKey>,
     name_py: Py<PyString>,
     validator: CombinedValidator,
     frozen: bool,
+    on_missing: Option<OnMissing>,
+    // indexes of the fields which must, or must not, be in the input if this field is
+    requires: Vec<usize>,
+    conflicts_with: Vec<usize>,
 }
 
-impl_py_gc_traverse!(Field { validator });
+impl_py_gc_traverse!(Field { validator, on_missing });
+
+/// Where the value of a field came from, used to check `requires` and `conflicts_with`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldSource {
+    Input,
+    Default,
+    // the field is missing or its lookup failed, either way there's already an error
+    Missing,
+}
 
 #[derive(Debug)]
 pub struct ModelFieldsValidator {
@@ -36,6 +55,7 @@ pub struct ModelFieldsValidator {
     strict: bool,
     from_attributes: bool,
     loc_by_alias: bool,
+    has_dependencies: bool,
 }
 
 impl BuildValidator for ModelFieldsValidator {
@@ -51,6 +71,7 @@ impl BuildValidator for ModelFieldsValidator {
 
         let from_attributes = schema_or_config_same(schema, config, intern!(py, "from_attributes"))?.unwrap_or(false);
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+        let alias_generator = AliasGenerator::from_config(config)?;
 
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
@@ -65,6 +86,7 @@ impl BuildValidator for ModelFieldsValidator {
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<Field> = Vec::with_capacity(fields_dict.len());
+        let mut dependencies: Vec<(Vec<String>, Vec<String>)> = Vec::with_capacity(fields_dict.len());
 
         for (key, value) in fields_dict {
             let field_info = value.downcast::<PyDict>()?;
@@ -83,17 +105,55 @@ impl BuildValidator for ModelFieldsValidator {
                     let alt_alias = if populate_by_name { Some(field_name) } else { None };
                     LookupKey::from_py(py, &alias, alt_alias)?
                 }
-                None => LookupKey::from_string(py, field_name),
+                None => match field_info.get_item(intern!(py, "serialization_alias"))? {
+                    Some(aliases) if aliases.is_instance_of::<PyList>() => {
+                        let alt_alias = if populate_by_name { Some(field_name) } else { None };
+                        LookupKey::from_choices(py, aliases.downcast()?, alt_alias)?
+                    }
+                    Some(_) => LookupKey::from_string(py, field_name),
+                    None => match alias_generator {
+                        Some(alias_generator) => {
+                            let alias = PyString::new_bound(py, &alias_generator.apply(field_name));
+                            let alt_alias = if populate_by_name { Some(field_name) } else { None };
+                            LookupKey::from_py(py, &alias, alt_alias)?
+                        }
+                        None => LookupKey::from_string(py, field_name),
+                    },
+                },
+            };
+
+            let attribute_key = match field_info.get_item(intern!(py, "attribute_path"))? {
+                Some(attribute_path) => Some(LookupKey::from_attribute_path(py, &attribute_path)?),
+                None => None,
             };
 
             fields.push(Field {
                 name: field_name.to_string(),
                 lookup_key,
+                attribute_key,
                 name_py: field_name_py.into(),
                 validator,
                 frozen: field_info.get_as::<bool>(intern!(py, "frozen"))?.unwrap_or(false),
+                on_missing: OnMissing::from_field(field_info)?,
+                requires: Vec::new(),
+                conflicts_with: Vec::new(),
             });
+            dependencies.push((
+                field_info.get_as(intern!(py, "requires"))?.unwrap_or_default(),
+                field_info.get_as(intern!(py, "conflicts_with"))?.unwrap_or_default(),
+            ));
+        }
+
+        for (index, (requires, conflicts_with)) in dependencies.into_iter().enumerate() {
+            let requires = field_indexes(&fields, index, &requires)?;
+            let conflicts_with = field_indexes(&fields, index, &conflicts_with)?;
+            let field = &mut fields[index];
+            field.requires = requires;
+            field.conflicts_with = conflicts_with;
         }
+        let has_dependencies = fields
+            .iter()
+            .any(|f| !f.requires.is_empty() || !f.conflicts_with.is_empty());
 
         Ok(Self {
             fields,
@@ -103,16 +163,277 @@ impl BuildValidator for ModelFieldsValidator {
             strict,
             from_attributes,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            has_dependencies,
         }
         .into())
     }
 }
 
+/// Find the indexes of the fields named in the `requires` or `conflicts_with` of `fields[index]`
+fn field_indexes(fields: &[Field], index: usize, names: &[String]) -> PyResult<Vec<usize>> {
+    let field_name = &fields[index].name;
+    names
+        .iter()
+        .map(|name| match fields.iter().position(|f| &f.name == name) {
+            Some(i) if i == index => py_schema_err!("Field \"{}\": can't depend on itself", field_name),
+            Some(i) => Ok(i),
+            None => py_schema_err!("Field \"{}\": unknown field \"{}\" in dependencies", field_name, name),
+        })
+        .collect()
+}
+
 impl_py_gc_traverse!(ModelFieldsValidator {
     fields,
     extras_validator
 });
 
+impl ModelFieldsValidator {
+    /// The name and lookup key of each field, in order
+    pub(super) fn lookup_keys(&self) -> impl Iterator<Item = (&str, &LookupKey)> {
+        self.fields.iter().map(|field| (field.name.as_str(), &field.lookup_key))
+    }
+
+    /// The validator of the field called `name`
+    pub(super) fn field_validator(&self, name: &str) -> Option<&CombinedValidator> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| &field.validator)
+    }
+
+    /// Check the `requires` and `conflicts_with` of each field in the input between fields of which at least one
+    /// is `involved`, a required field which is missing and has no default already has a `missing` error
+    fn check_dependencies<'py>(
+        &self,
+        field_sources: &[FieldSource],
+        involved: impl Fn(usize) -> bool,
+        input: &(impl Input<'py> + ?Sized),
+        errors: &mut Vec<ValLineError>,
+    ) {
+        for (field_index, (field, source)) in self.fields.iter().zip(field_sources).enumerate() {
+            if *source != FieldSource::Input {
+                continue;
+            }
+            for &index in &field.requires {
+                let required = &self.fields[index];
+                if field_sources[index] == FieldSource::Default && (involved(field_index) || involved(index)) {
+                    let error_type = ErrorType::DependentFieldMissing {
+                        field_name: field.name.clone(),
+                        context: None,
+                    };
+                    errors.push(
+                        required
+                            .lookup_key
+                            .error(error_type, input, self.loc_by_alias, &required.name),
+                    );
+                }
+            }
+            for &index in &field.conflicts_with {
+                if field_sources[index] == FieldSource::Input && (involved(field_index) || involved(index)) {
+                    let error_type = ErrorType::ConflictingField {
+                        field_name: self.fields[index].name.clone(),
+                        context: None,
+                    };
+                    errors.push(
+                        field
+                            .lookup_key
+                            .error(error_type, input, self.loc_by_alias, &field.name),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Revalidate the fields of `dict`, a model's `__dict__`, named by the first item of each of `paths` in place,
+    /// an empty path names every field, `revalidate` is called with each field's validator, value and the rest of
+    /// its paths and returns `None` when the value has to be validated in full. Then check the `requires` and
+    /// `conflicts_with` involving those fields, taking the fields in `fields_set` to be from the input.
+    pub(super) fn revalidate_fields<'py>(
+        &self,
+        py: Python<'py>,
+        dict: &Bound<'py, PyDict>,
+        fields_set: Option<&Bound<'py, PyAny>>,
+        paths: &[&[Bound<'py, PyAny>]],
+        state: &mut ValidationState<'_, 'py>,
+        revalidate: RevalidateFn<'_, 'py>,
+    ) -> ValResult<()> {
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for first in paths.iter().filter_map(|path| path.first()) {
+            if !segment_name(first).is_some_and(|name| self.fields.iter().any(|field| field.name == name)) {
+                let loc: LocItem = match first.extract::<i64>() {
+                    Ok(index) => index.into(),
+                    Err(_) => first.str()?.to_string().into(),
+                };
+                let error_type = ErrorType::NoSuchAttribute {
+                    attribute: loc.to_string(),
+                    context: None,
+                };
+                errors.push(ValLineError::new_with_loc(error_type, dict.as_any(), loc));
+            }
+        }
+
+        let every_field = paths.iter().any(|path| path.is_empty());
+        let mut revalidated = vec![false; self.fields.len()];
+        for (index, field) in self.fields.iter().enumerate() {
+            let rest: Vec<&[Bound<'py, PyAny>]> = if every_field {
+                vec![&[]]
+            } else {
+                paths
+                    .iter()
+                    .filter(|path| path.first().and_then(segment_name) == Some(field.name.as_str()))
+                    .map(|path| &path[1..])
+                    .collect()
+            };
+            if rest.is_empty() {
+                continue;
+            }
+            revalidated[index] = true;
+            let Some(value) = dict.get_item(&field.name_py)? else {
+                errors.push(ValLineError::new_with_loc(
+                    ErrorTypeDefaults::Missing,
+                    dict.as_any(),
+                    field.name.clone(),
+                ));
+                continue;
+            };
+
+            // as with assignment, the other fields are the data
+            let data_dict = dict.copy()?;
+            data_dict.del_item(&field.name_py)?;
+            let state = &mut state.rebind_extra(|extra| extra.data = Some(data_dict));
+            let result = state.traced(
+                py,
+                field.validator.get_name(),
+                Some(&field.name),
+                |state| match revalidate(&field.validator, &value, &rest, state) {
+                    Ok(Some(output)) => Ok(output),
+                    Ok(None) => field.validator.validate(py, &value, state),
+                    Err(err) => Err(err),
+                },
+            );
+            match result {
+                Ok(output) => dict.set_item(&field.name_py, output)?,
+                Err(ValError::LineErrors(line_errors)) => {
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(&field.name)));
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        if self.has_dependencies {
+            let field_sources = self
+                .fields
+                .iter()
+                .map(|field| match fields_set {
+                    Some(fields_set) if !fields_set.contains(&field.name_py)? => Ok(FieldSource::Default),
+                    _ => Ok(FieldSource::Input),
+                })
+                .collect::<PyResult<Vec<_>>>()?;
+            self.check_dependencies(&field_sources, |index| revalidated[index], dict.as_any(), &mut errors);
+        }
+
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(ValError::LineErrors(errors)),
+        }
+    }
+
+    /// Validate the assignment of `field_value` to `field_name` and set the output in `dict`, the fields and extras
+    fn assign_field<'py>(
+        &self,
+        py: Python<'py>,
+        dict: &Bound<'py, PyDict>,
+        field_name: &str,
+        field_value: &Bound<'py, PyAny>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<()> {
+        let get_updated_dict = |output: PyObject| Ok(dict.set_item(field_name, output)?);
+
+        let prepare_result = |result: ValResult<PyObject>| match result {
+            Ok(output) => get_updated_dict(output),
+            Err(ValError::LineErrors(line_errors)) => {
+                let errors = line_errors
+                    .into_iter()
+                    .map(|e| e.with_outer_location(field_name))
+                    .collect();
+                Err(ValError::LineErrors(errors))
+            }
+            Err(err) => Err(err),
+        };
+
+        // by using dict but removing the field in question, we match V1 behaviour
+        let data_dict = dict.copy()?;
+        if let Err(err) = data_dict.del_item(field_name) {
+            // KeyError is fine here as the field might not be in the dict
+            if !err.get_type_bound(py).is(&PyType::new_bound::<PyKeyError>(py)) {
+                return Err(err.into());
+            }
+        }
+
+        let state = &mut state.rebind_extra(move |extra| extra.data = Some(data_dict));
+
+        if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
+            if field.frozen {
+                return Err(ValError::new_with_loc(
+                    ErrorTypeDefaults::FrozenField,
+                    field_value,
+                    field.name.to_string(),
+                ));
+            }
+
+            prepare_result(
+                state.traced(py, field.validator.get_name(), Some(&field.name), |state| {
+                    field.validator.validate(py, field_value, state)
+                }),
+            )
+        } else {
+            // Handle extra (unknown) field
+            // We partially use the extra_behavior for initialization / validation
+            // to determine how to handle assignment
+            // For models / typed dicts we forbid assigning extra attributes
+            // unless the user explicitly set extra_behavior to 'allow'
+            match self.extra_behavior {
+                ExtraBehavior::Allow => match self.extras_validator {
+                    Some(ref validator) => prepare_result(validator.validate(py, field_value, state)),
+                    None => get_updated_dict(field_value.to_object(py)),
+                },
+                ExtraBehavior::Forbid | ExtraBehavior::Ignore => Err(ValError::new_with_loc(
+                    ErrorType::NoSuchAttribute {
+                        attribute: field_name.to_string(),
+                        context: None,
+                    },
+                    field_value,
+                    field_name.to_string(),
+                )),
+            }
+        }
+    }
+
+    /// Move the extras out of `dict` after assignment, returning them, or `None` if extras aren't allowed
+    fn split_extra(&self, py: Python<'_>, dict: &Bound<'_, PyDict>) -> PyResult<PyObject> {
+        Ok(match &self.extra_behavior {
+            ExtraBehavior::Allow => {
+                let non_extra_data = PyDict::new_bound(py);
+                self.fields.iter().for_each(|f| {
+                    let popped_value = PyAnyMethods::get_item(&**dict, &f.name).unwrap();
+                    dict.del_item(&f.name).unwrap();
+                    non_extra_data.set_item(&f.name, popped_value).unwrap();
+                });
+                let new_extra = dict.copy()?;
+                dict.clear();
+                dict.update(non_extra_data.as_mapping())?;
+                new_extra.to_object(py)
+            }
+            _ => py.None(),
+        })
+    }
+}
+
+/// The field name a path segment is, if it's a string
+fn segment_name<'a>(segment: &'a Bound<'_, PyAny>) -> Option<&'a str> {
+    segment.downcast::<PyString>().ok()?.to_str().ok()
+}
+
 impl Validator for ModelFieldsValidator {
     fn validate<'py>(
         &self,
@@ -160,12 +481,22 @@ impl Validator for ModelFieldsValidator {
             } else {
                 Some(AHashSet::with_capacity(self.fields.len()))
             };
+        // we only track where values came from if fields have dependencies to check
+        let mut field_sources: Option<Vec<FieldSource>> = if self.has_dependencies {
+            Some(vec![FieldSource::Missing; self.fields.len()])
+        } else {
+            None
+        };
 
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(model_dict.clone()));
 
-            for field in &self.fields {
-                let op_key_value = match dict.get_item(&field.lookup_key) {
+            for (index, field) in self.fields.iter().enumerate() {
+                let lookup_key = match field.attribute_key {
+                    Some(ref attribute_key) if dict.is_py_get_attr() => attribute_key,
+                    _ => &field.lookup_key,
+                };
+                let op_key_value = match dict.get_item(lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
                         for err in line_errors {
@@ -176,12 +507,21 @@ impl Validator for ModelFieldsValidator {
                     Err(err) => return Err(err),
                 };
                 if let Some((lookup_path, value)) = op_key_value {
+                    if let Some(ref mut field_sources) = field_sources {
+                        field_sources[index] = FieldSource::Input;
+                    }
                     if let Some(ref mut used_keys) = used_keys {
                         // key is "used" whether or not validation passes, since we want to skip this key in
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    let segment = PathSegment::Field(field.name_py.clone_ref(py));
+                    let field_result = state.with_path(segment, |state| {
+                        state.traced(py, field.validator.get_name(), Some(&field.name), |state| {
+                            field.validator.validate(py, value.borrow_input(), state)
+                        })
+                    });
+                    match field_result {
                         Ok(value) => {
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
@@ -198,14 +538,38 @@ impl Validator for ModelFieldsValidator {
                     continue;
                 }
 
+                if let Some(ref mut field_sources) = field_sources {
+                    field_sources[index] = FieldSource::Default;
+                }
+                if let Some(ref on_missing) = field.on_missing {
+                    match on_missing.value(py, input, &field.name_py, &model_dict, &field.validator, state) {
+                        Ok(Some(value)) => {
+                            model_dict.set_item(&field.name_py, value)?;
+                            continue;
+                        }
+                        // `PydanticUseDefault` was raised, fall back to the default
+                        Ok(None) => (),
+                        Err(ValError::Omit) => continue,
+                        Err(ValError::LineErrors(line_errors)) => {
+                            for err in line_errors {
+                                errors.push(err.with_outer_location(&field.name));
+                            }
+                            continue;
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
                         model_dict.set_item(&field.name_py, value)?;
                     }
                     Ok(None) => {
+                        if let Some(ref mut field_sources) = field_sources {
+                            field_sources[index] = FieldSource::Missing;
+                        }
                         // This means there was no default value
-                        errors.push(field.lookup_key.error(
+                        errors.push(lookup_key.error(
                             ErrorTypeDefaults::Missing,
                             input,
                             self.loc_by_alias,
@@ -227,6 +591,10 @@ impl Validator for ModelFieldsValidator {
             }
         }
 
+        if let Some(field_sources) = field_sources {
+            self.check_dependencies(&field_sources, |_| true, input, &mut errors);
+        }
+
         if let Some(used_keys) = used_keys {
             struct ValidateToModelExtra<'a, 's, 'py> {
                 py: Python<'py>,
@@ -350,89 +718,35 @@ impl Validator for ModelFieldsValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let dict = obj.downcast::<PyDict>()?;
+        self.assign_field(py, dict, field_name, field_value, state)?;
+        let new_extra = self.split_extra(py, dict)?;
+        let fields_set = PySet::new_bound(py, &[field_name.to_string()])?;
+        Ok((dict.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
+    }
 
-        let get_updated_dict = |output: PyObject| {
-            dict.set_item(field_name, output)?;
-            Ok(dict)
-        };
-
-        let prepare_result = |result: ValResult<PyObject>| match result {
-            Ok(output) => get_updated_dict(output),
-            Err(ValError::LineErrors(line_errors)) => {
-                let errors = line_errors
-                    .into_iter()
-                    .map(|e| e.with_outer_location(field_name))
-                    .collect();
-                Err(ValError::LineErrors(errors))
-            }
-            Err(err) => Err(err),
-        };
-
-        // by using dict but removing the field in question, we match V1 behaviour
-        let data_dict = dict.copy()?;
-        if let Err(err) = data_dict.del_item(field_name) {
-            // KeyError is fine here as the field might not be in the dict
-            if !err.get_type_bound(py).is(&PyType::new_bound::<PyKeyError>(py)) {
-                return Err(err.into());
+    fn validate_assignment_many<'py>(
+        &self,
+        py: Python<'py>,
+        obj: &Bound<'py, PyAny>,
+        values: &Bound<'py, PyDict>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let dict = obj.downcast::<PyDict>()?;
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (field_name, field_value) in values {
+            let field_name = field_name.downcast_into::<PyString>()?;
+            match self.assign_field(py, dict, field_name.to_str()?, &field_value, state) {
+                Ok(()) => {}
+                Err(ValError::LineErrors(line_errors)) => errors.extend(line_errors),
+                Err(err) => return Err(err),
             }
         }
-
-        let new_data = {
-            let state = &mut state.rebind_extra(move |extra| extra.data = Some(data_dict));
-
-            if let Some(field) = self.fields.iter().find(|f| f.name == field_name) {
-                if field.frozen {
-                    return Err(ValError::new_with_loc(
-                        ErrorTypeDefaults::FrozenField,
-                        field_value,
-                        field.name.to_string(),
-                    ));
-                }
-
-                prepare_result(field.validator.validate(py, field_value, state))?
-            } else {
-                // Handle extra (unknown) field
-                // We partially use the extra_behavior for initialization / validation
-                // to determine how to handle assignment
-                // For models / typed dicts we forbid assigning extra attributes
-                // unless the user explicitly set extra_behavior to 'allow'
-                match self.extra_behavior {
-                    ExtraBehavior::Allow => match self.extras_validator {
-                        Some(ref validator) => prepare_result(validator.validate(py, field_value, state))?,
-                        None => get_updated_dict(field_value.to_object(py))?,
-                    },
-                    ExtraBehavior::Forbid | ExtraBehavior::Ignore => {
-                        return Err(ValError::new_with_loc(
-                            ErrorType::NoSuchAttribute {
-                                attribute: field_name.to_string(),
-                                context: None,
-                            },
-                            field_value,
-                            field_name.to_string(),
-                        ))
-                    }
-                }
-            }
-        };
-
-        let new_extra = match &self.extra_behavior {
-            ExtraBehavior::Allow => {
-                let non_extra_data = PyDict::new_bound(py);
-                self.fields.iter().for_each(|f| {
-                    let popped_value = PyAnyMethods::get_item(&**new_data, &f.name).unwrap();
-                    new_data.del_item(&f.name).unwrap();
-                    non_extra_data.set_item(&f.name, popped_value).unwrap();
-                });
-                let new_extra = new_data.copy()?;
-                new_data.clear();
-                new_data.update(non_extra_data.as_mapping())?;
-                new_extra.to_object(py)
-            }
-            _ => py.None(),
-        };
-
-        let fields_set = PySet::new_bound(py, &[field_name.to_string()])?;
-        Ok((new_data.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        let new_extra = self.split_extra(py, dict)?;
+        let fields_set = PySet::new_bound(py, &values.keys().iter().collect::<Vec<_>>())?;
+        Ok((dict.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
     }
 
     fn get_name(&self) -> &str {
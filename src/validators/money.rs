@@ -0,0 +1,210 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict, PyList, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::decimal::{create_decimal, extract_decimal_digits_info, get_decimal_type, quantize, quantize_context};
+use super::literal::expected_repr_name;
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Active ISO 4217 currency codes by the number of decimal places of their minor unit, each sorted
+static CURRENCIES: [&[&str]; 5] = [
+    &[
+        "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "UYI", "VND", "VUV", "XAF", "XOF",
+        "XPF",
+    ],
+    &[],
+    &[
+        "AED", "AFN", "ALL", "AMD", "ANG", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN", "BMD", "BND",
+        "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD", "CDF", "CHE", "CHF", "CHW", "CNY", "COP", "COU",
+        "CRC", "CUC", "CUP", "CVE", "CZK", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL",
+        "GHS", "GIP", "GMD", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR", "IRR", "JMD", "KES", "KGS",
+        "KHR", "KPW", "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "MAD", "MDL", "MGA", "MKD", "MMK", "MNT", "MOP",
+        "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN", "NIO", "NOK", "NPR", "NZD", "PAB", "PEN",
+        "PGK", "PHP", "PKR", "PLN", "QAR", "RON", "RSD", "RUB", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE",
+        "SLL", "SOS", "SRD", "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TOP", "TRY", "TTD", "TWD", "TZS",
+        "UAH", "USD", "USN", "UYU", "UZS", "VED", "VES", "WST", "XCD", "YER", "ZAR", "ZMW", "ZWG", "ZWL",
+    ],
+    &["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"],
+    &["CLF", "UYW"],
+];
+
+/// The currency code and number of decimal places of its minor unit, codes are matched case-insensitively
+fn minor_unit(code: &str) -> Option<(&'static str, usize)> {
+    if code.len() != 3 {
+        return None;
+    }
+    let code = code.to_ascii_uppercase();
+    CURRENCIES.iter().enumerate().find_map(|(places, codes)| {
+        let index = codes.binary_search(&code.as_str()).ok()?;
+        Some((codes[index], places))
+    })
+}
+
+#[derive(Debug)]
+pub struct MoneyValidator {
+    // validates the dict form, `{'amount': Decimal, 'currency': str}`
+    fields_validator: Box<CombinedValidator>,
+    currencies: Option<Vec<&'static str>>,
+    currencies_repr: String,
+    round: bool,
+    context: Py<PyAny>,
+    // `Decimal('1e-{n}')` for each number of decimal places a minor unit can have
+    exps: Vec<Py<PyAny>>,
+}
+
+impl BuildValidator for MoneyValidator {
+    const EXPECTED_TYPE: &'static str = "money";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let currencies = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "currencies"))? {
+            Some(currencies) if currencies.is_empty() => return py_schema_err!("`currencies` must not be empty"),
+            Some(currencies) => Some(
+                currencies
+                    .iter()
+                    .map(|currency| {
+                        let currency = currency.extract::<String>()?;
+                        match minor_unit(&currency) {
+                            Some((code, _)) => Ok(code),
+                            None => py_schema_err!("Invalid ISO 4217 currency code: '{}'", currency),
+                        }
+                    })
+                    .collect::<PyResult<Vec<_>>>()?,
+            ),
+            None => None,
+        };
+        let currencies_repr = match &currencies {
+            Some(currencies) => expected_repr_name(currencies.iter().map(|c| format!("'{c}'")).collect(), "money").0,
+            None => String::new(),
+        };
+        let rounding = schema.get_as(intern!(py, "rounding"))?;
+        let field = |schema_type: &str| -> PyResult<Bound<'_, PyDict>> {
+            let field = PyDict::new_bound(py);
+            field.set_item("type", "typed-dict-field")?;
+            field.set_item("schema", [("type", schema_type)].into_py_dict_bound(py))?;
+            Ok(field)
+        };
+        let fields = PyDict::new_bound(py);
+        fields.set_item("amount", field("decimal")?)?;
+        fields.set_item("currency", field("str")?)?;
+        let fields_schema = PyDict::new_bound(py);
+        fields_schema.set_item("type", "typed-dict")?;
+        fields_schema.set_item("fields", fields)?;
+        let exps = (0..CURRENCIES.len())
+            .map(|places| Ok(get_decimal_type(py).call1((format!("1e-{places}"),))?.unbind()))
+            .collect::<PyResult<_>>()?;
+        Ok(Self {
+            fields_validator: Box::new(build_validator(&fields_schema, config, definitions)?),
+            currencies,
+            currencies_repr,
+            round: rounding.is_some(),
+            context: quantize_context(py, rounding)?.unbind(),
+            exps,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(MoneyValidator {
+    fields_validator,
+    context,
+    exps
+});
+
+impl Validator for MoneyValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        // errors in the dict form are located at the `amount` or `currency` key
+        let (amount, currency, from_str) = if let Ok(either_str) = input.validate_str(true, false) {
+            let either_str = either_str.into_inner();
+            let money_str = either_str.as_cow()?;
+            let (amount, currency) =
+                split_money_str(&money_str).ok_or_else(|| ValError::new(ErrorTypeDefaults::MoneyParsing, input))?;
+            let amount = create_decimal(PyString::new_bound(py, amount).as_any(), input)?;
+            (amount, PyString::new_bound(py, currency), true)
+        } else {
+            input
+                .validate_dict(false)
+                .map_err(|_| ValError::new(ErrorTypeDefaults::MoneyType, input))?;
+            let fields = self.fields_validator.validate(py, input, state)?.into_bound(py);
+            let fields = fields.downcast::<PyDict>()?;
+            let amount = fields.get_as_req(intern!(py, "amount"))?;
+            let currency = fields.get_as_req(intern!(py, "currency"))?;
+            (amount, currency, false)
+        };
+        let error = |error_type: ErrorType, value: &Bound<'py, PyAny>, loc: &'static str| {
+            if from_str {
+                ValError::new(error_type, input)
+            } else {
+                ValError::new_with_loc(error_type, value, loc)
+            }
+        };
+
+        let Some((code, places)) = minor_unit(currency.to_str()?) else {
+            return Err(error(ErrorTypeDefaults::MoneyCurrency, currency.as_any(), "currency"));
+        };
+        if let Some(currencies) = &self.currencies {
+            if !currencies.contains(&code) {
+                let expected = self.currencies_repr.clone();
+                let error_type = ErrorType::LiteralError {
+                    expected,
+                    context: None,
+                };
+                return Err(error(error_type, currency.as_any(), "currency"));
+            }
+        }
+
+        if !amount.call_method0(intern!(py, "is_finite"))?.extract::<bool>()? {
+            return Err(error(ErrorTypeDefaults::FiniteNumber, &amount, "amount"));
+        }
+        if !self.round {
+            let (decimals, _) = extract_decimal_digits_info(&amount, true)?;
+            if decimals > places as u64 {
+                let error_type = ErrorType::DecimalMaxPlaces {
+                    decimal_places: places as u64,
+                    context: None,
+                };
+                return Err(error(error_type, &amount, "amount"));
+            }
+        }
+        // amounts are always given exactly the currency's number of decimal places
+        let amount = quantize(&amount, self.exps[places].bind(py), &self.context)?;
+
+        let money = PyDict::new_bound(py);
+        money.set_item(intern!(py, "amount"), amount)?;
+        money.set_item(intern!(py, "currency"), code)?;
+        Ok(money.into())
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+/// Split strings like `'12.34 USD'` or `'USD 12.34'` into the amount and currency
+fn split_money_str(money: &str) -> Option<(&str, &str)> {
+    let mut parts = money.split_whitespace();
+    let (first, second) = (parts.next()?, parts.next()?);
+    if parts.next().is_some() {
+        return None;
+    }
+    let is_code = |part: &str| part.len() == 3 && part.bytes().all(|b| b.is_ascii_alphabetic());
+    match (is_code(first), is_code(second)) {
+        (false, true) => Some((first, second)),
+        (true, false) => Some((second, first)),
+        _ => None,
+    }
+}
@@ -0,0 +1,92 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyString, PyTuple, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{input_as_python_instance, Input, InputType};
+use crate::tools::SchemaDict;
+
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+#[derive(Debug)]
+pub struct NamedTupleValidator {
+    strict: bool,
+    class: Py<PyType>,
+    // validates the fields like the arguments of `cls`, by name from dicts and by position from sequences
+    arguments_validator: Box<CombinedValidator>,
+    name: String,
+}
+
+impl BuildValidator for NamedTupleValidator {
+    const EXPECTED_TYPE: &'static str = "namedtuple";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let class = schema.get_as_req::<Bound<'_, PyType>>(intern!(py, "cls"))?;
+        let fields: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
+        if fields.is_empty() {
+            return py_schema_err!("`fields` must not be empty");
+        }
+
+        let parameters = PyList::empty_bound(py);
+        for (name, field_schema) in fields {
+            let parameter = PyDict::new_bound(py);
+            parameter.set_item("name", name.downcast_into::<PyString>()?)?;
+            parameter.set_item("schema", field_schema)?;
+            parameter.set_item("mode", "positional_or_keyword")?;
+            parameters.append(parameter)?;
+        }
+        let arguments_schema = PyDict::new_bound(py);
+        arguments_schema.set_item("type", "arguments")?;
+        arguments_schema.set_item("arguments_schema", parameters)?;
+        arguments_schema.set_item("extra_behavior", "forbid")?;
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            arguments_validator: Box::new(build_validator(&arguments_schema, config, definitions)?),
+            name: class.qualname()?.to_string(),
+            class: class.unbind(),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(NamedTupleValidator {
+    class,
+    arguments_validator
+});
+
+impl Validator for NamedTupleValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let class = self.class.bind(py);
+        if state.strict_or(self.strict)
+            && state.extra().input_type == InputType::Python
+            && input_as_python_instance(input, class).is_none()
+        {
+            return Err(ValError::new(
+                ErrorType::IsInstanceOf {
+                    class: self.name.clone(),
+                    context: None,
+                },
+                input,
+            ));
+        }
+        let output = self.arguments_validator.validate(py, input, state)?;
+        let (args, kwargs): (Bound<'_, PyTuple>, Bound<'_, PyDict>) = output.extract(py)?;
+        Ok(class.call(args, Some(&kwargs))?.unbind())
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
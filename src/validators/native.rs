@@ -0,0 +1,54 @@
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::build_tools::is_strict;
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::native::NativeType;
+
+use super::function::convert_err;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Validates with the `validate` function of a native type from an extension module
+#[derive(Debug, Clone)]
+pub struct NativeValidator {
+    native: NativeType,
+    strict: bool,
+}
+
+impl BuildValidator for NativeValidator {
+    const EXPECTED_TYPE: &'static str = "native";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            native: NativeType::from_schema(schema)?,
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(NativeValidator { native });
+
+impl Validator for NativeValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let input_py = input.to_object(py).into_bound(py);
+        match self.native.validate(&input_py, state.strict_or(self.strict)) {
+            Ok(value) => Ok(value.unbind()),
+            Err(err) => Err(convert_err(py, err, input)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.native.name
+    }
+}
@@ -32,6 +32,13 @@ impl BuildValidator for NullableValidator {
 
 impl_py_gc_traverse!(NullableValidator { validator });
 
+impl NullableValidator {
+    /// The validator of values which aren't `None`
+    pub(super) fn inner_validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
+}
+
 impl Validator for NullableValidator {
     fn validate<'py>(
         &self,
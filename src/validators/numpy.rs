@@ -0,0 +1,35 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::errors::ValResult;
+use crate::input::Input;
+use crate::serializers::ObTypeLookup;
+use crate::tools::SchemaDict;
+
+use super::{Exactness, ValidationState};
+
+/// Whether the `numpy_support` config is set
+pub(super) fn numpy_support(config: Option<&Bound<'_, PyDict>>) -> PyResult<bool> {
+    match config {
+        Some(config) => Ok(config.get_as(intern!(config.py(), "numpy_support"))?.unwrap_or(false)),
+        None => Ok(false),
+    }
+}
+
+/// The Python value held by a numpy scalar, from `item()`, which is validated instead of the scalar in lax mode,
+/// `None` in strict mode or if `input` isn't a numpy scalar
+pub(super) fn numpy_item<'py>(
+    input: &(impl Input<'py> + ?Sized),
+    strict: bool,
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<Option<Bound<'py, PyAny>>> {
+    let Some(value) = input.as_python().filter(|_| !strict) else {
+        return Ok(None);
+    };
+    let item = ObTypeLookup::cached_numpy(value.py()).numpy_item(value)?;
+    if item.is_some() {
+        state.floor_exactness(Exactness::Lax);
+    }
+    Ok(item)
+}
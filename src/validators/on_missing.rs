@@ -0,0 +1,53 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::errors::{ValError, ValResult};
+use crate::input::Input;
+use crate::tools::SchemaDict;
+
+use super::function::convert_err;
+use super::{CombinedValidator, ValidationState, Validator};
+
+/// The `on_missing` function of a typed dict or model field, called with the field name, the fields validated so
+/// far and the context when the field isn't in the input
+#[derive(Debug)]
+pub(super) struct OnMissing {
+    func: PyObject,
+}
+
+impl_py_gc_traverse!(OnMissing { func });
+
+impl OnMissing {
+    pub fn from_field(field_info: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let py = field_info.py();
+        Ok(field_info
+            .get_as::<Bound<'_, PyAny>>(intern!(py, "on_missing"))?
+            .map(|func| Self { func: func.unbind() }))
+    }
+
+    /// The value returned by the function after validation by `validator`, or `None` if the function raised
+    /// `PydanticUseDefault` so the field's default should be used, errors have no field location
+    pub fn value<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        field_name: &Py<PyString>,
+        data: &Bound<'py, PyDict>,
+        validator: &CombinedValidator,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<Option<PyObject>> {
+        state.count_python_callback()?;
+        let context = state.extra().context;
+        let value = match self.func.call1(py, (field_name, data, context)) {
+            Ok(value) => value.into_bound(py),
+            Err(err) => {
+                return match convert_err(py, err, input) {
+                    ValError::UseDefault => Ok(None),
+                    err => Err(err),
+                }
+            }
+        };
+        validator.validate(py, &value, state).map(Some)
+    }
+}
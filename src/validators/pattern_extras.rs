@@ -0,0 +1,59 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::build_tools::py_schema_err;
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+use super::string::Pattern;
+use super::{build_validator, CombinedValidator, DefinitionsBuilder};
+
+/// Validators for extra keys matching regex patterns, like `patternProperties` in JSON Schema, keys which
+/// match no pattern are handled as other extra keys
+#[derive(Debug)]
+pub(super) struct PatternExtras {
+    patterns: Vec<(Pattern, CombinedValidator)>,
+}
+
+impl PatternExtras {
+    /// Build from the dict of patterns to schemas under `key` in `schema`, if it's set
+    pub fn build(
+        schema: &Bound<'_, PyDict>,
+        key: &Bound<'_, PyString>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<Option<Self>> {
+        let Some(pattern_schemas) = schema.get_as::<Bound<'_, PyDict>>(key)? else {
+            return Ok(None);
+        };
+        let mut patterns = Vec::with_capacity(pattern_schemas.len());
+        for (pattern, pattern_schema) in pattern_schemas {
+            let validator = match build_validator(&pattern_schema, config, definitions) {
+                Ok(v) => v,
+                Err(err) => return py_schema_err!("Pattern {}:\n  {}", pattern.repr()?, err),
+            };
+            patterns.push((Pattern::compile_with_config(pattern, config)?, validator));
+        }
+        Ok(Some(Self { patterns }))
+    }
+
+    /// The validator of the first pattern which matches `key`
+    pub fn find(&self, py: Python<'_>, key: &str) -> PyResult<Option<&CombinedValidator>> {
+        for (pattern, validator) in &self.patterns {
+            if pattern.is_match(py, key)? {
+                return Ok(Some(validator));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl PyGcTraverse for PatternExtras {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for (_, validator) in &self.patterns {
+            validator.py_gc_traverse(visit)?;
+        }
+        Ok(())
+    }
+}
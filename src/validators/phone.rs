@@ -0,0 +1,90 @@
+use phonenumber::country::Id;
+use phonenumber::Mode;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::Input;
+use crate::phone::{format_phone_number, parse_phone_number, parse_region, PyPhoneNumber};
+use crate::tools::SchemaDict;
+
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// Validates phone numbers in E.164, international or, with `default_region`, national format, returning
+/// the E.164 string or with `as_phone_number` a `PhoneNumber`
+#[derive(Debug, Clone)]
+pub struct PhoneValidator {
+    strict: bool,
+    // the region numbers without a `+` and country code are read as national numbers of
+    default_region: Option<Id>,
+    as_phone_number: bool,
+}
+
+impl BuildValidator for PhoneValidator {
+    const EXPECTED_TYPE: &'static str = "phone";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let default_region: Option<Bound<'_, PyString>> = schema.get_as(intern!(py, "default_region"))?;
+        let default_region = match default_region {
+            Some(s) => {
+                let s = s.to_str()?;
+                match parse_region(s) {
+                    Some(id) => Some(id),
+                    None => {
+                        return py_schema_err!(
+                            "Invalid `default_region` value: {}, expected an ISO 3166-1 alpha-2 code",
+                            s
+                        )
+                    }
+                }
+            }
+            None => None,
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            default_region,
+            as_phone_number: schema.get_as(intern!(py, "as_phone_number"))?.unwrap_or(false),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(PhoneValidator {});
+
+impl Validator for PhoneValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if let Some(py_phone) = input.as_python().and_then(|any| any.downcast::<PyPhoneNumber>().ok()) {
+            return if self.as_phone_number {
+                Ok(py_phone.clone().into_py(py))
+            } else {
+                Ok(py_phone.get().__str__().into_py(py))
+            };
+        }
+        let either_str = input
+            .validate_str(state.strict_or(self.strict), false)?
+            .unpack(state, input, "str");
+        let number = parse_phone_number(&either_str.as_cow()?, self.default_region)
+            .map_err(|error| ValError::new(ErrorType::PhoneParsing { error, context: None }, input))?;
+        if self.as_phone_number {
+            Ok(PyPhoneNumber::new(number).into_py(py))
+        } else {
+            Ok(format_phone_number(&number, Mode::E164).into_py(py))
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
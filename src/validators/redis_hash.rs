@@ -0,0 +1,118 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use jiter::{JsonValue, LazyIndexMap};
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+/// Read a Redis hash, e.g. written by `SchemaSerializer.to_redis_hash`, so it can be validated as if it were
+/// JSON.
+///
+/// Fields are split on `separator` into nested objects, values which are JSON other than numbers are parsed,
+/// anything else (including numbers, so decimals don't lose precision) is read as a string.
+pub fn parse_redis_hash(mapping: &Bound<'_, PyAny>, separator: &str) -> PyResult<JsonValue<'static>> {
+    let py = mapping.py();
+    let mut root = Object::default();
+    for item in mapping.call_method0(intern!(py, "items"))?.iter()? {
+        let (field, value): (Bound<'_, PyAny>, Bound<'_, PyAny>) = item?.extract()?;
+        let field = hash_str(&field)?;
+        let value = parse_value(hash_str(&value)?);
+        let mut parts = field.split(separator).peekable();
+        let mut object = &mut root;
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                if !object.insert(part, Node::Value(value)) {
+                    return Err(field_conflict(&field));
+                }
+                break;
+            }
+            object = match object.get_or_insert_object(part) {
+                Some(object) => object,
+                None => return Err(field_conflict(&field)),
+            };
+        }
+    }
+    Ok(root.into_json())
+}
+
+/// Redis clients return bytes unless they're asked to decode responses
+fn hash_str(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    if let Ok(py_str) = value.downcast::<PyString>() {
+        Ok(py_str.to_str()?.to_string())
+    } else if let Ok(py_bytes) = value.downcast::<PyBytes>() {
+        String::from_utf8(py_bytes.as_bytes().to_vec())
+            .map_err(|e| PyValueError::new_err(format!("Invalid Redis hash: {e}")))
+    } else {
+        Err(PyValueError::new_err(format!(
+            "Invalid Redis hash: fields and values must be str or bytes, got '{}'",
+            value.get_type().qualname()?
+        )))
+    }
+}
+
+fn parse_value(value: String) -> JsonValue<'static> {
+    let json = JsonValue::parse(value.as_bytes(), false)
+        .ok()
+        .filter(|json| !matches!(json, JsonValue::Int(_) | JsonValue::BigInt(_) | JsonValue::Float(_)))
+        .map(JsonValue::into_static);
+    json.unwrap_or(JsonValue::Str(Cow::Owned(value)))
+}
+
+fn field_conflict(field: &str) -> PyErr {
+    PyValueError::new_err(format!(
+        "Invalid Redis hash: field '{field}' conflicts with another field"
+    ))
+}
+
+enum Node {
+    Value(JsonValue<'static>),
+    Object(Object),
+}
+
+#[derive(Default)]
+struct Object {
+    fields: Vec<(String, Node)>,
+    index: AHashMap<String, usize>,
+}
+
+impl Object {
+    /// Returns false if there's already a field called `key`
+    fn insert(&mut self, key: &str, node: Node) -> bool {
+        if self.index.contains_key(key) {
+            return false;
+        }
+        self.index.insert(key.to_string(), self.fields.len());
+        self.fields.push((key.to_string(), node));
+        true
+    }
+
+    /// Returns None if `key` is a value rather than an object
+    fn get_or_insert_object(&mut self, key: &str) -> Option<&mut Object> {
+        let index = match self.index.get(key) {
+            Some(index) => *index,
+            None => {
+                self.insert(key, Node::Object(Object::default()));
+                self.fields.len() - 1
+            }
+        };
+        match &mut self.fields[index].1 {
+            Node::Object(object) => Some(object),
+            Node::Value(_) => None,
+        }
+    }
+
+    fn into_json(self) -> JsonValue<'static> {
+        let mut map = LazyIndexMap::new();
+        for (key, node) in self.fields {
+            let value = match node {
+                Node::Value(value) => value,
+                Node::Object(object) => object.into_json(),
+            };
+            map.insert(Cow::Owned(key), value);
+        }
+        JsonValue::Object(Arc::new(map))
+    }
+}
@@ -0,0 +1,123 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyInt, PyList, PyString, PyTuple};
+
+use crate::errors::{ValError, ValResult};
+
+use super::model::force_setattr;
+use super::{CombinedValidator, ValidationState};
+
+/// Revalidates a field's value given the rest of the paths into it, returning `None` when the value has to be
+/// validated in full
+pub(super) type RevalidateFn<'a, 'py> = &'a mut dyn FnMut(
+    &CombinedValidator,
+    &Bound<'py, PyAny>,
+    &[&[Bound<'py, PyAny>]],
+    &mut ValidationState<'_, 'py>,
+) -> ValResult<Option<PyObject>>;
+
+/// The `paths` argument of `revalidate_paths`, each a field name or a tuple or list of field names and indexes
+pub(super) fn extract_paths<'py>(paths: &Bound<'py, PyAny>) -> PyResult<Vec<Vec<Bound<'py, PyAny>>>> {
+    if paths.is_instance_of::<PyString>() {
+        return Err(PyTypeError::new_err("`paths` should be a sequence of paths, not a str"));
+    }
+    paths
+        .iter()?
+        .map(|path| {
+            let path = path?;
+            if path.is_instance_of::<PyString>() {
+                return Ok(vec![path]);
+            }
+            let segments: Vec<Bound<'py, PyAny>> = if let Ok(list) = path.downcast::<PyList>() {
+                list.iter().collect()
+            } else if let Ok(tuple) = path.downcast::<PyTuple>() {
+                tuple.iter().collect()
+            } else {
+                return Err(PyTypeError::new_err(format!(
+                    "Each path should be a str, tuple or list, not {}",
+                    path.get_type().qualname()?
+                )));
+            };
+            if let Some(segment) = segments.iter().find(|segment| {
+                !(segment.is_instance_of::<PyString>()
+                    || (segment.is_instance_of::<PyInt>() && !segment.is_instance_of::<PyBool>()))
+            }) {
+                return Err(PyTypeError::new_err(format!(
+                    "Path segments should be str or int, not {}",
+                    segment.get_type().qualname()?
+                )));
+            }
+            Ok(segments)
+        })
+        .collect()
+}
+
+/// Revalidate the parts of `instance` named by `paths` and set them on it, restoring the `__dict__` of each model
+/// revalidated if validation fails
+pub(super) fn revalidate_instance<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+    instance: &Bound<'py, PyAny>,
+    paths: &[&[Bound<'py, PyAny>]],
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<PyObject> {
+    let mut undo = Vec::new();
+    let result = match revalidate_paths(py, validator, instance, paths, state, &mut undo) {
+        Ok(Some(output)) => return Ok(output),
+        Ok(None) => Err(ValError::InternalErr(PyTypeError::new_err(
+            "`revalidate_paths` expects an instance of the model being validated",
+        ))),
+        Err(err) => Err(err),
+    };
+    for (model, dict) in undo.into_iter().rev() {
+        force_setattr(py, &model, intern!(py, "__dict__"), dict)?;
+    }
+    result
+}
+
+/// Revalidate the parts of `instance`, validated by `validator`, named by `paths`, looking through model after
+/// validators, which are called again, defaults, nullables and definition references to models, whose revalidated
+/// `__dict__` is set with their old `__dict__` added to `undo`. `None` when `instance` has to be validated in full.
+fn revalidate_paths<'py>(
+    py: Python<'py>,
+    validator: &CombinedValidator,
+    instance: &Bound<'py, PyAny>,
+    paths: &[&[Bound<'py, PyAny>]],
+    state: &mut ValidationState<'_, 'py>,
+    undo: &mut Vec<(Bound<'py, PyAny>, Bound<'py, PyAny>)>,
+) -> ValResult<Option<PyObject>> {
+    match validator {
+        CombinedValidator::Model(v) => {
+            let mut revalidate = |validator: &CombinedValidator,
+                                  value: &Bound<'py, PyAny>,
+                                  paths: &[&[Bound<'py, PyAny>]],
+                                  state: &mut ValidationState<'_, 'py>| {
+                revalidate_paths(py, validator, value, paths, state, undo)
+            };
+            let Some(dict) = v.revalidate_dict(py, instance, paths, state, &mut revalidate)? else {
+                return Ok(None);
+            };
+            let dict_name = intern!(py, "__dict__");
+            undo.push((instance.clone(), instance.getattr(dict_name)?));
+            force_setattr(py, instance, dict_name, dict)?;
+            Ok(Some(instance.to_object(py)))
+        }
+        CombinedValidator::FunctionAfter(v) => {
+            match revalidate_paths(py, v.inner_validator(), instance, paths, state, undo)? {
+                Some(output) => v.call_func(py, output, instance, state).map(Some),
+                None => Ok(None),
+            }
+        }
+        CombinedValidator::WithDefault(v) => revalidate_paths(py, v.inner_validator(), instance, paths, state, undo),
+        CombinedValidator::Nullable(v) if !instance.is_none() => {
+            revalidate_paths(py, v.inner_validator(), instance, paths, state, undo)
+        }
+        CombinedValidator::DefinitionRef(v) => v.definition().read(|definition| {
+            definition.map_or(Ok(None), |definition| {
+                revalidate_paths(py, definition, instance, paths, state, undo)
+            })
+        }),
+        _ => Ok(None),
+    }
+}
@@ -2,7 +2,9 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
 use crate::errors::ValResult;
-use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
+use crate::input::{
+    validate_iter_to_set, BorrowInput, BuildSet, ConsumeIterator, Input, MaxErrorItems, OrderedSetBuilder, ValidatedSet,
+};
 use crate::tools::SchemaDict;
 
 use super::list::min_length_check;
@@ -15,7 +17,9 @@ pub struct SetValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: String,
-    fail_fast: bool,
+    max_error_items: MaxErrorItems,
+    // the unique items are returned in the order they're first seen, as a list
+    ordered: bool,
 }
 
 macro_rules! set_build {
@@ -43,7 +47,8 @@ macro_rules! set_build {
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
                 name,
-                fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+                max_error_items: crate::input::MaxErrorItems::from_schema(schema)?,
+                ordered: schema.get_as(pyo3::intern!(py, "ordered"))?.unwrap_or(false),
             }
             .into())
         }
@@ -65,16 +70,35 @@ impl Validator for SetValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_set(state.strict_or(self.strict))?.unpack(state);
+        let collection = input
+            .validate_set(state.strict_or(self.strict))?
+            .unpack(state, input, "set");
+        if self.ordered {
+            let ordered = OrderedSetBuilder::new(py)?;
+            collection.iterate(ValidateToSet {
+                py,
+                input,
+                set: &ordered,
+                field_type: "Set",
+                max_length: self.max_length,
+                item_validator: &self.item_validator,
+                state,
+                max_error_items: self.max_error_items,
+            })??;
+            let items = ordered.into_list();
+            min_length_check!(input, "Set", self.min_length, items);
+            return Ok(items.into_py(py));
+        }
         let set = PySet::empty_bound(py)?;
         collection.iterate(ValidateToSet {
             py,
             input,
             set: &set,
+            field_type: "Set",
             max_length: self.max_length,
             item_validator: &self.item_validator,
             state,
-            fail_fast: self.fail_fast,
+            max_error_items: self.max_error_items,
         })??;
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py(py))
@@ -85,20 +109,22 @@ impl Validator for SetValidator {
     }
 }
 
-struct ValidateToSet<'a, 's, 'py, I: Input<'py> + ?Sized> {
-    py: Python<'py>,
-    input: &'a I,
-    set: &'a Bound<'py, PySet>,
-    max_length: Option<usize>,
-    item_validator: &'a CombinedValidator,
-    state: &'a mut ValidationState<'s, 'py>,
-    fail_fast: bool,
+pub(crate) struct ValidateToSet<'a, 's, 'py, I: Input<'py> + ?Sized, S: BuildSet> {
+    pub py: Python<'py>,
+    pub input: &'a I,
+    pub set: &'a S,
+    pub field_type: &'static str,
+    pub max_length: Option<usize>,
+    pub item_validator: &'a CombinedValidator,
+    pub state: &'a mut ValidationState<'s, 'py>,
+    pub max_error_items: MaxErrorItems,
 }
 
-impl<'py, T, I> ConsumeIterator<PyResult<T>> for ValidateToSet<'_, '_, 'py, I>
+impl<'py, T, I, S> ConsumeIterator<PyResult<T>> for ValidateToSet<'_, '_, 'py, I, S>
 where
     T: BorrowInput<'py>,
     I: Input<'py> + ?Sized,
+    S: BuildSet,
 {
     type Output = ValResult<()>;
     fn consume_iterator(self, iterator: impl Iterator<Item = PyResult<T>>) -> ValResult<()> {
@@ -107,11 +133,11 @@ where
             self.set,
             iterator,
             self.input,
-            "Set",
+            self.field_type,
             self.max_length,
             self.item_validator,
             self.state,
-            self.fail_fast,
+            self.max_error_items,
         )
     }
 }
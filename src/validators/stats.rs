@@ -0,0 +1,101 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use ahash::AHashMap;
+use pyo3::prelude::*;
+use pyo3::types::{IntoPyDict, PyDict, PyTuple};
+
+use crate::errors::{LocItem, ValLineError};
+
+// counts of each error type, keyed by the location of the errors
+type FieldFailures = AHashMap<Vec<String>, AHashMap<String, u64>>;
+
+/// Counters of how a `SchemaValidator` has been used, see `SchemaValidator.stats()`
+#[derive(Debug, Default)]
+pub struct ValidatorStats {
+    calls: AtomicU64,
+    failures: AtomicU64,
+    lax_fallbacks: AtomicU64,
+    union_retries: AtomicU64,
+    // only counted with the `field_failure_stats` config
+    field_failures: Option<Mutex<FieldFailures>>,
+}
+
+impl ValidatorStats {
+    pub fn new(field_failure_stats: bool) -> Self {
+        Self {
+            field_failures: field_failure_stats.then(Mutex::default),
+            ..Self::default()
+        }
+    }
+
+    pub fn record_call(&self, success: bool) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        if !success {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Count the errors of a failed call by location and error type, integer locations, e.g. list indexes,
+    /// are counted together as `'*'` so the number of counters doesn't grow with the length of inputs
+    pub fn record_field_failures(&self, errors: &[ValLineError]) {
+        let Some(ref field_failures) = self.field_failures else {
+            return;
+        };
+        let mut field_failures = field_failures.lock().unwrap_or_else(PoisonError::into_inner);
+        for error in errors {
+            let loc = error
+                .location
+                .iter()
+                .map(|item| match item {
+                    LocItem::S(s) => s.clone(),
+                    LocItem::I(_) => "*".to_string(),
+                })
+                .collect();
+            *field_failures
+                .entry(loc)
+                .or_default()
+                .entry(error.error_type.type_string())
+                .or_default() += 1;
+        }
+    }
+
+    /// A smart-mode union was only matched by lax validation
+    pub fn record_lax_fallback(&self) {
+        self.lax_fallbacks.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// A union member was tried after a previous member didn't match exactly
+    pub fn record_union_retry(&self) {
+        self.union_retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn to_dict<'py>(&self, py: Python<'py>, reset: bool) -> PyResult<Bound<'py, PyDict>> {
+        let read = |counter: &AtomicU64| {
+            if reset {
+                counter.swap(0, Ordering::Relaxed)
+            } else {
+                counter.load(Ordering::Relaxed)
+            }
+        };
+        let dict = PyDict::new_bound(py);
+        dict.set_item("calls", read(&self.calls))?;
+        dict.set_item("failures", read(&self.failures))?;
+        dict.set_item("lax_fallbacks", read(&self.lax_fallbacks))?;
+        dict.set_item("union_retries", read(&self.union_retries))?;
+        if let Some(ref field_failures) = self.field_failures {
+            let mut field_failures = field_failures.lock().unwrap_or_else(PoisonError::into_inner);
+            let field_failures = if reset {
+                std::mem::take(&mut *field_failures)
+            } else {
+                field_failures.clone()
+            };
+            let field_failures_dict = PyDict::new_bound(py);
+            for (loc, counts) in field_failures {
+                field_failures_dict.set_item(PyTuple::new_bound(py, loc), counts.into_py_dict_bound(py))?;
+            }
+            dict.set_item("field_failures", field_failures_dict)?;
+        }
+        Ok(dict)
+    }
+}
@@ -1,9 +1,17 @@
+use std::borrow::Cow;
+use std::sync::Mutex;
+
+use ahash::AHashMap;
 use pyo3::intern;
 use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyString};
 use regex::Regex;
+use unicode_normalization::{is_nfc_quick, is_nfkc_quick, IsNormalized, UnicodeNormalization as _};
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
-use crate::build_tools::{is_strict, py_schema_error_type, schema_or_config, schema_or_config_same};
+use crate::build_tools::{is_strict, py_schema_err, py_schema_error_type, schema_or_config, schema_or_config_same};
 use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
@@ -47,9 +55,12 @@ impl Validator for StrValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)
-            .map(|val_match| val_match.unpack(state).as_py_string(py, state.cache_str()).into_py(py))
+        let either_str = input
+            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
+            .unpack(state, input, "str");
+        let py_string = state.maybe_intern_str(either_str.as_py_string(py, state.cache_str()))?;
+        state.maybe_cache_utf8(&py_string);
+        Ok(py_string.into_py(py))
     }
 
     fn get_name(&self) -> &str {
@@ -64,7 +75,11 @@ pub struct StrConstrainedValidator {
     pattern: Option<Pattern>,
     max_length: Option<usize>,
     min_length: Option<usize>,
+    length_unit: LengthUnit,
     strip_whitespace: bool,
+    strip_chars: Option<Vec<char>>,
+    unicode_normalization: Option<UnicodeNormalization>,
+    casefold: bool,
     to_lower: bool,
     to_upper: bool,
     coerce_numbers_to_str: bool,
@@ -81,15 +96,28 @@ impl Validator for StrConstrainedValidator {
     ) -> ValResult<PyObject> {
         let either_str = input
             .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
-            .unpack(state);
+            .unpack(state, input, "str");
         let cow = either_str.as_cow()?;
-        let mut str = cow.as_ref();
+        // normalization and case folding can change the string, so happen before stripping
+        let normalized = match self.unicode_normalization {
+            Some(normalization) => normalization.normalize(cow),
+            None => cow,
+        };
+        let normalized = if self.casefold {
+            casefold(py, normalized)?
+        } else {
+            normalized
+        };
+        let mut str = normalized.as_ref();
         if self.strip_whitespace {
             str = str.trim();
         }
+        if let Some(strip_chars) = &self.strip_chars {
+            str = str.trim_matches(strip_chars.as_slice());
+        }
 
         let str_len: Option<usize> = if self.min_length.is_some() | self.max_length.is_some() {
-            Some(str.chars().count())
+            Some(self.length_unit.len(str))
         } else {
             None
         };
@@ -98,7 +126,7 @@ impl Validator for StrConstrainedValidator {
                 return Err(ValError::new(
                     ErrorType::StringTooShort {
                         min_length,
-                        context: None,
+                        context: self.length_unit.error_context(py)?,
                     },
                     input,
                 ));
@@ -109,7 +137,7 @@ impl Validator for StrConstrainedValidator {
                 return Err(ValError::new(
                     ErrorType::StringTooLong {
                         max_length,
-                        context: None,
+                        context: self.length_unit.error_context(py)?,
                     },
                     input,
                 ));
@@ -132,12 +160,18 @@ impl Validator for StrConstrainedValidator {
             state.maybe_cached_str(py, &str.to_lowercase())
         } else if self.to_upper {
             state.maybe_cached_str(py, &str.to_uppercase())
-        } else if self.strip_whitespace {
+        } else if self.strip_whitespace
+            || self.strip_chars.is_some()
+            || self.unicode_normalization.is_some()
+            || self.casefold
+        {
             state.maybe_cached_str(py, str)
         } else {
             // we haven't modified the string, return the original as it might be a PyString
             either_str.as_py_string(py, state.cache_str())
         };
+        let py_string = state.maybe_intern_str(py_string)?;
+        state.maybe_cache_utf8(&py_string);
         Ok(py_string.into_py(py))
     }
 
@@ -172,6 +206,17 @@ impl StrConstrainedValidator {
         let max_length: Option<usize> =
             schema_or_config(schema, config, intern!(py, "max_length"), intern!(py, "str_max_length"))?;
 
+        let length_unit: Option<Bound<'_, PyString>> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "length_unit"),
+            intern!(py, "str_length_unit"),
+        )?;
+        let length_unit = length_unit
+            .map(|unit| LengthUnit::from_str(unit.to_str()?))
+            .transpose()?
+            .unwrap_or_default();
+
         let strip_whitespace: bool = schema_or_config(
             schema,
             config,
@@ -179,6 +224,23 @@ impl StrConstrainedValidator {
             intern!(py, "str_strip_whitespace"),
         )?
         .unwrap_or(false);
+        let strip_chars: Option<String> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "strip_chars"),
+            intern!(py, "str_strip_chars"),
+        )?;
+        let unicode_normalization: Option<Bound<'_, PyString>> = schema_or_config(
+            schema,
+            config,
+            intern!(py, "unicode_normalization"),
+            intern!(py, "str_unicode_normalization"),
+        )?;
+        let unicode_normalization = unicode_normalization
+            .map(|form| UnicodeNormalization::from_str(form.to_str()?))
+            .transpose()?;
+        let casefold: bool =
+            schema_or_config(schema, config, intern!(py, "casefold"), intern!(py, "str_casefold"))?.unwrap_or(false);
         let to_lower: bool =
             schema_or_config(schema, config, intern!(py, "to_lower"), intern!(py, "str_to_lower"))?.unwrap_or(false);
         let to_upper: bool =
@@ -192,7 +254,11 @@ impl StrConstrainedValidator {
             pattern,
             min_length,
             max_length,
+            length_unit,
             strip_whitespace,
+            strip_chars: strip_chars.map(|chars| chars.chars().collect()),
+            unicode_normalization,
+            casefold,
             to_lower,
             to_upper,
             coerce_numbers_to_str,
@@ -206,14 +272,113 @@ impl StrConstrainedValidator {
             || self.max_length.is_some()
             || self.min_length.is_some()
             || self.strip_whitespace
+            || self.strip_chars.is_some()
+            || self.unicode_normalization.is_some()
+            || self.casefold
             || self.to_lower
             || self.to_upper
             || self.coerce_numbers_to_str
     }
 }
 
+/// How `min_length` and `max_length` are measured
+#[derive(Debug, Clone, Copy, Default)]
+enum LengthUnit {
+    // unicode code points, the same as python's `len()`
+    #[default]
+    Chars,
+    // UTF-8 bytes, e.g. for database columns with byte limits
+    Bytes,
+    // extended grapheme clusters, i.e. what's displayed as a single character
+    Graphemes,
+    // UTF-16 code units, as counted by javascript and many UI toolkits
+    Utf16,
+    // columns taken in a terminal, wide east asian characters and emoji take two
+    DisplayWidth,
+}
+
+impl LengthUnit {
+    fn from_str(unit: &str) -> PyResult<Self> {
+        match unit {
+            "chars" | "codepoints" => Ok(Self::Chars),
+            "bytes" => Ok(Self::Bytes),
+            "graphemes" => Ok(Self::Graphemes),
+            "utf16" => Ok(Self::Utf16),
+            "display-width" => Ok(Self::DisplayWidth),
+            _ => py_schema_err!(
+                "Invalid length unit: {}, expected {}",
+                unit,
+                "'chars', 'codepoints', 'bytes', 'graphemes', 'utf16' or 'display-width'"
+            ),
+        }
+    }
+
+    /// The `unit` of the context of length errors, unset for characters so those errors are unchanged
+    fn error_context(self, py: Python) -> PyResult<Option<Py<PyDict>>> {
+        let unit = match self {
+            Self::Chars => return Ok(None),
+            Self::Bytes => "bytes",
+            Self::Graphemes => "graphemes",
+            Self::Utf16 => "utf16",
+            Self::DisplayWidth => "display-width",
+        };
+        let context = PyDict::new_bound(py);
+        context.set_item(intern!(py, "unit"), unit)?;
+        Ok(Some(context.unbind()))
+    }
+
+    fn len(self, str: &str) -> usize {
+        match self {
+            Self::Chars => str.chars().count(),
+            Self::Bytes => str.len(),
+            Self::Graphemes => str.graphemes(true).count(),
+            Self::Utf16 => str.encode_utf16().count(),
+            Self::DisplayWidth => str.width(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum UnicodeNormalization {
+    Nfc,
+    Nfkc,
+}
+
+impl UnicodeNormalization {
+    fn from_str(form: &str) -> PyResult<Self> {
+        match form {
+            "NFC" => Ok(Self::Nfc),
+            "NFKC" => Ok(Self::Nfkc),
+            _ => py_schema_err!("Invalid unicode normalization form: {}, expected 'NFC' or 'NFKC'", form),
+        }
+    }
+
+    fn normalize(self, str: Cow<'_, str>) -> Cow<'_, str> {
+        // most strings are already normalized, the quick check avoids copying them
+        match self {
+            Self::Nfc if is_nfc_quick(str.chars()) != IsNormalized::Yes => Cow::Owned(str.nfc().collect()),
+            Self::Nfkc if is_nfkc_quick(str.chars()) != IsNormalized::Yes => Cow::Owned(str.nfkc().collect()),
+            _ => str,
+        }
+    }
+}
+
+/// Full unicode case folding as done by python's `str.casefold()`, ASCII strings are folded in Rust
+fn casefold<'s>(py: Python<'_>, str: Cow<'s, str>) -> PyResult<Cow<'s, str>> {
+    if str.is_ascii() {
+        if str.bytes().any(|b| b.is_ascii_uppercase()) {
+            Ok(Cow::Owned(str.to_ascii_lowercase()))
+        } else {
+            Ok(str)
+        }
+    } else {
+        let folded = PyString::new_bound(py, &str).call_method0(intern!(py, "casefold"))?;
+        Ok(Cow::Owned(folded.extract()?))
+    }
+}
+
 #[derive(Debug, Clone)]
-struct Pattern {
+pub(super) struct Pattern {
     pattern: String,
     engine: RegexEngine,
 }
@@ -227,6 +392,57 @@ enum RegexEngine {
 impl RegexEngine {
     const RUST_REGEX: &'static str = "rust-regex";
     const PYTHON_RE: &'static str = "python-re";
+    const RE2: &'static str = "re2";
+}
+
+const PATTERN_CACHE_SIZE: usize = 1024;
+
+// compiled patterns shared between validators, so schemas with many identical patterns compile each once
+static PATTERN_CACHE: GILOnceCell<Mutex<PatternCache>> = GILOnceCell::new();
+
+/// Compiled patterns keyed by engine and pattern, the least recently used pattern is evicted when full
+#[derive(Default)]
+struct PatternCache {
+    entries: AHashMap<(&'static str, String), (RegexEngine, u64)>,
+    uses: u64,
+}
+
+impl PatternCache {
+    /// Get `pattern` compiled for `engine` from the cache, calling `compile` and caching the result if it's missing
+    fn get_or_compile(
+        py: Python<'_>,
+        engine: &'static str,
+        pattern: &str,
+        compile: impl FnOnce() -> PyResult<RegexEngine>,
+    ) -> PyResult<RegexEngine> {
+        let cache = PATTERN_CACHE.get_or_init(py, Mutex::default);
+        let key = (engine, pattern.to_string());
+        {
+            let mut cache = cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+            cache.uses += 1;
+            let uses = cache.uses;
+            if let Some((regex, last_used)) = cache.entries.get_mut(&key) {
+                *last_used = uses;
+                return Ok(regex.clone());
+            }
+        }
+        // the lock isn't held while compiling since python engines may release the GIL
+        let regex = compile()?;
+        let mut cache = cache.lock().unwrap_or_else(std::sync::PoisonError::into_inner);
+        if cache.entries.len() >= PATTERN_CACHE_SIZE {
+            let least_recent = cache
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, last_used))| *last_used)
+                .map(|(key, _)| key.clone());
+            if let Some(least_recent) = least_recent {
+                cache.entries.remove(&least_recent);
+            }
+        }
+        let uses = cache.uses;
+        cache.entries.insert(key, (regex.clone(), uses));
+        Ok(regex)
+    }
 }
 
 impl Pattern {
@@ -260,9 +476,26 @@ impl Pattern {
         } else {
             let engine = match engine {
                 RegexEngine::RUST_REGEX => {
-                    RegexEngine::RustRegex(Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?)
+                    PatternCache::get_or_compile(py, RegexEngine::RUST_REGEX, &pattern_str, || {
+                        Ok(RegexEngine::RustRegex(
+                            Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?,
+                        ))
+                    })?
                 }
-                RegexEngine::PYTHON_RE => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
+                RegexEngine::PYTHON_RE => {
+                    PatternCache::get_or_compile(py, RegexEngine::PYTHON_RE, &pattern_str, || {
+                        Ok(RegexEngine::PythonRe(re_compile.call1((&pattern,))?.into()))
+                    })?
+                }
+                // `re2` patterns have the same interface as `re` patterns
+                RegexEngine::RE2 => PatternCache::get_or_compile(py, RegexEngine::RE2, &pattern_str, || {
+                    let re2_module = py.import_bound(intern!(py, "re2")).map_err(|_| {
+                        py_schema_error_type!("The `re2` regex engine requires the `google-re2` package")
+                    })?;
+                    Ok(RegexEngine::PythonRe(
+                        re2_module.call_method1(intern!(py, "compile"), (&pattern,))?.into(),
+                    ))
+                })?,
                 _ => return Err(py_schema_error_type!("Invalid regex engine: {}", engine)),
             };
 
@@ -273,7 +506,14 @@ impl Pattern {
         }
     }
 
-    fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
+    /// Compile `pattern` with the `regex_engine` of `config`, for patterns in schemas which don't set an engine
+    pub(super) fn compile_with_config(pattern: Bound<'_, PyAny>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let regex_engine: Option<Bound<'_, PyString>> = config.get_as(intern!(pattern.py(), "regex_engine"))?;
+        let regex_engine = regex_engine.as_ref().map(|s| s.to_str()).transpose()?;
+        Self::compile(pattern, regex_engine.unwrap_or(RegexEngine::RUST_REGEX))
+    }
+
+    pub(super) fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
         match &self.engine {
             RegexEngine::RustRegex(regex) => Ok(regex.is_match(target)),
             RegexEngine::PythonRe(py_regex) => {
@@ -0,0 +1,218 @@
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::sync::{Mutex, PoisonError};
+
+use jiter::JsonValue;
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use pyo3::{PyTraverseError, PyVisit};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ValError, ValResult};
+use crate::input::{Input, InputType};
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+
+/// JSON Schema `format`s checked by pydantic-core, custom formats can't use these names
+const BUILTIN_FORMATS: &[&str] = &[
+    "date-time",
+    "date",
+    "time",
+    "duration",
+    "uri",
+    "uri-reference",
+    "hostname",
+    "idn-hostname",
+    "ipv4",
+    "ipv6",
+    "uuid",
+    "regex",
+];
+
+static CUSTOM_FORMATS: Mutex<Vec<(String, PyObject)>> = Mutex::new(Vec::new());
+
+/// Register `check` to validate strings with the format `name`, replacing any check with the same name.
+#[pyfunction]
+pub fn register_string_format(name: String, check: &Bound<'_, PyAny>) -> PyResult<()> {
+    if !check.is_callable() {
+        return Err(PyTypeError::new_err("String format check must be callable"));
+    }
+    if BUILTIN_FORMATS.contains(&name.as_str()) {
+        return Err(PyValueError::new_err(format!(
+            "String format '{name}' is checked by pydantic-core and can't be registered"
+        )));
+    }
+    let mut formats = CUSTOM_FORMATS.lock().unwrap_or_else(PoisonError::into_inner);
+    let check = check.clone().unbind();
+    match formats.iter_mut().find(|(n, _)| *n == name) {
+        Some(existing) => existing.1 = check,
+        None => formats.push((name, check)),
+    }
+    Ok(())
+}
+
+/// Remove the string format registered as `name`, returns whether there was one
+#[pyfunction]
+pub fn unregister_string_format(name: &str) -> bool {
+    let mut formats = CUSTOM_FORMATS.lock().unwrap_or_else(PoisonError::into_inner);
+    let len = formats.len();
+    formats.retain(|(n, _)| n != name);
+    formats.len() != len
+}
+
+#[derive(Debug)]
+enum FormatCheck {
+    // the string is validated by the validator for the equivalent core schema, e.g. `datetime` for `date-time`
+    Validator(Box<CombinedValidator>),
+    Ipv4,
+    Ipv6,
+    Regex,
+    Custom(PyObject),
+    // an unknown format with `allow_unknown`, which like JSON Schema is just an annotation
+    Any,
+}
+
+impl PyGcTraverse for FormatCheck {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        match self {
+            Self::Validator(validator) => validator.py_gc_traverse(visit),
+            Self::Custom(check) => visit.call(check),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Validates strings against a JSON Schema `format`, returning the string unchanged
+#[derive(Debug)]
+pub struct StringFormatValidator {
+    strict: bool,
+    format: String,
+    check: FormatCheck,
+    name: String,
+}
+
+impl BuildValidator for StringFormatValidator {
+    const EXPECTED_TYPE: &'static str = "string-format";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let format: String = schema.get_as_req(intern!(py, "format"))?;
+        let check = match format.as_str() {
+            "ipv4" => FormatCheck::Ipv4,
+            "ipv6" => FormatCheck::Ipv6,
+            "regex" => FormatCheck::Regex,
+            s => match builtin_schema(py, s)? {
+                Some(inner_schema) => {
+                    FormatCheck::Validator(Box::new(build_validator(inner_schema.as_any(), None, definitions)?))
+                }
+                None => {
+                    let formats = CUSTOM_FORMATS.lock().unwrap_or_else(PoisonError::into_inner);
+                    match formats.iter().find(|(n, _)| n == s) {
+                        Some((_, check)) => FormatCheck::Custom(check.clone_ref(py)),
+                        None if schema.get_as(intern!(py, "allow_unknown"))?.unwrap_or(false) => FormatCheck::Any,
+                        None => return py_schema_err!("Unknown string format: '{}'", s),
+                    }
+                }
+            },
+        };
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            name: format!("{}[{format}]", Self::EXPECTED_TYPE),
+            format,
+            check,
+        }
+        .into())
+    }
+}
+
+/// The schema of the validator used for built-in formats which have an equivalent core schema
+fn builtin_schema<'py>(py: Python<'py>, format: &str) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let schema = PyDict::new_bound(py);
+    let schema_type = match format {
+        "date-time" => {
+            // RFC 3339 date-times always have an offset
+            schema.set_item("tz_constraint", "aware")?;
+            "datetime"
+        }
+        "date" => "date",
+        "time" => "time",
+        "duration" => "timedelta",
+        "uri" => "url",
+        "uri-reference" => "uri-reference",
+        "hostname" => {
+            schema.set_item("idna", "punycode")?;
+            "hostname"
+        }
+        "idn-hostname" => "hostname",
+        "uuid" => "uuid",
+        _ => return Ok(None),
+    };
+    schema.set_item("type", schema_type)?;
+    Ok(Some(schema))
+}
+
+impl PyGcTraverse for StringFormatValidator {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        self.check.py_gc_traverse(visit)
+    }
+}
+
+impl Validator for StringFormatValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let either_str = input
+            .validate_str(state.strict_or(self.strict), false)?
+            .unpack(state, input, "str");
+        let s = either_str.as_cow()?;
+        let error = match &self.check {
+            FormatCheck::Validator(validator) => {
+                // the string is checked as strict JSON input, since strict validators of most types only accept
+                // strings from JSON, and the result is the string so exactness isn't changed
+                let exactness = state.exactness;
+                let json_state = &mut state.rebind_extra(|extra| {
+                    extra.input_type = InputType::Json;
+                    extra.strict = Some(true);
+                });
+                validator.validate(py, &JsonValue::Str(s.clone()), json_state)?;
+                json_state.exactness = exactness;
+                None
+            }
+            FormatCheck::Ipv4 => s.parse::<Ipv4Addr>().err().map(|e| e.to_string()),
+            FormatCheck::Ipv6 => s.parse::<Ipv6Addr>().err().map(|e| e.to_string()),
+            FormatCheck::Regex => regex::Regex::new(&s)
+                .err()
+                .map(|_| "invalid regular expression".to_string()),
+            FormatCheck::Custom(check) => match check.call1(py, (s.as_ref(),)) {
+                Ok(result) if result.is_truthy(py)? => None,
+                Ok(_) => Some("invalid value".to_string()),
+                Err(err) if err.is_instance_of::<PyValueError>(py) => Some(err.value_bound(py).to_string()),
+                Err(err) => return Err(err.into()),
+            },
+            FormatCheck::Any => None,
+        };
+        if let Some(error) = error {
+            let error_type = ErrorType::StringFormatMismatch {
+                format: self.format.clone(),
+                error,
+                context: None,
+            };
+            return Err(ValError::new(error_type, input));
+        }
+        Ok(either_str.as_py_string(py, state.cache_str()).into_py(py))
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
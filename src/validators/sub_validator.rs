@@ -0,0 +1,174 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyInt, PyList, PyString, PyTuple};
+
+use crate::errors::{LocItem, ValError, ValResult};
+use crate::input::{Input, InputType};
+
+use super::json::{map_json_err, validate_json_bytes};
+use super::{CombinedValidator, SchemaValidator, ValidationState, Validator};
+
+/// Validates just the part of the input at `loc` of a `SchemaValidator`, from `SchemaValidator.validator_for_loc`,
+/// the part's validator is looked up again for each call since it's owned by the `SchemaValidator`
+#[pyclass(module = "pydantic_core._pydantic_core", frozen)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct SubValidator {
+    schema_validator: Py<SchemaValidator>,
+    loc: Vec<LocItem>,
+}
+
+impl SubValidator {
+    pub(super) fn new(schema_validator: &Bound<'_, SchemaValidator>, loc: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let py = schema_validator.py();
+        let loc = extract_loc(loc)?;
+        if with_validator_for_loc(&schema_validator.get().validator, &loc, &mut |_| ()).is_none() {
+            return Err(PyValueError::new_err(format!(
+                "No validator found for location {}",
+                PyTuple::new_bound(py, &loc).repr()?
+            )));
+        }
+        Ok(Self {
+            schema_validator: schema_validator.clone().unbind(),
+            loc,
+        })
+    }
+
+    /// Validate `input` with the validator at `loc`, errors are located from the root as if the whole input had been
+    /// validated
+    fn validate_at_loc<'py>(
+        &self,
+        py: Python<'py>,
+        validator: &CombinedValidator,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let result = with_validator_for_loc(validator, &self.loc, &mut |validator| {
+            validator.validate(py, input, state)
+        })
+        .ok_or_else(|| ValError::InternalErr(PyValueError::new_err("The validator at `loc` was not found")))?;
+        self.loc.iter().rev().fold(result, |result, loc_item| {
+            result.map_err(|e| e.with_outer_location(loc_item.clone()))
+        })
+    }
+}
+
+#[pymethods]
+impl SubValidator {
+    #[getter]
+    fn loc<'py>(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::new_bound(py, &self.loc)
+    }
+
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None))]
+    fn validate_python(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let schema_validator = self.schema_validator.get();
+        schema_validator.validate_with(
+            py,
+            InputType::Python,
+            strict,
+            from_attributes,
+            context,
+            |validator, state| self.validate_at_loc(py, validator, input, state),
+        )
+    }
+
+    #[pyo3(signature = (input, *, strict=None, context=None))]
+    fn validate_json(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let schema_validator = self.schema_validator.get();
+        schema_validator.validate_with(py, InputType::Json, strict, None, context, |validator, state| {
+            let json_bytes = validate_json_bytes(input)?.into_inner();
+            let json_data = json_bytes.as_slice();
+            let json_value = jiter::JsonValue::parse(json_data, true).map_err(|e| map_json_err(input, e, json_data))?;
+            self.validate_at_loc(py, validator, &json_value, state)
+        })
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("SubValidator(loc={})", self.loc(py).repr()?))
+    }
+}
+
+/// The `loc` argument of `validator_for_loc` and `serializer_for_loc`, a field name or a tuple or list of field names
+/// and indexes
+pub(crate) fn extract_loc(loc: &Bound<'_, PyAny>) -> PyResult<Vec<LocItem>> {
+    if loc.is_instance_of::<PyString>() {
+        return Ok(vec![loc.into()]);
+    }
+    let segments: Vec<Bound<'_, PyAny>> = if let Ok(list) = loc.downcast::<PyList>() {
+        list.iter().collect()
+    } else if let Ok(tuple) = loc.downcast::<PyTuple>() {
+        tuple.iter().collect()
+    } else {
+        return Err(PyTypeError::new_err(format!(
+            "`loc` should be a str, tuple or list, not {}",
+            loc.get_type().qualname()?
+        )));
+    };
+    segments
+        .into_iter()
+        .map(|segment| {
+            if segment.is_instance_of::<PyString>()
+                || (segment.is_instance_of::<PyInt>() && !segment.is_instance_of::<PyBool>())
+            {
+                Ok(segment.into())
+            } else {
+                Err(PyTypeError::new_err(format!(
+                    "Location items should be str or int, not {}",
+                    segment.get_type().qualname()?
+                )))
+            }
+        })
+        .collect()
+}
+
+/// Call `f` with the validator of the part of the input at `loc`, going into the fields of models, dataclasses and
+/// typed dicts by name, the items of lists and tuples by index and the values of dicts by any key, looking through
+/// function validators, defaults, nullables and definition references on the way, `None` if there's no validator
+/// at `loc`
+fn with_validator_for_loc<R>(
+    validator: &CombinedValidator,
+    loc: &[LocItem],
+    f: &mut dyn FnMut(&CombinedValidator) -> R,
+) -> Option<R> {
+    let Some((first, rest)) = loc.split_first() else {
+        return Some(f(validator));
+    };
+    let next = match (validator, first) {
+        (CombinedValidator::ModelFields(v), LocItem::S(name)) => v.field_validator(name),
+        (CombinedValidator::TypedDict(v), LocItem::S(name)) => v.field_validator(name),
+        (CombinedValidator::DataclassArgs(v), LocItem::S(name)) => v.field_validator(name),
+        (CombinedValidator::List(v), LocItem::I(_)) => v.item_validator(),
+        (CombinedValidator::Tuple(v), LocItem::I(index)) => {
+            usize::try_from(*index).ok().and_then(|index| v.item_validator(index))
+        }
+        (CombinedValidator::Dict(v), _) => Some(v.value_validator()),
+        // validators wrapping the part of the input at `loc`
+        (CombinedValidator::Model(v), _) => return with_validator_for_loc(v.inner_validator(), loc, f),
+        (CombinedValidator::Dataclass(v), _) => return with_validator_for_loc(v.inner_validator(), loc, f),
+        (CombinedValidator::FunctionBefore(v), _) => return with_validator_for_loc(v.inner_validator(), loc, f),
+        (CombinedValidator::FunctionAfter(v), _) => return with_validator_for_loc(v.inner_validator(), loc, f),
+        (CombinedValidator::FunctionWrap(v), _) => return with_validator_for_loc(v.inner_validator(), loc, f),
+        (CombinedValidator::WithDefault(v), _) => return with_validator_for_loc(v.inner_validator(), loc, f),
+        (CombinedValidator::Nullable(v), _) => return with_validator_for_loc(v.inner_validator(), loc, f),
+        (CombinedValidator::DefinitionRef(v), _) => {
+            return v
+                .definition()
+                .read(|definition| definition.and_then(|definition| with_validator_for_loc(definition, loc, f)))
+        }
+        _ => None,
+    };
+    next.and_then(|next| with_validator_for_loc(next, rest, f))
+}
@@ -0,0 +1,160 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyInt, PyList, PyString};
+use pyo3::{PyTraverseError, PyVisit};
+use regex::RegexSet;
+
+use crate::build_tools::{py_schema_err, py_schema_error_type};
+use crate::py_gc::PyGcTraverse;
+use crate::tools::SchemaDict;
+
+use super::{build_validator, CombinedValidator, DefinitionsBuilder, Validator};
+
+/// Tagged union choices keyed by ranges of int tags or regex patterns matching str tags,
+/// used when a tag isn't one of the union's `choices`
+#[derive(Debug)]
+pub struct TagMatcher {
+    // inclusive `(start, end)` ranges sorted by `start`, they can't overlap so can be binary searched
+    ranges: Vec<(i64, i64, CombinedValidator)>,
+    patterns: Option<RegexSet>,
+    pattern_validators: Vec<CombinedValidator>,
+    // for `expected_tags` in errors, and the union's name, in the order given in the schema
+    tag_reprs: Vec<String>,
+    names: Vec<String>,
+}
+
+impl TagMatcher {
+    pub fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<Option<Self>> {
+        let py = schema.py();
+        let range_choices: Option<Bound<'_, PyList>> = schema.get_as(intern!(py, "range_choices"))?;
+        let pattern_choices: Option<Bound<'_, PyList>> = schema.get_as(intern!(py, "pattern_choices"))?;
+        if range_choices.is_none() && pattern_choices.is_none() {
+            return Ok(None);
+        }
+
+        let mut tag_reprs = Vec::new();
+        let mut names = Vec::new();
+
+        let mut ranges = Vec::new();
+        for choice in range_choices.iter().flatten() {
+            let choice = choice.downcast::<PyDict>()?;
+            let (start, end, tag_repr) = tag_range(choice)?;
+            let validator = build_validator(&choice.get_as_req(intern!(py, "schema"))?, config, definitions)?;
+            tag_reprs.push(tag_repr);
+            names.push(validator.get_name().to_string());
+            ranges.push((start, end, validator));
+        }
+        ranges.sort_by_key(|(start, ..)| *start);
+        if let Some(w) = ranges.windows(2).find(|w| w[0].1 >= w[1].0) {
+            return py_schema_err!(
+                "Tag ranges must not overlap, {}..={} overlaps {}..={}",
+                w[0].0,
+                w[0].1,
+                w[1].0,
+                w[1].1
+            );
+        }
+
+        let mut pattern_strs = Vec::new();
+        let mut pattern_validators = Vec::new();
+        for choice in pattern_choices.iter().flatten() {
+            let choice = choice.downcast::<PyDict>()?;
+            let pattern: String = choice.get_as_req(intern!(py, "pattern"))?;
+            let validator = build_validator(&choice.get_as_req(intern!(py, "schema"))?, config, definitions)?;
+            tag_reprs.push(format!("pattern {}", PyString::new_bound(py, &pattern).repr()?));
+            names.push(validator.get_name().to_string());
+            pattern_strs.push(pattern);
+            pattern_validators.push(validator);
+        }
+        let patterns = match pattern_strs.is_empty() {
+            true => None,
+            false => Some(RegexSet::new(&pattern_strs).map_err(|e| py_schema_error_type!("{}", e))?),
+        };
+
+        Ok(Some(Self {
+            ranges,
+            patterns,
+            pattern_validators,
+            tag_reprs,
+            names,
+        }))
+    }
+
+    /// The validator for `tag`, ranges are checked for int tags and patterns, in order, for str tags
+    pub fn find(&self, tag: &Bound<'_, PyAny>) -> PyResult<Option<&CombinedValidator>> {
+        if let Ok(py_str) = tag.downcast::<PyString>() {
+            let Some(ref patterns) = self.patterns else {
+                return Ok(None);
+            };
+            let index = patterns.matches(py_str.to_str()?).iter().next();
+            return Ok(index.map(|index| &self.pattern_validators[index]));
+        }
+        if tag.is_instance_of::<PyInt>() && !tag.is_instance_of::<PyBool>() {
+            let Ok(int) = tag.extract::<i64>() else {
+                return Ok(None);
+            };
+            let index = self.ranges.partition_point(|(start, ..)| *start <= int);
+            return Ok(index
+                .checked_sub(1)
+                .map(|index| &self.ranges[index])
+                .filter(|(_, end, _)| int <= *end)
+                .map(|(.., validator)| validator));
+        }
+        Ok(None)
+    }
+
+    pub fn tag_reprs(&self) -> impl Iterator<Item = &str> {
+        self.tag_reprs.iter().map(String::as_str)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.names.iter().map(String::as_str)
+    }
+}
+
+/// The inclusive bounds of a range choice, and how it's shown in errors
+fn tag_range(choice: &Bound<'_, PyDict>) -> PyResult<(i64, i64, String)> {
+    let py = choice.py();
+    let ge: Option<i64> = choice.get_as(intern!(py, "ge"))?;
+    let gt: Option<i64> = choice.get_as(intern!(py, "gt"))?;
+    let le: Option<i64> = choice.get_as(intern!(py, "le"))?;
+    let lt: Option<i64> = choice.get_as(intern!(py, "lt"))?;
+
+    let mut bounds = Vec::new();
+    for (name, bound) in [("ge", ge), ("gt", gt), ("le", le), ("lt", lt)] {
+        if let Some(bound) = bound {
+            bounds.push(format!("{name}={bound}"));
+        }
+    }
+    if bounds.is_empty() {
+        return py_schema_err!("Tag ranges require at least one of `ge`, `gt`, `le` or `lt`");
+    }
+
+    let start = [ge, gt.map(|gt| gt.saturating_add(1))]
+        .into_iter()
+        .flatten()
+        .max()
+        .unwrap_or(i64::MIN);
+    let end = [le, lt.map(|lt| lt.saturating_sub(1))]
+        .into_iter()
+        .flatten()
+        .min()
+        .unwrap_or(i64::MAX);
+    if start > end || gt == Some(i64::MAX) || lt == Some(i64::MIN) {
+        return py_schema_err!("Tag range {} is empty", bounds.join(" "));
+    }
+    Ok((start, end, format!("range {}", bounds.join(" "))))
+}
+
+impl PyGcTraverse for TagMatcher {
+    fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
+        for (.., validator) in &self.ranges {
+            validator.py_gc_traverse(visit)?;
+        }
+        self.pattern_validators.py_gc_traverse(visit)
+    }
+}
@@ -48,7 +48,7 @@ impl Validator for TimeValidator {
     ) -> ValResult<PyObject> {
         let time = input
             .validate_time(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+            .unpack(state, input, "time");
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
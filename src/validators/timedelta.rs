@@ -73,7 +73,7 @@ impl Validator for TimeDeltaValidator {
     ) -> ValResult<PyObject> {
         let timedelta = input
             .validate_timedelta(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+            .unpack(state, input, "timedelta");
         let py_timedelta = timedelta.try_into_py(py)?;
         if let Some(constraints) = &self.constraints {
             let raw_timedelta = timedelta.to_duration()?;
@@ -3,13 +3,15 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 use std::collections::VecDeque;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, py_schema_err};
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
-use crate::input::{BorrowInput, Input, ValidatedTuple};
+use crate::input::{BorrowInput, Input, MaxErrorItems, ValidatedTuple};
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, PathSegment, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 pub struct TupleValidator {
@@ -19,7 +21,7 @@ pub struct TupleValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: String,
-    fail_fast: bool,
+    max_error_items: MaxErrorItems,
 }
 
 impl BuildValidator for TupleValidator {
@@ -38,8 +40,14 @@ impl BuildValidator for TupleValidator {
 
         let mut validator_names = validators.iter().map(Validator::get_name).collect::<Vec<_>>();
         let variadic_item_index: Option<usize> = schema.get_as(intern!(py, "variadic_item_index"))?;
-        // FIXME add friendly schema error if item out of bounds
         if let Some(variadic_item_index) = variadic_item_index {
+            if variadic_item_index >= validators.len() {
+                return py_schema_err!(
+                    "`variadic_item_index` {} is out of bounds for {} items",
+                    variadic_item_index,
+                    validators.len()
+                );
+            }
             validator_names.insert(variadic_item_index + 1, "...");
         }
         let name = format!("tuple[{}]", validator_names.join(", "));
@@ -51,7 +59,7 @@ impl BuildValidator for TupleValidator {
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
             name,
-            fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            max_error_items: MaxErrorItems::from_schema(schema)?,
         }
         .into())
     }
@@ -60,6 +68,15 @@ impl BuildValidator for TupleValidator {
 impl_py_gc_traverse!(TupleValidator { validators });
 
 impl TupleValidator {
+    /// The validator of the item at `index`, items from the variadic item on are validated by the variadic item's
+    /// validator since their position relative to any items after it depends on the length of the input
+    pub(super) fn item_validator(&self, index: usize) -> Option<&CombinedValidator> {
+        match self.variadic_item_index {
+            Some(variadic_item_index) if index >= variadic_item_index => self.validators.get(variadic_item_index),
+            _ => self.validators.get(index),
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn validate_tuple_items<'py, I: BorrowInput<'py>>(
         &self,
@@ -68,18 +85,21 @@ impl TupleValidator {
         state: &mut ValidationState<'_, 'py>,
         output: &mut Vec<PyObject>,
         errors: &mut Vec<ValLineError>,
+        invalid_items: &mut usize,
         item_validators: &[CombinedValidator],
         collection_iter: &mut NextCountingIterator<impl Iterator<Item = I>>,
         actual_length: Option<usize>,
-        fail_fast: bool,
     ) -> ValResult<()> {
         // Validate the head:
         for validator in item_validators {
             match collection_iter.next() {
-                Some((index, input_item)) => match validator.validate(py, input_item.borrow_input(), state) {
+                Some((index, input_item)) => match state.with_path(PathSegment::Loc(index.into()), |state| {
+                    validator.validate(py, input_item.borrow_input(), state)
+                }) {
                     Ok(item) => self.push_output_item(input, output, item, actual_length)?,
                     Err(ValError::LineErrors(line_errors)) => {
                         errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        *invalid_items += 1;
                     }
                     Err(ValError::Omit) => (),
                     Err(err) => return Err(err),
@@ -90,10 +110,11 @@ impl TupleValidator {
                         output.push(value);
                     } else {
                         errors.push(ValLineError::new_with_loc(ErrorTypeDefaults::Missing, input, index));
+                        *invalid_items += 1;
                     }
                 }
             }
-            if fail_fast && !errors.is_empty() {
+            if self.max_error_items.reached(*invalid_items) {
                 return Ok(());
             }
         }
@@ -117,6 +138,7 @@ impl TupleValidator {
             self.validators.len()
         };
         let mut output = Vec::with_capacity(expected_length);
+        let mut invalid_items = 0;
         if let Some(variable_validator_index) = self.variadic_item_index {
             let (head_validators, [variable_validator, tail_validators @ ..]) =
                 self.validators.split_at(variable_validator_index)
@@ -131,29 +153,34 @@ impl TupleValidator {
                 state,
                 &mut output,
                 errors,
+                &mut invalid_items,
                 head_validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if self.max_error_items.reached(invalid_items) {
                 return Ok(output);
             }
 
             let n_tail_validators = tail_validators.len();
             if n_tail_validators == 0 {
                 for (index, input_item) in collection_iter {
-                    match variable_validator.validate(py, input_item.borrow_input(), state) {
+                    state.check_deadline()?;
+                    let segment = PathSegment::Loc(index.into());
+                    match state.with_path(segment, |state| {
+                        variable_validator.validate(py, input_item.borrow_input(), state)
+                    }) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
                             errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                            invalid_items += 1;
                         }
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if self.max_error_items.reached(invalid_items) {
                         return Ok(output);
                     }
                 }
@@ -164,12 +191,14 @@ impl TupleValidator {
                 // right one for user errors.
                 let mut tail_buffer: VecDeque<I> = collection_iter.inner.by_ref().take(n_tail_validators).collect();
 
-                // Save the current index for the tail validation below when we recreate a new NextCountingIterator
+                // Save the index of the first tail item for the tail validation below when we recreate a new
+                // NextCountingIterator, each item "leaving" the buffer moves the tail along by one
                 let mut index = collection_iter.next_calls();
 
                 // Iterate over all remaining collection items, validating as items "leave" the buffer
                 for (buffer_item_index, input_item) in collection_iter {
-                    index = buffer_item_index;
+                    state.check_deadline()?;
+                    index = buffer_item_index + 1;
                     // This `unwrap` is safe because you can only get here
                     // if there were at least `n_tail_validators` (> 0) items in the iterator
                     let buffered_item = tail_buffer.pop_front().unwrap();
@@ -183,12 +212,13 @@ impl TupleValidator {
                                     .into_iter()
                                     .map(|err| err.with_outer_location(buffer_item_index)),
                             );
+                            invalid_items += 1;
                         }
                         Err(ValError::Omit) => (),
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if self.max_error_items.reached(invalid_items) {
                         return Ok(output);
                     }
                 }
@@ -200,10 +230,10 @@ impl TupleValidator {
                     state,
                     &mut output,
                     errors,
+                    &mut invalid_items,
                     tail_validators,
                     &mut NextCountingIterator::new(tail_buffer.into_iter(), index),
                     actual_length,
-                    self.fail_fast,
                 )?;
             }
         } else {
@@ -214,13 +244,13 @@ impl TupleValidator {
                 state,
                 &mut output,
                 errors,
+                &mut invalid_items,
                 &self.validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if self.max_error_items.reached(invalid_items) {
                 return Ok(output);
             }
 
@@ -272,7 +302,9 @@ impl Validator for TupleValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let collection = input.validate_tuple(state.strict_or(self.strict))?.unpack(state);
+        let collection = input
+            .validate_tuple(state.strict_or(self.strict))?
+            .unpack(state, input, "tuple");
         let actual_length = collection.len();
 
         let mut errors: Vec<ValLineError> = Vec::new();
@@ -1,11 +1,13 @@
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use ahash::AHashSet;
 
 use crate::build_tools::py_schema_err;
-use crate::build_tools::{is_strict, schema_or_config, schema_or_config_same, ExtraBehavior};
+use crate::build_tools::{
+    is_strict, nested_config, schema_or_config, schema_or_config_same, AliasGenerator, ExtraBehavior,
+};
 use crate::errors::LocItem;
 use crate::errors::{ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::BorrowInput;
@@ -15,7 +17,11 @@ use crate::input::{Input, ValidatedDict};
 use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::on_missing::OnMissing;
+use super::pattern_extras::PatternExtras;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, PathSegment, ValidationState, Validator,
+};
 
 #[derive(Debug)]
 struct TypedDictField {
@@ -24,15 +30,17 @@ struct TypedDictField {
     name_py: Py<PyString>,
     required: bool,
     validator: CombinedValidator,
+    on_missing: Option<OnMissing>,
 }
 
-impl_py_gc_traverse!(TypedDictField { validator });
+impl_py_gc_traverse!(TypedDictField { validator, on_missing });
 
 #[derive(Debug)]
 pub struct TypedDictValidator {
     fields: Vec<TypedDictField>,
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
+    pattern_extras: Option<PatternExtras>,
     strict: bool,
     loc_by_alias: bool,
 }
@@ -42,20 +50,22 @@ impl BuildValidator for TypedDictValidator {
 
     fn build(
         schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        config: Option<&Bound<'_, PyDict>>,
         definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
         let py = schema.py();
 
-        // typed dicts ignore the parent config and always use the config from this TypedDict
-        let config = schema.get_as(intern!(py, "config"))?;
+        // typed dicts use the config from this TypedDict, unless it's set to merge with the parent config
+        let config = nested_config(schema, config)?;
         let config = config.as_ref();
+        definitions.record_config(schema, config)?;
 
         let strict = is_strict(schema, config)?;
 
         let total =
             schema_or_config(schema, config, intern!(py, "total"), intern!(py, "typed_dict_total"))?.unwrap_or(true);
         let populate_by_name = schema_or_config_same(schema, config, intern!(py, "populate_by_name"))?.unwrap_or(false);
+        let alias_generator = AliasGenerator::from_config(config)?;
 
         let extra_behavior = ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Ignore)?;
 
@@ -64,6 +74,7 @@ impl BuildValidator for TypedDictValidator {
             (Some(_), _) => return py_schema_err!("extras_schema can only be used if extra_behavior=allow"),
             (_, _) => None,
         };
+        let pattern_extras = PatternExtras::build(schema, intern!(py, "extras_pattern_schemas"), config, definitions)?;
 
         let fields_dict: Bound<'_, PyDict> = schema.get_as_req(intern!(py, "fields"))?;
         let mut fields: Vec<TypedDictField> = Vec::with_capacity(fields_dict.len());
@@ -113,7 +124,21 @@ impl BuildValidator for TypedDictValidator {
                     let alt_alias = if populate_by_name { Some(field_name) } else { None };
                     LookupKey::from_py(py, &alias, alt_alias)?
                 }
-                None => LookupKey::from_string(py, field_name),
+                None => match field_info.get_item(intern!(py, "serialization_alias"))? {
+                    Some(aliases) if aliases.is_instance_of::<PyList>() => {
+                        let alt_alias = if populate_by_name { Some(field_name) } else { None };
+                        LookupKey::from_choices(py, aliases.downcast()?, alt_alias)?
+                    }
+                    Some(_) => LookupKey::from_string(py, field_name),
+                    None => match alias_generator {
+                        Some(alias_generator) => {
+                            let alias = PyString::new_bound(py, &alias_generator.apply(field_name));
+                            let alt_alias = if populate_by_name { Some(field_name) } else { None };
+                            LookupKey::from_py(py, &alias, alt_alias)?
+                        }
+                        None => LookupKey::from_string(py, field_name),
+                    },
+                },
             };
 
             fields.push(TypedDictField {
@@ -122,6 +147,7 @@ impl BuildValidator for TypedDictValidator {
                 name_py: field_name_py.into(),
                 validator,
                 required,
+                on_missing: OnMissing::from_field(field_info)?,
             });
         }
 
@@ -129,6 +155,7 @@ impl BuildValidator for TypedDictValidator {
             fields,
             extra_behavior,
             extras_validator,
+            pattern_extras,
             strict,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
         }
@@ -138,9 +165,25 @@ impl BuildValidator for TypedDictValidator {
 
 impl_py_gc_traverse!(TypedDictValidator {
     fields,
-    extras_validator
+    extras_validator,
+    pattern_extras
 });
 
+impl TypedDictValidator {
+    /// The name and lookup key of each field, in order
+    pub(super) fn lookup_keys(&self) -> impl Iterator<Item = (&str, &LookupKey)> {
+        self.fields.iter().map(|field| (field.name.as_str(), &field.lookup_key))
+    }
+
+    /// The validator of the field called `name`
+    pub(super) fn field_validator(&self, name: &str) -> Option<&CombinedValidator> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| &field.validator)
+    }
+}
+
 impl Validator for TypedDictValidator {
     fn validate<'py>(
         &self,
@@ -157,7 +200,8 @@ impl Validator for TypedDictValidator {
         // we only care about which keys have been used if we're iterating over the object for extra after
         // the first pass
         let mut used_keys: Option<AHashSet<&str>> =
-            if self.extra_behavior == ExtraBehavior::Ignore || dict.is_py_get_attr() {
+            if (self.extra_behavior == ExtraBehavior::Ignore && self.pattern_extras.is_none()) || dict.is_py_get_attr()
+            {
                 None
             } else {
                 Some(AHashSet::with_capacity(self.fields.len()))
@@ -184,7 +228,13 @@ impl Validator for TypedDictValidator {
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    let segment = PathSegment::Field(field.name_py.clone_ref(py));
+                    let field_result = state.with_path(segment, |state| {
+                        state.traced(py, field.validator.get_name(), Some(&field.name), |state| {
+                            field.validator.validate(py, value.borrow_input(), state)
+                        })
+                    });
+                    match field_result {
                         Ok(value) => {
                             output_dict.set_item(&field.name_py, value)?;
                             fields_set_count += 1;
@@ -200,6 +250,24 @@ impl Validator for TypedDictValidator {
                     continue;
                 }
 
+                if let Some(ref on_missing) = field.on_missing {
+                    match on_missing.value(py, input, &field.name_py, &output_dict, &field.validator, state) {
+                        Ok(Some(value)) => {
+                            output_dict.set_item(&field.name_py, value)?;
+                            continue;
+                        }
+                        // `PydanticUseDefault` was raised, fall back to the default
+                        Ok(None) => (),
+                        Err(ValError::Omit) => continue,
+                        Err(ValError::LineErrors(line_errors)) => {
+                            for err in line_errors {
+                                errors.push(err.with_outer_location(&field.name));
+                            }
+                            continue;
+                        }
+                        Err(err) => return Err(err),
+                    }
+                }
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
                         // Default value exists, and passed validation if required
@@ -239,6 +307,7 @@ impl Validator for TypedDictValidator {
                 used_keys: AHashSet<&'a str>,
                 errors: &'a mut Vec<ValLineError>,
                 extras_validator: Option<&'a CombinedValidator>,
+                pattern_extras: Option<&'a PatternExtras>,
                 output_dict: &'a Bound<'py, PyDict>,
                 state: &'a mut ValidationState<'s, 'py>,
                 extra_behavior: ExtraBehavior,
@@ -276,6 +345,24 @@ impl Validator for TypedDictValidator {
                         }
 
                         let value = value.borrow_input();
+                        // keys matching a pattern are validated by its schema whatever the extra behavior
+                        if let Some(pattern_extras) = self.pattern_extras {
+                            if let Some(validator) = pattern_extras.find(self.py, &cow)? {
+                                match validator.validate(self.py, value, self.state) {
+                                    Ok(value) => {
+                                        let py_key = either_str.as_py_string(self.py, self.state.cache_str());
+                                        self.output_dict.set_item(py_key, value)?;
+                                    }
+                                    Err(ValError::LineErrors(line_errors)) => {
+                                        for err in line_errors {
+                                            self.errors.push(err.with_outer_location(raw_key.clone()));
+                                        }
+                                    }
+                                    Err(err) => return Err(err),
+                                }
+                                continue;
+                            }
+                        }
                         // Unknown / extra field
                         match self.extra_behavior {
                             ExtraBehavior::Forbid => {
@@ -316,6 +403,7 @@ impl Validator for TypedDictValidator {
                 py,
                 errors: &mut errors,
                 extras_validator: self.extras_validator.as_deref(),
+                pattern_extras: self.pattern_extras.as_ref(),
                 output_dict: &output_dict,
                 state,
                 extra_behavior: self.extra_behavior,
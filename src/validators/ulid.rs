@@ -0,0 +1,146 @@
+use std::str::from_utf8;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use uuid::Uuid;
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{input_as_python_instance, Input, InputType, ValidationMatch};
+use crate::tools::SchemaDict;
+
+use super::uuid::{create_py_uuid, get_uuid_type};
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// Crockford's base32 alphabet, which ULIDs are written in
+const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ULID_LEN: usize = 26;
+
+/// The canonical, uppercase, string form of the 128 bit ULID `value`
+pub(crate) fn ulid_to_string(mut value: u128) -> String {
+    let mut chars = [0u8; ULID_LEN];
+    for c in chars.iter_mut().rev() {
+        *c = ALPHABET[(value & 0x1f) as usize];
+        value >>= 5;
+    }
+    // all characters are ASCII
+    String::from_utf8(chars.to_vec()).unwrap()
+}
+
+/// Parse the 26 character string form of a ULID, lowercase characters are only allowed when `lowercase` is set
+fn parse_ulid(s: &str, lowercase: bool) -> Result<u128, String> {
+    let char_count = s.chars().count();
+    if char_count != ULID_LEN {
+        return Err(format!("invalid length: expected 26 characters, found {char_count}"));
+    }
+    let mut value: u128 = 0;
+    for (index, c) in s.chars().enumerate() {
+        let upper = if lowercase { c.to_ascii_uppercase() } else { c };
+        let Some(digit) = ALPHABET.iter().position(|&a| char::from(a) == upper) else {
+            return Err(format!(
+                "invalid character: expected [0-9A-HJKMNP-TV-Z], found `{c}` at {index}"
+            ));
+        };
+        if index == 0 && digit > 7 {
+            return Err("the value is too large, the first character should be between 0 and 7".to_string());
+        }
+        value = (value << 5) | digit as u128;
+    }
+    Ok(value)
+}
+
+#[derive(Debug, Clone)]
+pub struct UlidValidator {
+    strict: bool,
+    // return a `uuid.UUID` with the same 128 bits rather than the ULID string
+    as_uuid: bool,
+}
+
+impl BuildValidator for UlidValidator {
+    const EXPECTED_TYPE: &'static str = "ulid";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            as_uuid: schema.get_as(intern!(py, "as_uuid"))?.unwrap_or(false),
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(UlidValidator {});
+
+impl Validator for UlidValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
+        let value = match input.validate_str(true, false).map(ValidationMatch::into_inner) {
+            Ok(either_str) => {
+                let cow = either_str.as_cow()?;
+                match parse_ulid(&cow, false) {
+                    Ok(value) => value,
+                    Err(error) if strict => return Err(parsing_error(error, input)),
+                    // lowercase, as written by some libraries, is only accepted in lax mode
+                    Err(_) => {
+                        state.floor_exactness(Exactness::Lax);
+                        parse_ulid(&cow, true).map_err(|error| parsing_error(error, input))?
+                    }
+                }
+            }
+            Err(_) if strict || state.extra().input_type != InputType::Python => {
+                return Err(ValError::new(ErrorTypeDefaults::UlidType, input));
+            }
+            Err(_) => {
+                state.floor_exactness(Exactness::Lax);
+                self.lax_value(py, input)?
+            }
+        };
+        if self.as_uuid {
+            create_py_uuid(get_uuid_type(py)?, &Uuid::from_u128(value))
+        } else {
+            Ok(ulid_to_string(value).into_py(py))
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
+
+impl UlidValidator {
+    /// The value of a `uuid.UUID` or bytes input, either 16 raw bytes or the string form
+    fn lax_value<'py>(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> ValResult<u128> {
+        if let Some(py_uuid) = input_as_python_instance(input, get_uuid_type(py)?) {
+            return Ok(py_uuid.getattr(intern!(py, "int"))?.extract()?);
+        }
+        let either_bytes = input
+            .validate_bytes(false)
+            .map_err(|_| ValError::new(ErrorTypeDefaults::UlidType, input))?
+            .into_inner();
+        let bytes = either_bytes.as_slice();
+        if let Ok(bytes) = <[u8; 16]>::try_from(bytes) {
+            return Ok(u128::from_be_bytes(bytes));
+        }
+        let s = from_utf8(bytes).map_err(|_| {
+            parsing_error(
+                format!("invalid length: expected 16 bytes, found {}", bytes.len()),
+                input,
+            )
+        })?;
+        parse_ulid(s, true).map_err(|error| parsing_error(error, input))
+    }
+}
+
+fn parsing_error<'py>(error: String, input: &(impl Input<'py> + ?Sized)) -> ValError {
+    ValError::new(ErrorType::UlidParsing { error, context: None }, input)
+}
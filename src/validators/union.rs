@@ -1,5 +1,7 @@
-use std::fmt::Write;
+use std::cmp::Ordering;
 use std::str::FromStr;
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{PoisonError, RwLock};
 
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString, PyTuple};
@@ -16,8 +18,10 @@ use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
 use super::literal::LiteralLookup;
+use super::tag_matcher::TagMatcher;
 use super::{
-    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, LaxReport, ValidationState,
+    Validator,
 };
 
 #[derive(Debug)]
@@ -44,6 +48,12 @@ pub struct UnionValidator {
     choices: Vec<(CombinedValidator, Option<String>)>,
     custom_error: Option<CustomError>,
     strict: bool,
+    // in smart mode, whether an exact type match is better than a strict match
+    prefer_exact_type: bool,
+    // in smart mode, whether the leftmost rather than the rightmost of equally good matches is chosen
+    prefer_leftmost_on_tie: bool,
+    // in smart mode, the order choices are tried in if it adapts to which choices are chosen most often
+    adaptive_order: Option<Box<AdaptiveOrder>>,
     name: String,
 }
 
@@ -77,6 +87,10 @@ impl BuildValidator for UnionValidator {
         let mode = schema
             .get_as::<Bound<'_, PyString>>(intern!(py, "mode"))?
             .map_or(Ok(UnionMode::Smart), |mode| mode.to_str().and_then(UnionMode::from_str))?;
+        let adaptive_order: bool = schema.get_as(intern!(py, "adaptive_order"))?.unwrap_or(false);
+        if adaptive_order && matches!(mode, UnionMode::LeftToRight) {
+            return py_schema_err!("`adaptive_order` is only supported in `smart` mode");
+        }
         match choices.len() {
             0 => py_schema_err!("One or more union choices required"),
             1 if auto_collapse() => Ok(choices.into_iter().next().unwrap().0),
@@ -89,9 +103,12 @@ impl BuildValidator for UnionValidator {
 
                 Ok(Self {
                     mode,
+                    adaptive_order: adaptive_order.then(|| Box::new(AdaptiveOrder::new(choices.len()))),
                     choices,
                     custom_error: CustomError::build(schema, config, definitions)?,
                     strict: is_strict(schema, config)?,
+                    prefer_exact_type: schema.get_as(intern!(py, "prefer_exact_type"))?.unwrap_or(true),
+                    prefer_leftmost_on_tie: schema.get_as(intern!(py, "prefer_leftmost_on_tie"))?.unwrap_or(true),
                     name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
                 }
                 .into())
@@ -100,7 +117,183 @@ impl BuildValidator for UnionValidator {
     }
 }
 
+// how many successful validations of an `adaptive_order` union there are between recomputing the try order
+const REORDER_INTERVAL: u64 = 1024;
+
+/// Counts of how often each choice of a smart mode union is chosen, so the choices chosen most often are tried first,
+/// an exact type match is still only returned once the choices before it in the schema have been tried so the choice
+/// returned never depends on earlier traffic
+#[derive(Debug)]
+struct AdaptiveOrder {
+    hits: Vec<AtomicU64>,
+    successes: AtomicU64,
+    order: RwLock<SmallVec<[usize; 8]>>,
+}
+
+impl AdaptiveOrder {
+    fn new(choices_len: usize) -> Self {
+        Self {
+            hits: (0..choices_len).map(|_| AtomicU64::new(0)).collect(),
+            successes: AtomicU64::new(0),
+            order: RwLock::new((0..choices_len).collect()),
+        }
+    }
+
+    /// The indexes of the choices in the order they should be tried
+    fn order(&self) -> SmallVec<[usize; 8]> {
+        self.order.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    fn record_hit(&self, index: usize) {
+        self.hits[index].fetch_add(1, atomic::Ordering::Relaxed);
+        if (self.successes.fetch_add(1, atomic::Ordering::Relaxed) + 1) % REORDER_INTERVAL == 0 {
+            self.reorder();
+        }
+    }
+
+    fn reorder(&self) {
+        // halve the counts as they're read, so they stay bounded and recent traffic counts for more
+        let hits: SmallVec<[u64; 8]> = self
+            .hits
+            .iter()
+            .map(|hits| {
+                let count = hits.load(atomic::Ordering::Relaxed);
+                hits.fetch_sub(count / 2, atomic::Ordering::Relaxed);
+                count
+            })
+            .collect();
+        let mut order: SmallVec<[usize; 8]> = (0..hits.len()).collect();
+        // the sort is stable, so equally popular choices stay in schema order
+        order.sort_by_key(|&index| std::cmp::Reverse(hits[index]));
+        *self.order.write().unwrap_or_else(PoisonError::into_inner) = order;
+    }
+}
+
+// the value, exactness, fields set count, what was recorded in `lax_report` and the choice index for the best
+// union member so far, `lax_report` is only kept for the best match
+type BestMatch = (Py<PyAny>, Exactness, Option<usize>, LaxReport, usize);
+
+// how a union member fared, only collected within `union_debug()`
+struct UnionCandidate {
+    index: usize,
+    // `None` if validation failed
+    exactness: Option<Exactness>,
+    fields_set_count: Option<usize>,
+}
+
 impl UnionValidator {
+    /// How a successful match with the given exactness ranks in smart mode
+    fn rank_exactness(&self, exactness: Exactness) -> Exactness {
+        match exactness {
+            Exactness::Exact if !self.prefer_exact_type => Exactness::Strict,
+            exactness => exactness,
+        }
+    }
+
+    /// Compare two successful matches, each described by its exactness and fields set count.
+    ///
+    /// We use both the exactness and the fields_set_count to determine the best union member match.
+    /// If fields_set_count is available for both matches, we use this as the primary metric,
+    /// and exactness as a tie breaker. Otherwise we use exactness to compare the matches.
+    fn compare_matches(&self, a: (Exactness, Option<usize>), b: (Exactness, Option<usize>)) -> Ordering {
+        match (a.1, b.1) {
+            (Some(a_count), Some(b_count)) if a_count != b_count => a_count.cmp(&b_count),
+            _ => self.rank_exactness(a.0).cmp(&self.rank_exactness(b.0)),
+        }
+    }
+
+    fn choice_name(&self, index: usize) -> &str {
+        let (choice, label) = &self.choices[index];
+        label.as_deref().unwrap_or(choice.get_name())
+    }
+
+    /// Why the choice at `best` was chosen from `candidates`
+    fn decision_reason(&self, candidates: &[UnionCandidate], best: usize) -> &'static str {
+        let as_match = |c: &UnionCandidate| c.exactness.map(|exactness| (exactness, c.fields_set_count));
+        let Some(best_match) = candidates.iter().find(|c| c.index == best).and_then(as_match) else {
+            return "only valid choice";
+        };
+        let others: Vec<_> = candidates
+            .iter()
+            .filter(|c| c.index != best)
+            .filter_map(as_match)
+            .collect();
+        if others.is_empty() {
+            "only valid choice"
+        } else if others
+            .iter()
+            .any(|other| self.compare_matches(*other, best_match) == Ordering::Equal)
+        {
+            if self.prefer_leftmost_on_tie {
+                "leftmost of equally good choices"
+            } else {
+                "rightmost of equally good choices"
+            }
+        } else if others
+            .iter()
+            .any(|(_, count)| matches!((count, best_match.1), (Some(a), Some(b)) if *a != b))
+        {
+            "most fields set"
+        } else {
+            "best exactness"
+        }
+    }
+
+    /// Append a description of a smart mode decision to the list yielded by `union_debug()`
+    fn record_decision(
+        &self,
+        py: Python,
+        state: &ValidationState,
+        candidates: &[UnionCandidate],
+        chosen: Option<usize>,
+        reason: &str,
+    ) -> PyResult<()> {
+        let Some(union_debug) = &state.union_debug else {
+            return Ok(());
+        };
+        let py_candidates = PyList::empty_bound(py);
+        for candidate in candidates {
+            let py_candidate = PyDict::new_bound(py);
+            py_candidate.set_item(intern!(py, "choice"), self.choice_name(candidate.index))?;
+            py_candidate.set_item(
+                intern!(py, "exactness"),
+                candidate.exactness.map(|exactness| match exactness {
+                    Exactness::Exact => "exact",
+                    Exactness::Strict => "strict",
+                    Exactness::Lax => "lax",
+                }),
+            )?;
+            py_candidate.set_item(intern!(py, "fields_set_count"), candidate.fields_set_count)?;
+            py_candidates.append(py_candidate)?;
+        }
+        let decision = PyDict::new_bound(py);
+        decision.set_item(intern!(py, "union"), &self.name)?;
+        decision.set_item(intern!(py, "loc"), PyTuple::new_bound(py, &state.field_path))?;
+        decision.set_item(intern!(py, "choice"), chosen.map(|index| self.choice_name(index)))?;
+        decision.set_item(intern!(py, "reason"), reason)?;
+        decision.set_item(intern!(py, "candidates"), py_candidates)?;
+        union_debug.bind(py).append(decision)
+    }
+
+    /// Return `output` of the choice at `index` which matched the input's exact type
+    fn exact_type_match(
+        &self,
+        py: Python,
+        state: &ValidationState,
+        candidates: Option<&mut Vec<UnionCandidate>>,
+        output: PyObject,
+        index: usize,
+    ) -> ValResult<PyObject> {
+        if let Some(candidates) = candidates {
+            candidates.sort_by_key(|candidate| candidate.index);
+            self.record_decision(py, state, candidates, Some(index), "exact type match")?;
+        }
+        if let Some(adaptive_order) = &self.adaptive_order {
+            adaptive_order.record_hit(index);
+        }
+        Ok(output)
+    }
+
     fn validate_smart<'py>(
         &self,
         py: Python<'py>,
@@ -109,13 +302,33 @@ impl UnionValidator {
     ) -> ValResult<PyObject> {
         let old_exactness = state.exactness;
         let old_fields_set_count = state.fields_set_count;
+        let lax_mark = state.lax_mark();
 
         let strict = state.strict_or(self.strict);
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
-        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>)> = None;
-
-        for (choice, label) in &self.choices {
+        let mut best_match: Option<BestMatch> = None;
+        let mut candidates: Option<Vec<UnionCandidate>> = state.union_debug.as_ref().map(|_| Vec::new());
+
+        let order = self
+            .adaptive_order
+            .as_ref()
+            .map(|adaptive_order| adaptive_order.order());
+        // with `adaptive_order`, an exact type match is held until the choices before it in the schema have been
+        // tried, one of those matching exactly takes precedence, choices after it needn't be tried
+        let mut tried = order.as_ref().map(|_| vec![false; self.choices.len()]);
+        let mut held_exact_match: Option<(PyObject, LaxReport, usize)> = None;
+        for position in 0..self.choices.len() {
+            let index = order.as_ref().map_or(position, |order| order[position]);
+            if matches!(held_exact_match, Some((.., exact_index)) if index > exact_index) {
+                continue;
+            }
+            let (choice, _) = &self.choices[index];
+            if position > 0 {
+                if let Some(stats) = &state.stats {
+                    stats.record_union_retry();
+                }
+            }
             let state = &mut state.rebind_extra(|extra| {
                 if strict {
                     extra.strict = Some(strict);
@@ -124,16 +337,27 @@ impl UnionValidator {
             state.exactness = Some(Exactness::Exact);
             state.fields_set_count = None;
             let result = choice.validate(py, input, state);
+            if let Some(ref mut tried) = tried {
+                tried[index] = true;
+            }
+            if let Some(ref mut candidates) = candidates {
+                candidates.push(UnionCandidate {
+                    index,
+                    exactness: result.as_ref().ok().and(state.exactness),
+                    fields_set_count: result.as_ref().ok().and(state.fields_set_count),
+                });
+            }
             match result {
                 Ok(new_success) => match (state.exactness, state.fields_set_count) {
-                    (Some(Exactness::Exact), None) => {
-                        // exact match with no fields set data, return immediately
-                        return {
-                            // exact match, return, restore any previous exactness
-                            state.exactness = old_exactness;
-                            state.fields_set_count = old_fields_set_count;
-                            Ok(new_success)
-                        };
+                    (Some(Exactness::Exact), None) if self.prefer_exact_type && self.prefer_leftmost_on_tie => {
+                        if tried.as_ref().is_some_and(|tried| tried[..index].contains(&false)) {
+                            held_exact_match = Some((new_success, state.take_lax(lax_mark), index));
+                            continue;
+                        }
+                        // exact match with no fields set data, return immediately, restore any previous exactness
+                        state.exactness = old_exactness;
+                        state.fields_set_count = old_fields_set_count;
+                        return self.exact_type_match(py, state, candidates.as_mut(), new_success, index);
                     }
                     _ => {
                         // success should always have an exactness
@@ -141,32 +365,32 @@ impl UnionValidator {
 
                         let new_exactness = state.exactness.unwrap_or(Exactness::Lax);
                         let new_fields_set_count = state.fields_set_count;
-
-                        // we use both the exactness and the fields_set_count to determine the best union member match
-                        // if fields_set_count is available for the current best match and the new candidate, we use this
-                        // as the primary metric. If the new fields_set_count is greater, the new candidate is better.
-                        // if the fields_set_count is the same, we use the exactness as a tie breaker to determine the best match.
-                        // if the fields_set_count is not available for either the current best match or the new candidate,
-                        // we use the exactness to determine the best match.
-                        let new_success_is_best_match: bool =
-                            best_match
-                                .as_ref()
-                                .map_or(true, |(_, cur_exactness, cur_fields_set_count)| {
-                                    match (*cur_fields_set_count, new_fields_set_count) {
-                                        (Some(cur), Some(new)) if cur != new => cur < new,
-                                        _ => *cur_exactness < new_exactness,
-                                    }
-                                });
+                        let new_lax_report = state.take_lax(lax_mark);
+
+                        let new_success_is_best_match: bool = best_match.as_ref().map_or(
+                            true,
+                            |(_, cur_exactness, cur_fields_set_count, _, cur_index)| match self.compare_matches(
+                                (new_exactness, new_fields_set_count),
+                                (*cur_exactness, *cur_fields_set_count),
+                            ) {
+                                Ordering::Greater => true,
+                                // compare indexes rather than the order tried, which `adaptive_order` may change
+                                Ordering::Equal => (index < *cur_index) == self.prefer_leftmost_on_tie,
+                                Ordering::Less => false,
+                            },
+                        );
 
                         if new_success_is_best_match {
-                            best_match = Some((new_success, new_exactness, new_fields_set_count));
+                            best_match =
+                                Some((new_success, new_exactness, new_fields_set_count, new_lax_report, index));
                         }
                     }
                 },
                 Err(ValError::LineErrors(lines)) => {
+                    state.truncate_lax(lax_mark);
                     // if we don't yet know this validation will succeed, record the error
                     if best_match.is_none() {
-                        errors.push(choice, label.as_deref(), lines);
+                        errors.push(index, lines);
                     }
                 }
                 otherwise => return otherwise,
@@ -177,8 +401,33 @@ impl UnionValidator {
         state.exactness = old_exactness;
         state.fields_set_count = old_fields_set_count;
 
-        if let Some((best_match, exactness, fields_set_count)) = best_match {
+        if let Some((exact_match, lax_report, index)) = held_exact_match {
+            state.extend_lax(lax_report);
+            return self.exact_type_match(py, state, candidates.as_mut(), exact_match, index);
+        }
+
+        if let Some(ref mut candidates) = candidates {
+            candidates.sort_by_key(|candidate| candidate.index);
+            match best_match {
+                Some((.., index)) => {
+                    let reason = self.decision_reason(candidates, index);
+                    self.record_decision(py, state, candidates, Some(index), reason)?;
+                }
+                None => self.record_decision(py, state, candidates, None, "no valid choice")?,
+            }
+        }
+
+        if let Some((best_match, exactness, fields_set_count, lax_report, index)) = best_match {
+            if let Some(adaptive_order) = &self.adaptive_order {
+                adaptive_order.record_hit(index);
+            }
+            if exactness == Exactness::Lax {
+                if let Some(stats) = &state.stats {
+                    stats.record_lax_fallback();
+                }
+            }
             state.floor_exactness(exactness);
+            state.extend_lax(lax_report);
             if let Some(count) = fields_set_count {
                 state.add_fields_set(count);
             }
@@ -186,7 +435,7 @@ impl UnionValidator {
         }
 
         // no matches, build errors
-        Err(errors.into_val_error(input))
+        Err(errors.into_val_error(self, input))
     }
 
     fn validate_left_to_right<'py>(
@@ -205,14 +454,23 @@ impl UnionValidator {
             state
         };
 
-        for (validator, label) in &self.choices {
+        let lax_mark = state.lax_mark();
+        for (index, (validator, _)) in self.choices.iter().enumerate() {
+            if index > 0 {
+                if let Some(stats) = &state.stats {
+                    stats.record_union_retry();
+                }
+            }
             match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
+                Err(ValError::LineErrors(lines)) => {
+                    state.truncate_lax(lax_mark);
+                    errors.push(index, lines);
+                }
                 otherwise => return otherwise,
             };
         }
 
-        Err(errors.into_val_error(input))
+        Err(errors.into_val_error(self, input))
     }
 }
 
@@ -241,15 +499,14 @@ impl Validator for UnionValidator {
     }
 }
 
-struct ChoiceLineErrors<'a> {
-    choice: &'a CombinedValidator,
-    label: Option<&'a str>,
+struct ChoiceLineErrors {
+    index: usize,
     line_errors: Vec<ValLineError>,
 }
 
 enum MaybeErrors<'a> {
     Custom(&'a CustomError),
-    Errors(SmallVec<[ChoiceLineErrors<'a>; 4]>),
+    Errors(SmallVec<[ChoiceLineErrors; 4]>),
 }
 
 impl<'a> MaybeErrors<'a> {
@@ -260,37 +517,29 @@ impl<'a> MaybeErrors<'a> {
         }
     }
 
-    fn push(&mut self, choice: &'a CombinedValidator, label: Option<&'a str>, line_errors: Vec<ValLineError>) {
+    fn push(&mut self, index: usize, line_errors: Vec<ValLineError>) {
         match self {
             Self::Custom(_) => {}
-            Self::Errors(errors) => errors.push(ChoiceLineErrors {
-                choice,
-                label,
-                line_errors,
-            }),
+            Self::Errors(errors) => errors.push(ChoiceLineErrors { index, line_errors }),
         }
     }
 
-    fn into_val_error(self, input: impl ToErrorValue) -> ValError {
+    fn into_val_error(self, union: &UnionValidator, input: impl ToErrorValue) -> ValError {
         match self {
             Self::Custom(custom_error) => custom_error.as_val_error(input),
-            Self::Errors(errors) => ValError::LineErrors(
+            Self::Errors(mut errors) => ValError::LineErrors({
+                // choices may have been tried out of order with `adaptive_order`, errors are always in schema order
+                errors.sort_by_key(|errors| errors.index);
                 errors
                     .into_iter()
-                    .flat_map(
-                        |ChoiceLineErrors {
-                             choice,
-                             label,
-                             line_errors,
-                         }| {
-                            line_errors.into_iter().map(move |err| {
-                                let case_label = label.unwrap_or(choice.get_name());
-                                err.with_outer_location(case_label)
-                            })
-                        },
-                    )
-                    .collect(),
-            ),
+                    .flat_map(|ChoiceLineErrors { index, line_errors }| {
+                        let case_label = union.choice_name(index);
+                        line_errors
+                            .into_iter()
+                            .map(move |err| err.with_outer_location(case_label))
+                    })
+                    .collect()
+            }),
         }
     }
 }
@@ -345,6 +594,10 @@ pub struct TaggedUnionValidator {
     from_attributes: bool,
     strict: bool,
     custom_error: Option<CustomError>,
+    // choices keyed by tag ranges or patterns, tried when the tag isn't one of the choices
+    tag_matcher: Option<Box<TagMatcher>>,
+    // the tag of the choice to use when the tag is missing or isn't one of the choices
+    default_choice: Option<Py<PyAny>>,
     tags_repr: String,
     discriminator_repr: String,
     name: String,
@@ -362,29 +615,35 @@ impl BuildValidator for TaggedUnionValidator {
         let discriminator = Discriminator::new(py, &schema.get_as_req(intern!(py, "discriminator"))?)?;
         let discriminator_repr = discriminator.to_string_py(py)?;
 
-        let choices = PyDict::new_bound(py);
-        let mut tags_repr = String::with_capacity(50);
-        let mut descr = String::with_capacity(50);
-        let mut first = true;
         let schema_choices: Bound<PyDict> = schema.get_as_req(intern!(py, "choices"))?;
-        let mut lookup_map = Vec::with_capacity(choices.len());
+        let mut tag_reprs = Vec::with_capacity(schema_choices.len());
+        let mut names = Vec::with_capacity(schema_choices.len());
+        let mut lookup_map = Vec::with_capacity(schema_choices.len());
         for (choice_key, choice_schema) in schema_choices {
             let validator = build_validator(&choice_schema, config, definitions)?;
-            let tag_repr = choice_key.repr()?.to_string();
-            if first {
-                first = false;
-                write!(tags_repr, "{tag_repr}").unwrap();
-                descr.push_str(validator.get_name());
-            } else {
-                write!(tags_repr, ", {tag_repr}").unwrap();
-                // no spaces in get_name() output to make loc easy to read
-                write!(descr, ",{}", validator.get_name()).unwrap();
-            }
+            tag_reprs.push(choice_key.repr()?.to_string());
+            names.push(validator.get_name().to_string());
             lookup_map.push((choice_key, validator));
         }
 
+        let tag_matcher = TagMatcher::build(schema, config, definitions)?;
+        if let Some(ref tag_matcher) = tag_matcher {
+            tag_reprs.extend(tag_matcher.tag_reprs().map(str::to_string));
+            names.extend(tag_matcher.names().map(str::to_string));
+        }
+        let tags_repr = tag_reprs.join(", ");
+        // no spaces in get_name() output to make loc easy to read
+        let descr = names.join(",");
+
         let lookup = LiteralLookup::new(py, lookup_map.into_iter())?;
 
+        let default_choice: Option<Bound<'_, PyAny>> = schema.get_as(intern!(py, "default_choice"))?;
+        if let Some(ref default_choice) = default_choice {
+            if !matches!(lookup.validate(py, default_choice), Ok(Some(_))) {
+                return py_schema_err!("`default_choice` {} is not one of the choices", default_choice.repr()?);
+            }
+        }
+
         let key = intern!(py, "from_attributes");
         let from_attributes = schema_or_config(schema, config, key, key)?.unwrap_or(true);
 
@@ -399,6 +658,8 @@ impl BuildValidator for TaggedUnionValidator {
             from_attributes,
             strict: is_strict(schema, config)?,
             custom_error: CustomError::build(schema, config, definitions)?,
+            tag_matcher: tag_matcher.map(Box::new),
+            default_choice: default_choice.map(Bound::unbind),
             tags_repr,
             discriminator_repr,
             name: format!("{}[{descr}]", Self::EXPECTED_TYPE),
@@ -407,7 +668,12 @@ impl BuildValidator for TaggedUnionValidator {
     }
 }
 
-impl_py_gc_traverse!(TaggedUnionValidator { discriminator, lookup });
+impl_py_gc_traverse!(TaggedUnionValidator {
+    discriminator,
+    lookup,
+    tag_matcher,
+    default_choice
+});
 
 impl Validator for TaggedUnionValidator {
     fn validate<'py>(
@@ -424,14 +690,14 @@ impl Validator for TaggedUnionValidator {
                 // errors when getting attributes which should be "raised"
                 let tag = match dict.get_item(lookup_key)? {
                     Some((_, value)) => value,
-                    None => return Err(self.tag_not_found(input)),
+                    None => return self.validate_tag_not_found(py, input, state),
                 };
                 self.find_call_validator(py, tag.borrow_input().to_object(py).bind(py), input, state)
             }
             Discriminator::Function(func) => {
                 let tag: Py<PyAny> = func.call1(py, (input.to_object(py),))?;
                 if tag.is_none(py) {
-                    Err(self.tag_not_found(input))
+                    self.validate_tag_not_found(py, input, state)
                 } else {
                     self.find_call_validator(py, tag.bind(py), input, state)
                 }
@@ -490,6 +756,15 @@ impl TaggedUnionValidator {
                 Err(err) => Err(err.with_outer_location(tag)),
             };
         }
+        if let Some(validator) = self.tag_matcher.as_ref().map(|m| m.find(tag)).transpose()?.flatten() {
+            return match validator.validate(py, input, state) {
+                Ok(res) => Ok(res),
+                Err(err) => Err(err.with_outer_location(tag)),
+            };
+        }
+        if let Some(ref default_choice) = self.default_choice {
+            return self.find_call_validator(py, default_choice.bind(py), input, state);
+        }
         match self.custom_error {
             Some(ref custom_error) => Err(custom_error.as_val_error(input)),
             None => Err(ValError::new(
@@ -504,6 +779,19 @@ impl TaggedUnionValidator {
         }
     }
 
+    /// Validate against `default_choice` if set, otherwise raise a `union_tag_not_found` error
+    fn validate_tag_not_found<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        match self.default_choice {
+            Some(ref default_choice) => self.find_call_validator(py, default_choice.bind(py), input, state),
+            None => Err(self.tag_not_found(input)),
+        }
+    }
+
     fn tag_not_found<'py>(&self, input: &(impl Input<'py> + ?Sized)) -> ValError {
         match self.custom_error {
             Some(ref custom_error) => custom_error.as_val_error(input),
@@ -4,7 +4,7 @@ use std::str::Chars;
 
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDict, PyList};
+use pyo3::types::{PyDict, PyList, PyString};
 
 use ahash::AHashSet;
 use url::{ParseError, SyntaxViolation, Url};
@@ -15,7 +15,7 @@ use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::downcast_python_input;
 use crate::input::Input;
 use crate::tools::SchemaDict;
-use crate::url::{schema_is_special, PyMultiHostUrl, PyUrl};
+use crate::url::{normalize_percent_encoding, normalize_url, schema_is_special, PyMultiHostUrl, PyUrl};
 
 use super::literal::expected_repr_name;
 use super::Exactness;
@@ -32,6 +32,7 @@ pub struct UrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    constraints: UrlConstraints,
     name: String,
 }
 
@@ -53,6 +54,7 @@ impl BuildValidator for UrlValidator {
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
             allowed_schemes,
+            constraints: UrlConstraints::build(schema)?,
             name,
         }
         .into())
@@ -89,7 +91,9 @@ impl Validator for UrlValidator {
             &self.default_host,
             self.default_port,
             &self.default_path,
-        ) {
+        )
+        .and_then(|()| self.constraints.check(either_url.url()))
+        {
             Ok(()) => {
                 // Lax rather than strict to preserve V2.4 semantic that str wins over url in union
                 state.floor_exactness(Exactness::Lax);
@@ -114,7 +118,11 @@ impl UrlValidator {
 
                 self.check_length(input, url_str)?;
 
-                parse_url(url_str, input, strict).map(EitherUrl::Rust)
+                let url = parse_url(url_str, input, strict)?;
+                self.constraints
+                    .check_idna_input(&url, url_str)
+                    .map_err(|error_type| ValError::new(error_type, input))?;
+                Ok(EitherUrl::Rust(url))
             }
             Err(_) => {
                 // we don't need to worry about whether the url was parsed in strict mode before,
@@ -192,6 +200,7 @@ pub struct MultiHostUrlValidator {
     default_host: Option<String>,
     default_port: Option<u16>,
     default_path: Option<String>,
+    constraints: UrlConstraints,
     name: String,
 }
 
@@ -219,6 +228,7 @@ impl BuildValidator for MultiHostUrlValidator {
             default_host,
             default_port: schema.get_as(intern!(schema.py(), "default_port"))?,
             default_path: schema.get_as(intern!(schema.py(), "default_path"))?,
+            constraints: UrlConstraints::build(schema)?,
             name,
         }
         .into())
@@ -254,7 +264,9 @@ impl Validator for MultiHostUrlValidator {
             &self.default_host,
             self.default_port,
             &self.default_path,
-        ) {
+        )
+        .and_then(|()| multi_url.lib_urls().try_for_each(|url| self.constraints.check(url)))
+        {
             Ok(()) => {
                 // Lax rather than strict to preserve V2.4 semantic that str wins over url in union
                 state.floor_exactness(Exactness::Lax);
@@ -279,7 +291,12 @@ impl MultiHostUrlValidator {
 
                 self.check_length(input, || url_str.len())?;
 
-                parse_multihost_url(url_str, input, strict).map(EitherMultiHostUrl::Rust)
+                let multi_url = parse_multihost_url(url_str, input, strict)?;
+                multi_url
+                    .lib_urls()
+                    .try_for_each(|url| self.constraints.check_idna_input(url, url_str))
+                    .map_err(|error_type| ValError::new(error_type, input))?;
+                Ok(EitherMultiHostUrl::Rust(multi_url))
             }
             Err(_) => {
                 // we don't need to worry about whether the url was parsed in strict mode before,
@@ -325,6 +342,13 @@ enum EitherMultiHostUrl<'py> {
 }
 
 impl EitherMultiHostUrl<'_> {
+    fn lib_urls(&self) -> impl Iterator<Item = &Url> {
+        match self {
+            EitherMultiHostUrl::Py(py_multi_url) => py_multi_url.get().lib_urls(),
+            EitherMultiHostUrl::Rust(rust_multi_url) => rust_multi_url.lib_urls(),
+        }
+    }
+
     fn into_py(self, py: Python) -> PyObject {
         match self {
             EitherMultiHostUrl::Py(py_multi_url) => py_multi_url.into_py(py),
@@ -471,6 +495,156 @@ fn parse_multihost_url<'py>(
     }
 }
 
+/// A URI reference as per RFC 3986, either an absolute URL or a relative reference like `../path?query`,
+/// unlike the other URL validators the output is a string
+#[derive(Debug, Clone)]
+pub struct UriReferenceValidator {
+    strict: bool,
+    max_length: Option<usize>,
+    allowed_schemes: AllowedSchemas,
+    normalize: bool,
+    // relative references are resolved against this to check they're valid
+    base: Url,
+    name: String,
+}
+
+impl BuildValidator for UriReferenceValidator {
+    const EXPECTED_TYPE: &'static str = "uri-reference";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let (allowed_schemes, name) = get_allowed_schemas(schema, Self::EXPECTED_TYPE)?;
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            max_length: schema.get_as(intern!(py, "max_length"))?,
+            allowed_schemes,
+            normalize: schema.get_as(intern!(py, "normalize"))?.unwrap_or(false),
+            base: Url::parse("http://localhost/").expect("base URL is valid"),
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(UriReferenceValidator {});
+
+impl Validator for UriReferenceValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let strict = state.strict_or(self.strict);
+        let either_str = match input.validate_str(strict, false) {
+            Ok(val_match) => val_match.into_inner(),
+            Err(_) => return self.validate_url_instance(py, input, state),
+        };
+        let cow = either_str.as_cow()?;
+        let url_str = cow.as_ref();
+        self.check_length(input, url_str)?;
+
+        let normalized = if has_scheme(url_str) {
+            let url = parse_url(url_str, input, strict)?;
+            self.check_scheme(input, url.scheme())?;
+            self.normalize.then(|| normalize_url(&url).to_string())
+        } else {
+            parse_url_with_base(url_str, Some(&self.base), input, strict)?;
+            self.normalize.then(|| normalize_percent_encoding(url_str).into_owned())
+        };
+
+        match normalized {
+            Some(normalized) => Ok(normalized.into_py(py)),
+            None => Ok(either_str.as_py_string(py, state.cache_str()).into_py(py)),
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl UriReferenceValidator {
+    /// `Url` and `MultiHostUrl` instances are absolute URLs which have already been validated
+    fn validate_url_instance<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let url_str = if let Some(py_url) = downcast_python_input::<PyUrl>(input) {
+            if self.normalize {
+                py_url.get().normalized().__str__().to_string()
+            } else {
+                py_url.get().__str__().to_string()
+            }
+        } else if let Some(multi_host_url) = downcast_python_input::<PyMultiHostUrl>(input) {
+            if self.normalize {
+                multi_host_url.get().normalized().__str__()
+            } else {
+                multi_host_url.get().__str__()
+            }
+        } else {
+            return Err(ValError::new(ErrorTypeDefaults::UrlType, input));
+        };
+        self.check_length(input, &url_str)?;
+        if let Some((scheme, _)) = url_str.split_once(':') {
+            self.check_scheme(input, scheme)?;
+        }
+        state.floor_exactness(Exactness::Lax);
+        Ok(url_str.into_py(py))
+    }
+
+    fn check_length<'py>(&self, input: &(impl Input<'py> + ?Sized), url_str: &str) -> ValResult<()> {
+        if let Some(max_length) = self.max_length {
+            if url_str.len() > max_length {
+                return Err(ValError::new(
+                    ErrorType::UrlTooLong {
+                        max_length,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    fn check_scheme<'py>(&self, input: &(impl Input<'py> + ?Sized), scheme: &str) -> ValResult<()> {
+        if let Some((ref allowed_schemes, ref expected_schemes_repr)) = self.allowed_schemes {
+            if !allowed_schemes.contains(scheme) {
+                let expected_schemes = expected_schemes_repr.clone();
+                return Err(ValError::new(
+                    ErrorType::UrlScheme {
+                        expected_schemes,
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `s` starts with a scheme, as per RFC 3986 section 3.1, otherwise it's a relative reference
+fn has_scheme(s: &str) -> bool {
+    match s.split_once(':') {
+        Some((scheme, _)) => {
+            scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+                && scheme
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+        }
+        None => false,
+    }
+}
+
 fn parse_url(url_str: &str, input: impl ToErrorValue, strict: bool) -> ValResult<Url> {
     if url_str.is_empty() {
         return Err(ValError::new(
@@ -481,13 +655,18 @@ fn parse_url(url_str: &str, input: impl ToErrorValue, strict: bool) -> ValResult
             input,
         ));
     }
+    parse_url_with_base(url_str, None, input, strict)
+}
 
+/// Parse `url_str`, resolved against `base` if it's a relative reference
+fn parse_url_with_base(url_str: &str, base: Option<&Url>, input: impl ToErrorValue, strict: bool) -> ValResult<Url> {
     // if we're in strict mode, we collect consider a syntax violation as an error
     if strict {
         // we could build a vec of syntax violations and return them all, but that seems like overkill
         // and unlike other parser style validators
         let vios: RefCell<Option<SyntaxViolation>> = RefCell::new(None);
         let r = Url::options()
+            .base_url(base)
             .syntax_violation_callback(Some(&|v| {
                 match v {
                     // telling users offer about credentials in URLs doesn't really make sense in this context
@@ -520,7 +699,7 @@ fn parse_url(url_str: &str, input: impl ToErrorValue, strict: bool) -> ValResult
             )),
         }
     } else {
-        Url::parse(url_str).map_err(move |e| {
+        Url::options().base_url(base).parse(url_str).map_err(move |e| {
             ValError::new(
                 ErrorType::UrlParsing {
                     error: e.to_string(),
@@ -544,14 +723,11 @@ fn check_sub_defaults(
         error: e.to_string(),
         context: None,
     };
-    if let Some(ref default_host) = default_host {
-        if !url.url().has_host() {
+    if !url.url().has_host() {
+        if let Some(ref default_host) = default_host {
             url.url_mut().set_host(Some(default_host)).map_err(map_parse_err)?;
         } else if host_required {
-            return Err(ErrorType::UrlParsing {
-                error: ParseError::EmptyHost.to_string(),
-                context: None,
-            });
+            return Err(map_parse_err(ParseError::EmptyHost));
         }
     }
     if let Some(default_port) = default_port {
@@ -577,6 +753,147 @@ trait CopyFromPyUrl {
     fn url_mut(&mut self) -> &mut Url;
 }
 
+/// How internationalized domain names in hosts are treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(super) enum IdnaPolicy {
+    #[default]
+    Allow,
+    // only when written in punycode, e.g. `xn--caf-dma.com` but not `café.com`
+    Punycode,
+    Forbid,
+}
+
+impl IdnaPolicy {
+    pub(super) fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let idna: Option<Bound<'_, PyString>> = schema.get_as(intern!(schema.py(), "idna"))?;
+        match idna.as_ref().map(|s| s.to_str()).transpose()? {
+            None | Some("allow") => Ok(Self::Allow),
+            Some("punycode") => Ok(Self::Punycode),
+            Some("forbid") => Ok(Self::Forbid),
+            Some(s) => py_schema_err!("Invalid `idna` value: {}, expected 'allow', 'punycode' or 'forbid'", s),
+        }
+    }
+}
+
+/// Checks of the host, userinfo and component lengths of URLs, made after any defaults are substituted
+#[derive(Debug, Clone, Default)]
+struct UrlConstraints {
+    // patterns, either a host or `*.` followed by a domain to match its subdomains, and their repr
+    allowed_hosts: Option<(Vec<String>, String)>,
+    forbid_userinfo: bool,
+    idna: IdnaPolicy,
+    max_component_lengths: Vec<(&'static str, usize)>,
+}
+
+const URL_COMPONENTS: [&str; 6] = ["username", "password", "host", "path", "query", "fragment"];
+
+impl UrlConstraints {
+    fn build(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let py = schema.py();
+        let allowed_hosts = match schema.get_as::<Vec<String>>(intern!(py, "allowed_hosts"))? {
+            Some(hosts) if hosts.is_empty() => return py_schema_err!("`allowed_hosts` should have length > 0"),
+            Some(hosts) => {
+                let hosts: Vec<String> = hosts.iter().map(|host| host.to_ascii_lowercase()).collect();
+                let (repr, _) = expected_repr_name(hosts.iter().map(|host| format!("'{host}'")).collect(), "");
+                Some((hosts, repr))
+            }
+            None => None,
+        };
+        let mut max_component_lengths = Vec::new();
+        if let Some(lengths) = schema.get_as::<Bound<'_, PyDict>>(intern!(py, "max_component_lengths"))? {
+            for (key, value) in lengths {
+                let key: String = key.extract()?;
+                let Some(component) = URL_COMPONENTS.iter().find(|c| **c == key) else {
+                    return py_schema_err!(
+                        "Invalid `max_component_lengths` key: {}, expected one of {}",
+                        key,
+                        URL_COMPONENTS.join(", ")
+                    );
+                };
+                max_component_lengths.push((*component, value.extract()?));
+            }
+        }
+        Ok(Self {
+            allowed_hosts,
+            forbid_userinfo: schema.get_as(intern!(py, "forbid_userinfo"))?.unwrap_or(false),
+            idna: IdnaPolicy::from_schema(schema)?,
+            max_component_lengths,
+        })
+    }
+
+    fn check(&self, url: &Url) -> Result<(), ErrorType> {
+        if self.forbid_userinfo && (!url.username().is_empty() || url.password().is_some()) {
+            return Err(ErrorType::UrlUserinfo { context: None });
+        }
+        if let Some((ref allowed_hosts, ref expected_hosts)) = self.allowed_hosts {
+            let host = url.host_str().unwrap_or_default();
+            if !allowed_hosts.iter().any(|pattern| host_matches(pattern, host)) {
+                return Err(ErrorType::UrlHost {
+                    expected_hosts: expected_hosts.clone(),
+                    context: None,
+                });
+            }
+        }
+        if self.idna == IdnaPolicy::Forbid && idna_labels(url).next().is_some() {
+            return Err(ErrorType::UrlIdna {
+                error: "internationalized domain names are not allowed".to_string(),
+                context: None,
+            });
+        }
+        for &(component, max_length) in &self.max_component_lengths {
+            let length = match component {
+                "username" => url.username().len(),
+                "password" => url.password().map_or(0, str::len),
+                "host" => url.host_str().map_or(0, str::len),
+                "path" => url.path().len(),
+                "query" => url.query().map_or(0, str::len),
+                _ => url.fragment().map_or(0, str::len),
+            };
+            if length > max_length {
+                return Err(ErrorType::UrlComponentTooLong {
+                    component: component.to_string(),
+                    max_length,
+                    context: None,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// With the `punycode` policy, check internationalized domain names in the host of `url` were written
+    /// in punycode in `url_str` rather than converted from unicode when parsing
+    fn check_idna_input(&self, url: &Url, url_str: &str) -> Result<(), ErrorType> {
+        if self.idna == IdnaPolicy::Punycode {
+            let url_str = url_str.to_ascii_lowercase();
+            if idna_labels(url).any(|label| !url_str.contains(label)) {
+                return Err(ErrorType::UrlIdna {
+                    error: "internationalized domain names should be written in punycode".to_string(),
+                    context: None,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Whether `host` matches `pattern`, patterns starting with `*.` match any subdomain of the rest of the pattern
+fn host_matches(pattern: &str, host: &str) -> bool {
+    match pattern.strip_prefix("*.") {
+        Some(domain) => host
+            .strip_suffix(domain)
+            .is_some_and(|sub| sub.len() > 1 && sub.ends_with('.')),
+        None => pattern == host,
+    }
+}
+
+/// The punycode labels of the host of `url`, e.g. `xn--caf-dma` in `xn--caf-dma.com`
+fn idna_labels(url: &Url) -> impl Iterator<Item = &str> {
+    url.domain()
+        .into_iter()
+        .flat_map(|domain| domain.split('.'))
+        .filter(|label| label.starts_with("xn--"))
+}
+
 fn get_allowed_schemas(schema: &Bound<'_, PyDict>, name: &'static str) -> PyResult<(AllowedSchemas, String)> {
     match schema.get_as::<Bound<'_, PyList>>(intern!(schema.py(), "allowed_schemes"))? {
         Some(list) => {
@@ -23,17 +23,23 @@ const UUID_INT: &str = "int";
 const UUID_IS_SAFE: &str = "is_safe";
 
 static UUID_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static UUID_SAFE: GILOnceCell<PyObject> = GILOnceCell::new();
 
 fn import_type(py: Python, module: &str, attr: &str) -> PyResult<Py<PyType>> {
     py.import_bound(module)?.getattr(attr)?.extract()
 }
 
-fn get_uuid_type(py: Python) -> PyResult<&Bound<'_, PyType>> {
+pub(crate) fn get_uuid_type(py: Python) -> PyResult<&Bound<'_, PyType>> {
     Ok(UUID_TYPE
         .get_or_init(py, || import_type(py, "uuid", "UUID").unwrap())
         .bind(py))
 }
 
+/// Whether `uuid` is an RFC 4122 UUID of the given version, matching `UUID.version` in python
+fn is_version(uuid: &Uuid, expected_version: usize) -> bool {
+    uuid.get_variant() == Variant::RFC4122 && uuid.get_version_num() == expected_version
+}
+
 #[derive(Debug, Clone, Copy)]
 enum Version {
     UUIDv1 = 1,
@@ -97,11 +103,9 @@ impl Validator for UuidValidator {
         let class = get_uuid_type(py)?;
         if let Some(py_input) = input_as_python_instance(input, class) {
             if let Some(expected_version) = self.version {
-                let py_input_version: Option<usize> = py_input.getattr(intern!(py, "version"))?.extract()?;
-                if !match py_input_version {
-                    Some(py_input_version) => py_input_version == expected_version,
-                    None => false,
-                } {
+                // `UUID.version` is derived from `UUID.int`, so check it here rather than via the python property
+                let int: u128 = py_input.getattr(intern!(py, UUID_INT))?.extract()?;
+                if !is_version(&Uuid::from_u128(int), expected_version) {
                     return Err(ValError::new(
                         ErrorType::UuidVersion {
                             expected_version,
@@ -135,7 +139,7 @@ impl Validator for UuidValidator {
             // if the UUID variant conforms to RFC 4122. When dealing with Python inputs,
             // UUIDs must adhere to RFC 4122 standards.
             if let Some(expected_version) = self.version {
-                if uuid.get_version_num() != expected_version || uuid.get_variant() != Variant::RFC4122 {
+                if !is_version(&uuid, expected_version) {
                     return Err(ValError::new(
                         ErrorType::UuidVersion {
                             expected_version,
@@ -145,7 +149,7 @@ impl Validator for UuidValidator {
                     ));
                 }
             }
-            self.create_py_uuid(class, &uuid)
+            create_py_uuid(class, &uuid)
         }
     }
 
@@ -210,24 +214,27 @@ impl UuidValidator {
         };
         Ok(uuid)
     }
+}
 
-    /// Sets the attributes in a Python type object (`py_type`) to represent a UUID class.
-    /// The function creates the python class and converts the UUID to a u128 integer and
-    /// sets the corresponding attributes in the dictionary object to the converted value
-    /// and a 'safe' flag.
-    ///
-    /// This implementation does not use the Python `__init__` function to speed up the process,
-    /// as the `__init__` function in the Python `uuid` module performs extensive checks.
-    fn create_py_uuid(&self, py_type: &Bound<'_, PyType>, uuid: &Uuid) -> ValResult<PyObject> {
-        let py = py_type.py();
-        let dc = create_class(py_type)?;
-        let int = uuid.as_u128();
-        let safe = py
+/// Sets the attributes in a Python type object (`py_type`) to represent a UUID class.
+/// The function creates the python class and converts the UUID to a u128 integer and
+/// sets the corresponding attributes in the dictionary object to the converted value
+/// and a 'safe' flag.
+///
+/// This implementation does not use the Python `__init__` function to speed up the process,
+/// as the `__init__` function in the Python `uuid` module performs extensive checks.
+pub(crate) fn create_py_uuid(py_type: &Bound<'_, PyType>, uuid: &Uuid) -> ValResult<PyObject> {
+    let py = py_type.py();
+    let dc = create_class(py_type)?;
+    let int = uuid.as_u128();
+    let safe = UUID_SAFE.get_or_try_init(py, || -> PyResult<PyObject> {
+        Ok(py
             .import_bound(intern!(py, "uuid"))?
             .getattr(intern!(py, "SafeUUID"))?
-            .get_item("safe")?;
-        force_setattr(py, &dc, intern!(py, UUID_INT), int)?;
-        force_setattr(py, &dc, intern!(py, UUID_IS_SAFE), safe)?;
-        Ok(dc.into())
-    }
+            .get_item("safe")?
+            .unbind())
+    })?;
+    force_setattr(py, &dc, intern!(py, UUID_INT), int)?;
+    force_setattr(py, &dc, intern!(py, UUID_IS_SAFE), safe)?;
+    Ok(dc.into())
 }
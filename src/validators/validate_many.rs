@@ -0,0 +1,130 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+
+use crate::errors::{catch_panics, ValError, ValResult};
+use crate::input::InputType;
+
+use super::json::{map_json_err, validate_json_bytes};
+use super::{emit_warnings, LaxReport, SchemaValidator, ValidateOptions, ValidationState, Validator};
+
+/// How each input of `validate_many` is validated, from the `mode` argument
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ManyMode {
+    #[default]
+    Python,
+    Json,
+}
+
+impl<'py> FromPyObject<'py> for ManyMode {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<ManyMode> {
+        match ob.extract::<&str>() {
+            Ok("python") => Ok(Self::Python),
+            Ok("json") => Ok(Self::Json),
+            _ => Err(PyValueError::new_err(
+                "Invalid mode parameter, should be `'python'` or `'json'`",
+            )),
+        }
+    }
+}
+
+/// What `validate_many` does with inputs which fail validation, from the `collect` argument
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CollectMode {
+    #[default]
+    Errors,
+    Raise,
+}
+
+impl<'py> FromPyObject<'py> for CollectMode {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<CollectMode> {
+        match ob.extract::<&str>() {
+            Ok("errors") => Ok(Self::Errors),
+            Ok("raise") => Ok(Self::Raise),
+            _ => Err(PyValueError::new_err(
+                "Invalid collect parameter, should be `'errors'` or `'raise'`",
+            )),
+        }
+    }
+}
+
+/// Validate each of `inputs` with one state, so the recursion guard and interned strings are reused, returning the
+/// outputs, `None` for inputs which failed, and the `ValidationError` of each input which failed keyed by index,
+/// with `CollectMode::Raise` the first error is raised instead, located by the index of its input
+pub(super) fn validate_many<'py>(
+    schema_validator: &SchemaValidator,
+    py: Python<'py>,
+    inputs: &Bound<'py, PyAny>,
+    mode: ManyMode,
+    collect: CollectMode,
+    options: ValidateOptions<'_, 'py>,
+) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyDict>)> {
+    schema_validator.audit_frozen(py)?;
+    let input_type = match mode {
+        ManyMode::Python => InputType::Python,
+        ManyMode::Json => InputType::Json,
+    };
+    let mut recursion_guard = schema_validator.recursion_guard(options.deadline_ms);
+    let (mut state, coercion_audit) = schema_validator.new_state(py, &mut recursion_guard, input_type, &options)?;
+
+    let validator = &schema_validator.validator;
+    let results = PyList::empty_bound(py);
+    let errors = PyDict::new_bound(py);
+    for (index, input) in inputs.iter()?.enumerate() {
+        let input = input?;
+        state.recursion_guard.reset_python_callbacks();
+        state.exactness = None;
+        state.fields_set_count = None;
+        let result = catch_panics(schema_validator.catch_panics, validator.get_name(), || {
+            state.traced(py, validator.get_name(), None, |state| match mode {
+                ManyMode::Python => validator.validate(py, &input, state),
+                ManyMode::Json => validate_json_input(py, validator, &input, state),
+            })
+        });
+        schema_validator.record_stats(&result);
+        if let Some(ref coercion_audit) = coercion_audit {
+            // the coercions of each input which succeeds are audited, like a `validate_python` call per input
+            if let (Ok(_), Some(lax_report)) = (&result, state.lax_report.replace(LaxReport::new(false, true))) {
+                lax_report.extend_audit(coercion_audit)?;
+            }
+        }
+        let warnings = state.warnings.take();
+        match result {
+            Ok(output) => {
+                if let Some(warnings) = warnings {
+                    emit_warnings(warnings.bind(py))?;
+                }
+                results.append(output)?;
+            }
+            Err(err @ ValError::LineErrors(_)) => match collect {
+                CollectMode::Errors => {
+                    errors.set_item(
+                        index,
+                        schema_validator
+                            .prepare_validation_err(py, err, input_type)
+                            .value_bound(py),
+                    )?;
+                    results.append(py.None())?;
+                }
+                CollectMode::Raise => {
+                    let err = err.with_outer_location(index);
+                    return Err(schema_validator.prepare_validation_err(py, err, input_type));
+                }
+            },
+            Err(err) => return Err(schema_validator.prepare_validation_err(py, err, input_type)),
+        }
+    }
+    Ok((results, errors))
+}
+
+fn validate_json_input<'py>(
+    py: Python<'py>,
+    validator: &super::CombinedValidator,
+    input: &Bound<'py, PyAny>,
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<PyObject> {
+    let json_bytes = validate_json_bytes(input)?.into_inner();
+    let json_data = json_bytes.as_slice();
+    let json_value = jiter::JsonValue::parse(json_data, true).map_err(|e| map_json_err(input, e, json_data))?;
+    validator.validate(py, &json_value, state)
+}
@@ -1,11 +1,21 @@
+use std::sync::Arc;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyDateTime, PyDict, PyInt, PyList, PySet, PyString, PyTuple};
 
-use jiter::StringCacheMode;
+use jiter::{JsonValue, StringCacheMode};
+use speedate::DateTime;
+use strum::EnumMessage;
 
+use crate::build_tools::py_schema_error_type;
+use crate::errors::{InputValue, LocItem, ValResult, ValidationTimeout};
+use crate::input::{pydatetime_as_datetime, Input, InputType};
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
 use crate::tools::new_py_string;
 
+use super::stats::ValidatorStats;
 use super::Extra;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -15,6 +25,237 @@ pub enum Exactness {
     Exact,
 }
 
+/// The `tracer` object passed to validation
+pub struct Tracer {
+    tracer: PyObject,
+}
+
+impl Tracer {
+    pub fn new(tracer: PyObject) -> Self {
+        Self { tracer }
+    }
+
+    pub fn clone_ref(&self, py: Python) -> Self {
+        Self {
+            tracer: self.tracer.clone_ref(py),
+        }
+    }
+}
+
+impl_py_gc_traverse!(Tracer { tracer });
+
+/// The source of the current time for constraints relative to now, e.g. `now_op`, either a `datetime` or a function
+/// returning one, from the `clock` config or passed to validation
+pub struct Clock {
+    clock: PyObject,
+}
+
+impl Clock {
+    pub fn new(clock: PyObject) -> Self {
+        Self { clock }
+    }
+
+    fn now(&self, py: Python, tz_offset: i32) -> PyResult<DateTime> {
+        let clock = self.clock.bind(py);
+        let now = if clock.is_callable() {
+            clock.call0()?
+        } else {
+            clock.clone()
+        };
+        let now = match now.downcast::<PyDateTime>() {
+            Ok(now) => pydatetime_as_datetime(now)?,
+            Err(_) => {
+                return Err(PyTypeError::new_err(
+                    "`clock` should be a datetime or a function returning one",
+                ))
+            }
+        };
+        if now.time.tz_offset.is_none() {
+            return Err(PyValueError::new_err("`clock` datetimes should be timezone aware"));
+        }
+        now.in_timezone(tz_offset).map_err(|e| {
+            PyValueError::new_err(format!(
+                "Invalid `clock` datetime: {}",
+                e.get_documentation().unwrap_or("unknown")
+            ))
+        })
+    }
+}
+
+/// The random number generator passed to default factories with `default_factory_takes_rng`, from the `rng` config
+/// or passed to validation, either an `int` seed for a new `random.Random` or a generator which is used as is
+#[derive(Default)]
+pub struct Rng {
+    source: Option<PyObject>,
+    // created on first use, then shared for the rest of validation
+    generator: Option<PyObject>,
+}
+
+impl Rng {
+    pub fn new(source: Option<PyObject>) -> Self {
+        Self {
+            source,
+            generator: None,
+        }
+    }
+
+    /// The generator, the same one each time it's called
+    pub fn generator<'py>(&mut self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(ref generator) = self.generator {
+            return Ok(generator.bind(py).clone());
+        }
+        let random_cls = py.import_bound(intern!(py, "random"))?.getattr(intern!(py, "Random"))?;
+        let generator = match self.source {
+            Some(ref source) if source.bind(py).is_instance_of::<PyInt>() => random_cls.call1((source,))?,
+            Some(ref source) => source.bind(py).clone(),
+            None => random_cls.call0()?,
+        };
+        self.generator = Some(generator.clone().unbind());
+        Ok(generator)
+    }
+}
+
+/// A lax conversion of the input, e.g. `str` to `int`
+#[derive(Debug, Clone)]
+struct Coercion {
+    loc: Vec<String>,
+    from_type: String,
+    to_type: &'static str,
+}
+
+/// Where lax validation was used, collected when `report_lax=True` is passed to validation
+/// or when validating within `pydantic_core.coercion_audit()`
+#[derive(Debug, Clone, Default)]
+pub struct LaxReport {
+    // the deepest field locations where validation was lax, for `report_lax=True`
+    locations: Option<Vec<Vec<String>>>,
+    // every lax conversion, for `coercion_audit()`
+    coercions: Option<Vec<Coercion>>,
+}
+
+/// The number of locations and coercions recorded at some point during validation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LaxMark {
+    locations: usize,
+    coercions: usize,
+}
+
+impl LaxReport {
+    pub fn new(report_locations: bool, audit_coercions: bool) -> Self {
+        Self {
+            locations: report_locations.then(Vec::new),
+            coercions: audit_coercions.then(Vec::new),
+        }
+    }
+
+    pub fn locations_to_py<'py>(&self, py: Python<'py>) -> Bound<'py, PyList> {
+        let locations = self.locations.as_deref().unwrap_or_default();
+        PyList::new_bound(py, locations.iter().map(|loc| PyTuple::new_bound(py, loc)))
+    }
+
+    /// Append `(loc, from_type, to_type)` to `audit` for each coercion
+    pub fn extend_audit(&self, audit: &Bound<'_, PyList>) -> PyResult<()> {
+        let py = audit.py();
+        for coercion in self.coercions.iter().flatten() {
+            let loc = PyTuple::new_bound(py, &coercion.loc);
+            audit.append((loc, &coercion.from_type, coercion.to_type))?;
+        }
+        Ok(())
+    }
+
+    fn mark(&self) -> LaxMark {
+        LaxMark {
+            locations: self.locations.as_ref().map_or(0, Vec::len),
+            coercions: self.coercions.as_ref().map_or(0, Vec::len),
+        }
+    }
+
+    fn truncate(&mut self, mark: LaxMark) {
+        if let Some(ref mut locations) = self.locations {
+            locations.truncate(mark.locations);
+        }
+        if let Some(ref mut coercions) = self.coercions {
+            coercions.truncate(mark.coercions);
+        }
+    }
+
+    fn split_off(&mut self, mark: LaxMark) -> Self {
+        Self {
+            locations: self
+                .locations
+                .as_mut()
+                .map(|l| l.split_off(mark.locations.min(l.len()))),
+            coercions: self
+                .coercions
+                .as_mut()
+                .map(|c| c.split_off(mark.coercions.min(c.len()))),
+        }
+    }
+
+    fn extend(&mut self, other: Self) {
+        if let (Some(locations), Some(other)) = (&mut self.locations, other.locations) {
+            locations.extend(other);
+        }
+        if let (Some(coercions), Some(other)) = (&mut self.coercions, other.coercions) {
+            coercions.extend(other);
+        }
+    }
+}
+
+/// The name of the input's type, JSON values are named by the Python type they'd be parsed as
+fn input_type_name<'py>(input: &(impl Input<'py> + ?Sized)) -> String {
+    if let Some(py_input) = input.as_python() {
+        return py_input
+            .get_type()
+            .qualname()
+            .map_or_else(|_| "<unknown type>".to_string(), |name| name.to_string());
+    }
+    let name = match input.as_error_value() {
+        InputValue::Python(_) => "<unknown type>",
+        InputValue::Json(JsonValue::Null) => "NoneType",
+        InputValue::Json(JsonValue::Bool(_)) => "bool",
+        InputValue::Json(JsonValue::Int(_) | JsonValue::BigInt(_)) => "int",
+        InputValue::Json(JsonValue::Float(_)) => "float",
+        InputValue::Json(JsonValue::Str(_)) => "str",
+        InputValue::Json(JsonValue::Array(_)) => "list",
+        InputValue::Json(JsonValue::Object(_)) => "dict",
+    };
+    name.to_string()
+}
+
+/// An item of the location of the value being validated, see `ValidationInfo.path`
+#[derive(Clone)]
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub enum PathSegment {
+    // the name of a field of a model, typed dict or dataclass
+    Field(Py<PyString>),
+    // the index of an item in a sequence or the key of a dict value
+    Loc(LocItem),
+}
+
+impl ToPyObject for PathSegment {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::Field(name) => name.clone_ref(py).into_any(),
+            Self::Loc(loc) => loc.to_object(py),
+        }
+    }
+}
+
+/// Emit the warnings from `ValidationInfo.warn()`, identical warnings are only emitted once, then clear them
+pub fn emit_warnings(warnings: &Bound<'_, PyList>) -> PyResult<()> {
+    let py = warnings.py();
+    let emitted = PySet::empty_bound(py)?;
+    for warning in warnings.iter() {
+        if !emitted.contains(&warning)? {
+            let (category, message): (Bound<'_, PyAny>, String) = warning.extract()?;
+            PyErr::warn_bound(py, &category, &message, 1)?;
+            emitted.add(warning)?;
+        }
+    }
+    warnings.del_slice(0, warnings.len())
+}
+
 pub struct ValidationState<'a, 'py> {
     pub recursion_guard: &'a mut RecursionState,
     pub exactness: Option<Exactness>,
@@ -23,6 +264,27 @@ pub struct ValidationState<'a, 'py> {
     // `model_fields_set` attached to a model. `model_fields_set` includes extra fields
     // when extra='allow', whereas this tally does not.
     pub fields_set_count: Option<usize>,
+    // called around the validation of each field when passed to validation, used for profiling
+    pub tracer: Option<Tracer>,
+    // where lax validation was used, only collected when `report_lax=True` is passed to validation
+    // or within `coercion_audit()`
+    pub lax_report: Option<LaxReport>,
+    // smart mode union decisions are appended here within `union_debug()`
+    pub union_debug: Option<Py<PyList>>,
+    // names of the fields currently being validated, only tracked with a `tracer`, `lax_report` or `union_debug`
+    pub field_path: Vec<String>,
+    // the location of the value being validated, for `ValidationInfo.path`
+    pub path: Vec<PathSegment>,
+    // `(category, message)` for each `ValidationInfo.warn()`, emitted once validation succeeds
+    pub warnings: Option<Py<PyList>>,
+    // counters of the `SchemaValidator` running validation
+    pub stats: Option<Arc<ValidatorStats>>,
+    // the first of each distinct string output by `str` validators, only with the `intern_strings` config
+    pub interned_strs: Option<Py<PyDict>>,
+    // where the current time comes from, the system clock if unset
+    pub clock: Option<Clock>,
+    // the generator for default factories which take one, a new unseeded `random.Random` if unset
+    pub rng: Rng,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -33,10 +295,30 @@ impl<'a, 'py> ValidationState<'a, 'py> {
             recursion_guard, // Don't care about exactness unless doing union validation
             exactness: None,
             fields_set_count: None,
+            tracer: None,
+            lax_report: None,
+            union_debug: None,
+            field_path: Vec::new(),
+            path: Vec::new(),
+            warnings: None,
+            stats: None,
+            interned_strs: None,
+            clock: None,
+            rng: Rng::default(),
             extra,
         }
     }
 
+    /// The current time from the `clock`, in the timezone with offset `tz_offset` seconds from UTC
+    pub fn now(&self, py: Python, tz_offset: i32) -> PyResult<DateTime> {
+        match self.clock {
+            Some(ref clock) => clock.now(py, tz_offset),
+            None => DateTime::now(tz_offset).map_err(|e| {
+                py_schema_error_type!("DateTime::now() error: {}", e.get_documentation().unwrap_or("unknown"))
+            }),
+        }
+    }
+
     /// Temporarily rebinds the extra field by calling `f` to modify extra.
     ///
     /// When `ValidationStateWithReboundExtra` drops, the extra field is restored to its original value.
@@ -53,6 +335,132 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         &self.extra
     }
 
+    /// Count a call of a function validator or default factory, see `max_python_callbacks`
+    pub fn count_python_callback(&self) -> ValResult<()> {
+        Ok(self.recursion_guard.count_python_callback()?)
+    }
+
+    /// Abandon validation if the `deadline_ms` passed to validation has been exceeded
+    pub fn check_deadline(&self) -> ValResult<()> {
+        match self.recursion_guard.deadline_exceeded() {
+            Some(deadline_ms) => Err(ValidationTimeout::new_err(deadline_ms).into()),
+            None => Ok(()),
+        }
+    }
+
+    /// Run `f`, calling `tracer.on_enter(validator_name, loc)` before and `tracer.on_exit(validator_name, loc)`
+    /// after if a tracer was passed to validation, `loc` being the path of fields including `field_name`.
+    ///
+    /// If lax validation is being reported, `loc` is recorded when `f` used lax validation and no
+    /// location within it has already been recorded.
+    pub fn traced<R>(
+        &mut self,
+        py: Python<'py>,
+        validator_name: &str,
+        field_name: Option<&str>,
+        f: impl FnOnce(&mut Self) -> ValResult<R>,
+    ) -> ValResult<R> {
+        if self.tracer.is_none() && self.lax_report.is_none() && self.union_debug.is_none() {
+            return f(self);
+        }
+        if let Some(field_name) = field_name {
+            self.field_path.push(field_name.to_string());
+        }
+        let tracer = self.tracer.as_ref().map(|tracer| {
+            let loc = PyTuple::new_bound(py, &self.field_path);
+            (tracer.tracer.clone_ref(py), loc)
+        });
+        if let Some((ref tracer, ref loc)) = tracer {
+            tracer.call_method1(py, intern!(py, "on_enter"), (validator_name, loc))?;
+        }
+
+        let outer_exactness = self.exactness;
+        let lax_mark = self.lax_mark();
+        let report_locations = matches!(self.lax_report, Some(LaxReport { locations: Some(_), .. }));
+        if report_locations {
+            self.exactness = Some(Exactness::Exact);
+        }
+        let result = f(self);
+        if report_locations {
+            if let Some(LaxReport {
+                locations: Some(ref mut locations),
+                ..
+            }) = self.lax_report
+            {
+                if self.exactness == Some(Exactness::Lax) && locations.len() == lax_mark.locations {
+                    locations.push(self.field_path.clone());
+                }
+            }
+            self.exactness = outer_exactness.map(|outer| self.exactness.map_or(outer, |inner| outer.min(inner)));
+        }
+
+        if field_name.is_some() {
+            self.field_path.pop();
+        }
+        if let Some((ref tracer, ref loc)) = tracer {
+            tracer.call_method1(py, intern!(py, "on_exit"), (validator_name, loc))?;
+        }
+        result
+    }
+
+    /// Run `f` with `segment` appended to the location of the value being validated
+    pub fn with_path<R>(&mut self, segment: PathSegment, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.path.push(segment);
+        let result = f(self);
+        self.path.pop();
+        result
+    }
+
+    /// The location of the value being validated as a tuple of field names, indexes and keys
+    pub fn path_to_py(&self, py: Python<'py>) -> Bound<'py, PyTuple> {
+        PyTuple::new_bound(py, &self.path)
+    }
+
+    /// The list `ValidationInfo.warn()` appends to, created when first needed
+    pub fn warnings(&mut self, py: Python) -> &Py<PyList> {
+        self.warnings.get_or_insert_with(|| PyList::empty_bound(py).unbind())
+    }
+
+    /// Record a lax conversion of `input` to `to_type` if validating within `coercion_audit()`
+    pub fn record_coercion(&mut self, input: &(impl Input<'py> + ?Sized), to_type: &'static str) {
+        if let Some(LaxReport {
+            coercions: Some(ref mut coercions),
+            ..
+        }) = self.lax_report
+        {
+            coercions.push(Coercion {
+                loc: self.field_path.clone(),
+                from_type: input_type_name(input),
+                to_type,
+            });
+        }
+    }
+
+    /// What's been recorded in `lax_report` so far, used with `truncate_lax` and `take_lax`
+    /// to discard what was recorded while validating union members which weren't used.
+    pub fn lax_mark(&self) -> LaxMark {
+        self.lax_report.as_ref().map(LaxReport::mark).unwrap_or_default()
+    }
+
+    pub fn truncate_lax(&mut self, mark: LaxMark) {
+        if let Some(ref mut lax_report) = self.lax_report {
+            lax_report.truncate(mark);
+        }
+    }
+
+    pub fn take_lax(&mut self, mark: LaxMark) -> LaxReport {
+        match self.lax_report {
+            Some(ref mut lax_report) => lax_report.split_off(mark),
+            None => LaxReport::default(),
+        }
+    }
+
+    pub fn extend_lax(&mut self, taken: LaxReport) {
+        if let Some(ref mut lax_report) = self.lax_report {
+            lax_report.extend(taken);
+        }
+    }
+
     pub fn strict_or(&self, default: bool) -> bool {
         self.extra.strict.unwrap_or(default)
     }
@@ -85,6 +493,35 @@ impl<'a, 'py> ValidationState<'a, 'py> {
     pub fn maybe_cached_str(&self, py: Python<'py>, s: &str) -> Bound<'py, PyString> {
         new_py_string(py, s, self.extra.cache_str)
     }
+
+    /// If `intern_strings` is set, return the string equal to `py_string` output earlier in this validation call,
+    /// so repeated values share one object. `str` subclasses are returned unchanged.
+    pub fn maybe_intern_str(&self, py_string: Bound<'py, PyString>) -> PyResult<Bound<'py, PyString>> {
+        let Some(ref interned_strs) = self.interned_strs else {
+            return Ok(py_string);
+        };
+        if !py_string.is_exact_instance_of::<PyString>() {
+            return Ok(py_string);
+        }
+        let interned_strs = interned_strs.bind(py_string.py());
+        match interned_strs.get_item(&py_string)? {
+            // only exact `str`s are inserted
+            Some(interned) => Ok(interned.downcast_into_exact::<PyString>()?),
+            None => {
+                interned_strs.set_item(&py_string, &py_string)?;
+                Ok(py_string)
+            }
+        }
+    }
+
+    /// If `val_json_cache_utf8` is set, populate the UTF-8 buffer CPython keeps on each `str`
+    /// while validating JSON, so serializing the value later doesn't have to re-encode it.
+    pub fn maybe_cache_utf8(&self, py_string: &Bound<'py, PyString>) {
+        if self.extra.cache_utf8 && self.extra.input_type == InputType::Json {
+            // ignore errors, the string will be re-encoded (and the error raised) on serialization
+            let _ = py_string.to_str();
+        }
+    }
 }
 
 impl ContainsRecursionState for ValidationState<'_, '_> {
@@ -6,7 +6,7 @@ use pyo3::types::PyString;
 use pyo3::PyTraverseError;
 use pyo3::PyVisit;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Rng, ValidationState, Validator};
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
 use crate::errors::{LocItem, ValError, ValResult};
@@ -26,6 +26,8 @@ pub enum DefaultType {
     None,
     Default(PyObject),
     DefaultFactory(PyObject),
+    // a default factory which is called with the random number generator for validation, see `Rng`
+    DefaultFactoryTakesRng(PyObject),
 }
 
 impl DefaultType {
@@ -37,15 +39,27 @@ impl DefaultType {
         ) {
             (Some(_), Some(_)) => py_schema_err!("'default' and 'default_factory' cannot be used together"),
             (Some(default), None) => Ok(Self::Default(default)),
-            (None, Some(default_factory)) => Ok(Self::DefaultFactory(default_factory)),
+            (None, Some(default_factory)) => {
+                if schema
+                    .get_as(intern!(py, "default_factory_takes_rng"))?
+                    .unwrap_or(false)
+                {
+                    Ok(Self::DefaultFactoryTakesRng(default_factory))
+                } else {
+                    Ok(Self::DefaultFactory(default_factory))
+                }
+            }
             (None, None) => Ok(Self::None),
         }
     }
 
-    pub fn default_value(&self, py: Python) -> PyResult<Option<PyObject>> {
+    pub fn default_value(&self, py: Python, rng: &mut Rng) -> PyResult<Option<PyObject>> {
         match self {
             Self::Default(ref default) => Ok(Some(default.clone_ref(py))),
             Self::DefaultFactory(ref default_factory) => Ok(Some(default_factory.call0(py)?)),
+            Self::DefaultFactoryTakesRng(ref default_factory) => {
+                Ok(Some(default_factory.call1(py, (rng.generator(py)?,))?))
+            }
             Self::None => Ok(None),
         }
     }
@@ -53,7 +67,7 @@ impl DefaultType {
 
 impl PyGcTraverse for DefaultType {
     fn py_gc_traverse(&self, visit: &PyVisit<'_>) -> Result<(), PyTraverseError> {
-        if let Self::Default(obj) | Self::DefaultFactory(obj) = self {
+        if let Self::Default(obj) | Self::DefaultFactory(obj) | Self::DefaultFactoryTakesRng(obj) = self {
             visit.call(obj)?;
         }
         Ok(())
@@ -163,7 +177,13 @@ impl Validator for WithDefaultValidator {
         outer_loc: Option<impl Into<LocItem>>,
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<Option<PyObject>> {
-        match self.default.default_value(py)? {
+        if matches!(
+            self.default,
+            DefaultType::DefaultFactory(_) | DefaultType::DefaultFactoryTakesRng(_)
+        ) {
+            state.count_python_callback()?;
+        }
+        match self.default.default_value(py, &mut state.rng)? {
             Some(stored_dft) => {
                 let dft: Py<PyAny> = if self.copy_default {
                     let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
@@ -203,4 +223,9 @@ impl WithDefaultValidator {
     pub fn omit_on_error(&self) -> bool {
         matches!(self.on_error, OnError::Omit)
     }
+
+    /// The validator of values which aren't missing
+    pub(super) fn inner_validator(&self) -> &CombinedValidator {
+        &self.validator
+    }
 }
@@ -1,5 +1,6 @@
 from __future__ import annotations as _annotations
 
+import ctypes
 import functools
 import importlib.util
 import json
@@ -161,3 +162,39 @@ def infinite_generator():
     while True:
         yield i
         i += 1
+
+
+_native_type_fn = ctypes.CFUNCTYPE(ctypes.py_object, ctypes.c_void_p, ctypes.py_object, ctypes.c_int)
+
+
+class _NativeTypeVTable(ctypes.Structure):
+    _fields_ = [
+        ('abi_version', ctypes.c_uint32),
+        ('name', ctypes.c_char_p),
+        ('data', ctypes.c_void_p),
+        ('validate', _native_type_fn),
+        ('serialize', _native_type_fn),
+    ]
+
+
+_capsule_new = ctypes.pythonapi.PyCapsule_New
+_capsule_new.restype = ctypes.py_object
+_capsule_new.argtypes = [ctypes.c_void_p, ctypes.c_char_p, ctypes.c_void_p]
+
+
+NATIVE_CAPSULE_NAME = b'pydantic_core.native_type'
+
+
+class NativeType:
+    """
+    A native type for `core_schema.native_schema` built with ctypes, as an extension module would in C or Rust,
+    the functions are called with `(value, flag)` and must not raise, this must outlive the capsule.
+    """
+
+    def __init__(self, validate, serialize=None, *, name=b'test', abi_version=1, capsule_name=NATIVE_CAPSULE_NAME):
+        validate_fn = _native_type_fn(lambda _, value, flag: validate(value, flag))
+        self.vtable = _NativeTypeVTable(abi_version, name, None, validate_fn)
+        if serialize is not None:
+            self.vtable.serialize = _native_type_fn(lambda _, value, flag: serialize(value, flag))
+        self.capsule_name = capsule_name
+        self.capsule = _capsule_new(ctypes.addressof(self.vtable), capsule_name, None)
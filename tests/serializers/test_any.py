@@ -3,6 +3,8 @@ import json
 import platform
 import re
 import sys
+import warnings
+import warnings
 from collections import namedtuple
 from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal
@@ -10,12 +12,21 @@ from enum import Enum
 from math import inf, isinf, isnan, nan
 from pathlib import Path
 from typing import ClassVar
+from uuid import UUID
 
 import pytest
 from dirty_equals import HasRepr, IsList
 
 import pydantic_core
-from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, core_schema, to_json
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    to_json,
+    to_jsonable_python,
+)
 
 from ..conftest import plain_repr
 from .test_dataclasses import IsStrictDict, on_pypy
@@ -33,7 +44,9 @@ def any_serializer():
 
 
 def test_repr(any_serializer):
-    assert plain_repr(any_serializer) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert (
+        plain_repr(any_serializer) == 'SchemaSerializer(serializer=Any(AnySerializer{type_markers:false}),definitions=[])'
+    )
 
 
 @dataclasses.dataclass(frozen=True)
@@ -132,7 +145,7 @@ def test_any_json(any_serializer, value, expected_json):
 def test_other_type():
     """Types with no serializer, fall back to any serializer"""
     v = SchemaSerializer(core_schema.is_instance_schema(int))
-    assert plain_repr(v) == 'SchemaSerializer(serializer=Any(AnySerializer),definitions=[])'
+    assert plain_repr(v) == 'SchemaSerializer(serializer=Any(AnySerializer{type_markers:false}),definitions=[])'
     assert v.to_json('foobar') == b'"foobar"'
 
 
@@ -211,6 +224,46 @@ def test_recursion(any_serializer):
         any_serializer.to_json(v)
 
 
+def test_recursion_cycles(any_serializer):
+    v = {'a': [1, {'b/c': 2}]}
+    v['a'][1]['d~'] = v['a']
+    v['e'] = (v,)
+    assert any_serializer.to_python(v, mode='json', cycles='omit') == {'a': [1, {'b/c': 2, 'd~': None}], 'e': [None]}
+    assert any_serializer.to_json(v, cycles='omit') == b'{"a":[1,{"b/c":2,"d~":null}],"e":[null]}'
+
+    expected = {'a': [1, {'b/c': 2, 'd~': {'$ref': '#/a'}}], 'e': [{'$ref': '#'}]}
+    assert any_serializer.to_python(v, mode='json', cycles='ref') == expected
+    assert any_serializer.to_python(v, cycles='ref') == {
+        'a': [1, {'b/c': 2, 'd~': {'$ref': '#/a'}}],
+        'e': ({'$ref': '#'},),
+    }
+    assert json.loads(any_serializer.to_json(v, cycles='ref')) == expected
+    assert to_jsonable_python(v, cycles='ref') == expected
+    assert json.loads(to_json(v, cycles='ref')) == expected
+
+    # keys are escaped as JSON pointer reference tokens
+    v = {'b/c': {'d~': []}}
+    v['b/c']['d~'].append(v['b/c'])
+    assert any_serializer.to_json(v, cycles='ref') == b'{"b/c":{"d~":[{"$ref":"#/b~1c"}]}}'
+    v['b/c']['d~'].append(v['b/c']['d~'])
+    assert any_serializer.to_python(v, mode='json', cycles='ref') == {
+        'b/c': {'d~': [{'$ref': '#/b~1c'}, {'$ref': '#/b~1c/d~0'}]}
+    }
+
+
+def test_dedupe_shared():
+    point = {'x': 1, 'y': 2}
+    line = [point, point]
+    assert json.loads(to_json({'a': line, 'b': line, 'c': point, 'd': 'text'}, dedupe_shared=True)) == {
+        '$defs': {'0': {'x': 1, 'y': 2}, '1': [{'$ref': '#/$defs/0'}, {'$ref': '#/$defs/0'}]},
+        'value': {'a': {'$ref': '#/$defs/1'}, 'b': {'$ref': '#/$defs/1'}, 'c': {'$ref': '#/$defs/0'}, 'd': 'text'},
+    }
+    assert json.loads(SchemaSerializer(core_schema.any_schema()).to_json(line, dedupe_shared=True)) == {
+        '$defs': {'0': {'x': 1, 'y': 2}},
+        'value': [{'$ref': '#/$defs/0'}, {'$ref': '#/$defs/0'}],
+    }
+
+
 @pytest.mark.parametrize('seq_f', [as_list, as_tuple])
 def test_include_list_tuple(any_serializer, seq_f):
     assert any_serializer.to_python(seq_f(0, 1, 2, 3)) == seq_f(0, 1, 2, 3)
@@ -606,6 +659,27 @@ def test_numpy_float(any_serializer):
         any_serializer.to_json(numpy.float16(1.0))
 
 
+@pytest.mark.skipif(numpy is None, reason='numpy is not installed')
+def test_numpy_support():
+    s = SchemaSerializer(core_schema.any_schema(), core_schema.CoreConfig(numpy_support=True))
+    value = {'int': numpy.int64(1), 'float': numpy.float16(1.5), 'bool': numpy.bool_(True), 'array': numpy.eye(2)}
+    assert s.to_python(value)['array'] is value['array']
+    assert s.to_python(value, mode='json') == {'int': 1, 'float': 1.5, 'bool': True, 'array': [[1, 0], [0, 1]]}
+    assert s.to_json(value) == b'{"int":1,"float":1.5,"bool":true,"array":[[1.0,0.0],[0.0,1.0]]}'
+    assert s.to_json({numpy.int32(1): numpy.array(['a', 'b'])}) == b'{"1":["a","b"]}'
+
+    for schema, value, expected in [
+        (core_schema.int_schema(), numpy.uint8(1), b'1'),
+        (core_schema.float_schema(), numpy.float32(1.5), b'1.5'),
+        (core_schema.bool_schema(), numpy.bool_(False), b'false'),
+    ]:
+        s = SchemaSerializer(schema, core_schema.CoreConfig(numpy_support=True))
+        # no warnings
+        with warnings.catch_warnings():
+            warnings.simplefilter('error')
+            assert s.to_json(value) == expected
+
+
 def test_ser_json_inf_nan_with_any() -> None:
     s = SchemaSerializer(core_schema.any_schema(), core_schema.CoreConfig(ser_json_inf_nan='constants'))
     assert isinf(s.to_python(inf))
@@ -652,3 +726,95 @@ def test_ser_json_inf_nan_with_list_of_any() -> None:
     assert isnan(s.to_python([nan])[0])
     assert s.to_python([nan], mode='json')[0] is None
     assert s.to_json([nan]) == b'[null]'
+
+
+@pytest.mark.parametrize(
+    'value,json_value',
+    [
+        (datetime(2024, 1, 1, 12, 30), {'__type__': 'datetime', 'value': '2024-01-01T12:30:00'}),
+        (date(2024, 1, 1), {'__type__': 'date', 'value': '2024-01-01'}),
+        (time(12, 30, 1), {'__type__': 'time', 'value': '12:30:01'}),
+        (timedelta(hours=1), {'__type__': 'timedelta', 'value': 'PT1H'}),
+        (
+            UUID('12345678-1234-5678-1234-567812345678'),
+            {'__type__': 'uuid', 'value': '12345678-1234-5678-1234-567812345678'},
+        ),
+        (Decimal('1.10'), {'__type__': 'decimal', 'value': '1.10'}),
+        (b'\xff\x00', {'__type__': 'bytes', 'value': '_wA='}),
+        (bytearray(b'abc'), {'__type__': 'bytearray', 'value': 'YWJj'}),
+        ((1, 'a'), {'__type__': 'tuple', 'value': [1, 'a']}),
+        ({1}, {'__type__': 'set', 'value': [1]}),
+        (frozenset({1}), {'__type__': 'frozenset', 'value': [1]}),
+        ({1: 'a'}, {'__type__': 'dict', 'value': [[1, 'a']]}),
+        # dicts which look like markers are marked so they aren't mistaken for markers
+        ({'__type__': 'date', 'value': 'x'}, {'__type__': 'dict', 'value': [['__type__', 'date'], ['value', 'x']]}),
+        ([1, 'a', None, 1.5, True], [1, 'a', None, 1.5, True]),
+        ({'a': [(1,)]}, {'a': [{'__type__': 'tuple', 'value': [1]}]}),
+    ],
+)
+def test_type_markers_round_trip(value, json_value):
+    schema = core_schema.any_schema(type_markers=True)
+    s = SchemaSerializer(schema)
+    v = SchemaValidator(schema)
+
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == json_value
+    assert json.loads(s.to_json(value)) == json_value
+
+    assert v.validate_python(json_value) == value
+    restored = v.validate_json(s.to_json(value))
+    assert restored == value
+    assert type(restored) is type(value)
+
+
+def test_type_markers_nested():
+    schema = core_schema.typed_dict_schema(
+        {'payload': core_schema.typed_dict_field(core_schema.any_schema(type_markers=True))}
+    )
+    value = {
+        'payload': {'when': [datetime(2024, 1, 1)], 'ids': frozenset({(1, b'x')}), 'by_day': {date(2024, 1, 1): 1}}
+    }
+    json_data = SchemaSerializer(schema).to_json(value)
+    assert SchemaValidator(schema).validate_json(json_data) == value
+
+
+def test_type_markers_strict():
+    v = SchemaValidator(core_schema.any_schema(type_markers=True))
+    assert v.validate_json('{"__type__": "date", "value": "2024-01-01"}', strict=True) == date(2024, 1, 1)
+
+
+def test_type_markers_unknown_type():
+    v = SchemaValidator(core_schema.any_schema(type_markers=True))
+    assert v.validate_python({'__type__': 'foobar', 'value': 1}) == {'__type__': 'foobar', 'value': 1}
+    assert v.validate_python({'__type__': 'date', 'value': 1, 'x': 2}) == {'__type__': 'date', 'value': 1, 'x': 2}
+
+
+def test_type_markers_invalid():
+    v = SchemaValidator(core_schema.any_schema(type_markers=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": [{"__type__": "date", "value": "foobar"}]}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'date_from_datetime_parsing',
+            'loc': ('a', 0, 'value'),
+            'msg': 'Input should be a valid date or datetime, input is too short',
+            'input': 'foobar',
+            'ctx': {'error': 'input is too short'},
+        }
+    ]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"__type__": "tuple", "value": 1}')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'list_type', 'loc': ('value',), 'msg': 'Input should be a valid array', 'input': 1}
+    ]
+
+    with pytest.raises(ValidationError, match='bytes must be base64 encoded in type markers'):
+        v.validate_json('{"__type__": "bytes", "value": "$$"}')
+
+
+def test_type_markers_not_enabled():
+    schema = core_schema.any_schema()
+    assert SchemaSerializer(schema).to_json((1, date(2024, 1, 1))) == b'[1,"2024-01-01"]'
+    marker = {'__type__': 'date', 'value': '2024-01-01'}
+    assert SchemaValidator(schema).validate_python(marker) == marker
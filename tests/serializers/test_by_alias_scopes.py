@@ -0,0 +1,157 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+def user_schema(**kwargs) -> core_schema.ModelSchema:
+    return core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {
+                'first_name': core_schema.model_field(core_schema.str_schema(), serialization_alias='firstName'),
+                'last_name': core_schema.model_field(core_schema.str_schema(), serialization_alias='lastName'),
+            }
+        ),
+        **kwargs,
+    )
+
+
+@pytest.fixture(scope='module', name='serializer')
+def serializer_fixture():
+    meta_schema = core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {'created_by': core_schema.model_field(core_schema.str_schema(), serialization_alias='createdBy')}
+        ),
+    )
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'user': core_schema.model_field(user_schema()),
+                    'friends': core_schema.model_field(
+                        core_schema.list_schema(user_schema()), serialization_alias='Friends'
+                    ),
+                    'internal_meta': core_schema.model_field(meta_schema, serialization_alias='internalMeta'),
+                }
+            ),
+        )
+    )
+
+
+@pytest.fixture(name='value')
+def value_fixture():
+    return BasicModel(
+        user=BasicModel(first_name='Ada', last_name='Lovelace'),
+        friends=[BasicModel(first_name='Charles', last_name='Babbage')],
+        internal_meta=BasicModel(created_by='admin'),
+    )
+
+
+def test_scopes(serializer: SchemaSerializer, value: BasicModel):
+    expected = {
+        'user': {'firstName': 'Ada', 'lastName': 'Lovelace'},
+        'Friends': [{'firstName': 'Charles', 'lastName': 'Babbage'}],
+        'internal_meta': {'created_by': 'admin'},
+    }
+    assert serializer.to_python(value, by_alias={'internal_meta': False}) == expected
+    assert json.loads(serializer.to_json(value, by_alias={'internal_meta': False})) == expected
+
+
+def test_root_scope(serializer: SchemaSerializer, value: BasicModel):
+    assert serializer.to_python(value, by_alias={(): False, 'user': True}) == {
+        'user': {'firstName': 'Ada', 'lastName': 'Lovelace'},
+        'friends': [{'first_name': 'Charles', 'last_name': 'Babbage'}],
+        'internal_meta': {'created_by': 'admin'},
+    }
+    assert serializer.to_python(value, by_alias={}) == serializer.to_python(value)
+
+
+def test_nested_scopes(serializer: SchemaSerializer, value: BasicModel):
+    by_alias = {(): False, ('user',): True, ('user', 'last_name'): False, ('friends', 'first_name'): True}
+    expected = {
+        'user': {'firstName': 'Ada', 'last_name': 'Lovelace'},
+        'friends': [{'firstName': 'Charles', 'last_name': 'Babbage'}],
+        'internal_meta': {'created_by': 'admin'},
+    }
+    assert serializer.to_python(value, by_alias=by_alias) == expected
+    assert json.loads(serializer.to_json(value, by_alias=by_alias)) == expected
+
+
+def test_function_serializer():
+    def wrap(value, handler, info):
+        return {'by_alias': info.by_alias, **handler(value)}
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'user': core_schema.model_field(
+                        user_schema(serialization=core_schema.wrap_serializer_function_ser_schema(wrap, info_arg=True)),
+                        serialization_alias='User',
+                    )
+                }
+            ),
+        )
+    )
+    value = BasicModel(user=BasicModel(first_name='Ada', last_name='Lovelace'))
+    assert s.to_python(value, by_alias={'user': False, ('user', 'last_name'): True}) == {
+        'user': {'by_alias': False, 'first_name': 'Ada', 'lastName': 'Lovelace'}
+    }
+
+
+def test_computed_fields():
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {'user': core_schema.model_field(user_schema())},
+                computed_fields=[
+                    core_schema.computed_field('full_name', core_schema.str_schema(), alias='fullName'),
+                    core_schema.computed_field('best_friend', user_schema(), alias='bestFriend'),
+                ],
+            ),
+        )
+    )
+    value = BasicModel(
+        user=BasicModel(first_name='Ada', last_name='Lovelace'),
+        full_name='Ada Lovelace',
+        best_friend=BasicModel(first_name='Charles', last_name='Babbage'),
+    )
+    by_alias = {'full_name': False, ('best_friend', 'first_name'): False}
+    expected = {
+        'user': {'firstName': 'Ada', 'lastName': 'Lovelace'},
+        'full_name': 'Ada Lovelace',
+        'bestFriend': {'first_name': 'Charles', 'lastName': 'Babbage'},
+    }
+    assert s.to_python(value, by_alias=by_alias) == expected
+    assert json.loads(s.to_json(value, by_alias=by_alias)) == expected
+
+
+def test_selection_ignores_scopes(serializer: SchemaSerializer, value: BasicModel):
+    assert serializer.to_python(value, by_alias={'user': True}, selection={'user': {'first_name': True}}) == {
+        'user': {'first_name': 'Ada'}
+    }
+
+
+@pytest.mark.parametrize(
+    'by_alias,message',
+    [
+        ({1: True}, '`by_alias` keys must be field names or tuples of field names'),
+        ({('user', 1): True}, '`by_alias` keys must be field names or tuples of field names'),
+        ({'user': 1}, '`by_alias` values must be bools'),
+    ],
+)
+def test_invalid(serializer: SchemaSerializer, value: BasicModel, by_alias, message):
+    with pytest.raises(TypeError, match=message):
+        serializer.to_python(value, by_alias=by_alias)
@@ -0,0 +1,55 @@
+from collections import Counter, OrderedDict, defaultdict, deque
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+def test_deque():
+    s = SchemaSerializer(core_schema.deque_schema(core_schema.int_schema(), maxlen=3))
+    value = deque([1, 2, 3], maxlen=3)
+    output = s.to_python(value)
+    assert type(output) is deque
+    assert output == value
+    assert output.maxlen == 3
+    assert s.to_python(value, mode='json') == [1, 2, 3]
+    assert s.to_json(value) == b'[1,2,3]'
+    # lists are serialized like the list schema
+    assert s.to_python([1, 2]) == [1, 2]
+
+
+def test_deque_include_exclude():
+    s = SchemaSerializer(core_schema.deque_schema(core_schema.int_schema()))
+    assert s.to_python(deque([1, 2, 3]), exclude={1}) == deque([1, 3])
+    assert s.to_json(deque([1, 2, 3]), include={0, 2}) == b'[1,3]'
+
+
+def test_deque_unexpected():
+    s = SchemaSerializer(core_schema.deque_schema(core_schema.int_schema()))
+    with pytest.warns(UserWarning, match='Expected `list\\[int\\]` but got `str` - serialized value may not be'):
+        assert s.to_python('abc') == 'abc'
+
+
+@pytest.mark.parametrize(
+    'schema,value',
+    [
+        (core_schema.counter_schema(core_schema.str_schema(), core_schema.int_schema()), Counter(a=2, b=1)),
+        (core_schema.ordered_dict_schema(core_schema.str_schema(), core_schema.int_schema()), OrderedDict(b=1, a=2)),
+        (core_schema.defaultdict_schema(int, values_schema=core_schema.int_schema()), defaultdict(int, b=1)),
+    ],
+)
+def test_mappings(schema, value):
+    s = SchemaSerializer(schema)
+    output = s.to_python(value)
+    assert type(output) is type(value)
+    assert list(output.items()) == list(value.items())
+    assert s.to_python(value, mode='json') == dict(value)
+    assert type(s.to_python(value, mode='json')) is dict
+    assert s.to_json(value) == '{{{}}}'.format(','.join(f'"{k}":{v}' for k, v in value.items())).encode()
+
+
+def test_defaultdict_factory():
+    s = SchemaSerializer(core_schema.defaultdict_schema(list, values_schema=core_schema.list_schema()))
+    output = s.to_python(defaultdict(list, a=[1]))
+    assert output.default_factory is list
+    assert output['b'] == []
@@ -192,3 +192,40 @@ def test_extra_custom_serializer():
     m = v.validate_python({'extra': 'extra'})
 
     assert s.to_python(m) == {'extra': 'extra bam!'}
+
+
+@pytest.mark.parametrize('extras_destination', ['merge', '__pydantic_extra__', 'extras'])
+def test_extras_destination(extras_destination):
+    @dataclasses.dataclass
+    class MyDataclass:
+        a: int
+
+    schema = core_schema.dataclass_schema(
+        MyDataclass,
+        core_schema.dataclass_args_schema(
+            'MyDataclass', [core_schema.dataclass_field('a', core_schema.int_schema())], extra_behavior='allow'
+        ),
+        ['a'],
+        config={'extras_destination': extras_destination},
+    )
+    dc = SchemaValidator(schema).validate_python({'a': 1, 'b': 2})
+    s = SchemaSerializer(schema)
+    assert s.to_python(dc) == {'a': 1, 'b': 2}
+    assert s.to_json(dc) == b'{"a":1,"b":2}'
+
+
+def test_extras_not_serialized_by_default():
+    @dataclasses.dataclass
+    class MyDataclass:
+        a: int
+
+    schema = core_schema.dataclass_schema(
+        MyDataclass,
+        core_schema.dataclass_args_schema(
+            'MyDataclass', [core_schema.dataclass_field('a', core_schema.int_schema())], extra_behavior='allow'
+        ),
+        ['a'],
+    )
+    dc = SchemaValidator(schema).validate_python({'a': 1, 'b': 2})
+    assert dc.__dict__ == {'a': 1, 'b': 2}
+    assert SchemaSerializer(schema).to_python(dc) == {'a': 1}
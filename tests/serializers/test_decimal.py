@@ -2,7 +2,7 @@ from decimal import Decimal
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_decimal():
@@ -55,3 +55,65 @@ def test_any_decimal_key():
 
     assert v.to_python(input_value, mode='json') == {'123.456': 1}
     assert v.to_json(input_value) == b'{"123.456":1}'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (Decimal('1.10'), b'1.10'),
+        (Decimal('123456789123456789123456789.123456789123456789'), b'123456789123456789123456789.123456789123456789'),
+        (Decimal('1E+3'), b'1E+3'),
+        (Decimal('-0.001'), b'-0.001'),
+        (Decimal('NaN'), b'null'),
+        (Decimal('-Infinity'), b'null'),
+    ],
+)
+def test_decimal_number(value, expected):
+    v = SchemaSerializer(core_schema.decimal_schema(ser_json_decimal='number'))
+    assert v.to_json(value) == expected
+    assert v.to_python(value) is value
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (Decimal('1.10'), b'1.1'),
+        (Decimal('123456789123456789123456789.123456789'), b'1.2345678912345679e26'),
+        (Decimal('Infinity'), b'"Infinity"'),
+    ],
+)
+def test_decimal_float(value, expected):
+    v = SchemaSerializer(core_schema.decimal_schema(ser_json_decimal='float'), config={'ser_json_inf_nan': 'strings'})
+    assert v.to_json(value) == expected
+
+
+@pytest.mark.parametrize('mode', ['number', 'float'])
+def test_decimal_to_python_json(mode):
+    v = SchemaSerializer(core_schema.decimal_schema(ser_json_decimal=mode))
+    assert v.to_python(Decimal('1.5'), mode='json') == 1.5
+    v = SchemaSerializer(core_schema.dict_schema(core_schema.decimal_schema(ser_json_decimal=mode)))
+    assert v.to_json({Decimal('1.5'): 1}) == b'{"1.5":1}'
+
+
+def test_decimal_config():
+    v = SchemaSerializer(core_schema.list_schema(core_schema.decimal_schema()), config={'ser_json_decimal': 'number'})
+    assert v.to_json([Decimal('1.5'), Decimal('2')]) == b'[1.5,2]'
+
+    v = SchemaSerializer(
+        core_schema.list_schema(core_schema.decimal_schema(ser_json_decimal='str')),
+        config={'ser_json_decimal': 'number'},
+    )
+    assert v.to_json([Decimal('1.5')]) == b'["1.5"]'
+
+
+def test_decimal_inf_nan_schema_override():
+    v = SchemaSerializer(
+        core_schema.decimal_schema(ser_json_decimal='float', ser_json_inf_nan='constants'),
+        config={'ser_json_inf_nan': 'strings'},
+    )
+    assert v.to_json(Decimal('-Infinity')) == b'-Infinity'
+
+
+def test_decimal_invalid_mode():
+    with pytest.raises(SchemaError, match='Invalid DecimalMode serialization mode: `int`'):
+        SchemaSerializer(core_schema.decimal_schema(), config={'ser_json_decimal': 'int'})
@@ -1,3 +1,5 @@
+import json
+
 import pytest
 
 from pydantic_core import SchemaSerializer, core_schema
@@ -57,6 +59,68 @@ def test_cyclic_recursion():
         s.to_json(v)
 
 
+def test_cyclic_recursion_cycles():
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                        'sub_branches': core_schema.typed_dict_field(
+                            core_schema.list_schema(core_schema.definition_reference_schema('Branch'))
+                        ),
+                        'parent': core_schema.typed_dict_field(
+                            core_schema.nullable_schema(core_schema.definition_reference_schema('Branch')),
+                            serialization_alias='up',
+                        ),
+                    },
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    root = {'name': 'root', 'sub_branches': [], 'parent': None}
+    branch = {'name': 'branch', 'sub_branches': [], 'parent': root}
+    leaf = {'name': 'leaf', 'sub_branches': [], 'parent': branch}
+    branch['sub_branches'].append(leaf)
+    root['sub_branches'].append(branch)
+
+    omitted = {
+        'name': 'root',
+        'sub_branches': [
+            {'name': 'branch', 'sub_branches': [{'name': 'leaf', 'sub_branches': [], 'up': None}], 'up': None}
+        ],
+        'up': None,
+    }
+    assert s.to_python(root, by_alias=True, cycles='omit') == omitted
+    assert s.to_python(root, by_alias=True, mode='json', cycles='omit') == omitted
+
+    refs = {
+        'name': 'root',
+        'sub_branches': [
+            {
+                'name': 'branch',
+                'sub_branches': [{'name': 'leaf', 'sub_branches': [], 'up': {'$ref': '#/sub_branches/0'}}],
+                'up': {'$ref': '#'},
+            }
+        ],
+        'up': None,
+    }
+    assert s.to_python(root, by_alias=True, cycles='ref') == refs
+    assert s.to_python(root, by_alias=True, mode='json', cycles='ref') == refs
+    assert json.loads(s.to_json(root, by_alias=True, cycles='ref')) == refs
+
+    with pytest.raises(ValueError, match=r'Circular reference detected \(id repeated\)'):
+        s.to_json(root, cycles='error')
+
+
+def test_cycles_invalid():
+    s = SchemaSerializer(core_schema.any_schema())
+    with pytest.raises(ValueError, match="Invalid cycles parameter, should be `'error'`, `'omit'` or `'ref'`"):
+        s.to_python(1, cycles='foobar')
+
+
 def test_custom_ser():
     s = SchemaSerializer(
         core_schema.definitions_schema(
@@ -1,9 +1,12 @@
+import dataclasses
 import json
+import uuid
+from enum import Enum
 
 import pytest
 from dirty_equals import IsStrictDict
 
-from pydantic_core import SchemaError, SchemaSerializer, core_schema, validate_core_schema
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, core_schema, to_json, validate_core_schema
 
 
 def test_dict_str_int():
@@ -158,3 +161,104 @@ def test_include_error(include_value, error_msg):
         validate_core_schema(
             core_schema.dict_schema(serialization=core_schema.filter_dict_schema(include=include_value))
         )
+
+
+class IntSubclass(int):
+    def __str__(self):
+        return 'int-subclass'
+
+
+class UuidSubclass(uuid.UUID):
+    def __str__(self):
+        return 'uuid-subclass'
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [core_schema.dict_schema(), core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema())],
+    ids=['any', 'int'],
+)
+def test_int_keys(schema):
+    s = SchemaSerializer(schema)
+    assert s.to_json({1: 1, -2: 2, 2**70: 3, IntSubclass(4): 4}) == (
+        b'{"1":1,"-2":2,"1180591620717411303424":3,"int-subclass":4}'
+    )
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [core_schema.dict_schema(), core_schema.dict_schema(core_schema.uuid_schema(), core_schema.int_schema())],
+    ids=['any', 'uuid'],
+)
+def test_uuid_keys(schema):
+    s = SchemaSerializer(schema)
+    value = uuid.UUID('12345678-1234-5678-1234-567812345678')
+    assert s.to_json({value: 1, UuidSubclass(int=1): 2}) == (
+        b'{"12345678-1234-5678-1234-567812345678":1,"uuid-subclass":2}'
+    )
+
+
+class Color(Enum):
+    RED = (255, 0, 0)
+    GREEN = (0, 255, 0)
+
+
+@dataclasses.dataclass(frozen=True)
+class Point:
+    x: int
+    y: int
+
+
+@pytest.mark.parametrize(
+    'key,expected',
+    [
+        (Color.RED, 'red'),
+        ((1, 2), '1:2'),
+        (Point(1, 2), 'Point(1, 2)'),
+    ],
+    ids=['enum', 'tuple', 'dataclass'],
+)
+def test_key_serialization(key, expected):
+    def ser_key(key):
+        if isinstance(key, Color):
+            return key.name.lower()
+        elif isinstance(key, tuple):
+            return ':'.join(map(str, key))
+        else:
+            return f'Point({key.x}, {key.y})'
+
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.any_schema(),
+            core_schema.int_schema(),
+            key_serialization=core_schema.any_schema(
+                serialization=core_schema.plain_serializer_function_ser_schema(ser_key)
+            ),
+        )
+    )
+    assert s.to_json({key: 1}) == f'{{"{expected}":1}}'.encode()
+    assert s.to_python({key: 1}) == {expected: 1}
+    assert s.to_python({key: 1}, mode='json') == {expected: 1}
+
+
+def test_key_serialization_overrides_keys_schema():
+    schema = core_schema.dict_schema(
+        core_schema.int_schema(),
+        core_schema.int_schema(),
+        key_serialization=core_schema.int_schema(
+            serialization=core_schema.plain_serializer_function_ser_schema(lambda k: f'0x{k:x}')
+        ),
+    )
+    # `key_serialization` has no effect on validation
+    assert SchemaValidator(schema).validate_python({'10': 2}) == {10: 2}
+    assert SchemaSerializer(schema).to_json({10: 2}) == b'{"0xa":2}'
+
+
+def test_read_only():
+    schema = core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), read_only=True)
+    value = SchemaValidator(schema).validate_python({'a': 1, 'b': 2})
+    s = SchemaSerializer(schema)
+    assert s.to_python(value) == IsStrictDict(a=1, b=2)
+    assert s.to_python(value, exclude={'a'}) == {'b': 2}
+    assert s.to_json(value) == b'{"a":1,"b":2}'
+    assert to_json([value]) == b'[{"a":1,"b":2}]'
@@ -0,0 +1,70 @@
+from datetime import date
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+@pytest.fixture(scope='module')
+def serializer() -> SchemaSerializer:
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'name': core_schema.model_field(core_schema.str_schema(), serialization_alias='Name'),
+                    'born': core_schema.model_field(core_schema.date_schema()),
+                    'tags': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+                    'meta': core_schema.model_field(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema())
+                    ),
+                }
+            ),
+        )
+    )
+
+
+def test_diff(serializer: SchemaSerializer):
+    old = BasicModel(name='alice', born=date(2000, 1, 1), tags=['a', 'b', 'c'], meta={'x': 1, 'y': {'z': 1}})
+    new = BasicModel(name='alice', born=date(2000, 1, 2), tags=['a', 'd'], meta={'y': {'z': 2}, 'w': True})
+    assert serializer.diff(old, new) == [
+        {'op': 'replace', 'path': ('born',), 'before': '2000-01-01', 'after': '2000-01-02'},
+        {'op': 'replace', 'path': ('tags', 1), 'before': 'b', 'after': 'd'},
+        {'op': 'remove', 'path': ('tags', 2), 'before': 'c'},
+        {'op': 'remove', 'path': ('meta', 'x'), 'before': 1},
+        {'op': 'replace', 'path': ('meta', 'y', 'z'), 'before': 1, 'after': 2},
+        {'op': 'add', 'path': ('meta', 'w'), 'after': True},
+    ]
+    assert serializer.diff(old, old) == []
+
+
+def test_diff_options(serializer: SchemaSerializer):
+    old = BasicModel(name='alice', born=date(2000, 1, 1), tags=[], meta={})
+    new = BasicModel(name='bob', born=date(2000, 1, 2), tags=[], meta={})
+    assert serializer.diff(old, new, mode='python', by_alias=False) == [
+        {'op': 'replace', 'path': ('name',), 'before': 'alice', 'after': 'bob'},
+        {'op': 'replace', 'path': ('born',), 'before': date(2000, 1, 1), 'after': date(2000, 1, 2)},
+    ]
+    assert serializer.diff(old, new)[0] == {'op': 'replace', 'path': ('Name',), 'before': 'alice', 'after': 'bob'}
+
+
+def test_diff_types():
+    s = SchemaSerializer(core_schema.any_schema())
+    # values of different types are changed even if they're equal
+    assert s.diff({'a': 1, 'b': [1]}, {'a': True, 'b': (1,)}, mode='python') == [
+        {'op': 'replace', 'path': ('a',), 'before': 1, 'after': True},
+        {'op': 'replace', 'path': ('b',), 'before': [1], 'after': (1,)},
+    ]
+    assert s.diff((1, 2), (1, 3), mode='python') == [{'op': 'replace', 'path': (1,), 'before': 2, 'after': 3}]
+    assert s.diff(1, 'a') == [{'op': 'replace', 'path': (), 'before': 1, 'after': 'a'}]
+
+
+def test_diff_error(serializer: SchemaSerializer):
+    with pytest.raises(PydanticSerializationError):
+        serializer.diff(BasicModel(name='a', born=date(2000, 1, 1), tags=[], meta={}), object(), warnings='error')
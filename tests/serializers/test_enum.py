@@ -1,8 +1,8 @@
-from enum import Enum
+from enum import Enum, IntFlag
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 def test_plain_enum():
@@ -103,3 +103,75 @@ def test_int_dict_key():
         assert v.to_python({'x': 'x'}) == {'x': 'x'}
     with pytest.warns(UserWarning, match='Expected `enum` but got `str` - serialized value may not be as expected'):
         assert v.to_json({'x': 'x'}) == b'{"x":"x"}'
+
+
+@pytest.mark.parametrize('sub_type', [None, 'int'])
+def test_by_name(sub_type):
+    class MyEnum(int, Enum):
+        a = 1
+        b = 2
+
+    v = SchemaSerializer(
+        core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type=sub_type, by='name')
+    )
+    assert v.to_python(MyEnum.a) is MyEnum.a
+    assert v.to_python(MyEnum.a, mode='json') == 'a'
+    assert v.to_json(MyEnum.b) == b'"b"'
+
+
+def test_by_name_dict_key():
+    class MyEnum(Enum):
+        a = 1
+        b = 2
+
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), by='name'),
+            core_schema.int_schema(),
+        )
+    )
+    assert v.to_python({MyEnum.a: 1}, mode='json') == {'a': 1}
+    assert v.to_json({MyEnum.a: 1, MyEnum.b: 2}) == b'{"a":1,"b":2}'
+
+
+def test_by_any_serializes_value():
+    class MyEnum(Enum):
+        a = 1
+
+    v = SchemaSerializer(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), by='any'))
+    assert v.to_json(MyEnum.a) == b'1'
+
+
+class Perm(IntFlag):
+    R = 4
+    W = 2
+    X = 1
+    RW = 6
+
+
+@pytest.mark.parametrize('sub_type', [None, 'int'])
+def test_flag_list(sub_type):
+    v = SchemaSerializer(
+        core_schema.enum_schema(Perm, list(Perm.__members__.values()), sub_type=sub_type, flag_serialization='list')
+    )
+    assert v.to_python(Perm.RW) is Perm.RW
+    assert v.to_python(Perm.RW, mode='json') == ['R', 'W']
+    assert v.to_json(Perm.R | Perm.X) == b'["R","X"]'
+    assert v.to_json(Perm(0)) == b'[]'
+
+
+def test_flag_int():
+    v = SchemaSerializer(core_schema.enum_schema(Perm, list(Perm.__members__.values()), sub_type='int'))
+    assert v.to_json(Perm.R | Perm.X) == b'5'
+    v = SchemaSerializer(
+        core_schema.enum_schema(Perm, list(Perm.__members__.values()), sub_type='int', flag_serialization='int')
+    )
+    assert v.to_json(Perm.R | Perm.X) == b'5'
+
+
+def test_flag_serialization_not_flag():
+    class MyEnum(Enum):
+        a = 1
+
+    with pytest.raises(SchemaError, match='`flag_serialization` is only supported for `enum.Flag` subclasses'):
+        SchemaSerializer(core_schema.enum_schema(MyEnum, [MyEnum.a], flag_serialization='list'))
@@ -0,0 +1,76 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+@pytest.fixture(scope='module')
+def serializer():
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'id': core_schema.model_field(core_schema.int_schema()),
+                    'name': core_schema.model_field(core_schema.str_schema(), serialization_alias='fullName'),
+                    'items': core_schema.model_field(core_schema.list_schema(core_schema.dict_schema())),
+                }
+            ),
+        )
+    )
+
+
+@pytest.fixture
+def model():
+    return BasicModel(id=1, name='café', items=[{'a': 1}, {'b': [1, 2]}])
+
+
+def member_sizes(data):
+    # the size of each `"key":value` member when written on its own, without the braces
+    return {k: len(json.dumps({k: v}, separators=(',', ':'), ensure_ascii=False).encode()) - 2 for k, v in data.items()}
+
+
+def test_field_sizes(serializer, model):
+    output, sizes = serializer.to_json(model, field_sizes=True)
+    assert output == serializer.to_json(model)
+    assert sizes == {'id': 6, 'fullName': 18, 'items': 29}
+    assert sizes == member_sizes(json.loads(output))
+    assert sum(sizes.values()) + len(sizes) - 1 + 2 == len(output)
+
+
+def test_indent(serializer, model):
+    output, sizes = serializer.to_json(model, field_sizes=True, indent=2)
+    assert output == serializer.to_json(model, indent=2)
+    assert sizes['id'] == len('"id": 1')
+    assert sizes['fullName'] == len('"fullName": "café"'.encode())
+    assert list(sizes) == ['id', 'fullName', 'items']
+
+
+def test_filtering(serializer, model):
+    assert serializer.to_json(model, field_sizes=True, exclude={'items'}, by_alias=False) == (
+        b'{"id":1,"name":"caf\xc3\xa9"}',
+        {'id': 6, 'name': 14},
+    )
+
+
+def test_escaped_keys():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    assert s.to_json({'a"b': 1, '': 22}, field_sizes=True) == (b'{"a\\"b":1,"":22}', {'a"b': 8, '': 5})
+
+
+@pytest.mark.parametrize('value', [[{'a': 1}], 1, 'x', {}])
+def test_not_object(value):
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_json(value, field_sizes=True) == (json.dumps(value, separators=(',', ':')).encode(), {})
+
+
+def test_release_gil():
+    s = SchemaSerializer(core_schema.dict_schema(), config={'ser_json_release_gil_every': 1})
+    assert s.to_json({'a': [1, 2], 'b': None}, field_sizes=True) == (b'{"a":[1,2],"b":null}', {'a': 9, 'b': 8})
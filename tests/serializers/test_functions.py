@@ -1,3 +1,4 @@
+import asyncio
 import json
 import platform
 import re
@@ -12,6 +13,7 @@ from pydantic_core import (
     PydanticOmit,
     PydanticSerializationError,
     PydanticSerializationUnexpectedValue,
+    PythonCallbackLimitExceeded,
     SchemaSerializer,
     core_schema,
 )
@@ -663,3 +665,131 @@ def test_serialize_pattern():
     assert s.to_python(pattern) == pattern
     assert s.to_python(pattern, mode='json') == '^regex$'
     assert s.to_json(pattern) == b'"^regex$"'
+
+
+def test_function_plain_async():
+    async def double(value):
+        await asyncio.sleep(0)
+        return value * 2
+
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.int_schema(
+                serialization=core_schema.plain_serializer_function_ser_schema(
+                    double, return_schema=core_schema.int_schema()
+                )
+            )
+        )
+    )
+    assert asyncio.run(s.to_python_async([1, 2, 3])) == [2, 4, 6]
+    assert asyncio.run(s.to_python_async([1, 2, 3], mode='json')) == [2, 4, 6]
+    assert asyncio.run(s.to_json_async([1, 2, 3])) == b'[2,4,6]'
+    assert asyncio.run(s.to_json_async([1, 2], indent=2)) == b'[\n  2,\n  4\n]'
+
+
+def test_function_wrap_async():
+    async def wrap(value, handler):
+        await asyncio.sleep(0)
+        return handler(value) + 1
+
+    s = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.str_schema(),
+            core_schema.int_schema(serialization=core_schema.wrap_serializer_function_ser_schema(wrap)),
+        )
+    )
+    assert asyncio.run(s.to_python_async({'a': 1, 'b': 2})) == {'a': 2, 'b': 3}
+    assert asyncio.run(s.to_json_async({'a': 1, 'b': 2})) == b'{"a":2,"b":3}'
+
+
+def test_function_async_return_schema():
+    async def get_bytes(value):
+        return value.encode()
+
+    s = SchemaSerializer(
+        core_schema.str_schema(
+            serialization=core_schema.plain_serializer_function_ser_schema(
+                get_bytes, return_schema=core_schema.bytes_schema()
+            )
+        )
+    )
+    assert asyncio.run(s.to_python_async('foo')) == b'foo'
+    assert asyncio.run(s.to_python_async('foo', mode='json')) == 'foo'
+    assert asyncio.run(s.to_json_async('foo')) == b'"foo"'
+
+
+def test_function_async_mixed_model():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_extra__', '__pydantic_fields_set__', '__pydantic_private__'
+
+    async def get_name(value):
+        return value.upper()
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'name': core_schema.model_field(
+                    core_schema.str_schema(serialization=core_schema.plain_serializer_function_ser_schema(get_name))
+                ),
+                'age': core_schema.model_field(core_schema.int_schema()),
+            }
+        ),
+    )
+    s = SchemaSerializer(schema)
+    m = MyModel()
+    m.__dict__ = {'name': 'alice', 'age': 42}
+    m.__pydantic_extra__ = None
+    m.__pydantic_fields_set__ = {'name', 'age'}
+    assert asyncio.run(s.to_python_async(m)) == {'name': 'ALICE', 'age': 42}
+    assert asyncio.run(s.to_json_async(m)) == b'{"name":"ALICE","age":42}'
+    # without awaitables the result is still awaitable
+    assert asyncio.run(SchemaSerializer(core_schema.int_schema()).to_python_async(1)) == 1
+
+
+def test_function_async_error():
+    async def boom(value):
+        raise ValueError('boom')
+
+    async def fine(value):
+        return value
+
+    calls = []
+
+    def tracked(value):
+        coro = fine(value)
+        calls.append(coro)
+        return coro
+
+    s = SchemaSerializer(
+        core_schema.tuple_positional_schema(
+            [
+                core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(boom)),
+                core_schema.int_schema(serialization=core_schema.plain_serializer_function_ser_schema(tracked)),
+            ]
+        )
+    )
+    with pytest.raises(ValueError, match='boom'):
+        asyncio.run(s.to_python_async((1, 2)))
+    # the coroutine which was never reached is closed
+    assert calls[0].cr_frame is None
+
+
+def test_max_python_callbacks():
+    increment = core_schema.plain_serializer_function_ser_schema(lambda x: x + 1)
+    item_schema = core_schema.int_schema(serialization=increment)
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            item_schema,
+            serialization=core_schema.wrap_serializer_function_ser_schema(lambda x, handler: handler(x)),
+        ),
+        {'max_python_callbacks': 3},
+    )
+    assert s.to_python([1, 2]) == [2, 3]
+    assert s.to_json([1, 2]) == b'[2,3]'
+    # the items are serialized from the handler of the wrap serializer
+    with pytest.raises(PythonCallbackLimitExceeded) as exc_info:
+        s.to_python([1, 2, 3])
+    assert exc_info.value.max_python_callbacks == 3
+    with pytest.raises(PythonCallbackLimitExceeded):
+        s.to_json([1, 2, 3])
@@ -1,10 +1,21 @@
 import json
 import re
+import sys
+import threading
+import time
 from functools import partial
 
 import pytest
 
-from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema, validate_core_schema
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaError,
+    SchemaSerializer,
+    SchemaValidator,
+    core_schema,
+    to_json,
+    validate_core_schema,
+)
 
 
 def test_list_any():
@@ -390,6 +401,45 @@ def test_function_positional_tuple():
     assert s.to_json((1, 2, 3)) == b'["a1","b2","extra3"]'
 
 
+def test_tuple_variadic_middle():
+    s = SchemaSerializer(
+        core_schema.tuple_schema(
+            [core_schema.int_schema(), core_schema.str_schema(), core_schema.float_schema()], variadic_item_index=1
+        )
+    )
+    assert s.to_python((1, 2.5)) == (1, 2.5)
+    assert s.to_python((1, 'a', 'b', 2.5), mode='json') == [1, 'a', 'b', 2.5]
+    assert s.to_json((1, 'a', 'b', 2.5)) == b'[1,"a","b",2.5]'
+    assert s.to_python((1, 'a', 'b', 2.5), exclude={3}) == (1, 'a', 'b')
+
+
+def test_tuple_variadic_middle_union():
+    upper_str_schema = core_schema.str_schema(
+        serialization=core_schema.plain_serializer_function_ser_schema(str.upper)
+    )
+    s = SchemaSerializer(
+        core_schema.union_schema(
+            [
+                core_schema.tuple_schema(
+                    [upper_str_schema, core_schema.int_schema(), core_schema.str_schema()], variadic_item_index=1
+                ),
+                core_schema.tuple_schema([core_schema.str_schema()], variadic_item_index=0),
+            ]
+        )
+    )
+    assert s.to_python(('a', 'b'), mode='json') == ['A', 'b']
+    # too few items for the first choice, every item other than the variadic one is required
+    assert s.to_python(('a',), mode='json') == ['a']
+
+
+def test_list_output_generator():
+    schema = core_schema.list_schema(core_schema.int_schema(), output='generator')
+    v = SchemaValidator(schema)
+    s = SchemaSerializer(schema)
+    assert s.to_json(v.validate_json('[1, "2", 3]')) == b'[1,2,3]'
+    assert s.to_python(v.validate_python([1, 2, 3]), mode='json', exclude={1}) == [1, 3]
+
+
 def test_list_dict_key():
     s = SchemaSerializer(core_schema.dict_schema(core_schema.list_schema(), core_schema.int_schema()))
     with pytest.warns(UserWarning, match=r'Expected `list\[any\]` but got `str`'):
@@ -423,6 +473,31 @@ def test_tuple_pos_dict_key():
     assert s.to_json({(1, 'a', 2): 1}) == b'{"1,a,2":1}'
 
 
+@pytest.mark.parametrize(
+    'config,expected',
+    [
+        ({'ser_json_tuple_key_separator': '|'}, '1|a'),
+        ({'ser_json_tuple_keys': 'json'}, '[1,"a"]'),
+    ],
+)
+@pytest.mark.parametrize(
+    'keys_schema',
+    [core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]), core_schema.any_schema()],
+    ids=['tuple', 'any'],
+)
+def test_tuple_dict_key_config(keys_schema, config, expected):
+    s = SchemaSerializer(core_schema.dict_schema(keys_schema, core_schema.int_schema()), config)
+    assert s.to_python({(1, 'a'): 1}, mode='json') == {expected: 1}
+    assert s.to_json({(1, 'a'): 1}) == json.dumps({expected: 1}, separators=(',', ':')).encode()
+
+
+def test_tuple_dict_key_invalid_config():
+    with pytest.raises(SchemaError, match='Invalid TupleKeyMode serialization mode: `foobar`'):
+        SchemaSerializer(core_schema.dict_schema(), {'ser_json_tuple_keys': 'foobar'})
+    with pytest.raises(SchemaError, match='`ser_json_tuple_key_separator` must not be empty'):
+        SchemaSerializer(core_schema.dict_schema(), {'ser_json_tuple_key_separator': ''})
+
+
 def test_tuple_wrong_size_union():
     # See https://github.com/pydantic/pydantic/issues/8677
 
@@ -447,3 +522,57 @@ def test_tuple_wrong_size_union():
 
     with pytest.warns(UserWarning, match='Unexpected extra items present in tuple'):
         s.to_json((1.0, 2.0, 3.0, 4.0))
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [core_schema.list_schema(core_schema.any_schema()), core_schema.any_schema()],
+    ids=['list', 'any'],
+)
+def test_release_gil(schema):
+    value = [list(range(100)), (1, 2), {'a': [1, 2]}, {3, 4}] * 10
+    s = SchemaSerializer(schema, config={'ser_json_release_gil_every': 10})
+    assert s.to_json(value) == SchemaSerializer(schema).to_json(value)
+    assert s.to_json(value, indent=2) == SchemaSerializer(schema).to_json(value, indent=2)
+
+
+def test_release_gil_other_threads_run():
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())),
+        config={'ser_json_release_gil_every': 10_000},
+    )
+    value = [list(range(100)) for _ in range(5_000)]
+    ticks = []
+    stop = threading.Event()
+
+    def tick():
+        while not stop.is_set():
+            ticks.append(time.perf_counter())
+
+    switch_interval = sys.getswitchinterval()
+    sys.setswitchinterval(1e-4)
+    thread = threading.Thread(target=tick)
+    thread.start()
+    try:
+        start = time.perf_counter()
+        s.to_json(value)
+        end = time.perf_counter()
+    finally:
+        stop.set()
+        thread.join()
+        sys.setswitchinterval(switch_interval)
+
+    # the other thread ran while the middle of the list was being serialized
+    quarter = (end - start) / 4
+    assert any(start + quarter < t < end - quarter for t in ticks)
+
+
+def test_list_read_only():
+    schema = core_schema.list_schema(core_schema.int_schema(), read_only=True)
+    value = SchemaValidator(schema).validate_python([1, 2, 3])
+    s = SchemaSerializer(schema)
+    assert s.to_python(value) == [1, 2, 3]
+    assert type(s.to_python(value)) is list
+    assert s.to_python(value, include={0, 2}) == [1, 3]
+    assert s.to_json(value) == b'[1,2,3]'
+    assert to_json({'a': value}) == b'{"a":[1,2,3]}'
@@ -0,0 +1,124 @@
+import dataclasses
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+class Method(Enum):
+    get = 'GET'
+
+
+def model_serializer():
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'method': core_schema.model_field(core_schema.any_schema(), metric_label=True),
+                    'path': core_schema.model_field(
+                        core_schema.str_schema(), metric_label=True, serialization_alias='route-path'
+                    ),
+                    'status': core_schema.model_field(core_schema.int_schema(), metric_label=True),
+                    'body': core_schema.model_field(core_schema.str_schema()),
+                }
+            ),
+        )
+    )
+
+
+def test_model():
+    s = model_serializer()
+    value = BasicModel(method=Method.get, path='/users/{id}', status=200, body='lots of data')
+    assert s.to_metric_labels(value) == {'method': 'GET', 'route_path': '/users/{id}', 'status': '200'}
+    assert s.to_metric_labels(value, by_alias=False) == {'method': 'GET', 'path': '/users/{id}', 'status': '200'}
+
+
+@pytest.mark.parametrize(
+    'method,expected',
+    [
+        ('a b\nc', 'a_b_c'),
+        ('k=v,k2:v2|c#t', 'k_v_k2_v2_c_t'),
+        ('say "hi" \\o/', 'say__hi___o/'),
+        ('café', 'café'),
+        (None, ''),
+        (True, 'true'),
+        (1.5, '1.5'),
+        ([1, 'a'], '[1__a_]'),
+    ],
+)
+def test_label_values(method, expected):
+    s = model_serializer()
+    labels = s.to_metric_labels(BasicModel(method=method, path='/', status=200, body=''))
+    assert labels['method'] == expected
+
+
+def test_label_names():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                '1st': core_schema.typed_dict_field(core_schema.int_schema(), metric_label=True),
+                'http.method': core_schema.typed_dict_field(core_schema.str_schema(), metric_label=True),
+                'other': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+    assert s.to_metric_labels({'1st': 1, 'http.method': 'GET', 'other': 'x'}) == {'_1st': '1', 'http_method': 'GET'}
+
+
+def test_dataclass():
+    @dataclasses.dataclass
+    class Request:
+        method: str
+        size: int
+
+    s = SchemaSerializer(
+        core_schema.dataclass_schema(
+            Request,
+            core_schema.dataclass_args_schema(
+                'Request',
+                [
+                    core_schema.dataclass_field(name='method', schema=core_schema.str_schema(), metric_label=True),
+                    core_schema.dataclass_field(name='size', schema=core_schema.int_schema()),
+                ],
+            ),
+            ['method', 'size'],
+        )
+    )
+    assert s.to_metric_labels(Request(method='POST', size=10)) == {'method': 'POST'}
+
+
+def test_definitions():
+    s = SchemaSerializer(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Model'),
+            [
+                core_schema.model_schema(
+                    BasicModel,
+                    core_schema.model_fields_schema(
+                        {
+                            'name': core_schema.model_field(core_schema.str_schema(), metric_label=True),
+                            'child': core_schema.model_field(
+                                core_schema.nullable_schema(core_schema.definition_reference_schema('Model'))
+                            ),
+                        }
+                    ),
+                    ref='Model',
+                )
+            ],
+        )
+    )
+    assert s.to_metric_labels(BasicModel(name='a', child=BasicModel(name='b', child=None))) == {'name': 'a'}
+
+
+def test_no_labels():
+    assert SchemaSerializer(core_schema.int_schema()).to_metric_labels(1) == {}
+    s = SchemaSerializer(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    assert s.to_metric_labels({'a': 1}) == {}
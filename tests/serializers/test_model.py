@@ -660,6 +660,34 @@ def test_property_alias():
     assert s.to_json(Model(3, 4)) == b'{"width":3,"height":4,"Area":12,"volume":48}'
 
 
+def test_alias_generator():
+    @dataclasses.dataclass
+    class Model:
+        box_width: int
+        box_height: int
+
+        @property
+        def box_area(self) -> int:
+            return self.box_width * self.box_height
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'box_width': core_schema.model_field(core_schema.int_schema()),
+                    'box_height': core_schema.model_field(core_schema.int_schema(), serialization_alias='height'),
+                },
+                computed_fields=[core_schema.computed_field('box_area', core_schema.int_schema())],
+            ),
+            config=core_schema.CoreConfig(alias_generator='to_kebab'),
+        )
+    )
+    assert s.to_python(Model(3, 4)) == {'box-width': 3, 'height': 4, 'box-area': 12}
+    assert s.to_json(Model(3, 4)) == b'{"box-width":3,"height":4,"box-area":12}'
+    assert s.to_python(Model(3, 4), by_alias=False) == {'box_width': 3, 'box_height': 4, 'box_area': 12}
+
+
 def test_computed_field_exclude_none():
     @dataclasses.dataclass
     class Model:
@@ -1041,3 +1069,81 @@ def test_extra_custom_serializer():
     m.__pydantic_extra__ = {'extra': 'extra'}
 
     assert s.to_python(m) == {'extra': 'extra bam!'}
+
+
+@pytest.mark.parametrize('extras_destination', ['__pydantic_extra__', 'merge', 'extras'])
+def test_extras_destination(extras_destination):
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())},
+            extras_schema=core_schema.int_schema(serialization=core_schema.to_string_ser_schema(when_used='always')),
+        ),
+        config={'extra_fields_behavior': 'allow', 'extras_destination': extras_destination},
+    )
+    m = SchemaValidator(schema).validate_python({'a': 1, 'b': 2})
+    s = SchemaSerializer(schema)
+    assert s.to_python(m) == {'a': 1, 'b': '2'}
+    assert s.to_python(m, mode='json') == {'a': 1, 'b': '2'}
+    assert s.to_python(m, exclude={'b'}) == {'a': 1}
+
+
+def test_dedupe_shared():
+    customer_schema = core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'tags': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        ),
+    )
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.model_schema(
+                BasicModel,
+                core_schema.model_fields_schema(
+                    {
+                        'id': core_schema.model_field(core_schema.int_schema()),
+                        'customer': core_schema.model_field(customer_schema),
+                    }
+                ),
+            )
+        )
+    )
+    tags = ['a', 'b']
+    alice = BasicModel(name='alice', tags=tags)
+    bob = BasicModel(name='bob', tags=tags)
+    orders = [BasicModel(id=1, customer=alice), BasicModel(id=2, customer=bob), BasicModel(id=3, customer=alice)]
+
+    assert json.loads(s.to_json(orders, dedupe_shared=True)) == {
+        '$defs': {
+            '0': ['a', 'b'],
+            '1': {'name': 'alice', 'tags': {'$ref': '#/$defs/0'}},
+        },
+        'value': [
+            {'id': 1, 'customer': {'$ref': '#/$defs/1'}},
+            {'id': 2, 'customer': {'name': 'bob', 'tags': {'$ref': '#/$defs/0'}}},
+            {'id': 3, 'customer': {'$ref': '#/$defs/1'}},
+        ],
+    }
+    assert json.loads(s.to_json(orders[1:2], dedupe_shared=True)) == {
+        '$defs': {},
+        'value': [{'id': 2, 'customer': {'name': 'bob', 'tags': ['a', 'b']}}],
+    }
+    # values serialized with include or exclude aren't shared
+    assert json.loads(s.to_json(orders, exclude={'__all__': {'customer': {'tags'}}}, dedupe_shared=True)) == {
+        '$defs': {},
+        'value': [
+            {'id': 1, 'customer': {'name': 'alice'}},
+            {'id': 2, 'customer': {'name': 'bob'}},
+            {'id': 3, 'customer': {'name': 'alice'}},
+        ],
+    }
+    assert json.loads(s.to_json(orders)) == json.loads(s.to_json(orders, dedupe_shared=False))
+
+    with pytest.raises(ValueError, match=r"`dedupe_shared` can't be used with `cycles='ref'`"):
+        s.to_json(orders, dedupe_shared=True, cycles='ref')
@@ -0,0 +1,43 @@
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def test_money_object():
+    s = SchemaSerializer(core_schema.money_schema())
+    value = {'amount': Decimal('12.30'), 'currency': 'USD'}
+    assert s.to_python(value) == {'amount': Decimal('12.30'), 'currency': 'USD'}
+    assert s.to_python(value, mode='json') == {'amount': '12.30', 'currency': 'USD'}
+    assert s.to_json(value) == b'{"amount":"12.30","currency":"USD"}'
+
+
+def test_money_str():
+    s = SchemaSerializer(core_schema.money_schema(money_serialization='str'))
+    value = {'amount': Decimal('500'), 'currency': 'JPY'}
+    assert s.to_python(value) == '500 JPY'
+    assert s.to_python(value, mode='json') == '500 JPY'
+    assert s.to_json(value) == b'"500 JPY"'
+
+
+@pytest.mark.parametrize('money_serialization', ['object', 'str'])
+def test_round_trip(money_serialization):
+    schema = core_schema.money_schema(money_serialization=money_serialization)
+    v = SchemaValidator(schema)
+    money = v.validate_python('1.5 KWD')
+    assert money == {'amount': Decimal('1.500'), 'currency': 'KWD'}
+    assert v.validate_json(SchemaSerializer(schema).to_json(money)) == money
+
+
+def test_fallback():
+    s = SchemaSerializer(core_schema.money_schema())
+    with pytest.warns(UserWarning, match='Expected `money` but got `str` - serialized value may not be as expected'):
+        assert s.to_python('12 USD') == '12 USD'
+    with pytest.warns(UserWarning, match='Expected `money` but got `dict` - serialized value may not be as expected'):
+        assert s.to_json({'amount': 1}) == b'{"amount":1}'
+
+
+def test_invalid_money_serialization():
+    with pytest.raises(Exception, match="Invalid `money_serialization` value: dict, expected 'object' or 'str'"):
+        SchemaSerializer(core_schema.money_schema(money_serialization='dict'))
@@ -0,0 +1,44 @@
+from typing import NamedTuple
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+class Point(NamedTuple):
+    x: int
+    y: int
+
+
+@pytest.fixture(scope='module')
+def schema():
+    return core_schema.namedtuple_schema(Point, {'x': core_schema.int_schema(), 'y': core_schema.int_schema()})
+
+
+def test_namedtuple_array(schema):
+    s = SchemaSerializer(schema)
+    assert s.to_python(Point(1, 2)) == (1, 2)
+    assert s.to_python(Point(1, 2), mode='json') == [1, 2]
+    assert s.to_json(Point(1, 2)) == b'[1,2]'
+    assert s.to_json(Point(1, 2), exclude={0}) == b'[2]'
+
+
+def test_namedtuple_object(schema):
+    s = SchemaSerializer(schema, {'ser_json_namedtuple': 'object'})
+    assert s.to_python(Point(1, 2)) == (1, 2)
+    assert s.to_python(Point(1, 2), mode='json') == {'x': 1, 'y': 2}
+    assert s.to_json(Point(1, 2)) == b'{"x":1,"y":2}'
+    assert s.to_json(Point(1, 2), include={1}) == b'{"y":2}'
+
+
+@pytest.mark.parametrize('ser_json_namedtuple', ['array', 'object'])
+def test_round_trip(schema, ser_json_namedtuple):
+    config = core_schema.CoreConfig(ser_json_namedtuple=ser_json_namedtuple)
+    json = SchemaSerializer(schema, config).to_json(Point(1, 2))
+    assert SchemaValidator(schema, config).validate_json(json) == Point(1, 2)
+
+
+def test_wrong_length(schema):
+    s = SchemaSerializer(schema)
+    with pytest.warns(UserWarning, match='Expected `Point` but got `tuple`'):
+        assert s.to_json((1, 2, 3)) == b'[1,2,3]'
@@ -0,0 +1,39 @@
+import json
+
+from pydantic_core import SchemaSerializer, core_schema
+
+from ..conftest import NativeType
+
+
+class Point:
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+
+
+def serialize_point(value, json_mode):
+    if json_mode:
+        return f'{value.x},{value.y}'
+    return {'x': value.x, 'y': value.y}
+
+
+def test_serialize():
+    native_type = NativeType(lambda value, _: value, serialize_point)
+    s = SchemaSerializer(core_schema.native_schema(native_type.capsule))
+    assert s.to_python(Point(1, 2)) == {'x': 1, 'y': 2}
+    assert s.to_python(Point(1, 2), mode='json') == '1,2'
+    assert s.to_json(Point(1, 2)) == b'"1,2"'
+
+
+def test_dict_keys():
+    native_type = NativeType(lambda value, _: value, serialize_point)
+    key_schema = core_schema.native_schema(native_type.capsule)
+    s = SchemaSerializer(core_schema.dict_schema(key_schema, core_schema.int_schema()))
+    assert json.loads(s.to_json({Point(1, 2): 3})) == {'1,2': 3}
+
+
+def test_no_serialize():
+    native_type = NativeType(lambda value, _: value)
+    s = SchemaSerializer(core_schema.native_schema(native_type.capsule))
+    assert s.to_python([1, 2]) == [1, 2]
+    assert s.to_json((1, 2)) == b'[1,2]'
@@ -0,0 +1,66 @@
+import pytest
+
+from pydantic_core import PhoneNumber, SchemaError, SchemaSerializer, SchemaValidator, core_schema
+
+NUMBER = PhoneNumber('+44 20 7946 0018')
+
+
+def test_phone():
+    s = SchemaSerializer(core_schema.phone_schema())
+    assert s.to_python('+442079460018') == '+442079460018'
+    assert s.to_python('+442079460018', mode='json') == '+442079460018'
+    assert s.to_json('+442079460018') == b'"+442079460018"'
+
+
+def test_phone_number():
+    s = SchemaSerializer(core_schema.phone_schema(as_phone_number=True))
+    assert s.to_python(NUMBER) is NUMBER
+    assert s.to_python(NUMBER, mode='json') == '+442079460018'
+    assert s.to_json(NUMBER) == b'"+442079460018"'
+
+
+@pytest.mark.parametrize(
+    'output_format,expected',
+    [
+        ('e164', '+442079460018'),
+        ('international', '+44 20 7946 0018'),
+        ('national', '020 7946 0018'),
+        ('rfc3966', 'tel:+44-20-7946-0018'),
+    ],
+)
+def test_output_format(output_format, expected):
+    s = SchemaSerializer(core_schema.phone_schema(output_format=output_format))
+    assert s.to_python(NUMBER) == expected
+    assert s.to_python('+442079460018') == expected
+    assert s.to_json(NUMBER) == f'"{expected}"'.encode()
+    assert s.to_json('+442079460018') == f'"{expected}"'.encode()
+
+
+def test_invalid_output_format():
+    with pytest.raises(SchemaError, match="Invalid `output_format` value: x, expected 'e164', 'international'"):
+        SchemaSerializer(core_schema.phone_schema(output_format='x'))
+
+
+def test_phone_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.phone_schema(), core_schema.int_schema()))
+    assert s.to_python({NUMBER: 1}, mode='json') == {'+442079460018': 1}
+    assert s.to_json({NUMBER: 1}) == b'{"+442079460018":1}'
+
+
+def test_infer():
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_python(NUMBER) is NUMBER
+    assert s.to_python({'a': NUMBER}, mode='json') == {'a': '+442079460018'}
+    assert s.to_json({NUMBER: NUMBER}) == b'{"+442079460018":"+442079460018"}'
+
+
+def test_round_trip():
+    schema = core_schema.phone_schema(as_phone_number=True)
+    value = SchemaValidator(schema).validate_python('+44 20 7946 0018')
+    assert SchemaValidator(schema).validate_json(SchemaSerializer(schema).to_json(value)) == value
+
+
+def test_fallback():
+    s = SchemaSerializer(core_schema.phone_schema())
+    with pytest.warns(UserWarning, match='Expected `phone` but got `int` - serialized value may not be as expected'):
+        assert s.to_json(123) == b'123'
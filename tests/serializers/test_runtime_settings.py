@@ -0,0 +1,125 @@
+import asyncio
+import threading
+import warnings
+from datetime import timedelta
+
+import pytest
+
+from pydantic_core import (
+    PydanticSerializationError,
+    RuntimeSettings,
+    SchemaError,
+    SchemaSerializer,
+    core_schema,
+    runtime_settings,
+)
+
+
+def test_timedelta():
+    s = SchemaSerializer(core_schema.timedelta_schema())
+    td = timedelta(seconds=4, microseconds=500_000)
+    assert s.to_json(td) == b'"PT4.5S"'
+    with runtime_settings(ser_json_timedelta='float'):
+        assert s.to_json(td) == b'4.5'
+        assert s.to_python(td, mode='json') == 4.5
+    assert s.to_json(td) == b'"PT4.5S"'
+
+
+def test_timedelta_overrides_config():
+    s = SchemaSerializer(core_schema.timedelta_schema(), config={'ser_json_timedelta': 'float'})
+    td = timedelta(seconds=4, microseconds=500_000)
+    assert s.to_json(td) == b'4.5'
+    with runtime_settings(ser_json_timedelta='iso8601'):
+        assert s.to_json(td) == b'"PT4.5S"'
+
+
+def test_timedelta_inferred():
+    s = SchemaSerializer(core_schema.any_schema())
+    td = timedelta(seconds=4, microseconds=500_000)
+    with runtime_settings(ser_json_timedelta='float'):
+        assert s.to_json({'a': td}) == b'{"a":4.5}'
+        assert s.to_python({'a': td}, mode='json') == {'a': 4.5}
+        assert s.to_json({td: 1}) == b'{"4.5":1}'
+
+
+@pytest.mark.parametrize('schema', [core_schema.float_schema(), core_schema.any_schema()], ids=['float', 'any'])
+def test_inf_nan(schema):
+    s = SchemaSerializer(schema)
+    assert s.to_json(float('inf')) == b'null'
+    with runtime_settings(ser_json_inf_nan='strings'):
+        assert s.to_json(float('inf')) == b'"Infinity"'
+        assert s.to_json(float('nan')) == b'"NaN"'
+    with runtime_settings(ser_json_inf_nan='constants'):
+        assert s.to_json(float('-inf')) == b'-Infinity'
+    assert s.to_json(float('inf')) == b'null'
+
+
+def test_warnings():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.warns(UserWarning, match='Expected `int` but got `str`'):
+        assert s.to_python('a') == 'a'
+
+    with runtime_settings(warnings='error'):
+        with pytest.raises(PydanticSerializationError, match='Expected `int` but got `str`'):
+            s.to_python('a')
+        with pytest.raises(PydanticSerializationError, match='Expected `int` but got `str`'):
+            s.to_json('a')
+
+    with runtime_settings(warnings=False):
+        with warnings.catch_warnings():
+            warnings.simplefilter('error')
+            assert s.to_python('a') == 'a'
+        # an explicit argument takes priority
+        with pytest.warns(UserWarning, match='Expected `int` but got `str`'):
+            assert s.to_python('a', warnings=True) == 'a'
+
+
+def test_nested():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.any_schema()))
+    value = [timedelta(seconds=1), float('nan')]
+    with runtime_settings(ser_json_timedelta='float'):
+        assert s.to_json(value) == b'[1.0,null]'
+        with runtime_settings(ser_json_inf_nan='strings') as settings:
+            assert isinstance(settings, RuntimeSettings)
+            assert s.to_json(value) == b'[1.0,"NaN"]'
+            with runtime_settings(ser_json_timedelta='iso8601'):
+                assert s.to_json(value) == b'["PT1S","NaN"]'
+            assert s.to_json(value) == b'[1.0,"NaN"]'
+        assert s.to_json(value) == b'[1.0,null]'
+    assert s.to_json(value) == b'["PT1S",null]'
+
+
+def test_thread_local():
+    s = SchemaSerializer(core_schema.timedelta_schema())
+    results = []
+
+    with runtime_settings(ser_json_timedelta='float'):
+        thread = threading.Thread(target=lambda: results.append(s.to_json(timedelta(seconds=1))))
+        thread.start()
+        thread.join()
+        assert s.to_json(timedelta(seconds=1)) == b'1.0'
+
+    assert results == [b'"PT1S"']
+
+
+def test_task_local():
+    s = SchemaSerializer(core_schema.timedelta_schema())
+
+    async def serialize(mode):
+        with runtime_settings(ser_json_timedelta=mode):
+            await asyncio.sleep(0)
+            return s.to_json(timedelta(seconds=1))
+
+    async def main():
+        return await asyncio.gather(serialize('float'), serialize('iso8601'))
+
+    assert asyncio.run(main()) == [b'1.0', b'"PT1S"']
+
+
+def test_invalid():
+    with pytest.raises(SchemaError, match='Invalid TimedeltaMode serialization mode: `foobar`'):
+        with runtime_settings(ser_json_timedelta='foobar'):
+            pass
+    with pytest.raises(TypeError, match='Invalid warnings parameter'):
+        with runtime_settings(warnings='foobar'):
+            pass
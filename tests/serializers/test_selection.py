@@ -0,0 +1,148 @@
+import json
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+@pytest.fixture(scope='module')
+def serializer():
+    author_schema = core_schema.model_schema(
+        BasicModel,
+        core_schema.model_fields_schema(
+            {
+                'id': core_schema.model_field(core_schema.int_schema()),
+                'full_name': core_schema.model_field(core_schema.str_schema(), serialization_alias='fullName'),
+                'email': core_schema.model_field(core_schema.nullable_schema(core_schema.str_schema())),
+            }
+        ),
+    )
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'id': core_schema.model_field(core_schema.int_schema()),
+                    'title': core_schema.model_field(core_schema.str_schema()),
+                    'body': core_schema.model_field(core_schema.str_schema()),
+                    'author': core_schema.model_field(author_schema),
+                    'reviewers': core_schema.model_field(core_schema.list_schema(author_schema)),
+                }
+            ),
+        )
+    )
+
+
+@pytest.fixture
+def post():
+    return BasicModel(
+        id=1,
+        title='Hello',
+        body='lots of text',
+        author=BasicModel(id=2, full_name='Alice', email='alice@example.com'),
+        reviewers=[BasicModel(id=3, full_name='Bob', email=None), BasicModel(id=4, full_name='Eve', email=None)],
+    )
+
+
+def test_selection(serializer, post):
+    selection = {'title': True, 'author': {'full_name': True}}
+    assert serializer.to_python(post, selection=selection) == {'title': 'Hello', 'author': {'full_name': 'Alice'}}
+
+
+def test_order(serializer, post):
+    assert list(serializer.to_python(post, selection={'title': True, 'id': True})) == ['title', 'id']
+
+
+def test_list(serializer, post):
+    selection = {'reviewers': {'id': True}}
+    assert serializer.to_python(post, selection=selection) == {'reviewers': [{'id': 3}, {'id': 4}]}
+
+
+def test_aliases(serializer, post):
+    selection = {'headline': ('title', True), 'writer': ('author', {'name': ('full_name', True), 'id': True})}
+    assert serializer.to_python(post, selection=selection) == {
+        'headline': 'Hello',
+        'writer': {'name': 'Alice', 'id': 2},
+    }
+
+
+def test_by_alias_ignored(serializer, post):
+    assert serializer.to_python(post, selection={'author': {'full_name': True}}, by_alias=True) == {
+        'author': {'full_name': 'Alice'}
+    }
+
+
+def test_include_exclude(serializer, post):
+    assert serializer.to_python(post, selection={'id': True, 'title': True}, exclude={'id'}) == {'title': 'Hello'}
+    assert serializer.to_python(post, selection={'id': True, 'title': True}, include={'title'}) == {'title': 'Hello'}
+
+
+def test_exclude_none(serializer, post):
+    selection = {'reviewers': {'id': True, 'email': True}}
+    assert serializer.to_python(post, selection=selection, exclude_none=True) == {'reviewers': [{'id': 3}, {'id': 4}]}
+
+
+def test_unselected_fields_not_serialized(post):
+    calls = []
+
+    def ser_body(value):
+        calls.append(value)
+        return value
+
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'title': core_schema.typed_dict_field(core_schema.str_schema()),
+                'body': core_schema.typed_dict_field(
+                    core_schema.str_schema(serialization=core_schema.plain_serializer_function_ser_schema(ser_body))
+                ),
+            }
+        )
+    )
+    assert s.to_python({'title': 'Hello', 'body': 'text'}, selection={'t': ('title', True)}) == {'t': 'Hello'}
+    assert calls == []
+
+
+def test_to_json(serializer, post):
+    selection = {'title': True, 'writer': ('author', {'fullName': ('full_name', True)})}
+    assert serializer.to_json(post, selection=selection) == b'{"title":"Hello","writer":{"fullName":"Alice"}}'
+    assert json.loads(serializer.to_json(post, selection=selection, indent=2)) == {
+        'title': 'Hello',
+        'writer': {'fullName': 'Alice'},
+    }
+
+
+def test_list_root():
+    s = SchemaSerializer(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(core_schema.bytes_schema()),
+                }
+            )
+        )
+    )
+    assert s.to_python([{'a': 1, 'b': b'x'}], selection={'b': True}) == [{'b': b'x'}]
+    assert s.to_json([{'a': 1, 'b': b'x'}], selection={'B': ('b', True)}) == b'[{"B":"x"}]'
+
+
+@pytest.mark.parametrize(
+    'selection,message',
+    [
+        (['a'], "`selection` must be a dict, got 'list'"),
+        ({1: True}, '`selection` keys must be strings'),
+        ({'a': False}, "Invalid selection for 'a', expected `True`, a dict or a `\\(field name, selection\\)` tuple"),
+        ({'a': ('b', 1)}, "Invalid selection for 'a'"),
+        ({'a': (1, True)}, "Invalid selection for 'a'"),
+    ],
+)
+def test_invalid_selection(serializer, post, selection, message):
+    with pytest.raises(TypeError, match=message):
+        serializer.to_python(post, selection=selection)
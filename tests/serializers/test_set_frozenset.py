@@ -45,3 +45,21 @@ def test_set_fallback(input_value, json_output, warning_type):
 
     with pytest.warns(UserWarning, match=f'Expected {warning_type} - serialized value may not be as expected'):
         assert json.loads(v.to_json(input_value)) == json_output
+
+
+@pytest.mark.parametrize(
+    'schema,value',
+    [
+        (core_schema.set_schema(core_schema.int_schema(), ordered=True), [3, 1, 2]),
+        (core_schema.frozenset_schema(core_schema.int_schema(), ordered=True), (3, 1, 2)),
+    ],
+)
+def test_ordered(schema, value):
+    s = SchemaSerializer(schema)
+    # the order of the validated items is kept
+    assert s.to_python(value) == value
+    assert type(s.to_python(value)) is type(value)
+    assert s.to_python(value, mode='json') == [3, 1, 2]
+    assert s.to_json(value) == b'[3,1,2]'
+    # sets are still serialized like sets
+    assert s.to_json({1}) == b'[1]'
@@ -166,3 +166,10 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+
+def test_float_inf_nan_schema_override():
+    s = SchemaSerializer(core_schema.float_schema(ser_json_inf_nan='strings'), {'ser_json_inf_nan': 'constants'})
+    assert s.to_json(float('inf')) == b'"Infinity"'
+    s = SchemaSerializer(core_schema.list_schema(core_schema.float_schema(ser_json_inf_nan='constants')))
+    assert s.to_json([float('nan'), 1.5]) == b'[NaN,1.5]'
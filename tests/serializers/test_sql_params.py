@@ -0,0 +1,108 @@
+from datetime import date, datetime, timezone
+from decimal import Decimal
+from enum import Enum
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+class BasicModel:
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+
+class Color(Enum):
+    red = 'r'
+
+
+@pytest.fixture(scope='module')
+def serializer():
+    return SchemaSerializer(
+        core_schema.model_schema(
+            BasicModel,
+            core_schema.model_fields_schema(
+                {
+                    'id': core_schema.model_field(core_schema.int_schema()),
+                    'name': core_schema.model_field(
+                        core_schema.nullable_schema(core_schema.str_schema()), serialization_alias='full_name'
+                    ),
+                    'price': core_schema.model_field(core_schema.decimal_schema()),
+                    'created': core_schema.model_field(core_schema.datetime_schema()),
+                    'tags': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+                }
+            ),
+        )
+    )
+
+
+def model(**kwargs):
+    fields = dict(
+        id=1, name='apple', price=Decimal('1.50'), created=datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone.utc), tags=[]
+    )
+    return BasicModel(**{**fields, **kwargs})
+
+
+def test_sql_params(serializer):
+    columns, params = serializer.to_sql_params(model(tags=['a', 'b']))
+    assert columns == ('id', 'full_name', 'price', 'created', 'tags')
+    assert params == (1, 'apple', Decimal('1.50'), '2024-01-02T03:04:05Z', '["a","b"]')
+    assert type(params[2]) is Decimal
+
+
+def test_sql_params_options(serializer):
+    assert serializer.to_sql_params(model(name=None), by_alias=False, exclude={'tags'}, exclude_none=True) == (
+        ('id', 'price', 'created'),
+        (1, Decimal('1.50'), '2024-01-02T03:04:05Z'),
+    )
+
+
+def test_sql_params_many(serializer):
+    columns, rows = serializer.to_sql_params([model(), model(id=2, name=None)], many=True)
+    assert columns == ('id', 'full_name', 'price', 'created', 'tags')
+    assert rows == [
+        (1, 'apple', Decimal('1.50'), '2024-01-02T03:04:05Z', '[]'),
+        (2, None, Decimal('1.50'), '2024-01-02T03:04:05Z', '[]'),
+    ]
+    assert serializer.to_sql_params(iter([]), many=True) == ((), [])
+
+
+def test_sql_params_many_field_order(serializer):
+    reordered = BasicModel(tags=[], created=datetime(2024, 1, 1), price=Decimal(2), name='b', id=2)
+    columns, rows = serializer.to_sql_params([model(), reordered], many=True)
+    assert columns == ('id', 'full_name', 'price', 'created', 'tags')
+    assert rows[1] == (2, 'b', Decimal(2), '2024-01-01T00:00:00', '[]')
+
+
+def test_sql_params_many_different_columns(serializer):
+    with pytest.raises(PydanticSerializationError, match=r'All rows must have the same columns, expected .* but row 1'):
+        serializer.to_sql_params([model(), model(name=None)], many=True, exclude_none=True)
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (None, None),
+        (True, True),
+        (1.5, 1.5),
+        (b'abc', b'abc'),
+        (date(2024, 1, 2), '2024-01-02'),
+        (UUID(int=1), '00000000-0000-0000-0000-000000000001'),
+        (Color.red, 'r'),
+        ({'a': [1, 2]}, '{"a":[1,2]}'),
+        ((1, 'a'), '[1,"a"]'),
+    ],
+)
+def test_sql_param_types(value, expected):
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    assert s.to_sql_params({'x': value}) == (('x',), (expected,))
+
+
+def test_sql_params_not_dict():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(
+        PydanticSerializationError, match="SQL parameters can only be built from values serialized to dicts, got 'int'"
+    ):
+        s.to_sql_params(1)
@@ -106,3 +106,9 @@ def test_subclass_str(schema_type, input_value, expected):
     assert type(v) == str
 
     assert s.to_json(input_value) == json.dumps(expected).encode('utf-8')
+
+
+def test_string_format():
+    s = SchemaSerializer(core_schema.string_format_schema('ipv4'))
+    assert s.to_python('127.0.0.1') == '127.0.0.1'
+    assert s.to_json('127.0.0.1') == b'"127.0.0.1"'
@@ -0,0 +1,89 @@
+import datetime
+import re
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, core_schema
+
+
+class Item:
+    def __init__(self, price, when, secret):
+        self.price = price
+        self.when = when
+        self.secret = secret
+
+
+class Order:
+    def __init__(self, items, labels, point):
+        self.items = items
+        self.labels = labels
+        self.point = point
+
+
+def order_schema():
+    item = core_schema.model_schema(
+        Item,
+        core_schema.model_fields_schema(
+            {
+                'price': core_schema.model_field(core_schema.float_schema(), serialization_alias='Price'),
+                'when': core_schema.model_field(core_schema.date_schema()),
+                'secret': core_schema.model_field(core_schema.str_schema(), serialization_exclude=True),
+            }
+        ),
+    )
+    return core_schema.model_schema(
+        Order,
+        core_schema.model_fields_schema(
+            {
+                'items': core_schema.model_field(core_schema.list_schema(item)),
+                'labels': core_schema.model_field(
+                    core_schema.nullable_schema(core_schema.dict_schema(core_schema.str_schema(), item))
+                ),
+                'point': core_schema.model_field(
+                    core_schema.tuple_schema(
+                        [core_schema.str_schema(), core_schema.date_schema()], variadic_item_index=1
+                    )
+                ),
+            }
+        ),
+    )
+
+
+def test_serializer_for_loc():
+    s = SchemaSerializer(order_schema())
+    item = Item(1.5, datetime.date(2024, 1, 2), 'x')
+
+    sub = s.serializer_for_loc(('items', 0))
+    assert sub.loc == ('items', 0)
+    assert repr(sub) == "SubSerializer(loc=('items', 0))"
+    assert sub.to_python(item) == {'Price': 1.5, 'when': datetime.date(2024, 1, 2)}
+    assert sub.to_python(item, mode='json', by_alias=False) == {'price': 1.5, 'when': '2024-01-02'}
+    assert sub.to_json(item, exclude={'when'}) == b'{"Price":1.5}'
+
+    assert s.serializer_for_loc(['labels', 'a', 'when']).to_json(datetime.date(2024, 1, 3)) == b'"2024-01-03"'
+    assert s.serializer_for_loc(('point', 4)).to_python(datetime.date(2024, 1, 4), mode='json') == '2024-01-04'
+    assert s.serializer_for_loc('labels').to_python({'a': item}, mode='json') == {
+        'a': {'Price': 1.5, 'when': '2024-01-02'}
+    }
+
+
+def test_serializer_for_loc_warnings():
+    sub = SchemaSerializer(order_schema()).serializer_for_loc(('items', 0, 'price'))
+    with pytest.warns(UserWarning, match='Expected `float` but got `str`'):
+        assert sub.to_python('x') == 'x'
+    with pytest.raises(PydanticSerializationError, match='Expected `float` but got `str`'):
+        sub.to_json('x', warnings='error')
+
+
+@pytest.mark.parametrize(
+    'loc,message',
+    [
+        (('items', 0, 'secret'), "No serializer found for location ('items', 0, 'secret')"),
+        (('items', 'x'), "No serializer found for location ('items', 'x')"),
+        (('missing',), "No serializer found for location ('missing',)"),
+    ],
+)
+def test_serializer_for_loc_invalid(loc, message):
+    s = SchemaSerializer(order_schema())
+    with pytest.raises(ValueError, match=re.escape(message)):
+        s.serializer_for_loc(loc)
@@ -5,7 +5,7 @@ import pytest
 from dirty_equals import IsStrictDict
 from typing_extensions import TypedDict
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema
 
 
 @pytest.mark.parametrize('extra_behavior_kw', [{}, {'extra_behavior': 'ignore'}, {'extra_behavior': None}])
@@ -127,6 +127,84 @@ def test_alias():
     assert json.loads(s.to_json(value, by_alias=False)) == IsStrictDict(cat=0, dog=1, bird=2)
 
 
+
+def test_alias_fan_out():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'cat': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias=['Meow', 'Purr']),
+                'bird': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    value = {'cat': 0, 'bird': 2}
+    assert s.to_python(value) == IsStrictDict(Purr=0, Meow=0, bird=2)
+    assert s.to_python(value, exclude={'cat'}) == IsStrictDict(bird=2)
+    assert s.to_python(value, by_alias=False) == IsStrictDict(cat=0, bird=2)
+    assert s.to_python(value, mode='json') == IsStrictDict(Purr=0, Meow=0, bird=2)
+    assert s.to_json(value) == b'{"Meow":0,"Purr":0,"bird":2}'
+    assert json.loads(s.to_json(value, by_alias=False)) == IsStrictDict(cat=0, bird=2)
+
+
+def test_alias_profiles():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'user_id': core_schema.typed_dict_field(
+                    core_schema.int_schema(),
+                    serialization_alias='userId',
+                    serialization_alias_profiles={'legacy': ['uid', 'UID'], 'snake': []},
+                ),
+                'full_name': core_schema.typed_dict_field(
+                    core_schema.str_schema(),
+                    serialization_alias='fullName',
+                    serialization_alias_profiles={'legacy': 'name'},
+                ),
+            }
+        )
+    )
+    value = {'user_id': 1, 'full_name': 'Ada'}
+    assert s.to_python(value) == IsStrictDict(userId=1, fullName='Ada')
+    assert s.to_python(value, alias_profile='legacy') == IsStrictDict(UID=1, uid=1, name='Ada')
+    assert s.to_json(value, alias_profile='legacy') == b'{"uid":1,"UID":1,"name":"Ada"}'
+    # fields without aliases for the profile use `serialization_alias`
+    assert s.to_python(value, alias_profile='snake') == IsStrictDict(user_id=1, fullName='Ada')
+    assert s.to_python(value, alias_profile='other') == IsStrictDict(userId=1, fullName='Ada')
+    assert s.to_python(value, alias_profile='legacy', by_alias=False) == IsStrictDict(user_id=1, full_name='Ada')
+
+
+def test_alias_priority_first_non_colliding():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='ID'),
+                'user_id': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias=['id', 'userId']),
+                'name': core_schema.typed_dict_field(
+                    core_schema.str_schema(),
+                    serialization_alias=['ID', 'Name'],
+                    serialization_alias_profiles={'legacy': ['userId', 'Name']},
+                ),
+                'title': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias=['ID', 'userId']),
+            },
+            alias_priority='first_non_colliding',
+        )
+    )
+    value = {'id': 1, 'user_id': 2, 'name': 'Ada', 'title': 'Countess'}
+    assert s.to_python(value) == IsStrictDict(ID=1, userId=2, Name='Ada', title='Countess')
+    assert s.to_json(value) == b'{"ID":1,"userId":2,"Name":"Ada","title":"Countess"}'
+    # `userId` is taken by `user_id`, which is earlier
+    assert s.to_python(value, alias_profile='legacy') == IsStrictDict(ID=1, userId=2, Name='Ada', title='Countess')
+    assert s.to_python(value, by_alias=False) == IsStrictDict(id=1, user_id=2, name='Ada', title='Countess')
+
+
+def test_alias_priority_invalid():
+    schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.int_schema())}, alias_priority='last'
+    )
+    with pytest.raises(SchemaError, match='Invalid alias_priority: `last`, expected `all` or `first_non_colliding`'):
+        SchemaSerializer(schema)
+
+
 def test_exclude_none():
     v = SchemaSerializer(
         core_schema.typed_dict_schema(
@@ -333,3 +411,30 @@ def test_extra_custom_serializer():
     m = {'extra': 'extra'}
 
     assert s.to_python(m) == {'extra': 'extra bam!'}
+
+
+def test_extras_pattern_schemas():
+    schema = core_schema.typed_dict_schema(
+        {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+        extras_pattern_schemas={'^x_': core_schema.int_schema()},
+    )
+    s = SchemaSerializer(schema)
+    # keys matching a pattern are kept by validation, so are included in the output
+    assert s.to_python({'a': 1, 'x_1': 2}) == {'a': 1, 'x_1': 2}
+    assert s.to_json({'a': 1, 'x_1': 2}) == b'{"a":1,"x_1":2}'
+
+
+def test_alias_generator():
+    schema = core_schema.typed_dict_schema(
+        {
+            'user_id': core_schema.typed_dict_field(core_schema.int_schema()),
+            'user_name': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias='name'),
+        },
+        config=core_schema.CoreConfig(alias_generator='to_pascal'),
+    )
+    s = SchemaSerializer(schema)
+
+    value = {'user_id': 1, 'user_name': 'Ada'}
+    assert s.to_python(value) == {'UserId': 1, 'name': 'Ada'}
+    assert json.loads(s.to_json(value)) == {'UserId': 1, 'name': 'Ada'}
+    assert s.to_python(value, by_alias=False) == value
@@ -0,0 +1,40 @@
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+ULID = '01ARZ3NDEKTSV4RRFFQ69G5FAV'
+ULID_UUID = UUID('01563e3a-b5d3-d676-4c61-efb99302bd5b')
+
+
+def test_ulid():
+    s = SchemaSerializer(core_schema.ulid_schema())
+    assert s.to_python(ULID) == ULID
+    assert s.to_python(ULID, mode='json') == ULID
+    assert s.to_json(ULID) == f'"{ULID}"'.encode()
+
+
+def test_ulid_as_uuid():
+    s = SchemaSerializer(core_schema.ulid_schema(as_uuid=True))
+    assert s.to_python(ULID_UUID) == ULID_UUID
+    assert s.to_python(ULID_UUID, mode='json') == ULID
+    assert s.to_json(ULID_UUID) == f'"{ULID}"'.encode()
+
+
+def test_ulid_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.ulid_schema(), core_schema.int_schema()))
+    assert s.to_python({ULID_UUID: 1}, mode='json') == {ULID: 1}
+    assert s.to_json({ULID: 1, ULID_UUID: 2}) == f'{{"{ULID}":1,"{ULID}":2}}'.encode()
+
+
+def test_round_trip():
+    schema = core_schema.ulid_schema(as_uuid=True)
+    value = SchemaValidator(schema).validate_python(ULID.lower())
+    assert SchemaValidator(schema).validate_json(SchemaSerializer(schema).to_json(value)) == value
+
+
+def test_fallback():
+    s = SchemaSerializer(core_schema.ulid_schema())
+    with pytest.warns(UserWarning, match='Expected `ulid` but got `int` - serialized value may not be as expected'):
+        assert s.to_json(123) == b'123'
@@ -105,3 +105,10 @@ def test_url_pickle(value):
     pickled = pickle.dumps(value)
     unpickled = pickle.loads(pickled)
     assert value == unpickled
+
+
+def test_uri_reference():
+    s = SchemaSerializer(core_schema.uri_reference_schema())
+    assert s.to_python('../a?b=1') == '../a?b=1'
+    assert s.to_python('../a?b=1', mode='json') == '../a?b=1'
+    assert s.to_json('../a?b=1') == b'"../a?b=1"'
@@ -56,3 +56,50 @@ def test_any_uuid_key():
 
     assert v.to_python(input_value, mode='json') == {'12345678-1234-5678-1234-567812345678': 1}
     assert v.to_json(input_value) == b'{"12345678-1234-5678-1234-567812345678":1}'
+
+
+@pytest.mark.parametrize(
+    'output_format,expected',
+    [
+        ('canonical', '12345678-1234-5678-1234-567812345678'),
+        ('hex', '12345678123456781234567812345678'),
+        ('urn', 'urn:uuid:12345678-1234-5678-1234-567812345678'),
+        ('braced', '{12345678-1234-5678-1234-567812345678}'),
+    ],
+)
+def test_uuid_output_format(output_format, expected):
+    v = SchemaSerializer(core_schema.uuid_schema(output_format=output_format))
+    value = UUID('12345678-1234-5678-1234-567812345678')
+    assert v.to_python(value) == expected
+    assert v.to_python(value, mode='json') == expected
+    assert v.to_json(value) == f'"{expected}"'.encode()
+
+
+def test_uuid_output_format_bytes():
+    v = SchemaSerializer(core_schema.uuid_schema(output_format='bytes'))
+    value = UUID('12345678-1234-5678-1234-567812345678')
+    assert v.to_python(value) == b'\x12\x34\x56\x78' * 4
+    assert v.to_python(value, mode='json') == '\x124Vx' * 4
+    assert v.to_json(value) == b'"\\u00124Vx\\u00124Vx\\u00124Vx\\u00124Vx"'
+
+    v = SchemaSerializer(core_schema.uuid_schema(output_format='bytes'), config={'ser_json_bytes': 'hex'})
+    assert v.to_python(value, mode='json') == '12345678123456781234567812345678'
+    assert v.to_json(value) == b'"12345678123456781234567812345678"'
+
+
+def test_uuid_output_format_key():
+    schema = core_schema.dict_schema(core_schema.uuid_schema(output_format='hex'), core_schema.int_schema())
+    v = SchemaSerializer(schema)
+    value = {UUID('12345678-1234-5678-1234-567812345678'): 1}
+    assert v.to_python(value) == {'12345678123456781234567812345678': 1}
+    assert v.to_python(value, mode='json') == {'12345678123456781234567812345678': 1}
+    assert v.to_json(value) == b'{"12345678123456781234567812345678":1}'
+
+    schema = core_schema.dict_schema(core_schema.uuid_schema(output_format='bytes'), core_schema.int_schema())
+    v = SchemaSerializer(schema, config={'ser_json_bytes': 'base64'})
+    assert v.to_json(value) == b'{"EjRWeBI0VngSNFZ4EjRWeA==":1}'
+
+
+def test_invalid_output_format():
+    with pytest.raises(Exception, match="Invalid `output_format` value: raw, expected 'canonical', 'hex', 'urn'"):
+        SchemaSerializer(core_schema.uuid_schema(output_format='raw'))
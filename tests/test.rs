@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use _pydantic_core::{SchemaSerializer, SchemaValidator, WarningsArg};
+    use _pydantic_core::{ByAliasArg, CyclesMode, SchemaSerializer, SchemaValidator, WarningsArg};
     use pyo3::prelude::*;
     use pyo3::types::PyDict;
 
@@ -90,15 +90,20 @@ a = A()
                     None,
                     None,
                     None,
-                    true,
+                    ByAliasArg::Bool(true),
                     false,
                     false,
                     false,
                     false,
-                    WarningsArg::Bool(true),
+                    Some(WarningsArg::Bool(true)),
                     None,
                     false,
                     None,
+                    None,
+                    false,
+                    None,
+                    CyclesMode::Error,
+                    false,
                 )
                 .unwrap();
             let serialized: &[u8] = serialized.extract(py).unwrap();
@@ -129,7 +134,7 @@ json_input = '{"a": "something"}'
             let json_input = locals.get_item("json_input").unwrap().unwrap();
             let binding = SchemaValidator::py_new(py, &schema, None)
                 .unwrap()
-                .validate_json(py, &json_input, None, None, None)
+                .validate_json(py, &json_input, None, None, None, None, None, None, None, false)
                 .unwrap();
             let validation_result: Bound<'_, PyAny> = binding.extract(py).unwrap();
             let repr = format!("{}", validation_result.repr().unwrap());
@@ -194,15 +199,20 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     None,
                     None,
+                    ByAliasArg::Bool(false),
                     false,
                     false,
                     false,
                     false,
+                    Some(WarningsArg::Bool(false)),
+                    None,
                     false,
-                    WarningsArg::Bool(false),
+                    None,
                     None,
                     false,
                     None,
+                    CyclesMode::Error,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -215,15 +225,20 @@ dump_json_input_2 = {'a': 'something'}
                     None,
                     None,
                     None,
+                    ByAliasArg::Bool(false),
                     false,
                     false,
                     false,
                     false,
+                    Some(WarningsArg::Bool(false)),
+                    None,
                     false,
-                    WarningsArg::Bool(false),
+                    None,
                     None,
                     false,
                     None,
+                    CyclesMode::Error,
+                    false,
                 )
                 .unwrap();
             let repr = format!("{}", serialization_result.bind(py).repr().unwrap());
@@ -0,0 +1,132 @@
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+class MyModel:
+    pass
+
+
+def model_schema(**kwargs):
+    return core_schema.model_schema(
+        MyModel,
+        core_schema.model_fields_schema(
+            {
+                'first_name': core_schema.model_field(
+                    core_schema.str_schema(), validation_alias='firstName', serialization_alias='firstName'
+                ),
+                'city': core_schema.model_field(
+                    core_schema.str_schema(),
+                    validation_alias=[['address', 'city'], ['city']],
+                    serialization_alias=['city', 'town'],
+                ),
+                'age': core_schema.model_field(core_schema.int_schema()),
+                'secret': core_schema.model_field(core_schema.str_schema(), serialization_exclude=True),
+            },
+            computed_fields=[core_schema.computed_field('full_name', core_schema.str_schema(), alias='fullName')],
+        ),
+        **kwargs,
+    )
+
+
+def test_validator_alias_map():
+    v = SchemaValidator(model_schema())
+    assert v.alias_map() == {
+        'first_name': [['firstName']],
+        'city': [['address', 'city'], ['city']],
+        'age': [['age']],
+        'secret': [['secret']],
+    }
+    assert list(v.alias_map()) == ['first_name', 'city', 'age', 'secret']
+    assert v.alias_map(reverse=True) == {
+        'firstName': 'first_name',
+        ('address', 'city'): 'city',
+        'city': 'city',
+        'age': 'age',
+        'secret': 'secret',
+    }
+
+
+def test_validator_alias_map_config():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'first_name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'items': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias=['data', -1, 'x']),
+            },
+            config={'alias_generator': 'to_camel', 'populate_by_name': True},
+        )
+    )
+    assert v.alias_map() == {
+        'first_name': [['firstName'], ['first_name']],
+        'items': [['data', -1, 'x'], ['items']],
+    }
+    assert v.alias_map(reverse=True) == {
+        'firstName': 'first_name',
+        'first_name': 'first_name',
+        ('data', -1, 'x'): 'items',
+        'items': 'items',
+    }
+
+
+def test_validator_alias_map_wrapped():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('model'),
+            [core_schema.no_info_after_validator_function(lambda m: m, model_schema(), ref='model')],
+        )
+    )
+    assert v.alias_map()['first_name'] == [['firstName']]
+
+
+def test_validator_alias_map_dataclass():
+    v = SchemaValidator(
+        core_schema.dataclass_args_schema(
+            'MyDataclass',
+            [
+                core_schema.dataclass_field('a', core_schema.int_schema(), validation_alias='A'),
+                core_schema.dataclass_field('b', core_schema.int_schema(), init=False),
+            ],
+        )
+    )
+    assert v.alias_map() == {'a': [['A']]}
+
+
+def test_validator_alias_map_not_fields():
+    assert SchemaValidator(core_schema.int_schema()).alias_map() == {}
+
+
+def test_serializer_alias_map():
+    s = SchemaSerializer(model_schema())
+    assert s.alias_map() == {
+        'first_name': ['firstName'],
+        'city': ['city', 'town'],
+        'age': ['age'],
+        'full_name': ['fullName'],
+    }
+    assert list(s.alias_map()) == ['age', 'city', 'first_name', 'full_name']
+    assert s.alias_map(reverse=True) == {
+        'firstName': 'first_name',
+        'city': 'city',
+        'town': 'city',
+        'age': 'age',
+        'fullName': 'full_name',
+    }
+
+
+def test_serializer_alias_map_profile():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.int_schema(), serialization_alias='A', serialization_alias_profiles={'v2': 'aa'}
+                ),
+                'b': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='B'),
+            }
+        )
+    )
+    assert s.alias_map(alias_profile='v2') == {'a': ['aa'], 'b': ['B']}
+    assert s.alias_map(alias_profile='v2', reverse=True) == {'aa': 'a', 'B': 'b'}
+    assert s.alias_map(alias_profile='other') == {'a': ['A'], 'b': ['B']}
+
+
+def test_serializer_alias_map_not_fields():
+    assert SchemaSerializer(core_schema.list_schema(model_schema())).alias_map() == {}
@@ -0,0 +1,94 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+def fields_schema(**fields):
+    return core_schema.typed_dict_schema({k: core_schema.typed_dict_field(v) for k, v in fields.items()})
+
+
+def test_no_hints():
+    v = SchemaValidator(
+        fields_schema(
+            a=core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]),
+            b=core_schema.nullable_schema(core_schema.int_schema()),
+            c=core_schema.str_schema(pattern=r'^(\w+\s?)*$'),
+        )
+    )
+    assert v.analyze() == []
+
+
+def test_large_union():
+    choices = [core_schema.literal_schema([i]) for i in range(11)]
+    v = SchemaValidator(fields_schema(a=core_schema.union_schema(choices)))
+    assert v.analyze() == [
+        {
+            'kind': 'large_union',
+            'path': ('fields', 'a', 'schema'),
+            'message': 'Union with 11 choices and no discriminator, each choice may be tried in turn, '
+            'consider a tagged union',
+        }
+    ]
+    assert v.analyze(max_union_choices=11) == []
+
+
+def test_tagged_union():
+    choices = {i: core_schema.literal_schema([i]) for i in range(20)}
+    assert SchemaValidator(core_schema.tagged_union_schema(choices, discriminator='x')).analyze() == []
+
+
+@pytest.mark.parametrize('inner', [core_schema.nullable_schema(core_schema.int_schema()), core_schema.none_schema()])
+def test_redundant_nullable(inner):
+    v = SchemaValidator(core_schema.list_schema(core_schema.nullable_schema(inner)))
+    [hint] = v.analyze()
+    assert hint['kind'] == 'redundant_nullable'
+    assert hint['path'] == ('items_schema',)
+    assert hint['message'] == f"Nullable schema wraps a `{inner['type']}` schema, which already allows `None`"
+
+
+@pytest.mark.parametrize(
+    'pattern,flagged',
+    [
+        (r'^(a+)+$', True),
+        (r'^(\w+\s?)*$', True),
+        (r'(a|b{2,})*', True),
+        (r'^(ab?)+$', False),
+        (r'^\w+@\w+\.com$', False),
+        (r'(?<=a)b+', False),
+    ],
+)
+def test_backtracking_pattern(pattern, flagged):
+    schema = core_schema.str_schema(pattern=pattern, regex_engine='python-re')
+    hints = SchemaValidator(schema).analyze()
+    if flagged:
+        assert [h['kind'] for h in hints] == ['backtracking_pattern']
+        assert hints[0]['path'] == ()
+        assert hints[0]['message'].startswith(f'Pattern `{pattern}` has a repeated group containing a repetition')
+    else:
+        assert hints == []
+
+
+def test_backtracking_pattern_config():
+    schema = fields_schema(a=core_schema.str_schema(pattern=r'(a+)+'))
+    assert SchemaValidator(schema).analyze() == []
+    assert len(SchemaValidator(schema, config={'regex_engine': 'python-re'}).analyze()) == 1
+
+    schema = core_schema.model_schema(
+        type('MyModel', (), {}),
+        core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.str_schema(pattern=r'(a+)+'))}),
+        config={'regex_engine': 'python-re'},
+    )
+    [hint] = SchemaValidator(schema).analyze()
+    assert hint['path'] == ('schema', 'fields', 'a', 'schema')
+
+
+def test_compiled_pattern():
+    v = SchemaValidator(core_schema.str_schema(pattern=re.compile(r'(a+)+')))
+    assert [h['kind'] for h in v.analyze()] == ['backtracking_pattern']
+
+
+def test_metadata_ignored():
+    schema = core_schema.int_schema(metadata={'x': core_schema.nullable_schema(core_schema.none_schema())})
+    assert SchemaValidator(schema).analyze() == []
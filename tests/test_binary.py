@@ -0,0 +1,225 @@
+from datetime import date, datetime
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import (
+    PydanticSerializationError,
+    SchemaSerializer,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    register_format_backend,
+    unregister_format_backend,
+)
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture(scope='module')
+def model_schema():
+    return core_schema.model_schema(
+        Model,
+        core_schema.model_fields_schema(
+            {
+                'id': core_schema.model_field(core_schema.int_schema()),
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'tags': core_schema.model_field(core_schema.set_schema(core_schema.str_schema())),
+                'created': core_schema.model_field(core_schema.datetime_schema()),
+                'price': core_schema.model_field(core_schema.nullable_schema(core_schema.decimal_schema())),
+                'data': core_schema.model_field(core_schema.bytes_schema()),
+                'extra': core_schema.model_field(core_schema.any_schema()),
+            }
+        ),
+    )
+
+
+def test_model_round_trip(model_schema):
+    s = SchemaSerializer(model_schema)
+    v = SchemaValidator(model_schema)
+    m = v.validate_python(
+        {
+            'id': 2**70,
+            'name': 'apple',
+            'tags': {'fruit'},
+            'created': datetime(2024, 1, 1, 12),
+            'price': Decimal('1.50'),
+            'data': b'\x00\x01',
+            'extra': [1, -2, 3.5, None, True, 'x', {'a': [2**64 - 1]}],
+        }
+    )
+
+    data = s.to_binary(m)
+    assert isinstance(data, bytes)
+
+    m2 = v.from_binary(data)
+    assert isinstance(m2, Model)
+    assert m2.__dict__ == m.__dict__
+
+
+@pytest.mark.parametrize(
+    'schema,value',
+    [
+        (core_schema.int_schema(), -1),
+        (core_schema.int_schema(), 2**63),
+        (core_schema.int_schema(), -(2**100)),
+        (core_schema.float_schema(), 1.5),
+        (core_schema.str_schema(), 'caf\xe9'),
+        (core_schema.bool_schema(), False),
+        (core_schema.none_schema(), None),
+        (core_schema.date_schema(), date(2024, 2, 29)),
+        (core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]), (1, 'a')),
+        (core_schema.dict_schema(core_schema.int_schema(), core_schema.date_schema()), {1: date(2024, 1, 1)}),
+        (core_schema.any_schema(), {'a': [1, {'b': None}]}),
+    ],
+)
+def test_round_trip(schema, value):
+    assert SchemaValidator(schema).from_binary(SchemaSerializer(schema).to_binary(value)) == value
+
+
+def test_to_binary_options():
+    schema = core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema(), serialization_alias='A'),
+            'b': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.int_schema())),
+        }
+    )
+    s = SchemaSerializer(schema)
+    any_validator = SchemaValidator(core_schema.any_schema())
+    assert any_validator.from_binary(s.to_binary({'a': 1, 'b': None})) == {'A': 1, 'b': None}
+    assert any_validator.from_binary(s.to_binary({'a': 1, 'b': None}, by_alias=False, exclude_none=True)) == {'a': 1}
+    assert any_validator.from_binary(s.to_binary({'a': 1, 'b': 2}, exclude={'b'})) == {'A': 1}
+
+
+def test_to_binary_unknown_type():
+    s = SchemaSerializer(core_schema.any_schema())
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        s.to_binary(object())
+    assert SchemaValidator(core_schema.any_schema()).from_binary(s.to_binary(object(), fallback=lambda _: 'x')) == 'x'
+
+
+def test_from_binary_validation_error():
+    data = SchemaSerializer(core_schema.any_schema()).to_binary(['1', 'x'])
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.from_binary(SchemaSerializer(core_schema.any_schema()).to_binary(['1', 2])) == [1, 2]
+    with pytest.raises(ValidationError) as exc_info:
+        v.from_binary(data)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    # values are validated as if they were JSON
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.from_binary(data, strict=True)
+
+
+@pytest.mark.parametrize(
+    'data,message',
+    [
+        (b'', 'Invalid binary data: IO error while reading marker'),
+        (b'\xc1', 'Invalid binary data: wrong msgpack marker'),
+        (b'\x01\x02', 'Invalid binary data: unexpected data after the end of the value'),
+        # map with an int key
+        (b'\x81\x01\x02', 'Invalid binary data: map keys must be strings'),
+        # raw bytes aren't used by `to_binary`
+        (b'\xc4\x01x', 'Invalid binary data: invalid type: byte array'),
+    ],
+)
+def test_from_binary_invalid(data, message):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValueError, match=message):
+        v.from_binary(data)
+
+
+@pytest.mark.parametrize(
+    'media_type',
+    ['application/json', 'Application/JSON; charset=utf-8', 'application/problem+json', 'application/vnd.api+json'],
+)
+def test_serialize_json(media_type):
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    assert s.serialize({'a': 1}, media_type=media_type) == s.to_json({'a': 1}) == b'{"a":1}'
+
+
+@pytest.mark.parametrize(
+    'media_type', ['application/msgpack', 'application/x-msgpack', 'application/vnd.msgpack', 'application/foo+msgpack']
+)
+def test_serialize_msgpack(media_type):
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    data = s.serialize({'a': 1, 'b': 2}, media_type=media_type, exclude={'b'})
+    assert data == s.to_binary({'a': 1}) == b'\x81\xa1a\x01'
+
+
+def test_serialize_default_media_type():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.date_schema()))
+    assert s.serialize([date(2024, 1, 1)]) == b'["2024-01-01"]'
+
+
+def test_serialize_unsupported_media_type():
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(ValueError, match="^Unsupported media type 'application/cbor', expected one of: "):
+        s.serialize(1, media_type='application/cbor')
+
+
+@pytest.fixture
+def text_backend():
+    register_format_backend('text', lambda data: repr(data).encode(), media_types=['text/x-python'])
+    yield
+    assert unregister_format_backend('text')
+
+
+def test_format_backend(text_backend):
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.date_schema()))
+    value = {'a': date(2024, 1, 1), 'b': date(2024, 1, 2)}
+    assert s.serialize(value, media_type='text/x-python') == b"{'a': '2024-01-01', 'b': '2024-01-02'}"
+    assert s.serialize(value, media_type='Text/X-Python; charset=utf-8', exclude={'b'}) == b"{'a': '2024-01-01'}"
+    # builtin formats are unaffected
+    assert s.serialize(value, media_type='application/json') == b'{"a":"2024-01-01","b":"2024-01-02"}'
+
+
+def test_format_backend_replace(text_backend):
+    s = SchemaSerializer(core_schema.int_schema())
+    register_format_backend('text', lambda data: str(data).encode(), media_types=['text/x-python', 'text/plain'])
+    assert s.serialize(1, media_type='text/x-python') == b'1'
+    assert s.serialize(2, media_type='text/plain') == b'2'
+
+
+def test_format_backend_unregister():
+    register_format_backend('text', lambda data: b'', media_types=['text/plain'])
+    assert unregister_format_backend('text')
+    assert not unregister_format_backend('text')
+    with pytest.raises(ValueError, match="Unsupported media type 'text/plain'"):
+        SchemaSerializer(core_schema.int_schema()).serialize(1, media_type='text/plain')
+
+
+def test_format_backend_unsupported_lists_registered(text_backend):
+    with pytest.raises(ValueError, match="'application/bson', 'text/x-python'$"):
+        SchemaSerializer(core_schema.int_schema()).serialize(1, media_type='application/cbor')
+
+
+def test_format_backend_invalid(text_backend):
+    with pytest.raises(ValueError, match="Media type 'application/json' is serialized by pydantic-core"):
+        register_format_backend('json2', lambda data: b'', media_types=['application/json'])
+    with pytest.raises(ValueError, match="Media type 'application/problem\\+json' is serialized by pydantic-core"):
+        register_format_backend('json2', lambda data: b'', media_types=['application/problem+json'])
+    with pytest.raises(ValueError, match="Media type 'text/x-python' is already registered by format backend 'text'"):
+        register_format_backend('other', lambda data: b'', media_types=['text/x-python'])
+    with pytest.raises(ValueError, match='Format backends must have at least one media type'):
+        register_format_backend('other', lambda data: b'', media_types=[])
+    with pytest.raises(TypeError, match='Format backend encoder must be callable'):
+        register_format_backend('other', b'', media_types=['text/plain'])
+
+
+def test_format_backend_must_return_bytes():
+    register_format_backend('bad', lambda data: data, media_types=['application/x-bad'])
+    try:
+        with pytest.raises(TypeError, match="Format backend 'bad' must return bytes, got 'int'"):
+            SchemaSerializer(core_schema.int_schema()).serialize(1, media_type='application/x-bad')
+    finally:
+        unregister_format_backend('bad')
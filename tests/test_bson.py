@@ -0,0 +1,156 @@
+import re
+from datetime import date, datetime, timedelta, timezone
+from decimal import Decimal
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture(scope='module')
+def model_schema():
+    return core_schema.model_schema(
+        Model,
+        core_schema.model_fields_schema(
+            {
+                'id': core_schema.model_field(core_schema.int_schema()),
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'created': core_schema.model_field(core_schema.datetime_schema()),
+                'price': core_schema.model_field(core_schema.decimal_schema()),
+                'key': core_schema.model_field(core_schema.uuid_schema()),
+                'data': core_schema.model_field(core_schema.bytes_schema()),
+                'day': core_schema.model_field(core_schema.date_schema()),
+                'tags': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+            }
+        ),
+    )
+
+
+def test_model_round_trip(model_schema):
+    s = SchemaSerializer(model_schema)
+    v = SchemaValidator(model_schema)
+    m = v.validate_python(
+        {
+            'id': 2**40,
+            'name': 'apple',
+            'created': datetime(2024, 1, 1, 12, 30, 15, 123000, tzinfo=timezone.utc),
+            'price': Decimal('1.50'),
+            'key': UUID('12345678-1234-5678-1234-567812345678'),
+            'data': b'\x00\xff',
+            'day': date(2024, 2, 29),
+            'tags': ['fruit', 'red'],
+        }
+    )
+
+    data = s.to_bson(m)
+    assert isinstance(data, bytes)
+    m2 = v.validate_bson(data)
+    assert isinstance(m2, Model)
+    assert m2.__dict__ == m.__dict__
+
+
+def test_to_bson_bytes():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    # {"a": int32 1}
+    assert s.to_bson({'a': 1}) == b'\x0c\x00\x00\x00\x10a\x00\x01\x00\x00\x00\x00'
+    # {"a": int64 2**40}
+    assert s.to_bson({'a': 2**40}) == b'\x10\x00\x00\x00\x12a\x00\x00\x00\x00\x00\x00\x01\x00\x00\x00'
+
+
+def test_datetimes():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.datetime_schema()))
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    tz = timezone(timedelta(hours=2))
+    data = s.to_bson(
+        {
+            'naive': datetime(2024, 1, 1, 12, 0, 0, 999999),
+            'aware': datetime(2024, 1, 1, 12, tzinfo=tz),
+            'old': datetime(1900, 1, 1, tzinfo=timezone.utc),
+        }
+    )
+    assert v.validate_bson(data) == {
+        # naive datetimes are assumed to be UTC, microseconds are truncated to milliseconds
+        'naive': datetime(2024, 1, 1, 12, 0, 0, 999000, tzinfo=timezone.utc),
+        'aware': datetime(2024, 1, 1, 10, tzinfo=timezone.utc),
+        'old': datetime(1900, 1, 1, tzinfo=timezone.utc),
+    }
+
+
+def test_json_fallback():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    data = s.to_bson({'td': timedelta(seconds=90), 'set': {1}, 'key': {1: 'x'}, 'd': date(2024, 1, 1)})
+    assert v.validate_bson(data) == {'td': 'PT1M30S', 'set': [1], 'key': {'1': 'x'}, 'd': '2024-01-01'}
+
+
+class ObjectId:
+    """Stand-in for PyMongo's `bson.ObjectId`"""
+
+    __module__ = 'bson.objectid'
+
+    def __init__(self, binary: bytes):
+        self.binary = binary
+
+
+def test_object_id():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema()))
+    data = s.to_bson({'_id': ObjectId(bytes(range(12)))})
+    assert data[4] == 0x07
+    v = SchemaValidator(core_schema.typed_dict_schema({'_id': core_schema.typed_dict_field(core_schema.str_schema())}))
+    assert v.validate_bson(data) == {'_id': '000102030405060708090a0b'}
+
+
+def test_validate_bson_errors():
+    s = SchemaSerializer(core_schema.any_schema())
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    assert v.validate_bson(s.to_bson({'a': '1'})) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_bson(s.to_bson({'a': 'x'}))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    # validated like python data
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_bson(s.to_bson({'a': '1'}), strict=True)
+
+
+@pytest.mark.parametrize(
+    'data,message',
+    [
+        (b'', 'Invalid BSON data: '),
+        (b'\x05\x00\x00\x00\x00\x01', 'Invalid BSON data: unexpected data after the end of the document'),
+        # {"a": MinKey}
+        (b'\x08\x00\x00\x00\xffa\x00\x00', 'Invalid BSON data: unsupported element type MinKey'),
+    ],
+)
+def test_validate_bson_invalid(data, message):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValueError, match=re.escape(message)):
+        v.validate_bson(data)
+
+
+def test_to_bson_not_a_dict():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(PydanticSerializationError, match="BSON documents must be dicts, got 'list'"):
+        s.to_bson([1])
+
+
+def test_to_bson_big_int():
+    s = SchemaSerializer(core_schema.any_schema())
+    with pytest.raises(PydanticSerializationError, match='BSON ints must fit in 64 bits, got 18446744073709551616'):
+        s.to_bson({'a': 2**64})
+
+
+def test_serialize_media_type():
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.serialize({'a': b'x'}, media_type='application/bson') == s.to_bson({'a': b'x'})
@@ -0,0 +1,81 @@
+import pytest
+
+from pydantic_core import PydanticInternalError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+@pytest.fixture(scope='module')
+def panic_exception():
+    # pyo3 creates its `PanicException` type the first time it checks an error raised by python code
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError):
+        v.validate_python('x')
+    (exc_type,) = [c for c in BaseException.__subclasses__() if c.__name__ == 'PanicException']
+    return exc_type
+
+
+@pytest.fixture
+def panicking_function(panic_exception):
+    def f(value, *args):
+        # pyo3 resumes the panic when this propagates back into rust
+        raise panic_exception('broken invariant')
+
+    return f
+
+
+def test_panic_not_caught(panic_exception, panicking_function):
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(panicking_function))
+    with pytest.raises(panic_exception, match='broken invariant'):
+        v.validate_python(1)
+
+
+@pytest.mark.parametrize('method', ['validate_python', 'validate_json'])
+def test_validation(panicking_function, method):
+    schema = core_schema.list_schema(core_schema.no_info_plain_validator_function(panicking_function))
+    v = SchemaValidator(schema, {'catch_panics': True})
+    match = r'^Internal error in `list\[function-plain\[f\(\)\]\]`'
+    with pytest.raises(PydanticInternalError, match=match) as exc_info:
+        getattr(v, method)('[1]' if method == 'validate_json' else [1])
+    assert exc_info.value.message == 'broken invariant'
+    # the name of the root validator, the panic was in the function validator within it
+    assert exc_info.value.root_schema_node == 'list[function-plain[f()]]'
+    # the panic was raised in python, not at a location in the rust source
+    assert exc_info.value.location is None
+
+    # the validator is still usable afterwards
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'catch_panics': True})
+    assert v.validate_python([1, '2']) == [1, 2]
+
+
+def test_validate_assignment(panicking_function):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'x': core_schema.model_field(core_schema.no_info_plain_validator_function(panicking_function))}
+        ),
+        {'catch_panics': True},
+    )
+    with pytest.raises(PydanticInternalError, match='broken invariant'):
+        v.validate_assignment({'x': 1}, 'x', 2)
+
+
+@pytest.mark.parametrize('method', ['to_python', 'to_json'])
+def test_serialization(panicking_function, method):
+    s = SchemaSerializer(
+        core_schema.any_schema(
+            serialization=core_schema.plain_serializer_function_ser_schema(panicking_function),
+        ),
+        {'catch_panics': True},
+    )
+    with pytest.raises(PydanticInternalError, match='broken invariant') as exc_info:
+        getattr(s, method)(1)
+    assert exc_info.value.root_schema_node == 'plain_function[f]'
+
+
+def test_internal_error():
+    e = PydanticInternalError('boom', 'int', 'src/validators/int.rs:1:2')
+    assert str(e) == 'Internal error in `int` at src/validators/int.rs:1:2: boom'
+    assert repr(e) == (
+        'PydanticInternalError(message="boom", root_schema_node="int", '
+        'location=Some("src/validators/int.rs:1:2"))'
+    )
+    assert str(PydanticInternalError('boom', 'int')) == 'Internal error in `int`: boom'
+    assert isinstance(e, Exception)
@@ -1,10 +1,12 @@
 import math
+import platform
 import re
+import sys
 
 import pytest
 from dirty_equals import FunctionCheck, HasAttributes, IsInstance
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError
 
 from .conftest import Err, plain_repr
 
@@ -68,6 +70,55 @@ def test_field_priority_model():
     assert v.isinstance_python({'f': 'test long'}) is False
 
 
+@pytest.mark.parametrize(
+    'model_config,expected_config',
+    [
+        (None, None),
+        ({'str_to_upper': True}, {'str_to_upper': True}),
+        ({'config_merge': 'replace', 'str_to_upper': True}, {'config_merge': 'replace', 'str_to_upper': True}),
+        ({'config_merge': 'merge'}, {'config_merge': 'merge', 'str_max_length': 5}),
+        (
+            {'config_merge': 'merge', 'str_max_length': 10, 'str_to_upper': True},
+            {'config_merge': 'merge', 'str_max_length': 10, 'str_to_upper': True},
+        ),
+    ],
+)
+def test_config_merge(model_config, expected_config):
+    model_schema = {
+        'type': 'model',
+        'cls': MyModel,
+        'ref': 'MyModel',
+        'schema': {'type': 'model-fields', 'fields': {'f': {'type': 'model-field', 'schema': {'type': 'str'}}}},
+    }
+    if model_config is not None:
+        model_schema['config'] = model_config
+    v = SchemaValidator(
+        {
+            'type': 'definitions',
+            'schema': {'type': 'list', 'items_schema': {'type': 'definition-ref', 'schema_ref': 'MyModel'}, 'ref': 'L'},
+            'definitions': [model_schema],
+        },
+        {'str_max_length': 5},
+    )
+    assert v.get_effective_configs() == {'MyModel': expected_config, 'L': {'str_max_length': 5}}
+    max_length = (expected_config or {}).get('str_max_length')
+    assert v.isinstance_python([{'f': 'test long'}]) is (max_length != 5)
+    to_upper = (expected_config or {}).get('str_to_upper', False)
+    assert v.validate_python([{'f': 'test'}])[0].f == ('TEST' if to_upper else 'test')
+
+
+def test_config_merge_invalid():
+    with pytest.raises(SchemaError, match="Invalid config_merge: foobar, expected 'replace' or 'merge'"):
+        SchemaValidator(
+            {
+                'type': 'model',
+                'cls': MyModel,
+                'config': {'config_merge': 'foobar'},
+                'schema': {'type': 'model-fields', 'fields': {}},
+            }
+        )
+
+
 @pytest.mark.parametrize(
     'config,float_field_schema,input_value,expected',
     [
@@ -151,3 +202,42 @@ def test_cache_strings():
 
     v = SchemaValidator({'type': 'str'}, {'cache_strings': 'keys'})
     assert "cache_strings='keys'" in plain_repr(v)
+
+
+@pytest.mark.skipif(platform.python_implementation() != 'CPython', reason='relies on CPython str internals')
+@pytest.mark.parametrize('schema', [{'type': 'str'}, {'type': 'str', 'max_length': 10}])
+def test_val_json_cache_utf8(schema):
+    v = SchemaValidator(schema, {'cache_strings': False})
+    plain = v.validate_json('"café"')
+    assert plain == 'café'
+
+    v = SchemaValidator(schema, {'cache_strings': False, 'val_json_cache_utf8': True})
+    cached = v.validate_json('"café"')
+    assert cached == 'café'
+    # the UTF-8 buffer is stored on the string object, so it's bigger
+    assert sys.getsizeof(cached) > sys.getsizeof(plain)
+
+    # python input is unaffected
+    assert v.validate_python('café') == 'café'
+
+
+class MyStr(str): ...
+
+
+@pytest.mark.parametrize('schema', [{'type': 'str'}, {'type': 'str', 'max_length': 10}])
+def test_intern_strings(schema):
+    values = ['GB', 'US', MyStr('GB')] + [''.join(['U', 'S']) for _ in range(3)]
+    v = SchemaValidator({'type': 'list', 'items_schema': schema})
+    output = v.validate_python(values)
+    assert output == ['GB', 'US', 'GB', 'US', 'US', 'US']
+    assert output[1] is not output[3]
+
+    v = SchemaValidator({'type': 'list', 'items_schema': schema}, {'intern_strings': True})
+    output = v.validate_python(values)
+    assert output == ['GB', 'US', 'GB', 'US', 'US', 'US']
+    assert all(s is output[1] for s in output[3:])
+    assert output[2] is output[0]
+
+    output = v.validate_json('["abcdefghij", "abcdefghij", "x", "x"]')
+    assert output[0] is output[1]
+    assert output[2] is output[3]
@@ -15,6 +15,7 @@ from pydantic_core import (
     CoreConfig,
     PydanticCustomError,
     PydanticKnownError,
+    PydanticMultipleErrors,
     PydanticOmit,
     SchemaValidator,
     ValidationError,
@@ -253,6 +254,53 @@ def test_pydantic_custom_error_type_raise_custom_no_ctx(ctx: Optional[dict]):
     ]
 
 
+def test_pydantic_multiple_errors_raise():
+    def f(input_value):
+        raise PydanticMultipleErrors(
+            [
+                (('a', 0), PydanticCustomError('my_error', 'my message {n}', {'n': 1})),
+                ('b', PydanticKnownError('int_type')),
+                PydanticCustomError('whole_error', 'whole message'),
+            ]
+        )
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(core_schema.no_info_plain_validator_function(f)),
+                'y': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'x': {'a': [1]}, 'y': 'wrong'})
+    # insert_assert(exc_info.value.errors(include_url=False))
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'my_error', 'loc': ('x', 'a', 0), 'msg': 'my message 1', 'input': {'a': [1]}, 'ctx': {'n': 1}},
+        {'type': 'int_type', 'loc': ('x', 'b'), 'msg': 'Input should be a valid integer', 'input': {'a': [1]}},
+        {'type': 'whole_error', 'loc': ('x',), 'msg': 'whole message', 'input': {'a': [1]}},
+        {
+            'type': 'int_parsing',
+            'loc': ('y',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'wrong',
+        },
+    ]
+
+
+def test_pydantic_multiple_errors():
+    e = PydanticMultipleErrors([PydanticKnownError('int_type'), (['a', 1], PydanticCustomError('my_error', 'msg'))])
+    assert isinstance(e, ValueError)
+    assert str(e) == 'Input should be a valid integer; msg'
+    assert [(loc, error.type) for loc, error in e.errors] == [((), 'int_type'), (('a', 1), 'my_error')]
+
+    with pytest.raises(ValueError, match='`errors` must not be empty'):
+        PydanticMultipleErrors([])
+    with pytest.raises(TypeError, match='errors must be `PydanticCustomError` or `PydanticKnownError` instances'):
+        PydanticMultipleErrors([ValueError('foo')])
+
+
 all_errors = [
     ('no_such_attribute', "Object has no attribute 'wrong_name'", {'attribute': 'wrong_name'}),
     ('json_invalid', 'Invalid JSON: foobar', {'error': 'foobar'}),
@@ -260,6 +308,8 @@ all_errors = [
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
+    ('dependent_field_missing', "Field required when 'a' is set", {'field_name': 'a'}),
+    ('conflicting_field', "Field can't be set when 'a' is set", {'field_name': 'a'}),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
     ('dataclass_type', 'Input should be a dictionary or an instance of Foobar', {'class_name': 'Foobar'}),
     ('missing', 'Field required', None),
@@ -291,6 +341,11 @@ all_errors = [
     ('string_sub_type', 'Input should be a string, not an instance of a subclass of str', None),
     ('string_unicode', 'Input should be a valid string, unable to parse raw data as a unicode string', None),
     ('string_pattern_mismatch', "String should match pattern 'foo'", {'pattern': 'foo'}),
+    (
+        'string_format_mismatch',
+        "String should match format 'foo', Foobar",
+        {'format': 'foo', 'error': 'Foobar'},
+    ),
     ('string_too_short', 'String should have at least 42 characters', {'min_length': 42}),
     ('string_too_short', 'String should have at least 1 character', {'min_length': 1}),
     ('string_too_long', 'String should have at most 42 characters', {'max_length': 42}),
@@ -366,9 +421,19 @@ all_errors = [
     ('url_too_long', 'URL should have at most 42 characters', {'max_length': 42}),
     ('url_too_long', 'URL should have at most 1 character', {'max_length': 1}),
     ('url_scheme', 'URL scheme should be "foo", "bar" or "spam"', {'expected_schemes': '"foo", "bar" or "spam"'}),
+    ('url_host', "URL host should be 'a.com' or '*.b.com'", {'expected_hosts': "'a.com' or '*.b.com'"}),
+    ('url_userinfo', 'URL should not include a username or password', None),
+    ('url_idna', 'URL host is not allowed, Foobar', {'error': 'Foobar'}),
+    ('url_component_too_long', 'URL path should have at most 42 characters', {'component': 'path', 'max_length': 42}),
+    ('url_component_too_long', 'URL host should have at most 1 character', {'component': 'host', 'max_length': 1}),
     ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
     ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
     ('uuid_version', 'UUID version 42 expected', {'expected_version': 42}),
+    ('ulid_type', 'ULID input should be a string, bytes or UUID object', None),
+    ('ulid_parsing', 'Input should be a valid ULID, Foobar', {'error': 'Foobar'}),
+    ('hostname_parsing', 'Input should be a valid hostname, Foobar', {'error': 'Foobar'}),
+    ('domain_parsing', 'Input should be a valid domain name, Foobar', {'error': 'Foobar'}),
+    ('phone_parsing', 'Input should be a valid phone number, Foobar', {'error': 'Foobar'}),
     ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
     ('decimal_parsing', 'Input should be a valid decimal', None),
     ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
@@ -385,6 +450,9 @@ all_errors = [
         'Decimal input should have no more than 1 digit before the decimal point',
         {'whole_digits': 1},
     ),
+    ('money_type', 'Money input should be a dictionary with an amount and currency or a string', None),
+    ('money_parsing', "Input should be an amount and a currency code, e.g. '12.34 USD'", None),
+    ('money_currency', 'Input should be a valid ISO 4217 currency code', None),
 ]
 
 
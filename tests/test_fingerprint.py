@@ -0,0 +1,101 @@
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+def fields_schema(*names, **kwargs):
+    return core_schema.typed_dict_schema(
+        {name: core_schema.typed_dict_field(core_schema.int_schema()) for name in names}, **kwargs
+    )
+
+
+def test_equal():
+    v1 = SchemaValidator(core_schema.int_schema(gt=1, strict=True))
+    v2 = SchemaValidator(core_schema.int_schema(strict=True, gt=1))
+    assert v1 == v2
+    assert not v1 != v2
+    assert v1.fingerprint() == v2.fingerprint()
+    assert hash(v1) == hash(v2)
+    assert len({v1, v2}) == 1
+
+
+@pytest.mark.parametrize(
+    'schema1,schema2',
+    [
+        (core_schema.int_schema(), core_schema.str_schema()),
+        (core_schema.int_schema(gt=1), core_schema.int_schema(gt=2)),
+        # equal in python, but validate different values
+        (core_schema.literal_schema([1]), core_schema.literal_schema([True])),
+        (core_schema.int_schema(), core_schema.int_schema(strict=False)),
+        (fields_schema('a', 'b'), fields_schema('a')),
+        (fields_schema('a', 'b'), fields_schema('b', 'a')),
+        (
+            core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]),
+            core_schema.union_schema([core_schema.str_schema(), core_schema.int_schema()]),
+        ),
+    ],
+)
+def test_not_equal(schema1, schema2):
+    v1 = SchemaValidator(schema1)
+    v2 = SchemaValidator(schema2)
+    assert v1 != v2
+    assert not v1 == v2
+    assert v1.fingerprint() != v2.fingerprint()
+
+
+def test_ignored_keys():
+    v1 = SchemaValidator(core_schema.int_schema(metadata={'a': 1}, serialization=core_schema.to_string_ser_schema()))
+    v2 = SchemaValidator(core_schema.int_schema())
+    assert v1 == v2
+    assert v1.fingerprint() == v2.fingerprint()
+
+
+def test_fields_named_like_ignored_keys():
+    v1 = SchemaValidator(fields_schema('metadata', 'type'))
+    v2 = SchemaValidator(fields_schema('metadata'))
+    assert v1 != v2
+    assert v1.fingerprint() != v2.fingerprint()
+
+
+def test_config():
+    schema = core_schema.str_schema()
+    assert SchemaValidator(schema, {'str_max_length': 5}) != SchemaValidator(schema, {'str_max_length': 6})
+    assert SchemaValidator(schema, {}) == SchemaValidator(schema)
+    assert SchemaValidator(schema, {}).fingerprint() == SchemaValidator(schema).fingerprint()
+
+    v1 = SchemaValidator(schema, {'str_max_length': 5, 'str_to_lower': True})
+    v2 = SchemaValidator(schema, {'str_to_lower': True, 'str_max_length': 5})
+    assert v1 == v2
+    assert v1.fingerprint() == v2.fingerprint()
+
+
+def f(input_value, info):
+    return input_value
+
+
+def g(input_value, info):
+    return input_value
+
+
+def test_functions():
+    v1 = SchemaValidator(core_schema.with_info_plain_validator_function(f))
+    v2 = SchemaValidator(core_schema.with_info_plain_validator_function(f))
+    v3 = SchemaValidator(core_schema.with_info_plain_validator_function(g))
+    assert v1 == v2
+    assert v1.fingerprint() == v2.fingerprint()
+    assert v1 != v3
+    assert v1.fingerprint() != v3.fingerprint()
+
+
+def test_fingerprint_format():
+    fingerprint = SchemaValidator(core_schema.int_schema()).fingerprint()
+    assert len(fingerprint) == 16
+    int(fingerprint, 16)
+
+
+def test_other_types():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v != 1
+    assert (v == 1) is False
+    with pytest.raises(TypeError):
+        v < v
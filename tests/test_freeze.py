@@ -0,0 +1,77 @@
+from concurrent.futures import ThreadPoolExecutor
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+
+def make_schema():
+    return core_schema.typed_dict_schema(
+        {
+            'a': core_schema.typed_dict_field(core_schema.int_schema()),
+            'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+        }
+    )
+
+
+def test_freeze_validator():
+    v = SchemaValidator(make_schema())
+    assert not v.is_frozen
+    assert v.freeze() is v
+    assert v.is_frozen
+    assert v.freeze(audit=True) is v
+    assert v.validate_python({'a': '1', 'b': ['x']}) == {'a': 1, 'b': ['x']}
+
+
+def test_freeze_shared_between_threads():
+    v = SchemaValidator(make_schema()).freeze()
+    s = SchemaSerializer(make_schema()).freeze()
+
+    def round_trip(i):
+        return v.validate_json(s.to_json({'a': i, 'b': [str(i)] * 10}))
+
+    with ThreadPoolExecutor(4) as executor:
+        outputs = list(executor.map(round_trip, range(100)))
+    assert outputs == [{'a': i, 'b': [str(i)] * 10} for i in range(100)]
+
+
+def test_freeze_audit_validator():
+    schema = make_schema()
+    config = core_schema.CoreConfig(strict=False)
+    v = SchemaValidator(schema, config).freeze(audit=True)
+    assert v.validate_python({'a': 1, 'b': []}) == {'a': 1, 'b': []}
+
+    schema['fields']['a']['metadata'] = {'changed': True}
+    message = r'The schema or config of a frozen `SchemaValidator` was mutated after `freeze\(\)`'
+    with pytest.raises(RuntimeError, match=message):
+        v.validate_python({'a': 1, 'b': []})
+    with pytest.raises(RuntimeError, match=message):
+        v.validate_json('{"a": 1, "b": []}')
+    with pytest.raises(RuntimeError, match=message):
+        v.validate_assignment({'a': 1, 'b': []}, 'a', 2)
+
+    del schema['fields']['a']['metadata']
+    assert v.validate_python({'a': 1, 'b': []}) == {'a': 1, 'b': []}
+    config['strict'] = True
+    with pytest.raises(RuntimeError, match=message):
+        v.validate_python({'a': 1, 'b': []})
+
+
+def test_freeze_audit_serializer():
+    schema = make_schema()
+    s = SchemaSerializer(schema).freeze(audit=True)
+    assert s.to_json({'a': 1, 'b': []}) == b'{"a":1,"b":[]}'
+
+    schema['fields']['b']['serialization_exclude'] = True
+    message = r'The schema or config of a frozen `SchemaSerializer` was mutated after `freeze\(\)`'
+    with pytest.raises(RuntimeError, match=message):
+        s.to_python({'a': 1, 'b': []})
+    with pytest.raises(RuntimeError, match=message):
+        s.to_json({'a': 1, 'b': []})
+
+
+def test_mutation_without_audit():
+    schema = make_schema()
+    v = SchemaValidator(schema).freeze()
+    schema['fields']['a']['metadata'] = {'changed': True}
+    assert v.validate_python({'a': 1, 'b': []}) == {'a': 1, 'b': []}
@@ -1,6 +1,7 @@
 import json
 import platform
 import re
+from concurrent.futures import ThreadPoolExecutor
 from typing import List
 
 import pytest
@@ -376,3 +377,21 @@ def test_partial_parse():
     with pytest.raises(ValueError, match='EOF while parsing a string at line 1 column 15'):
         from_json(b'["aa", "bb", "c')
     assert from_json(b'["aa", "bb", "c', allow_partial=True) == ['aa', 'bb']
+
+
+@pytest.mark.parametrize('input_value', ['[1, "2", 3]', b'[1, "2", 3]', bytearray(b'[1, "2", 3]')])
+def test_validate_json_release_gil_during_parse(input_value):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_json(input_value, release_gil_during_parse=True) == [1, 2, 3]
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a list'):
+        v.validate_json(input_value[:-1], release_gil_during_parse=True)
+
+
+def test_validate_json_release_gil_during_parse_threads():
+    v = SchemaValidator(core_schema.list_schema(core_schema.dict_schema(core_schema.str_schema())))
+    inputs = [json.dumps([{'x': str(i)}] * 1000) for i in range(8)]
+    with ThreadPoolExecutor(4) as executor:
+        outputs = list(
+            executor.map(lambda input_value: v.validate_json(input_value, release_gil_during_parse=True), inputs)
+        )
+    assert outputs == [[{'x': str(i)}] * 1000 for i in range(8)]
@@ -0,0 +1,140 @@
+from datetime import datetime, timezone
+from decimal import Decimal
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture(scope='module')
+def model_schema():
+    address_schema = core_schema.typed_dict_schema(
+        {
+            'city': core_schema.typed_dict_field(core_schema.str_schema()),
+            'zip': core_schema.typed_dict_field(core_schema.nullable_schema(core_schema.str_schema())),
+        }
+    )
+    return core_schema.model_schema(
+        Model,
+        core_schema.model_fields_schema(
+            {
+                'id': core_schema.model_field(core_schema.int_schema()),
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'active': core_schema.model_field(core_schema.bool_schema()),
+                'price': core_schema.model_field(core_schema.decimal_schema()),
+                'created': core_schema.model_field(core_schema.datetime_schema()),
+                'key': core_schema.model_field(core_schema.uuid_schema()),
+                'tags': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+                'address': core_schema.model_field(address_schema),
+            }
+        ),
+    )
+
+
+@pytest.fixture
+def input_data():
+    return {
+        'id': 42,
+        'name': 'apple',
+        'active': True,
+        'price': Decimal('1.10'),
+        'created': datetime(2024, 1, 2, 3, 4, 5, tzinfo=timezone.utc),
+        'key': UUID(int=1),
+        'tags': ['red', 'fruit'],
+        'address': {'city': 'London', 'zip': None},
+    }
+
+
+def test_to_redis_hash(model_schema, input_data):
+    m = SchemaValidator(model_schema).validate_python(input_data)
+    assert SchemaSerializer(model_schema).to_redis_hash(m) == {
+        'id': '42',
+        'name': 'apple',
+        'active': 'true',
+        'price': '1.10',
+        'created': '2024-01-02T03:04:05Z',
+        'key': '00000000-0000-0000-0000-000000000001',
+        'tags': '["red","fruit"]',
+        'address.city': 'London',
+        'address.zip': 'null',
+    }
+
+
+def test_round_trip(model_schema, input_data):
+    s = SchemaSerializer(model_schema)
+    v = SchemaValidator(model_schema)
+    m = v.validate_python(input_data)
+    hash = s.to_redis_hash(m, separator=':')
+    assert 'address:city' in hash
+    assert v.validate_redis_hash(hash, separator=':').__dict__ == input_data
+
+    # redis clients return bytes by default
+    m2 = v.validate_redis_hash({k.encode(): v.encode() for k, v in hash.items()}, separator=':')
+    assert m2.__dict__ == input_data
+
+
+@pytest.mark.parametrize('value', ['true', 'null', '[1]', '{}', '"quoted"', ' false ', '1', '1.5', 'plain', ''])
+def test_json_like_strings(value):
+    schema = core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.str_schema())})
+    hash = SchemaSerializer(schema).to_redis_hash({'x': value})
+    assert SchemaValidator(schema).validate_redis_hash(hash) == {'x': value}
+
+
+def test_strings_written_as_json():
+    schema = core_schema.typed_dict_schema({'x': core_schema.typed_dict_field(core_schema.str_schema())})
+    s = SchemaSerializer(schema)
+    assert s.to_redis_hash({'x': 'true'}) == {'x': '"true"'}
+    assert s.to_redis_hash({'x': '1.5'}) == {'x': '1.5'}
+
+
+def test_empty_dict():
+    schema = core_schema.typed_dict_schema(
+        {'x': core_schema.typed_dict_field(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))}
+    )
+    hash = SchemaSerializer(schema).to_redis_hash({'x': {}})
+    assert hash == {'x': '{}'}
+    assert SchemaValidator(schema).validate_redis_hash(hash) == {'x': {}}
+
+
+def test_validation_error(model_schema):
+    with pytest.raises(ValidationError) as exc_info:
+        SchemaValidator(model_schema).validate_redis_hash({'id': 'x', 'address.city': 'Paris'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()][:3] == [
+        ('int_parsing', ('id',)),
+        ('missing', ('name',)),
+        ('missing', ('active',)),
+    ]
+
+
+@pytest.mark.parametrize(
+    'mapping,message',
+    [
+        ({'a': '1', 'a.b': '2'}, "Invalid Redis hash: field 'a.b' conflicts with another field"),
+        ({'a.b': '1', 'a': '2'}, "Invalid Redis hash: field 'a' conflicts with another field"),
+        ({'a': 1}, "Invalid Redis hash: fields and values must be str or bytes, got 'int'"),
+        ({b'a': b'\xff'}, 'Invalid Redis hash: invalid utf-8 sequence'),
+    ],
+)
+def test_invalid_hash(mapping, message):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValueError, match=message):
+        v.validate_redis_hash(mapping)
+
+
+def test_not_dict():
+    with pytest.raises(
+        PydanticSerializationError, match="Redis hashes can only be built from values serialized to dicts, got 'list'"
+    ):
+        SchemaSerializer(core_schema.list_schema()).to_redis_hash([1])
+
+
+def test_empty_separator():
+    with pytest.raises(ValueError, match='`separator` must not be empty'):
+        SchemaSerializer(core_schema.any_schema()).to_redis_hash({}, separator='')
+    with pytest.raises(ValueError, match='`separator` must not be empty'):
+        SchemaValidator(core_schema.any_schema()).validate_redis_hash({}, separator='')
@@ -0,0 +1,186 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+class Inner:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+def model_schema(cls, fields, **kwargs):
+    return core_schema.model_schema(cls, core_schema.model_fields_schema(fields), **kwargs)
+
+
+@pytest.fixture
+def validator() -> SchemaValidator:
+    inner_schema = model_schema(Inner, {'x': core_schema.model_field(core_schema.int_schema())})
+    return SchemaValidator(
+        model_schema(
+            Model,
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.str_schema()),
+                'inner': core_schema.model_field(inner_schema),
+                'items': core_schema.model_field(core_schema.list_schema(inner_schema)),
+            },
+        )
+    )
+
+
+def test_revalidate_field(validator: SchemaValidator):
+    m = validator.validate_python({'a': 1, 'b': 'b', 'inner': {'x': 1}, 'items': []})
+    m.__dict__['a'] = '2'
+    # fields which aren't revalidated are left as they are
+    m.__dict__['b'] = 3
+    assert validator.revalidate_paths(m, ['a']) is m
+    assert m.a == 2
+    assert m.b == 3
+
+
+def test_revalidate_nested(validator: SchemaValidator):
+    m = validator.validate_python({'a': 1, 'b': 'b', 'inner': {'x': 1}, 'items': [{'x': 1}]})
+    inner = m.inner
+    inner.__dict__['x'] = '2'
+    validator.revalidate_paths(m, [('inner', 'x')])
+    assert m.inner is inner
+    assert inner.x == 2
+
+    # paths into fields which aren't models revalidate the whole field
+    m.__dict__['items'] = [{'x': '3'}]
+    validator.revalidate_paths(m, [['items', 0, 'x']])
+    assert isinstance(m.items[0], Inner)
+    assert m.items[0].x == 3
+
+    # an empty path revalidates every field
+    m.__dict__['a'] = '4'
+    inner.__dict__['x'] = '5'
+    validator.revalidate_paths(m, [()])
+    assert (m.a, inner.x) == (4, 5)
+
+
+def test_revalidate_errors(validator: SchemaValidator):
+    m = validator.validate_python({'a': 1, 'b': 'b', 'inner': {'x': 1}, 'items': []})
+    m.__dict__['a'] = 'x'
+    m.inner.__dict__['x'] = '2'
+    with pytest.raises(ValidationError) as exc_info:
+        validator.revalidate_paths(m, [('inner', 'x'), 'a', ('c', 'd'), (0,)])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'no_such_attribute',
+            'loc': ('c',),
+            'msg': "Object has no attribute 'c'",
+            'input': {'a': 'x', 'b': 'b', 'inner': m.inner, 'items': []},
+            'ctx': {'attribute': 'c'},
+        },
+        {
+            'type': 'no_such_attribute',
+            'loc': (0,),
+            'msg': "Object has no attribute '0'",
+            'input': {'a': 'x', 'b': 'b', 'inner': m.inner, 'items': []},
+            'ctx': {'attribute': '0'},
+        },
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+    ]
+    # nested models revalidated before the error are left unchanged too
+    assert m.inner.x == '2'
+
+    m.inner.__dict__['x'] = 'y'
+    with pytest.raises(ValidationError) as exc_info:
+        validator.revalidate_paths(m, [('inner', 'x')])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('int_parsing', ('inner', 'x'))]
+
+
+def test_revalidate_after_validator():
+    def check_range(m):
+        if m.start > m.end:
+            raise ValueError('start after end')
+        return m
+
+    v = SchemaValidator(
+        core_schema.no_info_after_validator_function(
+            check_range,
+            model_schema(
+                Model,
+                {
+                    'start': core_schema.model_field(core_schema.int_schema()),
+                    'end': core_schema.model_field(core_schema.int_schema()),
+                },
+            ),
+        )
+    )
+    m = v.validate_python({'start': 1, 'end': 2})
+    m.__dict__['start'] = '3'
+    with pytest.raises(ValidationError, match='start after end'):
+        v.revalidate_paths(m, ['start'])
+    assert m.start == '3'
+
+    m.__dict__['end'] = '4'
+    v.revalidate_paths(m, ['start', 'end'])
+    assert (m.start, m.end) == (3, 4)
+
+
+def test_revalidate_field_dependencies():
+    optional_str = core_schema.with_default_schema(core_schema.nullable_schema(core_schema.str_schema()), default=None)
+    v = SchemaValidator(
+        model_schema(
+            Model,
+            {
+                'card_number': core_schema.model_field(optional_str, conflicts_with=['iban']),
+                'iban': core_schema.model_field(optional_str),
+                'name': core_schema.model_field(optional_str),
+            },
+        )
+    )
+    m = v.validate_python({'card_number': '4242'})
+    m.__dict__['iban'] = 'GB00'
+    m.__pydantic_fields_set__.add('iban')
+    # only dependencies involving the revalidated fields are checked
+    v.revalidate_paths(m, ['name'])
+    with pytest.raises(ValidationError) as exc_info:
+        v.revalidate_paths(m, ['iban'])
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('conflicting_field', ('card_number',))]
+
+
+def test_revalidate_info_data():
+    def check_b(value, info):
+        assert info.data == {'a': 1}
+        return value * 2
+
+    v = SchemaValidator(
+        model_schema(
+            Model,
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(
+                    core_schema.with_info_after_validator_function(check_b, core_schema.int_schema())
+                ),
+            },
+        )
+    )
+    m = v.validate_python({'a': 1, 'b': 2})
+    assert m.b == 4
+    v.revalidate_paths(m, ['b'])
+    assert m.b == 8
+
+
+def test_revalidate_invalid(validator: SchemaValidator):
+    m = validator.validate_python({'a': 1, 'b': 'b', 'inner': {'x': 1}, 'items': []})
+    with pytest.raises(TypeError, match='`revalidate_paths` expects an instance of the model being validated'):
+        validator.revalidate_paths(m.inner, ['x'])
+    with pytest.raises(TypeError, match='`paths` should be a sequence of paths, not a str'):
+        validator.revalidate_paths(m, 'a')
+    with pytest.raises(TypeError, match='Each path should be a str, tuple or list, not int'):
+        validator.revalidate_paths(m, [1])
+    with pytest.raises(TypeError, match=re.escape('Path segments should be str or int, not float')):
+        validator.revalidate_paths(m, [('a', 1.5)])
@@ -2,12 +2,14 @@ import dataclasses
 import re
 from datetime import date
 from enum import Enum
-from typing import Any
+from typing import Any, NamedTuple
 
 import pytest
 
 from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
+from .conftest import NativeType
+
 
 def val_function(x, *args: Any):
     return x
@@ -27,11 +29,18 @@ class MyDataclass:
     y: str
 
 
+class MyNamedTuple(NamedTuple):
+    foobar: int
+
+
 class MyEnum(int, Enum):
     a = 1
     b = 2
 
 
+native_type = NativeType(lambda value, _: value)
+
+
 def ids_function(val):
     if callable(val):
         return val.__name__
@@ -58,6 +67,12 @@ all_schema_functions = [
     (core_schema.float_schema, args(multiple_of=5, gt=1.2), {'type': 'float', 'multiple_of': 5, 'gt': 1.2}),
     (core_schema.str_schema, args(), {'type': 'str'}),
     (core_schema.str_schema, args(min_length=5, max_length=10), {'type': 'str', 'min_length': 5, 'max_length': 10}),
+    (core_schema.string_format_schema, args('ipv4'), {'type': 'string-format', 'format': 'ipv4'}),
+    (
+        core_schema.string_format_schema,
+        args('int32', allow_unknown=True),
+        {'type': 'string-format', 'format': 'int32', 'allow_unknown': True},
+    ),
     (core_schema.bytes_schema, args(), {'type': 'bytes'}),
     (core_schema.bytes_schema, args(min_length=5, ref='xx'), {'type': 'bytes', 'min_length': 5, 'ref': 'xx'}),
     (core_schema.date_schema, args(), {'type': 'date'}),
@@ -108,6 +123,11 @@ all_schema_functions = [
         args({'type': 'str'}, {'type': 'int'}),
         {'type': 'dict', 'keys_schema': {'type': 'str'}, 'values_schema': {'type': 'int'}},
     ),
+    (
+        core_schema.dict_schema,
+        args({'type': 'int'}, key_serialization={'type': 'str'}),
+        {'type': 'dict', 'keys_schema': {'type': 'int'}, 'key_serialization': {'type': 'str'}},
+    ),
     (
         core_schema.with_info_before_validator_function,
         args(val_function, {'type': 'int'}),
@@ -171,6 +191,16 @@ all_schema_functions = [
             'custom_error_message': 'This is Foobar',
         },
     ),
+    (
+        core_schema.union_schema,
+        args([{'type': 'int'}, {'type': 'str'}], prefer_exact_type=False, prefer_leftmost_on_tie=False),
+        {
+            'type': 'union',
+            'choices': [{'type': 'int'}, {'type': 'str'}],
+            'prefer_exact_type': False,
+            'prefer_leftmost_on_tie': False,
+        },
+    ),
     (
         core_schema.tagged_union_schema,
         args({'foo': {'type': 'int'}, 'bar': {'type': 'str'}}, 'foo'),
@@ -206,6 +236,16 @@ all_schema_functions = [
         args(MyModel, {'type': 'int'}),
         {'type': 'model', 'cls': MyModel, 'schema': {'type': 'int'}},
     ),
+    (
+        core_schema.tagged_union_range_choice,
+        args({'type': 'int'}, ge=1, lt=2),
+        {'schema': {'type': 'int'}, 'ge': 1, 'lt': 2},
+    ),
+    (
+        core_schema.tagged_union_pattern_choice,
+        args('^a', {'type': 'int'}),
+        {'pattern': '^a', 'schema': {'type': 'int'}},
+    ),
     (core_schema.arguments_parameter, args('foo', {'type': 'int'}), {'name': 'foo', 'schema': {'type': 'int'}}),
     (
         core_schema.arguments_schema,
@@ -225,6 +265,20 @@ all_schema_functions = [
             'serialization': {'type': 'format', 'formatting_string': 'd'},
         },
     ),
+    (
+        core_schema.arguments_schema,
+        args(
+            [core_schema.arguments_parameter('foo', {'type': 'int'})],
+            json_envelope=('args', 'kwargs'),
+            serialization=core_schema.format_ser_schema('d'),
+        ),
+        {
+            'type': 'arguments',
+            'arguments_schema': [{'name': 'foo', 'schema': {'type': 'int'}}],
+            'json_envelope': ('args', 'kwargs'),
+            'serialization': {'type': 'format', 'formatting_string': 'd'},
+        },
+    ),
     (
         core_schema.call_schema,
         args(core_schema.arguments_schema([core_schema.arguments_parameter('foo', {'type': 'int'})]), val_function),
@@ -247,6 +301,44 @@ all_schema_functions = [
     (core_schema.json_schema, args({'type': 'int'}), {'type': 'json', 'schema': {'type': 'int'}}),
     (core_schema.url_schema, args(), {'type': 'url'}),
     (core_schema.multi_host_url_schema, args(), {'type': 'multi-host-url'}),
+    (
+        core_schema.url_schema,
+        args(allowed_hosts=['*.example.com'], forbid_userinfo=True, idna='forbid', max_component_lengths={'host': 9}),
+        {
+            'type': 'url',
+            'allowed_hosts': ['*.example.com'],
+            'forbid_userinfo': True,
+            'idna': 'forbid',
+            'max_component_lengths': {'host': 9},
+        },
+    ),
+    (core_schema.uri_reference_schema, args(), {'type': 'uri-reference'}),
+    (
+        core_schema.uri_reference_schema,
+        args(max_length=10, allowed_schemes=['https'], normalize=True),
+        {'type': 'uri-reference', 'max_length': 10, 'allowed_schemes': ['https'], 'normalize': True},
+    ),
+    (core_schema.hostname_schema, args(), {'type': 'hostname'}),
+    (
+        core_schema.hostname_schema,
+        args(idna='punycode', output='unicode', allow_wildcard=True, max_label_length=10, max_length=20),
+        {
+            'type': 'hostname',
+            'idna': 'punycode',
+            'output': 'unicode',
+            'allow_wildcard': True,
+            'max_label_length': 10,
+            'max_length': 20,
+        },
+    ),
+    (core_schema.domain_schema, args(), {'type': 'domain'}),
+    (core_schema.domain_schema, args(idna='forbid'), {'type': 'domain', 'idna': 'forbid'}),
+    (core_schema.phone_schema, args(), {'type': 'phone'}),
+    (
+        core_schema.phone_schema,
+        args(default_region='GB', output_format='national'),
+        {'type': 'phone', 'default_region': 'GB', 'output_format': 'national'},
+    ),
     (
         core_schema.lax_or_strict_schema,
         args({'type': 'int'}, {'type': 'int'}),
@@ -287,9 +379,37 @@ all_schema_functions = [
         args(MyDataclass, {'type': 'int'}, ['foobar'], slots=True),
         {'type': 'dataclass', 'schema': {'type': 'int'}, 'fields': ['foobar'], 'cls': MyDataclass, 'slots': True},
     ),
+    (
+        core_schema.namedtuple_schema,
+        args(MyNamedTuple, {'foobar': {'type': 'int'}}),
+        {'type': 'namedtuple', 'cls': MyNamedTuple, 'fields': {'foobar': {'type': 'int'}}},
+    ),
+    (core_schema.deque_schema, args(), {'type': 'deque'}),
+    (
+        core_schema.deque_schema,
+        args({'type': 'int'}, maxlen=3),
+        {'type': 'deque', 'items_schema': {'type': 'int'}, 'maxlen': 3},
+    ),
+    (core_schema.counter_schema, args({'type': 'str'}), {'type': 'counter', 'keys_schema': {'type': 'str'}}),
+    (core_schema.ordered_dict_schema, args(strict=True), {'type': 'ordered-dict', 'strict': True}),
+    (core_schema.defaultdict_schema, args(list), {'type': 'defaultdict', 'default_factory': list}),
     (core_schema.uuid_schema, args(), {'type': 'uuid'}),
+    (core_schema.uuid_schema, args(output_format='hex'), {'type': 'uuid', 'output_format': 'hex'}),
+    (core_schema.ulid_schema, args(), {'type': 'ulid'}),
+    (core_schema.ulid_schema, args(as_uuid=True), {'type': 'ulid', 'as_uuid': True}),
+    (
+        core_schema.native_schema,
+        args(native_type.capsule),
+        {'type': 'native', 'capsule': native_type.capsule},
+    ),
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
+    (core_schema.money_schema, args(), {'type': 'money'}),
+    (
+        core_schema.money_schema,
+        args(currencies=['USD'], money_serialization='str'),
+        {'type': 'money', 'currencies': ['USD'], 'money_serialization': 'str'},
+    ),
 ]
 
 
@@ -0,0 +1,132 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class Item:
+    pass
+
+
+class Order:
+    pass
+
+
+def order_schema():
+    item = core_schema.model_schema(
+        Item,
+        core_schema.model_fields_schema(
+            {
+                'price': core_schema.model_field(
+                    core_schema.no_info_after_validator_function(
+                        lambda v: round(v, 2), core_schema.float_schema(gt=0)
+                    )
+                ),
+                'tags': core_schema.model_field(
+                    core_schema.with_default_schema(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()), default={}
+                    )
+                ),
+            }
+        ),
+    )
+    return core_schema.model_schema(
+        Order,
+        core_schema.model_fields_schema(
+            {
+                'items': core_schema.model_field(core_schema.list_schema(item)),
+                'customer': core_schema.model_field(
+                    core_schema.nullable_schema(
+                        core_schema.typed_dict_schema({'name': core_schema.typed_dict_field(core_schema.str_schema())})
+                    )
+                ),
+                'point': core_schema.model_field(
+                    core_schema.tuple_schema(
+                        [core_schema.str_schema(), core_schema.int_schema()], variadic_item_index=1
+                    )
+                ),
+            }
+        ),
+    )
+
+
+@pytest.mark.parametrize(
+    'loc,input_value,output',
+    [
+        (('items', 0, 'price'), '1.234', 1.23),
+        (['items', 5, 'tags', 'x'], '3', 3),
+        (('customer', 'name'), b'Sam', 'Sam'),
+        (('point', 0), 'a', 'a'),
+        (('point', 3), '4', 4),
+        ('customer', None, None),
+    ],
+)
+def test_validator_for_loc(loc, input_value, output):
+    sub = SchemaValidator(order_schema()).validator_for_loc(loc)
+    assert sub.loc == (tuple(loc) if isinstance(loc, (tuple, list)) else (loc,))
+    assert sub.validate_python(input_value) == output
+
+
+def test_validator_for_loc_errors():
+    sub = SchemaValidator(order_schema()).validator_for_loc(('items', 1, 'price'))
+    assert repr(sub) == "SubValidator(loc=('items', 1, 'price'))"
+    with pytest.raises(ValidationError) as exc_info:
+        sub.validate_python(-1)
+    # located as if the whole input had been validated
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'greater_than',
+            'loc': ('items', 1, 'price'),
+            'msg': 'Input should be greater than 0',
+            'input': -1,
+            'ctx': {'gt': 0.0},
+        }
+    ]
+    with pytest.raises(ValidationError, match='Input should be a valid number'):
+        sub.validate_python('x', strict=True)
+
+
+def test_validator_for_loc_json():
+    sub = SchemaValidator(order_schema()).validator_for_loc(('items', 0))
+    item = sub.validate_json('{"price": 2.5}')
+    assert isinstance(item, Item)
+    assert (item.price, item.tags) == (2.5, {})
+    with pytest.raises(ValidationError) as exc_info:
+        sub.validate_json('{"price": 1')
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'json_invalid'
+
+
+@pytest.mark.parametrize(
+    'loc,error,message',
+    [
+        (('items', 'x'), ValueError, "No validator found for location ('items', 'x')"),
+        (('missing',), ValueError, "No validator found for location ('missing',)"),
+        (('items', 0, 'price', 'x'), ValueError, "No validator found for location ('items', 0, 'price', 'x')"),
+        (3, TypeError, '`loc` should be a str, tuple or list, not int'),
+        (('items', True), TypeError, 'Location items should be str or int, not bool'),
+    ],
+)
+def test_validator_for_loc_invalid(loc, error, message):
+    v = SchemaValidator(order_schema())
+    with pytest.raises(error, match=re.escape(message)):
+        v.validator_for_loc(loc)
+
+
+def test_validator_for_loc_definitions():
+    schema = core_schema.definitions_schema(
+        core_schema.definition_reference_schema('node'),
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'value': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'children': core_schema.typed_dict_field(
+                        core_schema.list_schema(core_schema.definition_reference_schema('node'))
+                    ),
+                },
+                ref='node',
+            )
+        ],
+    )
+    sub = SchemaValidator(schema).validator_for_loc(('children', 0, 'children', 2, 'value'))
+    assert sub.validate_python('7') == 7
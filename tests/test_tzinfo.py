@@ -233,3 +233,25 @@ def test_tzinfo_could_be_reused():
 
     now = datetime.now(tz=m.value.tzinfo)
     assert isinstance(now, datetime)
+
+
+def test_tzinfo_utc_singleton():
+    assert TzInfo.utc is TzInfo.utc
+    assert TzInfo.utc == TzInfo(0)
+    assert str(TzInfo.utc) == 'UTC'
+
+    dt = SchemaValidator(core_schema.datetime_schema()).validate_python('2015-10-21T15:28:00Z')
+    assert dt.tzinfo is TzInfo.utc
+    t = SchemaValidator(core_schema.time_schema()).validate_python('15:28:00Z')
+    assert t.tzinfo is TzInfo.utc
+
+    dt = SchemaValidator(core_schema.datetime_schema()).validate_python('2015-10-21T15:28:00+01:00')
+    assert dt.tzinfo is not TzInfo.utc
+
+
+def test_tzinfo_copy_pickle():
+    tz = TzInfo(3600)
+    assert copy.copy(tz) is tz
+    assert copy.deepcopy(tz) is tz
+    assert pickle.loads(pickle.dumps(tz)) == tz
+    assert pickle.loads(pickle.dumps(TzInfo.utc)) == TzInfo.utc
@@ -0,0 +1,202 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, coercion_audit, core_schema, union_debug
+
+
+def validate_each(validator, inputs, **kwargs):
+    for input_value in inputs:
+        try:
+            validator.validate_python(input_value, **kwargs)
+        except ValidationError:
+            pass
+
+
+@pytest.fixture(scope='module')
+def validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            }
+        )
+    )
+
+
+def test_python(validator):
+    results, errors = validator.validate_many([{'id': 1, 'name': 'a'}, {'id': '2', 'name': 'b'}])
+    assert results == [{'id': 1, 'name': 'a'}, {'id': 2, 'name': 'b'}]
+    assert errors == {}
+
+
+def test_json(validator):
+    results, errors = validator.validate_many(
+        ['{"id": 1, "name": "a"}', b'{"id": 2, "name": "b"}', bytearray(b'{"id": 3, "name": "c"}')], mode='json'
+    )
+    assert results == [{'id': 1, 'name': 'a'}, {'id': 2, 'name': 'b'}, {'id': 3, 'name': 'c'}]
+    assert errors == {}
+
+
+def test_collect_errors(validator):
+    results, errors = validator.validate_many([{'id': 1, 'name': 'a'}, {'id': 'x', 'name': 'b'}, {'id': 3}])
+    assert results == [{'id': 1, 'name': 'a'}, None, None]
+    assert list(errors) == [1, 2]
+    assert all(isinstance(e, ValidationError) for e in errors.values())
+    assert errors[1].errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('id',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert errors[2].errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('name',), 'msg': 'Field required', 'input': {'id': 3}}
+    ]
+
+
+def test_collect_errors_json(validator):
+    results, errors = validator.validate_many(['{"id": 1, "name": "a"}', '{"id": 1', '[]'], mode='json')
+    assert results == [{'id': 1, 'name': 'a'}, None, None]
+    assert [e['type'] for e in errors[1].errors()] == ['json_invalid']
+    assert [e['type'] for e in errors[2].errors()] == ['dict_type']
+
+
+def test_collect_raise(validator):
+    inputs = [{'id': 1, 'name': 'a'}, {'id': 2, 'name': 'b'}, {'id': 'x', 'name': 'c'}, {'id': 'y'}]
+    with pytest.raises(ValidationError) as exc_info:
+        validator.validate_many(inputs, collect='raise')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (2, 'id'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_generator(validator):
+    consumed = []
+
+    def rows():
+        for i in range(3):
+            consumed.append(i)
+            yield {'id': i, 'name': str(i)}
+
+    results, errors = validator.validate_many(rows())
+    assert results == [{'id': i, 'name': str(i)} for i in range(3)]
+    assert errors == {}
+    assert consumed == [0, 1, 2]
+
+
+def test_raise_stops_consuming(validator):
+    consumed = []
+
+    def rows():
+        for i in range(5):
+            consumed.append(i)
+            yield {'id': i if i != 1 else 'x', 'name': 'a'}
+
+    with pytest.raises(ValidationError):
+        validator.validate_many(rows(), collect='raise')
+    assert consumed == [0, 1]
+
+
+def test_intern_strings():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()), {'intern_strings': True}
+    )
+    results, errors = v.validate_many(['{"key": 1}', '{"key": 2}'], mode='json')
+    assert errors == {}
+    assert results == [{'key': 1}, {'key': 2}]
+    (key_1,) = results[0]
+    (key_2,) = results[1]
+    assert key_1 is key_2
+
+
+def test_max_python_callbacks_per_input():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.no_info_after_validator_function(lambda v: v, core_schema.int_schema())),
+        {'max_python_callbacks': 2},
+    )
+    results, errors = v.validate_many([[1, 2], [3, 4]])
+    assert results == [[1, 2], [3, 4]]
+    assert errors == {}
+
+
+def test_context():
+    def f(v, info):
+        return v * info.context['multiplier']
+
+    v = SchemaValidator(core_schema.with_info_after_validator_function(f, core_schema.int_schema()))
+    assert v.validate_many([1, 2], context={'multiplier': 3}) == ([3, 6], {})
+
+
+def test_strict(validator):
+    results, errors = validator.validate_many([{'id': '1', 'name': 'a'}], strict=True)
+    assert results == [None]
+    assert [e['type'] for e in errors[0].errors()] == ['int_type']
+
+
+def test_coercion_audit(validator):
+    inputs = [{'id': '1', 'name': 'a'}, {'id': 'x', 'name': 'b'}, {'id': 3.0, 'name': 'c'}]
+    with coercion_audit() as audit:
+        validator.validate_many(inputs)
+    with coercion_audit() as expected:
+        validate_each(validator, inputs)
+    assert audit == expected
+    assert len(audit) == 2
+
+
+def test_union_debug():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    )
+    inputs = [[1, 'a'], ['2'], [None]]
+    with union_debug() as decisions:
+        v.validate_many(inputs)
+    with union_debug() as expected:
+        validate_each(v, inputs)
+    assert decisions == expected
+    assert [d['choice'] for d in decisions] == ['int', 'str', 'str', None]
+
+
+class Tracer:
+    def __init__(self):
+        self.events = []
+
+    def on_enter(self, validator_name, loc):
+        self.events.append(('enter', validator_name, loc))
+
+    def on_exit(self, validator_name, loc):
+        self.events.append(('exit', validator_name, loc))
+
+
+def test_tracer(validator):
+    inputs = [{'id': 1, 'name': 'a'}, {'id': 'x', 'name': 'b'}]
+    tracer = Tracer()
+    validator.validate_many(inputs, tracer=tracer)
+    expected = Tracer()
+    validate_each(validator, inputs, tracer=expected)
+    assert tracer.events == expected.events
+    assert tracer.events[:2] == [('enter', 'typed-dict', ()), ('enter', 'int', ('id',))]
+
+
+def test_not_iterable(validator):
+    with pytest.raises(TypeError, match="'int' object is not iterable"):
+        validator.validate_many(1)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'mode': 'yaml'}, "Invalid mode parameter, should be `'python'` or `'json'`"),
+        ({'collect': 'warn'}, "Invalid collect parameter, should be `'errors'` or `'raise'`"),
+    ],
+)
+def test_invalid_arguments(validator, kwargs, message):
+    with pytest.raises(ValueError, match=re.escape(message)):
+        validator.validate_many([], **kwargs)
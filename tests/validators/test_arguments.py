@@ -1103,3 +1103,138 @@ def test_error_display(pydantic_version):
         '[{"type":"missing_argument","loc":["b"],"msg":"Missing required argument",'
         '"input":"ArgsKwargs((), {\'a\': 1})"}]'
     )
+
+
+@pytest.fixture(scope='module', name='envelope_validator')
+def envelope_validator_fixture():
+    return SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only'),
+                core_schema.arguments_parameter('b', core_schema.int_schema(), mode='keyword_only'),
+            ],
+            json_envelope=True,
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('{"__args__": [1], "__kwargs__": {"b": 2}}', ((1,), {'b': 2})),
+        ('{"__kwargs__": {"b": 2}, "__args__": ["1"]}', ((1,), {'b': 2})),
+        (
+            '{"__args__": [1]}',
+            Err(
+                '',
+                [
+                    {
+                        'type': 'missing_keyword_only_argument',
+                        'loc': ('b',),
+                        'msg': 'Missing required keyword only argument',
+                        'input': {'__args__': [1]},
+                    }
+                ],
+            ),
+        ),
+        (
+            '{"__args__": [1], "b": 2}',
+            Err('', [{'type': 'extra_forbidden', 'loc': ('b',), 'msg': 'Extra inputs are not permitted', 'input': 2}]),
+        ),
+        (
+            '{"__args__": 1, "__kwargs__": {"b": 2}}',
+            Err('', [{'type': 'list_type', 'loc': ('__args__',), 'msg': 'Input should be a valid array', 'input': 1}]),
+        ),
+        (
+            '{"__args__": [1], "__kwargs__": [2]}',
+            Err('', [{'type': 'dict_type', 'loc': ('__kwargs__',), 'msg': 'Input should be an object', 'input': [2]}]),
+        ),
+        # objects without either key are keyword arguments as usual
+        (
+            '{"b": 2}',
+            Err(
+                '',
+                [
+                    {
+                        'type': 'missing_positional_only_argument',
+                        'loc': (0,),
+                        'msg': 'Missing required positional only argument',
+                        'input': {'b': 2},
+                    }
+                ],
+            ),
+        ),
+    ],
+)
+def test_json_envelope(envelope_validator, input_value, expected):
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError) as exc_info:
+            envelope_validator.validate_json(input_value)
+        assert exc_info.value.errors(include_url=False) == expected.errors
+    else:
+        assert envelope_validator.validate_json(input_value) == expected
+
+
+def test_json_envelope_python(envelope_validator):
+    # the envelope only applies to JSON, python input uses `ArgsKwargs`
+    assert envelope_validator.validate_python(ArgsKwargs((1,), {'b': 2})) == ((1,), {'b': 2})
+    with pytest.raises(ValidationError, match='missing_positional_only_argument'):
+        envelope_validator.validate_python({'__args__': [1], '__kwargs__': {'b': 2}})
+
+
+def test_json_envelope_custom_keys():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema(), mode='positional_only'),
+                core_schema.arguments_parameter('b', core_schema.int_schema()),
+            ],
+            json_envelope=('args', 'kwargs'),
+        )
+    )
+    assert v.validate_json('{"args": [1, 2]}') == ((1, 2), {})
+    assert v.validate_json('{"args": [1], "kwargs": {"b": 2}}') == ((1,), {'b': 2})
+    assert v.validate_json('[1, 2]') == ((1, 2), {})
+
+
+@pytest.mark.parametrize('json_envelope', [('a', 'a'), ('a',), ('a', 'b', 'c'), 'ab'])
+def test_json_envelope_invalid(json_envelope):
+    with pytest.raises(SchemaError):
+        SchemaValidator(
+            core_schema.arguments_schema(
+                [core_schema.arguments_parameter('a', core_schema.int_schema())], json_envelope=json_envelope
+            )
+        )
+
+
+def test_var_kwargs_pattern_schemas():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [core_schema.arguments_parameter('a', core_schema.int_schema())],
+            var_kwargs_pattern_schemas={'^x_': core_schema.int_schema()},
+        )
+    )
+    assert v.validate_python(ArgsKwargs((1,), {'x_1': '2'})) == ((1,), {'x_1': 2})
+    assert v.validate_json('{"a": 1, "x_1": "2"}') == ((), {'a': 1, 'x_1': 2})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(ArgsKwargs((1,), {'x_1': 'b', 'c': 3}))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('x_1',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'b',
+        },
+        {'type': 'unexpected_keyword_argument', 'loc': ('c',), 'msg': 'Unexpected keyword argument', 'input': 3},
+    ]
+
+
+def test_var_kwargs_pattern_schemas_with_var_kwargs_schema():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [],
+            var_kwargs_schema=core_schema.str_schema(),
+            var_kwargs_pattern_schemas={'^x_': core_schema.int_schema()},
+        )
+    )
+    assert v.validate_python(ArgsKwargs((), {'x_1': '2', 'y': 'z'})) == ((), {'x_1': 2, 'y': 'z'})
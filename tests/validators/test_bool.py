@@ -79,12 +79,12 @@ def test_bool_repr():
     v = SchemaValidator({'type': 'bool'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:false,numpy_support:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'bool', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="bool",validator=Bool(BoolValidator{strict:true,numpy_support:false}),definitions=[],cache_strings=True)'
     )
 
 
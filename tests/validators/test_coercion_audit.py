@@ -0,0 +1,133 @@
+import threading
+from datetime import date
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, coercion_audit, core_schema
+
+
+@pytest.fixture
+def validator():
+    return SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                'c': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema({'d': core_schema.typed_dict_field(core_schema.date_schema())})
+                ),
+            }
+        )
+    )
+
+
+def test_python(validator):
+    expected = {'a': 1, 'b': [2, 3], 'c': {'d': date(2020, 1, 1)}}
+    with coercion_audit() as audit:
+        assert validator.validate_python(expected) == expected
+        assert audit == []
+        assert validator.validate_python({'a': '1', 'b': ('2', 3.0), 'c': {'d': '2020-01-01'}}) == expected
+
+    assert audit == [
+        (('a',), 'str', 'int'),
+        (('b',), 'tuple', 'list'),
+        (('b',), 'str', 'int'),
+        (('b',), 'float', 'int'),
+        (('c', 'd'), 'str', 'date'),
+    ]
+
+
+def test_json(validator):
+    with coercion_audit() as audit:
+        validator.validate_json('{"a": "1", "b": [2.0], "c": {"d": "2020-01-01"}}')
+    # strings are the only way to represent dates in JSON, so they aren't a lax conversion
+    assert audit == [(('a',), 'str', 'int'), (('b',), 'float', 'int')]
+
+
+def test_root():
+    v = SchemaValidator(core_schema.int_schema())
+    with coercion_audit() as audit:
+        assert v.validate_python(True) == 1
+    assert audit == [((), 'bool', 'int')]
+
+
+def test_failed_validation_not_recorded(validator):
+    with coercion_audit() as audit:
+        with pytest.raises(ValidationError):
+            validator.validate_python({'a': '1', 'b': ['x'], 'c': {'d': '2020-01-01'}})
+    assert audit == []
+
+
+class Model:
+    __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+
+@pytest.fixture
+def model_validator():
+    return SchemaValidator(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+        )
+    )
+
+
+def test_assignment(model_validator):
+    m = model_validator.validate_python({'a': 1, 'b': 2})
+    with coercion_audit() as audit:
+        model_validator.validate_assignment(m, 'a', '2')
+        model_validator.validate_assignment_many(m, {'a': 3, 'b': 4.0})
+        with pytest.raises(ValidationError):
+            model_validator.validate_assignment(m, 'b', 'x')
+    assert audit == [(('a',), 'str', 'int'), (('b',), 'float', 'int')]
+
+
+def test_revalidate_paths(model_validator):
+    m = model_validator.validate_python({'a': 1, 'b': 2})
+    m.__dict__['a'] = '3'
+    with coercion_audit() as audit:
+        model_validator.revalidate_paths(m, ['a'])
+    assert m.a == 3
+    assert audit == [(('a',), 'str', 'int')]
+
+
+def test_union_discarded_members():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.float_schema())}),
+            ]
+        )
+    )
+    with coercion_audit() as audit:
+        # the first member fails after converting `a`
+        assert v.validate_python({'a': '1'}) == {'a': 1.0}
+    assert audit == [(('a',), 'str', 'float')]
+
+
+def test_outside_block(validator):
+    with coercion_audit() as audit:
+        pass
+    validator.validate_python({'a': '1', 'b': [], 'c': {'d': date(2020, 1, 1)}})
+    assert audit == []
+
+
+def test_thread_local(validator):
+    with coercion_audit() as audit:
+        thread = threading.Thread(
+            target=validator.validate_python, args=({'a': '1', 'b': [], 'c': {'d': date(2020, 1, 1)}},)
+        )
+        thread.start()
+        thread.join()
+    assert audit == []
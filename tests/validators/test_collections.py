@@ -0,0 +1,116 @@
+import re
+from collections import Counter, OrderedDict, defaultdict, deque
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ([1, '2', 3], deque([1, 2, 3])),
+        ((1, 2), deque([1, 2])),
+        ([], deque()),
+        ([1, 'x'], Err('Input should be a valid integer, unable to parse string as an integer [type=int_parsing,')),
+        (1, Err('[type=list_type, input_value=1, input_type=int]')),
+    ],
+)
+def test_deque(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.deque_schema(core_schema.int_schema()))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert type(output) is deque
+        assert output == expected
+
+
+def test_deque_instance():
+    v = SchemaValidator(core_schema.deque_schema(core_schema.int_schema()))
+    output = v.validate_python(deque([1, '2'], maxlen=5))
+    assert output == deque([1, 2])
+    assert output.maxlen is None
+
+
+def test_deque_maxlen():
+    v = SchemaValidator(core_schema.deque_schema(core_schema.int_schema(), maxlen=2))
+    output = v.validate_python([1, 2, 3])
+    assert output == deque([2, 3])
+    assert output.maxlen == 2
+    assert v.validate_json('[1]').maxlen == 2
+
+
+def test_deque_length():
+    v = SchemaValidator(core_schema.deque_schema(max_length=2))
+    assert v.validate_python([1, 2]) == deque([1, 2])
+    with pytest.raises(ValidationError, match=r'List should have at most 2 items after validation, not 3'):
+        v.validate_python([1, 2, 3])
+
+
+def test_deque_strict():
+    v = SchemaValidator(core_schema.deque_schema(core_schema.int_schema(), strict=True))
+    assert v.validate_python(deque([1, 2])) == deque([1, 2])
+    assert v.validate_json('[1, 2]') == deque([1, 2])
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 2])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'is_instance_of',
+            'loc': (),
+            'msg': 'Input should be an instance of deque',
+            'input': [1, 2],
+            'ctx': {'class': 'deque'},
+        }
+    ]
+
+
+def test_counter(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.counter_schema(core_schema.str_schema(), core_schema.int_schema()))
+    output = v.validate_test({'a': '1', 'b': 2})
+    assert type(output) is Counter
+    assert output == Counter(a=1, b=2)
+    with pytest.raises(ValidationError, match=r'Input should be (a valid dictionary|an object) \[type=dict_type,'):
+        v.validate_test([1, 2])
+
+
+def test_counter_instance():
+    v = SchemaValidator(core_schema.counter_schema(core_schema.str_schema(), core_schema.int_schema(), strict=True))
+    assert v.validate_python(Counter('aab')) == Counter(a=2, b=1)
+    with pytest.raises(ValidationError, match='Input should be an instance of Counter'):
+        v.validate_python({'a': 1})
+
+
+def test_ordered_dict(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.ordered_dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    output = v.validate_test({'b': '2', 'a': 1})
+    assert type(output) is OrderedDict
+    assert list(output.items()) == [('b', 2), ('a', 1)]
+
+
+def test_ordered_dict_length():
+    v = SchemaValidator(core_schema.ordered_dict_schema(min_length=1))
+    with pytest.raises(ValidationError, match='Dictionary should have at least 1 item after validation, not 0'):
+        v.validate_python({})
+
+
+def test_defaultdict(py_and_json: PyAndJson):
+    values_schema = core_schema.list_schema(core_schema.int_schema())
+    v = py_and_json(core_schema.defaultdict_schema(list, core_schema.str_schema(), values_schema))
+    output = v.validate_test({'a': [1, '2']})
+    assert type(output) is defaultdict
+    assert output.default_factory is list
+    assert output == {'a': [1, 2]}
+    assert output['b'] == []
+
+
+def test_defaultdict_instance():
+    v = SchemaValidator(core_schema.defaultdict_schema(int, values_schema=core_schema.int_schema(), strict=True))
+    output = v.validate_python(defaultdict(list, a='1'))
+    assert output.default_factory is int
+    assert output == {'a': 1}
+    with pytest.raises(ValidationError, match='Input should be an instance of defaultdict'):
+        v.validate_python({'a': 1})
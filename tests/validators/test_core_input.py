@@ -0,0 +1,125 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+class CoreInput:
+    def __init__(self, value):
+        self.value = value
+
+    def __get_pydantic_core_input__(self):
+        return self.value
+
+
+class Row:
+    """Like an ORM row, the fields aren't attributes."""
+
+    def __init__(self, **columns):
+        self._columns = columns
+
+    def __get_pydantic_core_input__(self):
+        return dict(self._columns)
+
+
+class MyModel:
+    pass
+
+
+def test_model():
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.list_schema(core_schema.str_schema())),
+                }
+            ),
+        )
+    )
+    m = v.validate_python(Row(a=1, b=CoreInput(('x', 'y'))))
+    assert (m.a, m.b) == (1, ['x', 'y'])
+    m = v.validate_python(Row(a=1, b=[]), strict=True)
+    assert (m.a, m.b) == (1, [])
+    m = v.validate_python(Row(a=1, b=[]), from_attributes=True)
+    assert (m.a, m.b) == (1, [])
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Row(a='x', b=[]))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.int_schema(), CoreInput(1), 1),
+        (core_schema.float_schema(), CoreInput(1.5), 1.5),
+        (core_schema.str_schema(), CoreInput('foo'), 'foo'),
+        (core_schema.bool_schema(), CoreInput(True), True),
+        (core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()), CoreInput({'a': 1}), {'a': 1}),
+        (
+            core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+            Row(a=1),
+            {'a': 1},
+        ),
+        (core_schema.tuple_variable_schema(core_schema.int_schema()), CoreInput([1, 2]), (1, 2)),
+        (core_schema.set_schema(core_schema.int_schema()), CoreInput((1, 2)), {1, 2}),
+        (core_schema.frozenset_schema(core_schema.int_schema()), CoreInput({1}), frozenset({1})),
+    ],
+)
+def test_simple(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    assert v.validate_python(input_value) == expected
+    assert v.validate_python(input_value, strict=True) == expected
+
+
+def test_unsupported_input():
+    v = SchemaValidator(core_schema.int_schema())
+    # the returned value is only used if it's exactly the type expected, here the object is left as it is
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(CoreInput('1'))
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(CoreInput)
+
+
+def test_dataclass_args():
+    v = SchemaValidator(
+        core_schema.dataclass_args_schema(
+            'MyDataclass',
+            [
+                core_schema.dataclass_field('a', core_schema.int_schema()),
+                core_schema.dataclass_field('b', core_schema.str_schema()),
+            ],
+        )
+    )
+    assert v.validate_python(Row(a=1, b='x')) == ({'a': 1, 'b': 'x'}, None)
+
+
+def test_arguments():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.str_schema()),
+            ]
+        )
+    )
+    assert v.validate_python(CoreInput((1, 'x'))) == ((1, 'x'), {})
+    assert v.validate_python(Row(a=1, b='x')) == ((), {'a': 1, 'b': 'x'})
+
+
+def test_error_propagates():
+    class Broken:
+        def __get_pydantic_core_input__(self):
+            raise RuntimeError('broken')
+
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(RuntimeError, match='broken'):
+        v.validate_python(Broken())
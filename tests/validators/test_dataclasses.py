@@ -9,7 +9,7 @@ from typing import Any, ClassVar, Dict, List, Optional, Union
 import pytest
 from dirty_equals import IsListOrTuple, IsStr
 
-from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError, core_schema
+from pydantic_core import ArgsKwargs, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -1481,6 +1481,67 @@ def test_slots_mixed():
     assert dataclasses.asdict(dc) == {'x': 1, 'x2': 2}
 
 
+@pytest.mark.skipif(sys.version_info < (3, 10), reason='slots are only supported for dataclasses in Python >= 3.10')
+def test_slots_init_var_post_init():
+    @dataclasses.dataclass(slots=True)
+    class Model:
+        x: int
+        y: dataclasses.InitVar[int]
+        total: int = dataclasses.field(init=False)
+
+        def __post_init__(self, y: int):
+            self.total = self.x + y
+
+    schema = core_schema.dataclass_schema(
+        Model,
+        # init only fields are collected to pass to `__post_init__` without `collect_init_only`
+        core_schema.dataclass_args_schema(
+            'Model',
+            [
+                core_schema.dataclass_field(name='x', schema=core_schema.int_schema(), kw_only=False),
+                core_schema.dataclass_field(name='y', init_only=True, schema=core_schema.int_schema()),
+            ],
+        ),
+        ['x', 'total'],
+        post_init=True,
+        slots=True,
+    )
+    v = SchemaValidator(schema)
+    dc = v.validate_python({'x': 1, 'y': '2'})
+    assert not hasattr(dc, '__dict__')
+    assert (dc.x, dc.total) == (1, 3)
+    assert v.validate_python(ArgsKwargs((1,), {'y': 3})).total == 4
+
+
+@pytest.mark.skipif(sys.version_info < (3, 10), reason='slots are only supported for dataclasses in Python >= 3.10')
+def test_slots_extra_allow():
+    @dataclasses.dataclass(slots=True)
+    class Model:
+        x: int
+
+    @dataclasses.dataclass
+    class SubModel(Model):
+        pass
+
+    def dataclass_schema(cls):
+        return core_schema.dataclass_schema(
+            cls,
+            core_schema.dataclass_args_schema(
+                cls.__name__,
+                [core_schema.dataclass_field(name='x', schema=core_schema.int_schema())],
+                extra_behavior='allow',
+            ),
+            ['x'],
+            slots=True,
+        )
+
+    with pytest.raises(SchemaError, match='Dataclass `Model` has `__slots__` and no `__dict__` to store extras in'):
+        SchemaValidator(dataclass_schema(Model))
+
+    dc = SchemaValidator(dataclass_schema(SubModel)).validate_python({'x': 1, 'y': 2})
+    assert (dc.x, dc.__dict__) == (1, {'y': 2})
+
+
 def test_dataclass_json():
     schema = core_schema.dataclass_schema(
         FooDataclass,
@@ -1713,3 +1774,29 @@ def test_dataclass_args_init_with_default(input_value, extra_behavior, expected)
             assert exc_info.value.errors(include_url=False) == expected.errors
     else:
         assert dataclasses.asdict(v.validate_python(input_value)) == expected
+
+
+@pytest.mark.parametrize('extras_destination', [None, 'merge', '__pydantic_extra__', 'extras'])
+def test_extras_destination(extras_destination):
+    @dataclasses.dataclass
+    class MyDataclass:
+        a: int
+
+    config = {} if extras_destination is None else {'extras_destination': extras_destination}
+    v = SchemaValidator(
+        core_schema.dataclass_schema(
+            MyDataclass,
+            core_schema.dataclass_args_schema(
+                'MyDataclass', [core_schema.dataclass_field('a', core_schema.int_schema())], extra_behavior='allow'
+            ),
+            ['a'],
+            config=config,
+        )
+    )
+    dc = v.validate_python({'a': 1, 'b': 2})
+    v.validate_assignment(dc, 'c', 3)
+    if extras_destination in (None, 'merge'):
+        assert dc.__dict__ == {'a': 1, 'c': 3}
+    else:
+        assert dc.__dict__ == {'a': 1, extras_destination: {'b': 2, 'c': 3}}
+        assert getattr(dc, extras_destination) == {'b': 2, 'c': 3}
@@ -290,6 +290,20 @@ def test_date_past_future_today():
     assert v.isinstance_python(today + timedelta(days=1)) is True
 
 
+def test_date_clock():
+    v = SchemaValidator(
+        core_schema.date_schema(now_op='past', now_utc_offset=0), {'clock': datetime(2000, 1, 1, tzinfo=timezone.utc)}
+    )
+    assert v.isinstance_python(date(1999, 12, 31)) is True
+    assert v.isinstance_python(date(2000, 1, 1)) is False
+    # 23:00 on the 1st in UTC is the 2nd with an offset of +2 hours
+    clock = datetime(2000, 1, 1, 23, tzinfo=timezone.utc)
+    v = SchemaValidator(core_schema.date_schema(now_op='past', now_utc_offset=2 * 3600))
+    assert v.validate_python(date(2000, 1, 1), clock=clock) == date(2000, 1, 1)
+    with pytest.raises(ValidationError, match=r'Date should be in the past \[type=date_past'):
+        v.validate_python(date(2000, 1, 2), clock=clock)
+
+
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         validate_core_schema(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
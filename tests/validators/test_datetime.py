@@ -400,6 +400,55 @@ def test_mock_utc_offset_8_hours(mocker):
     assert not v.isinstance_python(future)
 
 
+def test_clock_config():
+    clock = datetime(2000, 1, 1, tzinfo=timezone.utc)
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'), {'clock': clock})
+    assert v.isinstance_python(datetime(1999, 12, 31, 23, 59, tzinfo=timezone.utc))
+    assert not v.isinstance_python(datetime(2000, 1, 1, 0, 1, tzinfo=timezone.utc))
+    assert v.validate_json('"1999-12-31T23:59:00Z"') == datetime(1999, 12, 31, 23, 59, tzinfo=timezone.utc)
+
+
+def test_clock_per_call():
+    v = SchemaValidator(
+        core_schema.datetime_schema(now_op='future'), {'clock': datetime(2000, 1, 1, tzinfo=timezone.utc)}
+    )
+    value = datetime(2010, 1, 1, tzinfo=timezone.utc)
+    assert v.validate_python(value) == value
+    with pytest.raises(ValidationError, match=r'Input should be in the future \[type=datetime_future'):
+        v.validate_python(value, clock=datetime(2020, 1, 1, tzinfo=timezone.utc))
+    with pytest.raises(ValidationError, match=r'Input should be in the future \[type=datetime_future'):
+        v.validate_json('"2010-01-01T00:00:00Z"', clock=datetime(2020, 1, 1, tzinfo=timezone.utc))
+
+
+def test_clock_function():
+    calls = []
+
+    def clock():
+        calls.append(1)
+        return datetime(2000, 1, 1, tzinfo=timezone(timedelta(hours=2)))
+
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past', now_utc_offset=0))
+    assert v.isinstance_python(datetime(1999, 12, 31, 21, 59, tzinfo=timezone.utc)) is True
+    assert v.validate_python(datetime(1999, 12, 31, 21, 59), clock=clock) == datetime(1999, 12, 31, 21, 59)
+    with pytest.raises(ValidationError, match=r'Input should be in the past \[type=datetime_past'):
+        v.validate_python(datetime(1999, 12, 31, 22, 1), clock=clock)
+    assert len(calls) == 2
+
+
+@pytest.mark.parametrize(
+    'clock,error',
+    [
+        (datetime(2000, 1, 1), '`clock` datetimes should be timezone aware'),
+        (date(2000, 1, 1), '`clock` should be a datetime or a function returning one'),
+        (lambda: '2000-01-01T00:00:00Z', '`clock` should be a datetime or a function returning one'),
+    ],
+)
+def test_clock_invalid(clock, error):
+    v = SchemaValidator(core_schema.datetime_schema(now_op='past'))
+    with pytest.raises((TypeError, ValueError), match=re.escape(error)):
+        v.validate_python(datetime(1999, 1, 1, tzinfo=timezone.utc), clock=clock)
+
+
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be greater than -86400 \[type=greater_than,'):
         validate_core_schema(core_schema.datetime_schema(now_op='past', now_utc_offset=-24 * 3600))
@@ -9,7 +9,7 @@ from typing import Any
 import pytest
 from dirty_equals import FunctionCheck, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -467,3 +467,59 @@ def test_validate_max_digits_and_decimal_places_edge_case() -> None:
     assert v.validate_python(Decimal('9999999999999999.999999999999999999')) == Decimal(
         '9999999999999999.999999999999999999'
     )
+
+
+@pytest.mark.parametrize(
+    'rounding,input_value,expected',
+    [
+        ('ROUND_HALF_EVEN', '1.125', Decimal('1.12')),
+        ('ROUND_HALF_EVEN', '1.135', Decimal('1.14')),
+        ('ROUND_HALF_UP', '1.125', Decimal('1.13')),
+        ('ROUND_DOWN', '-1.129', Decimal('-1.12')),
+        ('ROUND_FLOOR', '-1.121', Decimal('-1.13')),
+        ('ROUND_CEILING', '1.121', Decimal('1.13')),
+        ('ROUND_HALF_EVEN', '1.5', Decimal('1.50')),
+        ('ROUND_HALF_EVEN', 3, Decimal('3.00')),
+    ],
+)
+def test_decimal_rounding(py_and_json: PyAndJson, rounding, input_value, expected):
+    v = py_and_json({'type': 'decimal', 'decimal_places': 2, 'rounding': rounding})
+    output = v.validate_test(input_value)
+    assert output == expected
+    assert output.as_tuple() == expected.as_tuple()
+
+
+def test_decimal_rounding_max_digits():
+    v = SchemaValidator({'type': 'decimal', 'decimal_places': 2, 'max_digits': 4, 'rounding': 'ROUND_HALF_UP'})
+    assert v.validate_python('12.345') == Decimal('12.35')
+    with pytest.raises(ValidationError, match=r'Decimal input should have no more than 4 digits in total'):
+        v.validate_python('123.456')
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_python('NaN')
+
+
+def test_decimal_quantize_to():
+    v = SchemaValidator(core_schema.decimal_schema(quantize_to=Decimal('0.05')))
+    assert v.validate_python('1.234').as_tuple() == Decimal('1.23').as_tuple()
+    assert v.validate_python(2).as_tuple() == Decimal('2.00').as_tuple()
+
+    v = SchemaValidator(core_schema.decimal_schema(quantize_to=Decimal('1E+2'), rounding='ROUND_UP', gt=100))
+    assert v.validate_python('101') == Decimal('200')
+    with pytest.raises(ValidationError, match='Input should be greater than 100'):
+        v.validate_python('99.5')
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        (core_schema.decimal_schema(rounding='ROUND_UP'), '`rounding` can only be used with `decimal_places` or'),
+        (core_schema.decimal_schema(quantize_to=Decimal('NaN')), '`quantize_to` must be a finite decimal, got NaN'),
+        (
+            core_schema.decimal_schema(quantize_to=Decimal('0.1'), allow_inf_nan=True),
+            'allow_inf_nan=True cannot be used with quantize_to',
+        ),
+    ],
+)
+def test_decimal_rounding_invalid_schema(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(schema)
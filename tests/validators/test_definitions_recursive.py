@@ -1,5 +1,7 @@
 import datetime
+import json
 import platform
+import time
 from dataclasses import dataclass
 from typing import List, Optional
 
@@ -7,7 +9,7 @@ import pytest
 from dirty_equals import AnyThing, HasAttributes, IsList, IsPartialDict, IsStr, IsTuple
 
 import pydantic_core
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, ValidationTimeout, core_schema
 
 from ..conftest import Err, plain_repr
 from .test_typed_dict import Cls
@@ -1104,3 +1106,71 @@ def test_no_exponential_blowup():
     )
 
     SchemaValidator(schema)
+
+
+def test_deadline_ms():
+    def slow(value):
+        time.sleep(0.002)
+        return value
+
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.definition_reference_schema('Branch'),
+            [
+                core_schema.no_info_before_validator_function(
+                    slow,
+                    core_schema.typed_dict_schema(
+                        {
+                            'sub_branch': core_schema.typed_dict_field(
+                                core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                            )
+                        }
+                    ),
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    data = {'sub_branch': {'sub_branch': {'sub_branch': {'sub_branch': None}}}}
+    assert v.validate_python(data) == data
+    assert v.validate_python(data, deadline_ms=10_000) == data
+    assert v.validate_json(json.dumps(data), deadline_ms=10_000) == data
+
+    with pytest.raises(ValidationTimeout) as exc_info:
+        v.validate_python(data, deadline_ms=1)
+    assert exc_info.value.deadline_ms == 1
+    assert str(exc_info.value) == 'Validation did not complete within 1ms'
+
+    with pytest.raises(ValidationTimeout):
+        v.validate_json(json.dumps(data), deadline_ms=1)
+
+
+@pytest.mark.parametrize(
+    'schema_fn,input_value',
+    [
+        (core_schema.list_schema, list(range(1000))),
+        (lambda s: core_schema.tuple_schema([s], variadic_item_index=0), tuple(range(1000))),
+        (
+            lambda s: core_schema.tuple_schema(
+                [core_schema.int_schema(), s, core_schema.int_schema()], variadic_item_index=1
+            ),
+            tuple(range(1000)),
+        ),
+        (core_schema.set_schema, set(range(1000))),
+        (core_schema.frozenset_schema, frozenset(range(1000))),
+        (lambda s: core_schema.dict_schema(core_schema.int_schema(), s), {i: i for i in range(1000)}),
+    ],
+)
+def test_deadline_ms_flat_collections(schema_fn, input_value):
+    calls = []
+
+    def slow(value):
+        calls.append(value)
+        time.sleep(0.002)
+        return value
+
+    v = SchemaValidator(schema_fn(core_schema.no_info_after_validator_function(slow, core_schema.int_schema())))
+    with pytest.raises(ValidationTimeout):
+        v.validate_python(input_value, deadline_ms=1)
+    # the deadline is checked before each item, not only at recursive schemas
+    assert len(calls) < 100
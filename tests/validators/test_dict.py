@@ -1,12 +1,15 @@
 import re
 from collections import OrderedDict
 from collections.abc import Mapping
+from datetime import date
+from decimal import Decimal
 from typing import Any, Dict
+from uuid import UUID
 
 import pytest
 from dirty_equals import HasRepr, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError
+from pydantic_core import ReadOnlyDict, SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 
@@ -235,3 +238,184 @@ def test_json_dict():
     assert exc_info.value.errors(include_url=False) == [
         {'type': 'dict_type', 'loc': (), 'msg': 'Input should be an object', 'input': 1}
     ]
+
+
+@pytest.mark.parametrize(
+    'config,key',
+    [
+        (None, 'a,1'),
+        ({'ser_json_tuple_key_separator': '|'}, 'a|1'),
+        ({'ser_json_tuple_keys': 'json'}, '[\\"a\\",1]'),
+    ],
+)
+def test_json_tuple_keys(config, key):
+    schema = core_schema.dict_schema(
+        core_schema.tuple_schema([core_schema.str_schema(), core_schema.int_schema()]), core_schema.int_schema()
+    )
+    v = SchemaValidator(schema, config)
+    assert v.validate_json(f'{{"{key}": 2}}') == {('a', 1): 2}
+    # round trip
+    assert v.validate_json(SchemaSerializer(schema, config).to_json({('b', 3): 4})) == {('b', 3): 4}
+
+
+def test_json_tuple_keys_invalid():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.tuple_schema([core_schema.str_schema(), core_schema.int_schema()]), core_schema.int_schema()
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a,b": 2}')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a,b', '[key]', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'b',
+        }
+    ]
+    # python keys aren't parsed
+    with pytest.raises(ValidationError, match='Input should be a valid tuple'):
+        v.validate_python({'a,1': 2})
+
+
+def test_exhaustive():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.literal_schema(['a', 'b', 1]), core_schema.int_schema(), exhaustive=True
+        )
+    )
+    assert v.validate_python({'a': 1, 'b': '2', 1: 3}) == {'a': 1, 'b': 2, 1: 3}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'b': 'x', 'c': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'literal_error',
+            'loc': ('c', '[key]'),
+            'msg': "Input should be 'a', 'b' or 1",
+            'input': 'c',
+            'ctx': {'expected': "'a', 'b' or 1"},
+        },
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {'b': 'x', 'c': 1}},
+        {'type': 'missing', 'loc': (1,), 'msg': 'Field required', 'input': {'b': 'x', 'c': 1}},
+    ]
+
+
+def test_exhaustive_json():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.literal_schema(['a', 'b']), core_schema.int_schema(), exhaustive=True)
+    )
+    assert v.validate_json('{"a": 1, "b": 2}') == {'a': 1, 'b': 2}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1}')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1}}
+    ]
+
+
+@pytest.mark.parametrize('keys_schema', [None, core_schema.str_schema()])
+def test_exhaustive_invalid_keys_schema(keys_schema):
+    with pytest.raises(SchemaError, match='`exhaustive` requires a literal `keys_schema`'):
+        SchemaValidator(core_schema.dict_schema(keys_schema, exhaustive=True))
+
+
+def test_read_only():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), read_only=True))
+    output = v.validate_python({'a': 1, 'b': '2'})
+    assert isinstance(output, ReadOnlyDict)
+    assert isinstance(output, Mapping)
+    assert repr(output) == "ReadOnlyDict({'a': 1, 'b': 2})"
+    assert output == {'a': 1, 'b': 2}
+    assert output == v.validate_json('{"a": 1, "b": 2}')
+    assert output != {'a': 1}
+    assert len(output) == 2
+    assert output['a'] == 1
+    assert output.get('b') == 2
+    assert output.get('c') is None
+    assert output.get('c', 3) == 3
+    assert 'a' in output
+    assert 'c' not in output
+    assert list(output) == ['a', 'b']
+    assert list(output.keys()) == ['a', 'b']
+    assert list(output.values()) == [1, 2]
+    assert list(output.items()) == [('a', 1), ('b', 2)]
+    assert dict(output) == {'a': 1, 'b': 2}
+
+    with pytest.raises(KeyError):
+        output['c']
+    with pytest.raises(TypeError):
+        output['c'] = 3
+    with pytest.raises(AttributeError):
+        output.update({'c': 3})
+
+    copy = output.copy()
+    copy['c'] = 3
+    assert copy == {'a': 1, 'b': 2, 'c': 3}
+    assert output == {'a': 1, 'b': 2}
+
+
+def test_return_input_if_valid():
+    v = SchemaValidator(
+        core_schema.dict_schema(
+            core_schema.str_schema(), core_schema.float_schema(), strict=True, return_input_if_valid=True
+        )
+    )
+    input_value = {'a': 1.0, 'b': 2.0}
+    assert v.validate_python(input_value) is input_value
+
+    input_value = {'a': 1.0, 'b': 2}
+    output = v.validate_python(input_value)
+    assert output == {'a': 1.0, 'b': 2.0}
+    assert output is not input_value
+
+    input_value = {'a': 1.0}
+    output = v.validate_python(input_value, strict=False)
+    assert output == input_value
+    assert output is not input_value
+
+
+def test_return_input_if_valid_read_only():
+    v = SchemaValidator(core_schema.dict_schema(strict=True, return_input_if_valid=True, read_only=True))
+    input_value = {'a': 1}
+    output = v.validate_python(input_value)
+    assert isinstance(output, ReadOnlyDict)
+    assert output == input_value
+
+
+@pytest.mark.parametrize(
+    'keys_schema,input_key,expected_key',
+    [
+        (core_schema.int_schema(), '1', 1),
+        (core_schema.float_schema(), '1.5', 1.5),
+        (core_schema.bool_schema(), 'true', True),
+        (core_schema.uuid_schema(), '12345678-1234-5678-1234-567812345678', UUID(int=0x12345678123456781234567812345678)),
+        (core_schema.date_schema(), '2020-01-01', date(2020, 1, 1)),
+        (core_schema.decimal_schema(), '1.5', Decimal('1.5')),
+    ],
+)
+def test_json_typed_keys(keys_schema, input_key, expected_key):
+    schema = core_schema.dict_schema(keys_schema, core_schema.int_schema())
+    v = SchemaValidator(schema)
+    output = v.validate_json(f'{{"{input_key}": 1}}')
+    assert output == {expected_key: 1}
+    assert type(next(iter(output))) is type(expected_key)
+    # the key serializes back to the same JSON key
+    assert SchemaSerializer(schema).to_json(output) == f'{{"{input_key}":1}}'.encode()
+
+
+def test_json_typed_key_loc():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"1": "x", "y": 2}')
+    # the value is located by the validated key, like when validating python
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), ('y', '[key]')]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'1': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,)]
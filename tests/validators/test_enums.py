@@ -1,6 +1,6 @@
 import re
 import sys
-from enum import Enum, IntEnum, IntFlag
+from enum import Enum, Flag, IntEnum, IntFlag
 
 import pytest
 
@@ -344,3 +344,143 @@ def test_big_int():
 
     assert v.validate_python(ColorEnum.GREEN) is ColorEnum.GREEN
     assert v.validate_python(1 << 63) is ColorEnum.GREEN
+
+
+class Color(Enum):
+    RED = 1
+    GREEN = 'green'
+
+
+def test_by_name():
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), by='name'))
+    assert v.validate_python('RED') is Color.RED
+    assert v.validate_json('"GREEN"') is Color.GREEN
+    assert v.validate_python(Color.GREEN) is Color.GREEN
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'enum',
+            'loc': (),
+            'msg': "Input should be 'RED' or 'GREEN'",
+            'input': 1,
+            'ctx': {'expected': "'RED' or 'GREEN'"},
+        }
+    ]
+    with pytest.raises(ValidationError, match="Input should be 'RED' or 'GREEN'"):
+        v.validate_python('red')
+
+
+def test_by_any():
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), by='any'))
+    assert v.validate_python(1) is Color.RED
+    assert v.validate_python('RED') is Color.RED
+    assert v.validate_python('green') is Color.GREEN
+    assert v.validate_json('"GREEN"') is Color.GREEN
+    with pytest.raises(ValidationError, match="Input should be 1, 'green', 'RED' or 'GREEN'"):
+        v.validate_python('blue')
+
+
+def test_by_name_str_enum():
+    class Size(str, Enum):
+        SMALL = 's'
+        LARGE = 'l'
+
+    v = SchemaValidator(core_schema.enum_schema(Size, list(Size.__members__.values()), sub_type='str', by='name'))
+    assert v.validate_python('LARGE') is Size.LARGE
+    with pytest.raises(ValidationError, match="Input should be 'SMALL' or 'LARGE'"):
+        v.validate_python('l')
+
+
+def test_case_insensitive_names():
+    v = SchemaValidator(
+        core_schema.enum_schema(Color, list(Color.__members__.values()), by='name', case_insensitive_names=True)
+    )
+    assert v.validate_python('red') is Color.RED
+    assert v.validate_json('"Green"') is Color.GREEN
+    with pytest.raises(ValidationError, match="Input should be 'RED' or 'GREEN'"):
+        v.validate_python('blue')
+
+
+def test_by_name_strict():
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), by='name', strict=True))
+    assert v.validate_json('"RED"') is Color.RED
+    with pytest.raises(ValidationError, match='Input should be an instance of Color'):
+        v.validate_python('RED')
+
+
+def test_invalid_by():
+    with pytest.raises(SchemaError, match="Invalid enum `by` value: values, expected 'value', 'name' or 'any'"):
+        SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), by='values'))
+
+
+class Perm(IntFlag):
+    R = 4
+    W = 2
+    X = 1
+    RW = 6
+
+
+@pytest.mark.parametrize('sub_type', [None, 'int'])
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (Perm.R, Perm.R),
+        (4, Perm.R),
+        (5, Perm.R | Perm.X),
+        (7, Perm.R | Perm.W | Perm.X),
+        (0, Perm(0)),
+        (['R', 'X'], Perm.R | Perm.X),
+        (['RW'], Perm.RW),
+        ([], Perm(0)),
+        ('R|W', Perm.RW),
+        ('R | X', Perm.R | Perm.X),
+        ('W', Perm.W),
+    ],
+)
+def test_flag(sub_type, input_value, expected):
+    v = SchemaValidator(core_schema.enum_schema(Perm, list(Perm.__members__.values()), sub_type=sub_type))
+    assert v.validate_python(input_value) is expected
+
+
+@pytest.mark.parametrize('input_value', [8, 12, -1, 'R|Y', '', ['R', 'Y'], ['R', 1]])
+def test_flag_invalid(input_value):
+    v = SchemaValidator(core_schema.enum_schema(Perm, list(Perm.__members__.values())))
+    with pytest.raises(ValidationError, match=r'Input should be 4, 2, 1 or 6 \[type=enum'):
+        v.validate_python(input_value)
+
+
+def test_flag_json():
+    v = SchemaValidator(core_schema.enum_schema(Perm, list(Perm.__members__.values()), sub_type='int'))
+    assert v.validate_json('3') is Perm.W | Perm.X
+    assert v.validate_json('["R", "W"]') is Perm.RW
+    assert v.validate_json('"X|R"') is Perm.R | Perm.X
+    with pytest.raises(ValidationError, match='Input should be 4, 2, 1 or 6'):
+        v.validate_json('"r"')
+
+
+def test_flag_case_insensitive_names():
+    v = SchemaValidator(core_schema.enum_schema(Perm, list(Perm.__members__.values()), case_insensitive_names=True))
+    assert v.validate_python('r|w') is Perm.RW
+    assert v.validate_python(['x']) is Perm.X
+
+
+def test_plain_enum_not_flag():
+    class MyEnum(IntEnum):
+        a = 1
+        b = 2
+
+    v = SchemaValidator(core_schema.enum_schema(MyEnum, list(MyEnum.__members__.values()), sub_type='int'))
+    with pytest.raises(ValidationError, match='Input should be 1 or 2'):
+        v.validate_python(3)
+    with pytest.raises(ValidationError, match='Input should be 1 or 2'):
+        v.validate_python(['a'])
+
+
+def test_flag_invalid_values():
+    class BadFlag(Flag):
+        a = 1
+        b = -2
+
+    with pytest.raises(SchemaError, match='Flag enum values must be non-negative ints that fit in 64 bits, got -2'):
+        SchemaValidator(core_schema.enum_schema(BadFlag, [BadFlag.a, BadFlag.b]))
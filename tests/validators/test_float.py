@@ -6,7 +6,7 @@ from typing import Any, Dict
 import pytest
 from dirty_equals import FunctionCheck, IsFloatNan, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
@@ -183,12 +183,12 @@ def test_float_repr():
     v = SchemaValidator({'type': 'float'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:false,allow_inf_nan:true,inf_nan_strings:false,numpy_support:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="float",validator=Float(FloatValidator{strict:true,allow_inf_nan:true,inf_nan_strings:false,numpy_support:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'float', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-float",validator=ConstrainedFloat(')
@@ -392,6 +392,32 @@ def test_allow_inf_nan_true_json() -> None:
     assert v.validate_json('"-Infinity"') == float('-inf')
 
 
+@pytest.mark.parametrize('mode', ['strings', 'constants'])
+@pytest.mark.parametrize('value', [float('inf'), float('-inf'), float('nan')])
+def test_inf_nan_json_round_trip(mode, value) -> None:
+    schema = core_schema.float_schema(strict=True, ser_json_inf_nan=mode, val_json_inf_nan=mode)
+    json = SchemaSerializer(schema).to_json(value)
+    assert SchemaValidator(schema).validate_json(json) == (IsFloatNan() if math.isnan(value) else value)
+
+
+def test_val_json_inf_nan_strings() -> None:
+    v = SchemaValidator(core_schema.float_schema(strict=True), core_schema.CoreConfig(val_json_inf_nan='strings'))
+    assert v.validate_json('"-Infinity"') == float('-inf')
+    # only the strings written by `ser_json_inf_nan='strings'` and only from JSON
+    for input_value in ('"inf"', '"1.5"'):
+        with pytest.raises(ValidationError, match='Input should be a valid number'):
+            v.validate_json(input_value)
+    with pytest.raises(ValidationError, match='Input should be a valid number'):
+        v.validate_python('Infinity')
+
+    v = SchemaValidator(core_schema.float_schema(strict=True, le=10, val_json_inf_nan='strings', allow_inf_nan=False))
+    with pytest.raises(ValidationError, match='Input should be a finite number'):
+        v.validate_json('"NaN"')
+
+    with pytest.raises(SchemaError, match="`val_json_inf_nan` should be 'constants' or 'strings'"):
+        SchemaValidator(core_schema.float_schema(val_json_inf_nan='null'))
+
+
 def test_allow_inf_nan_false_json() -> None:
     v = SchemaValidator(core_schema.float_schema(), core_schema.CoreConfig(allow_inf_nan=False))
 
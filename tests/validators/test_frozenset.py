@@ -247,9 +247,10 @@ def test_repr():
         'SchemaValidator('
         'title="frozenset[any]",'
         'validator=FrozenSet(FrozenSetValidator{'
-        'strict:true,item_validator:Any(AnyValidator),min_length:Some(42),max_length:None,'
+        'strict:true,item_validator:Any(AnyValidator{marker_validators:None}),min_length:Some(42),max_length:None,'
         'name:"frozenset[any]",'
-        'fail_fast:false'
+        'max_error_items:MaxErrorItems(None),'
+        'ordered:false'
         '}),'
         'definitions=[],'
         'cache_strings=True)'
@@ -341,3 +342,11 @@ def test_frozenset_fail_fast(fail_fast, expected):
         v.validate_python([1, 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+def test_frozenset_ordered(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'frozenset', 'items_schema': {'type': 'int'}, 'ordered': True})
+    output = v.validate_test([3, '1', 3, 2, 1])
+    # the unique items in the order they're first seen
+    assert output == (3, 1, 2)
+    assert type(output) is tuple
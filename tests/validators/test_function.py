@@ -1,13 +1,21 @@
 import datetime
 import platform
 import re
+import warnings
 from copy import deepcopy
 from typing import Any, Dict, List, Type
 
 import pytest
 from dirty_equals import HasRepr
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
+from pydantic_core import (
+    PythonCallbackLimitExceeded,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
 
 from ..conftest import plain_repr
 
@@ -914,6 +922,125 @@ def test_function_validation_info_mode():
     calls.clear()
 
 
+def test_function_validation_info_path():
+    paths: List[tuple] = []
+
+    def f(v: Any, info: core_schema.ValidationInfo) -> Any:
+        paths.append(info.path)
+        return v
+
+    def f_w(v: Any, handler: core_schema.ValidatorFunctionWrapHandler, info: core_schema.ValidationInfo) -> Any:
+        paths.append(info.path)
+        return handler(v)
+
+    int_schema = core_schema.with_info_before_validator_function(f, core_schema.int_schema())
+    tuple_schema = core_schema.tuple_schema([int_schema])
+    v = SchemaValidator(
+        core_schema.with_info_after_validator_function(
+            f,
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(
+                        core_schema.list_schema(
+                            core_schema.dict_schema(
+                                core_schema.str_schema(), core_schema.with_info_plain_validator_function(f)
+                            )
+                        )
+                    ),
+                    'b': core_schema.typed_dict_field(core_schema.with_info_wrap_validator_function(f_w, tuple_schema)),
+                }
+            ),
+        )
+    )
+    assert v.validate_python({'a': [{'x': 1}, {'y': 2}], 'b': [3]}) == {'a': [{'x': 1}, {'y': 2}], 'b': (3,)}
+    assert paths == [('a', 0, 'x'), ('a', 1, 'y'), ('b',), ('b', 0), ()]
+    paths.clear()
+
+    assert v.validate_json('{"a": [{"z": 1}], "b": [4]}') == {'a': [{'z': 1}], 'b': (4,)}
+    assert paths == [('a', 0, 'z'), ('b',), ('b', 0), ()]
+
+
+def test_function_validation_info_path_generator():
+    paths: List[tuple] = []
+
+    def f(v: Any, info: core_schema.ValidationInfo) -> Any:
+        paths.append(info.path)
+        return v
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'items': core_schema.model_field(
+                    core_schema.generator_schema(core_schema.with_info_plain_validator_function(f))
+                )
+            }
+        )
+    )
+    fields, _, _ = v.validate_python({'items': ['a', 'b']})
+    assert list(fields['items']) == ['a', 'b']
+    assert paths == [('items', 0), ('items', 1)]
+
+
+def test_function_validation_info_warn():
+    def f(v: Any, info: core_schema.ValidationInfo) -> Any:
+        info.warn(f'{v} is deprecated', DeprecationWarning)
+        info.warn('always')
+        return v
+
+    item_schema = core_schema.with_info_after_validator_function(f, core_schema.int_schema())
+    v = SchemaValidator(core_schema.list_schema(item_schema))
+
+    with warnings.catch_warnings(record=True) as w:
+        warnings.simplefilter('always')
+        assert v.validate_python([1, 2, 1]) == [1, 2, 1]
+        assert v.validate_json('[3]') == [3]
+    assert [(warning.category, str(warning.message)) for warning in w] == [
+        (DeprecationWarning, '1 is deprecated'),
+        (UserWarning, 'always'),
+        (DeprecationWarning, '2 is deprecated'),
+        (DeprecationWarning, '3 is deprecated'),
+        (UserWarning, 'always'),
+    ]
+    assert all(warning.filename == __file__ for warning in w)
+
+    with warnings.catch_warnings(record=True) as w:
+        warnings.simplefilter('always')
+        with pytest.raises(ValidationError):
+            v.validate_python([1, 'x'])
+    assert w == []
+
+
+def test_function_validation_info_warn_wrap_generator():
+    def f(v: Any, info: core_schema.ValidationInfo) -> Any:
+        info.warn(f'item {v}')
+        return v
+
+    def f_w(v: Any, handler: core_schema.ValidatorFunctionWrapHandler, info: core_schema.ValidationInfo) -> Any:
+        info.warn('wrap')
+        return handler(v)
+
+    item_schema = core_schema.with_info_plain_validator_function(f)
+    v = SchemaValidator(core_schema.with_info_wrap_validator_function(f_w, core_schema.list_schema(item_schema)))
+    with warnings.catch_warnings(record=True) as w:
+        warnings.simplefilter('always')
+        assert v.validate_python([1]) == [1]
+    assert [str(warning.message) for warning in w] == ['wrap', 'item 1']
+
+    gen = SchemaValidator(core_schema.generator_schema(item_schema)).validate_python([1, 2])
+    with warnings.catch_warnings(record=True) as w:
+        warnings.simplefilter('always')
+        assert next(gen) == 1
+        assert [str(warning.message) for warning in w] == ['item 1']
+        assert next(gen) == 2
+    assert [str(warning.message) for warning in w] == ['item 1', 'item 2']
+
+
+def test_function_validation_info_warn_category():
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(lambda v, info: info.warn('x', int)))
+    with pytest.raises(TypeError, match='`category` must be a subclass of `Warning`'):
+        v.validate_python(1)
+
+
 def test_reprs() -> None:
     reprs: List[str] = []
 
@@ -959,3 +1086,149 @@ def test_function_after_doesnt_change_mode() -> None:
     assert v.validate_json(b'"2000-01-01"') == datetime.date(2000, 1, 1)
     with pytest.raises(ValidationError):
         v.validate_python(b'"2000-01-01"')
+
+
+def test_max_python_callbacks():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.no_info_after_validator_function(lambda x: x, core_schema.int_schema())),
+        {'max_python_callbacks': 3},
+    )
+    assert v.validate_python([1, 2, 3]) == [1, 2, 3]
+    with pytest.raises(PythonCallbackLimitExceeded) as exc_info:
+        v.validate_python([1, 2, 3, 4])
+    assert exc_info.value.max_python_callbacks == 3
+    message = 'More than 3 Python callbacks were called in a single call, see `max_python_callbacks`'
+    assert str(exc_info.value) == message
+    # the count starts again for each call
+    assert v.validate_json('[1, 2, 3]') == [1, 2, 3]
+    with pytest.raises(PythonCallbackLimitExceeded):
+        v.validate_json('[1, 2, 3, 4]')
+
+
+def test_max_python_callbacks_wrap():
+    inner = core_schema.list_schema(core_schema.no_info_plain_validator_function(lambda x: x))
+    v = SchemaValidator(
+        core_schema.no_info_wrap_validator_function(lambda x, handler: handler(x), inner),
+        {'max_python_callbacks': 2},
+    )
+    assert v.validate_python([1]) == [1]
+    # validators called from the handler count too
+    with pytest.raises(PythonCallbackLimitExceeded):
+        v.validate_python([1, 2])
+
+
+def test_max_python_callbacks_default_factory():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                name: core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.list_schema(), default_factory=list)
+                )
+                for name in 'abc'
+            }
+        ),
+        {'max_python_callbacks': 2},
+    )
+    assert v.validate_python({'a': []}) == {'a': [], 'b': [], 'c': []}
+    with pytest.raises(PythonCallbackLimitExceeded):
+        v.validate_python({})
+
+
+def test_function_cache_by_value():
+    calls = []
+
+    def f(input_value):
+        calls.append(input_value)
+        return input_value * 2
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f, cache='by_value'))
+    assert v.validate_python(2) == 4
+    assert v.validate_python(2) == 4
+    assert v.validate_json('2') == 4
+    # equal inputs of different types aren't the same input
+    assert v.validate_python(2.0) == 4.0
+    # unhashable inputs aren't cached
+    assert v.validate_python([1]) == [1, 1]
+    assert v.validate_python([1]) == [1, 1]
+    assert calls == [2, 2.0, [1], [1]]
+
+
+def test_function_cache_by_hash():
+    calls = []
+
+    def f(input_value):
+        calls.append(input_value)
+        return input_value
+
+    v = SchemaValidator(core_schema.no_info_after_validator_function(f, core_schema.int_schema(), cache='by_hash'))
+    assert v.validate_python(1) == 1
+    assert v.validate_python('1') == 1
+    assert v.validate_python(2) == 2
+    assert calls == [1, 2]
+
+
+def test_function_cache_errors_not_cached():
+    calls = []
+
+    def f(input_value):
+        calls.append(input_value)
+        if input_value < 0:
+            raise ValueError('negative')
+        return input_value
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f, cache='by_value'))
+    for _ in range(2):
+        with pytest.raises(ValidationError, match='negative'):
+            v.validate_python(-1)
+    assert calls == [-1, -1]
+
+
+def test_function_cache_size():
+    calls = []
+
+    def f(input_value):
+        calls.append(input_value)
+        return input_value
+
+    schema = core_schema.no_info_plain_validator_function(f, cache='by_value', cache_size=2)
+    v = SchemaValidator(schema)
+    for input_value in [1, 2, 1, 3, 1, 2, 3, 3, 2]:
+        v.validate_python(input_value)
+    # 2 is the least recently used when 3 is cached, then 3 when 2 is cached, then 1 when 3 is cached
+    assert calls == [1, 2, 3, 2, 3]
+
+
+def test_function_cache_output_shared():
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(lambda x: [x], cache='by_value'))
+    output = v.validate_python(1)
+    # cached outputs aren't copied
+    assert v.validate_python(1) is output
+
+
+@pytest.mark.parametrize('function_type', ['function-plain', 'function-after'])
+def test_function_cache_with_info(function_type):
+    schema = {
+        'type': function_type,
+        'function': {'type': 'with-info', 'function': lambda x, info: x},
+        'cache': 'by_value',
+    }
+    if function_type == 'function-after':
+        schema['schema'] = core_schema.int_schema()
+    with pytest.raises(SchemaError, match="`cache` can't be used with a `with-info` function"):
+        SchemaValidator(schema)
+
+
+@pytest.mark.parametrize(
+    'kwargs,message',
+    [
+        ({'cache': 'by_value', 'cache_size': 0}, '`cache_size` must be greater than 0'),
+        ({'cache_size': 10}, None),
+    ],
+)
+def test_function_cache_schema(kwargs, message):
+    schema = core_schema.no_info_plain_validator_function(lambda x: x, **kwargs)
+    if message is None:
+        assert SchemaValidator(schema).validate_python(1) == 1
+    else:
+        with pytest.raises(SchemaError, match=message):
+            SchemaValidator(schema)
@@ -0,0 +1,128 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('example.com', 'example.com'),
+        ('Example.COM', 'example.com'),
+        ('localhost', 'localhost'),
+        ('my-host-1', 'my-host-1'),
+        ('1.2.3.4', '1.2.3.4'),
+        ('Bücher.example', 'xn--bcher-kva.example'),
+        ('XN--BCHER-KVA.example', 'xn--bcher-kva.example'),
+        ('', Err('Input should be a valid hostname, input is empty [type=hostname_parsing,')),
+        ('a_b.com', Err('Input should be a valid hostname, invalid character `_` [type=hostname_parsing,')),
+        ('a b', Err('Input should be a valid hostname, invalid character ` ` [type=hostname_parsing,')),
+        ('a..b', Err('Input should be a valid hostname, empty label [type=hostname_parsing,')),
+        ('example.com.', Err('Input should be a valid hostname, empty label [type=hostname_parsing,')),
+        ('-a.com', Err('Input should be a valid hostname, label `-a` starts or ends with a hyphen')),
+        ('a-.com', Err('Input should be a valid hostname, label `a-` starts or ends with a hyphen')),
+        ('a' * 64, Err(f'Input should be a valid hostname, label `{"a" * 64}` is longer than 63 characters')),
+        ('.'.join(['a' * 63] * 4), Err('Input should be a valid hostname, longer than 253 characters')),
+        ('xn--zz.com', Err('Input should be a valid hostname, invalid internationalized domain name')),
+        ('*.example.com', Err('Input should be a valid hostname, invalid character `*`')),
+        (123, Err('Input should be a valid string [type=string_type,')),
+    ],
+)
+def test_hostname(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.hostname_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('example.com', 'example.com'),
+        ('sub.Example.co.uk', 'sub.example.co.uk'),
+        ('localhost', Err('Input should be a valid domain name, expected at least two labels [type=domain_parsing,')),
+        ('1.2.3.4', Err('Input should be a valid domain name, top-level domain `4` is numeric')),
+        ('a_b.com', Err('Input should be a valid domain name, invalid character `_` [type=domain_parsing,')),
+    ],
+)
+def test_domain(input_value, expected):
+    v = SchemaValidator(core_schema.domain_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'idna,input_value,expected',
+    [
+        ('allow', 'bücher.de', 'xn--bcher-kva.de'),
+        ('allow', 'xn--bcher-kva.de', 'xn--bcher-kva.de'),
+        ('punycode', 'xn--bcher-kva.de', 'xn--bcher-kva.de'),
+        ('punycode', 'bücher.de', Err('internationalized domain names should be written in punycode')),
+        ('forbid', 'example.de', 'example.de'),
+        ('forbid', 'bücher.de', Err('internationalized domain names are not allowed')),
+        ('forbid', 'xn--bcher-kva.de', Err('internationalized domain names are not allowed')),
+    ],
+)
+def test_idna(idna, input_value, expected):
+    v = SchemaValidator(core_schema.domain_schema(idna=idna))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_unicode_output():
+    v = SchemaValidator(core_schema.domain_schema(output='unicode'))
+    assert v.validate_python('XN--BCHER-KVA.Example') == 'bücher.example'
+    assert v.validate_python('Bücher.example') == 'bücher.example'
+    assert v.validate_python('Example.com') == 'example.com'
+
+
+def test_wildcard():
+    v = SchemaValidator(core_schema.domain_schema(allow_wildcard=True))
+    assert v.validate_python('*.Example.com') == '*.example.com'
+    assert v.validate_python('example.com') == 'example.com'
+    # the wildcard is only allowed as the first label
+    with pytest.raises(ValidationError, match=r'invalid character `\*`'):
+        v.validate_python('a.*.example.com')
+    with pytest.raises(ValidationError, match='expected at least two labels'):
+        v.validate_python('*.com')
+
+
+def test_max_lengths():
+    v = SchemaValidator(core_schema.hostname_schema(max_label_length=3, max_length=7))
+    assert v.validate_python('abc.def') == 'abc.def'
+    with pytest.raises(ValidationError, match='label `abcd` is longer than 3 characters'):
+        v.validate_python('abcd.e')
+    with pytest.raises(ValidationError, match='longer than 7 characters'):
+        v.validate_python('abc.def.g')
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.hostname_schema(strict=True))
+    assert v.validate_python('example.com') == 'example.com'
+    with pytest.raises(ValidationError, match=r'Input should be a valid string \[type=string_type,'):
+        v.validate_python(b'example.com')
+    assert SchemaValidator(core_schema.hostname_schema()).validate_python(b'example.com') == 'example.com'
+
+
+def test_invalid_schema():
+    with pytest.raises(SchemaError, match="Invalid `output` value: foo, expected 'ascii' or 'unicode'"):
+        SchemaValidator(core_schema.hostname_schema(output='foo'))
+    with pytest.raises(SchemaError, match="Invalid `idna` value: foo, expected 'allow', 'punycode' or 'forbid'"):
+        SchemaValidator(core_schema.domain_schema(idna='foo'))
+
+
+def test_serialization():
+    s = SchemaSerializer(core_schema.domain_schema())
+    assert s.to_python('example.com') == 'example.com'
+    assert s.to_json('example.com') == b'"example.com"'
@@ -10,6 +10,11 @@ from pydantic_core import SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, plain_repr
 
+try:
+    import numpy
+except ImportError:
+    numpy = None
+
 i64_max = 9_223_372_036_854_775_807
 
 
@@ -383,17 +388,33 @@ def test_int_repr():
     v = SchemaValidator({'type': 'int'})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:false,numpy_support:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'strict': True})
     assert (
         plain_repr(v)
-        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true}),definitions=[],cache_strings=True)'
+        == 'SchemaValidator(title="int",validator=Int(IntValidator{strict:true,numpy_support:false}),definitions=[],cache_strings=True)'
     )
     v = SchemaValidator({'type': 'int', 'multiple_of': 7})
     assert plain_repr(v).startswith('SchemaValidator(title="constrained-int",validator=ConstrainedInt(')
 
 
+@pytest.mark.skipif(numpy is None, reason='numpy is not installed')
+def test_numpy_support():
+    config = core_schema.CoreConfig(numpy_support=True)
+    big = numpy.uint64(2**64 - 1)
+    # without `numpy_support` numpy integers are converted via float
+    with pytest.raises(ValidationError):
+        SchemaValidator(core_schema.int_schema()).validate_python(big)
+    assert SchemaValidator(core_schema.int_schema(), config).validate_python(big) == 2**64 - 1
+    assert SchemaValidator(core_schema.int_schema(le=2), config).validate_python(numpy.int8(2)) == 2
+    assert SchemaValidator(core_schema.bool_schema(), config).validate_python(numpy.bool_(True)) is True
+    assert type(SchemaValidator(core_schema.float_schema(), config).validate_python(numpy.float16(1.5))) is float
+
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        SchemaValidator(core_schema.int_schema(strict=True), config).validate_python(numpy.int64(1))
+
+
 def test_too_long(pydantic_version):
     v = SchemaValidator({'type': 'int'})
 
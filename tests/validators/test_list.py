@@ -7,7 +7,7 @@ from typing import Any, Dict, Iterator, List, Union
 import pytest
 from dirty_equals import Contains, HasRepr, IsInstance, IsList, IsStr
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import ReadOnlyList, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -442,6 +442,28 @@ def test_list_fail_fast(fail_fast, expected):
     assert exc_info.value.errors(include_url=False) == expected
 
 
+@pytest.mark.parametrize(
+    'max_error_items,expected_locs,expected_calls',
+    [(None, [1, 2, 4], 6), (1, [1], 2), (2, [1, 2], 3), (5, [1, 2, 4], 6)],
+)
+def test_list_max_error_items(max_error_items, expected_locs, expected_calls):
+    calls = []
+
+    def f(v):
+        calls.append(v)
+        return v
+
+    item_schema = core_schema.no_info_before_validator_function(f, core_schema.int_schema())
+    v = SchemaValidator(core_schema.list_schema(item_schema, max_error_items=max_error_items))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a', 'b', 2, 'c', 3])
+
+    assert [e['loc'] for e in exc_info.value.errors()] == [(loc,) for loc in expected_locs]
+    # items after the last error counted aren't validated
+    assert len(calls) == expected_calls
+
+
 class MySequence(collections.abc.Sequence):
     def __init__(self, data: List[Any]):
         self._data = data
@@ -547,3 +569,104 @@ def test_list_dict_items_input(testcase: ListInputTestCase) -> None:
         output = v.validate_python(testcase.input)
         assert output == testcase.output
         assert output is not testcase.input
+
+
+def test_read_only():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), read_only=True))
+    output = v.validate_python([1, '2', 3])
+    assert isinstance(output, ReadOnlyList)
+    assert isinstance(output, collections.abc.Sequence)
+    assert repr(output) == 'ReadOnlyList([1, 2, 3])'
+    assert output == [1, 2, 3]
+    assert output == v.validate_json('[1, 2, 3]')
+    assert output != [1, 2]
+    assert len(output) == 3
+    assert output[1] == 2
+    assert output[-1] == 3
+    assert output[:2] == [1, 2]
+    assert list(output) == [1, 2, 3]
+    assert list(reversed(output)) == [3, 2, 1]
+    assert 2 in output
+    assert output.index(3) == 2
+    assert output.count(1) == 1
+
+    with pytest.raises(TypeError):
+        output[0] = 4
+    with pytest.raises(AttributeError):
+        output.append(4)
+
+    copy = output.copy()
+    copy.append(4)
+    assert copy == [1, 2, 3, 4]
+    assert output == [1, 2, 3]
+
+
+
+@pytest.mark.parametrize('items_schema', [None, core_schema.int_schema()])
+def test_return_input_if_valid(items_schema):
+    v = SchemaValidator(core_schema.list_schema(items_schema, strict=True, return_input_if_valid=True))
+    input_value = [1, 2, 3]
+    assert v.validate_python(input_value) is input_value
+    assert v.validate_python([]) == []
+
+    # not in lax mode
+    output = v.validate_python(input_value, strict=False)
+    assert output == input_value
+    assert output is not input_value
+
+
+def test_return_input_if_valid_changed():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.float_schema(), strict=True, return_input_if_valid=True)
+    )
+    input_value = [1.0, 2]
+    output = v.validate_python(input_value)
+    assert output == [1.0, 2.0]
+    assert output is not input_value
+
+    class MyList(list):
+        pass
+
+    input_value = MyList([1.0])
+    output = v.validate_python(input_value)
+    assert type(output) is list
+
+
+def test_output_generator(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), output='generator'))
+    output = v.validate_test([1, '2', 'x'])
+    assert isinstance(output, collections.abc.Iterator)
+    # items are only validated as they're iterated
+    assert next(output) == 1
+    assert next(output) == 2
+    with pytest.raises(ValidationError) as exc_info:
+        next(output)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (2,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_output_generator_list_type(py_and_json: PyAndJson):
+    v = py_and_json(core_schema.list_schema(core_schema.int_schema(), output='generator'))
+    # the input must still be a list, unlike with the generator schema
+    with pytest.raises(ValidationError, match=r'Input should be a valid (list|array) \[type=list_type,'):
+        v.validate_test('123')
+
+
+def test_output_generator_max_length():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema(), output='generator', max_length=2))
+    output = v.validate_python((1, 2, 3))
+    assert next(output) == 1
+    assert next(output) == 2
+    with pytest.raises(ValidationError, match='Generator should have at most 2 items after validation'):
+        next(output)
+
+
+def test_output_invalid():
+    with pytest.raises(SchemaError, match='Invalid list output: `tuple`'):
+        SchemaValidator({'type': 'list', 'output': 'tuple'})
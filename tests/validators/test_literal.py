@@ -389,3 +389,37 @@ def test_big_int():
     m = r'Input should be 18446744073709551617 or 340282366920938463463374607431768211457 \[type=literal_error'
     with pytest.raises(ValidationError, match=m):
         v.validate_python(37)
+
+
+def test_case_insensitive():
+    v = SchemaValidator(core_schema.literal_schema(['Apple', 'banana', 'Straße', 1], case_insensitive=True))
+    assert v.validate_python('Apple') == 'Apple'
+    assert v.validate_python('APPLE') == 'Apple'
+    assert v.validate_python('BaNaNa') == 'banana'
+    assert v.validate_json('"apple"') == 'Apple'
+    assert v.validate_python('STRAßE') == 'Straße'
+    assert v.validate_python(1) == 1
+    with pytest.raises(ValidationError, match="Input should be 'Apple', 'banana', 'Straße' or 1"):
+        v.validate_python('cherry')
+
+
+def test_case_insensitive_ambiguous():
+    with pytest.raises(SchemaError, match="Literal choices 'a' and 'A' can't be told apart when `case_insensitive`"):
+        SchemaValidator(core_schema.literal_schema(['a', 'A'], case_insensitive=True))
+
+
+def test_case_sensitive_by_default():
+    v = SchemaValidator(core_schema.literal_schema(['a', 'A']))
+    assert v.validate_python('A') == 'A'
+    with pytest.raises(ValidationError, match='literal_error'):
+        v.validate_python('b')
+
+
+def test_many_str_choices():
+    choices = [f'choice_{i}' for i in range(10_000)]
+    v = SchemaValidator(core_schema.literal_schema(choices))
+    # the expected value is returned, not a new string
+    assert v.validate_json('"choice_9999"') is choices[-1]
+    assert v.validate_python('choice_0') is choices[0]
+    v = SchemaValidator(core_schema.literal_schema(choices, case_insensitive=True))
+    assert v.validate_json('"CHOICE_9999"') is choices[-1]
@@ -1249,6 +1249,121 @@ def test_validate_assignment_model_validator_function(function_schema: Any, call
     assert calls == [call1, call2]
 
 
+def test_validate_assignment_many():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    def double(x, info):
+        return x * 2 if info.data['a'] == 'double' else x
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.str_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.with_info_after_validator_function(double, core_schema.int_schema())
+                    ),
+                    'c': core_schema.model_field(core_schema.int_schema(), frozen=True),
+                }
+            ),
+        )
+    )
+    m = v.validate_python({'a': 'x', 'b': 1, 'c': 1})
+    m.__pydantic_fields_set__ = {'c'}
+
+    # fields see the values assigned before them as data
+    assert v.validate_assignment_many(m, {'a': b'double', 'b': '2'}) is m
+    assert m.__dict__ == {'a': 'double', 'b': 4, 'c': 1}
+    assert m.__pydantic_fields_set__ == {'a', 'b', 'c'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment_many(m, {'a': 'y', 'b': 'z', 'c': 2, 'd': 3})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'z',
+        },
+        {'type': 'frozen_field', 'loc': ('c',), 'msg': 'Field is frozen', 'input': 2},
+        {
+            'type': 'no_such_attribute',
+            'loc': ('d',),
+            'msg': "Object has no attribute 'd'",
+            'input': 3,
+            'ctx': {'attribute': 'd'},
+        },
+    ]
+    # nothing is assigned if any assignment is invalid
+    assert m.__dict__ == {'a': 'double', 'b': 4, 'c': 1}
+
+    with pytest.raises(TypeError, match='Field names should be str, not int'):
+        v.validate_assignment_many(m, {1: 2})
+
+
+@pytest.mark.parametrize(
+    'function_schema',
+    [
+        core_schema.no_info_after_validator_function,
+        core_schema.no_info_before_validator_function,
+        core_schema.no_info_wrap_validator_function,
+    ],
+)
+def test_validate_assignment_many_model_validator_function(function_schema: Any):
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    calls: List[Any] = []
+
+    def f(value: Any, *args: Any) -> Any:
+        calls.append(deepcopy(value))
+        return args[0](value) if args else value
+
+    fields_schema = core_schema.model_fields_schema(
+        {'a': core_schema.model_field(core_schema.int_schema()), 'b': core_schema.model_field(core_schema.int_schema())}
+    )
+    # model validators are called once for all the assignments, not once for each
+    v = SchemaValidator(core_schema.model_schema(MyModel, function_schema(f, fields_schema)))
+    m = v.validate_python({'a': 1, 'b': 2})
+    calls.clear()
+    v.validate_assignment_many(m, {'a': '10', 'b': '20'})
+    assert m.__dict__ == {'a': 10, 'b': 20}
+    assert len(calls) == 1
+
+    v = SchemaValidator(function_schema(f, core_schema.model_schema(MyModel, fields_schema)))
+    m = v.validate_python({'a': 1, 'b': 2})
+    calls.clear()
+    v.validate_assignment_many(m, {'a': '10', 'b': '20'})
+    assert m.__dict__ == {'a': 10, 'b': 20}
+    assert len(calls) == 1
+
+
+def test_validate_assignment_many_frozen():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema({'f': core_schema.model_field(core_schema.str_schema())}),
+            frozen=True,
+        )
+    )
+    m = v.validate_python({'f': 'x'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment_many(m, {'f': 'y'})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'frozen_instance', 'loc': (), 'msg': 'Instance is frozen', 'input': {'f': 'y'}}
+    ]
+
+
+def test_validate_assignment_many_not_supported():
+    with pytest.raises(TypeError, match='validate_assignment_many is not supported for int'):
+        SchemaValidator(core_schema.int_schema()).validate_assignment_many(1, {'a': 1})
+
+
 def test_model_error():
     class MyModel:
         # this is not required, but it avoids `__pydantic_fields_set__` being included in `__dict__`
@@ -1312,3 +1427,141 @@ def test_model_error():
             'ctx': {'class_name': 'MyModel'},
         }
     ]
+
+
+class Tracer:
+    def __init__(self):
+        self.events = []
+
+    def on_enter(self, validator_name, loc):
+        self.events.append(('enter', validator_name, loc))
+
+    def on_exit(self, validator_name, loc):
+        self.events.append(('exit', validator_name, loc))
+
+
+def test_tracer():
+    class Inner:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    class Outer:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    inner_schema = core_schema.model_schema(
+        Inner, core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+    )
+    v = SchemaValidator(
+        core_schema.model_schema(
+            Outer,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.str_schema()),
+                    'inner': core_schema.model_field(
+                        core_schema.no_info_wrap_validator_function(lambda v, handler: handler(v), inner_schema)
+                    ),
+                }
+            ),
+        )
+    )
+
+    for validate, input_value in [
+        (v.validate_python, {'a': 'foo', 'inner': {'x': 1}}),
+        (v.validate_json, '{"a": "foo", "inner": {"x": 1}}'),
+    ]:
+        tracer = Tracer()
+        m = validate(input_value, tracer=tracer)
+        assert m.inner.x == 1
+        # insert_assert(tracer.events)
+        assert tracer.events == [
+            ('enter', 'Outer', ()),
+            ('enter', 'str', ('a',)),
+            ('exit', 'str', ('a',)),
+            ('enter', 'function-wrap[<lambda>()]', ('inner',)),
+            ('enter', 'int', ('inner', 'x')),
+            ('exit', 'int', ('inner', 'x')),
+            ('exit', 'function-wrap[<lambda>()]', ('inner',)),
+            ('exit', 'Outer', ()),
+        ]
+
+    # on_exit is still called when validation fails
+    tracer = Tracer()
+    with pytest.raises(ValidationError):
+        v.validate_python({'a': 'foo', 'inner': {'x': 'wrong'}}, tracer=tracer)
+    assert tracer.events[-3:] == [
+        ('exit', 'int', ('inner', 'x')),
+        ('exit', 'function-wrap[<lambda>()]', ('inner',)),
+        ('exit', 'Outer', ()),
+    ]
+
+
+def model_with_extras_schema(cls, extras_destination):
+    return core_schema.model_schema(
+        cls,
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())},
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+        ),
+        config={'extras_destination': extras_destination},
+        revalidate_instances='always',
+    )
+
+
+def test_extras_destination_merge():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(model_with_extras_schema(MyModel, 'merge'))
+    m = v.validate_python({'a': 1, 'b': '2'})
+    assert m.__dict__ == {'a': 1, 'b': 2}
+    assert m.__pydantic_extra__ is None
+    assert m.__pydantic_fields_set__ == {'a', 'b'}
+
+    v.validate_assignment(m, 'c', '3')
+    assert m.__dict__ == {'a': 1, 'b': 2, 'c': 3}
+    assert m.__pydantic_extra__ is None
+
+    m2 = v.validate_python(m)
+    assert m2 is not m
+    assert m2.__dict__ == {'a': 1, 'b': 2, 'c': 3}
+
+
+def test_extras_destination_attribute():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__', 'extras'
+
+    v = SchemaValidator(model_with_extras_schema(MyModel, 'extras'))
+    m = v.validate_python({'a': 1, 'b': '2'})
+    assert m.__dict__ == {'a': 1}
+    assert m.extras == {'b': 2}
+    assert m.__pydantic_extra__ is None
+
+    v.validate_assignment(m, 'c', '3')
+    assert m.__dict__ == {'a': 1}
+    assert m.extras == {'b': 2, 'c': 3}
+
+    m2 = v.validate_python(m)
+    assert m2.__dict__ == {'a': 1}
+    assert m2.extras == {'b': 2, 'c': 3}
+
+
+def test_extras_destination_attribute_in_dict():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(model_with_extras_schema(MyModel, 'extras'))
+    m = v.validate_python({'a': 1, 'b': '2'})
+    # without a slot the attribute is stored in `__dict__`, but it isn't treated as a field or an extra
+    assert m.__dict__ == {'a': 1, 'extras': {'b': 2}}
+
+    v.validate_assignment(m, 'a', '5')
+    assert m.__dict__ == {'a': 5, 'extras': {'b': 2}}
+
+    m2 = v.validate_python(m)
+    assert m2.__dict__ == {'a': 5, 'extras': {'b': 2}}
+
+
+@pytest.mark.parametrize('extras_destination', ['', '1extras', '__dict__', '__pydantic_fields_set__'])
+def test_extras_destination_invalid(extras_destination):
+    with pytest.raises(SchemaError, match=f'Invalid extras_destination: `{extras_destination}`'):
+        SchemaValidator(model_with_extras_schema(object, extras_destination))
@@ -525,6 +525,31 @@ def test_alias_allow_pop(py_and_json: PyAndJson):
         assert v.validate_test({'foobar': '123'})
 
 
+
+def test_serialization_aliases(py_and_json: PyAndJson):
+    v = py_and_json(
+        {
+            'type': 'model-fields',
+            'fields': {
+                'field_a': {'serialization_alias': ['FieldA', 'fieldA'], 'type': 'model-field', 'schema': {'type': 'int'}}
+            },
+        }
+    )
+    assert v.validate_test({'FieldA': '123'}) == ({'field_a': 123}, None, {'field_a'})
+    assert v.validate_test({'fieldA': '123'}) == ({'field_a': 123}, None, {'field_a'})
+    assert v.validate_test({'FieldA': '1', 'fieldA': '2'}) == ({'field_a': 1}, None, {'field_a'})
+    with pytest.raises(ValidationError, match=r'FieldA\n +Field required \[type=missing,'):
+        v.validate_test({'field_a': '123'})
+
+    # a single serialization alias has no effect on validation
+    v = py_and_json(
+        {
+            'type': 'model-fields',
+            'fields': {'field_a': {'serialization_alias': 'FieldA', 'type': 'model-field', 'schema': {'type': 'int'}}},
+        }
+    )
+    assert v.validate_test({'field_a': '123'}) == ({'field_a': 123}, None, {'field_a'})
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -1016,6 +1041,66 @@ def test_from_attributes_by_name():
     assert v.validate_python(Cls(a=1)) == ({'a': 1}, None, {'a'})
 
 
+def test_from_attributes_wildcard_path():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'names': core_schema.model_field(
+                    core_schema.list_schema(core_schema.str_schema()), validation_alias=['users', '*', 'name']
+                )
+            },
+            from_attributes=True,
+        )
+    )
+    users = (Cls(name='Ada'), {'name': 'Charles'})
+    assert v.validate_python(Cls(users=users)) == ({'names': ['Ada', 'Charles']}, None, {'names'})
+    with pytest.raises(ValidationError, match=r'users.\*.name\n +Field required'):
+        v.validate_python(Cls(users=[Cls(name='Ada'), Cls()]))
+
+
+@pytest.mark.parametrize('attribute_path', ['profile.address.city', ['profile', 'address', 'city']])
+def test_from_attributes_attribute_path(attribute_path):
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'city': core_schema.model_field(core_schema.str_schema(), attribute_path=attribute_path)},
+            from_attributes=True,
+        )
+    )
+    user = Cls(profile=Cls(address=Cls(city='London')), city='Paris')
+    assert v.validate_python(user) == ({'city': 'London'}, None, {'city'})
+    # dicts are still validated by the field's name
+    assert v.validate_python({'city': 'Paris', 'profile': {'address': {'city': 'London'}}}) == (
+        {'city': 'Paris'},
+        None,
+        {'city'},
+    )
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Cls(profile=Cls(address=Cls(city=1))))
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_type',
+            'loc': ('profile', 'address', 'city'),
+            'msg': 'Input should be a valid string',
+            'input': 1,
+        }
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Cls(profile=Cls(), city='Paris'))
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('profile', 'address', 'city')
+
+
+@pytest.mark.parametrize('attribute_path', ['profile..city', '.city', 'profile.', ''])
+def test_attribute_path_invalid(attribute_path):
+    with pytest.raises(SchemaError) as exc_info:
+        SchemaValidator(
+            core_schema.model_fields_schema(
+                {'city': core_schema.model_field(core_schema.str_schema(), attribute_path=attribute_path)}
+            )
+        )
+    assert f"Attribute path '{attribute_path}' should be attribute names separated by dots" in str(exc_info.value)
+
+
 def test_from_attributes_override_true():
     v = SchemaValidator(
         {
@@ -1781,3 +1866,163 @@ def test_extra_behavior_ignore(config: Union[core_schema.CoreConfig, None], sche
         }
     ]
     assert 'not_f' not in m
+
+
+@pytest.fixture(name='payment_validator')
+def payment_validator_fixture():
+    optional_str = core_schema.with_default_schema(core_schema.nullable_schema(core_schema.str_schema()), default=None)
+    return SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'card_number': core_schema.model_field(optional_str, requires=['cvv'], conflicts_with=['iban']),
+                'cvv': core_schema.model_field(optional_str, validation_alias='CVV'),
+                'iban': core_schema.model_field(optional_str),
+            }
+        )
+    )
+
+
+def test_field_dependencies(payment_validator: SchemaValidator):
+    assert payment_validator.validate_python({'card_number': '4242', 'CVV': '123'}) == (
+        {'card_number': '4242', 'cvv': '123', 'iban': None},
+        None,
+        {'card_number', 'cvv'},
+    )
+    assert payment_validator.validate_python({'iban': 'GB00'}) == (
+        {'card_number': None, 'cvv': None, 'iban': 'GB00'},
+        None,
+        {'iban'},
+    )
+    # a field set to its default still counts as being in the input
+    assert payment_validator.validate_json('{"card_number": null, "CVV": null}')[2] == {'card_number', 'cvv'}
+
+
+def test_field_requires(payment_validator: SchemaValidator):
+    with pytest.raises(ValidationError) as exc_info:
+        payment_validator.validate_python({'card_number': '4242'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'dependent_field_missing',
+            'loc': ('CVV',),
+            'msg': "Field required when 'card_number' is set",
+            'input': {'card_number': '4242'},
+            'ctx': {'field_name': 'card_number'},
+        }
+    ]
+
+
+def test_field_conflicts_with(payment_validator: SchemaValidator):
+    with pytest.raises(ValidationError) as exc_info:
+        payment_validator.validate_python({'card_number': '4242', 'CVV': '123', 'iban': 'GB00'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'conflicting_field',
+            'loc': ('card_number',),
+            'msg': "Field can't be set when 'iban' is set",
+            'input': {'card_number': '4242', 'CVV': '123', 'iban': 'GB00'},
+            'ctx': {'field_name': 'iban'},
+        }
+    ]
+
+
+def test_field_dependencies_with_field_errors(payment_validator: SchemaValidator):
+    # fields with invalid values were still in the input
+    with pytest.raises(ValidationError) as exc_info:
+        payment_validator.validate_python({'card_number': 4242, 'iban': 'GB00'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [
+        ('string_type', ('card_number',)),
+        ('dependent_field_missing', ('CVV',)),
+        ('conflicting_field', ('card_number',)),
+    ]
+
+
+def test_field_requires_missing():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema(), requires=['b']),
+                'b': core_schema.model_field(core_schema.int_schema()),
+            }
+        )
+    )
+    # `b` has no default so is already an error
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('missing', ('b',))]
+
+
+@pytest.mark.parametrize(
+    'field,error',
+    [
+        (core_schema.model_field(core_schema.int_schema(), requires=['c']), 'unknown field "c" in dependencies'),
+        (core_schema.model_field(core_schema.int_schema(), conflicts_with=['a']), "can't depend on itself"),
+    ],
+)
+def test_field_dependencies_invalid(field, error):
+    with pytest.raises(SchemaError, match=re.escape(f'Field "a": {error}')):
+        SchemaValidator(
+            core_schema.model_fields_schema({'a': field, 'b': core_schema.model_field(core_schema.int_schema())})
+        )
+
+
+def test_field_failure_stats():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'name': core_schema.model_field(core_schema.str_schema()),
+                'tags': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        ),
+        {'field_failure_stats': True},
+    )
+    assert v.stats()['field_failures'] == {}
+
+    v.validate_python({'name': 'a', 'tags': [1]})
+    with pytest.raises(ValidationError):
+        v.validate_python({'tags': [1, 'x', 'y']})
+    with pytest.raises(ValidationError):
+        v.validate_json('{"name": 1, "tags": []}')
+    with pytest.raises(ValidationError):
+        v.validate_python([])
+    with pytest.raises(ValidationError):
+        v.validate_assignment({'name': 'a', 'tags': []}, 'name', 2)
+
+    stats = v.stats(reset=True)
+    assert stats['calls'] == 5
+    assert stats['failures'] == 4
+    assert stats['field_failures'] == {
+        ('name',): {'missing': 1, 'string_type': 2},
+        # list indexes are counted together
+        ('tags', '*'): {'int_parsing': 2},
+        (): {'model_type': 1},
+    }
+    assert v.stats()['field_failures'] == {}
+
+
+def test_field_failure_stats_disabled():
+    v = SchemaValidator(core_schema.model_fields_schema({'name': core_schema.model_field(core_schema.str_schema())}))
+    with pytest.raises(ValidationError):
+        v.validate_python({})
+    assert 'field_failures' not in v.stats()
+
+
+def test_on_missing():
+    def on_missing(field_name, data, context):
+        return f'{data["first"]}_{field_name}'
+
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {
+                'first': core_schema.model_field(core_schema.str_schema()),
+                'second': core_schema.model_field(core_schema.str_schema(), on_missing=on_missing),
+            }
+        )
+    )
+    assert v.validate_python({'first': 'a', 'second': 'b'}) == (
+        {'first': 'a', 'second': 'b'},
+        None,
+        {'first', 'second'},
+    )
+    # values from `on_missing` aren't in the fields set, like defaults
+    assert v.validate_python({'first': 'a'}) == ({'first': 'a', 'second': 'a_second'}, None, {'first'})
+    assert v.validate_json('{"first": "a"}') == ({'first': 'a', 'second': 'a_second'}, None, {'first'})
@@ -126,6 +126,24 @@ def test_assignment():
     ]
 
 
+def test_assignment_many():
+    class RootModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+        root: str
+
+    v = SchemaValidator(core_schema.model_schema(RootModel, core_schema.str_schema(), root_model=True))
+
+    m = v.validate_python('foobar')
+    m2 = v.validate_assignment_many(m, {'root': 'qux'})
+    assert m2 is m
+    assert m.root == 'qux'
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_assignment_many(m, {'root': 'quux', 'different': 'baz'})
+    assert [(e['type'], e['loc']) for e in exc_info.value.errors()] == [('no_such_attribute', ('different',))]
+    assert m.root == 'qux'
+
+
 def test_field_function():
     call_infos = []
 
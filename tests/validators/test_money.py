@@ -0,0 +1,101 @@
+import re
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('12.34 USD', {'amount': Decimal('12.34'), 'currency': 'USD'}),
+        ('12.3 usd', {'amount': Decimal('12.30'), 'currency': 'USD'}),
+        ('USD 12', {'amount': Decimal('12.00'), 'currency': 'USD'}),
+        ('  -5   EUR ', {'amount': Decimal('-5.00'), 'currency': 'EUR'}),
+        ('1e3 JPY', {'amount': Decimal('1000'), 'currency': 'JPY'}),
+        ('1.500 BHD', {'amount': Decimal('1.500'), 'currency': 'BHD'}),
+        ('1.2300 USD', {'amount': Decimal('1.23'), 'currency': 'USD'}),
+        ({'amount': '12.34', 'currency': 'USD'}, {'amount': Decimal('12.34'), 'currency': 'USD'}),
+        ({'amount': 7, 'currency': 'gbp'}, {'amount': Decimal('7.00'), 'currency': 'GBP'}),
+        ({'amount': 1.5, 'currency': 'EUR', 'other': 1}, {'amount': Decimal('1.50'), 'currency': 'EUR'}),
+        ('12.345 USD', Err('Decimal input should have no more than 2 decimal places [type=decimal_max_places,')),
+        ('1.5 JPY', Err('Decimal input should have no more than 0 decimal places [type=decimal_max_places,')),
+        ('12.34', Err("Input should be an amount and a currency code, e.g. '12.34 USD' [type=money_parsing,")),
+        ('12.34 US D', Err('[type=money_parsing,')),
+        ('USD EUR', Err('[type=money_parsing,')),
+        ('1.2.3 USD', Err('Input should be a valid decimal [type=decimal_parsing,')),
+        ('12 XYZ', Err('Input should be a valid ISO 4217 currency code [type=money_currency,')),
+        ({'amount': 'x', 'currency': 'USD'}, Err('amount\n  Input should be a valid decimal [type=decimal_parsing,')),
+        ({'amount': 'NaN', 'currency': 'USD'}, Err('amount\n  Input should be a finite number [type=finite_number,')),
+        ({'amount': 1, 'currency': 'US'}, Err('currency\n  Input should be a valid ISO 4217 currency code')),
+        ({'amount': 1}, Err('currency\n  Field required [type=missing,')),
+        (123, Err('Money input should be a dictionary with an amount and currency or a string [type=money_type,')),
+    ],
+)
+def test_money(input_value, expected):
+    v = SchemaValidator(core_schema.money_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert str(output['amount']) == str(expected['amount'])
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('12.34 USD', {'amount': Decimal('12.34'), 'currency': 'USD'}),
+        ({'amount': '12.3', 'currency': 'USD'}, {'amount': Decimal('12.30'), 'currency': 'USD'}),
+        ({'amount': 12, 'currency': 'KWD'}, {'amount': Decimal('12.000'), 'currency': 'KWD'}),
+        ([1], Err('with an amount and currency or a string [type=money_type,')),
+    ],
+)
+def test_money_json(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.money_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_rounding():
+    v = SchemaValidator(core_schema.money_schema(rounding='ROUND_HALF_UP'))
+    assert v.validate_python('1.005 USD') == {'amount': Decimal('1.01'), 'currency': 'USD'}
+    assert v.validate_python('2.5 JPY') == {'amount': Decimal('3'), 'currency': 'JPY'}
+    v = SchemaValidator(core_schema.money_schema(rounding='ROUND_DOWN'))
+    assert v.validate_python({'amount': '1.0059', 'currency': 'TND'}) == {'amount': Decimal('1.005'), 'currency': 'TND'}
+
+
+def test_currencies():
+    v = SchemaValidator(core_schema.money_schema(currencies=['usd', 'EUR']))
+    assert v.validate_python('1 EUR') == {'amount': Decimal('1.00'), 'currency': 'EUR'}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'amount': 1, 'currency': 'GBP'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'literal_error',
+            'loc': ('currency',),
+            'msg': "Input should be 'USD' or 'EUR'",
+            'input': 'GBP',
+            'ctx': {'expected': "'USD' or 'EUR'"},
+        }
+    ]
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        (core_schema.money_schema(currencies=['ABC']), "Invalid ISO 4217 currency code: 'ABC'"),
+        (core_schema.money_schema(currencies=[]), '`currencies` must not be empty'),
+        (core_schema.money_schema(rounding='ROUND_SIDEWAYS'), 'Invalid decimal `rounding`'),
+    ],
+)
+def test_invalid_schema(schema, message):
+    with pytest.raises(SchemaError, match=message):
+        SchemaValidator(schema)
@@ -0,0 +1,113 @@
+import re
+from typing import NamedTuple
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+class Point(NamedTuple):
+    x: int
+    y: int = 0
+
+
+@pytest.fixture(scope='module')
+def schema():
+    return core_schema.namedtuple_schema(
+        Point,
+        {'x': core_schema.int_schema(), 'y': core_schema.with_default_schema(core_schema.int_schema(), default=0)},
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'x': 1, 'y': 2}, Point(1, 2)),
+        ({'x': '1'}, Point(1, 0)),
+        ([1, '2'], Point(1, 2)),
+        ([1], Point(1, 0)),
+        (
+            {'y': 1},
+            Err(
+                'x\n  Missing required argument',
+                [{'type': 'missing_argument', 'loc': ('x',), 'msg': 'Missing required argument', 'input': {'y': 1}}],
+            ),
+        ),
+        (
+            {'x': 1, 'z': 2},
+            Err(
+                'z\n  Unexpected keyword argument',
+                [
+                    {
+                        'type': 'unexpected_keyword_argument',
+                        'loc': ('z',),
+                        'msg': 'Unexpected keyword argument',
+                        'input': 2,
+                    }
+                ],
+            ),
+        ),
+        (
+            [1, 2, 3],
+            Err(
+                '2\n  Unexpected positional argument',
+                [
+                    {
+                        'type': 'unexpected_positional_argument',
+                        'loc': (2,),
+                        'msg': 'Unexpected positional argument',
+                        'input': 3,
+                    }
+                ],
+            ),
+        ),
+        ('foo', Err('Arguments must be')),
+    ],
+)
+def test_namedtuple(py_and_json: PyAndJson, schema, input_value, expected):
+    v = py_and_json(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        if expected.errors is not None:
+            assert exc_info.value.errors(include_url=False) == expected.errors
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is Point
+
+
+def test_python_input(schema):
+    v = SchemaValidator(schema)
+    assert v.validate_python((1, '2')) == Point(1, 2)
+    point = v.validate_python(Point('1', '2'))
+    assert point == Point(1, 2)
+    assert type(point) is Point
+
+
+def test_strict(schema):
+    v = SchemaValidator(schema)
+    assert v.validate_python(Point(1, 2), strict=True) == Point(1, 2)
+    with pytest.raises(ValidationError, match='Input should be an instance of Point'):
+        v.validate_python({'x': 1, 'y': 2}, strict=True)
+    with pytest.raises(ValidationError, match='Input should be a valid integer'):
+        v.validate_python(Point('1', 2), strict=True)
+    # JSON has no named tuples, objects and arrays are still accepted
+    assert v.validate_json('[1, 2]', strict=True) == Point(1, 2)
+
+
+def test_nested():
+    class Line(NamedTuple):
+        start: Point
+        end: Point
+
+    point_schema = core_schema.namedtuple_schema(Point, {'x': core_schema.int_schema(), 'y': core_schema.int_schema()})
+    v = SchemaValidator(core_schema.namedtuple_schema(Line, {'start': point_schema, 'end': point_schema}))
+    assert v.validate_json('{"start": [0, 0], "end": {"x": 1, "y": 2}}') == Line(Point(0, 0), Point(1, 2))
+
+
+def test_no_fields():
+    with pytest.raises(SchemaError, match='`fields` must not be empty'):
+        SchemaValidator(core_schema.namedtuple_schema(Point, {}))
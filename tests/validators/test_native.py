@@ -0,0 +1,57 @@
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, core_schema
+
+from ..conftest import NativeType, PyAndJson
+
+
+def validate_point(value, strict):
+    if isinstance(value, dict):
+        return (value['x'], value['y'])
+    elif not strict and isinstance(value, str):
+        x, y = value.split(',')
+        return (int(x), int(y))
+    return None
+
+
+@pytest.fixture(scope='module', name='point_type')
+def point_type_fixture():
+    return NativeType(validate_point, name=b'point')
+
+
+def test_native(py_and_json: PyAndJson, point_type: NativeType):
+    v = py_and_json(core_schema.native_schema(point_type.capsule))
+    assert v.validate_test({'x': 1, 'y': 2}) == (1, 2)
+    assert v.validate_test('3,4') == (3, 4)
+
+
+def test_strict(point_type: NativeType):
+    v = SchemaValidator(core_schema.native_schema(point_type.capsule, strict=True))
+    assert v.validate_python({'x': 1, 'y': 2}) == (1, 2)
+    assert v.validate_python('3,4') is None
+
+    v = SchemaValidator(core_schema.native_schema(point_type.capsule))
+    assert v.validate_python('3,4', strict=True) is None
+
+
+def test_nested(point_type: NativeType):
+    v = SchemaValidator(core_schema.list_schema(core_schema.native_schema(point_type.capsule)))
+    assert v.validate_json('[{"x": 1, "y": 2}, "3,4"]') == [(1, 2), (3, 4)]
+    assert 'native[point]' in repr(v)
+
+
+def test_invalid_capsule_name():
+    native_type = NativeType(validate_point, capsule_name=b'other.capsule')
+    with pytest.raises(SchemaError, match='`capsule` should be named `pydantic_core.native_type`'):
+        SchemaValidator(core_schema.native_schema(native_type.capsule))
+
+
+def test_invalid_abi_version():
+    native_type = NativeType(validate_point, abi_version=2)
+    with pytest.raises(SchemaError, match='Unsupported native type ABI version 2, expected 1'):
+        SchemaValidator(core_schema.native_schema(native_type.capsule))
+
+
+def test_not_a_capsule():
+    with pytest.raises(SchemaError, match="'int' object cannot be converted to 'PyCapsule'"):
+        SchemaValidator(core_schema.native_schema(1))
@@ -0,0 +1,108 @@
+import copy
+import pickle
+import re
+
+import pytest
+
+from pydantic_core import PhoneNumber, SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('+442079460018', '+442079460018'),
+        ('+44 20 7946 0018', '+442079460018'),
+        ('+1 (650) 253-0000', '+16502530000'),
+        ('tel:+1-650-253-0000', '+16502530000'),
+        ('+44 20 7946 0018 ext. 123', '+442079460018'),
+        ('020 7946 0018', Err('Input should be a valid phone number, invalid country code [type=phone_parsing,')),
+        ('abc', Err('Input should be a valid phone number, not a number [type=phone_parsing,')),
+        ('+999', Err('Input should be a valid phone number, invalid country code [type=phone_parsing,')),
+        ('+4412', Err('Input should be a valid phone number, the number is not valid [type=phone_parsing,')),
+        (442079460018, Err('Input should be a valid string [type=string_type,')),
+    ],
+)
+def test_phone(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.phone_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('020 7946 0018', '+442079460018'),
+        ('(020) 7946-0018', '+442079460018'),
+        ('+1 650 253 0000', '+16502530000'),
+        ('650 253 0000', Err('Input should be a valid phone number, the number is not valid [type=phone_parsing,')),
+    ],
+)
+def test_default_region(input_value, expected):
+    v = SchemaValidator(core_schema.phone_schema(default_region='gb'))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_invalid_default_region():
+    with pytest.raises(SchemaError, match='Invalid `default_region` value: XX, expected an ISO 3166-1 alpha-2 code'):
+        SchemaValidator(core_schema.phone_schema(default_region='XX'))
+
+
+def test_as_phone_number():
+    v = SchemaValidator(core_schema.phone_schema(as_phone_number=True))
+    number = v.validate_python('+44 20 7946 0018 ext. 123')
+    assert isinstance(number, PhoneNumber)
+    assert repr(number) == "PhoneNumber('+442079460018')"
+    assert str(number) == '+442079460018'
+    assert number.extension == '123'
+    assert v.validate_json('"+442079460018"') == PhoneNumber('+442079460018')
+    assert v.validate_python(number) is number
+
+
+def test_phone_number_instance():
+    v = SchemaValidator(core_schema.phone_schema(strict=True))
+    assert v.validate_python(PhoneNumber('020 7946 0018', default_region='GB')) == '+442079460018'
+
+
+def test_phone_number():
+    number = PhoneNumber('+44 20 7946 0018 ext. 123')
+    assert number.country_code == 44
+    assert number.national_number == '2079460018'
+    assert number.region == 'GB'
+    assert number.format() == '+442079460018'
+    assert number.format('international') == '+44 20 7946 0018 x123'
+    assert number.format('national') == '020 7946 0018 x123'
+    assert number.format('rfc3966') == 'tel:+44-20-7946-0018;ext=123'
+    assert PhoneNumber('+800 1234 5678').region is None
+    assert PhoneNumber('+3906 6988 1234').national_number == '0669881234'
+
+
+def test_phone_number_equality():
+    number = PhoneNumber('+44 20 7946 0018')
+    assert number == PhoneNumber('020 7946 0018', default_region='GB')
+    assert hash(number) == hash(PhoneNumber('020 7946 0018', default_region='GB'))
+    assert number != PhoneNumber('+44 20 7946 0018 ext. 123')
+    assert number != '+442079460018'
+
+
+def test_phone_number_copy():
+    number = PhoneNumber('+44 20 7946 0018 ext. 123')
+    assert pickle.loads(pickle.dumps(number)) == number
+    assert copy.deepcopy(number) == number
+
+
+def test_phone_number_errors():
+    with pytest.raises(ValueError, match=r'Invalid phone number `020 7946 0018`: invalid country code'):
+        PhoneNumber('020 7946 0018')
+    with pytest.raises(ValueError, match='Invalid region `XX`'):
+        PhoneNumber('020 7946 0018', default_region='XX')
+    with pytest.raises(ValueError, match="Invalid format `x`, expected 'e164', 'international', 'national' or"):
+        PhoneNumber('+442079460018').format('x')
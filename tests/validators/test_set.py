@@ -321,3 +321,29 @@ def test_set_fail_fast(fail_fast, expected):
         v.validate_python([1, 'not-num', 'again'])
 
     assert exc_info.value.errors(include_url=False) == expected
+
+
+def test_set_max_error_items():
+    v = SchemaValidator({'type': 'set', 'items_schema': {'type': 'int'}, 'max_error_items': 2})
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([1, 'a', 'b', 'c'])
+
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), (2,)]
+
+
+def test_set_ordered(py_and_json: PyAndJson):
+    v = py_and_json({'type': 'set', 'items_schema': {'type': 'int'}, 'ordered': True})
+    output = v.validate_test([3, '1', 3, 2, 1])
+    # the unique items in the order they're first seen
+    assert output == [3, 1, 2]
+    assert type(output) is list
+
+
+def test_set_ordered_length():
+    v = SchemaValidator({'type': 'set', 'ordered': True, 'min_length': 2, 'max_length': 3})
+    assert v.validate_python(['a', 'b', 'a']) == ['a', 'b']
+    with pytest.raises(ValidationError, match='Set should have at least 2 items after validation, not 1'):
+        v.validate_python(['a', 'a'])
+    with pytest.raises(ValidationError, match='Set should have at most 3 items after validation, not more'):
+        v.validate_python(['a', 'b', 'c', 'd'])
@@ -2,6 +2,7 @@ import re
 import sys
 from decimal import Decimal
 from numbers import Number
+from types import SimpleNamespace
 from typing import Any, Dict, Union
 
 import pytest
@@ -86,6 +87,18 @@ def test_str_not_json(input_value, expected):
         ({'to_upper': True, 'pattern': 'abc'}, 'abc', 'ABC'),
         ({'strip_whitespace': True, 'pattern': r'\d+$'}, 'foobar 123 ', 'foobar 123'),
         ({'min_length': 1}, '🐈 Hello', '🐈 Hello'),
+        ({'unicode_normalization': 'NFC'}, 'cafe\u0301', 'caf\xe9'),
+        ({'unicode_normalization': 'NFC'}, '\ufb01', '\ufb01'),
+        ({'unicode_normalization': 'NFKC'}, '\ufb01ve \uff11', 'five 1'),
+        ({'casefold': True}, 'FooBar', 'foobar'),
+        ({'casefold': True}, 'Straße', 'strasse'),
+        ({'strip_chars': '_-'}, '-_foo_bar_-', 'foo_bar'),
+        ({'strip_chars': '_', 'strip_whitespace': True}, ' _foo_ ', 'foo'),
+        # normalization, case folding and stripping come before constraint checks
+        ({'unicode_normalization': 'NFC', 'max_length': 4}, 'cafe\u0301', 'caf\xe9'),
+        ({'casefold': True, 'pattern': '^[a-z]+$'}, 'StraSSe', 'strasse'),
+        ({'strip_chars': '_', 'min_length': 3}, '__ab__', Err('String should have at least 3 characters')),
+        ({'unicode_normalization': 'NFKC', 'casefold': True, 'strip_chars': '_'}, '_\uff21dmin_', 'admin'),
     ],
 )
 def test_constrained_str(py_and_json: PyAndJson, kwargs: Dict[str, Any], input_value, expected):
@@ -194,6 +207,47 @@ def test_invalid_regex(engine):
         )
 
 
+def test_re2_engine(monkeypatch):
+    compiled = []
+
+    def compile(pattern):
+        compiled.append(pattern)
+        return re.compile(pattern)
+
+    monkeypatch.setitem(sys.modules, 're2', SimpleNamespace(compile=compile))
+    v = SchemaValidator(core_schema.str_schema(pattern='^re2-[a-z]+$', regex_engine='re2'))
+    assert v.validate_python('re2-abc') == 're2-abc'
+    with pytest.raises(ValidationError, match="String should match pattern '\\^re2-\\[a-z\\]\\+\\$'"):
+        v.validate_python('re2-123')
+
+    # the compiled pattern is shared with other validators using the same pattern and engine
+    SchemaValidator(core_schema.str_schema(pattern='^re2-[a-z]+$', regex_engine='re2'))
+    SchemaValidator(core_schema.str_schema(pattern='^re2-[a-z]+$'), core_schema.CoreConfig(regex_engine='re2'))
+    assert compiled == ['^re2-[a-z]+$']
+
+
+def test_re2_engine_missing(monkeypatch):
+    monkeypatch.setitem(sys.modules, 're2', None)
+    with pytest.raises(SchemaError, match='The `re2` regex engine requires the `google-re2` package'):
+        SchemaValidator(core_schema.str_schema(pattern='^re2-missing$', regex_engine='re2'))
+
+
+@pytest.mark.parametrize('engine', ['rust-regex', 'python-re'])
+def test_pattern_cache_shared(engine):
+    v1 = SchemaValidator(core_schema.str_schema(pattern='^shared-[0-9]+$', regex_engine=engine))
+    v2 = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.str_schema(pattern='^shared-[0-9]+$', regex_engine=engine))}
+        )
+    )
+    assert v1.validate_python('shared-1') == 'shared-1'
+    assert v2.validate_python({'a': 'shared-2'}) == {'a': 'shared-2'}
+    if engine == 'python-re':
+        # both validators hold the same compiled `re.Pattern`
+        address = re.search(r'PythonRe\(\s*Py\(\s*(0x[0-9a-f]+)', repr(v1)).group(1)
+        assert address in repr(v2)
+
+
 @pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
 def test_regex_error(engine):
     v = SchemaValidator(core_schema.str_schema(pattern='11', regex_engine=engine))
@@ -210,6 +264,125 @@ def test_regex_error(engine):
     ]
 
 
+@pytest.mark.parametrize(
+    'length_unit,value,length',
+    [
+        ('chars', 'abc', 3),
+        ('bytes', 'abc', 3),
+        ('graphemes', 'abc', 3),
+        ('utf16', 'abc', 3),
+        ('chars', 'caf\xe9', 4),
+        ('bytes', 'caf\xe9', 5),
+        ('chars', '🐈', 1),
+        ('bytes', '🐈', 4),
+        ('utf16', '🐈', 2),
+        ('graphemes', '🐈', 1),
+        # family emoji, 5 code points joined by zero width joiners
+        ('chars', '👨\u200d👩\u200d👧', 5),
+        ('graphemes', '👨\u200d👩\u200d👧', 1),
+        ('utf16', '👨\u200d👩\u200d👧', 8),
+        ('graphemes', 'e\u0301', 1),
+        ('codepoints', 'e\u0301', 2),
+        ('display-width', 'abc', 3),
+        ('display-width', 'e\u0301', 1),
+        ('display-width', '日本', 4),
+        ('display-width', '🐈', 2),
+    ],
+)
+def test_length_unit(py_and_json: PyAndJson, length_unit, value, length):
+    v = py_and_json(core_schema.str_schema(min_length=length, max_length=length, length_unit=length_unit))
+    assert v.validate_test(value) == value
+    with pytest.raises(ValidationError, match='String should have at most'):
+        v.validate_test(value + 'x')
+
+    v = py_and_json(core_schema.str_schema(max_length=length - 1, length_unit=length_unit))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(value)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'string_too_long'
+
+
+def test_length_unit_config():
+    v = SchemaValidator(core_schema.str_schema(max_length=4), core_schema.CoreConfig(str_length_unit='bytes'))
+    assert v.validate_python('caf') == 'caf'
+    with pytest.raises(ValidationError, match='String should have at most 4 bytes'):
+        v.validate_python('caf\xe9')
+
+    v = SchemaValidator(
+        core_schema.str_schema(max_length=4, length_unit='chars'), core_schema.CoreConfig(str_length_unit='bytes')
+    )
+    assert v.validate_python('caf\xe9') == 'caf\xe9'
+
+
+@pytest.mark.parametrize(
+    'length_unit,value,min_message,max_message,ctx',
+    [
+        ('chars', 'abc', 'at least 4 characters', 'at most 2 characters', {}),
+        ('codepoints', 'abc', 'at least 4 characters', 'at most 2 characters', {}),
+        ('bytes', 'abc', 'at least 4 bytes', 'at most 2 bytes', {'unit': 'bytes'}),
+        ('graphemes', 'abc', 'at least 4 graphemes', 'at most 2 graphemes', {'unit': 'graphemes'}),
+        ('utf16', 'abc', 'at least 4 UTF-16 code units', 'at most 2 UTF-16 code units', {'unit': 'utf16'}),
+        ('display-width', 'abc', 'at least 4 columns', 'at most 2 columns', {'unit': 'display-width'}),
+    ],
+)
+def test_length_unit_errors(length_unit, value, min_message, max_message, ctx):
+    v = SchemaValidator(core_schema.str_schema(min_length=4, length_unit=length_unit))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': (),
+            'msg': f'String should have {min_message}',
+            'input': value,
+            'ctx': {'min_length': 4, **ctx},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.str_schema(max_length=2, length_unit=length_unit))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(f'"{value}"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_long',
+            'loc': (),
+            'msg': f'String should have {max_message}',
+            'input': value,
+            'ctx': {'max_length': 2, **ctx},
+        }
+    ]
+
+
+def test_length_unit_singular():
+    v = SchemaValidator(core_schema.str_schema(max_length=1, length_unit='bytes'))
+    with pytest.raises(ValidationError, match='String should have at most 1 byte '):
+        v.validate_python('\xe9')
+
+
+def test_invalid_length_unit():
+    with pytest.raises(SchemaError, match='Invalid length unit: words, expected '):
+        SchemaValidator({'type': 'str', 'max_length': 1, 'length_unit': 'words'})
+
+
+def test_str_normalization_config():
+    v = SchemaValidator(
+        core_schema.str_schema(),
+        core_schema.CoreConfig(str_unicode_normalization='NFKC', str_casefold=True, str_strip_chars='.'),
+    )
+    assert v.validate_python('..\uff2a\uff4f\uff48\uff4e..') == 'john'
+
+    # schema options take precedence over config
+    v = SchemaValidator(
+        core_schema.str_schema(unicode_normalization='NFC', casefold=False, strip_chars='-'),
+        core_schema.CoreConfig(str_unicode_normalization='NFKC', str_casefold=True, str_strip_chars='.'),
+    )
+    assert v.validate_python('.\uff2a-') == '.\uff2a'
+
+
+def test_invalid_unicode_normalization():
+    with pytest.raises(SchemaError, match="Invalid unicode normalization form: NFD, expected 'NFC' or 'NFKC'"):
+        SchemaValidator({'type': 'str', 'unicode_normalization': 'NFD'})
+
+
 def test_default_validator():
     v = SchemaValidator(core_schema.str_schema(strict=True, to_lower=False), {'str_strip_whitespace': False})
     assert (
@@ -0,0 +1,135 @@
+import re
+
+import pytest
+
+from pydantic_core import (
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    register_string_format,
+    unregister_string_format,
+)
+
+from ..conftest import Err, PyAndJson
+
+
+@pytest.mark.parametrize(
+    'format,input_value,expected',
+    [
+        ('date-time', '2020-01-01T12:00:00Z', '2020-01-01T12:00:00Z'),
+        ('date-time', '2020-01-01T12:00:00+01:00', '2020-01-01T12:00:00+01:00'),
+        ('date-time', '2020-01-01T12:00:00', Err('Input should have timezone info [type=timezone_aware,')),
+        ('date-time', 'foobar', Err('Input should be a valid datetime, input is too short [type=datetime_parsing,')),
+        ('date', '2020-01-01', '2020-01-01'),
+        ('date', '2020-13-01', Err('Input should be a valid date in the format YYYY-MM-DD, month value is outside')),
+        ('time', '12:30:00', '12:30:00'),
+        ('time', '25:00', Err('hour value is outside expected range of 0-23 [type=time_parsing,')),
+        ('duration', 'P1DT2H', 'P1DT2H'),
+        ('duration', 'P1X', Err('quantity invalid in date part of duration [type=time_delta_parsing,')),
+        ('uri', 'https://example.com/path', 'https://example.com/path'),
+        ('uri', '/path', Err('Input should be a valid URL, relative URL without a base [type=url_parsing,')),
+        ('uri-reference', '../path?q=1', '../path?q=1'),
+        ('hostname', 'Example.com', 'Example.com'),
+        ('hostname', 'bücher.example', Err('internationalized domain names should be written in punycode')),
+        ('idn-hostname', 'bücher.example', 'bücher.example'),
+        ('ipv4', '192.168.0.1', '192.168.0.1'),
+        ('ipv4', '192.168.0', Err("String should match format 'ipv4', invalid IPv4 address syntax")),
+        ('ipv6', '::1', '::1'),
+        ('ipv6', '192.168.0.1', Err("String should match format 'ipv6', invalid IPv6 address syntax")),
+        ('uuid', '12345678-1234-5678-1234-567812345678', '12345678-1234-5678-1234-567812345678'),
+        ('uuid', '12345678', Err('Input should be a valid UUID, invalid length')),
+        ('regex', '^a+b?$', '^a+b?$'),
+        ('regex', 'a(b', Err("String should match format 'regex', invalid regular expression")),
+        ('ipv4', 123, Err('Input should be a valid string [type=string_type,')),
+    ],
+)
+def test_builtin_formats(py_and_json: PyAndJson, format, input_value, expected):
+    v = py_and_json(core_schema.string_format_schema(format))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        output = v.validate_test(input_value)
+        assert output == expected
+        assert type(output) is str
+
+
+def test_union_exactness():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.uuid_schema(), core_schema.string_format_schema('uuid')])
+    )
+    assert v.validate_python('12345678-1234-5678-1234-567812345678') == '12345678-1234-5678-1234-567812345678'
+
+
+def test_unknown_format():
+    with pytest.raises(SchemaError, match="Unknown string format: 'int32'"):
+        SchemaValidator(core_schema.string_format_schema('int32'))
+
+    v = SchemaValidator(core_schema.string_format_schema('int32', allow_unknown=True))
+    assert v.validate_python('anything') == 'anything'
+
+
+@pytest.fixture
+def custom_formats():
+    def email(value):
+        if '@' not in value:
+            raise ValueError('missing `@`')
+        return True
+
+    register_string_format('email', email)
+    register_string_format('even-length', lambda value: len(value) % 2 == 0)
+    yield
+    unregister_string_format('email')
+    unregister_string_format('even-length')
+
+
+def test_custom_format(custom_formats):
+    v = SchemaValidator(core_schema.string_format_schema('email'))
+    assert v.validate_python('user@example.com') == 'user@example.com'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('user')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_format_mismatch',
+            'loc': (),
+            'msg': "String should match format 'email', missing `@`",
+            'input': 'user',
+            'ctx': {'format': 'email', 'error': 'missing `@`'},
+        }
+    ]
+
+    v = SchemaValidator(core_schema.string_format_schema('even-length'))
+    assert v.validate_json('"ab"') == 'ab'
+    with pytest.raises(ValidationError, match="String should match format 'even-length', invalid value"):
+        v.validate_json('"abc"')
+
+
+def test_custom_format_error():
+    def check(value):
+        raise TypeError('broken')
+
+    register_string_format('broken', check)
+    try:
+        v = SchemaValidator(core_schema.string_format_schema('broken'))
+    finally:
+        unregister_string_format('broken')
+    with pytest.raises(TypeError, match='broken'):
+        v.validate_python('x')
+
+
+def test_register():
+    assert unregister_string_format('missing') is False
+    with pytest.raises(ValueError, match="String format 'uri' is checked by pydantic-core and can't be registered"):
+        register_string_format('uri', lambda value: True)
+    with pytest.raises(TypeError, match='String format check must be callable'):
+        register_string_format('foobar', 123)
+
+    register_string_format('foobar', lambda value: value == 'foo')
+    v = SchemaValidator(core_schema.string_format_schema('foobar'))
+    register_string_format('foobar', lambda value: value == 'bar')
+    assert v.validate_python('foo') == 'foo'
+    assert SchemaValidator(core_schema.string_format_schema('foobar')).validate_python('bar') == 'bar'
+    assert unregister_string_format('foobar') is True
+    # schemas keep the check they were built with
+    assert v.validate_python('foo') == 'foo'
@@ -1,9 +1,10 @@
+import re
 from enum import Enum
 
 import pytest
 from dirty_equals import IsAnyStr
 
-from pydantic_core import CoreConfig, SchemaValidator, ValidationError, core_schema
+from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson
 from .test_typed_dict import Cls
@@ -620,3 +621,166 @@ def test_custom_error_type():
             'input': {'foo': 'other', 'bar': 'Bar'},
         }
     ]
+
+
+@pytest.fixture(scope='module')
+def versioned_validator():
+    v1 = core_schema.typed_dict_schema({'name': core_schema.typed_dict_field(core_schema.str_schema())})
+    v2 = core_schema.typed_dict_schema(
+        {
+            'kind': core_schema.typed_dict_field(core_schema.literal_schema(['user'])),
+            'name': core_schema.typed_dict_field(core_schema.str_schema()),
+            'age': core_schema.typed_dict_field(core_schema.int_schema()),
+        }
+    )
+    return SchemaValidator(
+        core_schema.tagged_union_schema(
+            {'legacy': v1, 'user': v2},
+            # `type` is tried first, then `kind`
+            discriminator=[['type'], ['kind']],
+            default_choice='legacy',
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'kind': 'user', 'name': 'a', 'age': '1'}, {'kind': 'user', 'name': 'a', 'age': 1}),
+        ({'type': 'user', 'kind': 'user', 'name': 'a', 'age': 1}, {'kind': 'user', 'name': 'a', 'age': 1}),
+        ({'type': 'legacy', 'kind': 'user', 'name': 'a'}, {'name': 'a'}),
+        # tag missing
+        ({'name': 'a'}, {'name': 'a'}),
+        # tag unknown
+        ({'kind': 'admin', 'name': 'a'}, {'name': 'a'}),
+    ],
+)
+def test_default_choice(versioned_validator, input_value, expected):
+    assert versioned_validator.validate_python(input_value) == expected
+
+
+def test_default_choice_errors(versioned_validator):
+    with pytest.raises(ValidationError) as exc_info:
+        versioned_validator.validate_json('{"kind": "admin"}')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('legacy', 'name'), 'msg': 'Field required', 'input': {'kind': 'admin'}}
+    ]
+
+
+def test_default_choice_function():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            {'int': core_schema.int_schema(), 'str': core_schema.str_schema()},
+            discriminator=lambda x: 'int' if isinstance(x, int) else None,
+            default_choice='str',
+        )
+    )
+    assert v.validate_python(1) == 1
+    assert v.validate_python('a') == 'a'
+
+
+def test_default_choice_invalid():
+    with pytest.raises(SchemaError, match="`default_choice` 'other' is not one of the choices"):
+        SchemaValidator(
+            core_schema.tagged_union_schema({'a': core_schema.int_schema()}, 'foo', default_choice='other')
+        )
+
+
+@pytest.fixture(scope='module')
+def status_validator():
+    def response_schema(body_schema):
+        return core_schema.typed_dict_schema(
+            {
+                'status': core_schema.typed_dict_field(core_schema.int_schema()),
+                'body': core_schema.typed_dict_field(body_schema),
+            }
+        )
+
+    return SchemaValidator(
+        core_schema.tagged_union_schema(
+            {204: response_schema(core_schema.none_schema())},
+            discriminator='status',
+            range_choices=[
+                core_schema.tagged_union_range_choice(response_schema(core_schema.int_schema()), ge=500),
+                core_schema.tagged_union_range_choice(response_schema(core_schema.str_schema()), ge=400, lt=500),
+                core_schema.tagged_union_range_choice(response_schema(core_schema.list_schema()), gt=199, le=299),
+            ],
+        )
+    )
+
+
+@pytest.mark.parametrize(
+    'status,body',
+    [(204, None), (200, []), (299, [1]), (400, 'a'), (499, 'b'), (500, 1), (2**63 - 1, 2)],
+)
+def test_range_choices(status_validator, status, body):
+    assert status_validator.validate_python({'status': status, 'body': body}) == {'status': status, 'body': body}
+
+
+def test_range_choices_errors(status_validator):
+    with pytest.raises(ValidationError) as exc_info:
+        status_validator.validate_json('{"status": 404, "body": 1}')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (404, 'body'), 'msg': 'Input should be a valid string', 'input': 1}
+    ]
+
+    for status in (300, '404', True):
+        with pytest.raises(ValidationError) as exc_info:
+            status_validator.validate_python({'status': status, 'body': 1})
+        assert exc_info.value.errors(include_url=False)[0]['ctx'] == {
+            'discriminator': "'status'",
+            'tag': str(status),
+            'expected_tags': '204, range ge=500, range ge=400 lt=500, range gt=199 le=299',
+        }
+
+
+def test_pattern_choices():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            {'v1': core_schema.typed_dict_schema({'v': core_schema.typed_dict_field(core_schema.str_schema())})},
+            discriminator='v',
+            pattern_choices=[
+                core_schema.tagged_union_pattern_choice(r'^v2\.', core_schema.dict_schema()),
+                core_schema.tagged_union_pattern_choice(r'^v\d', core_schema.any_schema()),
+            ],
+            default_choice='v1',
+        )
+    )
+    # exact choices are used before patterns
+    assert v.validate_python({'v': 'v1', 'x': 1}) == {'v': 'v1'}
+    # the first matching pattern is used
+    assert v.validate_python({'v': 'v2.1', 'x': 1}) == {'v': 'v2.1', 'x': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(Cls(v='v2.1'))
+    assert exc_info.value.errors(include_url=False)[0]['loc'] == ('v2.1',)
+    assert v.validate_python(Cls(v='v3')).v == 'v3'
+    # patterns which don't match use the default
+    assert v.validate_python({'v': 'latest', 'x': 1}) == {'v': 'latest'}
+
+
+@pytest.mark.parametrize(
+    'range_choices,error',
+    [
+        ([{'ge': 0, 'lt': 10}, {'ge': 9}], 'Tag ranges must not overlap, 0..=9 overlaps 9..=9223372036854775807'),
+        ([{'gt': 5, 'lt': 6}], 'Tag range gt=5 lt=6 is empty'),
+        ([{}], 'Tag ranges require at least one of `ge`, `gt`, `le` or `lt`'),
+    ],
+)
+def test_range_choices_invalid(range_choices, error):
+    with pytest.raises(SchemaError, match=re.escape(error)):
+        SchemaValidator(
+            core_schema.tagged_union_schema(
+                {},
+                'foo',
+                range_choices=[{**c, 'schema': core_schema.dict_schema()} for c in range_choices],
+            )
+        )
+
+
+def test_pattern_choices_invalid():
+    with pytest.raises(SchemaError, match='regex parse error'):
+        SchemaValidator(
+            core_schema.tagged_union_schema(
+                {}, 'foo', pattern_choices=[core_schema.tagged_union_pattern_choice('(', core_schema.dict_schema())]
+            )
+        )
@@ -5,7 +5,7 @@ from typing import Any, Dict, Type
 import pytest
 from dirty_equals import IsNonNegative, IsTuple
 
-from pydantic_core import SchemaValidator, ValidationError, core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
 
 from ..conftest import Err, PyAndJson, infinite_generator
 
@@ -431,6 +431,45 @@ def test_tuple_fix_extra_any():
     ]
 
 
+def test_tuple_max_error_items():
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0, max_error_items=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python((1, 'a', 2, 'b', 'c'))
+    assert [e['loc'] for e in exc_info.value.errors()] == [(1,), (3,)]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ((1, 2.5), (1, 2.5)),
+        ((1, 'a', 'b', 2.5), (1, 'a', 'b', 2.5)),
+        ([1, 'a', 2], (1, 'a', 2.0)),
+        (('x', 'a', 1, 'y'), Err('type=int_parsing', errors=[(0,), (2,), (3,)])),
+        (('x', 1, 'y'), Err('type=int_parsing', errors=[(0,), (1,), (2,)])),
+        (('x', 'y'), Err('type=int_parsing', errors=[(0,), (1,)])),
+        ((1,), Err('type=missing', errors=[(1,)])),
+    ],
+)
+def test_tuple_variadic_middle(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        core_schema.tuple_schema(
+            [core_schema.int_schema(), core_schema.str_schema(), core_schema.float_schema()], variadic_item_index=1
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)) as exc_info:
+            v.validate_test(input_value)
+        # head, body and tail items are located by their index in the input
+        assert [e['loc'] for e in exc_info.value.errors()] == expected.errors
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_tuple_variadic_item_index_out_of_bounds():
+    with pytest.raises(SchemaError, match='`variadic_item_index` 1 is out of bounds for 1 items'):
+        SchemaValidator(core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=1))
+
+
 def test_generator_error():
     def gen(error: bool):
         yield 1
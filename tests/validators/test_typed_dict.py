@@ -6,9 +6,17 @@ import weakref
 from typing import Any, Dict, Mapping, Union
 
 import pytest
-from dirty_equals import FunctionCheck
-
-from pydantic_core import CoreConfig, SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
+from dirty_equals import FunctionCheck, HasRepr
+
+from pydantic_core import (
+    CoreConfig,
+    PydanticUseDefault,
+    SchemaError,
+    SchemaValidator,
+    ValidationError,
+    core_schema,
+    validate_core_schema,
+)
 
 from ..conftest import Err, PyAndJson
 
@@ -416,6 +424,52 @@ def test_alias_allow_pop(py_and_json: PyAndJson):
         assert v.validate_test({'foobar': '123'})
 
 
+@pytest.mark.parametrize(
+    'alias_generator,aliases',
+    [
+        ('to_camel', ['userId', 'createdAt', 'httpStatus2Code', '_privateValue']),
+        ('to_pascal', ['UserId', 'CreatedAt', 'HttpStatus2Code', '_PrivateValue']),
+        ('to_kebab', ['user-id', 'created-at', 'http-status2-code', '_private-value']),
+    ],
+)
+def test_alias_generator(py_and_json: PyAndJson, alias_generator, aliases):
+    names = ['user_id', 'createdAt', 'http_status2Code', '_private_value']
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {name: core_schema.typed_dict_field(core_schema.int_schema()) for name in names},
+            config=CoreConfig(alias_generator=alias_generator),
+        )
+    )
+    assert v.validate_test(dict(zip(aliases, range(4)))) == dict(zip(names, range(4)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_test(dict(zip(names, range(4))))
+    assert [e['loc'] for e in exc_info.value.errors()] == [(alias,) for alias in aliases if alias not in names]
+
+
+def test_alias_generator_explicit_alias():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'user_id': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='uid'),
+                'user_name': core_schema.typed_dict_field(core_schema.str_schema()),
+            },
+            config=CoreConfig(alias_generator='to_camel', populate_by_name=True),
+        )
+    )
+    assert v.validate_python({'uid': 1, 'userName': 'Ada'}) == {'user_id': 1, 'user_name': 'Ada'}
+    assert v.validate_python({'user_id': 1, 'user_name': 'Ada'}) == {'user_id': 1, 'user_name': 'Ada'}
+
+
+def test_alias_generator_invalid():
+    with pytest.raises(SchemaError, match='Invalid alias_generator: `to_snake`'):
+        SchemaValidator(
+            core_schema.typed_dict_schema(
+                {'a': core_schema.typed_dict_field(core_schema.int_schema())},
+                config={'alias_generator': 'to_snake'},
+            )
+        )
+
+
 @pytest.mark.parametrize(
     'input_value,expected',
     [
@@ -596,6 +650,49 @@ def get_custom_getitem():
     assert v.validate_python({'bar': GetItemThing()}) == ({'field_a': 321}, {'field_a'})
 
 
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ({'items': [{'id': 1}, {'id': '2'}]}, {'ids': [1, 2], 'last': [1, 2]}),
+        ({'items': []}, {'ids': [], 'last': []}),
+        ({'items': [{'id': 1, 'tags': [1, 2]}, {'id': 2, 'tags': [3]}]}, {'ids': [1, 2], 'last': [2, 3]}),
+        ({'items': [{'id': 1}, {'name': 'x'}]}, Err(r'items.\*.id\n +Field required \[type=missing,')),
+        ({'items': {'id': 1}}, Err(r'items.\*.id\n +Field required \[type=missing,')),
+        ({'items': [{'id': 'x'}]}, Err(r"items.\*.id.0\n +Input should be a valid integer")),
+    ],
+    ids=repr,
+)
+def test_aliases_path_wildcard(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {
+                'ids': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.int_schema()), validation_alias=['items', '*', 'id']
+                ),
+                'last': core_schema.typed_dict_field(
+                    core_schema.list_schema(core_schema.int_schema()),
+                    validation_alias=[['items', '*', 'tags', -1], ['items', '*', 'id']],
+                    required=False,
+                ),
+            }
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=expected.message):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_aliases_path_nested_wildcards(py_and_json: PyAndJson):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'x': core_schema.typed_dict_field(core_schema.any_schema(), validation_alias=['rows', '*', '*', 'x'])}
+        )
+    )
+    assert v.validate_test({'rows': [[{'x': 1}, {'x': 2}], [{'x': 3}]]}) == {'x': [[1, 2], [3]]}
+
+
 @pytest.mark.parametrize('input_value', [{'foo': {'bar': 42}}, {'foo': 42}, {'field_a': 42}], ids=repr)
 def test_paths_allow_by_name(py_and_json: PyAndJson, input_value):
     v = py_and_json(
@@ -623,6 +720,7 @@ def test_paths_allow_by_name(py_and_json: PyAndJson, input_value):
         ({'validation_alias': [123]}, "TypeError: 'int' object cannot be converted to 'PyList'"),
         ({'validation_alias': [[[]]]}, 'Input should be a valid string'),
         ({'validation_alias': [[1, 'foo']]}, 'TypeError: The first item in an alias path should be a string'),
+        ({'validation_alias': [['*', 'foo']]}, 'TypeError: The first item in an alias path should not be a wildcard'),
     ],
     ids=repr,
 )
@@ -1196,3 +1294,205 @@ def test_leak_typed_dict():
     gc.collect()
 
     assert ref() is None
+
+
+def test_report_lax():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.typed_dict_schema(
+                        {
+                            'c': core_schema.typed_dict_field(core_schema.int_schema()),
+                            'd': core_schema.typed_dict_field(core_schema.str_schema()),
+                        }
+                    )
+                ),
+                'e': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    value = {'a': 1, 'b': {'c': 2, 'd': 'x'}, 'e': [3]}
+    assert v.validate_python(value, report_lax=True) == (value, [])
+    assert v.validate_python({'a': '1', 'b': {'c': '2', 'd': 'x'}, 'e': ['3']}, report_lax=True) == (
+        value,
+        [('a',), ('b', 'c'), ('e',)],
+    )
+    assert v.validate_python({'a': 1, 'b': {'c': 2, 'd': 'x'}, 'e': (3,)}, report_lax=True) == (value, [('e',)])
+    assert v.validate_python({'a': '1', 'b': {'c': 2, 'd': 'x'}, 'e': [3]}) == value
+
+
+def test_report_lax_root():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_python(1, report_lax=True) == (1, [])
+    assert v.validate_python('1', report_lax=True) == (1, [()])
+
+
+def test_report_lax_union():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.str_schema())}),
+            ]
+        )
+    )
+    # the first member fails after `a` was validated in lax mode
+    assert v.validate_python({'a': '1'}, report_lax=True) == ({'a': '1'}, [])
+    assert v.validate_python({'a': '1', 'b': 2}, report_lax=True) == ({'a': 1, 'b': 2}, [('a',)])
+
+
+def test_report_lax_function_wrap():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.no_info_wrap_validator_function(
+                        lambda value, handler: handler(value), core_schema.int_schema()
+                    )
+                )
+            }
+        )
+    )
+    assert v.validate_python({'a': '1'}, report_lax=True) == ({'a': 1}, [('a',)])
+
+
+@pytest.mark.parametrize(
+    'extra_behavior,expected',
+    [
+        ('ignore', {'f': 'x', 'x_1': 1}),
+        ('allow', {'f': 'x', 'x_1': 1, 'y': '2'}),
+        ('forbid', Err('y\n  Extra inputs are not permitted [type=extra_forbidden')),
+    ],
+)
+def test_extras_pattern_schemas(py_and_json: PyAndJson, extra_behavior, expected):
+    v = py_and_json(
+        core_schema.typed_dict_schema(
+            {'f': core_schema.typed_dict_field(core_schema.str_schema())},
+            extras_pattern_schemas={'^x_': core_schema.int_schema()},
+            extra_behavior=extra_behavior,
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test({'f': 'x', 'x_1': '1', 'y': '2'})
+    else:
+        assert v.validate_test({'f': 'x', 'x_1': '1', 'y': '2'}) == expected
+
+
+def test_extras_pattern_schemas_errors():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'f': core_schema.typed_dict_field(core_schema.str_schema())},
+            extras_pattern_schemas={'^x_': core_schema.int_schema(), '_s$': core_schema.str_schema()},
+            extra_behavior='allow',
+            extras_schema=core_schema.bool_schema(),
+        )
+    )
+    # keys are validated by the first pattern they match, fields and unmatched keys as usual
+    output = v.validate_python({'f': 'x', 'x_s': '1', 'a_s': 'a', 'b': 'yes'})
+    assert output == {'f': 'x', 'x_s': 1, 'a_s': 'a', 'b': True}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'f': 'x', 'x_1': 'a', 'a_s': 1, 'b': 'c'})
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('x_1',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'a',
+        },
+        {'type': 'string_type', 'loc': ('a_s',), 'msg': 'Input should be a valid string', 'input': 1},
+        {
+            'type': 'bool_parsing',
+            'loc': ('b',),
+            'msg': 'Input should be a valid boolean, unable to interpret input',
+            'input': 'c',
+        },
+    ]
+
+
+def test_extras_pattern_schemas_regex_engine():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {},
+            extras_pattern_schemas={r'^x_(?!y)': core_schema.int_schema()},
+            config=core_schema.CoreConfig(regex_engine='python-re'),
+        )
+    )
+    assert v.validate_python({'x_1': '1', 'x_y': '2'}) == {'x_1': 1}
+
+    with pytest.raises(SchemaError, match='look-around'):
+        SchemaValidator(
+            core_schema.typed_dict_schema({}, extras_pattern_schemas={r'^x_(?!y)': core_schema.int_schema()})
+        )
+
+
+def test_on_missing():
+    calls = []
+
+    def on_missing(field_name, data, context):
+        calls.append((field_name, dict(data), context))
+        if context is None:
+            raise PydanticUseDefault()
+        if context == 'error':
+            raise ValueError('no backfill')
+        return context[data['id']]
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'id': core_schema.typed_dict_field(core_schema.int_schema()),
+                'name': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='anon'),
+                    on_missing=on_missing,
+                ),
+            }
+        )
+    )
+    assert v.validate_python({'id': 1, 'name': 'x'}) == {'id': 1, 'name': 'x'}
+    assert calls == []
+
+    assert v.validate_python({'id': 1}, context={1: 'alice'}) == {'id': 1, 'name': 'alice'}
+    assert calls == [('name', {'id': 1}, {1: 'alice'})]
+    assert v.validate_json('{"id": 1}') == {'id': 1, 'name': 'anon'}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'id': 1}, context='error')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'value_error',
+            'loc': ('name',),
+            'msg': 'Value error, no backfill',
+            'input': {'id': 1},
+            'ctx': {'error': HasRepr(repr(ValueError('no backfill')))},
+        }
+    ]
+
+    # the returned value is validated
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'id': 1}, context={1: 123})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': ('name',), 'msg': 'Input should be a valid string', 'input': 123}
+    ]
+
+
+def test_on_missing_use_default_required():
+    def on_missing(field_name, data, context):
+        raise PydanticUseDefault()
+
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'a': core_schema.typed_dict_field(core_schema.int_schema(), on_missing=on_missing)}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {}}
+    ]
@@ -0,0 +1,104 @@
+import re
+from uuid import UUID
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err, PyAndJson
+
+ULID = '01ARZ3NDEKTSV4RRFFQ69G5FAV'
+ULID_UUID = UUID('01563e3a-b5d3-d676-4c61-efb99302bd5b')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (ULID, ULID),
+        ('01arz3ndektsv4rrffq69g5fav', ULID),
+        ('00000000000000000000000000', '00000000000000000000000000'),
+        ('7ZZZZZZZZZZZZZZZZZZZZZZZZZ', '7ZZZZZZZZZZZZZZZZZZZZZZZZZ'),
+        (ULID_UUID, ULID),
+        (ULID_UUID.bytes, ULID),
+        (ULID.encode(), ULID),
+        (b'01arz3ndektsv4rrffq69g5fav', ULID),
+        (
+            '8ZZZZZZZZZZZZZZZZZZZZZZZZZ',
+            Err('Input should be a valid ULID, the value is too large, the first character should be between 0 and 7'),
+        ),
+        (ULID[:-1], Err('Input should be a valid ULID, invalid length: expected 26 characters, found 25')),
+        (
+            '01ARZ3NDEKTSV4RRFFQ69G5FAU',
+            Err('Input should be a valid ULID, invalid character: expected [0-9A-HJKMNP-TV-Z], found `U` at 25'),
+        ),
+        (
+            '01ARZ3NDEKTSV4RRFFQ69G5FAé',
+            Err('Input should be a valid ULID, invalid character: expected [0-9A-HJKMNP-TV-Z], found `é` at 25'),
+        ),
+        (b'\xff' * 15, Err('Input should be a valid ULID, invalid length: expected 16 bytes, found 15')),
+        (123, Err('ULID input should be a string, bytes or UUID object [type=ulid_type,')),
+    ],
+)
+def test_ulid(input_value, expected):
+    v = SchemaValidator(core_schema.ulid_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (ULID, ULID),
+        ('01arz3ndektsv4rrffq69g5fav', Err('found `a` at 2 [type=ulid_parsing,')),
+        (ULID_UUID, Err('ULID input should be a string, bytes or UUID object [type=ulid_type,')),
+        (ULID.encode(), Err('[type=ulid_type,')),
+    ],
+)
+def test_ulid_strict(input_value, expected):
+    v = SchemaValidator(core_schema.ulid_schema(strict=True))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        (ULID, ULID),
+        ('01arz3ndektsv4rrffq69g5fav', ULID),
+        (1, Err('[type=ulid_type,')),
+    ],
+)
+def test_ulid_json(py_and_json: PyAndJson, input_value, expected):
+    v = py_and_json(core_schema.ulid_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_test(input_value)
+    else:
+        assert v.validate_test(input_value) == expected
+
+
+def test_ulid_json_type_error():
+    v = SchemaValidator(core_schema.ulid_schema())
+    with pytest.raises(ValidationError, match=r'ULID input should be a string \[type=ulid_type,'):
+        v.validate_json('1')
+
+
+def test_as_uuid():
+    v = SchemaValidator(core_schema.ulid_schema(as_uuid=True))
+    output = v.validate_python(ULID)
+    assert isinstance(output, UUID)
+    assert output == ULID_UUID
+    assert v.validate_python(ULID_UUID) == ULID_UUID
+    assert v.validate_json(f'"{ULID.lower()}"') == ULID_UUID
+
+
+def test_union_exactness():
+    v = SchemaValidator(core_schema.union_schema([core_schema.ulid_schema(as_uuid=True), core_schema.str_schema()]))
+    assert v.validate_python(ULID) == ULID_UUID
+    assert v.validate_python(ULID.lower()) == ULID.lower()
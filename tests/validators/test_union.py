@@ -8,7 +8,7 @@ from uuid import UUID
 import pytest
 from dirty_equals import IsFloat, IsInt
 
-from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, validate_core_schema
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema, union_debug, validate_core_schema
 
 from ..conftest import plain_repr
 
@@ -1333,3 +1333,244 @@ def test_smart_union_extra_behavior(extra_behavior) -> None:
 
     assert isinstance(validator.validate_python({'x': {'foo': 'foo'}}).x, Foo)
     assert isinstance(validator.validate_python({'x': {'bar': 'bar'}}).x, Bar)
+
+
+def test_stats():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    assert v.stats() == {'calls': 0, 'failures': 0, 'lax_fallbacks': 0, 'union_retries': 0}
+
+    # exact match on the first member
+    assert v.validate_python(1) == 1
+    assert v.stats() == {'calls': 1, 'failures': 0, 'lax_fallbacks': 0, 'union_retries': 0}
+
+    # exact match on the second member
+    assert v.validate_python('a') == 'a'
+    assert v.stats() == {'calls': 2, 'failures': 0, 'lax_fallbacks': 0, 'union_retries': 1}
+
+    # lax match on the first member only
+    assert v.validate_python(1.0) == 1
+    assert v.stats() == {'calls': 3, 'failures': 0, 'lax_fallbacks': 1, 'union_retries': 2}
+
+    with pytest.raises(ValidationError):
+        v.validate_json('[]')
+    assert v.stats(reset=True) == {'calls': 4, 'failures': 1, 'lax_fallbacks': 1, 'union_retries': 3}
+    assert v.stats() == {'calls': 0, 'failures': 0, 'lax_fallbacks': 0, 'union_retries': 0}
+
+
+def test_stats_left_to_right():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right')
+    )
+    assert v.validate_python('1') == 1
+    assert v.validate_python('a') == 'a'
+    assert v.stats() == {'calls': 2, 'failures': 0, 'lax_fallbacks': 0, 'union_retries': 1}
+
+
+def test_stats_validate_assignment():
+    v = SchemaValidator(
+        core_schema.model_fields_schema({'x': core_schema.model_field(core_schema.int_schema())})
+    )
+    assert v.validate_assignment({'x': 1}, 'x', 2) == ({'x': 2}, None, {'x'})
+    with pytest.raises(ValidationError):
+        v.validate_assignment({'x': 1}, 'x', 'a')
+    assert v.stats() == {'calls': 2, 'failures': 1, 'lax_fallbacks': 0, 'union_retries': 0}
+
+
+def test_prefer_exact_type():
+    choices = [core_schema.float_schema(), core_schema.int_schema()]
+    v = SchemaValidator(core_schema.union_schema(choices))
+    assert v.validate_python(1) == IsInt(exactly=1)
+
+    v = SchemaValidator(core_schema.union_schema(choices, prefer_exact_type=False))
+    # an int is valid for `float` in strict mode, which is as good as the exact match for `int`
+    assert v.validate_python(1) == IsFloat(exactly=1.0)
+    assert v.validate_python('1') == IsFloat(exactly=1.0)
+
+
+def test_prefer_leftmost_on_tie():
+    choices = [core_schema.int_schema(), core_schema.float_schema()]
+    v = SchemaValidator(core_schema.union_schema(choices))
+    assert v.validate_python('1') == IsInt(exactly=1)
+
+    v = SchemaValidator(core_schema.union_schema(choices, prefer_leftmost_on_tie=False))
+    assert v.validate_python('1') == IsFloat(exactly=1.0)
+    # exact matches still win
+    assert v.validate_python(1) == IsInt(exactly=1)
+    assert v.validate_python(1.5) == 1.5
+
+
+def test_adaptive_order():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.str_schema(), core_schema.int_schema()], adaptive_order=True)
+    )
+    for _ in range(1024):
+        assert v.validate_python(1) == 1
+    assert v.stats(reset=True)['union_retries'] == 1024
+
+    # `int` is chosen most often, so it's now tried first
+    assert v.validate_python('a') == 'a'
+    assert v.stats(reset=True)['union_retries'] == 1
+    # `str` is before `int` in the schema, so it's still tried before `int` is returned
+    assert v.validate_python(1) == 1
+    assert v.stats(reset=True)['union_retries'] == 1
+
+
+def test_adaptive_order_exact_type_match():
+    def first(value):
+        if isinstance(value, str):
+            raise ValueError('not a str')
+        return 'first'
+
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                (core_schema.no_info_plain_validator_function(first), 'first'),
+                (core_schema.no_info_plain_validator_function(lambda value: 'second'), 'second'),
+            ],
+            adaptive_order=True,
+        )
+    )
+    for _ in range(1024):
+        assert v.validate_python('a') == 'second'
+
+    # `second` is now tried first, both match exactly, the first in the schema is still returned
+    with union_debug() as decisions:
+        assert v.validate_python(1) == 'first'
+    assert [(d['choice'], d['reason']) for d in decisions] == [('first', 'exact type match')]
+    assert v.validate_python('a') == 'second'
+
+
+def test_adaptive_order_same_result():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()], adaptive_order=True)
+    )
+    for _ in range(1024):
+        assert v.validate_python(1.5) == 1.5
+
+    # `float` is tried first, but the leftmost of equally good matches is still returned
+    assert v.validate_python('1') == IsInt(exactly=1)
+    # and errors are in schema order
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('a')
+    assert [e['loc'] for e in exc_info.value.errors(include_url=False)] == [('int',), ('float',)]
+
+
+def test_adaptive_order_left_to_right():
+    with pytest.raises(SchemaError, match='`adaptive_order` is only supported in `smart` mode'):
+        SchemaValidator(
+            core_schema.union_schema(
+                [core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right', adaptive_order=True
+            )
+        )
+
+
+def test_union_debug():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'x': core_schema.typed_dict_field(
+                    core_schema.union_schema(
+                        [core_schema.int_schema(), (core_schema.str_schema(), 'text'), core_schema.float_schema()]
+                    )
+                )
+            }
+        )
+    )
+    with union_debug() as decisions:
+        assert v.validate_python({'x': 1}) == {'x': 1}
+        assert v.validate_python({'x': 'a'}) == {'x': 'a'}
+        assert v.validate_python({'x': 1.0}) == {'x': 1.0}
+
+    name = 'union[int,text,float]'
+    assert decisions == [
+        {
+            'union': name,
+            'loc': ('x',),
+            'choice': 'int',
+            'reason': 'exact type match',
+            'candidates': [{'choice': 'int', 'exactness': 'exact', 'fields_set_count': None}],
+        },
+        {
+            'union': name,
+            'loc': ('x',),
+            'choice': 'text',
+            'reason': 'exact type match',
+            'candidates': [
+                {'choice': 'int', 'exactness': None, 'fields_set_count': None},
+                {'choice': 'text', 'exactness': 'exact', 'fields_set_count': None},
+            ],
+        },
+        {
+            'union': name,
+            'loc': ('x',),
+            'choice': 'float',
+            'reason': 'exact type match',
+            'candidates': [
+                {'choice': 'int', 'exactness': 'lax', 'fields_set_count': None},
+                {'choice': 'text', 'exactness': None, 'fields_set_count': None},
+                {'choice': 'float', 'exactness': 'exact', 'fields_set_count': None},
+            ],
+        },
+    ]
+
+
+@pytest.mark.parametrize(
+    'input_value,expected_reason',
+    [
+        ({'a': 1, 'b': 2}, 'most fields set'),
+        ({'a': 1}, 'leftmost of equally good choices'),
+        ({'a': 1.0}, 'best exactness'),
+        ({'a': 1.5}, 'only valid choice'),
+        ({'a': 'x'}, 'no valid choice'),
+    ],
+)
+def test_union_debug_reason(input_value, expected_reason):
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.int_schema())}),
+                core_schema.model_fields_schema(
+                    {
+                        'a': core_schema.model_field(core_schema.int_schema()),
+                        'b': core_schema.model_field(core_schema.with_default_schema(core_schema.int_schema(), default=0)),
+                    }
+                ),
+                core_schema.model_fields_schema({'a': core_schema.model_field(core_schema.float_schema())}),
+            ]
+        )
+    )
+    with union_debug() as decisions:
+        try:
+            v.validate_python(input_value)
+        except ValidationError:
+            pass
+    assert [d['reason'] for d in decisions] == [expected_reason]
+
+
+def test_union_debug_outside_block():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    with union_debug() as decisions:
+        pass
+    v.validate_python('1')
+    assert decisions == []
+
+
+def test_union_debug_assignment():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    union_schema = core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()])
+    v = SchemaValidator(
+        core_schema.model_schema(Model, core_schema.model_fields_schema({'x': core_schema.model_field(union_schema)}))
+    )
+    m = v.validate_python({'x': 1})
+    with union_debug() as decisions:
+        v.validate_assignment(m, 'x', 'a')
+        v.validate_assignment_many(m, {'x': 2})
+        m.__dict__['x'] = 'b'
+        v.revalidate_paths(m, ['x'])
+    assert [(d['loc'], d['choice'], d['reason']) for d in decisions] == [
+        (('x',), 'str', 'exact type match'),
+        (('x',), 'int', 'exact type match'),
+        (('x',), 'str', 'exact type match'),
+    ]
@@ -0,0 +1,104 @@
+import re
+
+import pytest
+
+from pydantic_core import MultiHostUrl, SchemaValidator, Url, ValidationError, core_schema
+
+from ..conftest import Err
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('https://example.com/path?q=1#f', 'https://example.com/path?q=1#f'),
+        ('HTTPS://Example.com', 'HTTPS://Example.com'),
+        ('mailto:someone@example.com', 'mailto:someone@example.com'),
+        ('../images/logo.png?size=2', '../images/logo.png?size=2'),
+        ('/absolute/path', '/absolute/path'),
+        ('//example.com/path', '//example.com/path'),
+        ('?query', '?query'),
+        ('#fragment', '#fragment'),
+        ('', ''),
+        ('c:relative-looking', 'c:relative-looking'),
+        ('//example.com:99999/', Err('Input should be a valid URL, invalid port number [type=url_parsing,')),
+        ('http://', Err('Input should be a valid URL, empty host [type=url_parsing,')),
+        ('https://[::1', Err('Input should be a valid URL, invalid IPv6 address [type=url_parsing,')),
+        (1, Err('URL input should be a string or URL [type=url_type,')),
+    ],
+)
+def test_uri_reference(input_value, expected):
+    v = SchemaValidator(core_schema.uri_reference_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is str
+
+
+def test_uri_reference_json():
+    v = SchemaValidator(core_schema.uri_reference_schema())
+    assert v.validate_json('"../a?b=1"') == '../a?b=1'
+    with pytest.raises(ValidationError, match=r'URL input should be a string or URL \[type=url_type,'):
+        v.validate_json('123')
+
+
+def test_url_instances():
+    v = SchemaValidator(core_schema.uri_reference_schema())
+    assert v.validate_python(Url('https://example.com')) == 'https://example.com/'
+    assert v.validate_python(MultiHostUrl('redis://a:1,b:2/0')) == 'redis://a:1,b:2/0'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('HTTPS://Example.com/%7euser/a%2fb', 'https://example.com/~user/a%2Fb'),
+        ('../%7euser/?q=%41%2a', '../~user/?q=A%2A'),
+        ('', ''),
+    ],
+)
+def test_normalize(input_value, expected):
+    v = SchemaValidator(core_schema.uri_reference_schema(normalize=True))
+    assert v.validate_python(input_value) == expected
+
+
+def test_allowed_schemes():
+    v = SchemaValidator(core_schema.uri_reference_schema(allowed_schemes=['http', 'https']))
+    assert v.validate_python('https://example.com') == 'https://example.com'
+    # relative references have no scheme to check
+    assert v.validate_python('../path') == '../path'
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('ftp://example.com')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'url_scheme',
+            'loc': (),
+            'msg': "URL scheme should be 'http' or 'https'",
+            'input': 'ftp://example.com',
+            'ctx': {'expected_schemes': "'http' or 'https'"},
+        }
+    ]
+
+
+def test_max_length():
+    v = SchemaValidator(core_schema.uri_reference_schema(max_length=5))
+    assert v.validate_python('../ab') == '../ab'
+    with pytest.raises(ValidationError, match=r'URL should have at most 5 characters \[type=url_too_long,'):
+        v.validate_python('../abc')
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.uri_reference_schema(strict=True))
+    assert v.validate_python('../a') == '../a'
+    with pytest.raises(ValidationError, match=r'non-URL code point \[type=url_syntax_violation,'):
+        v.validate_python('../a b')
+
+    v = SchemaValidator(core_schema.uri_reference_schema())
+    assert v.validate_python('../a b') == '../a b'
+
+
+def test_in_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.uri_reference_schema()]))
+    assert v.validate_python('/a') == '/a'
+    assert v.validate_python(1) == 1
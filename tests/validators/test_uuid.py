@@ -32,6 +32,10 @@ class MyStr(str): ...
         (b'\x12\x34\x56\x78' * 4, UUID('12345678-1234-5678-1234-567812345678')),
         (b'\x00\x00\x00\x00' * 4, UUID('00000000-0000-0000-0000-000000000000')),
         (b'ebcdab58-6eb8-46fb-a190-d07a33e9eac8', UUID('ebcdab58-6eb8-46fb-a190-d07a33e9eac8')),
+        ('{12345678-1234-5678-1234-567812345678}', UUID('12345678-1234-5678-1234-567812345678')),
+        ('urn:uuid:12345678-1234-5678-1234-567812345678', UUID('12345678-1234-5678-1234-567812345678')),
+        ('12345678123456781234567812345678', UUID('12345678-1234-5678-1234-567812345678')),
+        (b'{12345678-1234-5678-1234-567812345678}', UUID('12345678-1234-5678-1234-567812345678')),
         (UUID('12345678-1234-5678-1234-567812345678'), UUID('12345678-1234-5678-1234-567812345678')),
         (UUID('550e8400-e29b-41d4-a716-446655440000'), UUID('550e8400-e29b-41d4-a716-446655440000')),
         # Invalid UUIDs
@@ -147,6 +151,9 @@ def test_uuid_strict(input_value, expected):
         (UUID('08ed0736-fb95-5cc5-85ed-37e4f3df9b29'), 1, Err('UUID version 1 expected')),
         ('00000000-0000-4000-0000-000000000000', 4, Err('UUID version 4 expected')),
         (UUID('00000000-0000-4000-0000-000000000000'), 4, Err('UUID version 4 expected')),
+        ('urn:uuid:a6cc5730-2261-11ee-9c43-2eb5a363657c', 1, UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c')),
+        (b'\xa6\xccW0"a\x11\xee\x9cC.\xb5\xa3ce|', 1, UUID('a6cc5730-2261-11ee-9c43-2eb5a363657c')),
+        (b'\xa6\xccW0"a\x11\xee\x9cC.\xb5\xa3ce|', 4, Err('UUID version 4 expected')),
     ],
 )
 def test_uuid_version(input_value, version, expected):
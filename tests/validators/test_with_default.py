@@ -1,5 +1,6 @@
 import gc
 import platform
+import random
 import sys
 import weakref
 from collections import deque
@@ -812,3 +813,59 @@ def test_validate_default_raises_dataclass(input_value: dict, expected: Any) ->
         v.validate_python(input_value)
 
     assert exc_info.value.errors(include_url=False, include_context=False) == expected
+
+
+def random_ids_schema() -> core_schema.TypedDictSchema:
+    field = core_schema.typed_dict_field(
+        core_schema.with_default_schema(
+            core_schema.int_schema(), default_factory=lambda rng: rng.randrange(1000), default_factory_takes_rng=True
+        )
+    )
+    return core_schema.typed_dict_schema({'a': field, 'b': field})
+
+
+def test_default_factory_rng_seed():
+    v = SchemaValidator(random_ids_schema())
+    expected_rng = random.Random(42)
+    expected = {'a': expected_rng.randrange(1000), 'b': expected_rng.randrange(1000)}
+    assert v.validate_python({}, rng=42) == expected
+    assert v.validate_python({}, rng=42) == expected
+    assert v.validate_json('{}', rng=42) == expected
+    assert v.validate_python({'a': 1}, rng=42) == {'a': 1, 'b': expected['a']}
+
+
+def test_default_factory_rng_config():
+    v = SchemaValidator(random_ids_schema(), {'rng': 42})
+    expected_rng = random.Random(42)
+    expected = {'a': expected_rng.randrange(1000), 'b': expected_rng.randrange(1000)}
+    assert v.validate_python({}) == expected
+    assert v.validate_python({}) == expected
+    # passed to validation overrides the config
+    assert v.validate_python({}, rng=43) != expected
+
+
+def test_default_factory_rng_generator():
+    v = SchemaValidator(random_ids_schema())
+    rng = random.Random(42)
+    expected_rng = random.Random(42)
+    # a generator is used as is, so carries on from where the last validation stopped
+    assert v.validate_python({}, rng=rng) == {'a': expected_rng.randrange(1000), 'b': expected_rng.randrange(1000)}
+    assert v.validate_python({}, rng=rng) == {'a': expected_rng.randrange(1000), 'b': expected_rng.randrange(1000)}
+
+
+def test_default_factory_rng_unset():
+    rngs = []
+
+    def factory(rng):
+        rngs.append(rng)
+        return 1
+
+    schema = core_schema.with_default_schema(
+        core_schema.int_schema(), default_factory=factory, default_factory_takes_rng=True
+    )
+    v = SchemaValidator(schema)
+    assert v.get_default_value().value == 1
+    assert isinstance(rngs[0], random.Random)
+    assert v.get_default_value(rng=1).value == 1
+    assert v.validate_python(1, rng=1) == 1
+    assert len(rngs) == 2